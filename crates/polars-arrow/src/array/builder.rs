@@ -375,7 +375,7 @@ pub fn make_builder(dtype: &ArrowDataType) -> Box<dyn ArrayBuilder> {
         BinaryView => Box::new(BinaryViewArrayGenericBuilder::<[u8]>::new(dtype.clone())),
         Utf8View => Box::new(BinaryViewArrayGenericBuilder::<str>::new(dtype.clone())),
 
-        List | Binary | Utf8 | LargeUtf8 | Map | Union | Dictionary(_) => {
+        List | Binary | Utf8 | LargeUtf8 | Map | Union | RunEndEncoded | Dictionary(_) => {
             unimplemented!()
         },
     }
@@ -12,6 +12,7 @@ mod list;
 mod map;
 mod null;
 mod primitive;
+mod run_end_encoded;
 mod struct_;
 mod union;
 mod utf8;
@@ -284,6 +285,11 @@ pub fn equal(lhs: &dyn Array, rhs: &dyn Array) -> bool {
             let rhs = rhs.as_any().downcast_ref().unwrap();
             map::equal(lhs, rhs)
         },
+        RunEndEncoded => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            run_end_encoded::equal(lhs, rhs)
+        },
         BinaryView => {
             let lhs = lhs.as_any().downcast_ref().unwrap();
             let rhs = rhs.as_any().downcast_ref().unwrap();
@@ -0,0 +1,34 @@
+use super::equal as equal_dyn;
+use crate::array::{Array, RunEndEncodedArray};
+
+pub(super) fn equal(lhs: &RunEndEncodedArray, rhs: &RunEndEncodedArray) -> bool {
+    if lhs.dtype() != rhs.dtype() || lhs.len() != rhs.len() {
+        return false;
+    }
+
+    let mut lhs_runs = lhs.runs();
+    let mut rhs_runs = rhs.runs();
+    let mut lhs_run = lhs_runs.next();
+    let mut rhs_run = rhs_runs.next();
+    while let (Some((i, lhs_len)), Some((j, rhs_len))) = (lhs_run, rhs_run) {
+        let lhs_value = lhs.values().sliced(i, 1);
+        let rhs_value = rhs.values().sliced(j, 1);
+        if !equal_dyn(lhs_value.as_ref(), rhs_value.as_ref()) {
+            return false;
+        }
+
+        // Advance the shortest run, keeping the remainder of the longest one.
+        let overlap = lhs_len.min(rhs_len);
+        lhs_run = if lhs_len == overlap {
+            lhs_runs.next()
+        } else {
+            Some((i, lhs_len - overlap))
+        };
+        rhs_run = if rhs_len == overlap {
+            rhs_runs.next()
+        } else {
+            Some((j, rhs_len - overlap))
+        };
+    }
+    true
+}
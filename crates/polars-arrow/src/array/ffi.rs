@@ -73,6 +73,7 @@ pub fn offset_buffers_children_dictionary(array: &dyn Array) -> BuffersChildren
         Struct => ffi_dyn!(array, StructArray),
         Union => ffi_dyn!(array, UnionArray),
         Map => ffi_dyn!(array, MapArray),
+        RunEndEncoded => ffi_dyn!(array, RunEndEncodedArray),
         BinaryView => ffi_dyn!(array, BinaryViewArray),
         Utf8View => ffi_dyn!(array, Utf8ViewArray),
         Dictionary(key_type) => {
@@ -91,6 +91,14 @@ pub fn get_value_display<'a, F: Write + 'a>(
         Map => Box::new(move |f, index| {
             super::map::fmt::write_value(array.as_any().downcast_ref().unwrap(), index, null, f)
         }),
+        RunEndEncoded => Box::new(move |f, index| {
+            super::run_end_encoded::fmt::write_value(
+                array.as_any().downcast_ref().unwrap(),
+                index,
+                null,
+                f,
+            )
+        }),
         BinaryView => Box::new(move |f, index| {
             super::binview::fmt::write_value::<[u8], _>(
                 array.as_any().downcast_ref().unwrap(),
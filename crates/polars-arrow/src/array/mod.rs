@@ -376,6 +376,7 @@ impl std::fmt::Debug for dyn Array + '_ {
                 })
             },
             Map => fmt_dyn!(self, MapArray, f),
+            RunEndEncoded => fmt_dyn!(self, RunEndEncodedArray, f),
         }
     }
 }
@@ -400,6 +401,7 @@ pub fn new_empty_array(dtype: ArrowDataType) -> Box<dyn Array> {
         Struct => Box::new(StructArray::new_empty(dtype)),
         Union => Box::new(UnionArray::new_empty(dtype)),
         Map => Box::new(MapArray::new_empty(dtype)),
+        RunEndEncoded => Box::new(RunEndEncodedArray::new_empty(dtype)),
         Utf8View => Box::new(Utf8ViewArray::new_empty(dtype)),
         BinaryView => Box::new(BinaryViewArray::new_empty(dtype)),
         Dictionary(key_type) => {
@@ -413,7 +415,7 @@ pub fn new_empty_array(dtype: ArrowDataType) -> Box<dyn Array> {
 /// Creates a new [`Array`] of [`ArrowDataType`] `dtype` and `length`.
 ///
 /// The array is guaranteed to have [`Array::null_count`] equal to [`Array::len`]
-/// for all types except Union, which does not have a validity.
+/// for all types except Union and RunEndEncoded, which do not have a validity.
 pub fn new_null_array(dtype: ArrowDataType, length: usize) -> Box<dyn Array> {
     use crate::datatypes::PhysicalType::*;
     match dtype.to_physical_type() {
@@ -433,6 +435,7 @@ pub fn new_null_array(dtype: ArrowDataType, length: usize) -> Box<dyn Array> {
         Struct => Box::new(StructArray::new_null(dtype, length)),
         Union => Box::new(UnionArray::new_null(dtype, length)),
         Map => Box::new(MapArray::new_null(dtype, length)),
+        RunEndEncoded => Box::new(RunEndEncodedArray::new_null(dtype, length)),
         BinaryView => Box::new(BinaryViewArray::new_null(dtype, length)),
         Utf8View => Box::new(Utf8ViewArray::new_null(dtype, length)),
         Dictionary(key_type) => {
@@ -655,6 +658,7 @@ pub fn clone(array: &dyn Array) -> Box<dyn Array> {
         Struct => clone_dyn!(array, StructArray),
         Union => clone_dyn!(array, UnionArray),
         Map => clone_dyn!(array, MapArray),
+        RunEndEncoded => clone_dyn!(array, RunEndEncodedArray),
         BinaryView => clone_dyn!(array, BinaryViewArray),
         Utf8View => clone_dyn!(array, Utf8ViewArray),
         Dictionary(key_type) => {
@@ -684,6 +688,7 @@ pub use list::LIST_VALUES_NAME;
 mod map;
 mod null;
 mod primitive;
+mod run_end_encoded;
 pub mod specification;
 mod static_array;
 mod static_array_collect;
@@ -728,6 +733,7 @@ pub use null::{MutableNullArray, NullArray, NullArrayBuilder};
 use polars_buffer::Buffer;
 use polars_error::PolarsResult;
 pub use primitive::*;
+pub use run_end_encoded::RunEndEncodedArray;
 pub use static_array::{ParameterFreeDtypeStaticArray, StaticArray};
 pub use static_array_collect::{ArrayCollectIterExt, ArrayFromIter, ArrayFromIterDtype};
 pub use struct_::{StructArray, StructArrayBuilder};
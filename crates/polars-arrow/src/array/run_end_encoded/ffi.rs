@@ -0,0 +1,46 @@
+use polars_error::{PolarsResult, polars_ensure};
+
+use super::super::Array;
+use super::super::ffi::ToFfi;
+use super::RunEndEncodedArray;
+use crate::array::FromFfi;
+use crate::ffi;
+
+unsafe impl ToFfi for RunEndEncodedArray {
+    fn buffers(&self) -> Vec<Option<*const u8>> {
+        vec![]
+    }
+
+    fn children(&self) -> Vec<Box<dyn Array>> {
+        vec![self.run_ends.clone(), self.values.clone()]
+    }
+
+    fn offset(&self) -> Option<usize> {
+        Some(self.offset)
+    }
+
+    fn to_ffi_aligned(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl<A: ffi::ArrowArrayRef> FromFfi<A> for RunEndEncodedArray {
+    unsafe fn try_from_ffi(array: A) -> PolarsResult<Self> {
+        let dtype = array.dtype().clone();
+
+        let length = array.array().len();
+        let offset = array.array().offset();
+        let run_ends = ffi::try_from(unsafe { array.child(0) }?)?;
+        let values = ffi::try_from(unsafe { array.child(1) }?)?;
+
+        // The children of a run-end encoded array are never sliced, the slice is stored as
+        // the offset and length of the parent.
+        let mut array = Self::try_new(dtype, run_ends, values)?;
+        polars_ensure!(
+            offset + length <= array.len(),
+            ComputeError: "the offset and length of a run-end encoded array exceed its run ends"
+        );
+        array.slice(offset, length);
+        Ok(array)
+    }
+}
@@ -0,0 +1,23 @@
+use std::fmt::{Debug, Formatter, Result, Write};
+
+use super::super::fmt::{get_display, write_vec};
+use super::RunEndEncodedArray;
+
+pub fn write_value<W: Write>(
+    array: &RunEndEncodedArray,
+    index: usize,
+    null: &'static str,
+    f: &mut W,
+) -> Result {
+    let index = array.physical_index(index);
+    get_display(array.values().as_ref(), null)(f, index)
+}
+
+impl Debug for RunEndEncodedArray {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let writer = |f: &mut Formatter, index| write_value(self, index, "None", f);
+
+        write!(f, "RunEndEncodedArray")?;
+        write_vec(f, writer, None, self.len(), "None", false)
+    }
+}
@@ -0,0 +1,313 @@
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+
+use super::{Array, PrimitiveArray, Splitable, new_empty_array, new_null_array};
+use crate::bitmap::Bitmap;
+use crate::datatypes::{ArrowDataType, Field};
+
+mod ffi;
+pub(super) mod fmt;
+
+/// [`RunEndEncodedArray`] represents an array as a sequence of runs, where every run is a single
+/// value repeated a number of times.
+///
+/// The `run_ends` child contains the (exclusive) end position of every run and the `values`
+/// child contains the value of every run. Like in the Arrow specification this array has no
+/// validity of its own: null slots are represented by runs of a null value.
+///
+// How to read a value at slot i:
+// ```
+// let physical_index = run_ends.partition_point(|end| end <= offset + i);
+// let value = values.value(physical_index);
+// ```
+#[derive(Clone)]
+pub struct RunEndEncodedArray {
+    dtype: ArrowDataType,
+    // Invariant: strictly increasing, positive and without nulls.
+    run_ends: Box<dyn Array>,
+    // Invariant: `values.len() == run_ends.len()`
+    values: Box<dyn Array>,
+    // The logical offset and length of this array. The children are never sliced.
+    offset: usize,
+    length: usize,
+}
+
+impl RunEndEncodedArray {
+    /// Returns a new [`RunEndEncodedArray`].
+    /// # Errors
+    /// This function errors iff:
+    /// * `dtype`'s physical type is not [`crate::datatypes::PhysicalType::RunEndEncoded`].
+    /// * the data types of `run_ends` or `values` differ from the `dtype`'s children.
+    /// * `run_ends` is not a signed integer array of 16, 32 or 64 bits.
+    /// * `run_ends` and `values` have a different length.
+    /// * `run_ends` contains nulls or is not strictly increasing and positive.
+    pub fn try_new(
+        dtype: ArrowDataType,
+        run_ends: Box<dyn Array>,
+        values: Box<dyn Array>,
+    ) -> PolarsResult<Self> {
+        let (run_ends_field, values_field) = Self::try_get_fields(&dtype)?;
+
+        polars_ensure!(
+            run_ends_field.dtype() == run_ends.dtype(),
+            ComputeError: "RunEndEncodedArray's run ends must have data type {:?}, got {:?}",
+            run_ends_field.dtype(), run_ends.dtype()
+        );
+        polars_ensure!(
+            values_field.dtype() == values.dtype(),
+            ComputeError: "RunEndEncodedArray's values must have data type {:?}, got {:?}",
+            values_field.dtype(), values.dtype()
+        );
+        polars_ensure!(
+            run_ends.len() == values.len(),
+            ComputeError: "RunEndEncodedArray's run ends and values must have the same length"
+        );
+        polars_ensure!(
+            run_ends.null_count() == 0,
+            ComputeError: "RunEndEncodedArray's run ends cannot contain nulls"
+        );
+
+        let ends = physical_run_ends(run_ends.as_ref())?;
+        let mut previous = 0;
+        for end in ends.iter().copied() {
+            polars_ensure!(
+                end > previous,
+                ComputeError: "RunEndEncodedArray's run ends must be strictly increasing and positive"
+            );
+            previous = end;
+        }
+
+        Ok(Self {
+            dtype,
+            run_ends,
+            values,
+            offset: 0,
+            length: previous as usize,
+        })
+    }
+
+    /// Returns a new [`RunEndEncodedArray`].
+    /// # Panics
+    /// This function panics iff [`Self::try_new`] errors.
+    pub fn new(dtype: ArrowDataType, run_ends: Box<dyn Array>, values: Box<dyn Array>) -> Self {
+        Self::try_new(dtype, run_ends, values).unwrap()
+    }
+
+    /// Returns a new [`RunEndEncodedArray`] from `run_ends` that are relative to the start of the
+    /// array.
+    /// # Errors
+    /// This function errors iff [`Self::try_new`] errors or if a run end does not fit in the run
+    /// ends type of `dtype`.
+    pub fn try_from_run_ends(
+        dtype: ArrowDataType,
+        run_ends: &[u64],
+        values: Box<dyn Array>,
+    ) -> PolarsResult<Self> {
+        let (run_ends_field, _) = Self::try_get_fields(&dtype)?;
+        let run_ends = run_ends_to_array(run_ends_field.dtype(), run_ends)?;
+        Self::try_new(dtype, run_ends, values)
+    }
+
+    /// Creates a new empty [`RunEndEncodedArray`].
+    pub fn new_empty(dtype: ArrowDataType) -> Self {
+        let (run_ends_field, values_field) = Self::get_fields(&dtype);
+        let run_ends = new_empty_array(run_ends_field.dtype().clone());
+        let values = new_empty_array(values_field.dtype().clone());
+        Self::new(dtype, run_ends, values)
+    }
+
+    /// Creates a new null [`RunEndEncodedArray`], consisting of a single run of nulls.
+    pub fn new_null(dtype: ArrowDataType, length: usize) -> Self {
+        if length == 0 {
+            return Self::new_empty(dtype);
+        }
+
+        let (_, values_field) = Self::get_fields(&dtype);
+        let values = new_null_array(values_field.dtype().clone(), 1);
+        Self::try_from_run_ends(dtype, &[length as u64], values).unwrap()
+    }
+}
+
+impl RunEndEncodedArray {
+    /// Returns a slice of this [`RunEndEncodedArray`].
+    /// # Implementation
+    /// This operation is `O(1)`.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    #[inline]
+    pub fn slice(&mut self, offset: usize, length: usize) {
+        assert!(
+            offset + length <= self.len(),
+            "the offset of the new array cannot exceed the existing length"
+        );
+        unsafe { self.slice_unchecked(offset, length) }
+    }
+
+    /// Returns a slice of this [`RunEndEncodedArray`].
+    /// # Implementation
+    /// This operation is `O(1)`.
+    ///
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    #[inline]
+    pub unsafe fn slice_unchecked(&mut self, offset: usize, length: usize) {
+        self.offset += offset;
+        self.length = length;
+    }
+
+    impl_sliced!();
+    impl_into_array!();
+}
+
+impl RunEndEncodedArray {
+    /// The length of this array.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// The (exclusive) end of every run, relative to the start of the unsliced array.
+    #[inline]
+    pub fn run_ends(&self) -> &Box<dyn Array> {
+        &self.run_ends
+    }
+
+    /// The value of every run.
+    #[inline]
+    pub fn values(&self) -> &Box<dyn Array> {
+        &self.values
+    }
+
+    /// Returns the runs that overlap with this (possibly sliced) array as `(index, length)`
+    /// pairs, where `index` is the index of the run's value in [`Self::values`] and `length` is
+    /// the number of slots of this array covered by the run.
+    pub fn runs(&self) -> impl Iterator<Item = (usize, usize)> + use<> {
+        // The run ends were validated on construction.
+        let ends = physical_run_ends(self.run_ends.as_ref()).unwrap();
+        let start = self.offset as u64;
+        let end = (self.offset + self.length) as u64;
+
+        let first = ends.partition_point(|e| *e <= start);
+        let n_runs = ends.len();
+        let mut previous = start;
+        (first..n_runs).map_while(move |i| {
+            if previous >= end {
+                return None;
+            }
+            let run_end = ends[i].min(end);
+            let length = (run_end - previous) as usize;
+            previous = run_end;
+            Some((i, length))
+        })
+    }
+
+    /// Returns the index in [`Self::values`] of the run that contains slot `i`.
+    /// # Panic
+    /// This function panics iff `i >= self.len()`.
+    pub fn physical_index(&self, i: usize) -> usize {
+        assert!(i < self.len());
+        let ends = physical_run_ends(self.run_ends.as_ref()).unwrap();
+        let position = (self.offset + i) as u64;
+        ends.partition_point(|e| *e <= position)
+    }
+}
+
+impl RunEndEncodedArray {
+    pub(crate) fn try_get_fields(dtype: &ArrowDataType) -> PolarsResult<(&Field, &Field)> {
+        match dtype.to_storage() {
+            ArrowDataType::RunEndEncoded(run_ends, values) => Ok((run_ends, values)),
+            _ => polars_bail!(ComputeError:
+                "RunEndEncodedArray must be created with a DataType whose physical type is RunEndEncoded"
+            ),
+        }
+    }
+
+    /// Returns the run ends and values fields of the `DataType::RunEndEncoded`.
+    /// # Panic
+    /// Panics iff `dtype`'s logical type is not [`ArrowDataType::RunEndEncoded`].
+    pub fn get_fields(dtype: &ArrowDataType) -> (&Field, &Field) {
+        Self::try_get_fields(dtype).unwrap()
+    }
+}
+
+impl Array for RunEndEncodedArray {
+    impl_common_array!();
+
+    fn validity(&self) -> Option<&Bitmap> {
+        None
+    }
+
+    fn with_validity(&self, _: Option<Bitmap>) -> Box<dyn Array> {
+        panic!("cannot set validity of a run-end encoded array")
+    }
+}
+
+impl Splitable for RunEndEncodedArray {
+    fn check_bound(&self, offset: usize) -> bool {
+        offset <= self.len()
+    }
+
+    unsafe fn _split_at_unchecked(&self, offset: usize) -> (Self, Self) {
+        let mut lhs = self.clone();
+        let mut rhs = self.clone();
+        unsafe {
+            lhs.slice_unchecked(0, offset);
+            rhs.slice_unchecked(offset, self.len() - offset);
+        }
+        (lhs, rhs)
+    }
+}
+
+/// Get the run ends of `run_ends` as `u64`.
+fn physical_run_ends(run_ends: &dyn Array) -> PolarsResult<Vec<u64>> {
+    fn collect<T: crate::types::NativeType + TryInto<u64>>(
+        run_ends: &dyn Array,
+    ) -> PolarsResult<Vec<u64>> {
+        let run_ends = run_ends
+            .as_any()
+            .downcast_ref::<PrimitiveArray<T>>()
+            .unwrap();
+        run_ends
+            .values_iter()
+            .map(|end| {
+                (*end).try_into().map_err(
+                    |_| polars_err!(ComputeError: "RunEndEncodedArray's run ends must be positive"),
+                )
+            })
+            .collect()
+    }
+
+    match run_ends.dtype() {
+        ArrowDataType::Int16 => collect::<i16>(run_ends),
+        ArrowDataType::Int32 => collect::<i32>(run_ends),
+        ArrowDataType::Int64 => collect::<i64>(run_ends),
+        dt => polars_bail!(ComputeError:
+            "RunEndEncodedArray's run ends must be Int16, Int32 or Int64, got {dt:?}"
+        ),
+    }
+}
+
+/// Create a run ends array of type `dtype` from `run_ends`.
+fn run_ends_to_array(dtype: &ArrowDataType, run_ends: &[u64]) -> PolarsResult<Box<dyn Array>> {
+    fn convert<T: crate::types::NativeType + TryFrom<u64>>(
+        run_ends: &[u64],
+    ) -> PolarsResult<Box<dyn Array>> {
+        let values = run_ends
+            .iter()
+            .map(|end| {
+                T::try_from(*end).map_err(|_| {
+                    polars_err!(ComputeError: "run end {end} does not fit in the run ends type")
+                })
+            })
+            .collect::<PolarsResult<Vec<T>>>()?;
+        Ok(PrimitiveArray::from_vec(values).boxed())
+    }
+
+    match dtype {
+        ArrowDataType::Int16 => convert::<i16>(run_ends),
+        ArrowDataType::Int32 => convert::<i32>(run_ends),
+        ArrowDataType::Int64 => convert::<i64>(run_ends),
+        dt => polars_bail!(ComputeError:
+            "RunEndEncodedArray's run ends must be Int16, Int32 or Int64, got {dt:?}"
+        ),
+    }
+}
@@ -142,5 +142,10 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
             let offsets = array.offsets().len_proxy() * size_of::<i32>();
             offsets + estimated_bytes_size(array.field().as_ref()) + validity_size(array.validity())
         },
+        RunEndEncoded => {
+            let array = array.as_any().downcast_ref::<RunEndEncodedArray>().unwrap();
+            estimated_bytes_size(array.run_ends().as_ref())
+                + estimated_bytes_size(array.values().as_ref())
+        },
     }
 }
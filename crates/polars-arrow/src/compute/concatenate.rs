@@ -101,6 +101,7 @@ pub fn concatenate_unchecked<A: AsRef<dyn Array>>(arrays: &[A]) -> PolarsResult<
         Struct => Ok(Box::new(concatenate_struct(arrays)?)),
         Union => unimplemented!(),
         Map => unimplemented!(),
+        RunEndEncoded => unimplemented!(),
         Dictionary(_) => unimplemented!(),
     }
 }
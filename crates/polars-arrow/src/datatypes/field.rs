@@ -15,6 +15,8 @@ pub static DTYPE_ENUM_VALUES_NEW: &str = "_PL_ENUM_VALUES2";
 // These have different encodings.
 pub static DTYPE_CATEGORICAL_LEGACY: &str = "_PL_CATEGORICAL";
 pub static DTYPE_CATEGORICAL_NEW: &str = "_PL_CATEGORICAL2";
+// The categories of a persistent Categories, in the order of their ids.
+pub static DTYPE_CATEGORICAL_MAPPING: &str = "_PL_CATEGORICAL_MAPPING";
 
 pub static PARQUET_EMPTY_STRUCT: &str = "_PL_EMPTY_STRUCT";
 
@@ -9,8 +9,9 @@ use std::collections::BTreeMap;
 use std::sync::Arc;
 
 pub use field::{
-    DTYPE_CATEGORICAL_LEGACY, DTYPE_CATEGORICAL_NEW, DTYPE_ENUM_VALUES_LEGACY,
-    DTYPE_ENUM_VALUES_NEW, Field, MAINTAIN_PL_TYPE, PARQUET_EMPTY_STRUCT, PL_KEY,
+    DTYPE_CATEGORICAL_LEGACY, DTYPE_CATEGORICAL_MAPPING, DTYPE_CATEGORICAL_NEW,
+    DTYPE_ENUM_VALUES_LEGACY, DTYPE_ENUM_VALUES_NEW, Field, MAINTAIN_PL_TYPE, PARQUET_EMPTY_STRUCT,
+    PL_KEY,
 };
 pub use physical_type::*;
 use polars_utils::pl_str::PlSmallStr;
@@ -186,6 +187,12 @@ pub enum ArrowDataType {
     /// Third argument represents mode
     #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
     Union(Box<UnionType>),
+    /// A run-end encoded array (`run_ends`, `values`), where every run is a single value of
+    /// `values` repeated up to the (exclusive) end position of that run in `run_ends`.
+    ///
+    /// The run ends must be strictly increasing signed integers of 16, 32 or 64 bits.
+    #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
+    RunEndEncoded(Box<Field>, Box<Field>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -325,6 +332,7 @@ impl ArrowDataType {
             Struct(_) => PhysicalType::Struct,
             Union(_) => PhysicalType::Union,
             Map(_, _) => PhysicalType::Map,
+            RunEndEncoded(_, _) => PhysicalType::RunEndEncoded,
             Dictionary(key, _, _) => PhysicalType::Dictionary(*key),
             Extension(ext) => ext.inner.to_physical_type(),
             Unknown => unimplemented!(),
@@ -368,6 +376,7 @@ impl ArrowDataType {
             Dictionary(keys, _, _) => (*keys).into(),
             Union(_) => unimplemented!(),
             Map(_, _) => unimplemented!(),
+            RunEndEncoded(_, _) => unimplemented!(),
             Extension(ext) => ext.inner.underlying_physical_type(),
         }
     }
@@ -416,6 +425,13 @@ impl ArrowDataType {
             Dictionary(keys, values, is_sorted) => {
                 Dictionary(*keys, Box::new(values.to_storage_recursive()), *is_sorted)
             },
+            RunEndEncoded(run_ends, values) => RunEndEncoded(
+                run_ends.clone(),
+                Box::new(Field {
+                    dtype: values.dtype.to_storage_recursive(),
+                    ..*values.clone()
+                }),
+            ),
             Union(_) => unimplemented!(),
             Map(_, _) => unimplemented!(),
             _ => self.clone(),
@@ -443,6 +459,7 @@ impl ArrowDataType {
                 | D::Union(_)
                 | D::Map(_, _)
                 | D::Dictionary(_, _, _)
+                | D::RunEndEncoded(_, _)
                 | D::Extension(_)
         )
     }
@@ -530,6 +547,7 @@ impl ArrowDataType {
             | D::LargeList(field) => field.dtype().contains_dictionary(),
             D::Struct(fields) => fields.iter().any(|f| f.dtype().contains_dictionary()),
             D::Union(union) => union.fields.iter().any(|f| f.dtype().contains_dictionary()),
+            D::RunEndEncoded(_, values) => values.dtype().contains_dictionary(),
             D::Dictionary(_, _, _) => true,
             D::Extension(ext) => ext.inner.contains_dictionary(),
         }
@@ -46,6 +46,8 @@ pub enum PhysicalType {
     /// A string type that inlines small values
     /// and can intern strings.
     Utf8View,
+    /// A run-end encoded array of runs of repeated values.
+    RunEndEncoded,
 }
 
 impl PhysicalType {
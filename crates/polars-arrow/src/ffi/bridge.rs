@@ -31,6 +31,7 @@ pub fn align_to_c_data_interface(array: Box<dyn Array>) -> Box<dyn Array> {
         Struct => ffi_dyn!(array, StructArray),
         Union => ffi_dyn!(array, UnionArray),
         Map => ffi_dyn!(array, MapArray),
+        RunEndEncoded => ffi_dyn!(array, RunEndEncodedArray),
         Dictionary(key_type) => {
             match_integer_type!(key_type, |$T| {
                 ffi_dyn!(array, DictionaryArray<$T>)
@@ -60,6 +60,10 @@ fn schema_children(dtype: &ArrowDataType, flags: &mut i64) -> Box<[*mut ArrowSch
             .iter()
             .map(|field| Box::into_raw(Box::new(ArrowSchema::new(field))))
             .collect::<Box<[_]>>(),
+        ArrowDataType::RunEndEncoded(run_ends, values) => Box::new([
+            Box::into_raw(Box::new(ArrowSchema::new(run_ends.as_ref()))),
+            Box::into_raw(Box::new(ArrowSchema::new(values.as_ref()))),
+        ]),
         ArrowDataType::Extension(ext) => schema_children(&ext.inner, flags),
         _ => Box::new([]),
     }
@@ -316,6 +320,11 @@ unsafe fn to_dtype(schema: &ArrowSchema) -> PolarsResult<ArrowDataType> {
                 .collect::<PolarsResult<Vec<_>>>()?;
             ArrowDataType::Struct(children)
         },
+        "+r" => {
+            let run_ends = to_field(schema.child(0))?;
+            let values = to_field(schema.child(1))?;
+            ArrowDataType::RunEndEncoded(Box::new(run_ends), Box::new(values))
+        },
         other => {
             match other.splitn(2, ':').collect::<Vec<_>>()[..] {
                 // Timestamps with no timezone
@@ -531,6 +540,7 @@ fn to_format(dtype: &ArrowDataType) -> String {
             r
         },
         ArrowDataType::Map(_, _) => "+m".to_string(),
+        ArrowDataType::RunEndEncoded(_, _) => "+r".to_string(),
         ArrowDataType::Dictionary(index, _, _) => to_format(&(*index).into()),
         ArrowDataType::Extension(ext) => to_format(&ext.inner),
         ArrowDataType::Unknown => unimplemented!(),
@@ -545,6 +555,8 @@ pub(super) fn get_child(dtype: &ArrowDataType, index: usize) -> PolarsResult<Arr
         (0, ArrowDataType::Map(field, _)) => Ok(field.dtype().clone()),
         (index, ArrowDataType::Struct(fields)) => Ok(fields[index].dtype().clone()),
         (index, ArrowDataType::Union(u)) => Ok(u.fields[index].dtype().clone()),
+        (0, ArrowDataType::RunEndEncoded(run_ends, _)) => Ok(run_ends.dtype().clone()),
+        (1, ArrowDataType::RunEndEncoded(_, values)) => Ok(values.dtype().clone()),
         (index, ArrowDataType::Extension(ext)) => get_child(&ext.inner, index),
         (child, dtype) => polars_bail!(ComputeError:
             "Requested child {child} to type {dtype:?} that has no such child",
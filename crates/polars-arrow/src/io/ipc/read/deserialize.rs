@@ -257,6 +257,7 @@ pub fn read<R: Read + Seek>(
             checked,
         )
         .map(|x| x.boxed()),
+        RunEndEncoded => unimplemented!(),
         Utf8View => read_binview::<str, _>(
             field_nodes,
             variadic_buffer_counts,
@@ -307,6 +308,7 @@ pub fn skip(
         Dictionary(_) => skip_dictionary(field_nodes, buffers),
         Union => skip_union(field_nodes, dtype, buffers, variadic_buffer_counts),
         Map => skip_map(field_nodes, dtype, buffers, variadic_buffer_counts),
+        RunEndEncoded => unimplemented!(),
         BinaryView | Utf8View => skip_binview(field_nodes, buffers, variadic_buffer_counts),
     }
 }
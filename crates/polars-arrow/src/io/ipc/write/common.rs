@@ -134,6 +134,9 @@ pub fn dictionaries_to_encode(
                     )
                 })
         },
+        RunEndEncoded => {
+            polars_bail!(nyi = "writing run-end encoded arrays to IPC")
+        },
         Map => {
             let values = array.as_any().downcast_ref::<MapArray>().unwrap().field();
             let field = field.fields.first().ok_or_else(|| polars_err!(ComputeError: "Invalid IPC field structure: expected nested field but fields vector is empty"))?;
@@ -299,6 +299,7 @@ fn serialize_type(dtype: &ArrowDataType) -> arrow_format::ipc::Type {
         Extension(ext) => serialize_type(&ext.inner),
         Utf8View => ipc::Type::Utf8View(Box::new(ipc::Utf8View {})),
         BinaryView => ipc::Type::BinaryView(Box::new(ipc::BinaryView {})),
+        RunEndEncoded(_, _) => unimplemented!(),
         Unknown => unimplemented!(),
     }
 }
@@ -358,6 +359,7 @@ fn serialize_children(
             .collect(),
         Dictionary(_, inner, _) => serialize_children(inner, ipc_field),
         Extension(ext) => serialize_children(&ext.inner, ipc_field),
+        RunEndEncoded(_, _) => unimplemented!(),
         Unknown => unimplemented!(),
     }
 }
@@ -162,6 +162,7 @@ pub fn write(
                 compression,
             );
         },
+        RunEndEncoded => unimplemented!(),
         Map => {
             write_map(
                 array.as_any().downcast_ref().unwrap(),
@@ -190,6 +190,10 @@ pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
             };
             Box::new(MapScalar::new(array.dtype().clone(), value))
         },
+        RunEndEncoded => {
+            let array = array.as_any().downcast_ref::<RunEndEncodedArray>().unwrap();
+            new_scalar(array.values().as_ref(), array.physical_index(index))
+        },
         Dictionary(key_type) => match_integer_type!(key_type, |$T| {
             let array = array
                 .as_any()
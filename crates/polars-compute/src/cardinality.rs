@@ -119,6 +119,7 @@ pub fn estimate_cardinality(array: &dyn Array) -> usize {
         PT::LargeList => unimplemented!(),
         PT::Struct => unimplemented!(),
         PT::Union => unimplemented!(),
+        PT::RunEndEncoded => unimplemented!(),
         PT::Map => unimplemented!(),
         PT::Dictionary(_) => unimplemented!(),
     }
@@ -1,6 +1,7 @@
 use arrow::array::{
     Array, BinaryArray, BinaryViewArray, BooleanArray, DictionaryArray, FixedSizeBinaryArray,
-    ListArray, NullArray, PrimitiveArray, StructArray, Utf8Array, Utf8ViewArray,
+    ListArray, NullArray, PrimitiveArray, RunEndEncodedArray, StructArray, Utf8Array,
+    Utf8ViewArray,
 };
 use arrow::bitmap::Bitmap;
 use arrow::types::{days_ms, i256, months_days_ns};
@@ -65,6 +66,7 @@ macro_rules! compare {
             PH::LargeList => call_binary!(ListArray<i64>, lhs, rhs, $op),
             PH::Struct => call_binary!(StructArray, lhs, rhs, $op),
             PH::Union => todo!("Comparison of UnionArrays is not yet supported"),
+            PH::RunEndEncoded => call_binary!(RunEndEncodedArray, lhs, rhs, $op),
             PH::Map => todo!("Comparison of MapArrays is not yet supported"),
             PH::Dictionary(I::Int8) => call_binary!(DictionaryArray<i8>, lhs, rhs, $op),
             PH::Dictionary(I::Int16) => call_binary!(DictionaryArray<i16>, lhs, rhs, $op),
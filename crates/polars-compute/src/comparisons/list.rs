@@ -1,6 +1,7 @@
 use arrow::array::{
     Array, BinaryArray, BinaryViewArray, BooleanArray, DictionaryArray, FixedSizeBinaryArray,
-    ListArray, NullArray, PrimitiveArray, StructArray, Utf8Array, Utf8ViewArray,
+    ListArray, NullArray, PrimitiveArray, RunEndEncodedArray, StructArray, Utf8Array,
+    Utf8ViewArray,
 };
 use arrow::bitmap::Bitmap;
 use arrow::legacy::utils::CustomIterTools;
@@ -96,6 +97,7 @@ macro_rules! compare {
             PH::LargeList => call_binary!(ListArray<i64>),
             PH::Struct => call_binary!(StructArray),
             PH::Union => todo!("Comparison of UnionArrays is not yet supported"),
+            PH::RunEndEncoded => call_binary!(RunEndEncodedArray),
             PH::Map => todo!("Comparison of MapArrays is not yet supported"),
             PH::Dictionary(I::Int8) => call_binary!(DictionaryArray<i8>),
             PH::Dictionary(I::Int16) => call_binary!(DictionaryArray<i16>),
@@ -196,6 +198,7 @@ macro_rules! compare_broadcast {
             PH::LargeList => call_binary!(ListArray<i64>),
             PH::Struct => call_binary!(StructArray),
             PH::Union => todo!("Comparison of UnionArrays is not yet supported"),
+            PH::RunEndEncoded => call_binary!(RunEndEncodedArray),
             PH::Map => todo!("Comparison of MapArrays is not yet supported"),
             PH::Dictionary(I::Int8) => call_binary!(DictionaryArray<i8>),
             PH::Dictionary(I::Int16) => call_binary!(DictionaryArray<i16>),
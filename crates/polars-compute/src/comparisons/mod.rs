@@ -80,6 +80,7 @@ mod dictionary;
 mod dyn_array;
 mod list;
 mod null;
+mod run_end_encoded;
 mod scalar;
 mod struct_;
 mod utf8;
@@ -0,0 +1,78 @@
+use arrow::array::{Array, RunEndEncodedArray};
+use arrow::bitmap::{Bitmap, BitmapBuilder};
+
+use super::TotalEqKernel;
+use crate::comparisons::dyn_array::{array_tot_eq_missing_kernel, array_tot_ne_missing_kernel};
+
+/// Compare two arrays of the same length run by run.
+///
+/// Every stretch of rows in which neither side starts a new run is compared once.
+fn compare_runs(
+    lhs: &RunEndEncodedArray,
+    rhs: &RunEndEncodedArray,
+    compare: impl Fn(&dyn Array, &dyn Array) -> Bitmap,
+) -> Bitmap {
+    assert_eq!(lhs.len(), rhs.len());
+
+    let mut bitmap = BitmapBuilder::with_capacity(lhs.len());
+    let mut lhs_runs = lhs.runs();
+    let mut rhs_runs = rhs.runs();
+    let mut lhs_run = lhs_runs.next();
+    let mut rhs_run = rhs_runs.next();
+    while let (Some((lhs_idx, lhs_length)), Some((rhs_idx, rhs_length))) = (lhs_run, rhs_run) {
+        let length = lhs_length.min(rhs_length);
+        let lhs_value = lhs.values().sliced(lhs_idx, 1);
+        let rhs_value = rhs.values().sliced(rhs_idx, 1);
+        let result = compare(lhs_value.as_ref(), rhs_value.as_ref());
+        bitmap.extend_constant(length, result.get_bit(0));
+
+        lhs_run = if lhs_length > length {
+            Some((lhs_idx, lhs_length - length))
+        } else {
+            lhs_runs.next()
+        };
+        rhs_run = if rhs_length > length {
+            Some((rhs_idx, rhs_length - length))
+        } else {
+            rhs_runs.next()
+        };
+    }
+    bitmap.freeze()
+}
+
+/// Compare the value of every run with `other` and repeat the result over the run.
+fn compare_runs_broadcast(
+    array: &RunEndEncodedArray,
+    other: &dyn Array,
+    compare: impl Fn(&dyn Array, &dyn Array) -> Bitmap,
+) -> Bitmap {
+    let mut bitmap = BitmapBuilder::with_capacity(array.len());
+    for (idx, length) in array.runs() {
+        let value = array.values().sliced(idx, 1);
+        let result = compare(value.as_ref(), other);
+        bitmap.extend_constant(length, result.get_bit(0));
+    }
+    bitmap.freeze()
+}
+
+// A run-end encoded array has no validity, missing values are in its values. So these kernels
+// compare missing values like the missing kernels do.
+impl TotalEqKernel for RunEndEncodedArray {
+    type Scalar = Box<dyn Array>;
+
+    fn tot_eq_kernel(&self, other: &Self) -> Bitmap {
+        compare_runs(self, other, array_tot_eq_missing_kernel)
+    }
+
+    fn tot_ne_kernel(&self, other: &Self) -> Bitmap {
+        compare_runs(self, other, array_tot_ne_missing_kernel)
+    }
+
+    fn tot_eq_kernel_broadcast(&self, other: &Self::Scalar) -> Bitmap {
+        compare_runs_broadcast(self, other.as_ref(), array_tot_eq_missing_kernel)
+    }
+
+    fn tot_ne_kernel_broadcast(&self, other: &Self::Scalar) -> Bitmap {
+        compare_runs_broadcast(self, other.as_ref(), array_tot_ne_missing_kernel)
+    }
+}
@@ -190,7 +190,7 @@ fn carrying_add_64(a: u64, b: u64, carry: bool) -> (u64, bool) {
 }
 
 #[inline]
-fn widening_mul_128(a: u128, b: u128) -> (u128, u128) {
+pub(crate) fn widening_mul_128(a: u128, b: u128) -> (u128, u128) {
     let a_lo = a as u64;
     let a_hi = (a >> 64) as u64;
     let b_lo = b as u64;
@@ -806,11 +806,14 @@ pub fn str_to_dec128(bytes: &[u8], p: usize, s: usize, decimal_comma: bool) -> O
     if negative { Some(-ret) } else { Some(ret) }
 }
 
-const DEC128_MAX_LEN: usize = 39 + 2;
+#[cfg(not(feature = "dtype-decimal256"))]
+const DEC_MAX_LEN: usize = DEC128_MAX_PREC + 3;
+#[cfg(feature = "dtype-decimal256")]
+const DEC_MAX_LEN: usize = crate::decimal256::DEC256_MAX_PREC + 3;
 
 #[derive(Clone, Copy)]
 pub struct DecimalFmtBuffer {
-    data: [u8; DEC128_MAX_LEN],
+    data: [u8; DEC_MAX_LEN],
     len: usize,
 }
 
@@ -824,7 +827,7 @@ impl DecimalFmtBuffer {
     #[inline]
     pub const fn new() -> Self {
         Self {
-            data: [0; DEC128_MAX_LEN],
+            data: [0; DEC_MAX_LEN],
             len: 0,
         }
     }
@@ -836,12 +839,23 @@ impl DecimalFmtBuffer {
         trim_zeros: bool,
         decimal_comma: bool,
     ) -> &str {
-        let decimal_sep = if decimal_comma { b',' } else { b'.' };
-
         let mut itoa_buf = itoa::Buffer::new();
         let xs = itoa_buf.format(x.unsigned_abs()).as_bytes();
+        self.format_digits(xs, x < 0, scale, trim_zeros, decimal_comma)
+    }
+
+    /// Formats the decimal digits `xs` of the magnitude of a decimal.
+    pub(crate) fn format_digits(
+        &mut self,
+        xs: &[u8],
+        negative: bool,
+        scale: usize,
+        trim_zeros: bool,
+        decimal_comma: bool,
+    ) -> &str {
+        let decimal_sep = if decimal_comma { b',' } else { b'.' };
 
-        if x >= 0 {
+        if !negative {
             self.len = 0;
         } else {
             self.data[0] = b'-';
@@ -0,0 +1,576 @@
+/*
+    Decimal256 implementation.
+
+    A Decimal256 is backed by a signed 256-bit integer, allowing for up to 76
+    significant digits. It is only used for precisions that don't fit in a
+    Decimal128, that is 39 up to and including 76.
+
+    Like the Decimal128 implementation, all rounding is done to nearest even,
+    and it's assumed that p and s fit in the maximum precision, giving panics
+    otherwise.
+*/
+
+use std::cmp::Ordering;
+use std::sync::LazyLock;
+
+use arrow::types::i256;
+use ethnum::{I256, U256};
+use polars_error::{PolarsResult, polars_ensure};
+
+use crate::decimal::{DEC128_MAX_PREC, DecimalFmtBuffer, widening_mul_128};
+
+/// The maximum precision of a Decimal256.
+pub const DEC256_MAX_PREC: usize = 76;
+
+/// The minimum precision of a Decimal256, smaller precisions use a Decimal128.
+pub const DEC256_MIN_PREC: usize = DEC128_MAX_PREC + 1;
+
+pub fn dec256_verify_prec_scale(p: usize, s: usize) -> PolarsResult<()> {
+    polars_ensure!((DEC256_MIN_PREC..=DEC256_MAX_PREC).contains(&p), InvalidOperation: "Decimal256 precision must be between 39 and 76");
+    polars_ensure!(s <= p, InvalidOperation: "scale must be less than or equal to precision");
+    Ok(())
+}
+
+static POW10_U256: LazyLock<[U256; DEC256_MAX_PREC + 1]> = LazyLock::new(|| {
+    let mut out = [U256::ONE; DEC256_MAX_PREC + 1];
+    for i in 1..out.len() {
+        out[i] = out[i - 1] * U256::new(10);
+    }
+    out
+});
+
+static POW10_F64: LazyLock<[f64; DEC256_MAX_PREC + 1]> = LazyLock::new(|| {
+    let mut out = [1.0; DEC256_MAX_PREC + 1];
+    for (i, x) in out.iter_mut().enumerate() {
+        *x = format!("1e{i}").parse().unwrap();
+    }
+    out
+});
+
+const TWO_POW_128_F64: f64 = 340282366920938463463374607431768211456.0;
+
+#[inline]
+fn pow10_u256(e: usize) -> U256 {
+    POW10_U256[e]
+}
+
+#[inline]
+fn unsigned_abs(x: I256) -> U256 {
+    let abs = if x < I256::ZERO { -x } else { x };
+    let (hi, lo) = abs.into_words();
+    U256::from_words(hi as u128, lo as u128)
+}
+
+/// Converts a magnitude to a signed integer, assuming the magnitude is < 2^255.
+#[inline]
+fn with_sign(mag: U256, negative: bool) -> I256 {
+    let (hi, lo) = mag.into_words();
+    let x = I256::from_words(hi as i128, lo as i128);
+    if negative { -x } else { x }
+}
+
+#[inline]
+fn fits_u256(mag: U256, p: usize) -> bool {
+    mag < pow10_u256(p)
+}
+
+/// Returns the full 512-bit product of a and b as (lo, hi).
+fn widening_mul_256(a: U256, b: U256) -> (U256, U256) {
+    let (a_hi, a_lo) = a.into_words();
+    let (b_hi, b_lo) = b.into_words();
+    let (x0, x1) = widening_mul_128(a_lo, b_lo);
+    let (y1, y2) = widening_mul_128(a_hi, b_lo);
+    let (z1, z2) = widening_mul_128(a_lo, b_hi);
+    let (w2, w3) = widening_mul_128(a_hi, b_hi);
+
+    let (t1, c1) = x1.overflowing_add(y1);
+    let (t1, c2) = t1.overflowing_add(z1);
+    let (t2, c3) = y2.overflowing_add(z2);
+    let (t2, c4) = t2.overflowing_add(w2);
+    let (t2, c5) = t2.overflowing_add(c1 as u128 + c2 as u128);
+    // Can't overflow, the product of two 256-bit numbers fits in 512 bits.
+    let t3 = w3 + c3 as u128 + c4 as u128 + c5 as u128;
+
+    (U256::from_words(t1, x0), U256::from_words(t3, t2))
+}
+
+/// Divides the 512-bit number (lo, hi) by d, returning the quotient and remainder.
+/// Assumes d < 2^255. Returns None if the quotient doesn't fit in 256 bits.
+fn divrem_512_256(lo: U256, hi: U256, d: U256) -> Option<(U256, U256)> {
+    if hi >= d {
+        return None;
+    }
+    if hi == U256::ZERO {
+        return Some((lo / d, lo % d));
+    }
+
+    // Restoring long division, shifting in one bit of lo at a time. Because
+    // rem < d < 2^255 shifting the remainder can't overflow.
+    let mut rem = hi;
+    let mut quot = U256::ZERO;
+    for i in (0..256u32).rev() {
+        rem = (rem << 1u32) | ((lo >> i) & U256::ONE);
+        quot <<= 1u32;
+        if rem >= d {
+            rem -= d;
+            quot |= U256::ONE;
+        }
+    }
+    Some((quot, rem))
+}
+
+/// Rounds the quotient q with remainder r of a division by d to nearest even.
+/// Assumes d < 2^255.
+#[inline]
+fn round_quotient(q: U256, r: U256, d: U256) -> Option<U256> {
+    let twice_r = r << 1u32;
+    if twice_r > d || (twice_r == d && (q & U256::ONE) == U256::ONE) {
+        q.checked_add(U256::ONE)
+    } else {
+        Some(q)
+    }
+}
+
+/// Returns round(x / 10^e), with e <= DEC256_MAX_PREC, rounding to nearest even.
+#[inline]
+fn div_256_pow10(x: I256, e: usize) -> I256 {
+    if e == 0 {
+        return x;
+    }
+
+    let n = unsigned_abs(x);
+    let d = pow10_u256(e);
+    // Can't overflow, rounding up n / d with d >= 10 stays below 2^256.
+    let q = round_quotient(n / d, n % d, d).unwrap();
+    with_sign(q, x < I256::ZERO)
+}
+
+/// Returns x * 10^e, with e <= DEC256_MAX_PREC.
+///
+/// Returns None if the multiplication overflows.
+#[inline]
+fn mul_256_pow10(x: I256, e: usize) -> Option<I256> {
+    let (hi, lo) = pow10_u256(e).into_words();
+    x.checked_mul(I256::from_words(hi as i128, lo as i128))
+}
+
+/// Returns whether the given Decimal256 fits in the given precision.
+#[inline]
+pub fn dec256_fits(x: i256, p: usize) -> bool {
+    fits_u256(unsigned_abs(x.0), p)
+}
+
+/// Converts a Decimal256 with the given scale to an i128, rounding to nearest
+/// even. Returns None if the value doesn't fit.
+#[inline]
+pub fn dec256_to_i128(x: i256, s: usize) -> Option<i128> {
+    i128::try_from(div_256_pow10(x.0, s)).ok()
+}
+
+/// Converts an i128 to a Decimal256 with the given precision and scale,
+/// returning None if the value doesn't fit.
+#[inline]
+pub fn i128_to_dec256(x: i128, p: usize, s: usize) -> Option<i256> {
+    let r = i256(mul_256_pow10(I256::new(x), s)?);
+    dec256_fits(r, p).then_some(r)
+}
+
+/// Widens a Decimal128 to a Decimal256 with the same scale.
+#[inline]
+pub fn dec128_widen(x: i128) -> i256 {
+    i256(I256::new(x))
+}
+
+/// Converts a Decimal128 to a Decimal256 with a new precision and scale,
+/// returning None if the value doesn't fit.
+#[inline]
+pub fn dec128_to_dec256(x: i128, old_s: usize, new_p: usize, new_s: usize) -> Option<i256> {
+    dec256_rescale(dec128_widen(x), old_s, new_p, new_s)
+}
+
+/// Converts a Decimal256 to a Decimal128 with a new precision and scale,
+/// returning None if the value doesn't fit.
+#[inline]
+pub fn dec256_to_dec128(x: i256, old_s: usize, new_p: usize, new_s: usize) -> Option<i128> {
+    let r = if new_s < old_s {
+        div_256_pow10(x.0, old_s - new_s)
+    } else {
+        mul_256_pow10(x.0, new_s - old_s)?
+    };
+    let r = i128::try_from(r).ok()?;
+    crate::decimal::dec128_fits(r, new_p).then_some(r)
+}
+
+/// Converts a Decimal256 with the given scale to a f64.
+#[inline]
+pub fn dec256_to_f64(x: i256, s: usize) -> f64 {
+    // TODO: correctly rounded result. This rounds multiple times.
+    let (hi, lo) = unsigned_abs(x.0).into_words();
+    let mag = (hi as f64 * TWO_POW_128_F64 + lo as f64) / POW10_F64[s];
+    if x.0 < I256::ZERO { -mag } else { mag }
+}
+
+/// Converts a f64 to a Decimal256 with the given precision and scale, returning
+/// None if the value doesn't fit.
+#[inline]
+pub fn f64_to_dec256(x: f64, p: usize, s: usize) -> Option<i256> {
+    // TODO: correctly rounded result. This rounds multiple times.
+    let y = (x * POW10_F64[s]).round_ties_even();
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    if !(y.abs() < POW10_F64[p]) {
+        // Comparison will fail for NaN, making us return None.
+        return None;
+    }
+
+    // |y| < 10^76 < 2^253, so the high word fits and the low word is exact.
+    let m = y.abs();
+    let hi = (m / TWO_POW_128_F64).floor();
+    let lo = m - hi * TWO_POW_128_F64;
+    let r = i256(with_sign(U256::from_words(hi as u128, lo as u128), y < 0.0));
+    dec256_fits(r, p).then_some(r)
+}
+
+/// Converts between two Decimal256s, with a new precision and scale, returning
+/// None if the value doesn't fit.
+#[inline]
+pub fn dec256_rescale(x: i256, old_s: usize, new_p: usize, new_s: usize) -> Option<i256> {
+    let r = if new_s < old_s {
+        div_256_pow10(x.0, old_s - new_s)
+    } else if new_s > old_s {
+        mul_256_pow10(x.0, new_s - old_s)?
+    } else {
+        x.0
+    };
+
+    let r = i256(r);
+    dec256_fits(r, new_p).then_some(r)
+}
+
+/// Adds two Decimal256s, assuming they have the same scale.
+#[inline]
+pub fn dec256_add(l: i256, r: i256, p: usize) -> Option<i256> {
+    l.0.checked_add(r.0)
+        .map(i256)
+        .filter(|x| dec256_fits(*x, p))
+}
+
+/// Subs two Decimal256s, assuming they have the same scale.
+#[inline]
+pub fn dec256_sub(l: i256, r: i256, p: usize) -> Option<i256> {
+    l.0.checked_sub(r.0)
+        .map(i256)
+        .filter(|x| dec256_fits(*x, p))
+}
+
+/// Multiplies two Decimal256s, assuming they have the same scale s.
+#[inline]
+pub fn dec256_mul(l: i256, r: i256, p: usize, s: usize) -> Option<i256> {
+    // Computes round(l * r / 10^s), rounding to nearest even.
+    let negative = (l.0 < I256::ZERO) ^ (r.0 < I256::ZERO);
+    let (lo, hi) = widening_mul_256(unsigned_abs(l.0), unsigned_abs(r.0));
+    let d = pow10_u256(s);
+    let (q, rem) = divrem_512_256(lo, hi, d)?;
+    let retu = round_quotient(q, rem, d)?;
+    if !fits_u256(retu, p) {
+        return None;
+    }
+    Some(i256(with_sign(retu, negative)))
+}
+
+/// Divides two Decimal256s, assuming they have the same scale s.
+#[inline]
+pub fn dec256_div(l: i256, r: i256, p: usize, s: usize) -> Option<i256> {
+    if r.0 == I256::ZERO {
+        return None;
+    }
+
+    // Computes round((l / r) * 10^s), rounding to nearest even.
+    let negative = (l.0 < I256::ZERO) ^ (r.0 < I256::ZERO);
+    let ru = unsigned_abs(r.0);
+    let (lo, hi) = widening_mul_256(unsigned_abs(l.0), pow10_u256(s));
+    let (q, rem) = divrem_512_256(lo, hi, ru)?;
+    let retu = round_quotient(q, rem, ru)?;
+    if !fits_u256(retu, p) {
+        return None;
+    }
+    Some(i256(with_sign(retu, negative)))
+}
+
+/// Checks if two Decimal256s are equal in value.
+#[inline]
+pub fn dec256_eq(lv: i256, ls: usize, rv: i256, rs: usize) -> bool {
+    dec256_cmp(lv, ls, rv, rs) == Ordering::Equal
+}
+
+/// Checks how two Decimal256s compare.
+#[inline]
+pub fn dec256_cmp(lv: i256, ls: usize, rv: i256, rs: usize) -> Ordering {
+    let (mut lv, mut rv) = (lv.0, rv.0);
+    // Rescale to largest scale. If this overflows we know the magnitude of the
+    // (attempted) rescaled number is larger and we can resolve the answer just
+    // using its sign.
+    if ls < rs {
+        let Some(scaled_lv) = mul_256_pow10(lv, rs - ls) else {
+            return if lv < I256::ZERO {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        };
+        lv = scaled_lv;
+    } else if ls > rs {
+        let Some(scaled_rv) = mul_256_pow10(rv, ls - rs) else {
+            return if I256::ZERO < rv {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        };
+        rv = scaled_rv;
+    }
+
+    lv.cmp(&rv)
+}
+
+/// Deserialize bytes to a single i256 representing a decimal, at a specified
+/// precision and scale. Accepts the same inputs as
+/// [`str_to_dec128`](crate::decimal::str_to_dec128).
+pub fn str_to_dec256(bytes: &[u8], p: usize, s: usize, decimal_comma: bool) -> Option<i256> {
+    assert!(dec256_verify_prec_scale(p, s).is_ok());
+    let (negative, bytes) = match bytes.split_first() {
+        Some((b'-', rest)) => (true, rest),
+        Some((b'+', rest)) => (false, rest),
+        _ => (false, bytes),
+    };
+
+    let exp_pos = bytes
+        .iter()
+        .position(|b| *b == b'e' || *b == b'E')
+        .unwrap_or(bytes.len());
+    let (num_bytes, exp_bytes) = bytes.split_at(exp_pos);
+    let decimal_sep = if decimal_comma { b',' } else { b'.' };
+    let sep_pos = num_bytes
+        .iter()
+        .position(|b| *b == decimal_sep)
+        .unwrap_or(num_bytes.len());
+    let (int_bytes, mut frac_bytes) = num_bytes.split_at(sep_pos);
+    if !frac_bytes.is_empty() {
+        frac_bytes = &frac_bytes[1..];
+    }
+
+    if int_bytes.is_empty() && frac_bytes.is_empty() {
+        // No digits at all.
+        return None;
+    }
+    if !int_bytes
+        .iter()
+        .chain(frac_bytes)
+        .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let exp: i64 = if exp_bytes.is_empty() {
+        0
+    } else {
+        std::str::from_utf8(&exp_bytes[1..]).ok()?.parse().ok()?
+    };
+
+    // The parsed digits need to be multiplied by 10^shift to get to scale s.
+    let n_digits = int_bytes.len() + frac_bytes.len();
+    let shift = exp.checked_add(s as i64)? - frac_bytes.len() as i64;
+    let n_keep = if shift < 0 {
+        n_digits.saturating_sub(shift.unsigned_abs() as usize)
+    } else {
+        n_digits
+    };
+
+    let mut digits = int_bytes.iter().chain(frac_bytes);
+    let mut mag = U256::ZERO;
+    for d in digits.by_ref().take(n_keep) {
+        mag = mag * U256::new(10) + U256::new((d - b'0') as u128);
+        if !fits_u256(mag, p) {
+            return None;
+        }
+    }
+
+    if shift < 0 {
+        // Round-to-even on the dropped digits.
+        let (next_digit, all_zero_after) = if shift.unsigned_abs() as usize > n_digits {
+            (b'0', true)
+        } else {
+            (*digits.next().unwrap(), digits.all(|b| *b == b'0'))
+        };
+        let odd = (mag & U256::ONE) == U256::ONE;
+        if next_digit > b'5' || next_digit == b'5' && (!all_zero_after || odd) {
+            mag += U256::ONE;
+        }
+    } else if mag != U256::ZERO {
+        if shift as usize > DEC256_MAX_PREC {
+            return None;
+        }
+        mag = mag.checked_mul(pow10_u256(shift as usize))?;
+    }
+
+    if !fits_u256(mag, p) {
+        return None;
+    }
+    Some(i256(with_sign(mag, negative)))
+}
+
+impl DecimalFmtBuffer {
+    pub fn format_dec256(
+        &mut self,
+        x: i256,
+        scale: usize,
+        trim_zeros: bool,
+        decimal_comma: bool,
+    ) -> &str {
+        // Write the digits of the magnitude back to front, 19 digits at a time.
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        let mut digits = [b'0'; DEC256_MAX_PREC + 19];
+        let mut start = digits.len();
+        let mut mag = unsigned_abs(x.0);
+        let mut itoa_buf = itoa::Buffer::new();
+        loop {
+            let chunk = (mag % U256::new(CHUNK as u128)).into_words().1 as u64;
+            mag /= U256::new(CHUNK as u128);
+            let chunk_digits = itoa_buf.format(chunk).as_bytes();
+            let end = start;
+            start -= chunk_digits.len();
+            digits[start..end].copy_from_slice(chunk_digits);
+            if mag == U256::ZERO {
+                break;
+            }
+            // Zero-pad the chunk to its full width.
+            start = end - 19;
+        }
+
+        self.format_digits(
+            &digits[start..],
+            x.0 < I256::ZERO,
+            scale,
+            trim_zeros,
+            decimal_comma,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bigdecimal::{BigDecimal, RoundingMode};
+    use num_bigint::BigInt;
+
+    use super::*;
+
+    fn dec256_to_bigdecimal(x: i256, s: usize) -> BigDecimal {
+        BigDecimal::from_bigint(x.0.to_string().parse::<BigInt>().unwrap(), s as i64)
+    }
+
+    fn bigdecimal_to_dec256(x: &BigDecimal, p: usize, s: usize) -> Option<i256> {
+        let n = x
+            .with_scale_round(s as i64, RoundingMode::HalfEven)
+            .into_bigint_and_scale()
+            .0;
+        let r = i256(n.to_string().parse::<I256>().ok()?);
+        dec256_fits(r, p).then_some(r)
+    }
+
+    fn parse(s: &str, p: usize, scale: usize) -> i256 {
+        str_to_dec256(s.as_bytes(), p, scale, false).unwrap()
+    }
+
+    fn fmt(x: i256, s: usize) -> String {
+        DecimalFmtBuffer::new()
+            .format_dec256(x, s, false, false)
+            .to_string()
+    }
+
+    #[test]
+    fn test_str_dec256_roundtrip() {
+        for v in [
+            "0.0000000000",
+            "1.5000000000",
+            "-12345678901234567890123456789012345678901234567890.1234567890",
+            "99999999999999999999999999999999999999999999999999.9999999999",
+            "0.0000000001",
+            "-0.0000000001",
+        ] {
+            assert_eq!(fmt(parse(v, 60, 10), 10), v);
+        }
+
+        assert_eq!(str_to_dec256(b"1e50", 50, 0, false), None);
+        assert_eq!(fmt(parse("1e49", 50, 0), 0), format!("1{}", "0".repeat(49)));
+        assert_eq!(fmt(parse("2.25", 40, 1), 1), "2.2");
+        assert_eq!(fmt(parse("2.35", 40, 1), 1), "2.4");
+        assert_eq!(fmt(parse("-.5", 40, 0), 0), "0");
+        assert_eq!(str_to_dec256(b"1,5", 40, 1, false), None);
+        assert_eq!(
+            str_to_dec256(b"1,5", 40, 1, true),
+            Some(parse("1.5", 40, 1))
+        );
+        assert_eq!(str_to_dec256(b"12.3ABC", 40, 1, false), None);
+        assert_eq!(str_to_dec256(b"-", 40, 1, false), None);
+    }
+
+    #[test]
+    fn test_dec256_arithmetic() {
+        let values = [
+            "0",
+            "1",
+            "-1",
+            "0.5",
+            "3.1415926535",
+            "-2.7182818284",
+            "123456789012345678901234567890.0123456789",
+            "-98765432109876543210987654321098765.4321098765",
+        ];
+        let (p, s) = (76, 10);
+        for l in values {
+            for r in values {
+                let (lb, rb) = (
+                    l.parse::<BigDecimal>().unwrap(),
+                    r.parse::<BigDecimal>().unwrap(),
+                );
+                let (ld, rd) = (parse(l, p, s), parse(r, p, s));
+
+                assert_eq!(
+                    dec256_add(ld, rd, p),
+                    bigdecimal_to_dec256(&(&lb + &rb), p, s)
+                );
+                assert_eq!(
+                    dec256_sub(ld, rd, p),
+                    bigdecimal_to_dec256(&(&lb - &rb), p, s)
+                );
+                assert_eq!(
+                    dec256_mul(ld, rd, p, s),
+                    bigdecimal_to_dec256(&(&lb * &rb), p, s)
+                );
+                if rb != BigDecimal::from(0) {
+                    let expected = bigdecimal_to_dec256(&(&lb / &rb), p, s);
+                    assert_eq!(dec256_div(ld, rd, p, s), expected);
+                }
+                assert_eq!(dec256_cmp(ld, s, rd, s), lb.cmp(&rb));
+                assert_eq!(dec256_to_bigdecimal(ld, s), lb.with_scale(s as i64));
+            }
+        }
+    }
+
+    #[test]
+    fn test_dec256_conversions() {
+        let x = parse("-1234.5", 40, 1);
+        assert_eq!(dec256_to_i128(x, 1), Some(-1234));
+        assert_eq!(dec256_to_dec128(x, 1, 10, 3), Some(-1234500));
+        assert_eq!(dec256_to_dec128(x, 1, 5, 3), None);
+        assert_eq!(dec128_to_dec256(-12345, 1, 40, 1), Some(x));
+        assert_eq!(i128_to_dec256(-70, 40, 39), None);
+        assert_eq!(dec256_to_f64(x, 1), -1234.5);
+        assert_eq!(f64_to_dec256(-1234.5, 40, 1), Some(x));
+        assert_eq!(f64_to_dec256(f64::NAN, 40, 1), None);
+        assert_eq!(f64_to_dec256(1e80, 76, 0), None);
+        assert_eq!(
+            dec256_cmp(x, 1, parse("-1234.50", 40, 2), 2),
+            Ordering::Equal
+        );
+    }
+}
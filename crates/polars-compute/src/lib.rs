@@ -14,6 +14,8 @@ pub mod cast;
 pub mod comparisons;
 #[cfg(feature = "dtype-decimal")]
 pub mod decimal;
+#[cfg(feature = "dtype-decimal256")]
+pub mod decimal256;
 pub mod ewm;
 pub mod filter;
 #[cfg(feature = "cast")]
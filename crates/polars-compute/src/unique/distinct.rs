@@ -77,6 +77,7 @@ pub fn amortized_unique_from_dtype(dtype: &ArrowDataType) -> Box<dyn AmortizedUn
         P::LargeUtf8 => unreachable!(),
         P::List => unreachable!(),
         P::Union => unreachable!(),
+        P::RunEndEncoded => unreachable!(),
         P::Map => unreachable!(),
 
         // Should be handled through BinaryView.
@@ -1,5 +1,6 @@
-use std::sync::LazyLock;
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU64, Ordering};
+use std::sync::{LazyLock, RwLock};
 
 mod engine;
 mod parse;
@@ -43,6 +44,12 @@ const DEFAULT_FORCE_ASYNC: bool = false;
 const IMPORT_INTERVAL_AS_STRUCT: &str = "POLARS_IMPORT_INTERVAL_AS_STRUCT";
 const DEFAULT_IMPORT_INTERVAL_AS_STRUCT: bool = false;
 
+const IMPORT_ARROW_MAP_AS_MAP: &str = "POLARS_IMPORT_ARROW_MAP_AS_MAP";
+const DEFAULT_IMPORT_ARROW_MAP_AS_MAP: bool = true;
+
+const PARQUET_INFER_VARIANT_GROUPS: &str = "POLARS_PARQUET_INFER_VARIANT_GROUPS";
+const DEFAULT_PARQUET_INFER_VARIANT_GROUPS: bool = false;
+
 const OOC_DRIFT_THRESHOLD: &str = "POLARS_OOC_DRIFT_THRESHOLD";
 const DEFAULT_OOC_DRIFT_THRESHOLD: u64 = 64 * 1024 * 1024;
 
@@ -52,6 +59,8 @@ const DEFAULT_OOC_SPILL_POLICY: SpillPolicy = SpillPolicy::NoSpill;
 const OOC_SPILL_FORMAT: &str = "POLARS_OOC_SPILL_FORMAT";
 const DEFAULT_OOC_SPILL_FORMAT: SpillFormat = SpillFormat::Ipc;
 
+const STREAM_TRACE_PATH: &str = "POLARS_STREAM_TRACE_PATH";
+
 static KNOWN_OPTIONS: &[&str] = &[
     // Public.
     VERBOSE,
@@ -87,9 +96,12 @@ static KNOWN_OPTIONS: &[&str] = &[
     VERBOSE_SENSITIVE,
     FORCE_ASYNC,
     IMPORT_INTERVAL_AS_STRUCT,
+    IMPORT_ARROW_MAP_AS_MAP,
+    PARQUET_INFER_VARIANT_GROUPS,
     OOC_DRIFT_THRESHOLD,
     OOC_SPILL_POLICY,
     OOC_SPILL_FORMAT,
+    STREAM_TRACE_PATH,
 ];
 
 pub struct Config {
@@ -105,9 +117,12 @@ pub struct Config {
     verbose_sensitive: AtomicBool,
     force_async: AtomicBool,
     import_interval_as_struct: AtomicBool,
+    import_arrow_map_as_map: AtomicBool,
+    parquet_infer_variant_groups: AtomicBool,
     ooc_drift_threshold: AtomicU64,
     ooc_spill_policy: AtomicU8,
     ooc_spill_format: AtomicU8,
+    stream_trace_path: RwLock<Option<PathBuf>>,
 }
 
 impl Config {
@@ -127,9 +142,12 @@ impl Config {
             verbose_sensitive: AtomicBool::new(DEFAULT_VERBOSE_SENSITIVE),
             force_async: AtomicBool::new(DEFAULT_FORCE_ASYNC),
             import_interval_as_struct: AtomicBool::new(DEFAULT_IMPORT_INTERVAL_AS_STRUCT),
+            import_arrow_map_as_map: AtomicBool::new(DEFAULT_IMPORT_ARROW_MAP_AS_MAP),
+            parquet_infer_variant_groups: AtomicBool::new(DEFAULT_PARQUET_INFER_VARIANT_GROUPS),
             ooc_drift_threshold: AtomicU64::new(DEFAULT_OOC_DRIFT_THRESHOLD),
             ooc_spill_policy: AtomicU8::new(DEFAULT_OOC_SPILL_POLICY as u8),
             ooc_spill_format: AtomicU8::new(DEFAULT_OOC_SPILL_FORMAT as u8),
+            stream_trace_path: RwLock::new(None),
         };
         cfg.reload_env_vars();
         cfg
@@ -202,6 +220,16 @@ impl Config {
                     .unwrap_or(DEFAULT_IMPORT_INTERVAL_AS_STRUCT),
                 Ordering::Relaxed,
             ),
+            IMPORT_ARROW_MAP_AS_MAP => self.import_arrow_map_as_map.store(
+                val.and_then(|x| parse::parse_bool(var, x))
+                    .unwrap_or(DEFAULT_IMPORT_ARROW_MAP_AS_MAP),
+                Ordering::Relaxed,
+            ),
+            PARQUET_INFER_VARIANT_GROUPS => self.parquet_infer_variant_groups.store(
+                val.and_then(|x| parse::parse_bool(var, x))
+                    .unwrap_or(DEFAULT_PARQUET_INFER_VARIANT_GROUPS),
+                Ordering::Relaxed,
+            ),
             OOC_DRIFT_THRESHOLD => self.ooc_drift_threshold.store(
                 val.and_then(|x| parse::parse_u64(var, x))
                     .unwrap_or(DEFAULT_OOC_DRIFT_THRESHOLD),
@@ -217,6 +245,10 @@ impl Config {
                     .unwrap_or(DEFAULT_OOC_SPILL_FORMAT) as u8,
                 Ordering::Relaxed,
             ),
+            STREAM_TRACE_PATH => {
+                *self.stream_trace_path.write().unwrap() =
+                    val.filter(|x| !x.is_empty()).map(PathBuf::from)
+            },
 
             _ => {
                 if var.starts_with("POLARS_") {
@@ -269,6 +301,18 @@ impl Config {
         self.import_interval_as_struct.load(Ordering::Relaxed)
     }
 
+    /// Whether Arrow `Map` columns not written by Polars are imported as Polars maps rather
+    /// than as lists of structs. This is on by default.
+    pub fn import_arrow_map_as_map(&self) -> bool {
+        self.import_arrow_map_as_map.load(Ordering::Relaxed)
+    }
+
+    /// Whether unannotated Parquet groups of a `metadata` and a `value` binary column are read
+    /// as variants. This is off by default.
+    pub fn parquet_infer_variant_groups(&self) -> bool {
+        self.parquet_infer_variant_groups.load(Ordering::Relaxed)
+    }
+
     pub fn ooc_drift_threshold(&self) -> u64 {
         self.ooc_drift_threshold.load(Ordering::Relaxed)
     }
@@ -280,6 +324,11 @@ impl Config {
     pub fn ooc_spill_format(&self) -> SpillFormat {
         SpillFormat::from_discriminant(self.ooc_spill_format.load(Ordering::Relaxed))
     }
+
+    /// The file streaming queries write a Chrome trace of their execution to, if any.
+    pub fn stream_trace_path(&self) -> Option<PathBuf> {
+        self.stream_trace_path.read().unwrap().clone()
+    }
 }
 
 pub fn config() -> &'static Config {
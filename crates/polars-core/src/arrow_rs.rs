@@ -0,0 +1,296 @@
+//! Zero-copy conversions between Polars and the [arrow-rs](https://docs.rs/arrow/) crates.
+//!
+//! Data is passed through Arrow's C data interface, so the buffers are shared
+//! and not copied. Only a [`Series`] or [`DataFrame`] with multiple chunks is
+//! rechunked first, as a single arrow-rs array or [`RecordBatch`] is contiguous.
+//!
+//! Field metadata is kept in both directions, which includes the Arrow
+//! extension type metadata and the metadata Polars uses to restore its
+//! categorical and enum types.
+use std::sync::Arc;
+
+use arrow::ffi;
+use arrow_array::ffi::{FFI_ArrowArray, FFI_ArrowSchema, from_ffi};
+use arrow_array::{ArrayRef, RecordBatch, RecordBatchOptions, make_array};
+use arrow_schema::{Field, Schema};
+use polars_error::to_compute_err;
+
+use crate::prelude::*;
+
+/// Exports a Polars array to arrow-rs.
+fn export_array(field: &ArrowField, array: Box<dyn Array>) -> PolarsResult<(Field, ArrayRef)> {
+    let schema = ffi::export_field_to_c(field);
+    let array = ffi::export_array_to_c(array);
+
+    // SAFETY: both are `#[repr(C)]` structs as defined by the C data interface.
+    let schema = unsafe { std::mem::transmute::<ffi::ArrowSchema, FFI_ArrowSchema>(schema) };
+    let array = unsafe { std::mem::transmute::<ffi::ArrowArray, FFI_ArrowArray>(array) };
+
+    let field = Field::try_from(&schema).map_err(to_compute_err)?;
+    // SAFETY: the array was exported with this schema.
+    let data = unsafe { from_ffi(array, &schema) }.map_err(to_compute_err)?;
+    Ok((field, make_array(data)))
+}
+
+/// Imports an arrow-rs array into Polars.
+fn import_array(
+    field: &Field,
+    array: &dyn arrow_array::Array,
+) -> PolarsResult<(ArrowField, Box<dyn Array>)> {
+    polars_ensure!(
+        field.data_type() == array.data_type(),
+        ComputeError: "arrow-rs Field dtype does not match the ArrayRef dtype"
+    );
+    let schema = FFI_ArrowSchema::try_from(field).map_err(to_compute_err)?;
+    let array = FFI_ArrowArray::new(&array.to_data());
+
+    // SAFETY: both are `#[repr(C)]` structs as defined by the C data interface.
+    let schema = unsafe { std::mem::transmute::<FFI_ArrowSchema, ffi::ArrowSchema>(schema) };
+    let array = unsafe { std::mem::transmute::<FFI_ArrowArray, ffi::ArrowArray>(array) };
+
+    // SAFETY: the schema and array were exported by arrow-rs.
+    let field = unsafe { ffi::import_field_from_c(&schema) }?;
+    let array = unsafe { ffi::import_array_from_c(array, field.dtype().clone()) }?;
+    Ok((field, array))
+}
+
+impl TryFrom<&Series> for (Field, ArrayRef) {
+    type Error = PolarsError;
+
+    fn try_from(s: &Series) -> PolarsResult<Self> {
+        polars_ensure!(
+            !s.dtype().is_object(),
+            InvalidOperation: "cannot convert a Series of dtype {} to arrow-rs", s.dtype()
+        );
+        let s = s.rechunk();
+        let field = s.field().to_arrow(CompatLevel::newest());
+        export_array(&field, s.to_arrow(0, CompatLevel::newest()))
+    }
+}
+
+impl TryFrom<&Series> for ArrayRef {
+    type Error = PolarsError;
+
+    fn try_from(s: &Series) -> PolarsResult<Self> {
+        <(Field, ArrayRef)>::try_from(s).map(|(_, array)| array)
+    }
+}
+
+impl TryFrom<(&Field, ArrayRef)> for Series {
+    type Error = PolarsError;
+
+    fn try_from(field_arr: (&Field, ArrayRef)) -> PolarsResult<Self> {
+        let (field, arr) = field_arr;
+        let (field, arr) = import_array(field, arr.as_ref())?;
+        Series::try_from((&field, arr))
+    }
+}
+
+impl TryFrom<(PlSmallStr, ArrayRef)> for Series {
+    type Error = PolarsError;
+
+    fn try_from(name_arr: (PlSmallStr, ArrayRef)) -> PolarsResult<Self> {
+        let (name, arr) = name_arr;
+        let field = Field::new(name.as_str(), arr.data_type().clone(), true);
+        Series::try_from((&field, arr))
+    }
+}
+
+impl TryFrom<&DataFrame> for RecordBatch {
+    type Error = PolarsError;
+
+    fn try_from(df: &DataFrame) -> PolarsResult<Self> {
+        let (fields, arrays): (Vec<_>, Vec<_>) = df
+            .columns()
+            .iter()
+            .map(|c| <(Field, ArrayRef)>::try_from(c.as_materialized_series()))
+            .collect::<PolarsResult<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        let schema = Arc::new(Schema::new(fields));
+        let options = RecordBatchOptions::new().with_row_count(Some(df.height()));
+        RecordBatch::try_new_with_options(schema, arrays, &options).map_err(to_compute_err)
+    }
+}
+
+impl TryFrom<&RecordBatch> for DataFrame {
+    type Error = PolarsError;
+
+    fn try_from(batch: &RecordBatch) -> PolarsResult<Self> {
+        let columns = batch
+            .schema_ref()
+            .fields()
+            .iter()
+            .zip(batch.columns())
+            .map(|(field, arr)| Series::try_from((field.as_ref(), arr.clone())).map(Column::from))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        DataFrame::new(batch.num_rows(), columns)
+    }
+}
+
+impl TryFrom<RecordBatch> for DataFrame {
+    type Error = PolarsError;
+
+    fn try_from(batch: RecordBatch) -> PolarsResult<Self> {
+        DataFrame::try_from(&batch)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+
+    use super::*;
+
+    fn roundtrip(df: &DataFrame) -> DataFrame {
+        let batch = RecordBatch::try_from(df).unwrap();
+        assert_eq!(batch.num_rows(), df.height());
+        assert_eq!(batch.num_columns(), df.width());
+        DataFrame::try_from(batch).unwrap()
+    }
+
+    #[test]
+    fn test_arrow_rs_zero_copy() {
+        let s = Series::new(PlSmallStr::from_static("a"), [Some(1i64), None, Some(3)]);
+        let ptr = s
+            .i64()
+            .unwrap()
+            .downcast_iter()
+            .next()
+            .unwrap()
+            .values()
+            .as_ptr();
+
+        let (field, array) = <(Field, ArrayRef)>::try_from(&s).unwrap();
+        assert_eq!(field.name(), "a");
+        assert_eq!(array.null_count(), 1);
+        assert_eq!(array.as_primitive::<Int64Type>().values().as_ptr(), ptr);
+
+        let out = Series::try_from((&field, array)).unwrap();
+        assert!(out.equals_missing(&s));
+        let out_ptr = out
+            .i64()
+            .unwrap()
+            .downcast_iter()
+            .next()
+            .unwrap()
+            .values()
+            .as_ptr();
+        assert_eq!(out_ptr, ptr);
+    }
+
+    #[test]
+    fn test_arrow_rs_roundtrip() {
+        let list = Series::new(
+            PlSmallStr::from_static("list"),
+            [
+                Series::new(PlSmallStr::EMPTY, [1i32, 2]),
+                Series::new(PlSmallStr::EMPTY, [3i32]),
+                Series::new(PlSmallStr::EMPTY, Vec::<i32>::new()),
+            ],
+        );
+        let mut df = df![
+            "int" => [Some(1i64), None, Some(3)],
+            "float" => [1.5f64, 2.5, -0.5],
+            "str" => [Some("a"), None, Some("a string longer than twelve bytes")],
+            "bool" => [true, false, true],
+        ]
+        .unwrap();
+        df.with_column(list.into_column()).unwrap();
+
+        let out = roundtrip(&df);
+        assert_eq!(out.schema(), df.schema());
+        assert!(out.equals_missing(&df));
+
+        let batch = RecordBatch::try_from(&df).unwrap();
+        assert_eq!(
+            batch.schema().field(2).data_type(),
+            &arrow_schema::DataType::Utf8View
+        );
+    }
+
+    #[test]
+    fn test_arrow_rs_rechunk_and_height() {
+        let mut df = df!["a" => [1i64, 2]].unwrap();
+        let other = df.clone();
+        df.vstack_mut(&other).unwrap();
+        assert!(df.first_col_n_chunks() > 1);
+        assert!(roundtrip(&df).equals_missing(&df));
+
+        let df = DataFrame::empty_with_height(3);
+        assert_eq!(roundtrip(&df).shape(), (3, 0));
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_arrow_rs_struct() {
+        let df = df![
+            "a" => [1i64, 2],
+            "b" => ["x", "y"],
+        ]
+        .unwrap();
+        let s = df.into_struct(PlSmallStr::from_static("s")).into_series();
+        let (field, array) = <(Field, ArrayRef)>::try_from(&s).unwrap();
+        assert!(matches!(
+            field.data_type(),
+            arrow_schema::DataType::Struct(_)
+        ));
+        assert!(
+            Series::try_from((&field, array))
+                .unwrap()
+                .equals_missing(&s)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_arrow_rs_categorical() {
+        let cats = Categories::new(
+            PlSmallStr::from_static("arrow_rs"),
+            PlSmallStr::EMPTY,
+            CategoricalPhysical::U32,
+        );
+        let dtype = DataType::from_categories(cats);
+        let s = Series::new(PlSmallStr::from_static("c"), [Some("a"), None, Some("b")])
+            .cast(&dtype)
+            .unwrap();
+
+        let (field, array) = <(Field, ArrayRef)>::try_from(&s).unwrap();
+        assert!(matches!(
+            field.data_type(),
+            arrow_schema::DataType::Dictionary(_, _)
+        ));
+        let out = Series::try_from((&field, array.clone())).unwrap();
+        assert_eq!(out.dtype(), &dtype);
+        assert!(out.equals_missing(&s));
+
+        // Without the field metadata the global categories are used.
+        let out = Series::try_from((PlSmallStr::from_static("c"), array)).unwrap();
+        assert_eq!(
+            out.dtype(),
+            &DataType::from_categories(Categories::global())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-uuid")]
+    fn test_arrow_rs_extension() {
+        let DataType::Extension(typ, _) = DataType::new_uuid() else {
+            unreachable!()
+        };
+        let ca: BinaryChunked = [Some(&[7u8; 16][..]), None].into_iter().collect();
+        let s = ca
+            .with_name(PlSmallStr::from_static("u"))
+            .into_series()
+            .into_extension(typ);
+
+        let (field, array) = <(Field, ArrayRef)>::try_from(&s).unwrap();
+        assert!(field.metadata().contains_key("ARROW:extension:name"));
+        let out = Series::try_from((&field, array)).unwrap();
+        assert_eq!(out.dtype(), s.dtype());
+        assert!(out.equals_missing(&s));
+    }
+}
@@ -0,0 +1,116 @@
+use polars_compute::decimal256::{
+    DEC256_MAX_PREC, dec256_add, dec256_div, dec256_mul, dec256_rescale, dec256_sub,
+};
+
+use super::*;
+
+/// Apply a fallible binary operation on two `Decimal256` arrays, broadcasting unit-length
+/// arrays.
+///
+/// Both sides are rescaled to the largest scale of the two, and the output has the maximum
+/// precision.
+fn decimal256_binary<F>(
+    lhs: &Decimal256Chunked,
+    rhs: &Decimal256Chunked,
+    op: F,
+) -> PolarsResult<Decimal256Chunked>
+where
+    F: Fn(i256, i256, usize, usize) -> PolarsResult<i256>,
+{
+    let left_s = lhs.scale();
+    let right_s = rhs.scale();
+    let scale = left_s.max(right_s);
+    let prec = DEC256_MAX_PREC;
+
+    let apply = |l: Option<i256>, r: Option<i256>| -> PolarsResult<Option<i256>> {
+        let (Some(l), Some(r)) = (l, r) else {
+            return Ok(None);
+        };
+        let ls = dec256_rescale(l, left_s, prec, scale).ok_or_else(|| {
+            polars_err!(ComputeError: "overflow in Decimal cast for {l} from scale {left_s} to {scale}")
+        })?;
+        let rs = dec256_rescale(r, right_s, prec, scale).ok_or_else(|| {
+            polars_err!(ComputeError: "overflow in Decimal cast for {r} from scale {right_s} to {scale}")
+        })?;
+        op(ls, rs, prec, scale).map(Some)
+    };
+
+    let values: Vec<Option<i256>> = match (lhs.len(), rhs.len()) {
+        (1, _) => {
+            let l = lhs.get(0);
+            rhs.iter()
+                .map(|r| apply(l, r))
+                .collect::<PolarsResult<_>>()?
+        },
+        (_, 1) => {
+            let r = rhs.get(0);
+            lhs.iter()
+                .map(|l| apply(l, r))
+                .collect::<PolarsResult<_>>()?
+        },
+        (a, b) => {
+            polars_ensure!(a == b, length_mismatch = "arithmetic", a, b);
+            lhs.iter()
+                .zip(rhs.iter())
+                .map(|(l, r)| apply(l, r))
+                .collect::<PolarsResult<_>>()?
+        },
+    };
+    Ok(Decimal256Chunked::from_i256_iter(
+        lhs.name().clone(),
+        values,
+        prec,
+        scale,
+    ))
+}
+
+impl Add for &Decimal256Chunked {
+    type Output = PolarsResult<Decimal256Chunked>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        decimal256_binary(self, rhs, |l, r, prec, _| {
+            dec256_add(l, r, prec).ok_or_else(
+                || polars_err!(ComputeError: "overflow in decimal addition for {l} + {r}"),
+            )
+        })
+    }
+}
+
+impl Sub for &Decimal256Chunked {
+    type Output = PolarsResult<Decimal256Chunked>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        decimal256_binary(self, rhs, |l, r, prec, _| {
+            dec256_sub(l, r, prec).ok_or_else(
+                || polars_err!(ComputeError: "overflow in decimal subtraction for {l} - {r}"),
+            )
+        })
+    }
+}
+
+impl Mul for &Decimal256Chunked {
+    type Output = PolarsResult<Decimal256Chunked>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        decimal256_binary(self, rhs, |l, r, prec, scale| {
+            dec256_mul(l, r, prec, scale).ok_or_else(
+                || polars_err!(ComputeError: "overflow in decimal multiplication for {l} * {r}"),
+            )
+        })
+    }
+}
+
+impl Div for &Decimal256Chunked {
+    type Output = PolarsResult<Decimal256Chunked>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        decimal256_binary(self, rhs, |l, r, prec, scale| {
+            if r == i256::from_words(0, 0) {
+                polars_bail!(ComputeError: "division by zero Decimal");
+            }
+            dec256_div(l, r, prec, scale).ok_or_else(
+                || polars_err!(ComputeError: "overflow in decimal division for {l} / {r}"),
+            )
+        })
+    }
+}
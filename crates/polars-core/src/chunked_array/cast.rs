@@ -95,6 +95,13 @@ fn cast_impl_inner(
             }
             chunks
         },
+        // The physical integer of an interval packs multiple components, so a numeric
+        // value has no meaningful interval representation.
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => polars_bail!(
+            InvalidOperation: "casting from {:?} to Interval not supported",
+            chunks.first().map(|arr| arr.dtype())
+        ),
         _ => cast_chunks(chunks, &dtype.to_physical(), options)?,
     };
 
@@ -202,6 +209,10 @@ where
             DataType::Struct(fields) => {
                 cast_single_to_struct(self.name().clone(), &self.chunks, fields, options)
             },
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(precision, scale) => {
+                cast_to_decimal256(&self.clone().into_series(), *precision, *scale, options)
+            },
             _ => cast_impl_inner(self.name().clone(), &self.chunks, dtype, options).map(|mut s| {
                 // maintain sorted if data types
                 // - remain signed
@@ -304,6 +315,10 @@ impl ChunkCast for StringChunked {
                 let ca = Int128Chunked::from_chunk_iter(self.name().clone(), chunks);
                 Ok(ca.into_decimal_unchecked(*precision, *scale).into_series())
             },
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(precision, scale) => {
+                cast_to_decimal256(&self.clone().into_series(), *precision, *scale, options)
+            },
             #[cfg(feature = "dtype-date")]
             DataType::Date => {
                 let result = cast_chunks(&self.chunks, dtype, options)?;
@@ -0,0 +1,528 @@
+use std::borrow::Cow;
+
+use arrow::compute::utils::combine_validities_and;
+pub use arrow::types::i256;
+use polars_compute::decimal::DecimalFmtBuffer;
+use polars_compute::decimal256::{
+    DEC256_MAX_PREC, dec128_to_dec256, dec256_add, dec256_cmp, dec256_rescale, dec256_to_dec128,
+    dec256_to_f64, dec256_to_i128, dec256_verify_prec_scale, f64_to_dec256, i128_to_dec256,
+    str_to_dec256,
+};
+
+use super::*;
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+use crate::utils::handle_casting_failures;
+
+/// A decimal with a precision of up to 76 digits.
+///
+/// Every value is physically stored as a struct of its most significant 128 bits (`hi`,
+/// signed) and least significant 128 bits (`lo`, unsigned). Ordering the physical structs
+/// orders the decimals numerically, so hashing, grouping, joining and sorting work on the
+/// physical representation.
+pub type Decimal256Chunked = Logical<Decimal256Type, StructType>;
+
+/// The fields of the physical representation of a `Decimal256`.
+pub fn decimal256_physical_fields() -> Vec<Field> {
+    vec![
+        Field::new(PlSmallStr::from_static("hi"), DataType::Int128),
+        Field::new(PlSmallStr::from_static("lo"), DataType::UInt128),
+    ]
+}
+
+/// The physical dtype of a `Decimal256`.
+pub fn decimal256_physical_dtype() -> DataType {
+    DataType::Struct(decimal256_physical_fields())
+}
+
+fn physical_arrow_dtype() -> ArrowDataType {
+    decimal256_physical_dtype().to_arrow(CompatLevel::newest())
+}
+
+fn physical_words(arr: &StructArray) -> (&PrimitiveArray<i128>, &PrimitiveArray<u128>) {
+    let values = arr.values();
+    let hi = values[0]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i128>>()
+        .unwrap();
+    let lo = values[1]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<u128>>()
+        .unwrap();
+    (hi, lo)
+}
+
+/// Get the value at `idx` of a physical `Decimal256` array, ignoring validity.
+///
+/// # Safety
+/// `idx` must be in bounds.
+#[inline]
+pub(crate) unsafe fn decimal256_value_unchecked(arr: &StructArray, idx: usize) -> i256 {
+    let (hi, lo) = physical_words(arr);
+    unsafe { i256::from_words(hi.value_unchecked(idx), lo.value_unchecked(idx) as i128) }
+}
+
+/// Convert an arrow `Decimal256` array to its physical representation.
+pub fn decimal256_array_to_physical(arr: &PrimitiveArray<i256>) -> StructArray {
+    let (hi, lo): (Vec<i128>, Vec<u128>) = arr
+        .values()
+        .iter()
+        .map(|v| {
+            let (hi, lo) = v.0.into_words();
+            (hi, lo as u128)
+        })
+        .unzip();
+    StructArray::new(
+        physical_arrow_dtype(),
+        arr.len(),
+        vec![
+            PrimitiveArray::from_vec(hi).boxed(),
+            PrimitiveArray::from_vec(lo).boxed(),
+        ],
+        arr.validity().cloned(),
+    )
+}
+
+/// Convert the physical representation of a `Decimal256` to an arrow `Decimal256` array.
+pub fn decimal256_array_from_physical(
+    arr: &StructArray,
+    precision: usize,
+    scale: usize,
+) -> PrimitiveArray<i256> {
+    let (hi, lo) = physical_words(arr);
+    let values = hi
+        .values()
+        .iter()
+        .zip(lo.values().iter())
+        .map(|(hi, lo)| i256::from_words(*hi, *lo as i128))
+        .collect();
+    let validity = combine_validities_and(
+        arr.validity(),
+        combine_validities_and(hi.validity(), lo.validity()).as_ref(),
+    );
+    PrimitiveArray::new(
+        ArrowDataType::Decimal256(precision, scale),
+        values,
+        validity,
+    )
+}
+
+impl Decimal256Chunked {
+    /// Create a `Decimal256` column from arrow `Decimal256` arrays.
+    pub fn from_i256_chunks<I: IntoIterator<Item = PrimitiveArray<i256>>>(
+        name: PlSmallStr,
+        chunks: I,
+        precision: usize,
+        scale: usize,
+    ) -> Self {
+        let chunks = chunks
+            .into_iter()
+            .map(|arr| decimal256_array_to_physical(&arr).boxed())
+            .collect();
+        // SAFETY: the chunks have the physical arrow dtype.
+        let phys = unsafe {
+            StructChunked::from_chunks_and_dtype_unchecked(
+                name,
+                chunks,
+                decimal256_physical_dtype(),
+            )
+        };
+        // SAFETY: no invalid states (from a safety perspective).
+        unsafe { Self::new_logical(phys, DataType::Decimal256(precision, scale)) }
+    }
+
+    pub fn from_i256_iter<I: IntoIterator<Item = Option<i256>>>(
+        name: PlSmallStr,
+        v: I,
+        precision: usize,
+        scale: usize,
+    ) -> Self {
+        let arr: PrimitiveArray<i256> = v.into_iter().collect();
+        let arr = arr.to(ArrowDataType::Decimal256(precision, scale));
+        Self::from_i256_chunks(name, [arr], precision, scale)
+    }
+
+    pub fn precision(&self) -> usize {
+        match &self.dtype {
+            DataType::Decimal256(precision, _) => *precision,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn scale(&self) -> usize {
+        match &self.dtype {
+            DataType::Decimal256(_, scale) => *scale,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Get the chunks as arrow `Decimal256` arrays.
+    pub fn i256_chunks(&self) -> impl Iterator<Item = PrimitiveArray<i256>> + '_ {
+        let (precision, scale) = (self.precision(), self.scale());
+        self.phys
+            .downcast_iter()
+            .map(move |arr| decimal256_array_from_physical(arr, precision, scale))
+    }
+
+    /// Iterate over the unscaled values.
+    pub fn iter(&self) -> impl Iterator<Item = Option<i256>> + '_ {
+        self.i256_chunks()
+            .flat_map(|arr| (0..arr.len()).map(move |i| arr.get(i)))
+    }
+
+    /// Get the unscaled value at index `i`.
+    pub fn get(&self, i: usize) -> Option<i256> {
+        if i >= self.len() {
+            return None;
+        }
+        let (chunk_idx, arr_idx) = self.phys.index_to_chunked_index(i);
+        // SAFETY: the index is in bounds.
+        unsafe {
+            let arr = self.phys.downcast_get_unchecked(chunk_idx);
+            (!arr.is_null_unchecked(arr_idx)).then(|| decimal256_value_unchecked(arr, arr_idx))
+        }
+    }
+
+    /// Apply `f` to every non-null value, nulling out values for which it returns `None`.
+    fn map_values<F>(&self, precision: usize, scale: usize, f: F) -> Self
+    where
+        F: Fn(i256) -> Option<i256>,
+    {
+        Self::from_i256_iter(
+            self.name().clone(),
+            self.iter().map(|v| f(v?)),
+            precision,
+            scale,
+        )
+    }
+
+    pub fn with_prec_scale(
+        &self,
+        prec: usize,
+        scale: usize,
+        strict: bool,
+    ) -> PolarsResult<Cow<'_, Self>> {
+        if self.precision() == prec && self.scale() == scale {
+            return Ok(Cow::Borrowed(self));
+        }
+
+        dec256_verify_prec_scale(prec, scale)?;
+        let old_s = self.scale();
+        let out = self.map_values(prec, scale, |v| dec256_rescale(v, old_s, prec, scale));
+        if strict && out.null_count() != self.null_count() {
+            polars_bail!(
+                ComputeError: "decimal precision {} can't fit all values of {:?}",
+                prec, self.dtype()
+            );
+        }
+        Ok(Cow::Owned(out))
+    }
+
+    /// Convert the values to `Int128`, truncating the fractional part.
+    fn to_i128(&self) -> Int128Chunked {
+        let scale = self.scale();
+        self.iter()
+            .map(|v| dec256_to_i128(v?, scale))
+            .collect_ca(self.name().clone())
+    }
+
+    /// Convert the values to `Float64`.
+    pub fn to_f64(&self) -> Float64Chunked {
+        let scale = self.scale();
+        self.iter()
+            .map(|v| v.map(|v| dec256_to_f64(v, scale)))
+            .collect_ca(self.name().clone())
+    }
+
+    /// Convert the values to their string representation.
+    pub fn to_string(&self) -> StringChunked {
+        let scale = self.scale();
+        let mut buf = DecimalFmtBuffer::new();
+        let out: StringChunked = self
+            .iter()
+            .map(|v| v.map(|v| buf.format_dec256(v, scale, false, false).to_string()))
+            .collect_ca(self.name().clone());
+        out
+    }
+
+    pub fn sum_reduce(&self) -> Scalar {
+        let prec = DEC256_MAX_PREC;
+        let scale = self.scale();
+        let dtype = DataType::Decimal256(prec, scale);
+
+        let mut sum = i256::from_words(0, 0);
+        for v in self.iter().flatten() {
+            match dec256_add(sum, v, prec) {
+                Some(s) => sum = s,
+                None => return Scalar::null(dtype),
+            }
+        }
+        Scalar::new(dtype, AnyValue::Decimal256(sum, prec, scale))
+    }
+
+    pub fn min_reduce(&self) -> Scalar {
+        self.extremum_reduce(std::cmp::Ordering::Less)
+    }
+
+    pub fn max_reduce(&self) -> Scalar {
+        self.extremum_reduce(std::cmp::Ordering::Greater)
+    }
+
+    fn extremum_reduce(&self, keep: std::cmp::Ordering) -> Scalar {
+        let scale = self.scale();
+        let out = self.iter().flatten().reduce(|acc, v| {
+            if dec256_cmp(v, scale, acc, scale) == keep {
+                v
+            } else {
+                acc
+            }
+        });
+        let av = match out {
+            Some(v) => AnyValue::Decimal256(v, self.precision(), scale),
+            None => AnyValue::Null,
+        };
+        Scalar::new(self.dtype().clone(), av)
+    }
+
+    /// The mean is computed from the exact sum where it fits in 76 digits.
+    pub fn mean(&self) -> Option<f64> {
+        let count = self.len() - self.null_count();
+        if count == 0 {
+            return None;
+        }
+        match self.sum_reduce().value() {
+            AnyValue::Decimal256(sum, _, scale) => Some(dec256_to_f64(*sum, *scale) / count as f64),
+            _ => self.to_f64().mean(),
+        }
+    }
+
+    pub fn mean_reduce(&self) -> Scalar {
+        Scalar::new(DataType::Float64, self.mean().into())
+    }
+}
+
+impl LogicalType for Decimal256Chunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    #[inline]
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        polars_ensure!(i < self.len(), oob = i, self.len());
+        Ok(unsafe { self.get_any_value_unchecked(i) })
+    }
+
+    #[inline]
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        let (chunk_idx, arr_idx) = self.phys.index_to_chunked_index(i);
+        unsafe {
+            let arr = self.phys.downcast_get_unchecked(chunk_idx);
+            if !arr.is_null_unchecked(arr_idx) {
+                AnyValue::Decimal256(
+                    decimal256_value_unchecked(arr, arr_idx),
+                    self.precision(),
+                    self.scale(),
+                )
+            } else {
+                AnyValue::Null
+            }
+        }
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        let out = match dtype {
+            DataType::Decimal256(to_prec, to_scale) => {
+                return Ok(self
+                    .with_prec_scale(*to_prec, *to_scale, cast_options.is_strict())?
+                    .into_owned()
+                    .into_series());
+            },
+            DataType::Decimal(to_prec, to_scale) => {
+                let (to_prec, to_scale) = (*to_prec, *to_scale);
+                let old_s = self.scale();
+                let out: Int128Chunked = self
+                    .iter()
+                    .map(|v| dec256_to_dec128(v?, old_s, to_prec, to_scale))
+                    .collect_ca(self.name().clone());
+                out.into_decimal_unchecked(to_prec, to_scale).into_series()
+            },
+            DataType::String => return Ok(self.to_string().into_series()),
+            DataType::Boolean => {
+                let zero = i256::from_words(0, 0);
+                let out: BooleanChunked = self
+                    .iter()
+                    .map(|v| v.map(|v| v != zero))
+                    .collect_ca(self.name().clone());
+                return Ok(out.into_series());
+            },
+            dt if dt.is_float() => {
+                return self.to_f64().cast_with_options(dt, cast_options);
+            },
+            dt if dt.is_integer() => self.to_i128().cast_with_options(dt, cast_options)?,
+            dt => {
+                polars_bail!(
+                    InvalidOperation:
+                    "casting from {:?} to {:?} not supported",
+                    self.dtype(), dt
+                )
+            },
+        };
+
+        if cast_options.is_strict() {
+            handle_casting_failures(&self.clone().into_series(), &out)?;
+        }
+        Ok(out)
+    }
+}
+
+/// Cast a numeric, string or decimal column to `Decimal256(precision, scale)`.
+pub(crate) fn cast_to_decimal256(
+    s: &Series,
+    precision: usize,
+    scale: usize,
+    cast_options: CastOptions,
+) -> PolarsResult<Series> {
+    dec256_verify_prec_scale(precision, scale)?;
+    let name = s.name().clone();
+    let out = match s.dtype() {
+        DataType::Decimal256(_, _) => {
+            return s
+                .decimal256()?
+                .cast_with_options(&DataType::Decimal256(precision, scale), cast_options);
+        },
+        DataType::Decimal(_, old_s) => {
+            let old_s = *old_s;
+            let ca = s.decimal()?;
+            Decimal256Chunked::from_i256_iter(
+                name,
+                ca.physical()
+                    .iter()
+                    .map(|v| dec128_to_dec256(v?, old_s, precision, scale)),
+                precision,
+                scale,
+            )
+        },
+        DataType::String => {
+            let ca = s.str()?;
+            Decimal256Chunked::from_i256_iter(
+                name,
+                ca.iter()
+                    .map(|v| str_to_dec256(v?.as_bytes(), precision, scale, false)),
+                precision,
+                scale,
+            )
+        },
+        dt if dt.is_float() => {
+            let ca = s.cast(&DataType::Float64)?;
+            let ca = ca.f64()?;
+            Decimal256Chunked::from_i256_iter(
+                name,
+                ca.iter().map(|v| f64_to_dec256(v?, precision, scale)),
+                precision,
+                scale,
+            )
+        },
+        dt if dt.is_integer() || dt.is_bool() => {
+            let ca = s.cast(&DataType::Int128)?;
+            let ca = ca.i128()?;
+            Decimal256Chunked::from_i256_iter(
+                name,
+                ca.iter().map(|v| i128_to_dec256(v?, precision, scale)),
+                precision,
+                scale,
+            )
+        },
+        dt => polars_bail!(
+            InvalidOperation:
+            "casting from {:?} to {:?} not supported",
+            dt, DataType::Decimal256(precision, scale)
+        ),
+    }
+    .into_series();
+
+    if cast_options.is_strict() {
+        handle_casting_failures(s, &out)?;
+    }
+    Ok(out)
+}
+
+/// Compare two `Decimal256` arrays element-wise, broadcasting unit-length arrays.
+///
+/// If `missing` is set, nulls compare equal to each other and unequal to any value.
+fn decimal256_compare<F>(
+    lhs: &Decimal256Chunked,
+    rhs: &Decimal256Chunked,
+    missing: bool,
+    op: F,
+) -> BooleanChunked
+where
+    F: Fn(std::cmp::Ordering) -> bool,
+{
+    let (ls, rs) = (lhs.scale(), rhs.scale());
+    let apply = |l: Option<i256>, r: Option<i256>| match (l, r) {
+        (Some(l), Some(r)) => Some(op(dec256_cmp(l, ls, r, rs))),
+        (None, None) if missing => Some(op(std::cmp::Ordering::Equal)),
+        _ if missing => Some(op(std::cmp::Ordering::Less)),
+        _ => None,
+    };
+    let name = lhs.name().clone();
+    match (lhs.len(), rhs.len()) {
+        (1, _) => {
+            let l = lhs.get(0);
+            rhs.iter().map(|r| apply(l, r)).collect_ca(name)
+        },
+        (_, 1) => {
+            let r = rhs.get(0);
+            lhs.iter().map(|l| apply(l, r)).collect_ca(name)
+        },
+        _ => lhs
+            .iter()
+            .zip(rhs.iter())
+            .map(|(l, r)| apply(l, r))
+            .collect_ca(name),
+    }
+}
+
+impl ChunkCompareEq<&Decimal256Chunked> for Decimal256Chunked {
+    type Item = BooleanChunked;
+
+    fn equal(&self, rhs: &Decimal256Chunked) -> BooleanChunked {
+        decimal256_compare(self, rhs, false, |o| o.is_eq())
+    }
+
+    fn equal_missing(&self, rhs: &Decimal256Chunked) -> BooleanChunked {
+        decimal256_compare(self, rhs, true, |o| o.is_eq())
+    }
+
+    fn not_equal(&self, rhs: &Decimal256Chunked) -> BooleanChunked {
+        decimal256_compare(self, rhs, false, |o| o.is_ne())
+    }
+
+    fn not_equal_missing(&self, rhs: &Decimal256Chunked) -> BooleanChunked {
+        decimal256_compare(self, rhs, true, |o| o.is_ne())
+    }
+}
+
+impl ChunkCompareIneq<&Decimal256Chunked> for Decimal256Chunked {
+    type Item = BooleanChunked;
+
+    fn gt(&self, rhs: &Decimal256Chunked) -> BooleanChunked {
+        decimal256_compare(self, rhs, false, |o| o.is_gt())
+    }
+
+    fn gt_eq(&self, rhs: &Decimal256Chunked) -> BooleanChunked {
+        decimal256_compare(self, rhs, false, |o| o.is_ge())
+    }
+
+    fn lt(&self, rhs: &Decimal256Chunked) -> BooleanChunked {
+        decimal256_compare(self, rhs, false, |o| o.is_lt())
+    }
+
+    fn lt_eq(&self, rhs: &Decimal256Chunked) -> BooleanChunked {
+        decimal256_compare(self, rhs, false, |o| o.is_le())
+    }
+}
@@ -55,6 +55,8 @@ impl LogicalType for DurationChunked {
                 Ok(out.into_duration(to_unit).into_series())
             },
             String => Ok(self.to_string("iso")?.into_series()),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Ok(self.to_interval().into_series()),
             dt if dt.is_primitive_numeric() => self.phys.cast_with_options(dtype, cast_options),
             dt => {
                 polars_bail!(
@@ -1,4 +1,7 @@
+use std::borrow::Cow;
+
 use crate::chunked_array::cast::CastOptions;
+use crate::datatypes::extension::ExtensionAggregation;
 use crate::prelude::*;
 
 #[derive(Clone)]
@@ -67,8 +70,82 @@ impl ExtensionChunked {
     pub fn cast_with_options(
         &self,
         dtype: &DataType,
-        _options: CastOptions,
+        options: CastOptions,
     ) -> PolarsResult<Series> {
-        polars_bail!(ComputeError: "cannot cast extension types to {dtype:?}")
+        if let Some(out) = self
+            .extension_type()
+            .0
+            .cast_to(&self.storage, dtype, options)
+        {
+            return out;
+        }
+        polars_ensure!(
+            dtype == self.storage.dtype(),
+            ComputeError: "cannot cast extension type {} to {dtype:?}", self.dtype
+        );
+        Ok(self.storage.clone())
+    }
+
+    /// Cast a [`Series`] of another dtype to the extension type `typ` with the given storage.
+    pub fn cast_from(
+        s: &Series,
+        typ: &ExtensionTypeInstance,
+        storage: &DataType,
+        options: CastOptions,
+    ) -> PolarsResult<Series> {
+        let out = match typ.0.cast_from(s, options) {
+            Some(out) => out?,
+            None => s.cast_with_options(storage, options)?,
+        };
+        polars_ensure!(
+            out.dtype() == storage,
+            ComputeError: "casting to extension type {typ} returned {}, expected {storage:?}", out.dtype()
+        );
+        typ.0.validate_storage(&out)?;
+        Ok(out.into_extension(typ.clone()))
+    }
+
+    /// Format the value at index `i`, if the extension type has a custom formatting.
+    pub fn fmt_value(&self, i: usize) -> PolarsResult<Option<String>> {
+        let av = self.storage.get(i)?;
+        if av.is_null() {
+            return Ok(None);
+        }
+        self.extension_type().0.fmt_value(&av)
+    }
+
+    /// Format the value at index `i` for display, showing the storage value with a warning if
+    /// the extension type fails to format it.
+    pub(crate) fn fmt_value_for_display(&self, i: usize) -> Option<String> {
+        self.fmt_value(i).unwrap_or_else(|err| {
+            polars_warn!(
+                "failed to format a value of extension type {}: {err}",
+                self.dtype()
+            );
+            None
+        })
+    }
+
+    /// Get the values by which this array is sorted and compared.
+    pub fn sort_key(&self) -> PolarsResult<Cow<'_, Series>> {
+        match self.extension_type().0.sort_key(&self.storage) {
+            Some(key) => {
+                let key = key?;
+                polars_ensure!(
+                    key.len() == self.len(),
+                    ComputeError: "extension sort key has length {}, expected {}", key.len(), self.len()
+                );
+                Ok(Cow::Owned(key))
+            },
+            None => Ok(Cow::Borrowed(&self.storage)),
+        }
+    }
+
+    /// Aggregate all values, returning `None` if the extension type does not support `agg`.
+    pub fn aggregate(&self, agg: ExtensionAggregation) -> Option<PolarsResult<Scalar>> {
+        let out = self.extension_type().0.aggregate(&self.storage, agg)?;
+        let out =
+            out.and_then(|sc| sc.cast_with_options(self.storage.dtype(), CastOptions::Strict));
+        Some(out.map(|sc| Scalar::new(self.dtype.clone(), sc.into_value())))
     }
 }
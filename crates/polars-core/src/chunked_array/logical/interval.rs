@@ -0,0 +1,186 @@
+pub use arrow::types::months_days_ns;
+
+use super::*;
+use crate::prelude::*;
+
+pub type IntervalChunked = Logical<IntervalType, Int128Type>;
+
+/// Pack an interval into its physical representation.
+///
+/// The months are stored in the most significant bits, followed by the days and the
+/// nanoseconds, such that ordering the physical values orders the intervals
+/// lexicographically by their components.
+#[inline]
+pub fn interval_to_i128(v: months_days_ns) -> i128 {
+    ((v.months() as i128) << 96) | ((v.days() as u32 as i128) << 64) | (v.ns() as u64 as i128)
+}
+
+/// Unpack the physical representation of an interval.
+#[inline]
+pub fn i128_to_interval(v: i128) -> months_days_ns {
+    months_days_ns::new((v >> 96) as i32, (v >> 64) as i32, v as i64)
+}
+
+/// Convert an arrow `Interval(MonthDayNano)` array to its physical representation.
+pub fn interval_array_to_physical(arr: &PrimitiveArray<months_days_ns>) -> PrimitiveArray<i128> {
+    let values = arr.values().iter().map(|v| interval_to_i128(*v)).collect();
+    PrimitiveArray::new(ArrowDataType::Int128, values, arr.validity().cloned())
+}
+
+/// Convert the physical representation of an interval to an arrow `Interval(MonthDayNano)` array.
+pub fn interval_array_from_physical(arr: &PrimitiveArray<i128>) -> PrimitiveArray<months_days_ns> {
+    let values = arr.values().iter().map(|v| i128_to_interval(*v)).collect();
+    PrimitiveArray::new(
+        ArrowDataType::Interval(arrow::datatypes::IntervalUnit::MonthDayNano),
+        values,
+        arr.validity().cloned(),
+    )
+}
+
+impl Int128Chunked {
+    pub fn into_interval(self) -> IntervalChunked {
+        // SAFETY: every i128 is a valid packed interval.
+        unsafe { IntervalChunked::new_logical(self, DataType::Interval) }
+    }
+}
+
+impl IntervalChunked {
+    pub fn from_intervals<I: IntoIterator<Item = Option<months_days_ns>>>(
+        name: PlSmallStr,
+        v: I,
+    ) -> Self {
+        Int128Chunked::from_iter_options(name, v.into_iter().map(|v| v.map(interval_to_i128)))
+            .into_interval()
+    }
+
+    /// Get the interval at index `i`.
+    pub fn get(&self, i: usize) -> Option<months_days_ns> {
+        self.phys.get(i).map(i128_to_interval)
+    }
+
+    /// Iterate over the intervals.
+    pub fn iter(&self) -> impl Iterator<Item = Option<months_days_ns>> + '_ {
+        self.phys.iter().map(|v| v.map(i128_to_interval))
+    }
+
+    /// Convert the intervals to their string representation.
+    pub fn to_string(&self) -> StringChunked {
+        self.phys
+            .apply_into_string_amortized(|v, buf| fmt_interval(buf, i128_to_interval(v)).unwrap())
+    }
+}
+
+impl LogicalType for IntervalChunked {
+    fn dtype(&self) -> &'static DataType {
+        &DataType::Interval
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.phys.get_any_value(i).map(|av| av.as_interval())
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.phys.get_any_value_unchecked(i).as_interval()
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        use DataType::*;
+        match dtype {
+            Interval => Ok(self.clone().into_series()),
+            Int128 => self.phys.cast_with_options(dtype, cast_options),
+            String => Ok(self.to_string().into_series()),
+            #[cfg(feature = "dtype-duration")]
+            Duration(tu) => {
+                // Only intervals without a calendar component have a fixed duration.
+                let ns: Int64Chunked = self.phys.try_apply_nonnull_values_generic(|v| {
+                    let v = i128_to_interval(v);
+                    polars_ensure!(
+                        v.months() == 0 && v.days() == 0,
+                        InvalidOperation: "cannot cast interval '{}' with months or days to \
+                        {dtype:?}; these do not have a fixed length",
+                        IntervalDisplay(v)
+                    );
+                    Ok(v.ns())
+                })?;
+                ns.into_duration(TimeUnit::Nanoseconds)
+                    .cast_with_options(&Duration(*tu), cast_options)
+            },
+            _ => {
+                polars_bail!(
+                    InvalidOperation:
+                    "casting from {:?} to {:?} not supported",
+                    self.dtype(), dtype
+                )
+            },
+        }
+    }
+}
+
+#[cfg(feature = "dtype-duration")]
+impl DurationChunked {
+    /// Convert a duration into an interval with only a nanosecond component.
+    pub fn to_interval(&self) -> IntervalChunked {
+        let factor: i64 = match self.time_unit() {
+            TimeUnit::Nanoseconds => 1,
+            TimeUnit::Microseconds => 1_000,
+            TimeUnit::Milliseconds => 1_000_000,
+        };
+        let phys: Int128Chunked = self
+            .phys
+            .apply_nonnull_values_generic(DataType::Int128, |v| {
+                interval_to_i128(months_days_ns::new(0, 0, v * factor))
+            });
+        phys.into_interval()
+    }
+}
+
+/// Format an interval as, e.g., `1y2mo3d4h5m6s7ns`.
+///
+/// Months are split into years and months, the nanoseconds into hours down to
+/// nanoseconds, and zero components are omitted. A zero interval is written as `0s`.
+pub fn fmt_interval<W: std::fmt::Write>(f: &mut W, v: months_days_ns) -> std::fmt::Result {
+    if v.months() == 0 && v.days() == 0 && v.ns() == 0 {
+        return f.write_str("0s");
+    }
+
+    let (years, months) = (v.months() / 12, v.months() % 12);
+    for (value, unit) in [
+        (years as i64, "y"),
+        (months as i64, "mo"),
+        (v.days() as i64, "d"),
+    ] {
+        if value != 0 {
+            write!(f, "{value}{unit}")?;
+        }
+    }
+
+    let mut ns = v.ns();
+    for (size, unit) in [
+        (3_600_000_000_000, "h"),
+        (60_000_000_000, "m"),
+        (1_000_000_000, "s"),
+        (1_000_000, "ms"),
+        (1_000, "us"),
+        (1, "ns"),
+    ] {
+        let value = ns / size;
+        if value != 0 {
+            write!(f, "{value}{unit}")?;
+            ns -= value * size;
+        }
+    }
+    Ok(())
+}
+
+/// Wrapper to [`Display`](std::fmt::Display) an interval with [`fmt_interval`].
+pub struct IntervalDisplay(pub months_days_ns);
+
+impl std::fmt::Display for IntervalDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_interval(f, self.0)
+    }
+}
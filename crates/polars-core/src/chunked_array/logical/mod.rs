@@ -14,6 +14,10 @@ pub use datetime::*;
 mod decimal;
 #[cfg(feature = "dtype-decimal")]
 pub use decimal::*;
+#[cfg(feature = "dtype-decimal256")]
+mod decimal256;
+#[cfg(feature = "dtype-decimal256")]
+pub use decimal256::*;
 #[cfg(feature = "dtype-duration")]
 mod duration;
 #[cfg(feature = "dtype-duration")]
@@ -22,6 +26,10 @@ pub use duration::*;
 mod extension;
 #[cfg(feature = "dtype-extension")]
 pub use extension::*;
+#[cfg(feature = "dtype-interval")]
+mod interval;
+#[cfg(feature = "dtype-interval")]
+pub use interval::*;
 #[cfg(feature = "dtype-time")]
 mod time;
 use std::marker::PhantomData;
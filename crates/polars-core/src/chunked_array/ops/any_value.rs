@@ -128,12 +128,23 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
             let v = arr.value_unchecked(idx);
             AnyValue::Time(v)
         },
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => {
+            let arr = &*(arr as *const dyn Array as *const Int128Array);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Interval(i128_to_interval(v))
+        },
         #[cfg(feature = "dtype-decimal")]
         DataType::Decimal(precision, scale) => {
             let arr = &*(arr as *const dyn Array as *const Int128Array);
             let v = arr.value_unchecked(idx);
             AnyValue::Decimal(v, *precision, *scale)
         },
+        #[cfg(feature = "dtype-decimal256")]
+        DataType::Decimal256(precision, scale) => {
+            let arr = &*(arr as *const dyn Array as *const StructArray);
+            AnyValue::Decimal256(decimal256_value_unchecked(arr, idx), *precision, *scale)
+        },
         #[cfg(feature = "dtype-extension")]
         DataType::Extension(typ, storage) => arr_to_any_value(arr, idx, storage),
         #[cfg(feature = "object")]
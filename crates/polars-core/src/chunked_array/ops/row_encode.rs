@@ -97,6 +97,12 @@ pub fn get_row_encoding_context(dtype: &DataType) -> Option<RowEncodingContext>
         | DataType::Date
         | DataType::Datetime(_, _)
         | DataType::Duration(_) => None,
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => None,
+        // Stored as a struct of its signed high and unsigned low words, which encode in
+        // numeric order without a context.
+        #[cfg(feature = "dtype-decimal256")]
+        DataType::Decimal256(_, _) => None,
 
         #[cfg(feature = "dtype-categorical")]
         DataType::Categorical(_, mapping) | DataType::Enum(_, mapping) => {
@@ -102,6 +102,11 @@ enum SerializableDataType {
         metadata: Option<String>,
         storage: Box<SerializableDataType>,
     },
+    /// A calendar interval of months, days and nanoseconds.
+    #[cfg(feature = "dtype-interval")]
+    Interval,
+    #[cfg(feature = "dtype-decimal256")]
+    Decimal256(usize, usize),
 }
 
 impl From<&DataType> for SerializableDataType {
@@ -129,6 +134,8 @@ impl From<&DataType> for SerializableDataType {
             Datetime(tu, tz) => Self::Datetime(*tu, tz.clone()),
             Duration(tu) => Self::Duration(*tu),
             Time => Self::Time,
+            #[cfg(feature = "dtype-interval")]
+            Interval => Self::Interval,
             List(dt) => Self::List(Box::new(dt.as_ref().into())),
             #[cfg(feature = "dtype-array")]
             Array(dt, width) => Self::Array(Box::new(dt.as_ref().into()), *width),
@@ -152,6 +159,8 @@ impl From<&DataType> for SerializableDataType {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(precision, scale) => Self::Decimal(*precision, *scale),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(precision, scale) => Self::Decimal256(*precision, *scale),
             #[cfg(feature = "object")]
             Object(name) => Self::Object(name.to_string()),
             #[cfg(feature = "dtype-extension")]
@@ -188,6 +197,8 @@ impl From<SerializableDataType> for DataType {
             Datetime(tu, tz) => Self::Datetime(tu, tz),
             Duration(tu) => Self::Duration(tu),
             Time => Self::Time,
+            #[cfg(feature = "dtype-interval")]
+            Interval => Self::Interval,
             List(dt) => Self::List(Box::new((*dt).into())),
             #[cfg(feature = "dtype-array")]
             Array(dt, width) => Self::Array(Box::new((*dt).into()), width),
@@ -218,6 +229,8 @@ impl From<SerializableDataType> for DataType {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(precision, scale) => Self::Decimal(precision, scale),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(precision, scale) => Self::Decimal256(precision, scale),
             #[cfg(feature = "object")]
             Object(_) => Self::Object("unknown"),
             #[cfg(feature = "dtype-extension")]
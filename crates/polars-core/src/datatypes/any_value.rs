@@ -29,6 +29,11 @@ use polars_compute::decimal::{
     dec128_cmp, dec128_eq, dec128_rescale, dec128_to_f64, dec128_to_i128, f64_to_dec128,
     i128_to_dec128,
 };
+#[cfg(feature = "dtype-decimal256")]
+use polars_compute::decimal256::{
+    dec128_to_dec256, dec128_widen, dec256_cmp, dec256_eq, dec256_rescale, dec256_to_dec128,
+    dec256_to_f64, dec256_to_i128, f64_to_dec256, i128_to_dec256,
+};
 
 #[derive(Debug, Clone, Default)]
 pub enum AnyValue<'a> {
@@ -82,6 +87,9 @@ pub enum AnyValue<'a> {
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     #[cfg(feature = "dtype-time")]
     Time(i64),
+    /// A calendar interval of months, days and nanoseconds.
+    #[cfg(feature = "dtype-interval")]
+    Interval(months_days_ns),
     #[cfg(feature = "dtype-categorical")]
     Categorical(CatSize, &'a Arc<CategoricalMapping>),
     #[cfg(feature = "dtype-categorical")]
@@ -114,6 +122,9 @@ pub enum AnyValue<'a> {
     /// A 128-bit fixed point decimal number with a precision and scale.
     #[cfg(feature = "dtype-decimal")]
     Decimal(i128, usize, usize),
+    /// A 256-bit fixed point decimal number with a precision and scale.
+    #[cfg(feature = "dtype-decimal256")]
+    Decimal256(i256, usize, usize),
 }
 
 impl AnyValue<'static> {
@@ -132,6 +143,8 @@ impl AnyValue<'static> {
             DataType::Duration(unit) => AnyValue::Duration(0, *unit),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(p, s) => AnyValue::Decimal(0, *p, *s),
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(p, s) => AnyValue::Decimal256(i256::default(), *p, *s),
             _ => AnyValue::Null,
         }
     }
@@ -165,6 +178,8 @@ impl AnyValue<'static> {
             DT::Float64 => AV::Float64(numeric_to_one.into()),
             #[cfg(feature = "dtype-decimal")]
             DT::Decimal(p, s) => AV::Decimal(0, *p, *s),
+            #[cfg(feature = "dtype-decimal256")]
+            DT::Decimal256(p, s) => AV::Decimal256(i256::default(), *p, *s),
             DT::String => AV::String(""),
             DT::Binary => AV::Binary(&[]),
             DT::BinaryOffset => AV::Binary(&[]),
@@ -177,6 +192,8 @@ impl AnyValue<'static> {
                 feature_gated!("dtype-duration", AV::Duration(0, *time_unit))
             },
             DT::Time => feature_gated!("dtype-time", AV::Time(0)),
+            #[cfg(feature = "dtype-interval")]
+            DT::Interval => AV::Interval(months_days_ns::default()),
             #[cfg(feature = "dtype-array")]
             DT::Array(inner_dtype, width) => {
                 let inner_value =
@@ -254,6 +271,8 @@ impl<'a> AnyValue<'a> {
             Date(_) => DataType::Date,
             #[cfg(feature = "dtype-time")]
             Time(_) => DataType::Time,
+            #[cfg(feature = "dtype-interval")]
+            Interval(_) => DataType::Interval,
             #[cfg(feature = "dtype-datetime")]
             Datetime(_, tu, tz) => DataType::Datetime(*tu, (*tz).cloned()),
             #[cfg(feature = "dtype-datetime")]
@@ -277,6 +296,8 @@ impl<'a> AnyValue<'a> {
             StructOwned(payload) => DataType::Struct(payload.1.clone()),
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, p, s) => DataType::Decimal(*p, *s),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(_, p, s) => DataType::Decimal256(*p, *s),
             #[cfg(feature = "object")]
             Object(o) => DataType::Object(o.type_name()),
             #[cfg(feature = "object")]
@@ -320,6 +341,14 @@ impl<'a> AnyValue<'a> {
                     NumCast::from(dec128_to_i128(*v, *s))
                 }
             },
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(v, _p, s) => {
+                if T::is_float() {
+                    NumCast::from(dec256_to_f64(*v, *s))
+                } else {
+                    NumCast::from(dec256_to_i128(*v, *s)?)
+                }
+            },
             Boolean(v) => NumCast::from(if *v { 1 } else { 0 }),
             String(v) => {
                 if let Ok(val) = (*v).parse::<i128>() {
@@ -646,6 +675,33 @@ impl<'a> AnyValue<'a> {
                 AnyValue::Decimal(converted, *p, *s)
             },
 
+            #[cfg(feature = "dtype-decimal256")]
+            (av, DataType::Decimal256(p, s)) if av.is_integer() => {
+                let int = av.try_extract::<i128>().ok()?;
+                AnyValue::Decimal256(i128_to_dec256(int, *p, *s)?, *p, *s)
+            },
+
+            #[cfg(feature = "dtype-decimal256")]
+            (av, DataType::Decimal256(p, s)) if av.is_float() => {
+                let f = av.try_extract::<f64>().unwrap();
+                AnyValue::Decimal256(f64_to_dec256(f, *p, *s)?, *p, *s)
+            },
+
+            #[cfg(feature = "dtype-decimal256")]
+            (AnyValue::Decimal(value, _old_p, old_s), DataType::Decimal256(p, s)) => {
+                AnyValue::Decimal256(dec128_to_dec256(*value, *old_s, *p, *s)?, *p, *s)
+            },
+
+            #[cfg(feature = "dtype-decimal256")]
+            (AnyValue::Decimal256(value, _old_p, old_s), DataType::Decimal(p, s)) => {
+                AnyValue::Decimal(dec256_to_dec128(*value, *old_s, *p, *s)?, *p, *s)
+            },
+
+            #[cfg(feature = "dtype-decimal256")]
+            (AnyValue::Decimal256(value, _old_p, old_s), DataType::Decimal256(p, s)) => {
+                AnyValue::Decimal256(dec256_rescale(*value, *old_s, *p, *s)?, *p, *s)
+            },
+
             // to self
             (av, dtype) if av.dtype() == *dtype => self.clone(),
 
@@ -730,6 +786,8 @@ impl<'a> AnyValue<'a> {
             Self::Duration(v, _) => Self::Int64(v),
             #[cfg(feature = "dtype-time")]
             Self::Time(v) => Self::Int64(v),
+            #[cfg(feature = "dtype-interval")]
+            Self::Interval(v) => Self::Int128(interval_to_i128(v)),
 
             #[cfg(feature = "dtype-categorical")]
             Self::Categorical(v, &ref m)
@@ -766,6 +824,14 @@ impl<'a> AnyValue<'a> {
 
             #[cfg(feature = "dtype-decimal")]
             Self::Decimal(v, _, _) => Self::Int128(v),
+            #[cfg(feature = "dtype-decimal256")]
+            Self::Decimal256(v, _, _) => {
+                let (hi, lo) = v.0.into_words();
+                Self::StructOwned(Box::new((
+                    vec![Self::Int128(hi), Self::UInt128(lo as u128)],
+                    decimal256_physical_fields(),
+                )))
+            },
         }
     }
 
@@ -864,6 +930,8 @@ impl AnyValue<'_> {
             },
             #[cfg(feature = "dtype-time")]
             Time(v) => v.hash(state),
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => v.hash(state),
             #[cfg(feature = "dtype-categorical")]
             Categorical(v, _) | CategoricalOwned(v, _) | Enum(v, _) | EnumOwned(v, _) => {
                 v.hash(state)
@@ -888,6 +956,12 @@ impl AnyValue<'_> {
                 s.hash(state);
                 p.hash(state);
             },
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(v, s, p) => {
+                v.hash(state);
+                s.hash(state);
+                p.hash(state);
+            },
             Null => {},
         }
     }
@@ -974,6 +1048,18 @@ impl<'a> AnyValue<'a> {
         }
     }
 
+    #[cfg(feature = "dtype-interval")]
+    pub(crate) fn as_interval(&self) -> AnyValue<'static> {
+        match self {
+            AnyValue::Int128(v) => AnyValue::Interval(i128_to_interval(*v)),
+            AnyValue::Null => AnyValue::Null,
+            av => panic!(
+                "cannot create interval from other type. dtype: {}",
+                av.dtype()
+            ),
+        }
+    }
+
     pub(crate) fn to_i128(&self) -> Option<i128> {
         match self {
             AnyValue::UInt8(v) => Some((*v).into()),
@@ -1081,6 +1167,8 @@ impl<'a> AnyValue<'a> {
             Duration(v, tu) => Duration(v, tu),
             #[cfg(feature = "dtype-time")]
             Time(v) => Time(v),
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => Interval(v),
             List(v) => List(v),
             #[cfg(feature = "dtype-array")]
             Array(s, size) => Array(s, size),
@@ -1109,6 +1197,8 @@ impl<'a> AnyValue<'a> {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(val, s, p) => Decimal(val, s, p),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(val, s, p) => Decimal256(val, s, p),
             #[cfg(feature = "dtype-categorical")]
             Categorical(cat, map) => CategoricalOwned(cat, map.clone()),
             #[cfg(feature = "dtype-categorical")]
@@ -1237,6 +1327,8 @@ impl AnyValue<'_> {
             (Binary(l), Binary(r)) => l == r,
             #[cfg(feature = "dtype-time")]
             (Time(l), Time(r)) => *l == *r,
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => *l == *r,
             #[cfg(all(feature = "dtype-datetime", feature = "dtype-date"))]
             (Date(l), Date(r)) => *l == *r,
             #[cfg(all(feature = "dtype-datetime", feature = "dtype-date"))]
@@ -1297,6 +1389,16 @@ impl AnyValue<'_> {
             ),
             #[cfg(feature = "dtype-decimal")]
             (Decimal(lv, _lp, ls), Decimal(rv, _rp, rs)) => dec128_eq(*lv, *ls, *rv, *rs),
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(lv, _lp, ls), Decimal256(rv, _rp, rs)) => dec256_eq(*lv, *ls, *rv, *rs),
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal(lv, _lp, ls), Decimal256(rv, _rp, rs)) => {
+                dec256_eq(dec128_widen(*lv), *ls, *rv, *rs)
+            },
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(lv, _lp, ls), Decimal(rv, _rp, rs)) => {
+                dec256_eq(*lv, *ls, dec128_widen(*rv), *rs)
+            },
             #[cfg(feature = "object")]
             (Object(l), Object(r)) => l == r,
             #[cfg(feature = "dtype-array")]
@@ -1414,6 +1516,8 @@ impl PartialOrd for AnyValue<'_> {
             },
             #[cfg(feature = "dtype-time")]
             (Time(l), Time(r)) => l.partial_cmp(r),
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => Some(l.tot_cmp(r)),
             #[cfg(feature = "dtype-categorical")]
             (Categorical(l_cat, l_map), Categorical(r_cat, r_map)) => unsafe {
                 let l_str = l_map.cat_to_str_unchecked(*l_cat);
@@ -1447,6 +1551,18 @@ impl PartialOrd for AnyValue<'_> {
             },
             #[cfg(feature = "dtype-decimal")]
             (Decimal(lv, _lp, ls), Decimal(rv, _rp, rs)) => Some(dec128_cmp(*lv, *ls, *rv, *rs)),
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(lv, _lp, ls), Decimal256(rv, _rp, rs)) => {
+                Some(dec256_cmp(*lv, *ls, *rv, *rs))
+            },
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal(lv, _lp, ls), Decimal256(rv, _rp, rs)) => {
+                Some(dec256_cmp(dec128_widen(*lv), *ls, *rv, *rs))
+            },
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(lv, _lp, ls), Decimal(rv, _rp, rs)) => {
+                Some(dec256_cmp(*lv, *ls, dec128_widen(*rv), *rs))
+            },
 
             (_, _) => {
                 unimplemented!(
@@ -2,9 +2,11 @@ use std::borrow::Cow;
 use std::collections::BTreeMap;
 
 use arrow::datatypes::{
-    DTYPE_CATEGORICAL_NEW, DTYPE_ENUM_VALUES_LEGACY, DTYPE_ENUM_VALUES_NEW, MAINTAIN_PL_TYPE,
-    Metadata, PL_KEY,
+    DTYPE_CATEGORICAL_MAPPING, DTYPE_CATEGORICAL_NEW, DTYPE_ENUM_VALUES_LEGACY,
+    DTYPE_ENUM_VALUES_NEW, MAINTAIN_PL_TYPE, Metadata, PL_KEY,
 };
+#[cfg(feature = "dtype-categorical")]
+use polars_dtype::categorical::encode_mapping;
 #[cfg(feature = "dtype-array")]
 use polars_utils::format_tuple;
 use polars_utils::itertools::Itertools;
@@ -40,6 +42,14 @@ pub trait MetaDataExt: IntoMetadata {
         )
     }
 
+    fn pl_categorical_mapping_metadata(&self) -> Option<&str> {
+        Some(
+            self.into_metadata_ref()
+                .get(DTYPE_CATEGORICAL_MAPPING)?
+                .as_str(),
+        )
+    }
+
     fn maintain_type(&self) -> bool {
         let metadata = self.into_metadata_ref();
         metadata.get(PL_KEY).map(|s| s.as_str()) == Some(MAINTAIN_PL_TYPE)
@@ -107,6 +117,11 @@ pub enum DataType {
     /// Meaning max precision is 38.
     #[cfg(feature = "dtype-decimal")]
     Decimal(usize, usize), // (precision, scale), invariant: 1 <= precision <= 38.
+    /// Fixed point decimal type for precisions that don't fit in a [`DataType::Decimal`].
+    /// This is backed by a signed 256-bit integer which allows for up to 76 significant digits.
+    /// It is physically stored as a struct of its high (signed) and low (unsigned) 128-bit words.
+    #[cfg(feature = "dtype-decimal256")]
+    Decimal256(usize, usize), // (precision, scale), invariant: 39 <= precision <= 76.
     /// String data
     String,
     Binary,
@@ -121,6 +136,10 @@ pub enum DataType {
     Duration(TimeUnit),
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     Time,
+    /// A calendar interval of months, days and nanoseconds.
+    /// This is backed by a signed 128-bit integer holding the three components.
+    #[cfg(feature = "dtype-interval")]
+    Interval,
     /// A nested list with a fixed size in each row
     #[cfg(feature = "dtype-array")]
     Array(Box<DataType>, usize),
@@ -169,6 +188,8 @@ impl PartialEq for DataType {
                 (Duration(tu_l), Duration(tu_r)) => tu_l == tu_r,
                 #[cfg(feature = "dtype-decimal")]
                 (Decimal(p1, s1), Decimal(p2, s2)) => (p1, s1) == (p2, s2),
+                #[cfg(feature = "dtype-decimal256")]
+                (Decimal256(p1, s1), Decimal256(p2, s2)) => (p1, s1) == (p2, s2),
                 #[cfg(feature = "object")]
                 (Object(lhs), Object(rhs)) => lhs == rhs,
                 #[cfg(feature = "dtype-struct")]
@@ -497,8 +518,12 @@ impl DataType {
             Datetime(_, _) => Int64,
             Duration(_) => Int64,
             Time => Int64,
+            #[cfg(feature = "dtype-interval")]
+            Interval => Int128,
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Int128,
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(_, _) => decimal256_physical_dtype(),
             #[cfg(feature = "dtype-categorical")]
             Categorical(cats, _) => cats.physical().dtype(),
             #[cfg(feature = "dtype-categorical")]
@@ -542,7 +567,12 @@ impl DataType {
     /// Check if this [`DataType`] is a temporal type
     pub fn is_temporal(&self) -> bool {
         use DataType::*;
-        matches!(self, Date | Datetime(_, _) | Duration(_) | Time)
+        match self {
+            Date | Datetime(_, _) | Duration(_) | Time => true,
+            #[cfg(feature = "dtype-interval")]
+            Interval => true,
+            _ => false,
+        }
     }
 
     /// Check if datatype is a primitive type. By that we mean that
@@ -743,6 +773,7 @@ impl DataType {
         let phys = self.to_physical();
         phys.is_primitive_numeric()
             || self.is_decimal()
+            || self.is_decimal256()
             || matches!(
                 phys,
                 DataType::Binary | DataType::String | DataType::Boolean
@@ -758,6 +789,15 @@ impl DataType {
         }
     }
 
+    /// Check if this [`DataType`] is a Decimal256 type (of any scale/precision).
+    pub fn is_decimal256(&self) -> bool {
+        match self {
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(_, _) => true,
+            _ => false,
+        }
+    }
+
     /// Check if this [`DataType`] is a basic floating point type (excludes Decimal).
     /// Note, this also includes `Unknown(UnknownKind::Float)`.
     pub fn is_float(&self) -> bool {
@@ -844,6 +884,120 @@ impl DataType {
         }
     }
 
+    pub fn is_map(&self) -> bool {
+        #[cfg(feature = "dtype-map")]
+        {
+            self.map_key_value_dtypes().is_some()
+        }
+        #[cfg(not(feature = "dtype-map"))]
+        {
+            false
+        }
+    }
+
+    /// Create a map [`DataType`], stored as a `List(Struct{key, value})`.
+    #[cfg(feature = "dtype-map")]
+    pub fn new_map(key: DataType, value: DataType) -> DataType {
+        use crate::datatypes::extension::MapExtensionType;
+
+        let storage = DataType::List(Box::new(DataType::Struct(vec![
+            Field::new(PlSmallStr::from_static("key"), key.clone()),
+            Field::new(PlSmallStr::from_static("value"), value.clone()),
+        ])));
+        let typ = ExtensionTypeInstance(Box::new(MapExtensionType::new(key, value)));
+        DataType::Extension(typ, Box::new(storage))
+    }
+
+    /// Get the key and value dtypes of a map [`DataType`].
+    #[cfg(feature = "dtype-map")]
+    pub fn map_key_value_dtypes(&self) -> Option<(&DataType, &DataType)> {
+        match self {
+            DataType::Extension(typ, storage) if typ.name() == POLARS_MAP_EXTENSION_NAME => {
+                crate::datatypes::extension::map_storage_key_value_dtypes(storage)
+            },
+            _ => None,
+        }
+    }
+
+    pub fn is_variant(&self) -> bool {
+        #[cfg(feature = "dtype-variant")]
+        {
+            matches!(
+                self,
+                DataType::Extension(typ, storage)
+                    if typ.name() == ARROW_VARIANT_EXTENSION_NAME
+                        && crate::datatypes::extension::is_variant_storage(storage)
+            )
+        }
+        #[cfg(not(feature = "dtype-variant"))]
+        {
+            false
+        }
+    }
+
+    /// Create a variant [`DataType`], stored as a `Struct{metadata: Binary, value: Binary}`.
+    #[cfg(feature = "dtype-variant")]
+    pub fn new_variant() -> DataType {
+        use crate::datatypes::extension::VariantExtensionType;
+
+        let storage = DataType::Struct(vec![
+            Field::new(PlSmallStr::from_static("metadata"), DataType::Binary),
+            Field::new(PlSmallStr::from_static("value"), DataType::Binary),
+        ]);
+        let typ = ExtensionTypeInstance(Box::new(VariantExtensionType));
+        DataType::Extension(typ, Box::new(storage))
+    }
+
+    pub fn is_uuid(&self) -> bool {
+        #[cfg(feature = "dtype-uuid")]
+        {
+            matches!(
+                self,
+                DataType::Extension(typ, storage)
+                    if typ.name() == ARROW_UUID_EXTENSION_NAME && **storage == DataType::Binary
+            )
+        }
+        #[cfg(not(feature = "dtype-uuid"))]
+        {
+            false
+        }
+    }
+
+    /// Create a UUID [`DataType`], stored as 16-byte `Binary` values.
+    #[cfg(feature = "dtype-uuid")]
+    pub fn new_uuid() -> DataType {
+        use crate::datatypes::extension::UuidExtensionType;
+
+        let typ = ExtensionTypeInstance(Box::new(UuidExtensionType));
+        DataType::Extension(typ, Box::new(DataType::Binary))
+    }
+
+    pub fn is_geometry(&self) -> bool {
+        #[cfg(feature = "dtype-geometry")]
+        {
+            matches!(
+                self,
+                DataType::Extension(typ, storage)
+                    if typ.name() == GEOARROW_WKB_EXTENSION_NAME && **storage == DataType::Binary
+            )
+        }
+        #[cfg(not(feature = "dtype-geometry"))]
+        {
+            false
+        }
+    }
+
+    /// Create a geometry [`DataType`], stored as Well-Known Binary values.
+    ///
+    /// The `metadata` is the GeoArrow extension metadata, such as `{"crs": "EPSG:4326"}`.
+    #[cfg(feature = "dtype-geometry")]
+    pub fn new_geometry(metadata: Option<String>) -> DataType {
+        use crate::datatypes::extension::geometry::GeometryExtensionType;
+
+        let typ = ExtensionTypeInstance(Box::new(GeometryExtensionType::new(metadata)));
+        DataType::Extension(typ, Box::new(DataType::Binary))
+    }
+
     /// Convert to an Arrow Field.
     pub fn to_arrow_field(&self, name: PlSmallStr, compat_level: CompatLevel) -> ArrowField {
         let field = ArrowField::new(name, self.to_arrow(compat_level), true);
@@ -876,7 +1030,7 @@ impl DataType {
                 )]))
             },
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(cats, _) => {
+            DataType::Categorical(cats, mapping) => {
                 let mut encoded = String::new();
                 encoded.push_str(itoa::Buffer::new().format(cats.name().len()));
                 encoded.push(';');
@@ -887,15 +1041,36 @@ impl DataType {
                 encoded.push_str(cats.physical().as_str());
                 encoded.push(';');
 
-                Some(BTreeMap::from([(
+                let mut metadata = BTreeMap::from([(
                     PlSmallStr::from_static(DTYPE_CATEGORICAL_NEW),
                     PlSmallStr::from_string(encoded),
-                )]))
+                )]);
+                if cats.is_persistent() {
+                    match encode_mapping(mapping) {
+                        Ok(encoded) => {
+                            _ = metadata.insert(
+                                PlSmallStr::from_static(DTYPE_CATEGORICAL_MAPPING),
+                                PlSmallStr::from_string(encoded),
+                            )
+                        },
+                        Err(e) => polars_warn!(
+                            CategoricalRemappingWarning,
+                            "could not store the categorical mapping of Categories '{}': {e}",
+                            cats.name()
+                        ),
+                    }
+                }
+                Some(metadata)
             },
             DataType::BinaryOffset => Some(BTreeMap::from([(
                 PlSmallStr::from_static(PL_KEY),
                 PlSmallStr::from_static(MAINTAIN_PL_TYPE),
             )])),
+            #[cfg(feature = "dtype-map")]
+            DataType::Extension(..) if self.is_map() => Some(BTreeMap::from([(
+                PlSmallStr::from_static(PL_KEY),
+                PlSmallStr::from_static(MAINTAIN_PL_TYPE),
+            )])),
             _ => None,
         }
     }
@@ -977,6 +1152,11 @@ impl DataType {
                 assert!(*precision >= 1 && *precision <= 38);
                 Ok(ArrowDataType::Decimal(*precision, *scale))
             },
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(precision, scale) => {
+                assert!(*precision >= 39 && *precision <= 76);
+                Ok(ArrowDataType::Decimal256(*precision, *scale))
+            },
             String => {
                 let dt = if compat_level.0 >= 1 {
                     ArrowDataType::Utf8View
@@ -1000,6 +1180,10 @@ impl DataType {
             )),
             Duration(unit) => Ok(ArrowDataType::Duration(unit.to_arrow())),
             Time => Ok(ArrowDataType::Time64(ArrowTimeUnit::Nanosecond)),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Ok(ArrowDataType::Interval(
+                arrow::datatypes::IntervalUnit::MonthDayNano,
+            )),
             #[cfg(feature = "dtype-array")]
             Array(dt, width) => Ok(ArrowDataType::FixedSizeList(
                 Box::new(dt.to_arrow_field(LIST_VALUES_NAME, compat_level)),
@@ -1040,6 +1224,33 @@ impl DataType {
                 Ok(ArrowDataType::Struct(fields))
             },
             BinaryOffset => Ok(ArrowDataType::LargeBinary),
+            #[cfg(feature = "dtype-map")]
+            Extension(_, inner) if self.is_map() => {
+                let DataType::List(entries) = inner.as_ref() else {
+                    unreachable!()
+                };
+                let ArrowDataType::Struct(mut fields) = entries.try_to_arrow(compat_level)? else {
+                    unreachable!()
+                };
+                // Arrow map keys are never null.
+                fields[0].is_nullable = false;
+                Ok(ArrowDataType::Map(
+                    Box::new(ArrowField::new(
+                        PlSmallStr::from_static("entries"),
+                        ArrowDataType::Struct(fields),
+                        false,
+                    )),
+                    false,
+                ))
+            },
+            #[cfg(feature = "dtype-uuid")]
+            Extension(typ, _) if self.is_uuid() => Ok(ArrowDataType::Extension(Box::new(
+                arrow::datatypes::ExtensionType {
+                    name: typ.name().into(),
+                    inner: ArrowDataType::FixedSizeBinary(16),
+                    metadata: None,
+                },
+            ))),
             #[cfg(feature = "dtype-extension")]
             Extension(typ, inner) => Ok(ArrowDataType::Extension(Box::new(
                 arrow::datatypes::ExtensionType {
@@ -1102,6 +1313,10 @@ impl DataType {
             (DataType::Null, DataType::Null) => Ok(false),
             #[cfg(feature = "dtype-decimal")]
             (DataType::Decimal(p1, s1), DataType::Decimal(p2, s2)) => Ok((p1, s1) != (p2, s2)),
+            #[cfg(feature = "dtype-decimal256")]
+            (DataType::Decimal256(p1, s1), DataType::Decimal256(p2, s2)) => {
+                Ok((p1, s1) != (p2, s2))
+            },
             // We don't allow the other way around, only if our current type is
             // null and the schema isn't we allow it.
             (DataType::Null, _) => Ok(true),
@@ -1174,7 +1389,7 @@ impl DataType {
     }
 
     pub fn is_numeric(&self) -> bool {
-        self.is_integer() || self.is_float() || self.is_decimal()
+        self.is_integer() || self.is_float() || self.is_decimal() || self.is_decimal256()
     }
 
     pub fn numeric_to_unsigned_bit_repr(&self) -> Option<DataType> {
@@ -1211,6 +1426,8 @@ impl Display for DataType {
             DataType::Float64 => "f64",
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(p, s) => return write!(f, "decimal[{p},{s}]"),
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(p, s) => return write!(f, "decimal[{p},{s}]"),
             DataType::String => "str",
             DataType::Binary => "binary",
             DataType::BinaryOffset => "binary[offset]",
@@ -1219,6 +1436,8 @@ impl Display for DataType {
             DataType::Datetime(tu, Some(tz)) => return write!(f, "datetime[{tu}, {tz}]"),
             DataType::Duration(tu) => return write!(f, "duration[{tu}]"),
             DataType::Time => "time",
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => "interval",
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => {
                 let tp = self.array_leaf_dtype().unwrap();
@@ -1276,6 +1495,8 @@ impl std::fmt::Debug for DataType {
             BinaryOffset => write!(f, "BinaryOffset"),
             Date => write!(f, "Date"),
             Time => write!(f, "Time"),
+            #[cfg(feature = "dtype-interval")]
+            Interval => write!(f, "Interval"),
             Duration(unit) => write!(f, "Duration('{unit}')"),
             Datetime(unit, opt_tz) => {
                 if let Some(tz) = opt_tz {
@@ -1286,6 +1507,8 @@ impl std::fmt::Debug for DataType {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(p, s) => write!(f, "Decimal({p}, {s})"),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(p, s) => write!(f, "Decimal256({p}, {s})"),
             #[cfg(feature = "dtype-array")]
             Array(inner, size) => write!(f, "Array({inner:?}, {size})"),
             List(inner) => write!(f, "List({inner:?})"),
@@ -0,0 +1,170 @@
+use std::any::Any;
+use std::borrow::Cow;
+use std::hash::{BuildHasher, Hash, Hasher};
+
+use polars_error::{PolarsResult, polars_bail};
+use polars_utils::aliases::PlFixedStateQuality;
+
+use super::{ExtensionTypeFactory, ExtensionTypeImpl, GenericExtensionType};
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+mod wkb;
+mod wkt;
+
+pub use wkb::{Coord, Geometry, read_wkb, write_wkb};
+pub use wkt::{format_wkt, parse_wkt};
+
+/// The built-in geometry extension type.
+///
+/// Geometries are stored as `Binary` values in the Well-Known Binary encoding, and are exported to
+/// and imported from the GeoArrow `geoarrow.wkb` extension type. The GeoArrow metadata, which
+/// holds the coordinate reference system, is kept as-is.
+pub struct GeometryExtensionType {
+    metadata: Option<String>,
+}
+
+impl GeometryExtensionType {
+    pub fn new(metadata: Option<String>) -> Self {
+        Self { metadata }
+    }
+}
+
+impl ExtensionTypeImpl for GeometryExtensionType {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(GEOARROW_WKB_EXTENSION_NAME)
+    }
+
+    fn serialize_metadata(&self) -> Option<Cow<'_, str>> {
+        self.metadata.as_deref().map(Cow::Borrowed)
+    }
+
+    fn dyn_clone(&self) -> Box<dyn ExtensionTypeImpl> {
+        Box::new(Self::new(self.metadata.clone()))
+    }
+
+    fn dyn_eq(&self, other: &dyn ExtensionTypeImpl) -> bool {
+        (other as &dyn Any)
+            .downcast_ref::<GeometryExtensionType>()
+            .is_some_and(|other| self.metadata == other.metadata)
+    }
+
+    fn dyn_hash(&self) -> u64 {
+        let mut hasher = PlFixedStateQuality::default().build_hasher();
+        GEOARROW_WKB_EXTENSION_NAME.hash(&mut hasher);
+        self.metadata.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn dyn_display(&self) -> Cow<'_, str> {
+        Cow::Borrowed("geometry")
+    }
+
+    fn dyn_debug(&self) -> Cow<'_, str> {
+        match &self.metadata {
+            Some(md) => Cow::Owned(format!("Geometry(metadata='{md}')")),
+            None => Cow::Borrowed("Geometry"),
+        }
+    }
+
+    fn fmt_value(&self, value: &AnyValue<'_>) -> PolarsResult<Option<String>> {
+        let bytes = match value {
+            AnyValue::Binary(b) => *b,
+            AnyValue::BinaryOwned(b) => b.as_slice(),
+            _ => return Ok(None),
+        };
+        let mut out = String::new();
+        format_wkt(&read_wkb(bytes)?, &mut out);
+        Ok(Some(out))
+    }
+
+    fn validate_storage(&self, storage: &Series) -> PolarsResult<()> {
+        for v in storage.binary()?.iter().flatten() {
+            read_wkb(v)?;
+        }
+        Ok(())
+    }
+
+    fn cast_from(&self, s: &Series, options: CastOptions) -> Option<PolarsResult<Series>> {
+        match s.dtype() {
+            DataType::String => {
+                Some(wkt_to_wkb(s.str().unwrap(), options.is_strict()).map(IntoSeries::into_series))
+            },
+            _ => None,
+        }
+    }
+
+    fn cast_to(
+        &self,
+        storage: &Series,
+        dtype: &DataType,
+        _options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        match dtype {
+            DataType::String => {
+                Some(wkb_to_wkt(storage.binary().unwrap()).map(IntoSeries::into_series))
+            },
+            _ => None,
+        }
+    }
+}
+
+pub(super) struct GeometryExtensionTypeFactory;
+
+impl ExtensionTypeFactory for GeometryExtensionTypeFactory {
+    fn create_type_instance(
+        &self,
+        name: &str,
+        storage: &DataType,
+        metadata: Option<&str>,
+    ) -> Box<dyn ExtensionTypeImpl> {
+        if storage == &DataType::Binary {
+            Box::new(GeometryExtensionType::new(metadata.map(|s| s.to_string())))
+        } else {
+            Box::new(GenericExtensionType::new(
+                name.to_string(),
+                metadata.map(|s| s.to_string()),
+            ))
+        }
+    }
+}
+
+/// Parse Well-Known Text geometries into their Well-Known Binary representation.
+///
+/// If `strict`, invalid geometries raise an error, otherwise they become null.
+pub fn wkt_to_wkb(ca: &StringChunked, strict: bool) -> PolarsResult<BinaryChunked> {
+    let mut out = Vec::with_capacity(ca.len());
+    for opt_s in ca.iter() {
+        let v = match opt_s.map(|s| (s, parse_wkt(s))) {
+            None => None,
+            Some((_, Ok(geom))) => {
+                let mut buf = Vec::new();
+                write_wkb(&geom, &mut buf);
+                Some(buf)
+            },
+            Some((s, Err(e))) if strict => {
+                polars_bail!(InvalidOperation: "conversion from `str` to `geometry` failed for value '{s}': {e}")
+            },
+            Some((_, Err(_))) => None,
+        };
+        out.push(v);
+    }
+    Ok(BinaryChunked::from_iter(out).with_name(ca.name().clone()))
+}
+
+/// Format Well-Known Binary geometries as Well-Known Text.
+pub fn wkb_to_wkt(ca: &BinaryChunked) -> PolarsResult<StringChunked> {
+    let mut out = Vec::with_capacity(ca.len());
+    for opt_b in ca.iter() {
+        let v = match opt_b {
+            None => None,
+            Some(b) => {
+                let mut s = String::new();
+                format_wkt(&read_wkb(b)?, &mut s);
+                Some(s)
+            },
+        };
+        out.push(v);
+    }
+    Ok(StringChunked::from_iter(out).with_name(ca.name().clone()))
+}
@@ -0,0 +1,303 @@
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
+
+/// A planar coordinate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Coord {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// A planar geometry, following the OGC simple features model.
+///
+/// The first ring of a polygon is its exterior, any other rings are holes. Only the `x` and `y`
+/// coordinates are kept, `z` and `m` values are dropped when reading.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Geometry {
+    /// `None` is the empty point.
+    Point(Option<Coord>),
+    LineString(Vec<Coord>),
+    Polygon(Vec<Vec<Coord>>),
+    MultiPoint(Vec<Coord>),
+    MultiLineString(Vec<Vec<Coord>>),
+    MultiPolygon(Vec<Vec<Vec<Coord>>>),
+    GeometryCollection(Vec<Geometry>),
+}
+
+impl Geometry {
+    fn type_code(&self) -> u32 {
+        match self {
+            Geometry::Point(_) => 1,
+            Geometry::LineString(_) => 2,
+            Geometry::Polygon(_) => 3,
+            Geometry::MultiPoint(_) => 4,
+            Geometry::MultiLineString(_) => 5,
+            Geometry::MultiPolygon(_) => 6,
+            Geometry::GeometryCollection(_) => 7,
+        }
+    }
+
+    /// The WKT name of the geometry type, such as `POINT`.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Geometry::Point(_) => "POINT",
+            Geometry::LineString(_) => "LINESTRING",
+            Geometry::Polygon(_) => "POLYGON",
+            Geometry::MultiPoint(_) => "MULTIPOINT",
+            Geometry::MultiLineString(_) => "MULTILINESTRING",
+            Geometry::MultiPolygon(_) => "MULTIPOLYGON",
+            Geometry::GeometryCollection(_) => "GEOMETRYCOLLECTION",
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        let mut empty = true;
+        self.for_each_coord(&mut |_| empty = false);
+        empty
+    }
+
+    /// Call `f` on every coordinate of the geometry.
+    pub fn for_each_coord(&self, f: &mut impl FnMut(Coord)) {
+        match self {
+            Geometry::Point(p) => p.iter().copied().for_each(f),
+            Geometry::LineString(cs) | Geometry::MultiPoint(cs) => cs.iter().copied().for_each(f),
+            Geometry::Polygon(rings) | Geometry::MultiLineString(rings) => {
+                rings.iter().flatten().copied().for_each(f)
+            },
+            Geometry::MultiPolygon(polys) => polys.iter().flatten().flatten().copied().for_each(f),
+            Geometry::GeometryCollection(geoms) => {
+                for g in geoms {
+                    g.for_each_coord(f)
+                }
+            },
+        }
+    }
+}
+
+/// Decode a geometry from its Well-Known Binary representation.
+///
+/// Both the ISO and the extended (PostGIS) WKB flavors are accepted.
+pub fn read_wkb(buf: &[u8]) -> PolarsResult<Geometry> {
+    let mut reader = WkbReader { buf, offset: 0 };
+    let geom = reader.read_geometry()?;
+    polars_ensure!(
+        reader.offset == buf.len(),
+        ComputeError: "invalid WKB: {} trailing bytes", buf.len() - reader.offset
+    );
+    Ok(geom)
+}
+
+/// Encode a geometry as little-endian two-dimensional Well-Known Binary.
+pub fn write_wkb(geom: &Geometry, out: &mut Vec<u8>) {
+    write_header(geom.type_code(), out);
+    match geom {
+        Geometry::Point(p) => {
+            let c = p.unwrap_or(Coord {
+                x: f64::NAN,
+                y: f64::NAN,
+            });
+            write_coord(c, out);
+        },
+        Geometry::LineString(cs) => write_coords(cs, out),
+        Geometry::Polygon(rings) => write_rings(rings, out),
+        Geometry::MultiPoint(cs) => {
+            write_len(cs.len(), out);
+            for c in cs {
+                write_header(1, out);
+                write_coord(*c, out);
+            }
+        },
+        Geometry::MultiLineString(lines) => {
+            write_len(lines.len(), out);
+            for cs in lines {
+                write_header(2, out);
+                write_coords(cs, out);
+            }
+        },
+        Geometry::MultiPolygon(polys) => {
+            write_len(polys.len(), out);
+            for rings in polys {
+                write_header(3, out);
+                write_rings(rings, out);
+            }
+        },
+        Geometry::GeometryCollection(geoms) => {
+            write_len(geoms.len(), out);
+            for g in geoms {
+                write_wkb(g, out);
+            }
+        },
+    }
+}
+
+fn write_header(type_code: u32, out: &mut Vec<u8>) {
+    out.push(1);
+    out.extend_from_slice(&type_code.to_le_bytes());
+}
+
+fn write_len(len: usize, out: &mut Vec<u8>) {
+    out.extend_from_slice(&(len as u32).to_le_bytes());
+}
+
+fn write_coord(c: Coord, out: &mut Vec<u8>) {
+    out.extend_from_slice(&c.x.to_le_bytes());
+    out.extend_from_slice(&c.y.to_le_bytes());
+}
+
+fn write_coords(cs: &[Coord], out: &mut Vec<u8>) {
+    write_len(cs.len(), out);
+    for c in cs {
+        write_coord(*c, out);
+    }
+}
+
+fn write_rings(rings: &[Vec<Coord>], out: &mut Vec<u8>) {
+    write_len(rings.len(), out);
+    for cs in rings {
+        write_coords(cs, out);
+    }
+}
+
+struct WkbReader<'a> {
+    buf: &'a [u8],
+    offset: usize,
+}
+
+impl WkbReader<'_> {
+    fn take<const N: usize>(&mut self) -> PolarsResult<[u8; N]> {
+        let Some(bytes) = self.buf.get(self.offset..self.offset + N) else {
+            polars_bail!(ComputeError: "invalid WKB: unexpected end of data");
+        };
+        self.offset += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn read_u32(&mut self, le: bool) -> PolarsResult<u32> {
+        let bytes = self.take::<4>()?;
+        Ok(if le {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_f64(&mut self, le: bool) -> PolarsResult<f64> {
+        let bytes = self.take::<8>()?;
+        Ok(if le {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    /// Read a count of items that take at least `min_item_size` bytes each.
+    fn read_len(&mut self, le: bool, min_item_size: usize) -> PolarsResult<usize> {
+        let len = self.read_u32(le)? as usize;
+        // Check against the remaining bytes so that corrupt lengths can't cause huge allocations.
+        polars_ensure!(
+            len.saturating_mul(min_item_size) <= self.buf.len() - self.offset,
+            ComputeError: "invalid WKB: unexpected end of data"
+        );
+        Ok(len)
+    }
+
+    fn read_coord(&mut self, le: bool, dims: usize) -> PolarsResult<Coord> {
+        let x = self.read_f64(le)?;
+        let y = self.read_f64(le)?;
+        for _ in 2..dims {
+            self.read_f64(le)?;
+        }
+        Ok(Coord { x, y })
+    }
+
+    fn read_coords(&mut self, le: bool, dims: usize) -> PolarsResult<Vec<Coord>> {
+        let len = self.read_len(le, dims * 8)?;
+        (0..len).map(|_| self.read_coord(le, dims)).collect()
+    }
+
+    fn read_rings(&mut self, le: bool, dims: usize) -> PolarsResult<Vec<Vec<Coord>>> {
+        let len = self.read_len(le, 4)?;
+        (0..len).map(|_| self.read_coords(le, dims)).collect()
+    }
+
+    /// Returns the byte order, the base geometry type code and the number of dimensions.
+    fn read_header(&mut self) -> PolarsResult<(bool, u32, usize)> {
+        let le = match self.take::<1>()? {
+            [0] => false,
+            [1] => true,
+            [b] => polars_bail!(ComputeError: "invalid WKB: unknown byte order {b}"),
+        };
+        let code = self.read_u32(le)?;
+
+        // Extended WKB stores the dimensions and SRID as flags in the high bits.
+        let mut dims = 2;
+        if code & 0x8000_0000 != 0 {
+            dims += 1;
+        }
+        if code & 0x4000_0000 != 0 {
+            dims += 1;
+        }
+        if code & 0x2000_0000 != 0 {
+            self.read_u32(le)?;
+        }
+
+        // ISO WKB adds 1000 for Z, 2000 for M and 3000 for ZM.
+        let code = code & 0x0FFF_FFFF;
+        dims += match code / 1000 {
+            0 => 0,
+            1 | 2 => 1,
+            3 => 2,
+            _ => polars_bail!(ComputeError: "invalid WKB: unknown geometry type {code}"),
+        };
+        Ok((le, code % 1000, dims))
+    }
+
+    fn read_geometry(&mut self) -> PolarsResult<Geometry> {
+        let (le, code, dims) = self.read_header()?;
+        let geom = match code {
+            1 => {
+                let c = self.read_coord(le, dims)?;
+                Geometry::Point((!(c.x.is_nan() && c.y.is_nan())).then_some(c))
+            },
+            2 => Geometry::LineString(self.read_coords(le, dims)?),
+            3 => Geometry::Polygon(self.read_rings(le, dims)?),
+            4 => {
+                let points = self.read_children(le, |g| match g {
+                    Geometry::Point(p) => Some(p),
+                    _ => None,
+                })?;
+                Geometry::MultiPoint(points.into_iter().flatten().collect())
+            },
+            5 => Geometry::MultiLineString(self.read_children(le, |g| match g {
+                Geometry::LineString(cs) => Some(cs),
+                _ => None,
+            })?),
+            6 => Geometry::MultiPolygon(self.read_children(le, |g| match g {
+                Geometry::Polygon(rings) => Some(rings),
+                _ => None,
+            })?),
+            7 => Geometry::GeometryCollection(self.read_children(le, Some)?),
+            _ => polars_bail!(ComputeError: "invalid WKB: unknown geometry type {code}"),
+        };
+        Ok(geom)
+    }
+
+    fn read_children<T>(
+        &mut self,
+        le: bool,
+        mut extract: impl FnMut(Geometry) -> Option<T>,
+    ) -> PolarsResult<Vec<T>> {
+        let len = self.read_len(le, 5)?;
+        (0..len)
+            .map(|_| {
+                let geom = self.read_geometry()?;
+                let name = geom.type_name();
+                match extract(geom) {
+                    Some(v) => Ok(v),
+                    None => {
+                        polars_bail!(ComputeError: "invalid WKB: unexpected {name} in multi-geometry")
+                    },
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,219 @@
+use std::fmt::Write;
+
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+
+use super::wkb::{Coord, Geometry};
+
+/// Parse a geometry from its Well-Known Text representation.
+///
+/// `Z`, `M` and `ZM` geometries are accepted but only their `x` and `y` coordinates are kept. An
+/// extended (PostGIS) `SRID=...;` prefix is ignored.
+pub fn parse_wkt(s: &str) -> PolarsResult<Geometry> {
+    let s = match s.trim_start().strip_prefix("SRID=") {
+        Some(rest) => {
+            rest.split_once(';')
+                .ok_or_else(|| polars_err!(ComputeError: "invalid WKT: missing ';' after SRID"))?
+                .1
+        },
+        None => s,
+    };
+    let mut parser = WktParser {
+        s: s.as_bytes(),
+        pos: 0,
+    };
+    let geom = parser.geometry()?;
+    parser.skip_whitespace();
+    polars_ensure!(
+        parser.pos == parser.s.len(),
+        ComputeError: "invalid WKT: unexpected trailing characters at position {}", parser.pos
+    );
+    Ok(geom)
+}
+
+/// Format a geometry as Well-Known Text, such as `POINT (1 2)`.
+pub fn format_wkt(geom: &Geometry, out: &mut String) {
+    out.push_str(geom.type_name());
+    if geom.is_empty() {
+        out.push_str(" EMPTY");
+        return;
+    }
+    out.push(' ');
+    match geom {
+        Geometry::Point(p) => fmt_coords(p.as_slice(), out),
+        Geometry::LineString(cs) => fmt_coords(cs, out),
+        Geometry::Polygon(rings) => fmt_rings(rings, out),
+        Geometry::MultiPoint(cs) => fmt_list(cs, out, |c, out| fmt_coords(&[*c], out)),
+        Geometry::MultiLineString(lines) => fmt_rings(lines, out),
+        Geometry::MultiPolygon(polys) => fmt_list(polys, out, |rings, out| fmt_rings(rings, out)),
+        Geometry::GeometryCollection(geoms) => fmt_list(geoms, out, format_wkt),
+    }
+}
+
+fn fmt_list<T>(items: &[T], out: &mut String, mut f: impl FnMut(&T, &mut String)) {
+    out.push('(');
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        f(item, out);
+    }
+    out.push(')');
+}
+
+fn fmt_coords(cs: &[Coord], out: &mut String) {
+    fmt_list(cs, out, |c, out| write!(out, "{} {}", c.x, c.y).unwrap())
+}
+
+fn fmt_rings(rings: &[Vec<Coord>], out: &mut String) {
+    fmt_list(rings, out, |cs, out| fmt_coords(cs, out))
+}
+
+struct WktParser<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl WktParser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.s.get(self.pos).is_some_and(u8::is_ascii_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.s.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, c: u8) -> PolarsResult<()> {
+        polars_ensure!(
+            self.peek() == Some(c),
+            ComputeError: "invalid WKT: expected '{}' at position {}", c as char, self.pos
+        );
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn word(&mut self) -> String {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self.s.get(self.pos).is_some_and(u8::is_ascii_alphabetic) {
+            self.pos += 1;
+        }
+        String::from_utf8_lossy(&self.s[start..self.pos]).to_ascii_uppercase()
+    }
+
+    /// Consume the `EMPTY` keyword if it is next.
+    fn empty(&mut self) -> bool {
+        if self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+            let start = self.pos;
+            if self.word() == "EMPTY" {
+                return true;
+            }
+            self.pos = start;
+        }
+        false
+    }
+
+    fn number(&mut self) -> PolarsResult<f64> {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self
+            .s
+            .get(self.pos)
+            .is_some_and(|c| !c.is_ascii_whitespace() && !matches!(c, b',' | b'(' | b')'))
+        {
+            self.pos += 1;
+        }
+        let token = std::str::from_utf8(&self.s[start..self.pos]).unwrap_or_default();
+        token.parse().map_err(
+            |_| polars_err!(ComputeError: "invalid WKT: expected a number at position {start}"),
+        )
+    }
+
+    fn coord(&mut self) -> PolarsResult<Coord> {
+        let x = self.number()?;
+        let y = self.number()?;
+        // Skip the z and m values.
+        while !matches!(self.peek(), Some(b',' | b')') | None) {
+            self.number()?;
+        }
+        Ok(Coord { x, y })
+    }
+
+    /// Parse a parenthesized, comma-separated list, or `EMPTY`.
+    fn list<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> PolarsResult<T>,
+    ) -> PolarsResult<Vec<T>> {
+        if self.empty() {
+            return Ok(Vec::new());
+        }
+        self.expect(b'(')?;
+        let mut out = vec![item(self)?];
+        while self.peek() == Some(b',') {
+            self.pos += 1;
+            out.push(item(self)?);
+        }
+        self.expect(b')')?;
+        Ok(out)
+    }
+
+    fn coords(&mut self) -> PolarsResult<Vec<Coord>> {
+        self.list(Self::coord)
+    }
+
+    fn rings(&mut self) -> PolarsResult<Vec<Vec<Coord>>> {
+        self.list(Self::coords)
+    }
+
+    fn geometry(&mut self) -> PolarsResult<Geometry> {
+        let start = self.pos;
+        let name = self.word();
+        // Skip the dimension, which may also be glued to the name as in `POINTZ`. None of the
+        // geometry type names end in `Z` or `M` themselves.
+        let name = name
+            .strip_suffix("ZM")
+            .or_else(|| name.strip_suffix('Z'))
+            .or_else(|| name.strip_suffix('M'))
+            .unwrap_or(&name)
+            .to_string();
+        if self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+            let dim_start = self.pos;
+            if !matches!(self.word().as_str(), "Z" | "M" | "ZM") {
+                self.pos = dim_start;
+            }
+        }
+
+        let geom = match name.as_str() {
+            "POINT" => {
+                let mut coords = self.coords()?;
+                polars_ensure!(coords.len() <= 1, ComputeError: "invalid WKT: point with multiple coordinates");
+                Geometry::Point(coords.pop())
+            },
+            "LINESTRING" => Geometry::LineString(self.coords()?),
+            "POLYGON" => Geometry::Polygon(self.rings()?),
+            "MULTIPOINT" => {
+                // Both `MULTIPOINT (1 2, 3 4)` and `MULTIPOINT ((1 2), (3 4))` are valid.
+                let points = self.list(|p| match p.peek() {
+                    Some(b'(') => {
+                        p.pos += 1;
+                        let c = p.coord()?;
+                        p.expect(b')')?;
+                        Ok(Some(c))
+                    },
+                    _ if p.empty() => Ok(None),
+                    _ => p.coord().map(Some),
+                })?;
+                Geometry::MultiPoint(points.into_iter().flatten().collect())
+            },
+            "MULTILINESTRING" => Geometry::MultiLineString(self.rings()?),
+            "MULTIPOLYGON" => Geometry::MultiPolygon(self.list(Self::rings)?),
+            "GEOMETRYCOLLECTION" => Geometry::GeometryCollection(self.list(Self::geometry)?),
+            _ => {
+                polars_bail!(ComputeError: "invalid WKT: unknown geometry type at position {start}")
+            },
+        };
+        Ok(geom)
+    }
+}
@@ -0,0 +1,114 @@
+use std::any::Any;
+use std::borrow::Cow;
+use std::hash::{BuildHasher, Hash, Hasher};
+
+use arrow::datatypes::Metadata;
+use polars_utils::aliases::PlFixedStateQuality;
+
+use super::{ExtensionTypeFactory, ExtensionTypeImpl, GenericExtensionType};
+use crate::prelude::{DataType, MetaDataExt, POLARS_MAP_EXTENSION_NAME};
+
+/// The built-in map extension type.
+///
+/// A map is stored as a `List(Struct{key, value})`, and is exported to and imported from
+/// Arrow's `Map` type so that the logical type survives IO.
+pub struct MapExtensionType {
+    key: DataType,
+    value: DataType,
+}
+
+impl MapExtensionType {
+    pub fn new(key: DataType, value: DataType) -> Self {
+        Self { key, value }
+    }
+
+    pub fn key_dtype(&self) -> &DataType {
+        &self.key
+    }
+
+    pub fn value_dtype(&self) -> &DataType {
+        &self.value
+    }
+}
+
+impl ExtensionTypeImpl for MapExtensionType {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(POLARS_MAP_EXTENSION_NAME)
+    }
+
+    fn serialize_metadata(&self) -> Option<Cow<'_, str>> {
+        None
+    }
+
+    fn dyn_clone(&self) -> Box<dyn ExtensionTypeImpl> {
+        Box::new(Self {
+            key: self.key.clone(),
+            value: self.value.clone(),
+        })
+    }
+
+    fn dyn_eq(&self, other: &dyn ExtensionTypeImpl) -> bool {
+        let Some(other) = (other as &dyn Any).downcast_ref::<MapExtensionType>() else {
+            return false;
+        };
+
+        self.key == other.key && self.value == other.value
+    }
+
+    fn dyn_hash(&self) -> u64 {
+        let mut hasher = PlFixedStateQuality::default().build_hasher();
+        POLARS_MAP_EXTENSION_NAME.hash(&mut hasher);
+        self.key.hash(&mut hasher);
+        self.value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn dyn_display(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("map[{},{}]", self.key, self.value))
+    }
+
+    fn dyn_debug(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("Map({:?}, {:?})", self.key, self.value))
+    }
+}
+
+pub(super) struct MapExtensionTypeFactory;
+
+impl ExtensionTypeFactory for MapExtensionTypeFactory {
+    fn create_type_instance(
+        &self,
+        name: &str,
+        storage: &DataType,
+        metadata: Option<&str>,
+    ) -> Box<dyn ExtensionTypeImpl> {
+        match map_storage_key_value_dtypes(storage) {
+            Some((key, value)) => Box::new(MapExtensionType::new(key.clone(), value.clone())),
+            None => Box::new(GenericExtensionType::new(
+                name.to_string(),
+                metadata.map(|s| s.to_string()),
+            )),
+        }
+    }
+}
+
+/// Returns the key and value dtypes if `storage` is a valid map storage type.
+pub(crate) fn map_storage_key_value_dtypes(storage: &DataType) -> Option<(&DataType, &DataType)> {
+    match storage {
+        DataType::List(inner) => match inner.as_ref() {
+            DataType::Struct(fields) if fields.len() == 2 => {
+                Some((fields[0].dtype(), fields[1].dtype()))
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether an Arrow `Map` field with the given metadata should be imported as a map.
+///
+/// Maps written by Polars are always imported as maps. Other Arrow maps are imported as
+/// maps too, unless `POLARS_IMPORT_ARROW_MAP_AS_MAP=0` opts out in favour of
+/// `List(Struct{key, value})`.
+pub(crate) fn import_arrow_map_as_map(md: Option<&Metadata>) -> bool {
+    md.is_some_and(|md| md.maintain_type()) || polars_config::config().import_arrow_map_as_map()
+}
@@ -3,16 +3,39 @@ use std::borrow::Cow;
 use std::fmt::{Debug, Display};
 use std::hash::{Hash, Hasher};
 
-use crate::datatypes::DataType;
+use crate::chunked_array::cast::CastOptions;
+use crate::datatypes::{AnyValue, DataType};
+use crate::error::PolarsResult;
+use crate::scalar::Scalar;
+use crate::series::Series;
 
 mod generic;
+#[cfg(feature = "dtype-geometry")]
+pub mod geometry;
+#[cfg(feature = "dtype-map")]
+mod map;
 mod registry;
+#[cfg(feature = "dtype-uuid")]
+mod uuid;
+#[cfg(feature = "dtype-variant")]
+mod variant;
 
 use generic::GenericExtensionType;
+#[cfg(feature = "dtype-map")]
+pub use map::MapExtensionType;
+#[cfg(feature = "dtype-map")]
+pub(crate) use map::{import_arrow_map_as_map, map_storage_key_value_dtypes};
 pub use registry::{
     UnknownExtensionTypeBehavior, get_extension_type_or_generic, get_extension_type_or_storage,
     register_extension_type, set_unknown_extension_type_behavior, unregister_extension_type,
 };
+#[cfg(feature = "dtype-variant")]
+pub use variant::VariantExtensionType;
+#[cfg(feature = "dtype-variant")]
+pub(crate) use variant::is_variant_storage;
+
+#[cfg(feature = "dtype-uuid")]
+pub use self::uuid::{UuidExtensionType, format_uuid, parse_uuid};
 
 pub trait ExtensionTypeFactory: 'static + Send + Sync {
     fn create_type_instance(
@@ -44,6 +67,69 @@ pub trait ExtensionTypeImpl: 'static + Send + Sync + Any {
     /// Should be a more verbose string representation, useful for debugging, in TitleCase,
     /// for example: String, Decimal(10, 2).
     fn dyn_debug(&self) -> Cow<'_, str>;
+
+    /// Format a single non-null value for display, given as a value of the storage type.
+    ///
+    /// Returns `None` to display the storage value as-is. An error is propagated where possible;
+    /// when displaying a `Series` or `DataFrame` it is shown as a warning instead, and the storage
+    /// value is displayed.
+    fn fmt_value(&self, _value: &AnyValue<'_>) -> PolarsResult<Option<String>> {
+        Ok(None)
+    }
+
+    /// Validate storage values before they are converted to this extension type.
+    fn validate_storage(&self, _storage: &Series) -> PolarsResult<()> {
+        Ok(())
+    }
+
+    /// Cast values of another dtype to the storage of this extension type.
+    ///
+    /// Returns `None` to cast the values to the storage dtype directly. The result is validated
+    /// with [`ExtensionTypeImpl::validate_storage`].
+    fn cast_from(&self, _s: &Series, _options: CastOptions) -> Option<PolarsResult<Series>> {
+        None
+    }
+
+    /// Cast the storage values of this extension type to `dtype`.
+    ///
+    /// Returns `None` to fall back to the default, which only allows casting to the storage dtype.
+    fn cast_to(
+        &self,
+        _storage: &Series,
+        _dtype: &DataType,
+        _options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        None
+    }
+
+    /// Get the keys by which the values are sorted and compared with `<`, `>`, `<=` and `>=`.
+    ///
+    /// The keys must have the same length as the storage. Returns `None` to order by the
+    /// storage values. Equality, hashing and grouping always use the storage values.
+    fn sort_key(&self, _storage: &Series) -> Option<PolarsResult<Series>> {
+        None
+    }
+
+    /// Aggregate the storage values of a single group, or of the whole column.
+    ///
+    /// The result must be a value of the storage type. Returns `None` if the aggregation is not
+    /// supported, which is the default.
+    fn aggregate(
+        &self,
+        _storage: &Series,
+        _agg: ExtensionAggregation,
+    ) -> Option<PolarsResult<Scalar>> {
+        None
+    }
+}
+
+/// An aggregation that can be customized by an extension type, see
+/// [`ExtensionTypeImpl::aggregate`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExtensionAggregation {
+    Min,
+    Max,
+    Sum,
 }
 
 #[repr(transparent)]
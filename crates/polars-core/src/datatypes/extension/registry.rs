@@ -87,9 +87,30 @@ pub fn get_extension_type_or_generic(
 #[allow(clippy::type_complexity)]
 static REGISTRY: LazyLock<RwLock<PlHashMap<PlSmallStr, Option<Arc<dyn ExtensionTypeFactory>>>>> =
     LazyLock::new(|| {
-        let mut m = PlHashMap::new();
+        let mut m: PlHashMap<PlSmallStr, Option<Arc<dyn ExtensionTypeFactory>>> = PlHashMap::new();
         m.insert(PlSmallStr::from_static(POLARS_OBJECT_EXTENSION_NAME), None);
+        #[cfg(feature = "dtype-uuid")]
+        m.insert(
+            PlSmallStr::from_static(ARROW_UUID_EXTENSION_NAME),
+            Some(Arc::new(super::uuid::UuidExtensionTypeFactory)),
+        );
+        #[cfg(not(feature = "dtype-uuid"))]
         m.insert(PlSmallStr::from_static(ARROW_UUID_EXTENSION_NAME), None);
+        #[cfg(feature = "dtype-map")]
+        m.insert(
+            PlSmallStr::from_static(crate::prelude::POLARS_MAP_EXTENSION_NAME),
+            Some(Arc::new(super::map::MapExtensionTypeFactory)),
+        );
+        #[cfg(feature = "dtype-geometry")]
+        m.insert(
+            PlSmallStr::from_static(crate::prelude::GEOARROW_WKB_EXTENSION_NAME),
+            Some(Arc::new(super::geometry::GeometryExtensionTypeFactory)),
+        );
+        #[cfg(feature = "dtype-variant")]
+        m.insert(
+            PlSmallStr::from_static(crate::prelude::ARROW_VARIANT_EXTENSION_NAME),
+            Some(Arc::new(super::variant::VariantExtensionTypeFactory)),
+        );
         RwLock::new(m)
     });
 
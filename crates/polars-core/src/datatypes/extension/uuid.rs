@@ -0,0 +1,141 @@
+use std::any::Any;
+use std::borrow::Cow;
+use std::hash::{BuildHasher, Hash, Hasher};
+
+use ::uuid::Uuid;
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
+use polars_utils::aliases::PlFixedStateQuality;
+
+use super::{ExtensionTypeFactory, ExtensionTypeImpl, GenericExtensionType};
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+/// The built-in UUID extension type.
+///
+/// A UUID is stored as a 16-byte `Binary` value in big-endian byte order, and is exported to and
+/// imported from the Arrow `arrow.uuid` canonical extension type (`FixedSizeBinary(16)`) and the
+/// Parquet UUID logical type.
+pub struct UuidExtensionType;
+
+impl ExtensionTypeImpl for UuidExtensionType {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(ARROW_UUID_EXTENSION_NAME)
+    }
+
+    fn serialize_metadata(&self) -> Option<Cow<'_, str>> {
+        None
+    }
+
+    fn dyn_clone(&self) -> Box<dyn ExtensionTypeImpl> {
+        Box::new(Self)
+    }
+
+    fn dyn_eq(&self, other: &dyn ExtensionTypeImpl) -> bool {
+        (other as &dyn Any).is::<UuidExtensionType>()
+    }
+
+    fn dyn_hash(&self) -> u64 {
+        let mut hasher = PlFixedStateQuality::default().build_hasher();
+        ARROW_UUID_EXTENSION_NAME.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn dyn_display(&self) -> Cow<'_, str> {
+        Cow::Borrowed("uuid")
+    }
+
+    fn dyn_debug(&self) -> Cow<'_, str> {
+        Cow::Borrowed("UUID")
+    }
+
+    fn fmt_value(&self, value: &AnyValue<'_>) -> PolarsResult<Option<String>> {
+        let bytes = match value {
+            AnyValue::Binary(b) => *b,
+            AnyValue::BinaryOwned(b) => b.as_slice(),
+            _ => return Ok(None),
+        };
+        let uuid = Uuid::from_slice(bytes).map_err(
+            |_| polars_err!(ComputeError: "invalid UUID: expected 16 bytes, got {}", bytes.len()),
+        )?;
+        Ok(Some(uuid.hyphenated().to_string()))
+    }
+
+    fn validate_storage(&self, storage: &Series) -> PolarsResult<()> {
+        for v in storage.binary()?.iter().flatten() {
+            polars_ensure!(
+                v.len() == 16,
+                ComputeError: "invalid UUID: expected 16 bytes, got {}", v.len()
+            );
+        }
+        Ok(())
+    }
+
+    fn cast_from(&self, s: &Series, options: CastOptions) -> Option<PolarsResult<Series>> {
+        match s.dtype() {
+            DataType::String => {
+                Some(parse_uuid(s.str().unwrap(), options.is_strict()).map(IntoSeries::into_series))
+            },
+            _ => None,
+        }
+    }
+
+    fn cast_to(
+        &self,
+        storage: &Series,
+        dtype: &DataType,
+        _options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        match dtype {
+            DataType::String => Some(Ok(format_uuid(storage.binary().unwrap()).into_series())),
+            _ => None,
+        }
+    }
+}
+
+pub(super) struct UuidExtensionTypeFactory;
+
+impl ExtensionTypeFactory for UuidExtensionTypeFactory {
+    fn create_type_instance(
+        &self,
+        name: &str,
+        storage: &DataType,
+        metadata: Option<&str>,
+    ) -> Box<dyn ExtensionTypeImpl> {
+        if storage == &DataType::Binary {
+            Box::new(UuidExtensionType)
+        } else {
+            Box::new(GenericExtensionType::new(
+                name.to_string(),
+                metadata.map(|s| s.to_string()),
+            ))
+        }
+    }
+}
+
+/// Parse UUID strings into their 16-byte representation.
+///
+/// Accepts the hyphenated, simple, braced and URN formats. If `strict`, invalid strings raise an
+/// error, otherwise they become null.
+pub fn parse_uuid(ca: &StringChunked, strict: bool) -> PolarsResult<BinaryChunked> {
+    let mut out = Vec::with_capacity(ca.len());
+    for opt_s in ca.iter() {
+        let v = match opt_s.map(|s| (s, Uuid::try_parse(s))) {
+            None => None,
+            Some((_, Ok(uuid))) => Some(uuid.into_bytes()),
+            Some((s, Err(_))) if strict => {
+                polars_bail!(InvalidOperation: "conversion from `str` to `uuid` failed for value '{s}'")
+            },
+            Some((_, Err(_))) => None,
+        };
+        out.push(v);
+    }
+    Ok(BinaryChunked::from_iter(out).with_name(ca.name().clone()))
+}
+
+/// Format 16-byte UUIDs as lowercase hyphenated strings.
+pub fn format_uuid(ca: &BinaryChunked) -> StringChunked {
+    ca.apply_into_string_amortized(|b, buf| {
+        let uuid = Uuid::from_slice(b).expect("UUIDs are 16 bytes");
+        buf.push_str(uuid.hyphenated().encode_lower(&mut Uuid::encode_buffer()));
+    })
+}
@@ -0,0 +1,81 @@
+use std::any::Any;
+use std::borrow::Cow;
+use std::hash::{BuildHasher, Hash, Hasher};
+
+use polars_utils::aliases::PlFixedStateQuality;
+
+use super::{ExtensionTypeFactory, ExtensionTypeImpl, GenericExtensionType};
+use crate::prelude::{ARROW_VARIANT_EXTENSION_NAME, DataType};
+
+/// The built-in variant extension type.
+///
+/// A variant stores a self-describing semi-structured value per row in the Parquet Variant
+/// binary encoding, as a `Struct{metadata: Binary, value: Binary}`. This matches the Arrow
+/// `arrow.parquet.variant` canonical extension type, so the logical type survives IO.
+pub struct VariantExtensionType;
+
+impl ExtensionTypeImpl for VariantExtensionType {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(ARROW_VARIANT_EXTENSION_NAME)
+    }
+
+    fn serialize_metadata(&self) -> Option<Cow<'_, str>> {
+        None
+    }
+
+    fn dyn_clone(&self) -> Box<dyn ExtensionTypeImpl> {
+        Box::new(Self)
+    }
+
+    fn dyn_eq(&self, other: &dyn ExtensionTypeImpl) -> bool {
+        (other as &dyn Any).is::<VariantExtensionType>()
+    }
+
+    fn dyn_hash(&self) -> u64 {
+        let mut hasher = PlFixedStateQuality::default().build_hasher();
+        ARROW_VARIANT_EXTENSION_NAME.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn dyn_display(&self) -> Cow<'_, str> {
+        Cow::Borrowed("variant")
+    }
+
+    fn dyn_debug(&self) -> Cow<'_, str> {
+        Cow::Borrowed("Variant")
+    }
+}
+
+pub(super) struct VariantExtensionTypeFactory;
+
+impl ExtensionTypeFactory for VariantExtensionTypeFactory {
+    fn create_type_instance(
+        &self,
+        name: &str,
+        storage: &DataType,
+        metadata: Option<&str>,
+    ) -> Box<dyn ExtensionTypeImpl> {
+        if is_variant_storage(storage) {
+            Box::new(VariantExtensionType)
+        } else {
+            Box::new(GenericExtensionType::new(
+                name.to_string(),
+                metadata.map(|s| s.to_string()),
+            ))
+        }
+    }
+}
+
+/// Whether `storage` is a valid (unshredded) variant storage type.
+pub(crate) fn is_variant_storage(storage: &DataType) -> bool {
+    match storage {
+        DataType::Struct(fields) => {
+            fields.len() == 2
+                && fields[0].name() == "metadata"
+                && fields[0].dtype() == &DataType::Binary
+                && fields[1].name() == "value"
+                && fields[1].dtype() == &DataType::Binary
+        },
+        _ => false,
+    }
+}
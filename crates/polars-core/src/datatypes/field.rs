@@ -1,5 +1,7 @@
 use arrow::datatypes::{IntervalUnit, Metadata};
 use polars_dtype::categorical::CategoricalPhysical;
+#[cfg(feature = "dtype-categorical")]
+use polars_dtype::categorical::decode_mapping;
 use polars_error::feature_gated;
 use polars_utils::pl_str::PlSmallStr;
 
@@ -7,6 +9,9 @@ use super::*;
 use crate::config::check_allow_importing_interval_as_struct;
 pub static POLARS_OBJECT_EXTENSION_NAME: &str = "_POLARS_PYTHON_OBJECT";
 pub static ARROW_UUID_EXTENSION_NAME: &str = "arrow.uuid";
+pub static POLARS_MAP_EXTENSION_NAME: &str = "polars.map";
+pub static ARROW_VARIANT_EXTENSION_NAME: &str = "arrow.parquet.variant";
+pub static GEOARROW_WKB_EXTENSION_NAME: &str = "geoarrow.wkb";
 
 /// Characterizes the name and the [`DataType`] of a column.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -239,6 +244,23 @@ impl DataType {
                         PlSmallStr::from_str(namespace),
                         physical,
                     );
+
+                    // Restore the ids of a persistent Categories. This only extends the
+                    // mapping, keeping it alive is left to the user. If the ids conflict with
+                    // the existing mapping the data is still correct, only the ids differ.
+                    if let Some(mapping_md) = md.and_then(|md| md.pl_categorical_mapping_metadata())
+                    {
+                        let restored = decode_mapping(mapping_md)
+                            .collect::<PolarsResult<Vec<_>>>()
+                            .and_then(|strings| cats.extend_mapping(strings));
+                        if let Err(e) = restored {
+                            polars_warn!(
+                                CategoricalRemappingWarning,
+                                "could not restore the categorical mapping of Categories '{}': {e}",
+                                cats.name()
+                            );
+                        }
+                    }
                     DataType::from_categories(cats)
                 } else if matches!(
                     value_type.as_ref(),
@@ -277,6 +299,12 @@ impl DataType {
                     None => storage,
                 }
             },
+            #[cfg(feature = "dtype-decimal256")]
+            ArrowDataType::Decimal256(precision, scale)
+                if *precision > polars_compute::decimal::DEC128_MAX_PREC =>
+            {
+                DataType::Decimal256(*precision, *scale)
+            },
             #[cfg(feature = "dtype-decimal")]
             ArrowDataType::Decimal(precision, scale)
             | ArrowDataType::Decimal32(precision, scale)
@@ -296,9 +324,24 @@ impl DataType {
             },
             ArrowDataType::LargeBinary | ArrowDataType::Binary => DataType::Binary,
             ArrowDataType::FixedSizeBinary(_) => DataType::Binary,
+            #[cfg(feature = "dtype-map")]
+            ArrowDataType::Map(inner, _is_sorted) => match Self::from_arrow_field(inner) {
+                DataType::Struct(fields)
+                    if fields.len() == 2
+                        && crate::datatypes::extension::import_arrow_map_as_map(md) =>
+                {
+                    DataType::new_map(fields[0].dtype.clone(), fields[1].dtype.clone())
+                },
+                dtype => DataType::List(dtype.boxed()),
+            },
+            #[cfg(not(feature = "dtype-map"))]
             ArrowDataType::Map(inner, _is_sorted) => {
                 DataType::List(Self::from_arrow_field(inner).boxed())
             },
+            ArrowDataType::RunEndEncoded(_, values) => DataType::from_arrow_field(values),
+            #[cfg(feature = "dtype-interval")]
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano) => DataType::Interval,
+            #[cfg(not(feature = "dtype-interval"))]
             ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
                 check_allow_importing_interval_as_struct("month_day_nano_interval").unwrap();
                 feature_gated!("dtype-struct", DataType::_month_days_ns_struct_type())
@@ -233,11 +233,15 @@ impl_polars_datatype!(BooleanType, DataType::Boolean, BooleanArray, 'a, bool, bo
 
 #[cfg(feature = "dtype-decimal")]
 impl_polars_datatype!(DecimalType, unimplemented!(), PrimitiveArray<i128>, 'a, i128, i128, i128, FalseT);
+#[cfg(feature = "dtype-decimal256")]
+impl_polars_datatype!(Decimal256Type, unimplemented!(), PrimitiveArray<i256>, 'a, i256, i256, i256, FalseT);
 impl_polars_datatype!(DatetimeType, unimplemented!(), PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT);
 impl_polars_datatype!(DurationType, unimplemented!(), PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT);
 impl_polars_datatype!(CategoricalType, unimplemented!(), PrimitiveArray<u32>, 'a, u32, u32, u32, FalseT);
 impl_polars_datatype!(DateType, DataType::Date, PrimitiveArray<i32>, 'a, i32, i32, i32, FalseT);
 impl_polars_datatype!(TimeType, DataType::Time, PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT);
+#[cfg(feature = "dtype-interval")]
+impl_polars_datatype!(IntervalType, DataType::Interval, PrimitiveArray<i128>, 'a, i128, i128, i128, FalseT);
 
 impl_polars_categorical_datatype!(Categorical8Type, UInt8Type, u8, U8);
 impl_polars_categorical_datatype!(Categorical16Type, UInt16Type, u16, U16);
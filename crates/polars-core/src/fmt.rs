@@ -218,6 +218,30 @@ macro_rules! format_array {
     }};
 }
 
+/// The values of an extension array, formatted with the custom formatting of its extension type.
+#[cfg(feature = "dtype-extension")]
+struct FormattedExtensionValues<'a>(&'a ExtensionChunked);
+
+#[cfg(feature = "dtype-extension")]
+impl FormattedExtensionValues<'_> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn dtype(&self) -> &DataType {
+        // Formatted values are not quoted, so they must not be truncated as strings.
+        static UNTRUNCATED: DataType = DataType::Null;
+        &UNTRUNCATED
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<String> {
+        match self.0.fmt_value_for_display(i) {
+            Some(v) => Ok(v),
+            None => Ok(self.0.get_any_value(i)?.to_string()),
+        }
+    }
+}
+
 #[cfg(feature = "object")]
 fn format_object_array(
     f: &mut Formatter<'_>,
@@ -393,6 +417,16 @@ impl Debug for Series {
             },
             #[cfg(feature = "dtype-time")]
             DataType::Time => format_array!(f, self.time().unwrap(), "time", self.name(), "Series"),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => {
+                format_array!(
+                    f,
+                    self.interval().unwrap(),
+                    "interval",
+                    self.name(),
+                    "Series"
+                )
+            },
             #[cfg(feature = "dtype-duration")]
             DataType::Duration(_) => {
                 let dt = format!("{}", self.dtype());
@@ -403,6 +437,11 @@ impl Debug for Series {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.decimal().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(_, _) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(f, self.decimal256().unwrap(), &dt, self.name(), "Series")
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => {
                 let dt = format!("{}", self.dtype());
@@ -453,7 +492,14 @@ impl Debug for Series {
             #[cfg(feature = "dtype-extension")]
             DataType::Extension(_, _) => {
                 let dt = format!("{}", self.dtype());
-                format_array!(f, self.ext().unwrap(), &dt, self.name(), "Series")
+                let ext = self.ext().unwrap();
+                let first_valid =
+                    crate::utils::first_non_null(self.chunks().iter().map(|a| a.as_ref()));
+                if first_valid.is_some_and(|i| ext.fmt_value_for_display(i).is_some()) {
+                    format_array!(f, FormattedExtensionValues(ext), &dt, self.name(), "Series")
+                } else {
+                    format_array!(f, ext, &dt, self.name(), "Series")
+                }
             },
             dt => panic!("{dt:?} not impl"),
         }
@@ -1201,6 +1247,8 @@ impl Display for AnyValue<'_> {
                 let nt: chrono::NaiveTime = self.into();
                 write!(f, "{nt}")
             },
+            #[cfg(feature = "dtype-interval")]
+            AnyValue::Interval(v) => fmt_interval(f, *v),
             #[cfg(feature = "dtype-categorical")]
             AnyValue::Categorical(_, _)
             | AnyValue::CategoricalOwned(_, _)
@@ -1226,6 +1274,8 @@ impl Display for AnyValue<'_> {
             AnyValue::StructOwned(payload) => fmt_struct(f, &payload.0),
             #[cfg(feature = "dtype-decimal")]
             AnyValue::Decimal(v, _prec, scale) => fmt_decimal(f, *v, *scale),
+            #[cfg(feature = "dtype-decimal256")]
+            AnyValue::Decimal256(v, _prec, scale) => fmt_decimal256(f, *v, *scale),
         }
     }
 }
@@ -1327,6 +1377,13 @@ fn fmt_decimal(f: &mut Formatter<'_>, v: i128, scale: usize) -> fmt::Result {
     f.write_str(fmt_float_string(fmt_buf.format_dec128(v, scale, trim_zeros, false)).as_str())
 }
 
+#[cfg(feature = "dtype-decimal256")]
+fn fmt_decimal256(f: &mut Formatter<'_>, v: i256, scale: usize) -> fmt::Result {
+    let mut fmt_buf = polars_compute::decimal::DecimalFmtBuffer::new();
+    let trim_zeros = get_trim_decimal_zeros();
+    f.write_str(fmt_float_string(fmt_buf.format_dec256(v, scale, trim_zeros, false)).as_str())
+}
+
 #[cfg(all(
     test,
     feature = "temporal",
@@ -97,8 +97,8 @@ impl DataFrameBuilder {
         assert!(self.builders.len() == columns.len());
         for (builder, column) in self.builders.iter_mut().zip(columns) {
             match column {
-                Column::Series(s) => {
-                    builder.subslice_extend(s, start, length, share);
+                Column::Series(_) | Column::RunEnd(_) => {
+                    builder.subslice_extend(column.as_materialized_series(), start, length, share);
                 },
                 Column::Scalar(sc) => {
                     let len = sc.len().saturating_sub(start).min(length);
@@ -125,8 +125,14 @@ impl DataFrameBuilder {
         assert!(self.builders.len() == columns.len());
         for (builder, column) in self.builders.iter_mut().zip(columns) {
             match column {
-                Column::Series(s) => {
-                    builder.subslice_extend_repeated(s, start, length, repeats, share);
+                Column::Series(_) | Column::RunEnd(_) => {
+                    builder.subslice_extend_repeated(
+                        column.as_materialized_series(),
+                        start,
+                        length,
+                        repeats,
+                        share,
+                    );
                 },
                 Column::Scalar(sc) => {
                     let len = sc.len().saturating_sub(start).min(length);
@@ -154,8 +160,14 @@ impl DataFrameBuilder {
         assert!(self.builders.len() == columns.len());
         for (builder, column) in self.builders.iter_mut().zip(columns) {
             match column {
-                Column::Series(s) => {
-                    builder.subslice_extend_each_repeated(s, start, length, repeats, share);
+                Column::Series(_) | Column::RunEnd(_) => {
+                    builder.subslice_extend_each_repeated(
+                        column.as_materialized_series(),
+                        start,
+                        length,
+                        repeats,
+                        share,
+                    );
                 },
                 Column::Scalar(sc) => {
                     let len = sc.len().saturating_sub(start).min(length);
@@ -185,8 +197,8 @@ impl DataFrameBuilder {
         assert!(self.builders.len() == columns.len());
         for (builder, column) in self.builders.iter_mut().zip(columns) {
             match column {
-                Column::Series(s) => {
-                    builder.gather_extend(s, idxs, share);
+                Column::Series(_) | Column::RunEnd(_) => {
+                    builder.gather_extend(column.as_materialized_series(), idxs, share);
                 },
                 Column::Scalar(sc) => {
                     let scalar_as_series = sc.scalar().clone().into_series(PlSmallStr::default());
@@ -209,8 +221,8 @@ impl DataFrameBuilder {
         assert!(self.builders.len() == columns.len());
         for (builder, column) in self.builders.iter_mut().zip(columns) {
             match column {
-                Column::Series(s) => {
-                    builder.opt_gather_extend(s, idxs, share);
+                Column::Series(_) | Column::RunEnd(_) => {
+                    builder.opt_gather_extend(column.as_materialized_series(), idxs, share);
                 },
                 Column::Scalar(sc) => {
                     let scalar_as_series = sc.scalar().clone().into_series(PlSmallStr::default());
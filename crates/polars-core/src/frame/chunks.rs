@@ -17,7 +17,7 @@ impl From<RecordBatch> for DataFrame {
                 // SAFETY: Record Batch has the invariant that the schema datatype matches the
                 // columns.
                 unsafe {
-                    Series::_try_from_arrow_unchecked_with_md(
+                    Column::_try_from_arrow_unchecked_with_md(
                         name.clone(),
                         vec![arr],
                         field.dtype(),
@@ -25,7 +25,6 @@ impl From<RecordBatch> for DataFrame {
                     )
                 }
                 .unwrap()
-                .into_column()
             })
             .collect();
 
@@ -55,7 +54,9 @@ impl DataFrame {
                 .iter()
                 .map(|col| match col {
                     Column::Series(s) => Column::from(s.select_chunk(i)),
-                    Column::Scalar(_) => col.slice(prev_height as i64, chunk_size),
+                    Column::Scalar(_) | Column::RunEnd(_) => {
+                        col.slice(prev_height as i64, chunk_size)
+                    },
                 })
                 .collect::<Vec<_>>();
 
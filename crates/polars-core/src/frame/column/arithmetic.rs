@@ -14,6 +14,7 @@ fn num_op_with_broadcast<T: Num + NumCast, F: Fn(&Series, T) -> Series>(
             ScalarColumn::from_single_value_series(op(&s.as_single_value_series(), n), s.len())
                 .into()
         },
+        Column::RunEnd(s) => s.with_values(op(s.values(), n)).into(),
     }
 }
 
@@ -16,6 +16,22 @@ macro_rules! column_element_wise_broadcasting {
                     }
                 })
             },
+            // Compare the value of every run and expand the result to the runs.
+            (Column::RunEnd(lhs), Column::Scalar(rhs))
+                if lhs.len() == rhs.len() || rhs.len() == 1 =>
+            {
+                $op(lhs.values(), &rhs.as_single_value_series()).map(|ca| lhs.expand_mask(&ca))
+            },
+            (Column::Scalar(lhs), Column::RunEnd(rhs))
+                if lhs.len() == rhs.len() || lhs.len() == 1 =>
+            {
+                $op(&lhs.as_single_value_series(), rhs.values()).map(|ca| rhs.expand_mask(&ca))
+            },
+            (Column::RunEnd(lhs), Column::RunEnd(rhs))
+                if lhs.run_ends()[..] == rhs.run_ends()[..] =>
+            {
+                $op(lhs.values(), rhs.values()).map(|ca| lhs.expand_mask(&ca))
+            },
             (lhs, rhs) => $op(lhs.as_materialized_series(), rhs.as_materialized_series()),
         }
     };
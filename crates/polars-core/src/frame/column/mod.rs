@@ -8,6 +8,7 @@ use polars_error::PolarsResult;
 use polars_utils::aliases::PlSeedableRandomStateQuality;
 use polars_utils::index::check_bounds;
 use polars_utils::pl_str::PlSmallStr;
+pub use run_end::RunEndColumn;
 pub use scalar::ScalarColumn;
 
 use self::compare_inner::{TotalEqInner, TotalOrdInner};
@@ -23,6 +24,7 @@ use crate::{HEAD_DEFAULT_LENGTH, TAIL_DEFAULT_LENGTH};
 
 mod arithmetic;
 mod compare;
+mod run_end;
 mod scalar;
 mod series;
 
@@ -32,15 +34,17 @@ mod series;
 /// [`as_materialized_series`][Column::as_materialized_series] and
 /// [`take_materialized_series`][Column::take_materialized_series].
 ///
-/// Currently, there are two ways to represent a [`Column`].
+/// Currently, there are three ways to represent a [`Column`].
 /// 1. A [`Series`] of values
 /// 2. A [`ScalarColumn`] that repeats a single [`Scalar`]
+/// 3. A [`RunEndColumn`] that stores runs of repeated values
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub enum Column {
     Series(SeriesColumn),
     Scalar(ScalarColumn),
+    RunEnd(RunEndColumn),
 }
 
 /// Convert `Self` into a [`Column`]
@@ -69,6 +73,57 @@ impl Column {
         Self::Scalar(ScalarColumn::new(name, scalar, length))
     }
 
+    /// Encode this [`Column`] as a [`RunEndColumn`] of runs of consecutive equal values.
+    pub fn run_end_encode(&self) -> PolarsResult<Column> {
+        match self {
+            Column::Series(s) => RunEndColumn::from_series(s).map(Column::from),
+            Column::Scalar(s) if !s.is_empty() => RunEndColumn::try_new(
+                s.name().clone(),
+                s.as_single_value_series(),
+                vec![s.len() as IdxSize].into(),
+            )
+            .map(Column::from),
+            Column::Scalar(s) => Ok(RunEndColumn::new_empty(s.name().clone(), s.dtype()).into()),
+            Column::RunEnd(_) => Ok(self.clone()),
+        }
+    }
+
+    /// Create a new [`Column`] from Arrow arrays without checking if the dtype of the chunks is
+    /// correct.
+    ///
+    /// `RunEndEncoded` arrays are kept encoded as a [`RunEndColumn`], all other arrays are
+    /// converted into a [`Series`].
+    ///
+    /// # Safety
+    /// The caller must ensure that the given `dtype` matches all the `ArrayRef` dtypes.
+    pub unsafe fn _try_from_arrow_unchecked_with_md(
+        name: PlSmallStr,
+        chunks: Vec<ArrayRef>,
+        dtype: &ArrowDataType,
+        md: Option<&arrow::datatypes::Metadata>,
+    ) -> PolarsResult<Column> {
+        if !matches!(dtype, ArrowDataType::RunEndEncoded(_, _)) {
+            return unsafe { Series::_try_from_arrow_unchecked_with_md(name, chunks, dtype, md) }
+                .map(Column::from);
+        }
+
+        let mut out: Option<RunEndColumn> = None;
+        for arr in &chunks {
+            let arr = arr
+                .as_any()
+                .downcast_ref::<arrow::array::RunEndEncodedArray>()
+                .unwrap();
+            let column = RunEndColumn::from_arrow(name.clone(), arr, md)?;
+            match &mut out {
+                None => out = Some(column),
+                Some(out) => _ = out.append(&column)?,
+            }
+        }
+        let out =
+            out.unwrap_or_else(|| RunEndColumn::new_empty(name, &DataType::from_arrow(dtype, md)));
+        Ok(out.into())
+    }
+
     pub fn new_row_index(name: PlSmallStr, offset: IdxSize, length: usize) -> PolarsResult<Column> {
         let Ok(length) = IdxSize::try_from(length) else {
             polars_bail!(
@@ -105,6 +160,7 @@ impl Column {
         match self {
             Column::Series(s) => s,
             Column::Scalar(s) => s.as_materialized_series(),
+            Column::RunEnd(s) => s.as_materialized_series(),
         }
     }
 
@@ -122,6 +178,7 @@ impl Column {
     ///
     /// * For `Column::Series` columns, simply returns the inner `Series`.
     /// * For `Column::Scalar` columns, returns an empty or unit length series.
+    /// * For `Column::RunEnd` columns, returns the value of every run.
     ///
     /// # Note
     /// This method is safe to use. However, care must be taken when operating on the returned
@@ -131,6 +188,7 @@ impl Column {
         match self {
             Column::Series(s) => (**s).clone(),
             Column::Scalar(s) => s.as_single_value_series(),
+            Column::RunEnd(s) => s.values().clone(),
         }
     }
 
@@ -143,6 +201,7 @@ impl Column {
     /// * `self` is `Column::Scalar` and if either:
     ///   * `self` is not empty and `new_s` is not of unit length.
     ///   * `self` is empty and `new_s` is not empty.
+    /// * `self` is `Column::RunEnd` and `new_s` does not have one value per run.
     pub fn _to_new_from_backing(&self, new_s: Series) -> Self {
         match self {
             Column::Series(s) => {
@@ -153,6 +212,7 @@ impl Column {
                 assert_eq!(new_s.len(), s.as_single_value_series().len());
                 Column::Scalar(ScalarColumn::from_single_value_series(new_s, self.len()))
             },
+            Column::RunEnd(s) => Column::RunEnd(s.with_values(new_s)),
         }
     }
 
@@ -175,6 +235,18 @@ impl Column {
                 };
                 s
             },
+            Column::RunEnd(s) => {
+                let series = std::mem::replace(
+                    s,
+                    RunEndColumn::new_empty(PlSmallStr::EMPTY, &DataType::Null),
+                )
+                .take_materialized_series();
+                *self = Column::Series(series.into());
+                let Column::Series(s) = self else {
+                    unreachable!();
+                };
+                s
+            },
         }
     }
     /// Take [`Series`] from a [`Column`]
@@ -185,6 +257,7 @@ impl Column {
         match self {
             Column::Series(s) => s.take(),
             Column::Scalar(s) => s.take_materialized_series(),
+            Column::RunEnd(s) => s.take_materialized_series(),
         }
     }
 
@@ -193,6 +266,7 @@ impl Column {
         match self {
             Column::Series(s) => s.dtype(),
             Column::Scalar(s) => s.dtype(),
+            Column::RunEnd(s) => s.dtype(),
         }
     }
 
@@ -204,6 +278,10 @@ impl Column {
                 None => Cow::Owned(Field::new(s.name().clone(), s.dtype().clone())),
                 Some(s) => s.field(),
             },
+            Column::RunEnd(s) => match s.lazy_as_materialized_series() {
+                None => Cow::Owned(Field::new(s.name().clone(), s.dtype().clone())),
+                Some(s) => s.field(),
+            },
         }
     }
 
@@ -212,6 +290,7 @@ impl Column {
         match self {
             Column::Series(s) => s.name(),
             Column::Scalar(s) => s.name(),
+            Column::RunEnd(s) => s.name(),
         }
     }
 
@@ -220,6 +299,7 @@ impl Column {
         match self {
             Column::Series(s) => s.len(),
             Column::Scalar(s) => s.len(),
+            Column::RunEnd(s) => s.len(),
         }
     }
 
@@ -234,6 +314,7 @@ impl Column {
         match self {
             Column::Series(s) => _ = s.rename(name),
             Column::Scalar(s) => _ = s.rename(name),
+            Column::RunEnd(s) => _ = s.rename(name),
         }
     }
 
@@ -259,6 +340,13 @@ impl Column {
             _ => None,
         }
     }
+    #[inline]
+    pub fn as_run_end_column(&self) -> Option<&RunEndColumn> {
+        match self {
+            Column::RunEnd(s) => Some(s),
+            _ => None,
+        }
+    }
 
     // # Try to Chunked Arrays
     pub fn try_bool(&self) -> Option<&BooleanChunked> {
@@ -465,18 +553,21 @@ impl Column {
         match self {
             Column::Series(s) => s.cast_with_options(dtype, options).map(Column::from),
             Column::Scalar(s) => s.cast_with_options(dtype, options).map(Column::from),
+            Column::RunEnd(s) => s.cast_with_options(dtype, options).map(Column::from),
         }
     }
     pub fn strict_cast(&self, dtype: &DataType) -> PolarsResult<Self> {
         match self {
             Column::Series(s) => s.strict_cast(dtype).map(Column::from),
             Column::Scalar(s) => s.strict_cast(dtype).map(Column::from),
+            Column::RunEnd(s) => s.strict_cast(dtype).map(Column::from),
         }
     }
     pub fn cast(&self, dtype: &DataType) -> PolarsResult<Column> {
         match self {
             Column::Series(s) => s.cast(dtype).map(Column::from),
             Column::Scalar(s) => s.cast(dtype).map(Column::from),
+            Column::RunEnd(s) => s.cast(dtype).map(Column::from),
         }
     }
     /// # Safety
@@ -486,6 +577,7 @@ impl Column {
         match self {
             Column::Series(s) => unsafe { s.cast_unchecked(dtype) }.map(Column::from),
             Column::Scalar(s) => unsafe { s.cast_unchecked(dtype) }.map(Column::from),
+            Column::RunEnd(s) => unsafe { s.cast_unchecked(dtype) }.map(Column::from),
         }
     }
 
@@ -494,6 +586,7 @@ impl Column {
         match self {
             Column::Series(s) => s.clear().into(),
             Column::Scalar(s) => s.resize(0).into(),
+            Column::RunEnd(s) => s.slice(0, 0).into(),
         }
     }
 
@@ -502,6 +595,7 @@ impl Column {
         match self {
             Column::Series(s) => s.shrink_to_fit(),
             Column::Scalar(_) => {},
+            Column::RunEnd(s) => s.shrink_to_fit(),
         }
     }
 
@@ -512,9 +606,9 @@ impl Column {
         }
 
         match self {
-            Column::Series(s) => {
+            Column::Series(_) | Column::RunEnd(_) => {
                 // SAFETY: Bounds check done before.
-                let av = unsafe { s.get_unchecked(index) };
+                let av = unsafe { self.get_unchecked(index) };
                 let scalar = Scalar::new(self.dtype().clone(), av.into_static());
                 Self::new_scalar(self.name().clone(), scalar, length)
             },
@@ -527,6 +621,7 @@ impl Column {
         match self {
             Self::Series(s) => s.has_nulls(),
             Self::Scalar(s) => s.has_nulls(),
+            Self::RunEnd(s) => s.has_nulls(),
         }
     }
 
@@ -537,6 +632,7 @@ impl Column {
             Self::Scalar(s) => {
                 BooleanChunked::full(s.name().clone(), s.scalar().is_null(), s.len())
             },
+            Self::RunEnd(s) => s.is_null(),
         }
     }
     #[inline]
@@ -546,6 +642,7 @@ impl Column {
             Self::Scalar(s) => {
                 BooleanChunked::full(s.name().clone(), !s.scalar().is_null(), s.len())
             },
+            Self::RunEnd(s) => s.is_not_null(),
         }
     }
 
@@ -584,6 +681,7 @@ impl Column {
                 let (_, length) = slice_offsets(offset, length, s.len());
                 s.resize(length).into()
             },
+            Column::RunEnd(s) => s.slice(offset, length).into(),
         }
     }
 
@@ -599,6 +697,7 @@ impl Column {
             Self::Series(s) => s.null_count(),
             Self::Scalar(s) if s.scalar().is_null() => s.len(),
             Self::Scalar(_) => 0,
+            Self::RunEnd(s) => s.null_count(),
         }
     }
 
@@ -606,6 +705,7 @@ impl Column {
         match self {
             Self::Series(s) => crate::utils::first_non_null(s.chunks().iter().map(|a| a.as_ref())),
             Self::Scalar(s) => (!s.scalar().is_null() && !s.is_empty()).then_some(0),
+            Self::RunEnd(s) => s.first_non_null(),
         }
     }
 
@@ -615,6 +715,7 @@ impl Column {
                 crate::utils::last_non_null(s.chunks().iter().map(|a| a.as_ref()), s.len())
             },
             Self::Scalar(s) => (!s.scalar().is_null() && !s.is_empty()).then(|| s.len() - 1),
+            Self::RunEnd(s) => s.last_non_null(),
         }
     }
 
@@ -663,6 +764,7 @@ impl Column {
                         .into_column()
                 }
             },
+            Self::RunEnd(s) => unsafe { s.take_unchecked(indices) }.into(),
         }
     }
     /// # Safety
@@ -679,6 +781,7 @@ impl Column {
                 indices.len(),
             )
             .into(),
+            Self::RunEnd(s) => unsafe { s.take_slice_unchecked(indices) }.into(),
         }
     }
 
@@ -692,6 +795,7 @@ impl Column {
     ) -> Column {
         match self {
             Column::Series(s) => series_agg(s, groups).into_column(),
+            Column::RunEnd(s) => series_agg(s.as_materialized_series(), groups).into_column(),
             Column::Scalar(s) => {
                 if s.is_empty() {
                     return series_agg(s.as_materialized_series(), groups).into_column();
@@ -767,6 +871,32 @@ impl Column {
         self.agg_with_scalar_identity(groups, |s, g| unsafe { s.agg_max(g) })
     }
 
+    /// Fallible [`agg_min`](Self::agg_min), which propagates errors of extension types.
+    ///
+    /// # Safety
+    ///
+    /// Does no bounds checks, groups must be correct.
+    #[cfg(feature = "algorithm_group_by")]
+    pub unsafe fn try_agg_min(&self, groups: &GroupsType) -> PolarsResult<Self> {
+        if self.dtype().is_extension() {
+            return unsafe { self.as_materialized_series().try_agg_min(groups) }.map(Self::from);
+        }
+        Ok(unsafe { self.agg_min(groups) })
+    }
+
+    /// Fallible [`agg_max`](Self::agg_max), which propagates errors of extension types.
+    ///
+    /// # Safety
+    ///
+    /// Does no bounds checks, groups must be correct.
+    #[cfg(feature = "algorithm_group_by")]
+    pub unsafe fn try_agg_max(&self, groups: &GroupsType) -> PolarsResult<Self> {
+        if self.dtype().is_extension() {
+            return unsafe { self.as_materialized_series().try_agg_max(groups) }.map(Self::from);
+        }
+        Ok(unsafe { self.agg_max(groups) })
+    }
+
     /// # Safety
     ///
     /// Does no bounds checks, groups must be correct.
@@ -782,6 +912,9 @@ impl Column {
     pub unsafe fn agg_arg_min(&self, groups: &GroupsType) -> Self {
         match self {
             Column::Series(s) => unsafe { Column::from(s.agg_arg_min(groups)) },
+            Column::RunEnd(s) => unsafe {
+                Column::from(s.as_materialized_series().agg_arg_min(groups))
+            },
             Column::Scalar(sc) => {
                 let scalar = if sc.is_empty() || sc.has_nulls() {
                     Scalar::null(IDX_DTYPE)
@@ -800,6 +933,9 @@ impl Column {
     pub unsafe fn agg_arg_max(&self, groups: &GroupsType) -> Self {
         match self {
             Column::Series(s) => unsafe { Column::from(s.agg_arg_max(groups)) },
+            Column::RunEnd(s) => unsafe {
+                Column::from(s.as_materialized_series().agg_arg_max(groups))
+            },
             Column::Scalar(sc) => {
                 let scalar = if sc.is_empty() || sc.has_nulls() {
                     Scalar::null(IDX_DTYPE)
@@ -820,6 +956,17 @@ impl Column {
         unsafe { self.as_materialized_series().agg_sum(groups) }.into()
     }
 
+    /// Fallible [`agg_sum`](Self::agg_sum), which propagates errors of extension types.
+    ///
+    /// # Safety
+    ///
+    /// Does no bounds checks, groups must be correct.
+    #[cfg(feature = "algorithm_group_by")]
+    pub unsafe fn try_agg_sum(&self, groups: &GroupsType) -> PolarsResult<Self> {
+        // @scalar-opt
+        unsafe { self.as_materialized_series().try_agg_sum(groups) }.map(Self::from)
+    }
+
     /// # Safety
     ///
     /// Does no bounds checks, groups must be correct.
@@ -959,6 +1106,7 @@ impl Column {
         match self {
             Column::Series(s) => s.reverse().into(),
             Column::Scalar(_) => self.clone(),
+            Column::RunEnd(s) => s.reverse().into(),
         }
     }
 
@@ -979,6 +1127,7 @@ impl Column {
         match self {
             Column::Series(s) => s.set_sorted_flag(sorted),
             Column::Scalar(_) => {},
+            Column::RunEnd(s) => s.set_sorted_flag(sorted),
         }
     }
 
@@ -988,6 +1137,8 @@ impl Column {
             Column::Scalar(_) => {
                 StatisticsFlags::IS_SORTED_ASC | StatisticsFlags::CAN_FAST_EXPLODE_LIST
             },
+            // Repeating the values of the runs does not change these flags.
+            Column::RunEnd(s) => s.values().get_flags(),
         }
     }
 
@@ -998,7 +1149,7 @@ impl Column {
                 s.set_flags(flags);
                 true
             },
-            Column::Scalar(_) => false,
+            Column::Scalar(_) | Column::RunEnd(_) => false,
         }
     }
 
@@ -1022,17 +1173,35 @@ impl Column {
     }
 
     pub fn append(&mut self, other: &Column) -> PolarsResult<&mut Self> {
+        if let (Column::RunEnd(lhs), Column::RunEnd(rhs)) = (&mut *self, other) {
+            lhs.append(rhs)?;
+            return Ok(self);
+        }
+
         // @scalar-opt
         self.into_materialized_series()
             .append(other.as_materialized_series())?;
         Ok(self)
     }
     pub fn append_owned(&mut self, other: Column) -> PolarsResult<&mut Self> {
+        if let (Column::RunEnd(lhs), Column::RunEnd(rhs)) = (&mut *self, &other) {
+            lhs.append(rhs)?;
+            return Ok(self);
+        }
+
         self.into_materialized_series()
             .append_owned(other.take_materialized_series())?;
         Ok(self)
     }
 
+    /// Fallible [`arg_sort`](Self::arg_sort), which propagates errors of extension types.
+    pub fn try_arg_sort(&self, options: SortOptions) -> PolarsResult<IdxCa> {
+        if self.dtype().is_extension() && !self.is_empty() {
+            return self.as_materialized_series().try_arg_sort(options);
+        }
+        Ok(self.arg_sort(options))
+    }
+
     pub fn arg_sort(&self, options: SortOptions) -> IdxCa {
         if self.is_empty() {
             return IdxCa::from_vec(self.name().clone(), Vec::new());
@@ -1185,6 +1354,16 @@ impl Column {
         self.as_materialized_series().arg_sort_multiple(by, options)
     }
 
+    #[cfg(feature = "algorithm_group_by")]
+    pub fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        match self {
+            Column::RunEnd(s) => s.group_tuples(multithreaded, sorted),
+            _ => self
+                .as_materialized_series()
+                .group_tuples(multithreaded, sorted),
+        }
+    }
+
     pub fn arg_unique(&self) -> PolarsResult<IdxCa> {
         match self {
             Column::Scalar(s) => Ok(IdxCa::new_vec(s.name().clone(), vec![0])),
@@ -1226,6 +1405,7 @@ impl Column {
                     self.clone()
                 }
             },
+            Column::RunEnd(s) => s.rechunk().into(),
         }
     }
 
@@ -1281,6 +1461,7 @@ impl Column {
         match self {
             Column::Series(s) => s.drop_nulls().into_column(),
             Column::Scalar(s) => s.drop_nulls().into_column(),
+            Column::RunEnd(s) => s.drop_nulls().into_column(),
         }
     }
 
@@ -1294,6 +1475,7 @@ impl Column {
         match self {
             Column::Series(s) => s.is_sorted_flag(),
             Column::Scalar(_) => IsSorted::Ascending,
+            Column::RunEnd(s) => s.is_sorted_flag(),
         }
     }
 
@@ -1308,6 +1490,7 @@ impl Column {
 
                 Ok(s.resize(1).into_column())
             },
+            Column::RunEnd(s) => s.values().unique().map(Column::from),
         }
     }
     pub fn unique_stable(&self) -> PolarsResult<Column> {
@@ -1321,6 +1504,7 @@ impl Column {
 
                 Ok(s.resize(1).into_column())
             },
+            Column::RunEnd(s) => s.values().unique_stable().map(Column::from),
         }
     }
 
@@ -1364,6 +1548,17 @@ impl Column {
 
                 Ok(s.resize(filter.sum().unwrap() as usize).into_column())
             },
+            Column::RunEnd(s) => {
+                // Broadcasting
+                if filter.len() == 1 && s.len() != 1 {
+                    return match filter.get(0) {
+                        Some(true) => Ok(s.clone().into_column()),
+                        _ => Ok(s.slice(0, 0).into_column()),
+                    };
+                }
+
+                s.filter(filter).map(Column::from)
+            },
         }
     }
 
@@ -1411,6 +1606,14 @@ impl Column {
                 let total = s.len() - offset;
                 Ok(s.resize(1 + (total - 1) / n).into())
             },
+            Column::RunEnd(s) => {
+                let indices = (offset..s.len())
+                    .step_by(n)
+                    .map(|i| i as IdxSize)
+                    .collect::<Vec<_>>();
+                // SAFETY: All indices are smaller than the length.
+                Ok(unsafe { s.take_slice_unchecked(&indices) }.into())
+            },
         }
     }
 
@@ -1434,6 +1637,7 @@ impl Column {
                         .map(Column::from)
                 }
             },
+            Column::RunEnd(s) => s.extend_constant(value, n).map(Column::from),
         }
     }
 
@@ -1487,6 +1691,7 @@ impl Column {
         match self {
             Column::Series(s) => unsafe { s.get_unchecked(index) },
             Column::Scalar(s) => s.scalar().as_any_value(),
+            Column::RunEnd(s) => unsafe { s.get_unchecked(index) },
         }
     }
 
@@ -1534,12 +1739,17 @@ impl Column {
     }
 
     pub(crate) fn str_value(&self, index: usize) -> PolarsResult<Cow<'_, str>> {
+        #[cfg(feature = "dtype-extension")]
+        if self.dtype().is_extension() {
+            return self.as_materialized_series().str_value(index);
+        }
         Ok(self.get(index)?.str_value())
     }
 
     pub fn min_reduce(&self) -> PolarsResult<Scalar> {
         match self {
             Column::Series(s) => s.min_reduce(),
+            Column::RunEnd(s) => s.values().min_reduce(),
             Column::Scalar(s) => {
                 // We don't really want to deal with handling the full semantics here so we just
                 // cast to a single value series. This is a tiny bit wasteful, but probably fine.
@@ -1550,6 +1760,7 @@ impl Column {
     pub fn max_reduce(&self) -> PolarsResult<Scalar> {
         match self {
             Column::Series(s) => s.max_reduce(),
+            Column::RunEnd(s) => s.values().max_reduce(),
             Column::Scalar(s) => {
                 // We don't really want to deal with handling the full semantics here so we just
                 // cast to a single value series. This is a tiny bit wasteful, but probably fine.
@@ -1560,6 +1771,7 @@ impl Column {
     pub fn median_reduce(&self) -> PolarsResult<Scalar> {
         match self {
             Column::Series(s) => s.median_reduce(),
+            Column::RunEnd(s) => s.as_materialized_series().median_reduce(),
             Column::Scalar(s) => {
                 // We don't really want to deal with handling the full semantics here so we just
                 // cast to a single value series. This is a tiny bit wasteful, but probably fine.
@@ -1570,6 +1782,7 @@ impl Column {
     pub fn mean_reduce(&self) -> PolarsResult<Scalar> {
         match self {
             Column::Series(s) => s.mean_reduce(),
+            Column::RunEnd(s) => s.as_materialized_series().mean_reduce(),
             Column::Scalar(s) => {
                 // We don't really want to deal with handling the full semantics here so we just
                 // cast to a single value series. This is a tiny bit wasteful, but probably fine.
@@ -1580,6 +1793,7 @@ impl Column {
     pub fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
         match self {
             Column::Series(s) => s.std_reduce(ddof),
+            Column::RunEnd(s) => s.as_materialized_series().std_reduce(ddof),
             Column::Scalar(s) => {
                 // We don't really want to deal with handling the full semantics here so we just
                 // cast to a small series. This is a tiny bit wasteful, but probably fine.
@@ -1591,6 +1805,7 @@ impl Column {
     pub fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
         match self {
             Column::Series(s) => s.var_reduce(ddof),
+            Column::RunEnd(s) => s.as_materialized_series().var_reduce(ddof),
             Column::Scalar(s) => {
                 // We don't really want to deal with handling the full semantics here so we just
                 // cast to a small series. This is a tiny bit wasteful, but probably fine.
@@ -1606,6 +1821,7 @@ impl Column {
     pub fn and_reduce(&self) -> PolarsResult<Scalar> {
         match self {
             Column::Series(s) => s.and_reduce(),
+            Column::RunEnd(s) => s.values().and_reduce(),
             Column::Scalar(s) => {
                 // We don't really want to deal with handling the full semantics here so we just
                 // cast to a single value series. This is a tiny bit wasteful, but probably fine.
@@ -1616,6 +1832,7 @@ impl Column {
     pub fn or_reduce(&self) -> PolarsResult<Scalar> {
         match self {
             Column::Series(s) => s.or_reduce(),
+            Column::RunEnd(s) => s.values().or_reduce(),
             Column::Scalar(s) => {
                 // We don't really want to deal with handling the full semantics here so we just
                 // cast to a single value series. This is a tiny bit wasteful, but probably fine.
@@ -1626,6 +1843,7 @@ impl Column {
     pub fn xor_reduce(&self) -> PolarsResult<Scalar> {
         match self {
             Column::Series(s) => s.xor_reduce(),
+            Column::RunEnd(s) => s.as_materialized_series().xor_reduce(),
             Column::Scalar(s) => {
                 // We don't really want to deal with handling the full semantics here so we just
                 // cast to a single value series. This is a tiny bit wasteful, but probably fine.
@@ -1641,6 +1859,7 @@ impl Column {
         match self {
             Column::Series(s) => s.n_unique(),
             Column::Scalar(s) => s.as_single_value_series().n_unique(),
+            Column::RunEnd(s) => s.values().n_unique(),
         }
     }
 
@@ -1666,6 +1885,10 @@ impl Column {
     pub fn sort_with(&self, options: SortOptions) -> PolarsResult<Self> {
         match self {
             Column::Series(s) => s.sort_with(options).map(Self::from),
+            Column::RunEnd(s) => s
+                .as_materialized_series()
+                .sort_with(options)
+                .map(Self::from),
             Column::Scalar(s) => {
                 // This makes this function throw the same errors as Series::sort_with
                 _ = s.as_single_value_series().sort_with(options)?;
@@ -1689,6 +1912,7 @@ impl Column {
         match self {
             Column::Series(s) => f(s),
             Column::Scalar(s) => Ok(f(&s.as_single_value_series())?.new_from_index(0, s.len())),
+            Column::RunEnd(s) => Ok(s.expand_mask(&f(s.values())?)),
         }
     }
 
@@ -1706,6 +1930,7 @@ impl Column {
                 s.len(),
             )
             .into()),
+            Column::RunEnd(s) => s.try_apply_unary_elementwise(f).map(Column::from),
         }
     }
 
@@ -1750,6 +1975,15 @@ impl Column {
 
                 Ok(ScalarColumn::from_single_value_series(op(&lhs, &rhs)?, length).into_column())
             },
+            (Column::RunEnd(lhs), Column::Scalar(rhs)) if lhs.len() == length => lhs
+                .try_apply_unary_elementwise(|v| op(v, &rhs.as_single_value_series()))
+                .map(Column::from),
+            (Column::Scalar(lhs), Column::RunEnd(rhs)) if rhs.len() == length => rhs
+                .try_apply_unary_elementwise(|v| op(&lhs.as_single_value_series(), v))
+                .map(Column::from),
+            (lhs, rhs) => {
+                op(lhs.as_materialized_series(), rhs.as_materialized_series()).map(Column::from)
+            },
         }
     }
 
@@ -1790,6 +2024,15 @@ impl Column {
                         .into_column(),
                 )
             },
+            (Column::RunEnd(lhs), Column::Scalar(rhs)) => lhs
+                .try_apply_unary_elementwise(|v| f_rb(v, rhs.scalar()))
+                .map(Column::from),
+            (Column::Scalar(lhs), Column::RunEnd(rhs)) => rhs
+                .try_apply_unary_elementwise(|v| f_lb(lhs.scalar(), v))
+                .map(Column::from),
+            (lhs, rhs) => {
+                f(lhs.as_materialized_series(), rhs.as_materialized_series()).map(Column::from)
+            },
         }
     }
 
@@ -1802,6 +2045,7 @@ impl Column {
                 s.as_single_value_series().approx_n_unique()?;
                 Ok(1)
             },
+            Column::RunEnd(s) => s.values().approx_n_unique(),
         }
     }
 
@@ -1809,6 +2053,7 @@ impl Column {
         match self {
             Column::Series(s) => s.n_chunks(),
             Column::Scalar(s) => s.lazy_as_materialized_series().map_or(1, |x| x.n_chunks()),
+            Column::RunEnd(s) => s.lazy_as_materialized_series().map_or(1, |x| x.n_chunks()),
         }
     }
 
@@ -0,0 +1,879 @@
+use std::sync::OnceLock;
+
+use arrow::array::{Array, BooleanArray, RunEndEncodedArray};
+use arrow::bitmap::utils::count_zeros;
+use arrow::bitmap::{BitmapBuilder, OptBitmapBuilder};
+use arrow::datatypes::ArrowDataType;
+use polars_buffer::Buffer;
+use polars_error::{PolarsResult, polars_ensure};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::{Column, IntoColumn};
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+use crate::series::IsSorted;
+use crate::utils::slice_offsets;
+
+/// A [`Column`] that consists of runs of repeated values.
+///
+/// Every run is stored once in `values` together with the (exclusive) row at which the run ends.
+/// Operations that only depend on the values, such as filters, gathers, comparisons and
+/// group-bys, work on the runs directly. Other operations lazily materialize into a [`Series`].
+#[derive(Debug, Clone)]
+pub struct RunEndColumn {
+    name: PlSmallStr,
+    // invariants:
+    // values.len() == run_ends.len()
+    // run_ends is strictly increasing and run_ends[0] > 0
+    values: Series,
+    run_ends: Buffer<IdxSize>,
+
+    // invariants:
+    // materialized.name() == name
+    // materialized.len() == self.len()
+    // materialized.dtype() == values.dtype()
+    /// A lazily materialized [`Series`] variant of this [`RunEndColumn`]
+    materialized: OnceLock<Series>,
+}
+
+impl RunEndColumn {
+    /// Create a new [`RunEndColumn`] where `values[i]` is repeated up to (exclusive) row
+    /// `run_ends[i]`.
+    pub fn try_new(
+        name: PlSmallStr,
+        values: Series,
+        run_ends: Buffer<IdxSize>,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            values.len() == run_ends.len(),
+            ShapeMismatch: "run-end encoded column has {} values but {} run ends",
+            values.len(), run_ends.len()
+        );
+        let mut previous = 0;
+        for end in run_ends.iter().copied() {
+            polars_ensure!(
+                end > previous,
+                ComputeError: "run ends must be strictly increasing and positive"
+            );
+            previous = end;
+        }
+
+        // SAFETY: We just checked the invariants.
+        Ok(unsafe { Self::new_unchecked(name, values, run_ends) })
+    }
+
+    /// Create a new [`RunEndColumn`] without checking the run ends.
+    ///
+    /// # Safety
+    /// `values` and `run_ends` must have the same length and `run_ends` must be strictly
+    /// increasing and positive.
+    pub unsafe fn new_unchecked(
+        name: PlSmallStr,
+        values: Series,
+        run_ends: Buffer<IdxSize>,
+    ) -> Self {
+        debug_assert_eq!(values.len(), run_ends.len());
+        Self {
+            values: values.with_name(name.clone()),
+            name,
+            run_ends,
+
+            materialized: OnceLock::new(),
+        }
+    }
+
+    pub fn new_empty(name: PlSmallStr, dtype: &DataType) -> Self {
+        let values = Series::new_empty(name.clone(), dtype);
+        // SAFETY: There are no runs.
+        unsafe { Self::new_unchecked(name, values, Buffer::default()) }
+    }
+
+    /// Encode `series` into runs of consecutive equal values.
+    ///
+    /// Missing values are considered equal to each other.
+    pub fn from_series(series: &Series) -> PolarsResult<Self> {
+        let length = series.len();
+        if length == 0 {
+            return Ok(Self::new_empty(series.name().clone(), series.dtype()));
+        }
+
+        let lhs = series.slice(0, length - 1);
+        let rhs = series.slice(1, length - 1);
+        let changes = lhs.not_equal_missing(&rhs)?;
+
+        let mut run_ends = Vec::new();
+        for (i, changed) in changes.iter().enumerate() {
+            if changed == Some(true) {
+                run_ends.push(i as IdxSize + 1);
+            }
+        }
+        run_ends.push(length as IdxSize);
+
+        let run_starts = std::iter::once(0)
+            .chain(run_ends[..run_ends.len() - 1].iter().copied())
+            .collect::<Vec<_>>();
+        // SAFETY: All run starts are in bounds.
+        let values = unsafe { series.take_slice_unchecked(&run_starts) };
+
+        // SAFETY: The run ends are strictly increasing and positive by construction.
+        Ok(unsafe { Self::new_unchecked(series.name().clone(), values, run_ends.into()) })
+    }
+
+    /// Decode a [`RunEndEncodedArray`] into a [`RunEndColumn`].
+    ///
+    /// The runs are kept as-is, they are not merged if consecutive runs have equal values.
+    pub fn from_arrow(
+        name: PlSmallStr,
+        array: &RunEndEncodedArray,
+        metadata: Option<&arrow::datatypes::Metadata>,
+    ) -> PolarsResult<Self> {
+        let (_, values_field) = RunEndEncodedArray::get_fields(array.dtype());
+        let mut values_field = values_field.clone();
+        values_field.name = name.clone();
+        if values_field.metadata.is_none() {
+            values_field.metadata = metadata.map(|md| Arc::new(md.clone()));
+        }
+        let values = Series::try_from((&values_field, array.values().clone()))?;
+
+        let mut run_idxs = Vec::new();
+        let mut run_ends = Vec::new();
+        let mut end = 0;
+        for (idx, length) in array.runs() {
+            end += length as IdxSize;
+            run_idxs.push(idx as IdxSize);
+            run_ends.push(end);
+        }
+
+        // The array might be sliced, only keep the runs that are still referenced.
+        let is_identity = run_idxs.len() == values.len()
+            && run_idxs
+                .iter()
+                .enumerate()
+                .all(|(i, idx)| i as IdxSize == *idx);
+        let values = if is_identity {
+            values
+        } else {
+            values.take_slice(&run_idxs)?
+        };
+
+        Self::try_new(name, values, run_ends.into())
+    }
+
+    /// The Arrow data type this [`RunEndColumn`] is exported as.
+    ///
+    /// The run ends are exported as `Int32` if possible and as `Int64` otherwise.
+    pub fn arrow_dtype(&self, compat_level: CompatLevel) -> ArrowDataType {
+        let run_ends_dtype = if self.len() <= i32::MAX as usize {
+            ArrowDataType::Int32
+        } else {
+            ArrowDataType::Int64
+        };
+        let mut values_field = self.values.field().to_arrow(compat_level);
+        values_field.name = PlSmallStr::from_static("values");
+        values_field.is_nullable = true;
+        ArrowDataType::RunEndEncoded(
+            Box::new(ArrowField::new(
+                PlSmallStr::from_static("run_ends"),
+                run_ends_dtype,
+                false,
+            )),
+            Box::new(values_field),
+        )
+    }
+
+    /// Export the [`RunEndColumn`] as a [`RunEndEncodedArray`].
+    pub fn to_arrow(&self, compat_level: CompatLevel) -> PolarsResult<RunEndEncodedArray> {
+        self.slice_to_arrow(0, self.len(), compat_level)
+    }
+
+    /// Export `length` rows starting at `offset` as a [`RunEndEncodedArray`].
+    ///
+    /// The data type is that of the whole column, so that all slices of a column share the same
+    /// data type.
+    pub fn slice_to_arrow(
+        &self,
+        offset: usize,
+        length: usize,
+        compat_level: CompatLevel,
+    ) -> PolarsResult<RunEndEncodedArray> {
+        let dtype = self.arrow_dtype(compat_level);
+        let sliced = self.slice(offset as i64, length);
+        let values = sliced.values.rechunk().to_arrow(0, compat_level);
+        let run_ends = sliced
+            .run_ends
+            .iter()
+            .map(|e| *e as u64)
+            .collect::<Vec<_>>();
+        RunEndEncodedArray::try_from_run_ends(dtype, &run_ends, values)
+    }
+
+    pub fn name(&self) -> &PlSmallStr {
+        &self.name
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        self.values.dtype()
+    }
+
+    pub fn len(&self) -> usize {
+        self.run_ends.last().map_or(0, |end| *end as usize)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.run_ends.is_empty()
+    }
+
+    /// The value of every run.
+    pub fn values(&self) -> &Series {
+        &self.values
+    }
+
+    /// The (exclusive) end of every run.
+    pub fn run_ends(&self) -> &Buffer<IdxSize> {
+        &self.run_ends
+    }
+
+    pub fn n_runs(&self) -> usize {
+        self.run_ends.len()
+    }
+
+    /// Iterate over the `(start, end)` rows of all runs.
+    pub fn runs(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let starts = std::iter::once(0).chain(self.run_ends.iter().map(|e| *e as usize));
+        starts.zip(self.run_ends.iter().map(|e| *e as usize))
+    }
+
+    /// The index of the run that contains `row`.
+    #[inline]
+    pub fn physical_index(&self, row: usize) -> usize {
+        self.run_ends.partition_point(|end| *end as usize <= row)
+    }
+
+    fn run_indices(&self) -> Vec<IdxSize> {
+        let mut indices = Vec::with_capacity(self.len());
+        for (i, (start, end)) in self.runs().enumerate() {
+            indices.extend(std::iter::repeat_n(i as IdxSize, end - start));
+        }
+        indices
+    }
+
+    /// Materialize the [`RunEndColumn`] into a [`Series`].
+    pub fn to_series(&self) -> Series {
+        // SAFETY: Every run index is in bounds of the values.
+        unsafe { self.values.take_slice_unchecked(&self.run_indices()) }
+    }
+
+    /// Get the [`RunEndColumn`] as [`Series`] if it was already materialized.
+    pub fn lazy_as_materialized_series(&self) -> Option<&Series> {
+        self.materialized.get()
+    }
+
+    /// Get the [`RunEndColumn`] as [`Series`]
+    ///
+    /// This needs to materialize upon the first call. Afterwards, this is cached.
+    pub fn as_materialized_series(&self) -> &Series {
+        self.materialized.get_or_init(|| self.to_series())
+    }
+
+    /// Take the [`RunEndColumn`] and materialize as a [`Series`] if not already done.
+    pub fn take_materialized_series(self) -> Series {
+        match self.materialized.get() {
+            Some(s) => s.clone(),
+            None => self.to_series(),
+        }
+    }
+
+    /// Create a new [`RunEndColumn`] with the same runs and new `values`.
+    ///
+    /// # Panics
+    /// Panics if `values` does not have one value per run.
+    pub fn with_values(&self, values: Series) -> Self {
+        assert_eq!(values.len(), self.n_runs());
+        // SAFETY: The number of runs is unchanged.
+        unsafe { Self::new_unchecked(values.name().clone(), values, self.run_ends.clone()) }
+    }
+
+    /// Keep the runs for which `keep` is `true` with their full length.
+    fn filter_runs(&self, keep: &BooleanChunked) -> Self {
+        // The mask has one value per run.
+        let values = self.values.filter(keep).unwrap();
+        let mut run_ends = Vec::with_capacity(values.len());
+        let mut end = 0;
+        for ((start, run_end), keep) in self.runs().zip(keep.iter()) {
+            if keep == Some(true) {
+                end += (run_end - start) as IdxSize;
+                run_ends.push(end);
+            }
+        }
+
+        // SAFETY: Only non-empty runs are pushed.
+        unsafe { Self::new_unchecked(self.name.clone(), values, run_ends.into()) }
+    }
+
+    /// Expand a mask with one value per run to one value per row.
+    pub fn expand_mask(&self, mask: &BooleanChunked) -> BooleanChunked {
+        assert_eq!(mask.len(), self.n_runs());
+
+        let mut values = BitmapBuilder::with_capacity(self.len());
+        let mut validity = OptBitmapBuilder::default();
+        validity.reserve(self.len());
+        for ((start, end), value) in self.runs().zip(mask.iter()) {
+            values.extend_constant(end - start, value.unwrap_or(false));
+            validity.extend_constant(end - start, value.is_some());
+        }
+
+        let array = BooleanArray::new(
+            ArrowDataType::Boolean,
+            values.freeze(),
+            validity.into_opt_validity(),
+        );
+        BooleanChunked::with_chunk(mask.name().clone(), array)
+    }
+
+    pub fn rename(&mut self, name: PlSmallStr) -> &mut Self {
+        if let Some(series) = self.materialized.get_mut() {
+            series.rename(name.clone());
+        }
+
+        self.values.rename(name.clone());
+        self.name = name;
+        self
+    }
+
+    pub fn slice(&self, offset: i64, length: usize) -> Self {
+        let (offset, length) = slice_offsets(offset, length, self.len());
+        if length == self.len() {
+            return self.clone();
+        }
+        if length == 0 {
+            return Self::new_empty(self.name.clone(), self.dtype());
+        }
+
+        let end = offset + length;
+        let first = self.physical_index(offset);
+        let last = self.physical_index(end - 1);
+        let run_ends = self.run_ends[first..=last]
+            .iter()
+            .map(|e| (*e).min(end as IdxSize) - offset as IdxSize)
+            .collect::<Vec<_>>();
+        let values = self.values.slice(first as i64, last - first + 1);
+
+        // SAFETY: Slicing keeps the run ends strictly increasing and positive.
+        unsafe { Self::new_unchecked(self.name.clone(), values, run_ends.into()) }
+    }
+
+    /// Filter the rows with a mask of the same length as `self`.
+    ///
+    /// This counts the kept rows of every run instead of materializing the column.
+    pub fn filter(&self, mask: &BooleanChunked) -> PolarsResult<Self> {
+        polars_ensure!(
+            mask.len() == self.len(),
+            ShapeMismatch: "filter's length: {} differs from that of the series: {}",
+            mask.len(), self.len()
+        );
+
+        let mask = mask.rechunk();
+        let mask = mask.downcast_as_array();
+        let bits = match mask.validity() {
+            Some(validity) => mask.values() & validity,
+            None => mask.values().clone(),
+        };
+        let (slice, bits_offset, _) = bits.as_slice();
+
+        let mut run_idxs = Vec::new();
+        let mut run_ends = Vec::new();
+        let mut end = 0;
+        for (i, (start, run_end)) in self.runs().enumerate() {
+            let length = run_end - start;
+            let kept = length - count_zeros(slice, bits_offset + start, length);
+            if kept > 0 {
+                end += kept as IdxSize;
+                run_idxs.push(i as IdxSize);
+                run_ends.push(end);
+            }
+        }
+
+        // SAFETY: All run indices are in bounds.
+        let values = unsafe { self.values.take_slice_unchecked(&run_idxs) };
+        // SAFETY: Only non-empty runs are pushed.
+        Ok(unsafe { Self::new_unchecked(self.name.clone(), values, run_ends.into()) })
+    }
+
+    /// Gather rows, merging consecutive indices that land in the same run.
+    ///
+    /// # Safety
+    /// All non-null indices must be in bounds.
+    unsafe fn gather(&self, indices: impl Iterator<Item = Option<IdxSize>>) -> Self {
+        let mut run_idxs: Vec<Option<IdxSize>> = Vec::new();
+        let mut run_ends = Vec::new();
+        let mut has_nulls = false;
+        for (i, idx) in indices.enumerate() {
+            let run = idx.map(|idx| self.physical_index(idx as usize) as IdxSize);
+            has_nulls |= run.is_none();
+            if run_idxs.last() == Some(&run) {
+                *run_ends.last_mut().unwrap() += 1;
+            } else {
+                run_idxs.push(run);
+                run_ends.push(i as IdxSize + 1);
+            }
+        }
+
+        let values = if has_nulls {
+            let run_idxs = IdxCa::from_iter_options(PlSmallStr::EMPTY, run_idxs.into_iter());
+            unsafe { self.values.take_unchecked(&run_idxs) }
+        } else {
+            let run_idxs = run_idxs.into_iter().map(Option::unwrap).collect::<Vec<_>>();
+            unsafe { self.values.take_slice_unchecked(&run_idxs) }
+        };
+
+        // SAFETY: Every pushed run has a length of at least one.
+        unsafe { Self::new_unchecked(self.name.clone(), values, run_ends.into()) }
+    }
+
+    /// # Safety
+    ///
+    /// No bounds on the indexes are performed.
+    pub unsafe fn take_unchecked(&self, indices: &IdxCa) -> Self {
+        unsafe { self.gather(indices.iter()) }
+    }
+
+    /// # Safety
+    ///
+    /// No bounds on the indexes are performed.
+    pub unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Self {
+        unsafe { self.gather(indices.iter().copied().map(Some)) }
+    }
+
+    /// # Safety
+    ///
+    /// `row` must be in bounds.
+    pub unsafe fn get_unchecked(&self, row: usize) -> AnyValue<'_> {
+        unsafe { self.values.get_unchecked(self.physical_index(row)) }
+    }
+
+    pub fn null_count(&self) -> usize {
+        if !self.values.has_nulls() {
+            return 0;
+        }
+
+        self.runs()
+            .zip(self.values.is_null().iter())
+            .filter(|(_, is_null)| *is_null == Some(true))
+            .map(|((start, end), _)| end - start)
+            .sum()
+    }
+
+    pub fn has_nulls(&self) -> bool {
+        self.values.has_nulls()
+    }
+
+    pub fn is_null(&self) -> BooleanChunked {
+        self.expand_mask(&self.values.is_null())
+    }
+
+    pub fn is_not_null(&self) -> BooleanChunked {
+        self.expand_mask(&self.values.is_not_null())
+    }
+
+    pub fn drop_nulls(&self) -> Self {
+        if !self.has_nulls() {
+            return self.clone();
+        }
+        self.filter_runs(&self.values.is_not_null())
+    }
+
+    pub fn first_non_null(&self) -> Option<usize> {
+        let run = crate::utils::first_non_null(self.values.chunks().iter().map(|a| a.as_ref()))?;
+        Some(if run == 0 {
+            0
+        } else {
+            self.run_ends[run - 1] as usize
+        })
+    }
+
+    pub fn last_non_null(&self) -> Option<usize> {
+        let run = crate::utils::last_non_null(
+            self.values.chunks().iter().map(|a| a.as_ref()),
+            self.values.len(),
+        )?;
+        Some(self.run_ends[run] as usize - 1)
+    }
+
+    pub fn reverse(&self) -> Self {
+        let mut run_ends = Vec::with_capacity(self.n_runs());
+        let mut end = 0;
+        for (start, run_end) in self.runs().collect::<Vec<_>>().into_iter().rev() {
+            end += (run_end - start) as IdxSize;
+            run_ends.push(end);
+        }
+
+        // SAFETY: Reversing the runs keeps the run ends strictly increasing.
+        unsafe { Self::new_unchecked(self.name.clone(), self.values.reverse(), run_ends.into()) }
+    }
+
+    pub fn extend_constant(&self, value: AnyValue, n: usize) -> PolarsResult<Self> {
+        if n == 0 {
+            return Ok(self.clone());
+        }
+
+        let values = self.values.extend_constant(value, 1)?;
+        let mut run_ends = self.run_ends.as_slice().to_vec();
+        run_ends.push((self.len() + n) as IdxSize);
+
+        // SAFETY: The new run is non-empty.
+        Ok(unsafe { Self::new_unchecked(self.name.clone(), values, run_ends.into()) })
+    }
+
+    /// Append the runs of `other` to this column.
+    ///
+    /// The last run of `self` and the first run of `other` are not merged, even if they have
+    /// equal values.
+    pub fn append(&mut self, other: &RunEndColumn) -> PolarsResult<&mut Self> {
+        let offset = self.len() as IdxSize;
+        let mut values = self.values.clone();
+        values.append(&other.values)?;
+        let run_ends = self
+            .run_ends
+            .iter()
+            .copied()
+            .chain(other.run_ends.iter().map(|e| e + offset))
+            .collect::<Vec<_>>();
+
+        // SAFETY: Both sides have valid run ends and the run ends of `other` are shifted past the
+        // end of `self`.
+        *self = unsafe { Self::new_unchecked(self.name.clone(), values, run_ends.into()) };
+        Ok(self)
+    }
+
+    pub fn rechunk(&self) -> Self {
+        self.with_values(self.values.rechunk())
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.values.shrink_to_fit();
+    }
+
+    pub fn cast_with_options(&self, dtype: &DataType, options: CastOptions) -> PolarsResult<Self> {
+        Ok(self.with_values(self.values.cast_with_options(dtype, options)?))
+    }
+
+    pub fn strict_cast(&self, dtype: &DataType) -> PolarsResult<Self> {
+        self.cast_with_options(dtype, CastOptions::Strict)
+    }
+
+    pub fn cast(&self, dtype: &DataType) -> PolarsResult<Self> {
+        self.cast_with_options(dtype, CastOptions::NonStrict)
+    }
+
+    /// # Safety
+    ///
+    /// This can lead to invalid memory access in downstream code.
+    pub unsafe fn cast_unchecked(&self, dtype: &DataType) -> PolarsResult<Self> {
+        Ok(self.with_values(unsafe { self.values.cast_unchecked(dtype) }?))
+    }
+
+    /// Apply an elementwise function to the value of every run.
+    pub fn try_apply_unary_elementwise(
+        &self,
+        f: impl Fn(&Series) -> PolarsResult<Series>,
+    ) -> PolarsResult<Self> {
+        let values = f(&self.values)?;
+        assert_eq!(values.len(), self.n_runs());
+        Ok(self.with_values(values))
+    }
+
+    pub fn is_sorted_flag(&self) -> IsSorted {
+        self.values.is_sorted_flag()
+    }
+
+    pub fn set_sorted_flag(&mut self, sorted: IsSorted) {
+        self.values.set_sorted_flag(sorted);
+        if let Some(series) = self.materialized.get_mut() {
+            series.set_sorted_flag(sorted);
+        }
+    }
+
+    /// Group the rows by grouping the value of every run.
+    #[cfg(feature = "algorithm_group_by")]
+    pub fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        use polars_utils::idx_vec::IdxVec;
+
+        let run_groups = self.values.group_tuples(multithreaded, sorted)?;
+
+        // Fast path: every run is its own group, e.g. when the values are sorted.
+        if run_groups.len() == self.n_runs() {
+            let mut groups = self
+                .runs()
+                .map(|(start, end)| [start as IdxSize, (end - start) as IdxSize])
+                .collect::<Vec<_>>();
+            groups.sort_unstable_by_key(|g| g[0]);
+            return Ok(GroupsType::new_slice(groups, false, true));
+        }
+
+        let run_groups = run_groups.into_idx();
+        let starts = std::iter::once(0)
+            .chain(self.run_ends.iter().copied())
+            .collect::<Vec<_>>();
+        let mut first = Vec::with_capacity(run_groups.len());
+        let mut all = Vec::with_capacity(run_groups.len());
+        for (first_run, runs) in run_groups.iter() {
+            first.push(starts[first_run as usize]);
+            let mut rows = Vec::new();
+            for run in runs.iter() {
+                let run = *run as usize;
+                rows.extend(starts[run]..self.run_ends[run]);
+            }
+            all.push(IdxVec::from(rows));
+        }
+
+        Ok(GroupsType::Idx(GroupsIdx::new(
+            first,
+            all,
+            run_groups.is_sorted_flag(),
+        )))
+    }
+}
+
+impl IntoColumn for RunEndColumn {
+    #[inline(always)]
+    fn into_column(self) -> Column {
+        self.into()
+    }
+}
+
+impl From<RunEndColumn> for Column {
+    #[inline]
+    fn from(value: RunEndColumn) -> Self {
+        Self::RunEnd(value)
+    }
+}
+
+#[cfg(feature = "dsl-schema")]
+impl schemars::JsonSchema for RunEndColumn {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "RunEndColumn".into()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(concat!(module_path!(), "::", "RunEndColumn"))
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        serde_impl::SerializeWrap::json_schema(generator)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use polars_error::PolarsError;
+    use polars_utils::IdxSize;
+    use polars_utils::pl_str::PlSmallStr;
+
+    use super::RunEndColumn;
+    use crate::frame::Series;
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+    pub struct SerializeWrap {
+        name: PlSmallStr,
+        /// The value of every run
+        values: Series,
+        run_ends: Vec<IdxSize>,
+    }
+
+    impl From<&RunEndColumn> for SerializeWrap {
+        fn from(value: &RunEndColumn) -> Self {
+            Self {
+                name: value.name.clone(),
+                values: value.values.clone(),
+                run_ends: value.run_ends.as_slice().to_vec(),
+            }
+        }
+    }
+
+    impl TryFrom<SerializeWrap> for RunEndColumn {
+        type Error = PolarsError;
+
+        fn try_from(value: SerializeWrap) -> Result<Self, Self::Error> {
+            RunEndColumn::try_new(value.name, value.values, value.run_ends.into())
+        }
+    }
+
+    impl serde::ser::Serialize for RunEndColumn {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            SerializeWrap::from(self).serialize(serializer)
+        }
+    }
+
+    impl<'de> serde::de::Deserialize<'de> for RunEndColumn {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde::de::Error;
+
+            SerializeWrap::deserialize(deserializer)
+                .and_then(|x| RunEndColumn::try_from(x).map_err(D::Error::custom))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn encode(values: &[Option<i32>]) -> RunEndColumn {
+        RunEndColumn::from_series(&Series::new(PlSmallStr::from_static("a"), values)).unwrap()
+    }
+
+    fn assert_decodes_to(column: &RunEndColumn, expected: &[Option<i32>]) {
+        let expected = Series::new(PlSmallStr::from_static("a"), expected);
+        assert!(column.as_materialized_series().equals_missing(&expected));
+    }
+
+    #[test]
+    fn test_run_end_slice() {
+        let column = encode(&[Some(1), Some(1), Some(2), Some(2), Some(2), None, Some(3)]);
+        assert_eq!(column.n_runs(), 4);
+
+        let sliced = column.slice(1, 4);
+        assert_eq!(sliced.n_runs(), 2);
+        assert_decodes_to(&sliced, &[Some(1), Some(2), Some(2), Some(2)]);
+
+        let sliced = column.slice(-2, 2);
+        assert_eq!(sliced.n_runs(), 2);
+        assert_decodes_to(&sliced, &[None, Some(3)]);
+
+        assert!(column.slice(3, 0).is_empty());
+    }
+
+    #[test]
+    fn test_run_end_filter() {
+        let column = encode(&[Some(1), Some(1), Some(2), Some(2), Some(2), None, Some(3)]);
+        let mask = BooleanChunked::new(
+            PlSmallStr::from_static("mask"),
+            &[
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(true),
+                None,
+                Some(true),
+                Some(false),
+            ],
+        );
+
+        let filtered = column.filter(&mask).unwrap();
+        assert_eq!(filtered.n_runs(), 3);
+        assert_decodes_to(&filtered, &[Some(1), Some(2), None]);
+
+        let mask = BooleanChunked::full(PlSmallStr::from_static("mask"), false, 3);
+        assert!(column.filter(&mask).is_err());
+    }
+
+    #[test]
+    fn test_run_end_gather_with_nulls() {
+        let column = encode(&[Some(1), Some(1), Some(2), Some(2), Some(2), None, Some(3)]);
+        let indices = IdxCa::new(
+            PlSmallStr::from_static("idx"),
+            &[Some(0), Some(1), None, Some(6), Some(6), Some(5)],
+        );
+
+        let gathered = unsafe { column.take_unchecked(&indices) };
+        assert_eq!(gathered.n_runs(), 4);
+        assert_decodes_to(&gathered, &[Some(1), Some(1), None, Some(3), Some(3), None]);
+
+        let gathered = unsafe { column.take_slice_unchecked(&[4, 2, 0]) };
+        assert_eq!(gathered.n_runs(), 2);
+        assert_decodes_to(&gathered, &[Some(2), Some(2), Some(1)]);
+    }
+
+    #[test]
+    #[cfg(feature = "algorithm_group_by")]
+    fn test_run_end_group_tuples() {
+        // Every run has a distinct value.
+        let column = encode(&[Some(1), Some(1), Some(2), Some(2), Some(2), Some(3)]);
+        let groups = column.group_tuples(false, true).unwrap();
+        assert_eq!(groups.unwrap_slice(), &[[0, 2], [2, 3], [5, 1]]);
+
+        // Values repeat across runs.
+        let column = encode(&[Some(1), Some(1), None, Some(1), Some(1), None]);
+        let groups = column.group_tuples(false, true).unwrap().into_idx();
+        assert_eq!(groups.first(), &[0, 2]);
+        let all = groups
+            .all()
+            .iter()
+            .map(|rows| rows.iter().copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(all, [vec![0, 1, 3, 4], vec![2, 5]]);
+    }
+
+    #[test]
+    fn test_run_end_reverse() {
+        let column = encode(&[Some(1), Some(1), Some(2), None, None, None]);
+        let reversed = column.reverse();
+        assert_eq!(reversed.n_runs(), 3);
+        assert_decodes_to(&reversed, &[None, None, None, Some(2), Some(1), Some(1)]);
+    }
+
+    #[test]
+    fn test_run_end_first_last_non_null() {
+        let column = encode(&[None, None, Some(1), Some(1), Some(2), None]);
+        assert_eq!(column.first_non_null(), Some(2));
+        assert_eq!(column.last_non_null(), Some(4));
+
+        let column = encode(&[Some(1), Some(1)]);
+        assert_eq!(column.first_non_null(), Some(0));
+        assert_eq!(column.last_non_null(), Some(1));
+
+        let column = encode(&[None, None, None]);
+        assert_eq!(column.first_non_null(), None);
+        assert_eq!(column.last_non_null(), None);
+    }
+
+    #[test]
+    fn test_run_end_arrow_roundtrip() {
+        let column = encode(&[Some(1), Some(1), None, Some(2), Some(2)]);
+        let df = DataFrame::new_infer_height(vec![
+            column.into_column(),
+            Column::new(PlSmallStr::from_static("b"), [0i32, 1, 2, 3, 4]),
+        ])
+        .unwrap();
+
+        let batch = df
+            .iter_chunks_encoded(CompatLevel::newest(), false)
+            .next()
+            .unwrap();
+        assert!(matches!(
+            batch.schema().get_at_index(0).unwrap().1.dtype(),
+            ArrowDataType::RunEndEncoded(_, _)
+        ));
+
+        let mut out = DataFrame::from(batch.clone());
+        assert!(matches!(out.column("a").unwrap(), Column::RunEnd(_)));
+        assert!(out.equals_missing(&df));
+
+        out.append_record_batch(batch).unwrap();
+        let Column::RunEnd(a) = out.column("a").unwrap() else {
+            panic!("expected a run-end encoded column");
+        };
+        assert_eq!(a.n_runs(), 6);
+        assert_eq!(out.height(), 10);
+
+        // Older compat levels and `iter_chunks` materialize the column.
+        for batch in [
+            df.iter_chunks_encoded(CompatLevel::oldest(), false)
+                .next()
+                .unwrap(),
+            df.iter_chunks(CompatLevel::newest(), false).next().unwrap(),
+        ] {
+            assert!(!matches!(
+                batch.schema().get_at_index(0).unwrap().1.dtype(),
+                ArrowDataType::RunEndEncoded(_, _)
+            ));
+        }
+    }
+}
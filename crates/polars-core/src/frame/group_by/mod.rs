@@ -56,9 +56,7 @@ impl DataFrame {
 
         let groups = if by.len() == 1 {
             let column = &by[0];
-            column
-                .as_materialized_series()
-                .group_tuples(multithreaded, sorted)
+            column.group_tuples(multithreaded, sorted)
         } else if by.iter().any(|s| s.dtype().is_object()) {
             #[cfg(feature = "object")]
             {
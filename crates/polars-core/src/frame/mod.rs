@@ -1539,7 +1539,7 @@ impl DataFrame {
                     }
                     return Ok(out.into_frame());
                 }
-                s.arg_sort(options)
+                s.try_arg_sort(options)?
             },
             _ => arg_sort(&by_column, sort_options)?,
         };
@@ -1580,6 +1580,7 @@ impl DataFrame {
             let (repr, materialized_at) = match col {
                 Column::Series(s) => ("series", s.materialized_at()),
                 Column::Scalar(_) => ("scalar", None),
+                Column::RunEnd(_) => ("run_end", None),
             };
             let sorted_asc = flags.contains(StatisticsFlags::IS_SORTED_ASC);
             let sorted_dsc = flags.contains(StatisticsFlags::IS_SORTED_DSC);
@@ -2138,6 +2139,37 @@ impl DataFrame {
         compat_level: CompatLevel,
         parallel: bool,
     ) -> impl Iterator<Item = RecordBatch> + '_ {
+        self.iter_chunks_impl(compat_level, parallel, false)
+    }
+
+    /// Iterator over the rows in this [`DataFrame`] as Arrow RecordBatches, keeping
+    /// [`Column::RunEnd`] columns run-end encoded.
+    ///
+    /// Run-end encoded columns are only exported as `RunEndEncoded` arrays at
+    /// [`CompatLevel::newest`], at older compat levels this is the same as
+    /// [`DataFrame::iter_chunks`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`DataFrame`] that is passed is not rechunked.
+    pub fn iter_chunks_encoded(
+        &self,
+        compat_level: CompatLevel,
+        parallel: bool,
+    ) -> impl Iterator<Item = RecordBatch> + '_ {
+        self.iter_chunks_impl(
+            compat_level,
+            parallel,
+            compat_level == CompatLevel::newest(),
+        )
+    }
+
+    fn iter_chunks_impl(
+        &self,
+        compat_level: CompatLevel,
+        parallel: bool,
+        keep_run_end_encoding: bool,
+    ) -> RecordBatchIterWrap<'_> {
         debug_assert!(!self.should_rechunk(), "expected equal chunks");
 
         if self.width() == 0 {
@@ -2160,13 +2192,20 @@ impl DataFrame {
             schema: Arc::new(
                 self.columns()
                     .iter()
-                    .map(|c| c.field().to_arrow(compat_level))
+                    .map(|c| match c {
+                        Column::RunEnd(rc) if keep_run_end_encoding => {
+                            ArrowField::new(c.name().clone(), rc.arrow_dtype(compat_level), true)
+                        },
+                        _ => c.field().to_arrow(compat_level),
+                    })
                     .collect(),
             ),
             idx: 0,
+            offset: 0,
             n_chunks: usize::max(1, self.first_col_n_chunks()),
             compat_level,
             parallel,
+            keep_run_end_encoding,
         })
     }
 
@@ -2691,9 +2730,12 @@ pub struct RecordBatchIter<'a> {
     df: &'a DataFrame,
     schema: ArrowSchemaRef,
     idx: usize,
+    // The row at which the current chunk starts.
+    offset: usize,
     n_chunks: usize,
     compat_level: CompatLevel,
     parallel: bool,
+    keep_run_end_encoding: bool,
 }
 
 impl Iterator for RecordBatchIter<'_> {
@@ -2704,27 +2746,34 @@ impl Iterator for RecordBatchIter<'_> {
             return None;
         }
 
+        // Run-end encoded columns are not chunked, so follow the size of the chunked columns.
+        let length = self
+            .df
+            .columns()
+            .iter()
+            .find_map(Column::as_series)
+            .map_or(self.df.height(), |s| s.chunks()[self.idx].len());
+        let (idx, offset, compat_level) = (self.idx, self.offset, self.compat_level);
+        let keep_run_end_encoding = self.keep_run_end_encoding;
+        let to_arrow = |c: &Column| match c {
+            Column::RunEnd(rc) if keep_run_end_encoding => rc
+                .slice_to_arrow(offset, length, compat_level)
+                // The run ends are valid by construction.
+                .unwrap()
+                .boxed(),
+            _ => c.as_materialized_series().to_arrow(idx, compat_level),
+        };
+
         // Create a batch of the columns with the same chunk no.
         let batch_cols: Vec<ArrayRef> = if self.parallel {
-            let iter = self
-                .df
-                .columns()
-                .par_iter()
-                .map(Column::as_materialized_series)
-                .map(|s| s.to_arrow(self.idx, self.compat_level));
+            let iter = self.df.columns().par_iter().map(to_arrow);
             POOL.install(|| iter.collect())
         } else {
-            self.df
-                .columns()
-                .iter()
-                .map(Column::as_materialized_series)
-                .map(|s| s.to_arrow(self.idx, self.compat_level))
-                .collect()
+            self.df.columns().iter().map(to_arrow).collect()
         };
 
-        let length = batch_cols.first().map_or(0, |arr| arr.len());
-
         self.idx += 1;
+        self.offset += length;
 
         Some(RecordBatch::new(length, self.schema.clone(), batch_cols))
     }
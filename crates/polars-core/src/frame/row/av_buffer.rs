@@ -147,6 +147,11 @@ impl<'a> AnyValueBuffer<'a> {
                     let mut fmt = DecimalFmtBuffer::new();
                     builder.append_value(fmt.format_dec128(v, s, false, false));
                 },
+                #[cfg(feature = "dtype-decimal256")]
+                AnyValue::Decimal256(v, _p, s) => {
+                    let mut fmt = DecimalFmtBuffer::new();
+                    builder.append_value(fmt.format_dec256(v, s, false, false));
+                },
                 _ => return None,
             },
             _ => return None,
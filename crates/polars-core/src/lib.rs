@@ -11,6 +11,8 @@ extern crate core;
 
 #[macro_use]
 pub mod utils;
+#[cfg(feature = "arrow_rs")]
+pub mod arrow_rs;
 pub mod chunked_array;
 pub mod config;
 pub mod datatypes;
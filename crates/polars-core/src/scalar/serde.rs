@@ -6,6 +6,10 @@ use polars_utils::pl_str::PlSmallStr;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use super::Scalar;
+#[cfg(feature = "dtype-decimal256")]
+use crate::prelude::i256;
+#[cfg(feature = "dtype-interval")]
+use crate::prelude::months_days_ns;
 use crate::prelude::{AnyValue, DataType, Field};
 use crate::series::Series;
 
@@ -134,6 +138,14 @@ pub enum SerializableScalar {
 
     #[cfg(feature = "dtype-struct")]
     Struct(Vec<(PlSmallStr, SerializableScalar)>),
+
+    /// A calendar interval of months, days and nanoseconds.
+    #[cfg(feature = "dtype-interval")]
+    Interval(i32, i32, i64),
+
+    /// A 256-bit fixed point decimal number, stored as its high and low words, with a scale.
+    #[cfg(feature = "dtype-decimal256")]
+    Decimal256(i128, u128, usize, usize),
 }
 
 impl TryFrom<Scalar> for SerializableScalar {
@@ -178,6 +190,9 @@ impl TryFrom<Scalar> for SerializableScalar {
             #[cfg(feature = "dtype-time")]
             AnyValue::Time(v) => Self::Time(v),
 
+            #[cfg(feature = "dtype-interval")]
+            AnyValue::Interval(v) => Self::Interval(v.months(), v.days(), v.ns()),
+
             #[cfg(feature = "dtype-categorical")]
             AnyValue::Categorical(cat, _) | AnyValue::CategoricalOwned(cat, _) => {
                 let DataType::Categorical(categories, mapping) = value.dtype() else {
@@ -262,6 +277,12 @@ impl TryFrom<Scalar> for SerializableScalar {
 
             #[cfg(feature = "dtype-decimal")]
             AnyValue::Decimal(v, prec, scale) => Self::Decimal(v, prec, scale),
+
+            #[cfg(feature = "dtype-decimal256")]
+            AnyValue::Decimal256(v, prec, scale) => {
+                let (hi, lo) = v.0.into_words();
+                Self::Decimal256(hi, lo as u128, prec, scale)
+            },
         };
         Ok(out)
     }
@@ -299,10 +320,18 @@ impl TryFrom<SerializableScalar> for Scalar {
             S::Duration(v, time_unit) => Self::new_duration(v, time_unit),
             #[cfg(feature = "dtype-time")]
             S::Time(v) => Self::new_time(v),
+            #[cfg(feature = "dtype-interval")]
+            S::Interval(months, days, ns) => {
+                Self::new_interval(months_days_ns::new(months, days, ns))
+            },
             #[cfg(feature = "dtype-array")]
             S::Array(v, width) => Self::new_array(v, width),
             #[cfg(feature = "dtype-decimal")]
             S::Decimal(v, prec, scale) => Self::new_decimal(v, prec, scale),
+            #[cfg(feature = "dtype-decimal256")]
+            S::Decimal256(hi, lo, prec, scale) => {
+                Self::new_decimal256(i256::from_words(hi, lo as i128), prec, scale)
+            },
 
             #[cfg(feature = "dtype-categorical")]
             S::Categorical {
@@ -132,6 +132,8 @@ impl Series {
             DataType::Date => any_values_to_date(values, strict)?.into_series(),
             #[cfg(feature = "dtype-time")]
             DataType::Time => any_values_to_time(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => any_values_to_interval(values, strict)?.into_series(),
             #[cfg(feature = "dtype-datetime")]
             DataType::Datetime(tu, tz) => {
                 any_values_to_datetime(values, *tu, (*tz).clone(), strict)?.into_series()
@@ -146,6 +148,10 @@ impl Series {
             DataType::Decimal(precision, scale) => {
                 any_values_to_decimal(values, *precision, *scale, strict)?.into_series()
             },
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(precision, scale) => {
+                any_values_to_decimal256(values, *precision, *scale, strict)?.into_series()
+            },
             #[cfg(feature = "dtype-extension")]
             DataType::Extension(typ, storage) => {
                 Series::from_any_values_and_dtype(name.clone(), values, storage, strict)?
@@ -507,6 +513,27 @@ fn any_values_to_time(values: &[AnyValue], strict: bool) -> PolarsResult<TimeChu
     Ok(builder.finish().into_time())
 }
 
+#[cfg(feature = "dtype-interval")]
+fn any_values_to_interval(values: &[AnyValue], strict: bool) -> PolarsResult<IntervalChunked> {
+    let mut builder = PrimitiveChunkedBuilder::<Int128Type>::new(PlSmallStr::EMPTY, values.len());
+    for av in values {
+        match av {
+            AnyValue::Interval(v) => builder.append_value(interval_to_i128(*v)),
+            AnyValue::Null => builder.append_null(),
+            av => {
+                if strict {
+                    return Err(invalid_value_error(&DataType::Interval, av));
+                }
+                match av.cast(&DataType::Interval) {
+                    AnyValue::Interval(v) => builder.append_value(interval_to_i128(v)),
+                    _ => builder.append_null(),
+                }
+            },
+        }
+    }
+    Ok(builder.finish().into_interval())
+}
+
 #[cfg(feature = "dtype-datetime")]
 fn any_values_to_datetime(
     values: &[AnyValue],
@@ -656,6 +683,50 @@ fn any_values_to_decimal(
     builder.finish().into_decimal(precision, scale)
 }
 
+#[cfg(feature = "dtype-decimal256")]
+fn any_values_to_decimal256(
+    values: &[AnyValue],
+    precision: usize,
+    scale: usize,
+    strict: bool,
+) -> PolarsResult<Decimal256Chunked> {
+    let target_dtype = DataType::Decimal256(precision, scale);
+
+    let mut out = Vec::with_capacity(values.len());
+    for av in values {
+        let v = match av {
+            // Allow equal or less scale. We do want to support different scales even in 'strict' mode.
+            AnyValue::Decimal256(_, _, s) | AnyValue::Decimal(_, _, s) if *s <= scale => {
+                match av.strict_cast(&target_dtype) {
+                    Some(AnyValue::Decimal256(i, _, _)) => Some(i),
+                    _ => polars_bail!(
+                        ComputeError: "decimal precision {} can't fit value {}",
+                        precision, av
+                    ),
+                }
+            },
+            AnyValue::Null => None,
+            av => {
+                if strict {
+                    return Err(invalid_value_error(&target_dtype, av));
+                }
+                match av.strict_cast(&target_dtype) {
+                    Some(AnyValue::Decimal256(i, _, _)) => Some(i),
+                    _ => None,
+                }
+            },
+        };
+        out.push(v);
+    }
+
+    Ok(Decimal256Chunked::from_i256_iter(
+        PlSmallStr::EMPTY,
+        out,
+        precision,
+        scale,
+    ))
+}
+
 fn any_values_to_list(
     avs: &[AnyValue],
     inner_type: &DataType,
@@ -305,6 +305,14 @@ impl ToArrowConverter {
 
                 time64ns_to_time64us(array).boxed()
             },
+            #[cfg(feature = "dtype-interval")]
+            (
+                DataType::Interval,
+                ArrowDataType::Interval(arrow::datatypes::IntervalUnit::MonthDayNano),
+            ) => {
+                let array: &PrimitiveArray<i128> = array.as_any().downcast_ref().unwrap();
+                crate::prelude::interval_array_from_physical(array).boxed()
+            },
             #[cfg(feature = "dtype-decimal")]
             (DataType::Decimal(prec, scale), ArrowDataType::Decimal(a_prec, a_scale)) => {
                 let matching = *a_prec == *prec && *a_scale == *scale;
@@ -315,6 +323,17 @@ impl ToArrowConverter {
 
                 primitive_to_boxed_with_logical!(array, i128, to_owned_dtype(arrow_field))
             },
+            #[cfg(feature = "dtype-decimal256")]
+            (DataType::Decimal256(prec, scale), ArrowDataType::Decimal256(a_prec, a_scale)) => {
+                let matching = *a_prec == *prec && *a_scale == *scale;
+
+                if !matching {
+                    bail_unhandled_arrow_conversion_dtype_pair!(polars_dtype, &arrow_field)
+                }
+
+                let array: &arrow::array::StructArray = array.as_any().downcast_ref().unwrap();
+                crate::prelude::decimal256_array_from_physical(array, *prec, *scale).boxed()
+            },
             #[cfg(feature = "object")]
             (DataType::Object(_), ArrowDataType::FixedSizeBinary(8)) => {
                 use crate::chunked_array::object::builder::object_series_to_arrow_array;
@@ -363,6 +382,48 @@ impl ToArrowConverter {
 
                 array.to_boxed()
             },
+            #[cfg(feature = "dtype-map")]
+            (DataType::Extension(_, storage_dtype), ArrowDataType::Map(_, _))
+                if polars_dtype.is_map() =>
+            {
+                use arrow::array::{MapArray, StructArray};
+                use arrow::offset::OffsetsBuffer;
+
+                let DataType::List(entries_dtype) = storage_dtype.as_ref() else {
+                    unreachable!()
+                };
+                let arr: &ListArray<i64> = array.as_any().downcast_ref().unwrap();
+
+                // Arrow requires every entry of a map to have a non-null key.
+                let start = *arr.offsets().first() as usize;
+                let entries = arr.values().sliced(start, arr.offsets().range() as usize);
+                let entries: &StructArray = entries.as_any().downcast_ref().unwrap();
+                polars_ensure!(
+                    entries.null_count() == 0 && entries.values()[0].null_count() == 0,
+                    InvalidOperation: "cannot convert a map with null keys to Arrow"
+                );
+
+                let arrow_dtype = to_owned_dtype(arrow_field);
+
+                let ArrowDataType::Map(arrow_entries_field, _) = &arrow_dtype else {
+                    unreachable!()
+                };
+
+                let new_values = self.array_to_arrow(
+                    arr.values().as_ref(),
+                    entries_dtype,
+                    Cow::Borrowed(arrow_entries_field.as_ref()),
+                )?;
+
+                let offsets = OffsetsBuffer::<i32>::try_from(arr.offsets())?;
+
+                Box::new(MapArray::try_new(
+                    arrow_dtype,
+                    offsets,
+                    new_values,
+                    arr.validity().cloned(),
+                )?)
+            },
             #[cfg(feature = "dtype-extension")]
             (
                 DataType::Extension(pl_ext_type, storage_dtype),
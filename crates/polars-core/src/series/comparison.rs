@@ -77,6 +77,12 @@ macro_rules! impl_eq_compare {
                     SchemaMismatch: "could not evaluate comparison between series '{}' of dtype: {:?} and series '{}' of dtype: {:?}",
                     lhs.name(), lhs.dtype(), rhs.name(), rhs.dtype()
             ))?;
+        #[cfg(feature = "dtype-decimal256")]
+        if let Decimal256(_, _) = lhs.dtype() {
+            let mut out = lhs.decimal256().unwrap().$method(rhs.decimal256().unwrap());
+            out.rename(lhs.name().clone());
+            return PolarsResult::Ok(out);
+        }
         let lhs = lhs.to_physical_repr();
         let rhs = rhs.to_physical_repr();
         let mut out = match lhs.dtype() {
@@ -173,7 +179,7 @@ macro_rules! impl_ineq_compare {
             (le @ Extension(_, _), re @ Extension(_, _)) if le == re => {
                 let lhs = lhs.ext().unwrap();
                 let rhs = rhs.ext().unwrap();
-                return lhs.storage().$method(rhs.storage());
+                return lhs.sort_key()?.$method(&*rhs.sort_key()?);
             },
 
             #[cfg(feature = "dtype-extension")]
@@ -198,6 +204,12 @@ macro_rules! impl_ineq_compare {
                 rhs.name(), rhs.dtype()
             )
         )?;
+        #[cfg(feature = "dtype-decimal256")]
+        if let Decimal256(_, _) = lhs.dtype() {
+            let mut out = lhs.decimal256().unwrap().$method(rhs.decimal256().unwrap());
+            out.rename(lhs.name().clone());
+            return PolarsResult::Ok(out);
+        }
         let lhs = lhs.to_physical_repr();
         let rhs = rhs.to_physical_repr();
         let mut out = match lhs.dtype() {
@@ -19,7 +19,9 @@ use crate::chunked_array::cast::{CastOptions, cast_chunks};
 use crate::chunked_array::object::extension::polars_extension::PolarsExtension;
 #[cfg(feature = "object")]
 use crate::chunked_array::object::registry::get_object_builder;
+#[cfg(not(feature = "dtype-interval"))]
 use crate::config::check_allow_importing_interval_as_struct;
+use crate::frame::column::RunEndColumn;
 use crate::prelude::*;
 
 impl Series {
@@ -84,6 +86,10 @@ impl Series {
             Time => Int64Chunked::from_chunks(name, chunks)
                 .into_time()
                 .into_series(),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Int128Chunked::from_chunks(name, chunks)
+                .into_interval()
+                .into_series(),
             #[cfg(feature = "dtype-duration")]
             Duration(tu) => Int64Chunked::from_chunks(name, chunks)
                 .into_duration(*tu)
@@ -96,6 +102,15 @@ impl Series {
             Decimal(precision, scale) => Int128Chunked::from_chunks(name, chunks)
                 .into_decimal_unchecked(*precision, *scale)
                 .into_series(),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(_, _) => {
+                let phys = StructChunked::from_chunks_and_dtype_unchecked(
+                    name,
+                    chunks,
+                    decimal256_physical_dtype(),
+                );
+                Decimal256Chunked::new_logical(phys, dtype.clone()).into_series()
+            },
             #[cfg(feature = "dtype-array")]
             Array(_, _) => {
                 ArrayChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
@@ -388,6 +403,25 @@ impl Series {
                     Ok(s)
                 })
             },
+            #[cfg(feature = "dtype-decimal256")]
+            ArrowDataType::Decimal256(precision, scale)
+                if *precision > polars_compute::decimal::DEC128_MAX_PREC =>
+            {
+                use arrow::types::i256;
+
+                polars_compute::decimal256::dec256_verify_prec_scale(*precision, *scale)?;
+                let arrs = chunks.into_iter().map(|chunk| {
+                    chunk
+                        .as_any()
+                        .downcast_ref::<PrimitiveArray<i256>>()
+                        .unwrap()
+                        .clone()
+                });
+                Ok(
+                    Decimal256Chunked::from_i256_chunks(name, arrs, *precision, *scale)
+                        .into_series(),
+                )
+            },
             ArrowDataType::Decimal256(precision, scale) => {
                 feature_gated!("dtype-decimal", {
                     use arrow::types::i256;
@@ -520,6 +554,17 @@ impl Series {
                         arr.field().clone()
                     })
                     .collect::<Vec<_>>();
+                #[cfg(feature = "dtype-map")]
+                let as_map = crate::datatypes::extension::import_arrow_map_as_map(md);
+                #[cfg(feature = "dtype-map")]
+                let struct_arrays = if as_map {
+                    struct_arrays
+                        .into_iter()
+                        .map(normalize_map_entries)
+                        .collect::<Vec<_>>()
+                } else {
+                    struct_arrays
+                };
 
                 let (phys_struct_arrays, dtype) =
                     to_physical_and_dtype(struct_arrays, field.metadata.as_deref());
@@ -542,16 +587,44 @@ impl Series {
                     })
                     .collect();
 
-                unsafe {
-                    let out = ListChunked::from_chunks_and_dtype_unchecked(
+                let out = unsafe {
+                    ListChunked::from_chunks_and_dtype_unchecked(
                         name,
                         chunks,
                         DataType::List(Box::new(dtype)),
-                    );
-
-                    Ok(out.into_series())
+                    )
+                }
+                .into_series();
+
+                #[cfg(feature = "dtype-map")]
+                if as_map
+                    && let Some((key, value)) =
+                        crate::datatypes::extension::map_storage_key_value_dtypes(out.dtype())
+                {
+                    let DataType::Extension(typ, _) = DataType::new_map(key.clone(), value.clone())
+                    else {
+                        unreachable!()
+                    };
+                    return Ok(out.into_extension(typ));
                 }
+
+                Ok(out)
+            },
+            #[cfg(feature = "dtype-interval")]
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| {
+                        let arr: &PrimitiveArray<months_days_ns> =
+                            arr.as_any().downcast_ref().unwrap();
+                        interval_array_to_physical(arr).boxed()
+                    })
+                    .collect();
+                Ok(Int128Chunked::from_chunks(name, chunks)
+                    .into_interval()
+                    .into_series())
             },
+            #[cfg(not(feature = "dtype-interval"))]
             ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
                 check_allow_importing_interval_as_struct("month_day_nano_interval")?;
 
@@ -570,6 +643,24 @@ impl Series {
                 })
             },
 
+            ArrowDataType::RunEndEncoded(_, _) => {
+                let mut out: Option<Series> = None;
+                for arr in chunks {
+                    let arr = arr.as_any().downcast_ref::<RunEndEncodedArray>().unwrap();
+                    let s =
+                        RunEndColumn::from_arrow(name.clone(), arr, md)?.take_materialized_series();
+                    match &mut out {
+                        None => out = Some(s),
+                        Some(out) => _ = out.append_owned(s)?,
+                    }
+                }
+                Ok(
+                    out.unwrap_or_else(|| {
+                        Series::new_empty(name, &DataType::from_arrow(dtype, md))
+                    }),
+                )
+            },
+
             dt => polars_bail!(ComputeError: "cannot create series from {:?}", dt),
         }
     }
@@ -739,9 +830,12 @@ unsafe fn to_physical_and_dtype(
         | ArrowDataType::Date32
         | ArrowDataType::Decimal(_, _)
         | ArrowDataType::Date64
-        | ArrowDataType::Map(_, _)) => {
+        | ArrowDataType::Map(_, _)
+        | ArrowDataType::RunEndEncoded(_, _)) => {
             let dt = dt.clone();
-            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let mut s =
+                Series::_try_from_arrow_unchecked_with_md(PlSmallStr::EMPTY, arrays, &dt, md)
+                    .unwrap();
             let dtype = s.dtype().clone();
             (std::mem::take(s.chunks_mut()), dtype)
         },
@@ -752,6 +846,35 @@ unsafe fn to_physical_and_dtype(
     }
 }
 
+/// Renames the fields of the entries of an Arrow map to `key` and `value`.
+#[cfg(feature = "dtype-map")]
+fn normalize_map_entries(arr: ArrayRef) -> ArrayRef {
+    let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+    let ArrowDataType::Struct(fields) = arr.dtype() else {
+        unreachable!()
+    };
+    if fields.len() != 2 {
+        return arr.to_boxed();
+    }
+
+    let fields = fields
+        .iter()
+        .zip(["key", "value"])
+        .map(|(field, name)| {
+            let mut field = field.clone();
+            field.name = PlSmallStr::from_static(name);
+            field
+        })
+        .collect();
+    StructArray::new(
+        ArrowDataType::Struct(fields),
+        arr.len(),
+        arr.values().to_vec(),
+        arr.validity().cloned(),
+    )
+    .boxed()
+}
+
 #[cfg(feature = "dtype-categorical")]
 unsafe fn import_arrow_dictionary_array(
     name: PlSmallStr,
@@ -841,7 +964,7 @@ unsafe fn import_arrow_dictionary_array(
     }
 }
 
-#[cfg(feature = "dtype-struct")]
+#[cfg(all(feature = "dtype-struct", not(feature = "dtype-interval")))]
 fn convert_month_day_nano_to_struct(chunk: Box<dyn Array>) -> PolarsResult<Box<dyn Array>> {
     let arr: &PrimitiveArray<months_days_ns> = chunk.as_any().downcast_ref().unwrap();
 
@@ -1016,6 +1139,13 @@ impl From<TimeChunked> for Series {
     }
 }
 
+#[cfg(feature = "dtype-interval")]
+impl From<IntervalChunked> for Series {
+    fn from(a: IntervalChunked) -> Self {
+        a.into_series()
+    }
+}
+
 unsafe impl IntoSeries for Arc<dyn SeriesTrait> {
     fn into_series(self) -> Series {
         Series(self)
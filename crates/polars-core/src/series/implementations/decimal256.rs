@@ -0,0 +1,404 @@
+use polars_compute::decimal256::DEC256_MAX_PREC;
+
+use super::*;
+#[cfg(feature = "algorithm_group_by")]
+use crate::frame::group_by::*;
+use crate::prelude::*;
+
+unsafe impl IntoSeries for Decimal256Chunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl SeriesWrap<Decimal256Chunked> {
+    fn physical_series(&self) -> Series {
+        self.0.physical().clone().into_series()
+    }
+
+    /// Wrap a series of the physical struct type as a `Decimal256` of the same dtype.
+    fn to_logical(&self, s: Series) -> Series {
+        let ca = s.struct_().unwrap().clone();
+        // SAFETY: the physical values are unchanged.
+        unsafe { Decimal256Chunked::new_logical(ca, self.0.dtype().clone()) }.into_series()
+    }
+
+    fn apply_on_physical<F>(&self, apply: F) -> Series
+    where
+        F: Fn(&Series) -> Series,
+    {
+        self.to_logical(apply(&self.physical_series()))
+    }
+
+    fn try_apply_on_physical<F>(&self, apply: F) -> PolarsResult<Series>
+    where
+        F: Fn(&Series) -> PolarsResult<Series>,
+    {
+        Ok(self.to_logical(apply(&self.physical_series())?))
+    }
+
+    fn other_physical(&self, other: &Series) -> PolarsResult<Series> {
+        Ok(other.decimal256()?.physical().clone().into_series())
+    }
+
+    /// Cast `rhs` to `Decimal256` so it can be combined with `self`.
+    fn arithmetic_rhs(&self, rhs: &Series) -> PolarsResult<Decimal256Chunked> {
+        let rhs = match rhs.dtype() {
+            DataType::Decimal256(_, _) => rhs.clone(),
+            DataType::Decimal(_, s) => rhs.cast(&DataType::Decimal256(DEC256_MAX_PREC, *s))?,
+            dt if dt.is_integer() => rhs.strict_cast(&DataType::Decimal256(DEC256_MAX_PREC, 0))?,
+            dt => polars_bail!(
+                InvalidOperation: "arithmetic on {} and {} is not supported",
+                self.0.dtype(), dt
+            ),
+        };
+        Ok(rhs.decimal256()?.clone())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_reduce<F>(&self, groups: &GroupsType, dtype: DataType, reduce: F) -> Series
+    where
+        F: Fn(&Decimal256Chunked) -> Scalar,
+    {
+        let values = groups.iter().map(|g| {
+            let ca = match g {
+                GroupsIndicator::Idx((_, idx)) => {
+                    let s = self.physical_series().take_slice_unchecked(idx.as_slice());
+                    self.to_logical(s)
+                },
+                GroupsIndicator::Slice([first, len]) => {
+                    self.0.slice(first as i64, len as usize).into_series()
+                },
+            };
+            reduce(ca.decimal256().unwrap()).into_value()
+        });
+        let values: Vec<AnyValue<'static>> = values.collect();
+        Series::from_any_values_and_dtype(self.0.name().clone(), &values, &dtype, true).unwrap()
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<Decimal256Chunked> {
+    fn compute_len(&mut self) {
+        self.0.physical_mut().compute_len()
+    }
+
+    fn _field(&self) -> Cow<'_, Field> {
+        Cow::Owned(self.0.field())
+    }
+
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+
+    fn _get_flags(&self) -> StatisticsFlags {
+        self.0.physical().get_flags()
+    }
+
+    fn _set_flags(&mut self, flags: StatisticsFlags) {
+        self.0.physical_mut().set_flags(flags)
+    }
+
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        self.0.get(idx_self) == other.decimal256().unwrap().get(idx_other)
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = self.other_physical(other)?;
+        self.try_apply_on_physical(|s| s.zip_with_same_type(mask, &other))
+    }
+
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        invalid_operation_panic!(into_total_eq_inner, self)
+    }
+
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        invalid_operation_panic!(into_total_ord_inner, self)
+    }
+
+    fn vec_hash(
+        &self,
+        random_state: PlSeedableRandomStateQuality,
+        buf: &mut Vec<u64>,
+    ) -> PolarsResult<()> {
+        self.physical_series().vec_hash(random_state, buf)
+    }
+
+    fn vec_hash_combine(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        hashes: &mut [u64],
+    ) -> PolarsResult<()> {
+        self.physical_series()
+            .vec_hash_combine(build_hasher, hashes)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_min(&self, groups: &GroupsType) -> Series {
+        self.agg_reduce(groups, self.0.dtype().clone(), |ca| ca.min_reduce())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_max(&self, groups: &GroupsType) -> Series {
+        self.agg_reduce(groups, self.0.dtype().clone(), |ca| ca.max_reduce())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_sum(&self, groups: &GroupsType) -> Series {
+        let dtype = DataType::Decimal256(DEC256_MAX_PREC, self.0.scale());
+        self.agg_reduce(groups, dtype, |ca| ca.sum_reduce())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        let list = self.physical_series().agg_list(groups);
+        let mut list = list.list().unwrap().clone();
+        unsafe { list.to_logical(self.dtype().clone()) };
+        list.into_series()
+    }
+
+    fn subtract(&self, rhs: &Series) -> PolarsResult<Series> {
+        let rhs = self.arithmetic_rhs(rhs)?;
+        ((&self.0) - &rhs).map(|ca| ca.into_series())
+    }
+
+    fn add_to(&self, rhs: &Series) -> PolarsResult<Series> {
+        let rhs = self.arithmetic_rhs(rhs)?;
+        ((&self.0) + &rhs).map(|ca| ca.into_series())
+    }
+
+    fn multiply(&self, rhs: &Series) -> PolarsResult<Series> {
+        let rhs = self.arithmetic_rhs(rhs)?;
+        ((&self.0) * &rhs).map(|ca| ca.into_series())
+    }
+
+    fn divide(&self, rhs: &Series) -> PolarsResult<Series> {
+        let rhs = self.arithmetic_rhs(rhs)?;
+        ((&self.0) / &rhs).map(|ca| ca.into_series())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        self.physical_series().group_tuples(multithreaded, sorted)
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Column],
+        options: &SortMultipleOptions,
+    ) -> PolarsResult<IdxCa> {
+        self.physical_series().arg_sort_multiple(by, options)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<Decimal256Chunked> {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.0.rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter<'_> {
+        self.0.physical().chunk_lengths()
+    }
+
+    fn name(&self) -> &PlSmallStr {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.physical().chunks()
+    }
+
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.physical_mut().chunks_mut()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.physical_mut().shrink_to_fit()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0.slice(offset, length).into_series()
+    }
+
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.split_at(offset);
+        (a.into_series(), b.into_series())
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let other = other.decimal256()?.physical().clone();
+        self.0.physical_mut().append_owned(other)
+    }
+
+    fn append_owned(&mut self, mut other: Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        self.0.physical_mut().append_owned(std::mem::take(
+            &mut other
+                ._get_inner_mut()
+                .as_any_mut()
+                .downcast_mut::<Decimal256Chunked>()
+                .unwrap()
+                .phys,
+        ))
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        self.0
+            .physical_mut()
+            .extend(other.decimal256()?.physical())?;
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        self.try_apply_on_physical(|s| s.filter(filter))
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        self.try_apply_on_physical(|s| s.take(indices))
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.apply_on_physical(|s| s.take_unchecked(indices))
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        self.try_apply_on_physical(|s| s.take_slice(indices))
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.apply_on_physical(|s| s.take_slice_unchecked(indices))
+    }
+
+    fn deposit(&self, validity: &Bitmap) -> Series {
+        self.apply_on_physical(|s| s.deposit(validity))
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.apply_on_physical(|s| s.rechunk())
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.apply_on_physical(|s| s.new_from_index(index, length))
+    }
+
+    fn cast(&self, dtype: &DataType, cast_options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(dtype, cast_options)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        self.try_apply_on_physical(|s| s.sort_with(options))
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.physical_series().arg_sort(options)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.0.has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        self.try_apply_on_physical(|s| s.unique())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.physical_series().n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.physical_series().arg_unique()
+    }
+
+    fn unique_id(&self) -> PolarsResult<(IdxSize, Vec<IdxSize>)> {
+        self.physical_series().unique_id()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.apply_on_physical(|s| s.reverse())
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.apply_on_physical(|s| s.shift(periods))
+    }
+
+    fn sum_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(self.0.sum_reduce())
+    }
+
+    fn min_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(self.0.min_reduce())
+    }
+
+    fn max_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(self.0.max_reduce())
+    }
+
+    fn mean(&self) -> Option<f64> {
+        self.0.mean()
+    }
+
+    fn mean_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(self.0.mean_reduce())
+    }
+
+    #[cfg(feature = "approx_unique")]
+    fn approx_n_unique(&self) -> PolarsResult<IdxSize> {
+        self.physical_series().approx_n_unique()
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn find_validity_mismatch(&self, other: &Series, idxs: &mut Vec<IdxSize>) {
+        self.physical_series().find_validity_mismatch(other, idxs)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.0
+    }
+
+    fn as_phys_any(&self) -> &dyn Any {
+        self.0.physical()
+    }
+
+    fn as_arc_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self as _
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<Decimal256Chunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        None
+    }
+}
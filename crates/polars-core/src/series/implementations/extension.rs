@@ -1,4 +1,5 @@
 use super::*;
+use crate::datatypes::extension::ExtensionAggregation;
 use crate::prelude::*;
 
 unsafe impl IntoSeries for ExtensionChunked {
@@ -21,6 +22,65 @@ impl SeriesWrap<ExtensionChunked> {
     {
         Ok(apply(self.0.storage())?.into_extension(self.0.extension_type().clone()))
     }
+
+    /// Aggregate every group with the extension type's aggregation.
+    ///
+    /// Query execution uses the fallible `try_agg_*` methods, which propagate errors of the
+    /// extension type. The infallible `agg_*` methods can't, so they warn and return nulls.
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_extension(&self, groups: &GroupsType, agg: ExtensionAggregation) -> Series {
+        unsafe { self.try_agg_extension(groups, agg) }.unwrap_or_else(|err| {
+            polars_warn!(
+                "failed to compute the {agg:?} of extension type {}: {err}",
+                self.dtype()
+            );
+            Series::full_null(self.0.name().clone(), groups.len(), self.dtype())
+        })
+    }
+
+    /// Aggregate every group with the extension type's aggregation, null if the extension type
+    /// does not support `agg`.
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn try_agg_extension(
+        &self,
+        groups: &GroupsType,
+        agg: ExtensionAggregation,
+    ) -> PolarsResult<Series> {
+        let typ = self.0.extension_type();
+        let storage = self.0.storage();
+        let mut values = Vec::with_capacity(groups.len());
+        for g in groups.iter() {
+            let group = match g {
+                GroupsIndicator::Idx((_, idx)) => storage.take_slice_unchecked(idx.as_slice()),
+                GroupsIndicator::Slice([first, len]) => storage.slice(first as i64, len as usize),
+            };
+            match typ.0.aggregate(&group, agg) {
+                Some(value) => values.push(value?.into_value()),
+                None => {
+                    return Ok(Series::full_null(
+                        self.0.name().clone(),
+                        groups.len(),
+                        self.dtype(),
+                    ));
+                },
+            }
+        }
+        let out = Series::from_any_values_and_dtype(
+            self.0.name().clone(),
+            &values,
+            storage.dtype(),
+            true,
+        )?;
+        Ok(out.into_extension(typ.clone()))
+    }
+
+    fn reduce_extension(&self, agg: ExtensionAggregation) -> PolarsResult<Scalar> {
+        self.0.aggregate(agg).unwrap_or_else(|| {
+            Err(polars_err!(
+                InvalidOperation: "extension type {} does not support {agg:?} aggregation", self.dtype()
+            ))
+        })
+    }
 }
 
 impl private::PrivateSeries for SeriesWrap<ExtensionChunked> {
@@ -77,6 +137,36 @@ impl private::PrivateSeries for SeriesWrap<ExtensionChunked> {
         self.try_apply_on_storage(|s| s.zip_with_same_type(mask, other.ext()?.storage()))
     }
 
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_min(&self, groups: &GroupsType) -> Series {
+        self.agg_extension(groups, ExtensionAggregation::Min)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_max(&self, groups: &GroupsType) -> Series {
+        self.agg_extension(groups, ExtensionAggregation::Max)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_sum(&self, groups: &GroupsType) -> Series {
+        self.agg_extension(groups, ExtensionAggregation::Sum)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn try_agg_min(&self, groups: &GroupsType) -> PolarsResult<Series> {
+        self.try_agg_extension(groups, ExtensionAggregation::Min)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn try_agg_max(&self, groups: &GroupsType) -> PolarsResult<Series> {
+        self.try_agg_extension(groups, ExtensionAggregation::Max)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn try_agg_sum(&self, groups: &GroupsType) -> PolarsResult<Series> {
+        self.try_agg_extension(groups, ExtensionAggregation::Sum)
+    }
+
     #[cfg(feature = "algorithm_group_by")]
     unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
         let list = self.0.storage().agg_list(groups);
@@ -90,7 +180,7 @@ impl private::PrivateSeries for SeriesWrap<ExtensionChunked> {
         by: &[Column],
         options: &SortMultipleOptions,
     ) -> PolarsResult<IdxCa> {
-        self.0.storage().arg_sort_multiple(by, options)
+        self.0.sort_key()?.arg_sort_multiple(by, options)
     }
 }
 
@@ -283,11 +373,33 @@ impl SeriesTrait for SeriesWrap<ExtensionChunked> {
     }
 
     fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
-        self.try_apply_on_storage(|s| s.sort_with(options))
+        match self.0.sort_key()? {
+            Cow::Borrowed(_) => self.try_apply_on_storage(|s| s.sort_with(options)),
+            Cow::Owned(key) => {
+                let idx = key.arg_sort(options);
+                // SAFETY: the sort key has the same length as the storage.
+                Ok(self.apply_on_storage(|s| unsafe { s.take_unchecked(&idx) }))
+            },
+        }
     }
 
     fn arg_sort(&self, options: SortOptions) -> IdxCa {
-        self.0.storage().arg_sort(options)
+        // `arg_sort` can't return an error, so order by the storage values if the sort key fails.
+        // Query execution uses `try_arg_sort`, which propagates the error instead.
+        match self.0.sort_key() {
+            Ok(key) => key.arg_sort(options),
+            Err(err) => {
+                polars_warn!(
+                    "failed to compute the sort key of extension type {}: {err}",
+                    self.dtype()
+                );
+                self.0.storage().arg_sort(options)
+            },
+        }
+    }
+
+    fn try_arg_sort(&self, options: SortOptions) -> PolarsResult<IdxCa> {
+        Ok(self.0.sort_key()?.arg_sort(options))
     }
 
     fn unique(&self) -> PolarsResult<Series> {
@@ -310,6 +422,18 @@ impl SeriesTrait for SeriesWrap<ExtensionChunked> {
         self.0.storage_mut().as_single_ptr()
     }
 
+    fn min_reduce(&self) -> PolarsResult<Scalar> {
+        self.reduce_extension(ExtensionAggregation::Min)
+    }
+
+    fn max_reduce(&self) -> PolarsResult<Scalar> {
+        self.reduce_extension(ExtensionAggregation::Max)
+    }
+
+    fn sum_reduce(&self) -> PolarsResult<Scalar> {
+        self.reduce_extension(ExtensionAggregation::Sum)
+    }
+
     #[cfg(feature = "approx_unique")]
     fn approx_n_unique(&self) -> PolarsResult<IdxSize> {
         self.0.storage().approx_n_unique()
@@ -12,11 +12,15 @@ mod date;
 mod datetime;
 #[cfg(feature = "dtype-decimal")]
 mod decimal;
+#[cfg(feature = "dtype-decimal256")]
+mod decimal256;
 #[cfg(feature = "dtype-duration")]
 mod duration;
 #[cfg(feature = "dtype-extension")]
 mod extension;
 mod floats;
+#[cfg(feature = "dtype-interval")]
+mod interval;
 mod list;
 pub(crate) mod null;
 #[cfg(feature = "object")]
@@ -466,6 +466,13 @@ impl Series {
             return Ok(Series::full_null(slf.name().clone(), len, dtype));
         }
 
+        #[cfg(feature = "dtype-extension")]
+        if let D::Extension(typ, storage) = dtype {
+            if !slf.dtype().is_extension() {
+                return ExtensionChunked::cast_from(&slf, typ, storage, options);
+            }
+        }
+
         let new_options = match options {
             // Strictness is handled on this level to improve error messages, if not nested.
             // Nested types could hide cast errors, so have to be done internally.
@@ -558,6 +565,15 @@ impl Series {
                 feature_gated!("dtype-duration", Ok(self.clone().into_duration(*tu)))
             },
             (D::Int64, D::Time) => feature_gated!("dtype-time", Ok(self.clone().into_time())),
+            #[cfg(feature = "dtype-interval")]
+            (D::Int128, D::Interval) => {
+                Ok(self.i128().unwrap().clone().into_interval().into_series())
+            },
+            #[cfg(feature = "dtype-decimal256")]
+            (D::Struct(_), D::Decimal256(_, _)) => unsafe {
+                let ca = self.struct_().unwrap().clone();
+                Ok(Decimal256Chunked::new_logical(ca, dtype.clone()).into_series())
+            },
 
             (D::List(_), D::List(to)) => unsafe {
                 self.list()
@@ -736,6 +752,7 @@ impl Series {
     /// * Datetime -> Int64
     /// * Duration -> Int64
     /// * Decimal -> Int128
+    /// * Decimal256 -> Struct{hi: Int128, lo: UInt128}
     /// * Time -> Int64
     /// * Categorical -> U8/U16/U32
     /// * List(inner) -> List(physical of inner)
@@ -755,6 +772,8 @@ impl Series {
             Duration(_) => Cow::Owned(self.duration().unwrap().phys.clone().into_series()),
             #[cfg(feature = "dtype-time")]
             Time => Cow::Owned(self.time().unwrap().phys.clone().into_series()),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Cow::Owned(self.interval().unwrap().phys.clone().into_series()),
             #[cfg(feature = "dtype-categorical")]
             dt @ (Categorical(_, _) | Enum(_, _)) => {
                 with_match_categorical_physical_type!(dt.cat_physical().unwrap(), |$C| {
@@ -764,6 +783,8 @@ impl Series {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Cow::Owned(self.decimal().unwrap().phys.clone().into_series()),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(_, _) => Cow::Owned(self.decimal256().unwrap().phys.clone().into_series()),
             List(_) => match self.list().unwrap().to_physical_repr() {
                 Cow::Borrowed(_) => Cow::Borrowed(self),
                 Cow::Owned(ca) => Cow::Owned(ca.into_series()),
@@ -977,6 +998,12 @@ impl Series {
 
     // used for formatting
     pub fn str_value(&self, index: usize) -> PolarsResult<Cow<'_, str>> {
+        #[cfg(feature = "dtype-extension")]
+        if let Some(ext) = self.try_ext() {
+            if let Some(v) = ext.fmt_value_for_display(index) {
+                return Ok(Cow::Owned(v));
+            }
+        }
         Ok(self.0.get(index)?.str_value())
     }
     /// Get the head of the Series.
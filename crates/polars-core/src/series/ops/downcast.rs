@@ -136,6 +136,12 @@ impl Series {
         try_unpack_chunked!(self, DataType::Time => TimeChunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Interval`]
+    #[cfg(feature = "dtype-interval")]
+    pub fn try_interval(&self) -> Option<&IntervalChunked> {
+        try_unpack_chunked!(self, DataType::Interval => IntervalChunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Date`]
     #[cfg(feature = "dtype-date")]
     pub fn try_date(&self) -> Option<&DateChunked> {
@@ -160,6 +166,12 @@ impl Series {
         try_unpack_chunked!(self, DataType::Decimal(_, _) => DecimalChunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Decimal256`]
+    #[cfg(feature = "dtype-decimal256")]
+    pub fn try_decimal256(&self) -> Option<&Decimal256Chunked> {
+        try_unpack_chunked!(self, DataType::Decimal256(_, _) => Decimal256Chunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype list
     pub fn try_list(&self) -> Option<&ListChunked> {
         try_unpack_chunked!(self, DataType::List(_) => ListChunked)
@@ -341,6 +353,13 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Time"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Interval`]
+    #[cfg(feature = "dtype-interval")]
+    pub fn interval(&self) -> PolarsResult<&IntervalChunked> {
+        self.try_interval()
+            .ok_or_else(|| unpack_chunked_err!(self => "Interval"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Date`]
     #[cfg(feature = "dtype-date")]
     pub fn date(&self) -> PolarsResult<&DateChunked> {
@@ -369,6 +388,13 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Decimal"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Decimal256`]
+    #[cfg(feature = "dtype-decimal256")]
+    pub fn decimal256(&self) -> PolarsResult<&Decimal256Chunked> {
+        self.try_decimal256()
+            .ok_or_else(|| unpack_chunked_err!(self => "Decimal256"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype list
     pub fn list(&self) -> PolarsResult<&ListChunked> {
         self.try_list()
@@ -133,6 +133,36 @@ pub(crate) mod private {
         unsafe fn agg_sum(&self, groups: &GroupsType) -> Series {
             Series::full_null(self._field().name().clone(), groups.len(), self._dtype())
         }
+
+        /// Fallible [`agg_min`](Self::agg_min), for dtypes whose aggregation can fail.
+        ///
+        /// # Safety
+        ///
+        /// Does no bounds checks, groups must be correct.
+        #[cfg(feature = "algorithm_group_by")]
+        unsafe fn try_agg_min(&self, groups: &GroupsType) -> PolarsResult<Series> {
+            Ok(unsafe { self.agg_min(groups) })
+        }
+
+        /// Fallible [`agg_max`](Self::agg_max), for dtypes whose aggregation can fail.
+        ///
+        /// # Safety
+        ///
+        /// Does no bounds checks, groups must be correct.
+        #[cfg(feature = "algorithm_group_by")]
+        unsafe fn try_agg_max(&self, groups: &GroupsType) -> PolarsResult<Series> {
+            Ok(unsafe { self.agg_max(groups) })
+        }
+
+        /// Fallible [`agg_sum`](Self::agg_sum), for dtypes whose aggregation can fail.
+        ///
+        /// # Safety
+        ///
+        /// Does no bounds checks, groups must be correct.
+        #[cfg(feature = "algorithm_group_by")]
+        unsafe fn try_agg_sum(&self, groups: &GroupsType) -> PolarsResult<Series> {
+            Ok(unsafe { self.agg_sum(groups) })
+        }
         /// # Safety
         ///
         /// Does no bounds checks, groups must be correct.
@@ -443,6 +473,12 @@ pub trait SeriesTrait:
         invalid_operation_panic!(arg_sort, self)
     }
 
+    /// Fallible [`arg_sort`](Self::arg_sort), for dtypes whose sort order can fail to be
+    /// computed.
+    fn try_arg_sort(&self, options: SortOptions) -> PolarsResult<IdxCa> {
+        Ok(self.arg_sort(options))
+    }
+
     /// Count the null values.
     fn null_count(&self) -> usize;
 
@@ -2,6 +2,8 @@ use bitflags::bitflags;
 use num_traits::Signed;
 #[cfg(feature = "dtype-decimal")]
 use polars_compute::decimal::{DEC128_MAX_PREC, i128_to_dec128};
+#[cfg(feature = "dtype-decimal256")]
+use polars_compute::decimal256::{DEC256_MAX_PREC, DEC256_MIN_PREC};
 
 use super::*;
 
@@ -495,6 +497,11 @@ pub fn get_supertype_with_options(
                         let DataType::Decimal(_prec, scale) = dt else { unreachable!() };
                         Some(DataType::Decimal(DEC128_MAX_PREC, *scale))
                     }
+                    #[cfg(feature = "dtype-decimal256")]
+                    UnknownKind::Int(_) if dt.is_decimal256() => {
+                        let DataType::Decimal256(_prec, scale) = dt else { unreachable!() };
+                        Some(DataType::Decimal256(DEC256_MAX_PREC, *scale))
+                    }
                     _ => Some(Unknown(UnknownKind::Any))
                 }
             },
@@ -541,6 +548,27 @@ pub fn get_supertype_with_options(
                     Some(Decimal(DEC128_MAX_PREC, *scale))
                 }
             }
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(p1, s1), Decimal256(p2, s2)) => {
+                Some(Decimal256((*p1).max(*p2), (*s1).max(*s2)))
+            },
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(p1, s1), Decimal(_, s2)) => {
+                Some(Decimal256(*p1, (*s1).max(*s2)))
+            },
+            #[cfg(all(feature = "dtype-decimal256", feature = "dtype-f16"))]
+            (Decimal256(_, _), Float16) => Some(Float64),
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(_, _), Float32 | Float64) => Some(Float64),
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(prec, scale), dt) if dt.is_signed_integer() || dt.is_unsigned_integer() => {
+                // Every 128-bit integer fits in 39 digits.
+                if *prec - *scale >= DEC256_MIN_PREC {
+                    Some(Decimal256(*prec, *scale))
+                } else {
+                    Some(Decimal256(DEC256_MAX_PREC, *scale))
+                }
+            }
             _ => None,
         }
     }
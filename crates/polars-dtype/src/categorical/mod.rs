@@ -14,9 +14,11 @@ use polars_utils::pl_str::PlSmallStr;
 
 mod catsize;
 mod mapping;
+mod persist;
 
 pub use catsize::{CatNative, CatSize};
 pub use mapping::CategoricalMapping;
+pub use persist::{decode_mapping, encode_mapping};
 
 /// The physical datatype backing a categorical / enum.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -115,6 +117,7 @@ static GLOBAL_CATEGORIES: LazyLock<Arc<Categories>> = LazyLock::new(|| {
     let global = Arc::new(Categories {
         id: CategoricalId::global(),
         mapping: Mutex::new(Weak::new()),
+        persistent_mapping: Mutex::new(None),
     });
     registry.insert(global_id, Arc::downgrade(&global));
     global
@@ -124,6 +127,8 @@ static GLOBAL_CATEGORIES: LazyLock<Arc<Categories>> = LazyLock::new(|| {
 pub struct Categories {
     id: CategoricalId,
     mapping: Mutex<Weak<CategoricalMapping>>,
+    // Set by `Categories::persist` to keep the mapping alive without any data using it.
+    persistent_mapping: Mutex<Option<Arc<CategoricalMapping>>>,
 }
 
 impl Categories {
@@ -149,6 +154,7 @@ impl Categories {
         let slf = Arc::new(Self {
             id: id.clone(),
             mapping,
+            persistent_mapping: Mutex::new(None),
         });
         registry.insert(id, Arc::downgrade(&slf));
         slf
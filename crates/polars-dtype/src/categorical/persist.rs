@@ -0,0 +1,177 @@
+//! A portable encoding of (frozen) categories, so that categorical ids can be
+//! reproduced across processes.
+//!
+//! Every string is written as `{byte_len};{string}`, the same encoding used for
+//! the enum values stored in the Arrow field metadata. A serialized
+//! [`Categories`] is prefixed with its name, namespace and physical type.
+use std::sync::Arc;
+
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_utils::aliases::{InitHashMaps, PlHashMap};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::{CatSize, CategoricalMapping, CategoricalPhysical, Categories, FrozenCategories};
+
+const CATEGORIES_TAG: &str = "categories;";
+const FROZEN_CATEGORIES_TAG: &str = "frozen;";
+
+fn encode_str(out: &mut String, s: &str) {
+    out.push_str(&s.len().to_string());
+    out.push(';');
+    out.push_str(s);
+}
+
+fn decode_str<'a>(encoded: &mut &'a str) -> PolarsResult<&'a str> {
+    let err = || polars_err!(ComputeError: "invalid serialized categories");
+    let (len, rest) = encoded.split_once(';').ok_or_else(err)?;
+    let len = len.parse::<usize>().map_err(|_| err())?;
+    polars_ensure!(rest.is_char_boundary(len), ComputeError: "invalid serialized categories");
+    let (s, rest) = rest.split_at(len);
+    *encoded = rest;
+    Ok(s)
+}
+
+/// Encodes the strings of a mapping, ordered by their categorical id.
+///
+/// Returns an error if an id has no string yet, which happens while the
+/// mapping is being extended concurrently.
+pub fn encode_mapping(mapping: &CategoricalMapping) -> PolarsResult<String> {
+    let mut out = String::new();
+    let n = mapping.num_cats_upper_bound();
+    for cat in 0..n {
+        let Some(s) = mapping.cat_to_str(cat as CatSize) else {
+            polars_bail!(
+                ComputeError: "cannot encode categorical mapping, id {cat} has no category (is it being extended concurrently?)"
+            );
+        };
+        encode_str(&mut out, s);
+    }
+    Ok(out)
+}
+
+/// Decodes strings which were encoded with [`encode_mapping`].
+pub fn decode_mapping(mut encoded: &str) -> impl Iterator<Item = PolarsResult<&str>> {
+    std::iter::from_fn(move || (!encoded.is_empty()).then(|| decode_str(&mut encoded)))
+}
+
+impl Categories {
+    /// Keeps the mapping of this Categories alive for as long as this object
+    /// is alive, even if no data uses it, and returns it.
+    ///
+    /// The mapping of a persistent Categories is embedded in the Arrow field
+    /// metadata, so that readers in other processes assign the same ids.
+    pub fn persist(&self) -> Arc<CategoricalMapping> {
+        let mapping = self.mapping();
+        *self.persistent_mapping.lock().unwrap() = Some(mapping.clone());
+        mapping
+    }
+
+    /// Whether [`Categories::persist`] was called on this Categories.
+    pub fn is_persistent(&self) -> bool {
+        self.persistent_mapping.lock().unwrap().is_some()
+    }
+
+    /// Inserts the given strings such that the nth string gets categorical id n.
+    ///
+    /// Returns an error if a string was already assigned a different id. All
+    /// ids are checked before anything is inserted, so on error the mapping is
+    /// left unchanged.
+    pub fn extend_mapping<'a, I: IntoIterator<Item = &'a str>>(
+        &self,
+        strings: I,
+    ) -> PolarsResult<Arc<CategoricalMapping>> {
+        let strings = strings.into_iter().collect::<Vec<_>>();
+        let mapping = self.mapping();
+        let n_existing = mapping.num_cats_upper_bound();
+        // The ids the new strings will get once inserted.
+        let mut new_cats = PlHashMap::new();
+        for (expected, s) in strings.iter().enumerate() {
+            let next = n_existing + new_cats.len();
+            let cat = match mapping.get_cat(s) {
+                Some(cat) => cat as usize,
+                None => *new_cats.entry(*s).or_insert(next),
+            };
+            polars_ensure!(
+                cat == expected,
+                ComputeError: "category '{s}' already has id {cat} in Categories '{}', expected id {expected}",
+                self.name()
+            );
+        }
+        polars_ensure!(
+            n_existing + new_cats.len() <= mapping.max_categories(),
+            ComputeError: "attempted to insert more categories than the maximum allowed"
+        );
+
+        for (expected, s) in strings.into_iter().enumerate() {
+            let cat = mapping.insert_cat(s)?;
+            // Only fails if the mapping was extended concurrently.
+            polars_ensure!(
+                cat as usize == expected,
+                ComputeError: "category '{s}' already has id {cat} in Categories '{}', expected id {expected}",
+                self.name()
+            );
+        }
+        Ok(mapping)
+    }
+
+    /// Serializes the identity and the current mapping of this Categories.
+    pub fn serialize_mapping(&self) -> PolarsResult<String> {
+        let mut out = String::from(CATEGORIES_TAG);
+        encode_str(&mut out, self.name());
+        encode_str(&mut out, self.namespace());
+        out.push_str(self.physical().as_str());
+        out.push(';');
+        out.push_str(&encode_mapping(&self.mapping())?);
+        Ok(out)
+    }
+
+    /// Loads a Categories serialized with [`Categories::serialize_mapping`].
+    ///
+    /// The loaded Categories is persistent, see [`Categories::persist`]. Returns
+    /// an error if a Categories with the same name, namespace and physical type
+    /// already exists with a conflicting mapping.
+    pub fn deserialize_mapping(encoded: &str) -> PolarsResult<Arc<Self>> {
+        let Some(mut encoded) = encoded.strip_prefix(CATEGORIES_TAG) else {
+            polars_bail!(ComputeError: "invalid serialized categories");
+        };
+        let name = decode_str(&mut encoded)?;
+        let namespace = decode_str(&mut encoded)?;
+        let (physical, encoded) = encoded
+            .split_once(';')
+            .ok_or_else(|| polars_err!(ComputeError: "invalid serialized categories"))?;
+        let physical: CategoricalPhysical = physical.parse().map_err(
+            |_| polars_err!(ComputeError: "invalid categorical physical type '{physical}'"),
+        )?;
+        polars_ensure!(!name.is_empty(), ComputeError: "cannot load the global categories");
+
+        let cats = Self::new(
+            PlSmallStr::from_str(name),
+            PlSmallStr::from_str(namespace),
+            physical,
+        );
+        let strings = decode_mapping(encoded).collect::<PolarsResult<Vec<_>>>()?;
+        cats.extend_mapping(strings)?;
+        cats.persist();
+        Ok(cats)
+    }
+}
+
+impl FrozenCategories {
+    /// Serializes these categories.
+    pub fn serialize_categories(&self) -> String {
+        let mut out = String::from(FROZEN_CATEGORIES_TAG);
+        for s in self.categories().values_iter() {
+            encode_str(&mut out, s);
+        }
+        out
+    }
+
+    /// Loads FrozenCategories serialized with [`FrozenCategories::serialize_categories`].
+    pub fn deserialize_categories(encoded: &str) -> PolarsResult<Arc<Self>> {
+        let Some(encoded) = encoded.strip_prefix(FROZEN_CATEGORIES_TAG) else {
+            polars_bail!(ComputeError: "invalid serialized frozen categories");
+        };
+        let strings = decode_mapping(encoded).collect::<PolarsResult<Vec<_>>>()?;
+        Self::new(strings)
+    }
+}
@@ -18,10 +18,11 @@ fn ext_to(s: &Column, dtype: DataType) -> PolarsResult<Column> {
         polars_bail!(ComputeError: "ext.to() requires an Extension dtype")
     };
 
-    Ok(s.apply_unary_elementwise(|s| {
+    s.try_apply_unary_elementwise(|s| {
         assert!(*s.dtype() == **storage);
-        s.clone().into_extension(typ.clone())
-    }))
+        typ.0.validate_storage(s)?;
+        Ok(s.clone().into_extension(typ.clone()))
+    })
 }
 
 fn ext_storage(s: &Column) -> PolarsResult<Column> {
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::prelude::{Column, IntoColumn};
+use polars_plan::dsl::{ColumnsUdf, SpecialEq};
+use polars_plan::plans::IRGeometryFunction;
+
+pub fn function_expr_to_udf(func: IRGeometryFunction) -> SpecialEq<Arc<dyn ColumnsUdf>> {
+    use IRGeometryFunction::*;
+    match func {
+        Bounds => map!(bounds),
+        Area => map!(area),
+        Length => map!(length),
+        Distance => map_as_slice!(distance),
+        Contains => map_as_slice!(contains),
+        Intersects => map_as_slice!(intersects),
+    }
+}
+
+fn bounds(s: &Column) -> PolarsResult<Column> {
+    polars_ops::prelude::st_bounds(s.as_materialized_series()).map(IntoColumn::into_column)
+}
+
+fn area(s: &Column) -> PolarsResult<Column> {
+    polars_ops::prelude::st_area(s.as_materialized_series()).map(IntoColumn::into_column)
+}
+
+fn length(s: &Column) -> PolarsResult<Column> {
+    polars_ops::prelude::st_length(s.as_materialized_series()).map(IntoColumn::into_column)
+}
+
+fn distance(s: &[Column]) -> PolarsResult<Column> {
+    polars_ops::prelude::st_distance(s[0].as_materialized_series(), s[1].as_materialized_series())
+        .map(IntoColumn::into_column)
+}
+
+fn contains(s: &[Column]) -> PolarsResult<Column> {
+    polars_ops::prelude::st_contains(s[0].as_materialized_series(), s[1].as_materialized_series())
+        .map(IntoColumn::into_column)
+}
+
+fn intersects(s: &[Column]) -> PolarsResult<Column> {
+    polars_ops::prelude::st_intersects(s[0].as_materialized_series(), s[1].as_materialized_series())
+        .map(IntoColumn::into_column)
+}
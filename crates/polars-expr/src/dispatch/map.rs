@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::prelude::{Column, IntoColumn};
+use polars_plan::dsl::{ColumnsUdf, SpecialEq};
+use polars_plan::plans::IRMapFunction;
+
+pub fn function_expr_to_udf(func: IRMapFunction) -> SpecialEq<Arc<dyn ColumnsUdf>> {
+    use IRMapFunction::*;
+    match func {
+        Get => map_as_slice!(get),
+        ContainsKey => map_as_slice!(contains_key),
+        Keys => map!(keys),
+        Values => map!(values),
+        FromLists => map_as_slice!(from_lists),
+    }
+}
+
+fn get(args: &mut [Column]) -> PolarsResult<Column> {
+    polars_ops::prelude::map_get(
+        args[0].as_materialized_series(),
+        args[1].as_materialized_series(),
+    )
+    .map(IntoColumn::into_column)
+}
+
+fn contains_key(args: &mut [Column]) -> PolarsResult<Column> {
+    polars_ops::prelude::map_contains_key(
+        args[0].as_materialized_series(),
+        args[1].as_materialized_series(),
+    )
+    .map(IntoColumn::into_column)
+}
+
+fn keys(s: &Column) -> PolarsResult<Column> {
+    polars_ops::prelude::map_keys(s.as_materialized_series()).map(IntoColumn::into_column)
+}
+
+fn values(s: &Column) -> PolarsResult<Column> {
+    polars_ops::prelude::map_values(s.as_materialized_series()).map(IntoColumn::into_column)
+}
+
+fn from_lists(args: &mut [Column]) -> PolarsResult<Column> {
+    polars_ops::prelude::map_from_lists(
+        args[0].as_materialized_series(),
+        args[1].as_materialized_series(),
+    )
+    .map(IntoColumn::into_column)
+}
@@ -216,13 +216,13 @@ pub(super) fn arg_max(s: &Column) -> PolarsResult<Column> {
 pub(super) fn arg_sort(s: &Column, descending: bool, nulls_last: bool) -> PolarsResult<Column> {
     // @scalar-opt
     Ok(s.as_materialized_series()
-        .arg_sort(SortOptions {
+        .try_arg_sort(SortOptions {
             descending,
             nulls_last,
             multithreaded: true,
             maintain_order: false,
             limit: None,
-        })
+        })?
         .into_column())
 }
 
@@ -1045,3 +1045,7 @@ pub fn repeat(args: &[Column]) -> PolarsResult<Column> {
 pub fn dynamic_pred(columns: &[Column], pred: &DynamicPred) -> PolarsResult<Column> {
     pred.evaluate(columns)
 }
+
+pub fn dynamic_pred_skip_batch(columns: &[Column], pred: &DynamicPred) -> PolarsResult<Column> {
+    pred.evaluate_skip_batch(columns)
+}
@@ -108,9 +108,13 @@ mod cum;
 mod datetime;
 #[cfg(feature = "dtype-extension")]
 mod extension;
+#[cfg(feature = "dtype-geometry")]
+mod geometry;
 mod groups_dispatch;
 mod horizontal;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 mod misc;
 mod pow;
 #[cfg(feature = "random")]
@@ -132,6 +136,10 @@ pub(crate) mod struct_;
 mod temporal;
 #[cfg(feature = "trigonometry")]
 mod trigonometry;
+#[cfg(feature = "dtype-uuid")]
+mod uuid;
+#[cfg(feature = "dtype-variant")]
+mod variant;
 
 pub use groups_dispatch::drop_items;
 
@@ -147,6 +155,14 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         #[cfg(feature = "dtype-extension")]
         F::Extension(func) => extension::function_expr_to_udf(func),
         F::ListExpr(func) => list::function_expr_to_udf(func),
+        #[cfg(feature = "dtype-geometry")]
+        F::GeometryExpr(func) => geometry::function_expr_to_udf(func),
+        #[cfg(feature = "dtype-map")]
+        F::MapExpr(func) => map::function_expr_to_udf(func),
+        #[cfg(feature = "dtype-uuid")]
+        F::UuidExpr(func) => uuid::function_expr_to_udf(func),
+        #[cfg(feature = "dtype-variant")]
+        F::VariantExpr(func) => variant::function_expr_to_udf(func),
         #[cfg(feature = "strings")]
         F::StringExpr(func) => strings::function_expr_to_udf(func),
         #[cfg(feature = "dtype-struct")]
@@ -531,6 +547,9 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::DynamicPred { pred } => {
             map_as_slice!(misc::dynamic_pred, &pred)
         },
+        F::DynamicPredSkipBatch { pred } => {
+            map_as_slice!(misc::dynamic_pred_skip_batch, &pred)
+        },
     }
 }
 
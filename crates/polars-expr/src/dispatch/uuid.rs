@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use polars_core::error::{PolarsResult, polars_err};
+use polars_core::prelude::{Column, IntoColumn};
+use polars_plan::dsl::{ColumnsUdf, SpecialEq};
+use polars_plan::plans::IRUuidFunction;
+
+pub fn function_expr_to_udf(func: IRUuidFunction) -> SpecialEq<Arc<dyn ColumnsUdf>> {
+    use IRUuidFunction::*;
+    match func {
+        Uuid4 => map!(uuid4),
+        Uuid7 => map!(uuid7),
+        Timestamp => map!(timestamp),
+    }
+}
+
+fn get_len(s: &Column) -> PolarsResult<usize> {
+    s.get(0)?
+        .extract::<usize>()
+        .ok_or_else(|| polars_err!(ComputeError: "invalid length input for UUID generation"))
+}
+
+fn uuid4(s: &Column) -> PolarsResult<Column> {
+    let n = get_len(s)?;
+    Ok(polars_ops::prelude::uuid4(s.name().clone(), n).into_column())
+}
+
+fn uuid7(s: &Column) -> PolarsResult<Column> {
+    let n = get_len(s)?;
+    Ok(polars_ops::prelude::uuid7(s.name().clone(), n).into_column())
+}
+
+fn timestamp(s: &Column) -> PolarsResult<Column> {
+    polars_ops::prelude::uuid_timestamp(s.as_materialized_series()).map(IntoColumn::into_column)
+}
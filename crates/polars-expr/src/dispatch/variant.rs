@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::prelude::{Column, DataType, IntoColumn, PlSmallStr};
+use polars_plan::dsl::{ColumnsUdf, SpecialEq};
+use polars_plan::plans::IRVariantFunction;
+
+pub fn function_expr_to_udf(func: IRVariantFunction) -> SpecialEq<Arc<dyn ColumnsUdf>> {
+    use IRVariantFunction::*;
+    match func {
+        Get(path) => map!(get, &path),
+        Cast { dtype, strict } => map!(cast, &dtype, strict),
+        ToJson => map!(to_json),
+        TypeName => map!(type_name),
+    }
+}
+
+fn get(s: &Column, path: &PlSmallStr) -> PolarsResult<Column> {
+    polars_ops::prelude::variant_get(s.as_materialized_series(), path).map(IntoColumn::into_column)
+}
+
+fn cast(s: &Column, dtype: &DataType, strict: bool) -> PolarsResult<Column> {
+    polars_ops::prelude::variant_cast(s.as_materialized_series(), dtype, strict)
+        .map(IntoColumn::into_column)
+}
+
+fn to_json(s: &Column) -> PolarsResult<Column> {
+    polars_ops::prelude::variant_to_json(s.as_materialized_series()).map(IntoColumn::into_column)
+}
+
+fn type_name(s: &Column) -> PolarsResult<Column> {
+    polars_ops::prelude::variant_type_name(s.as_materialized_series()).map(IntoColumn::into_column)
+}
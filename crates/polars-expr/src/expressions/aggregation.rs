@@ -218,12 +218,12 @@ impl PhysicalExpr for AggregationExpr {
             match self.agg_type.groupby {
                 GroupByMethod::Min => {
                     let (c, groups) = ac.get_final_aggregation();
-                    let agg_c = c.agg_min(&groups);
+                    let agg_c = c.try_agg_min(&groups)?;
                     AggregatedScalar(agg_c.with_name(keep_name))
                 },
                 GroupByMethod::Max => {
                     let (c, groups) = ac.get_final_aggregation();
-                    let agg_c = c.agg_max(&groups);
+                    let agg_c = c.try_agg_max(&groups)?;
                     AggregatedScalar(agg_c.with_name(keep_name))
                 },
                 GroupByMethod::ArgMin => {
@@ -248,7 +248,7 @@ impl PhysicalExpr for AggregationExpr {
                 },
                 GroupByMethod::Sum => {
                     let (c, groups) = ac.get_final_aggregation();
-                    let agg_c = c.agg_sum(&groups);
+                    let agg_c = c.try_agg_sum(&groups)?;
                     AggregatedScalar(agg_c.with_name(keep_name))
                 },
                 GroupByMethod::Count { include_nulls } => {
@@ -461,7 +461,7 @@ impl PhysicalExpr for AggregationExpr {
                             )
                             .into_column()
                         } else {
-                            c.agg_min(&groups)
+                            c.try_agg_min(&groups)?
                         };
                         AggregatedScalar(agg_c.with_name(keep_name))
                     }
@@ -481,7 +481,7 @@ impl PhysicalExpr for AggregationExpr {
                             )
                             .into_column()
                         } else {
-                            c.agg_max(&groups)
+                            c.try_agg_max(&groups)?
                         };
                         AggregatedScalar(agg_c.with_name(keep_name))
                     }
@@ -80,23 +80,23 @@ impl PhysicalExpr for SortExpr {
                                     // SAFETY: group tuples are always in bounds.
                                     let group = unsafe { series.take_slice_unchecked(idx) };
 
-                                    let sorted_idx = group.arg_sort(sort_options);
+                                    let sorted_idx = group.try_arg_sort(sort_options)?;
                                     let new_idx = map_sorted_indices_to_group_idx(&sorted_idx, idx);
-                                    (new_idx.first().copied().unwrap_or(first), new_idx)
+                                    Ok((new_idx.first().copied().unwrap_or(first), new_idx))
                                 })
-                                .collect()
+                                .collect::<PolarsResult<_>>()
                         },
                         GroupsType::Slice { groups, .. } => groups
                             .par_iter()
                             .map(|&[first, len]| {
                                 let group = series.slice(first as i64, len as usize);
-                                let sorted_idx = group.arg_sort(sort_options);
+                                let sorted_idx = group.try_arg_sort(sort_options)?;
                                 let new_idx = map_sorted_indices_to_group_slice(&sorted_idx, first);
-                                (new_idx.first().copied().unwrap_or(first), new_idx)
+                                Ok((new_idx.first().copied().unwrap_or(first), new_idx))
                             })
-                            .collect(),
+                            .collect::<PolarsResult<_>>(),
                     }
-                });
+                })?;
                 let groups = GroupsType::Idx(groups);
                 ac.with_groups(groups.into_sliceable());
             },
@@ -87,12 +87,12 @@ fn sort_by_groups_single_by(
             // SAFETY: group tuples are always in bounds.
             let group = unsafe { sort_by_s.take_slice_unchecked(idx) };
 
-            let sorted_idx = group.arg_sort(options);
+            let sorted_idx = group.try_arg_sort(options)?;
             map_sorted_indices_to_group_idx(&sorted_idx, idx)
         },
         GroupsIndicator::Slice([first, len]) => {
             let group = sort_by_s.slice(first as i64, len as usize);
-            let sorted_idx = group.arg_sort(options);
+            let sorted_idx = group.try_arg_sort(options)?;
             map_sorted_indices_to_group_slice(&sorted_idx, first)
         },
     };
@@ -119,11 +119,11 @@ fn sort_by_groups_no_match_single<'a>(
             .map(|(opt_s, s_sort_by)| match (opt_s, s_sort_by) {
                 (Some(s), Some(s_sort_by)) => {
                     polars_ensure!(s.len() == s_sort_by.len(), ComputeError: "series lengths don't match in 'sort_by' expression");
-                    let idx = s_sort_by.arg_sort(SortOptions {
+                    let idx = s_sort_by.try_arg_sort(SortOptions {
                         // We are already in par iter.
                         multithreaded: false,
                         ..options
-                    });
+                    })?;
                     Ok(Some(unsafe { s.take_unchecked(&idx) }))
                 },
                 _ => Ok(None),
@@ -208,7 +208,7 @@ impl PhysicalExpr for SortByExpr {
         let (series, sorted_idx) = if self.by.len() == 1 {
             let sorted_idx_f = || {
                 let s_sort_by = self.by[0].evaluate(df, state)?;
-                Ok(s_sort_by.arg_sort(SortOptions::from(&self.sort_options)))
+                s_sort_by.try_arg_sort(SortOptions::from(&self.sort_options))
             };
             POOL.install(|| rayon::join(series_f, sorted_idx_f))
         } else {
@@ -72,6 +72,8 @@ macro_rules! downcast_single_key_ca {
             DataType::Date => { let $ca = $self.date().unwrap().physical(); $($body)* },
             #[cfg(feature = "dtype-time")]
             DataType::Time => { let $ca = $self.time().unwrap().physical(); $($body)* },
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => { let $ca = $self.interval().unwrap().physical(); $($body)* },
             #[cfg(feature = "dtype-datetime")]
             DataType::Datetime(..) => { let $ca = $self.datetime().unwrap().physical(); $($body)* },
             #[cfg(feature = "dtype-duration")]
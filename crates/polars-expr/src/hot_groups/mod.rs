@@ -83,6 +83,8 @@ pub fn new_hash_hot_grouper(key_schema: Arc<Schema>, num_groups: usize) -> Box<d
             DataType::Duration(_) => Box::new(SK::<Int64Type>::new(dt, ng)),
             #[cfg(feature = "dtype-time")]
             DataType::Time => Box::new(SK::<Int64Type>::new(dt, ng)),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => Box::new(SK::<Int128Type>::new(dt, ng)),
 
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(_, _) => Box::new(SK::<Int128Type>::new(dt, ng)),
@@ -104,6 +104,8 @@ pub fn new_idx_table(key_schema: Arc<Schema>) -> Box<dyn IdxTable> {
             DataType::Duration(_) => Box::new(SKIT::<Int64Type>::new()),
             #[cfg(feature = "dtype-time")]
             DataType::Time => Box::new(SKIT::<Int64Type>::new()),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => Box::new(SKIT::<Int128Type>::new()),
 
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(_, _) => Box::new(SKIT::<Int128Type>::new()),
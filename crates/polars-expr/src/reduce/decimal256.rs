@@ -0,0 +1,222 @@
+use arrow::types::i256;
+use polars_compute::decimal256::{DEC256_MAX_PREC, dec256_add, dec256_to_f64};
+
+use super::*;
+
+/// The aggregations supported on `Decimal256` columns.
+#[derive(Clone, Copy)]
+pub enum Decimal256Agg {
+    Sum,
+    Mean,
+    Min,
+    Max,
+}
+
+#[derive(Clone, Copy)]
+struct State {
+    /// The running sum or extremum, `None` if the sum overflowed or there was no value
+    /// for the extremum yet.
+    acc: Option<i256>,
+    /// The running sum as a float, to fall back on if the exact sum of a mean overflows.
+    float_sum: f64,
+    count: IdxSize,
+}
+
+impl State {
+    fn new(v: i256, scale: usize) -> Self {
+        Self {
+            acc: Some(v),
+            float_sum: dec256_to_f64(v, scale),
+            count: 1,
+        }
+    }
+
+    fn combine(&mut self, other: &State, agg: Decimal256Agg) {
+        match agg {
+            Decimal256Agg::Sum | Decimal256Agg::Mean => {
+                self.acc = self
+                    .acc
+                    .zip(other.acc)
+                    .and_then(|(a, b)| dec256_add(a, b, DEC256_MAX_PREC));
+                self.float_sum += other.float_sum;
+            },
+            // All values of a column share the scale, so the unscaled values compare like
+            // the decimals.
+            Decimal256Agg::Min => self.acc = self.acc.into_iter().chain(other.acc).min(),
+            Decimal256Agg::Max => self.acc = self.acc.into_iter().chain(other.acc).max(),
+        }
+        self.count += other.count;
+    }
+}
+
+/// Reduces the unscaled 256-bit values of a `Decimal256` column, whose physical
+/// representation isn't a primitive array the generic reducers can work on.
+///
+/// Like the in-memory engine, a sum that overflows is null and the mean is a `Float64`.
+pub struct Decimal256GroupedReduction {
+    agg: Decimal256Agg,
+    in_dtype: DataType,
+    values: Vec<State>,
+    evicted_values: Vec<State>,
+}
+
+impl Decimal256GroupedReduction {
+    pub fn new(in_dtype: DataType, agg: Decimal256Agg) -> Self {
+        assert!(in_dtype.is_decimal256());
+        Self {
+            agg,
+            in_dtype,
+            values: Vec::new(),
+            evicted_values: Vec::new(),
+        }
+    }
+
+    fn scale(&self) -> usize {
+        match self.in_dtype {
+            DataType::Decimal256(_, scale) => scale,
+            _ => unreachable!(),
+        }
+    }
+
+    fn init(&self) -> State {
+        let acc = match self.agg {
+            Decimal256Agg::Sum | Decimal256Agg::Mean => Some(i256::from_words(0, 0)),
+            Decimal256Agg::Min | Decimal256Agg::Max => None,
+        };
+        State {
+            acc,
+            float_sum: 0.0,
+            count: 0,
+        }
+    }
+
+    fn finish(&self, values: Vec<State>) -> Series {
+        let scale = self.scale();
+        match self.agg {
+            Decimal256Agg::Sum => Decimal256Chunked::from_i256_iter(
+                PlSmallStr::EMPTY,
+                values.into_iter().map(|s| s.acc),
+                DEC256_MAX_PREC,
+                scale,
+            )
+            .into_series(),
+            Decimal256Agg::Min | Decimal256Agg::Max => {
+                let DataType::Decimal256(precision, _) = self.in_dtype else {
+                    unreachable!()
+                };
+                Decimal256Chunked::from_i256_iter(
+                    PlSmallStr::EMPTY,
+                    values.into_iter().map(|s| s.acc),
+                    precision,
+                    scale,
+                )
+                .into_series()
+            },
+            Decimal256Agg::Mean => {
+                let ca: Float64Chunked = values
+                    .into_iter()
+                    .map(|s| {
+                        let sum = s.acc.map_or(s.float_sum, |acc| dec256_to_f64(acc, scale));
+                        (s.count != 0).then(|| sum / s.count as f64)
+                    })
+                    .collect_ca(PlSmallStr::EMPTY);
+                ca.into_series()
+            },
+        }
+    }
+}
+
+impl GroupedReduction for Decimal256GroupedReduction {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(Self::new(self.in_dtype.clone(), self.agg))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional);
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.values.resize(num_groups as usize, self.init());
+    }
+
+    fn update_group(
+        &mut self,
+        values: &[&Column],
+        group_idx: IdxSize,
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        let &[values] = values else { unreachable!() };
+        assert!(values.dtype() == &self.in_dtype);
+        let ca = values.as_materialized_series().decimal256()?; // @scalar-opt
+        let (agg, scale) = (self.agg, self.scale());
+        let grp = &mut self.values[group_idx as usize];
+        for v in ca.iter().flatten() {
+            grp.combine(&State::new(v, scale), agg);
+        }
+        Ok(())
+    }
+
+    unsafe fn update_groups_while_evicting(
+        &mut self,
+        values: &[&Column],
+        subset: &[IdxSize],
+        group_idxs: &[EvictIdx],
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        let &[values] = values else { unreachable!() };
+        assert!(values.dtype() == &self.in_dtype);
+        assert!(subset.len() == group_idxs.len());
+        let ca = values.as_materialized_series().decimal256()?; // @scalar-opt
+        let (agg, scale, init) = (self.agg, self.scale(), self.init());
+        for (i, g) in subset.iter().zip(group_idxs) {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            let grp = unsafe { self.values.get_unchecked_mut(g.idx()) };
+            if g.should_evict() {
+                let old = core::mem::replace(grp, init);
+                self.evicted_values.push(old);
+            }
+            if let Some(v) = ca.get(*i as usize) {
+                grp.combine(&State::new(v, scale), agg);
+            }
+        }
+        Ok(())
+    }
+
+    unsafe fn combine_subset(
+        &mut self,
+        other: &dyn GroupedReduction,
+        subset: &[IdxSize],
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        assert!(self.in_dtype == other.in_dtype);
+        assert!(subset.len() == group_idxs.len());
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            for (i, g) in subset.iter().zip(group_idxs) {
+                let v = other.values.get_unchecked(*i as usize);
+                let grp = self.values.get_unchecked_mut(*g as usize);
+                grp.combine(v, self.agg);
+            }
+        }
+        Ok(())
+    }
+
+    fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
+        Box::new(Self {
+            agg: self.agg,
+            in_dtype: self.in_dtype.clone(),
+            values: core::mem::take(&mut self.evicted_values),
+            evicted_values: Vec::new(),
+        })
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let values = core::mem::take(&mut self.values);
+        Ok(self.finish(values))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
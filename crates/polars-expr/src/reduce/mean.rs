@@ -5,6 +5,8 @@ use num_traits::{AsPrimitive, Zero};
 use polars_core::with_match_physical_numeric_polars_type;
 
 use super::*;
+#[cfg(feature = "dtype-decimal256")]
+use crate::reduce::decimal256::{Decimal256Agg, Decimal256GroupedReduction};
 
 pub fn new_mean_reduction(dtype: DataType) -> PolarsResult<Box<dyn GroupedReduction>> {
     // TODO: Move the error checks up and make this function infallible
@@ -19,6 +21,8 @@ pub fn new_mean_reduction(dtype: DataType) -> PolarsResult<Box<dyn GroupedReduct
         },
         #[cfg(feature = "dtype-decimal")]
         Decimal(_, _) => Box::new(VGR::new(dtype, NumMeanReducer::<Int128Type>(PhantomData))),
+        #[cfg(feature = "dtype-decimal256")]
+        Decimal256(_, _) => Box::new(Decimal256GroupedReduction::new(dtype, Decimal256Agg::Mean)),
         Null => Box::new(super::NullGroupedReduction::new(Scalar::null(
             DataType::Null,
         ))),
@@ -12,6 +12,8 @@ use polars_utils::float::IsFloat;
 use polars_utils::min_max::MinMax;
 
 use super::*;
+#[cfg(feature = "dtype-decimal256")]
+use crate::reduce::decimal256::{Decimal256Agg, Decimal256GroupedReduction};
 
 pub fn new_min_reduction(
     dtype: DataType,
@@ -47,6 +49,8 @@ pub fn new_min_reduction(
         },
         #[cfg(feature = "dtype-decimal")]
         Decimal(_, _) => Box::new(VMGR::new(dtype, NumReducer::<Min<Int128Type>>::new())),
+        #[cfg(feature = "dtype-decimal256")]
+        Decimal256(_, _) => Box::new(Decimal256GroupedReduction::new(dtype, Decimal256Agg::Min)),
         #[cfg(feature = "dtype-categorical")]
         Categorical(cats, map) => with_match_categorical_physical_type!(cats.physical(), |$C| {
             Box::new(VMGR::new(dtype.clone(), CatMinReducer::<$C>(map.clone(), PhantomData)))
@@ -89,6 +93,8 @@ pub fn new_max_reduction(
         },
         #[cfg(feature = "dtype-decimal")]
         Decimal(_, _) => Box::new(VMGR::new(dtype, NumReducer::<Max<Int128Type>>::new())),
+        #[cfg(feature = "dtype-decimal256")]
+        Decimal256(_, _) => Box::new(Decimal256GroupedReduction::new(dtype, Decimal256Agg::Max)),
         #[cfg(feature = "dtype-categorical")]
         Categorical(cats, map) => with_match_categorical_physical_type!(cats.physical(), |$C| {
             Box::new(VMGR::new(dtype.clone(), CatMaxReducer::<$C>(map.clone(), PhantomData)))
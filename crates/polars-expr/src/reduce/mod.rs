@@ -8,6 +8,8 @@ mod convert;
 mod count;
 #[cfg(feature = "cov")]
 mod cov;
+#[cfg(feature = "dtype-decimal256")]
+mod decimal256;
 mod first_last;
 mod first_last_nonnull;
 mod implode;
@@ -8,6 +8,8 @@ use polars_utils::float::IsFloat;
 use polars_utils::float16::pf16;
 
 use super::*;
+#[cfg(feature = "dtype-decimal256")]
+use crate::reduce::decimal256::{Decimal256Agg, Decimal256GroupedReduction};
 
 pub trait SumCast: Sized {
     type Sum: NumericNative + From<Self>;
@@ -59,6 +61,8 @@ pub fn new_sum_reduction(dtype: DataType) -> PolarsResult<Box<dyn GroupedReducti
         },
         #[cfg(feature = "dtype-decimal")]
         Decimal(_, _) => Box::new(VGR::new(dtype, NumSumReducer::<Int128Type>(PhantomData))),
+        #[cfg(feature = "dtype-decimal256")]
+        Decimal256(_, _) => Box::new(Decimal256GroupedReduction::new(dtype, Decimal256Agg::Sum)),
         Duration(_) => Box::new(VGR::new(dtype, NumSumReducer::<Int64Type>(PhantomData))),
         Null => Box::new(super::NullGroupedReduction::new(Scalar::null(
             DataType::Null,
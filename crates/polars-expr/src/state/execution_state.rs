@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 use std::sync::atomic::{AtomicI64, Ordering};
 use std::sync::{Mutex, RwLock};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use arrow::bitmap::Bitmap;
 use bitflags::bitflags;
@@ -11,7 +11,7 @@ use polars_ops::prelude::ChunkJoinOptIds;
 use polars_utils::relaxed_cell::RelaxedCell;
 use polars_utils::unique_id::UniqueId;
 
-use super::NodeTimer;
+use super::{NodeTimer, ProgressCallback};
 use crate::prelude::AggregationContext;
 
 pub type JoinTuplesCache = Arc<Mutex<PlHashMap<String, ChunkJoinOptIds>>>;
@@ -112,6 +112,16 @@ struct CachedValue {
     df: DataFrame,
 }
 
+/// Resource limits of a single query.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QueryLimits {
+    /// Wall-clock time after which the query is aborted with an error.
+    pub timeout: Option<Duration>,
+    /// Maximum number of bytes the query may buffer. Only enforced by the
+    /// streaming engine.
+    pub memory_limit: Option<usize>,
+}
+
 /// State/ cache that is maintained during the Execution of the physical plan.
 #[derive(Clone)]
 pub struct ExecutionState {
@@ -131,6 +141,10 @@ pub struct ExecutionState {
     pub element: Arc<Option<(Column, Option<Bitmap>)>>,
     node_timer: Option<NodeTimer>,
     stop: Arc<RelaxedCell<bool>>,
+    /// The instant the query times out, together with the configured timeout.
+    deadline: Option<(Instant, Duration)>,
+    memory_limit: Option<usize>,
+    progress_callback: Option<ProgressCallback>,
 }
 
 impl ExecutionState {
@@ -153,6 +167,9 @@ impl ExecutionState {
             element: Default::default(),
             node_timer: None,
             stop: Arc::new(RelaxedCell::from(false)),
+            deadline: None,
+            memory_limit: None,
+            progress_callback: None,
         }
     }
 
@@ -184,9 +201,34 @@ impl ExecutionState {
     pub fn should_stop(&self) -> PolarsResult<()> {
         try_raise_keyboard_interrupt();
         polars_ensure!(!self.stop.load(), ComputeError: "query interrupted");
+        if let Some((deadline, timeout)) = self.deadline {
+            polars_ensure!(
+                Instant::now() < deadline,
+                ComputeError: "query timed out after {:?}", timeout
+            );
+        }
         Ok(())
     }
 
+    /// Apply the limits of the query, the timeout starts counting now.
+    pub fn set_limits(&mut self, limits: QueryLimits) {
+        self.deadline = limits.timeout.map(|t| (Instant::now() + t, t));
+        self.memory_limit = limits.memory_limit;
+    }
+
+    pub fn memory_limit(&self) -> Option<usize> {
+        self.memory_limit
+    }
+
+    /// Report the progress of the query to `callback`. Only supported by the streaming engine.
+    pub fn set_progress_callback(&mut self, callback: ProgressCallback) {
+        self.progress_callback = Some(callback);
+    }
+
+    pub fn progress_callback(&self) -> Option<&ProgressCallback> {
+        self.progress_callback.as_ref()
+    }
+
     pub fn cancel_token(&self) -> Arc<RelaxedCell<bool>> {
         self.stop.clone()
     }
@@ -223,6 +265,9 @@ impl ExecutionState {
             with_fields_ac: self.with_fields_ac.clone(),
             node_timer: self.node_timer.clone(),
             stop: self.stop.clone(),
+            deadline: self.deadline,
+            memory_limit: self.memory_limit,
+            progress_callback: self.progress_callback.clone(),
         }
     }
 
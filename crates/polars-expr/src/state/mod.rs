@@ -1,5 +1,7 @@
 mod execution_state;
 mod node_timer;
+mod progress;
 
 pub use execution_state::*;
 use node_timer::*;
+pub use progress::*;
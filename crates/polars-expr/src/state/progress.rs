@@ -0,0 +1,65 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use polars_utils::pl_str::PlSmallStr;
+
+/// Progress of a single file source of a streaming query.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SourceProgress {
+    pub name: PlSmallStr,
+    pub files_done: u64,
+    pub files_total: u64,
+    /// Row groups decoded so far, only reported by readers that know them (e.g. Parquet).
+    pub row_groups_done: u64,
+    /// `None` until the metadata of all files was read.
+    pub row_groups_total: Option<u64>,
+    /// Compressed size of the row groups decoded so far, according to the file metadata.
+    pub bytes_read: u64,
+    /// `None` until the metadata of all files was read.
+    pub bytes_total: Option<u64>,
+}
+
+/// Progress of a single sink of a streaming query.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SinkProgress {
+    pub name: PlSmallStr,
+    pub rows_written: u64,
+}
+
+/// Snapshot of the progress of a running query.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QueryProgress {
+    pub elapsed: Duration,
+    pub sources: Vec<SourceProgress>,
+    pub sinks: Vec<SinkProgress>,
+    /// Set on the last report, sent after the query completed successfully.
+    pub finished: bool,
+}
+
+/// Callback receiving the progress of a streaming query every `interval`.
+#[derive(Clone)]
+pub struct ProgressCallback {
+    pub callback: Arc<dyn Fn(&QueryProgress) + Send + Sync>,
+    pub interval: Duration,
+}
+
+impl ProgressCallback {
+    pub fn new(
+        interval: Duration,
+        callback: impl Fn(&QueryProgress) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            callback: Arc::new(callback),
+            interval,
+        }
+    }
+}
+
+impl fmt::Debug for ProgressCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProgressCallback")
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
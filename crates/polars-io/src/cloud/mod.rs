@@ -7,6 +7,8 @@ mod object_store_setup;
 pub mod options;
 #[cfg(feature = "cloud")]
 mod polars_object_store;
+#[cfg(feature = "cloud")]
+pub mod range_cache;
 
 #[cfg(feature = "cloud")]
 pub use glob::*;
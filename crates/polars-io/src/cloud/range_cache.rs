@@ -0,0 +1,303 @@
+//! Optional cache for byte ranges fetched from object stores.
+//!
+//! Selected through `POLARS_BYTE_RANGE_CACHE`:
+//! * `memory` - ranges are kept in an in-process LRU bounded to
+//!   `POLARS_BYTE_RANGE_CACHE_MEMORY_SIZE` bytes.
+//! * `disk` - ranges are additionally persisted to the file cache directory, where they can be
+//!   reused across processes. These are subject to the file cache TTL and size limit.
+//!
+//! Entries are keyed by the object path, its remote version (ETag or last-modified time), and the
+//! exact byte range, so a changed object never serves stale bytes.
+use std::ops::Range;
+use std::sync::LazyLock;
+
+use polars_buffer::Buffer;
+use polars_core::config::verbose;
+use polars_utils::cache::LruCache;
+use polars_utils::pl_path::PlRefPath;
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::relaxed_cell::RelaxedCell;
+
+use crate::configs::byte_range_cache_memory_size;
+
+/// Upper bound on the number of ranges held in memory, independent of their total size.
+const MAX_MEMORY_ENTRIES: usize = 64 * 1024;
+
+pub static BYTE_RANGE_CACHE: LazyLock<ByteRangeCache> = LazyLock::new(|| {
+    let mode = match std::env::var("POLARS_BYTE_RANGE_CACHE").as_deref() {
+        Err(_) | Ok("" | "0" | "none") => ByteRangeCacheMode::Disabled,
+        Ok("1" | "memory") => ByteRangeCacheMode::Memory,
+        Ok("disk") => ByteRangeCacheMode::Disk,
+        Ok(v) => panic!("invalid value for POLARS_BYTE_RANGE_CACHE: {v}"),
+    };
+
+    if verbose() && mode != ByteRangeCacheMode::Disabled {
+        eprintln!("byte_range_cache: mode: {mode:?}")
+    }
+
+    ByteRangeCache::new(mode, byte_range_cache_memory_size())
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ByteRangeCacheMode {
+    Disabled = 0,
+    Memory = 1,
+    /// Memory and disk.
+    Disk = 2,
+}
+
+impl ByteRangeCacheMode {
+    fn from_u8(v: u8) -> Self {
+        match v {
+            0 => Self::Disabled,
+            1 => Self::Memory,
+            2 => Self::Disk,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ByteRangeCacheKey {
+    pub uri: PlRefPath,
+    /// Remote version of the object, e.g. its ETag.
+    pub version: PlSmallStr,
+    pub range: Range<usize>,
+}
+
+impl ByteRangeCacheKey {
+    #[cfg(feature = "file_cache")]
+    fn disk_key_hash(&self) -> String {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(self.uri.as_str().as_bytes());
+        hasher.update(&(self.range.start as u64).to_le_bytes());
+        hasher.update(&(self.range.end as u64).to_le_bytes());
+        hasher.finalize().to_hex()[..32].to_string()
+    }
+
+    #[cfg(feature = "file_cache")]
+    fn disk_version_hash(&self) -> String {
+        blake3::hash(self.version.as_bytes()).to_hex()[..16].to_string()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ByteRangeCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Total size of the ranges held in memory.
+    pub memory_size: usize,
+}
+
+struct MemoryRanges {
+    lru: LruCache<ByteRangeCacheKey, Buffer<u8>>,
+    size: usize,
+}
+
+impl MemoryRanges {
+    fn pop_lru(&mut self) -> bool {
+        let Some((_, v)) = self.lru.pop_lru() else {
+            return false;
+        };
+        self.size -= v.len();
+        true
+    }
+}
+
+pub struct ByteRangeCache {
+    mode: RelaxedCell<u8>,
+    max_memory_size: RelaxedCell<usize>,
+    memory: std::sync::Mutex<MemoryRanges>,
+    hits: RelaxedCell<u64>,
+    misses: RelaxedCell<u64>,
+}
+
+impl ByteRangeCache {
+    pub fn new(mode: ByteRangeCacheMode, max_memory_size: usize) -> Self {
+        Self {
+            mode: RelaxedCell::from(mode as u8),
+            max_memory_size: RelaxedCell::from(max_memory_size),
+            memory: std::sync::Mutex::new(MemoryRanges {
+                lru: LruCache::with_capacity(MAX_MEMORY_ENTRIES),
+                size: 0,
+            }),
+            hits: RelaxedCell::from(0),
+            misses: RelaxedCell::from(0),
+        }
+    }
+
+    pub fn mode(&self) -> ByteRangeCacheMode {
+        ByteRangeCacheMode::from_u8(self.mode.load())
+    }
+
+    pub fn set_mode(&self, mode: ByteRangeCacheMode) {
+        self.mode.store(mode as u8)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.mode() != ByteRangeCacheMode::Disabled
+    }
+
+    pub fn set_max_memory_size(&self, max_memory_size: usize) {
+        self.max_memory_size.store(max_memory_size);
+
+        let mut memory = self.memory.lock().unwrap();
+        while memory.size > max_memory_size && memory.pop_lru() {}
+    }
+
+    pub fn stats(&self) -> ByteRangeCacheStats {
+        ByteRangeCacheStats {
+            hits: self.hits.load(),
+            misses: self.misses.load(),
+            memory_size: self.memory.lock().unwrap().size,
+        }
+    }
+
+    /// Drop all ranges held in memory. Ranges persisted to disk are left to the file cache
+    /// eviction.
+    pub fn clear_memory(&self) {
+        let mut memory = self.memory.lock().unwrap();
+        while memory.pop_lru() {}
+    }
+
+    pub async fn get(&self, key: &ByteRangeCacheKey) -> Option<Buffer<u8>> {
+        let mode = self.mode();
+
+        if mode == ByteRangeCacheMode::Disabled {
+            return None;
+        }
+
+        if let Some(v) = self.memory.lock().unwrap().lru.get(key) {
+            self.hits.fetch_add(1);
+            return Some(v.clone());
+        }
+
+        #[cfg(feature = "file_cache")]
+        if mode == ByteRangeCacheMode::Disk {
+            let (key_hash, version_hash) = (key.disk_key_hash(), key.disk_version_hash());
+
+            let bytes = crate::pl_async::get_runtime()
+                .spawn_blocking(move || {
+                    crate::file_cache::read_range_entry(&key_hash, &version_hash)
+                })
+                .await
+                .ok()
+                .flatten()
+                .filter(|bytes| bytes.len() == key.range.len());
+
+            if let Some(bytes) = bytes {
+                let bytes = Buffer::from_vec(bytes);
+                self.insert_memory(key.clone(), bytes.clone());
+                self.hits.fetch_add(1);
+                return Some(bytes);
+            }
+        }
+
+        self.misses.fetch_add(1);
+        None
+    }
+
+    pub async fn insert(&self, key: ByteRangeCacheKey, bytes: Buffer<u8>) {
+        let mode = self.mode();
+
+        if mode == ByteRangeCacheMode::Disabled {
+            return;
+        }
+
+        debug_assert_eq!(bytes.len(), key.range.len());
+
+        #[cfg(feature = "file_cache")]
+        if mode == ByteRangeCacheMode::Disk {
+            let (key_hash, version_hash) = (key.disk_key_hash(), key.disk_version_hash());
+            let uri = key.uri.clone();
+            let bytes = bytes.clone();
+
+            let result = crate::pl_async::get_runtime()
+                .spawn_blocking(move || {
+                    crate::file_cache::write_range_entry(uri, &key_hash, &version_hash, &bytes)
+                })
+                .await;
+
+            // Failing to persist a range only costs a re-fetch later.
+            if verbose() && !matches!(result, Ok(Ok(()))) {
+                eprintln!(
+                    "byte_range_cache: failed to write range {:?} of {} to disk",
+                    key.range, key.uri
+                )
+            }
+        }
+
+        self.insert_memory(key, bytes);
+    }
+
+    fn insert_memory(&self, key: ByteRangeCacheKey, bytes: Buffer<u8>) {
+        let max_memory_size = self.max_memory_size.load();
+
+        if bytes.len() > max_memory_size {
+            return;
+        }
+
+        let mut memory = self.memory.lock().unwrap();
+
+        if memory.lru.len() >= MAX_MEMORY_ENTRIES {
+            memory.pop_lru();
+        }
+
+        while memory.size + bytes.len() > max_memory_size && memory.pop_lru() {}
+
+        memory.size += bytes.len();
+
+        if let Some(prev) = memory.lru.insert(key, bytes) {
+            memory.size -= prev.len();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polars_buffer::Buffer;
+    use polars_utils::pl_path::PlRefPath;
+
+    use super::{ByteRangeCache, ByteRangeCacheKey, ByteRangeCacheMode};
+    use crate::pl_async::get_runtime;
+
+    fn key(range: std::ops::Range<usize>, version: &str) -> ByteRangeCacheKey {
+        ByteRangeCacheKey {
+            uri: PlRefPath::new("s3://bucket/file.parquet"),
+            version: version.into(),
+            range,
+        }
+    }
+
+    #[test]
+    fn test_byte_range_cache_memory() {
+        let cache = ByteRangeCache::new(ByteRangeCacheMode::Memory, 20);
+
+        get_runtime().block_on(async {
+            cache
+                .insert(key(0..10, "a"), Buffer::from_vec(vec![1; 10]))
+                .await;
+            cache
+                .insert(key(10..20, "a"), Buffer::from_vec(vec![2; 10]))
+                .await;
+
+            assert_eq!(&*cache.get(&key(0..10, "a")).await.unwrap(), &[1; 10]);
+            // Different version of the same object.
+            assert!(cache.get(&key(0..10, "b")).await.is_none());
+
+            // Evicts `10..20`, as `0..10` was more recently used.
+            cache
+                .insert(key(20..25, "a"), Buffer::from_vec(vec![3; 5]))
+                .await;
+            assert!(cache.get(&key(10..20, "a")).await.is_none());
+            assert!(cache.get(&key(0..10, "a")).await.is_some());
+
+            let stats = cache.stats();
+            assert_eq!((stats.hits, stats.misses, stats.memory_size), (2, 2, 15));
+
+            cache.set_mode(ByteRangeCacheMode::Disabled);
+            assert!(cache.get(&key(0..10, "a")).await.is_none());
+        });
+    }
+}
@@ -133,3 +133,46 @@ pub(crate) fn cloud_writer_copy_buffer_size() -> NonZeroUsize {
         v
     });
 }
+
+/// Staging locations of partitioned sinks using the staged commit protocol that are older than this
+/// are considered orphaned and removed by subsequent writes to the same base path.
+pub fn staged_commit_orphan_max_age() -> std::time::Duration {
+    return *ORPHAN_MAX_AGE;
+
+    static ORPHAN_MAX_AGE: LazyLock<std::time::Duration> = LazyLock::new(|| {
+        let mut v: u64 = 24 * 60 * 60;
+
+        if let Ok(s) = std::env::var("POLARS_STAGED_COMMIT_ORPHAN_MAX_AGE_SECS") {
+            v = s.parse::<u64>().unwrap_or_else(|_| {
+                panic!("invalid value for POLARS_STAGED_COMMIT_ORPHAN_MAX_AGE_SECS: {s}")
+            })
+        }
+
+        if verbose() {
+            eprintln!("staged_commit_orphan_max_age: {v}s")
+        }
+
+        std::time::Duration::from_secs(v)
+    });
+}
+
+/// Memory budget in bytes of the in-process tier of the byte-range cache.
+pub fn byte_range_cache_memory_size() -> usize {
+    return *BYTE_RANGE_CACHE_MEMORY_SIZE;
+
+    static BYTE_RANGE_CACHE_MEMORY_SIZE: LazyLock<usize> = LazyLock::new(|| {
+        let mut v: usize = usize::min(total_memory() as usize / 16, 1024 * 1024 * 1024);
+
+        if let Ok(s) = std::env::var("POLARS_BYTE_RANGE_CACHE_MEMORY_SIZE") {
+            v = s.parse::<usize>().unwrap_or_else(|_| {
+                panic!("invalid value for POLARS_BYTE_RANGE_CACHE_MEMORY_SIZE: {s}")
+            })
+        }
+
+        if verbose() {
+            eprintln!("byte_range_cache_memory_size: {v}")
+        }
+
+        v
+    });
+}
@@ -109,6 +109,14 @@ pub(crate) fn decompress(
             let mut decoder = zstd::Decoder::with_buffer(bytes).ok()?;
             decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
         },
+        SupportedCompression::BZIP2 => {
+            let mut decoder = bzip2::read::MultiBzDecoder::new(bytes);
+            decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
+        },
+        SupportedCompression::XZ => {
+            let mut decoder = liblzma::read::XzDecoder::new_multi_decoder(bytes);
+            decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
+        },
     }
 }
 
@@ -1,13 +1,15 @@
 use std::sync::atomic::AtomicU64;
 use std::sync::{Arc, LazyLock, RwLock};
 
+use fs4::fs_std::FileExt;
 use polars_core::config;
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_ensure};
 use polars_utils::aliases::PlHashMap;
 use polars_utils::pl_path::PlRefPath;
 
+use super::cache_lock::GLOBAL_FILE_CACHE_LOCK;
 use super::entry::{DATA_PREFIX, FileCacheEntry, METADATA_PREFIX};
-use super::eviction::EvictionManager;
+use super::eviction::{EvictionManager, list_data_files};
 use super::file_fetcher::FileFetcher;
 use super::utils::FILE_CACHE_PREFIX;
 use crate::path_utils::ensure_directory_init;
@@ -20,6 +22,9 @@ pub static FILE_CACHE: LazyLock<FileCache> = LazyLock::new(|| {
     }
 
     let min_ttl = Arc::new(AtomicU64::from(get_env_file_cache_ttl()));
+    let max_size = Arc::new(AtomicU64::from(
+        get_env_file_cache_max_size().unwrap_or(u64::MAX),
+    ));
     let notify_ttl_updated = Arc::new(tokio::sync::Notify::new());
 
     let metadata_dir = prefix.join(std::str::from_utf8(&[METADATA_PREFIX]).unwrap());
@@ -45,11 +50,12 @@ pub static FILE_CACHE: LazyLock<FileCache> = LazyLock::new(|| {
         files_to_remove: None,
         min_ttl: min_ttl.clone(),
         notify_ttl_updated: notify_ttl_updated.clone(),
+        max_size: max_size.clone(),
     }
     .run_in_background();
 
     // Safety: We have created the data and metadata directories.
-    unsafe { FileCache::new_unchecked(prefix, min_ttl, notify_ttl_updated) }
+    unsafe { FileCache::new_unchecked(prefix, min_ttl, notify_ttl_updated, max_size) }
 });
 
 pub struct FileCache {
@@ -57,6 +63,22 @@ pub struct FileCache {
     entries: Arc<RwLock<PlHashMap<PlRefPath, Arc<FileCacheEntry>>>>,
     min_ttl: Arc<AtomicU64>,
     notify_ttl_updated: Arc<tokio::sync::Notify>,
+    max_size: Arc<AtomicU64>,
+}
+
+/// Information about a file stored in the file cache.
+#[derive(Debug, Clone)]
+pub struct FileCacheEntryInfo {
+    /// URI of the cached file. `None` if the metadata of the entry could not be read.
+    pub uri: Option<PlRefPath>,
+    /// Path of the local copy.
+    pub local_path: PlRefPath,
+    /// Size of the local copy in bytes.
+    pub size: u64,
+    /// Last time the entry was accessed, in seconds since the UNIX epoch.
+    pub last_accessed: u64,
+    /// TTL since last access, in seconds.
+    pub ttl: Option<u64>,
 }
 
 impl FileCache {
@@ -68,12 +90,14 @@ impl FileCache {
         prefix: PlRefPath,
         min_ttl: Arc<AtomicU64>,
         notify_ttl_updated: Arc<tokio::sync::Notify>,
+        max_size: Arc<AtomicU64>,
     ) -> Self {
         Self {
             prefix,
             entries: Default::default(),
             min_ttl,
             notify_ttl_updated,
+            max_size,
         }
     }
 
@@ -168,6 +192,122 @@ impl FileCache {
     }
 }
 
+impl FileCache {
+    /// Maximum total size of the cached files in bytes. `None` means unlimited.
+    pub fn max_size(&self) -> Option<u64> {
+        Some(self.max_size.load(std::sync::atomic::Ordering::Relaxed)).filter(|x| *x != u64::MAX)
+    }
+
+    /// Set the maximum total size of the cached files in bytes. When exceeded, the least recently
+    /// used files are evicted by the background eviction task, regardless of their TTL. Lowering
+    /// the limit wakes the eviction task so that it takes effect right away.
+    pub fn set_max_size(&self, max_size: Option<u64>) {
+        self.max_size.store(
+            max_size.unwrap_or(u64::MAX),
+            std::sync::atomic::Ordering::Relaxed,
+        );
+        self.notify_ttl_updated.notify_one();
+    }
+
+    fn data_dir(&self) -> PlRefPath {
+        self.prefix
+            .join(std::str::from_utf8(&[DATA_PREFIX]).unwrap())
+    }
+
+    fn metadata_dir(&self) -> PlRefPath {
+        self.prefix
+            .join(std::str::from_utf8(&[METADATA_PREFIX]).unwrap())
+    }
+
+    /// List the files currently stored in the cache, including those stored by other processes.
+    /// The output is sorted from least to most recently used.
+    pub fn list_entries(&self) -> PolarsResult<Vec<FileCacheEntryInfo>> {
+        let _guard = GLOBAL_FILE_CACHE_LOCK.lock_shared();
+
+        let mut out: Vec<FileCacheEntryInfo> =
+            list_data_files(&self.data_dir(), &self.metadata_dir())?
+                .into_iter()
+                .map(|x| FileCacheEntryInfo {
+                    uri: x.metadata.as_ref().map(|m| m.uri.clone()),
+                    local_path: x.path,
+                    size: x.size,
+                    last_accessed: x.last_accessed,
+                    ttl: x.metadata.as_ref().map(|m| m.ttl),
+                })
+                .collect();
+
+        out.sort_unstable_by_key(|x| x.last_accessed);
+
+        Ok(out)
+    }
+
+    /// Total size in bytes of the files currently stored in the cache.
+    pub fn total_size(&self) -> PolarsResult<u64> {
+        Ok(self.list_entries()?.iter().map(|x| x.size).sum())
+    }
+
+    /// Remove all cached files that are not currently opened by this or another process. Returns
+    /// the number of bytes freed.
+    ///
+    /// This requires exclusive access to the cache directory, and will wait up to `timeout` for
+    /// other processes to release their shared lock on it.
+    pub fn purge(&self, timeout: std::time::Duration) -> PolarsResult<u64> {
+        let verbose = config::verbose();
+        let start = std::time::Instant::now();
+
+        let _guard = loop {
+            if let Some(guard) = GLOBAL_FILE_CACHE_LOCK.try_lock_eviction() {
+                break guard;
+            }
+
+            polars_ensure!(
+                start.elapsed() < timeout,
+                ComputeError: "timed out waiting for exclusive access to the file cache at {}",
+                self.prefix
+            );
+
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        };
+
+        let mut freed = 0;
+
+        for data_file in list_data_files(&self.data_dir(), &self.metadata_dir())? {
+            let Ok(file) = std::fs::File::open(&data_file.path) else {
+                continue;
+            };
+
+            // Files opened for reading hold a shared lock.
+            if FileExt::try_lock_exclusive(&file).is_err() {
+                if verbose {
+                    eprintln!(
+                        "[file_cache] purge: skipping {} (file is locked)",
+                        data_file.path
+                    );
+                }
+                continue;
+            }
+
+            if std::fs::remove_file(&data_file.path).is_ok() {
+                freed += data_file.size;
+                let _ = std::fs::remove_file(&data_file.metadata_path);
+            }
+        }
+
+        if verbose {
+            eprintln!("[file_cache] purge: freed {freed} bytes");
+        }
+
+        Ok(freed)
+    }
+}
+
+/// Maximum total size of the file cache in bytes, from `POLARS_FILE_CACHE_MAX_SIZE`.
+pub fn get_env_file_cache_max_size() -> Option<u64> {
+    std::env::var("POLARS_FILE_CACHE_MAX_SIZE")
+        .ok()
+        .map(|x| x.parse::<u64>().expect("integer"))
+}
+
 pub fn get_env_file_cache_ttl() -> u64 {
     std::env::var("POLARS_FILE_CACHE_TTL")
         .map(|x| x.parse::<u64>().expect("integer"))
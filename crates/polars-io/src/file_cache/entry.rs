@@ -10,9 +10,9 @@ use polars_utils::pl_path::PlRefPath;
 
 use super::cache_lock::{self, GLOBAL_FILE_CACHE_LOCK};
 use super::file_fetcher::{FileFetcher, RemoteMetadata};
-use super::file_lock::{FileLock, FileLockAnyGuard};
+use super::file_lock::{FileLock, FileLockAnyGuard, FileLockExclusiveGuard};
 use super::metadata::{EntryMetadata, FileVersion};
-use super::utils::update_last_accessed;
+use super::utils::{now_secs, update_last_accessed};
 
 pub(super) const DATA_PREFIX: u8 = b'd';
 pub(super) const METADATA_PREFIX: u8 = b'm';
@@ -68,7 +68,9 @@ impl Inner {
                             self.uri.clone()
                         );
                     }
-                    return Ok(finish_open(data_file_path, metadata_file));
+                    let data_file = finish_open(data_file_path, metadata_file);
+                    record_last_accessed(metadata, metadata_file)?;
+                    return Ok(data_file);
                 }
             }
         }
@@ -88,7 +90,7 @@ impl Inner {
         let remote_metadata = &self.file_fetcher.fetch_metadata()?;
         let cache_guard = GLOBAL_FILE_CACHE_LOCK.lock_shared();
 
-        {
+        let opened_data_file = 'opened: {
             let metadata_file = &mut self.metadata.acquire_shared().unwrap();
             update_last_accessed(metadata_file);
 
@@ -103,13 +105,34 @@ impl Inner {
                                 self.uri.clone()
                             );
                         }
-                        return Ok(finish_open(data_file_path, metadata_file));
+                        let data_file = finish_open(data_file_path, metadata_file);
+
+                        if !metadata.is_last_accessed_outdated(now_secs()) {
+                            return Ok(data_file);
+                        }
+
+                        break 'opened Some(data_file);
                     }
                 }
             }
-        }
+
+            None
+        };
 
         let metadata_file = &mut self.metadata.acquire_exclusive().unwrap();
+
+        // The metadata file cannot be written under the shared lock, so the last accessed time of
+        // a file opened above is recorded here.
+        if let Some(data_file) = opened_data_file {
+            if let Ok(metadata) = self.try_get_metadata(metadata_file, &cache_guard) {
+                if metadata.matches_remote_metadata(remote_metadata) {
+                    record_last_accessed(metadata, metadata_file)?;
+                }
+            }
+
+            return Ok(data_file);
+        }
+
         let metadata = self
             .try_get_metadata(metadata_file, &cache_guard)
             // Safety: `metadata_file` is an exclusive guard.
@@ -130,7 +153,9 @@ impl Inner {
                         self.uri.clone()
                     );
                 }
-                return Ok(finish_open(data_file_path, metadata_file));
+                let data_file = finish_open(data_file_path, metadata_file);
+                record_last_accessed(metadata, metadata_file)?;
+                return Ok(data_file);
             }
         }
 
@@ -221,6 +246,7 @@ impl Inner {
         metadata.local_last_modified = local_last_modified;
         metadata.local_size = local_size;
         metadata.remote_version = remote_metadata.version.clone();
+        metadata.touch(now_secs());
 
         if let Err(e) = metadata.compare_local_state(data_file_path) {
             panic!("metadata mismatch after file fetch: {e}");
@@ -369,6 +395,22 @@ impl FileCacheEntry {
     }
 }
 
+/// Persist the last accessed time of an entry to its metadata file.
+fn record_last_accessed(
+    mut metadata: Arc<EntryMetadata>,
+    metadata_file: &mut FileLockExclusiveGuard,
+) -> PolarsResult<()> {
+    if Arc::make_mut(&mut metadata).touch(now_secs()) {
+        metadata_file.set_len(0).unwrap();
+        metadata_file.seek(SeekFrom::Start(0)).unwrap();
+        metadata
+            .try_write(&mut **metadata_file)
+            .map_err(to_compute_err)?;
+    }
+
+    Ok(())
+}
+
 fn finish_open<F: FileLockAnyGuard>(data_file_path: &Path, _metadata_guard: &F) -> std::fs::File {
     let file = {
         #[cfg(not(target_family = "windows"))]
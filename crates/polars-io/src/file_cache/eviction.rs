@@ -8,14 +8,27 @@ use polars_utils::pl_path::PlRefPath;
 
 use super::cache_lock::{GLOBAL_FILE_CACHE_LOCK, GlobalFileCacheGuardExclusive};
 use super::metadata::EntryMetadata;
+use super::utils::last_accessed_secs;
 use crate::pl_async;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EvictionReason {
+    /// Not accessed within its TTL.
+    Ttl,
+    /// The cache exceeds its maximum size and this was among the least recently used data files.
+    /// Holds the last accessed time at the point of selection.
+    Size { last_accessed: u64 },
+}
+
 #[derive(Debug, Clone)]
 pub(super) struct EvictionCandidate {
     path: PlRefPath,
     metadata_path: PlRefPath,
     metadata_last_modified: SystemTime,
     ttl: u64,
+    /// Last accessed time recorded in the metadata file, in seconds since the UNIX epoch.
+    recorded_last_accessed: u64,
+    reason: EvictionReason,
 }
 
 pub(super) struct EvictionManager {
@@ -24,9 +37,35 @@ pub(super) struct EvictionManager {
     pub(super) files_to_remove: Option<Vec<EvictionCandidate>>,
     pub(super) min_ttl: Arc<AtomicU64>,
     pub(super) notify_ttl_updated: Arc<tokio::sync::Notify>,
+    /// Maximum total size of the data files in bytes. `u64::MAX` means unlimited.
+    pub(super) max_size: Arc<AtomicU64>,
+}
+
+/// A data file in the cache directory.
+#[derive(Debug, Clone)]
+pub(super) struct DataFileInfo {
+    pub(super) path: PlRefPath,
+    pub(super) metadata_path: PlRefPath,
+    pub(super) size: u64,
+    /// Seconds since the UNIX epoch.
+    pub(super) last_accessed: u64,
+    pub(super) metadata: Option<EntryMetadata>,
 }
 
 impl EvictionCandidate {
+    fn new(path: PlRefPath, metadata_path: PlRefPath, reason: EvictionReason) -> Self {
+        let mut out = Self {
+            path,
+            metadata_path,
+            metadata_last_modified: UNIX_EPOCH,
+            ttl: 0,
+            recorded_last_accessed: 0,
+            reason,
+        };
+        out.update_ttl();
+        out
+    }
+
     fn update_ttl(&mut self) {
         let Ok(metadata_last_modified) =
             std::fs::metadata(&self.metadata_path).map(|md| md.modified().unwrap())
@@ -47,12 +86,18 @@ impl EvictionCandidate {
             return;
         };
 
-        let ttl = EntryMetadata::try_from_reader(file)
-            .map(|x| x.ttl)
-            .unwrap_or(0);
+        let (ttl, recorded_last_accessed) = EntryMetadata::try_from_reader(file)
+            .map(|x| (x.ttl, x.last_accessed))
+            .unwrap_or((0, 0));
 
         self.metadata_last_modified = metadata_last_modified;
         self.ttl = ttl;
+        self.recorded_last_accessed = recorded_last_accessed;
+    }
+
+    /// Last accessed time considering both the file system and the metadata file.
+    fn last_accessed(&self, metadata: &std::fs::Metadata) -> u64 {
+        last_accessed_secs(metadata).max(self.recorded_last_accessed)
     }
 
     fn should_remove(&self, now: &SystemTime) -> bool {
@@ -109,7 +154,12 @@ impl EvictionCandidate {
             },
         };
 
-        if since_last_accessed < self.ttl {
+        let keep = match self.reason {
+            EvictionReason::Ttl => since_last_accessed < self.ttl,
+            EvictionReason::Size { last_accessed } => self.last_accessed(&metadata) > last_accessed,
+        };
+
+        if keep {
             if verbose {
                 eprintln!(
                     "[EvictionManager] evict_files: skipping {} (last accessed time was updated)",
@@ -198,6 +248,8 @@ impl EvictionManager {
                     },
                 }
 
+                let max_size = self.max_size.load(std::sync::atomic::Ordering::Relaxed);
+
                 loop {
                     let min_ttl = self.min_ttl.load(std::sync::atomic::Ordering::Relaxed);
                     let sleep_interval = std::cmp::max(min_ttl / 4, {
@@ -218,6 +270,11 @@ impl EvictionManager {
 
                     tokio::select! {
                         _ = self.notify_ttl_updated.notified() => {
+                            // Evict right away if the size limit was lowered, rather than waiting
+                            // for the next tick.
+                            if self.max_size.load(std::sync::atomic::Ordering::Relaxed) < max_size {
+                                break;
+                            }
                             continue;
                         }
                         _ = tokio::time::sleep(sleep_interval) => {
@@ -230,17 +287,7 @@ impl EvictionManager {
     }
 
     fn update_file_list(&mut self) -> PolarsResult<()> {
-        let data_files_iter = match std::fs::read_dir(self.data_dir.as_std_path()) {
-            Ok(v) => v,
-            Err(e) => {
-                let msg = format!("failed to read data directory: {e}");
-
-                return Err(PolarsError::IO {
-                    error: e.into(),
-                    msg: Some(msg.into()),
-                });
-            },
-        };
+        let data_files = list_data_files(&self.data_dir, &self.metadata_dir)?;
 
         let metadata_files_iter = match std::fs::read_dir(self.metadata_dir.as_std_path()) {
             Ok(v) => v,
@@ -255,10 +302,7 @@ impl EvictionManager {
         };
 
         let mut files_to_remove = Vec::with_capacity(
-            data_files_iter
-                .size_hint()
-                .1
-                .unwrap_or(data_files_iter.size_hint().0)
+            data_files.len()
                 + metadata_files_iter
                     .size_hint()
                     .1
@@ -266,46 +310,32 @@ impl EvictionManager {
         );
 
         let now = SystemTime::now();
+        let mut remaining_data_files = Vec::with_capacity(data_files.len());
 
-        for file in data_files_iter {
-            let file = file?;
-            let path = PlRefPath::try_from_pathbuf(file.path())?;
-
-            let hash = path
-                .file_name()
-                .unwrap()
-                .to_str()
-                .unwrap()
-                .get(..32)
-                .unwrap();
-            let metadata_path = self.metadata_dir.join(hash);
-
-            let mut eviction_candidate = EvictionCandidate {
-                path,
-                metadata_path,
-                metadata_last_modified: UNIX_EPOCH,
-                ttl: 0,
-            };
-            eviction_candidate.update_ttl();
+        for data_file in data_files {
+            let eviction_candidate = EvictionCandidate::new(
+                data_file.path.clone(),
+                data_file.metadata_path.clone(),
+                EvictionReason::Ttl,
+            );
 
             if eviction_candidate.should_remove(&now) {
                 files_to_remove.push(eviction_candidate);
+            } else {
+                remaining_data_files.push(data_file);
             }
         }
 
+        let max_size = self.max_size.load(std::sync::atomic::Ordering::Relaxed);
+        files_to_remove.extend(select_lru_data_files(remaining_data_files, max_size));
+
         for file in metadata_files_iter {
             let file = file?;
             let path = PlRefPath::try_from_pathbuf(file.path())?;
             let metadata_path = path.clone();
 
-            let mut eviction_candidate = EvictionCandidate {
-                path,
-                metadata_path,
-                metadata_last_modified: UNIX_EPOCH,
-                ttl: 0,
-            };
-
-            eviction_candidate.update_ttl();
+            let eviction_candidate =
+                EvictionCandidate::new(path, metadata_path, EvictionReason::Ttl);
 
             if eviction_candidate.should_remove(&now) {
                 files_to_remove.push(eviction_candidate);
@@ -329,3 +359,129 @@ impl EvictionManager {
         }
     }
 }
+
+/// List the data files in `data_dir`, along with their metadata from `metadata_dir` if it could be
+/// read.
+pub(super) fn list_data_files(
+    data_dir: &PlRefPath,
+    metadata_dir: &PlRefPath,
+) -> PolarsResult<Vec<DataFileInfo>> {
+    let data_files_iter = match std::fs::read_dir(data_dir.as_std_path()) {
+        Ok(v) => v,
+        Err(e) => {
+            let msg = format!("failed to read data directory: {e}");
+
+            return Err(PolarsError::IO {
+                error: e.into(),
+                msg: Some(msg.into()),
+            });
+        },
+    };
+
+    let mut out = Vec::with_capacity(data_files_iter.size_hint().0);
+
+    for file in data_files_iter {
+        let file = file?;
+        let path = PlRefPath::try_from_pathbuf(file.path())?;
+
+        let Some(hash) = path
+            .file_name()
+            .and_then(|x| x.to_str())
+            .and_then(|x| x.get(..32))
+        else {
+            continue;
+        };
+        let metadata_path = metadata_dir.join(hash);
+
+        // The file may have been removed by another process in the meantime.
+        let Ok(fs_metadata) = std::fs::metadata(&path) else {
+            continue;
+        };
+
+        let metadata = std::fs::File::open(&metadata_path)
+            .ok()
+            .and_then(|mut f| EntryMetadata::try_from_reader(&mut f).ok());
+
+        let last_accessed =
+            last_accessed_secs(&fs_metadata).max(metadata.as_ref().map_or(0, |x| x.last_accessed));
+
+        out.push(DataFileInfo {
+            path,
+            metadata_path,
+            size: fs_metadata.len(),
+            last_accessed,
+            metadata,
+        })
+    }
+
+    Ok(out)
+}
+
+/// Select the least recently used data files to remove such that the total size of the remaining
+/// files does not exceed `max_size`.
+fn select_lru_data_files(
+    mut data_files: Vec<DataFileInfo>,
+    max_size: u64,
+) -> impl Iterator<Item = EvictionCandidate> {
+    let mut total_size: u64 = data_files.iter().map(|x| x.size).sum();
+
+    if total_size <= max_size {
+        data_files.clear();
+    }
+
+    data_files.sort_unstable_by_key(|x| x.last_accessed);
+
+    data_files
+        .into_iter()
+        .take_while(move |x| {
+            let take = total_size > max_size;
+            total_size = total_size.saturating_sub(x.size);
+            take
+        })
+        .map(|x| {
+            EvictionCandidate::new(
+                x.path,
+                x.metadata_path,
+                EvictionReason::Size {
+                    last_accessed: x.last_accessed,
+                },
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DataFileInfo, EvictionReason, select_lru_data_files};
+
+    #[test]
+    fn test_select_lru_data_files() {
+        let data_files = [("a", 10, 3), ("b", 20, 1), ("c", 30, 2), ("d", 40, 4)]
+            .into_iter()
+            .map(|(name, size, last_accessed)| DataFileInfo {
+                path: format!("/nonexistent/d/{name}").as_str().into(),
+                metadata_path: format!("/nonexistent/m/{name}").as_str().into(),
+                size,
+                last_accessed,
+                metadata: None,
+            })
+            .collect::<Vec<_>>();
+
+        let selected = |max_size: u64| {
+            select_lru_data_files(data_files.clone(), max_size)
+                .map(|x| {
+                    let EvictionReason::Size { last_accessed } = x.reason else {
+                        unreachable!()
+                    };
+                    last_accessed
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(selected(100), [] as [u64; 0]);
+        assert_eq!(selected(u64::MAX), [] as [u64; 0]);
+        // Removing the least recently used file ("b") is enough.
+        assert_eq!(selected(80), [1]);
+        assert_eq!(selected(79), [1, 2]);
+        assert_eq!(selected(0), [1, 2, 3, 4]);
+    }
+}
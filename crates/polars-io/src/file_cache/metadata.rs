@@ -28,8 +28,20 @@ pub(super) struct EntryMetadata {
     pub(super) remote_version: FileVersion,
     /// TTL since last access, in seconds.
     pub(super) ttl: u64,
+    /// Last time this entry was opened, in seconds since the UNIX epoch. Used for LRU eviction
+    /// when the cache exceeds its maximum size.
+    ///
+    /// This is only refreshed while holding an exclusive lock on the metadata file, and at most
+    /// once per [`LAST_ACCESSED_RESOLUTION_SECS`], so readers should also consider the access time
+    /// of the data file.
+    #[serde(default)]
+    pub(super) last_accessed: u64,
 }
 
+/// Minimum interval between updates of [`EntryMetadata::last_accessed`], to avoid rewriting the
+/// metadata file on every open.
+pub(super) const LAST_ACCESSED_RESOLUTION_SECS: u64 = 60;
+
 impl std::fmt::Display for LocalCompareError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -55,7 +67,24 @@ impl EntryMetadata {
             local_size: 0,
             remote_version: FileVersion::Uninitialized,
             ttl,
+            last_accessed: 0,
+        }
+    }
+
+    /// Update the last accessed time to `now` (seconds since the UNIX epoch). Returns `false` if
+    /// the stored value is recent enough that it was left unchanged.
+    pub(super) fn touch(&mut self, now: u64) -> bool {
+        if !self.is_last_accessed_outdated(now) {
+            return false;
         }
+
+        self.last_accessed = now;
+        true
+    }
+
+    /// Whether the stored last accessed time is old enough that [`Self::touch`] would update it.
+    pub(super) fn is_last_accessed_outdated(&self, now: u64) -> bool {
+        now.saturating_sub(self.last_accessed) >= LAST_ACCESSED_RESOLUTION_SECS
     }
 
     pub(super) fn compare_local_state(&self, data_file_path: &Path) -> LocalCompareResult {
@@ -5,7 +5,11 @@ mod eviction;
 mod file_fetcher;
 mod file_lock;
 mod metadata;
+mod range_entry;
 mod utils;
-pub use cache::{FILE_CACHE, get_env_file_cache_ttl};
+pub use cache::{
+    FILE_CACHE, FileCache, FileCacheEntryInfo, get_env_file_cache_max_size, get_env_file_cache_ttl,
+};
 pub use entry::FileCacheEntry;
+pub(crate) use range_entry::{read_range_entry, write_range_entry};
 pub use utils::{FILE_CACHE_PREFIX, init_entries_from_uri_list};
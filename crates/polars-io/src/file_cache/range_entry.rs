@@ -0,0 +1,111 @@
+//! Storage of individual byte ranges of remote files in the file cache directory.
+//!
+//! Range entries use the same on-disk layout as whole-file entries, so they are subject to the
+//! same TTL / size based eviction:
+//! * `[prefix]/m/[key hash]` - metadata
+//! * `[prefix]/d/[key hash][version hash]` - data
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+
+use polars_error::{PolarsResult, to_compute_err};
+use polars_utils::pl_path::PlRefPath;
+
+use super::cache::get_env_file_cache_ttl;
+use super::cache_lock::GLOBAL_FILE_CACHE_LOCK;
+use super::entry::{DATA_PREFIX, METADATA_PREFIX};
+use super::file_lock::FileLock;
+use super::metadata::{EntryMetadata, FileVersion};
+use super::utils::{FILE_CACHE_PREFIX, last_modified_u64, now_secs, update_last_accessed};
+
+fn get_paths(key_hash: &str, version_hash: &str) -> (PathBuf, PathBuf) {
+    let prefix = FILE_CACHE_PREFIX.as_std_path();
+    let data_dir = prefix.join(std::str::from_utf8(&[DATA_PREFIX]).unwrap());
+    let metadata_dir = prefix.join(std::str::from_utf8(&[METADATA_PREFIX]).unwrap());
+
+    (
+        data_dir.join(format!("{key_hash}{version_hash}")),
+        metadata_dir.join(key_hash),
+    )
+}
+
+/// Read a cached range. Returns `None` if there is no entry for this `version_hash`.
+///
+/// `key_hash` must be 32 characters long.
+pub(crate) fn read_range_entry(key_hash: &str, version_hash: &str) -> Option<Vec<u8>> {
+    debug_assert_eq!(key_hash.len(), 32);
+
+    // Ensure the directories exist and the eviction task is running.
+    let _ = &*super::FILE_CACHE;
+
+    let (data_path, metadata_path) = get_paths(key_hash, version_hash);
+
+    let _cache_guard = GLOBAL_FILE_CACHE_LOCK.lock_shared();
+    let metadata_lock = FileLock::from(&metadata_path);
+    let metadata_file = &mut metadata_lock.acquire_shared().ok()?;
+
+    let metadata = EntryMetadata::try_from_reader(&mut **metadata_file).ok()?;
+
+    if metadata.remote_version != FileVersion::ETag(version_hash.to_string())
+        || metadata.compare_local_state(&data_path).is_err()
+    {
+        return None;
+    }
+
+    let mut file = std::fs::File::open(&data_path).ok()?;
+    let mut out = Vec::with_capacity(metadata.local_size as usize);
+    file.read_to_end(&mut out).ok()?;
+
+    // Setting the access time does not change the modified time that is validated above.
+    #[cfg(not(target_family = "windows"))]
+    update_last_accessed(&file);
+
+    Some(out)
+}
+
+/// Store a range in the cache, replacing any existing entry for `key_hash`.
+///
+/// `key_hash` must be 32 characters long.
+pub(crate) fn write_range_entry(
+    uri: PlRefPath,
+    key_hash: &str,
+    version_hash: &str,
+    bytes: &[u8],
+) -> PolarsResult<()> {
+    debug_assert_eq!(key_hash.len(), 32);
+
+    let _ = &*super::FILE_CACHE;
+
+    let (data_path, metadata_path) = get_paths(key_hash, version_hash);
+
+    let _cache_guard = GLOBAL_FILE_CACHE_LOCK.lock_shared();
+    let metadata_lock = FileLock::from(&metadata_path);
+    let metadata_file = &mut metadata_lock.acquire_exclusive()?;
+
+    // Remove data of a previous version of this range.
+    if let Ok(previous) = EntryMetadata::try_from_reader(&mut **metadata_file)
+        && let FileVersion::ETag(previous_version_hash) = &previous.remote_version
+        && previous_version_hash != version_hash
+    {
+        let _ = std::fs::remove_file(get_paths(key_hash, previous_version_hash).0);
+    }
+
+    let _ = std::fs::remove_file(&data_path);
+    std::fs::write(&data_path, bytes)?;
+
+    let data_file_metadata = std::fs::metadata(&data_path)?;
+
+    let mut metadata = EntryMetadata::new(uri, get_env_file_cache_ttl());
+    metadata.local_last_modified = last_modified_u64(&data_file_metadata);
+    metadata.local_size = data_file_metadata.len();
+    metadata.remote_version = FileVersion::ETag(version_hash.to_string());
+    metadata.touch(now_secs());
+
+    metadata_file.set_len(0)?;
+    metadata_file.seek(SeekFrom::Start(0))?;
+    metadata
+        .try_write(&mut **metadata_file)
+        .map_err(to_compute_err)?;
+    metadata_file.flush()?;
+
+    Ok(())
+}
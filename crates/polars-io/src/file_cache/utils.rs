@@ -35,6 +35,24 @@ pub(super) fn last_modified_u64(metadata: &std::fs::Metadata) -> u64 {
     .unwrap()
 }
 
+/// Last access time of a file in seconds since the UNIX epoch. Falls back to the last modified time
+/// on platforms / file systems that do not record access times.
+pub(super) fn last_accessed_secs(metadata: &std::fs::Metadata) -> u64 {
+    metadata
+        .accessed()
+        .or_else(|_| metadata.modified())
+        .ok()
+        .and_then(|x| x.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |x| x.as_secs())
+}
+
+pub(super) fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
 pub(super) fn update_last_accessed(file: &std::fs::File) {
     let file_metadata = file.metadata().unwrap();
 
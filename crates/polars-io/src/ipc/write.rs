@@ -23,6 +23,9 @@ pub struct IpcWriterOptions {
     /// Write record batch StatisticsFlags as custom metadata into the record batch header.
     #[cfg_attr(feature = "serde", serde(default))]
     pub record_batch_statistics: bool,
+    /// Write the Arrow IPC streaming format instead of the file format.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub stream: bool,
 }
 
 impl Default for IpcWriterOptions {
@@ -32,6 +35,7 @@ impl Default for IpcWriterOptions {
             compat_level: CompatLevel::newest(),
             record_batch_size: None,
             record_batch_statistics: false,
+            stream: false,
         }
     }
 }
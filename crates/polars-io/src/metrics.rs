@@ -1,4 +1,5 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 use polars_utils::live_timer::{LiveTimer, LiveTimerSession};
 use polars_utils::relaxed_cell::RelaxedCell;
@@ -11,6 +12,33 @@ pub struct IOMetrics {
     pub bytes_requested: RelaxedCell<u64>,
     pub bytes_received: RelaxedCell<u64>,
     pub bytes_sent: RelaxedCell<u64>,
+    /// If set, every IO session is recorded here, used for tracing.
+    pub sessions: Option<Arc<Mutex<Vec<IOSession>>>>,
+}
+
+impl IOMetrics {
+    /// Creates metrics which also record every IO session.
+    pub fn with_sessions() -> Self {
+        Self {
+            sessions: Some(Arc::default()),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IODirection {
+    Read,
+    Write,
+}
+
+/// A single completed IO request.
+#[derive(Debug, Clone, Copy)]
+pub struct IOSession {
+    pub start: Instant,
+    pub end: Instant,
+    pub bytes: u64,
+    pub direction: IODirection,
 }
 
 #[derive(Debug, Clone)]
@@ -37,6 +65,26 @@ impl OptIOMetrics {
         self.0.as_ref().map(|x| x.bytes_sent.fetch_add(bytes_sent));
     }
 
+    fn session_start(&self) -> Option<Instant> {
+        self.0
+            .as_ref()
+            .is_some_and(|x| x.sessions.is_some())
+            .then(Instant::now)
+    }
+
+    fn record_session(&self, start: Option<Instant>, bytes: u64, direction: IODirection) {
+        if let Some(start) = start
+            && let Some(sessions) = self.0.as_ref().and_then(|x| x.sessions.as_ref())
+        {
+            sessions.lock().unwrap().push(IOSession {
+                start,
+                end: Instant::now(),
+                bytes,
+                direction,
+            });
+        }
+    }
+
     pub async fn record_io_read<F, O>(&self, num_bytes: u64, fut: F) -> O
     where
         F: Future<Output = O>,
@@ -44,10 +92,12 @@ impl OptIOMetrics {
         self.add_bytes_requested(num_bytes);
 
         let io_session = self.start_io_session();
+        let session_start = self.session_start();
 
         let out = fut.await;
 
         drop(io_session);
+        self.record_session(session_start, num_bytes, IODirection::Read);
 
         self.add_bytes_received(num_bytes);
 
@@ -59,10 +109,12 @@ impl OptIOMetrics {
         F: Future<Output = O>,
     {
         let io_session = self.start_io_session();
+        let session_start = self.session_start();
 
         let out = fut.await;
 
         drop(io_session);
+        self.record_session(session_start, num_bytes, IODirection::Write);
 
         self.add_bytes_sent(num_bytes);
 
@@ -1,5 +1,5 @@
 use polars_core::schema::SchemaRef;
-use polars_error::{PolarsError, PolarsResult};
+use polars_error::{PolarsError, PolarsResult, polars_ensure};
 use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
@@ -71,6 +71,12 @@ pub enum ExternalCompression {
     Zstd {
         level: Option<u32>,
     },
+    Bzip2 {
+        level: Option<u32>,
+    },
+    Xz {
+        level: Option<u32>,
+    },
 }
 
 impl ExternalCompression {
@@ -80,6 +86,8 @@ impl ExternalCompression {
             Self::Uncompressed => None,
             Self::Gzip { .. } => Some(".gz"),
             Self::Zstd { .. } => Some(".zst"),
+            Self::Bzip2 { .. } => Some(".bz2"),
+            Self::Xz { .. } => Some(".xz"),
         }
     }
 
@@ -88,6 +96,20 @@ impl ExternalCompression {
             "uncompressed" => Ok(Self::Uncompressed),
             "gzip" => Ok(Self::Gzip { level }),
             "zstd" => Ok(Self::Zstd { level }),
+            "bzip2" => {
+                polars_ensure!(
+                    level.is_none_or(|level| (1..=9).contains(&level)),
+                    InvalidOperation: "invalid bzip2 compression level: {}, expected a value in 1..=9", level.unwrap()
+                );
+                Ok(Self::Bzip2 { level })
+            },
+            "xz" => {
+                polars_ensure!(
+                    level.is_none_or(|level| level <= 9),
+                    InvalidOperation: "invalid xz compression level: {}, expected a value in 0..=9", level.unwrap()
+                );
+                Ok(Self::Xz { level })
+            },
             _ => Err(PolarsError::InvalidOperation(
                 format!("Invalid compression format: ({value})").into(),
             )),
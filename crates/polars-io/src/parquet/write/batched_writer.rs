@@ -144,6 +144,20 @@ impl<W: Write> BatchedWriter<W> {
             })
             .transpose()?;
 
+        // Geometry columns are described by the GeoParquet file metadata.
+        #[cfg(feature = "dtype-geometry")]
+        let key_value_metadata = match super::geoparquet::geo_metadata(writer.schema()) {
+            Some(geo) => {
+                let mut kvs = key_value_metadata
+                    .unwrap_or_else(|| vec![schema_to_metadata_key(writer.schema())]);
+                if !kvs.iter().any(|kv| kv.key == geo.key) {
+                    kvs.push(geo);
+                }
+                Some(kvs)
+            },
+            None => key_value_metadata,
+        };
+
         let size = writer.end(key_value_metadata)?;
         Ok(size)
     }
@@ -0,0 +1,51 @@
+use arrow::datatypes::{ArrowDataType, ArrowSchema};
+use polars_core::prelude::GEOARROW_WKB_EXTENSION_NAME;
+use polars_parquet::write::KeyValue;
+use serde_json::{Map, Value, json};
+
+/// The key of the GeoParquet file metadata.
+pub(super) const GEO_METADATA_KEY: &str = "geo";
+
+/// Build the GeoParquet file metadata for the geometry columns of `schema`, if there are any.
+///
+/// The coordinate reference system and edge interpolation of every column are taken from its
+/// GeoArrow extension metadata.
+pub(super) fn geo_metadata(schema: &ArrowSchema) -> Option<KeyValue> {
+    let mut columns = Map::new();
+    let mut primary_column = None;
+    for field in schema.iter_values() {
+        let ArrowDataType::Extension(ext) = &field.dtype else {
+            continue;
+        };
+        if ext.name != GEOARROW_WKB_EXTENSION_NAME {
+            continue;
+        }
+
+        let mut column = Map::new();
+        column.insert("encoding".into(), json!("WKB"));
+        column.insert("geometry_types".into(), json!([]));
+        if let Some(Value::Object(md)) = ext
+            .metadata
+            .as_deref()
+            .and_then(|md| serde_json::from_str(md).ok())
+        {
+            for key in ["crs", "edges"] {
+                if let Some(v) = md.get(key) {
+                    column.insert(key.into(), v.clone());
+                }
+            }
+        }
+        primary_column.get_or_insert_with(|| field.name.to_string());
+        columns.insert(field.name.to_string(), Value::Object(column));
+    }
+
+    let value = json!({
+        "version": "1.1.0",
+        "primary_column": primary_column?,
+        "columns": columns,
+    });
+    Some(KeyValue {
+        key: GEO_METADATA_KEY.to_string(),
+        value: Some(value.to_string()),
+    })
+}
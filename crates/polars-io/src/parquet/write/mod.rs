@@ -1,6 +1,8 @@
 //! Functionality for reading and writing Apache Parquet files.
 
 mod batched_writer;
+#[cfg(feature = "dtype-geometry")]
+mod geoparquet;
 mod key_value_metadata;
 mod options;
 mod writer;
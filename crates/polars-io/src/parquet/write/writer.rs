@@ -118,7 +118,7 @@ where
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
         let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
         let parquet_schema = to_parquet_schema(&schema)?;
-        let encodings = get_encodings(&schema);
+        let encodings = get_encodings(&schema)?;
         let options = self.materialize_options();
         let writer = Mutex::new(FileWriter::try_new(self.writer, schema, options)?);
 
@@ -151,7 +151,7 @@ where
     }
 }
 
-pub fn get_encodings(schema: &ArrowSchema) -> Buffer<Vec<Encoding>> {
+pub fn get_encodings(schema: &ArrowSchema) -> PolarsResult<Buffer<Vec<Encoding>>> {
     schema
         .iter_values()
         .map(|f| get_dtype_encoding(&f.dtype))
@@ -167,7 +167,17 @@ pub fn expanded_from_single_directory(paths: &[PlRefPath], expanded_paths: &[PlR
     }
 }
 
+/// Whether a file or directory found while expanding a directory holds metadata rather than data,
+/// e.g. the `_SUCCESS` manifest or the `_polars_staging` directory of a staged commit. Names
+/// containing `=` are hive partitions and are kept.
+fn is_metadata_entry_name(name: &str) -> bool {
+    name.starts_with('_') && !name.contains('=')
+}
+
 /// Recursively traverses directories and expands globs if `glob` is `true`.
+///
+/// Entries whose name starts with `_` are skipped while traversing directories, see
+/// [`is_metadata_entry_name`].
 pub async fn expand_paths(
     paths: &[PlRefPath],
     glob: bool,
@@ -269,7 +279,13 @@ async fn expand_path_cloud(
                 let out = s
                     .list(Some(prefix_ref))
                     .try_filter_map(|x| async move {
-                        let out = (x.size > 0).then(|| {
+                        let is_metadata =
+                            x.location
+                                .prefix_match(prefix_ref)
+                                .is_some_and(|mut parts| {
+                                    parts.any(|p| is_metadata_entry_name(p.as_ref()))
+                                });
+                        let out = (x.size > 0 && !is_metadata).then(|| {
                             PlRefPath::new({
                                 format_path(
                                     cloud_location.scheme,
@@ -487,6 +503,14 @@ pub async fn expand_paths_hive(
                     last_err?;
 
                     for path in paths_scratch.drain(..) {
+                        if path
+                            .file_name()
+                            .and_then(|x| x.to_str())
+                            .is_some_and(is_metadata_entry_name)
+                        {
+                            continue;
+                        }
+
                         let md = path.metadata()?;
 
                         if md.is_dir() {
@@ -665,4 +689,41 @@ mod tests {
             .unwrap();
         assert_eq!(out.as_ref(), paths);
     }
+
+    #[test]
+    fn test_expand_directory_skips_metadata_entries() {
+        use super::expand_paths;
+
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+
+        for file in [
+            "a=1/0.parquet",
+            "_a=2/0.parquet",
+            "_SUCCESS",
+            "_polars_staging/write/a=3/0.parquet",
+            "a=1/_tmp.parquet",
+        ] {
+            let path = dir.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, b"x").unwrap();
+        }
+
+        let paths = &[PlRefPath::try_from_path(dir).unwrap()];
+        let out = get_runtime()
+            .block_on(expand_paths(paths, true, &[], &mut None))
+            .unwrap();
+        let out = out
+            .iter()
+            .map(|p| p.as_std_path().strip_prefix(dir).unwrap().to_path_buf())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            out,
+            [
+                PathBuf::from("_a=2/0.parquet"),
+                PathBuf::from("a=1/0.parquet")
+            ]
+        );
+    }
 }
@@ -2,15 +2,23 @@ use std::ops::Range;
 use std::path::Path;
 use std::sync::Arc;
 
+#[cfg(feature = "cloud")]
+use object_store::ObjectMeta;
 use polars_buffer::Buffer;
 use polars_core::prelude::PlHashMap;
 use polars_error::{PolarsResult, feature_gated};
 use polars_utils::_limit_path_len_io_err;
+#[cfg(feature = "cloud")]
+use polars_utils::format_pl_smallstr;
 use polars_utils::mmap::MMapSemaphore;
 use polars_utils::pl_path::PlRefPath;
+#[cfg(feature = "cloud")]
+use polars_utils::pl_str::PlSmallStr;
 
 use crate::cloud::options::CloudOptions;
 #[cfg(feature = "cloud")]
+use crate::cloud::range_cache::{BYTE_RANGE_CACHE, ByteRangeCacheKey};
+#[cfg(feature = "cloud")]
 use crate::cloud::{
     CloudLocation, ObjectStorePath, PolarsObjectStore, build_object_store, object_path_from_str,
 };
@@ -76,6 +84,9 @@ impl ByteSource for BufferByteSource {
 pub struct ObjectStoreByteSource {
     store: PolarsObjectStore,
     path: ObjectStorePath,
+    uri: PlRefPath,
+    /// Result of a HEAD request, fetched on first use.
+    metadata: tokio::sync::OnceCell<ObjectMeta>,
 }
 
 #[cfg(feature = "cloud")]
@@ -85,31 +96,129 @@ impl ObjectStoreByteSource {
         cloud_options: Option<&CloudOptions>,
         io_metrics: Option<Arc<IOMetrics>>,
     ) -> PolarsResult<Self> {
+        let uri = path.clone();
         let (CloudLocation { prefix, .. }, mut store) =
             build_object_store(path, cloud_options, false).await?;
         let path = object_path_from_str(&prefix)?;
 
         store.set_io_metrics(io_metrics);
 
-        Ok(Self { store, path })
+        Ok(Self {
+            store,
+            path,
+            uri,
+            metadata: tokio::sync::OnceCell::new(),
+        })
+    }
+
+    async fn metadata(&self) -> PolarsResult<&ObjectMeta> {
+        self.metadata
+            .get_or_try_init(|| self.store.head(&self.path))
+            .await
+    }
+
+    /// Returns `None` if the byte-range cache is disabled.
+    async fn range_cache_version(&self) -> PolarsResult<Option<PlSmallStr>> {
+        if !BYTE_RANGE_CACHE.is_enabled() {
+            return Ok(None);
+        }
+
+        let metadata = self.metadata().await?;
+
+        let version = match (&metadata.e_tag, &metadata.version) {
+            (Some(e_tag), _) => e_tag.as_str().into(),
+            (None, Some(version)) => version.as_str().into(),
+            (None, None) => format_pl_smallstr!(
+                "{}-{}",
+                metadata.last_modified.timestamp_millis(),
+                metadata.size
+            ),
+        };
+
+        Ok(Some(version))
+    }
+
+    fn range_cache_key(&self, version: &PlSmallStr, range: Range<usize>) -> ByteRangeCacheKey {
+        ByteRangeCacheKey {
+            uri: self.uri.clone(),
+            version: version.clone(),
+            range,
+        }
     }
 }
 
 #[cfg(feature = "cloud")]
 impl ByteSource for ObjectStoreByteSource {
     async fn get_size(&self) -> PolarsResult<usize> {
-        Ok(self.store.head(&self.path).await?.size as usize)
+        Ok(self.metadata().await?.size as usize)
     }
 
     async fn get_range(&self, range: Range<usize>) -> PolarsResult<Buffer<u8>> {
-        self.store.get_range(&self.path, range).await
+        let Some(version) = self.range_cache_version().await? else {
+            return self.store.get_range(&self.path, range).await;
+        };
+
+        let key = self.range_cache_key(&version, range);
+
+        if let Some(bytes) = BYTE_RANGE_CACHE.get(&key).await {
+            return Ok(bytes);
+        }
+
+        let bytes = self.store.get_range(&self.path, key.range.clone()).await?;
+        BYTE_RANGE_CACHE.insert(key, bytes.clone()).await;
+
+        Ok(bytes)
     }
 
     async fn get_ranges(
         &self,
         ranges: &mut [Range<usize>],
     ) -> PolarsResult<PlHashMap<usize, Buffer<u8>>> {
-        self.store.get_ranges_sort(&self.path, ranges).await
+        let Some(version) = self.range_cache_version().await? else {
+            return self.store.get_ranges_sort(&self.path, ranges).await;
+        };
+
+        let mut out: PlHashMap<usize, Buffer<u8>> = PlHashMap::default();
+        let mut missing = Vec::with_capacity(ranges.len());
+
+        for range in ranges.iter() {
+            match BYTE_RANGE_CACHE
+                .get(&self.range_cache_key(&version, range.clone()))
+                .await
+            {
+                Some(bytes) => insert_longest(&mut out, range.start, bytes),
+                None => missing.push(range.clone()),
+            }
+        }
+
+        if !missing.is_empty() {
+            let fetched = self.store.get_ranges_sort(&self.path, &mut missing).await?;
+
+            for range in missing {
+                // Entries with a shared start offset hold the longest of those ranges.
+                let bytes = fetched[&range.start].clone().sliced(0..range.len());
+                BYTE_RANGE_CACHE
+                    .insert(self.range_cache_key(&version, range), bytes)
+                    .await;
+            }
+
+            for (start, bytes) in fetched {
+                insert_longest(&mut out, start, bytes);
+            }
+        }
+
+        ranges.sort_unstable_by_key(|x| x.start);
+
+        Ok(out)
+    }
+}
+
+#[cfg(feature = "cloud")]
+fn insert_longest(out: &mut PlHashMap<usize, Buffer<u8>>, start: usize, bytes: Buffer<u8>) {
+    let v = out.entry(start).or_insert_with(|| bytes.clone());
+
+    if bytes.len() > v.len() {
+        *v = bytes;
     }
 }
 
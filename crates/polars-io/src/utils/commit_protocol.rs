@@ -0,0 +1,22 @@
+/// How files written by a multi-file sink are made visible at their destination.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum CommitProtocol {
+    /// Write files directly to their final location.
+    #[default]
+    Direct,
+
+    /// Write files to a staging location and publish them once all files have been written.
+    Staged {
+        /// Remove existing files from the partitions that are written to once the new files have
+        /// been published. Partitions that are not written to are left untouched.
+        overwrite_partitions: bool,
+    },
+}
+
+impl CommitProtocol {
+    pub fn is_staged(&self) -> bool {
+        matches!(self, Self::Staged { .. })
+    }
+}
@@ -15,6 +15,8 @@ pub enum SupportedCompression {
     GZIP,
     ZLIB,
     ZSTD,
+    BZIP2,
+    XZ,
 }
 
 impl SupportedCompression {
@@ -34,6 +36,10 @@ impl SupportedCompression {
             [0x78, 0x9c, _, _] => Some(Self::ZLIB),
             [0x78, 0xda, _, _] => Some(Self::ZLIB),
             [0x28, 0xb5, 0x2f, 0xfd] => Some(Self::ZSTD),
+            // Skippable frame, e.g. as written by some seekable zstd encoders.
+            [0x50..=0x5f, 0x2a, 0x4d, 0x18] => Some(Self::ZSTD),
+            [0x42, 0x5a, 0x68, b'1'..=b'9'] => Some(Self::BZIP2),
+            [0xfd, 0x37, 0x7a, 0x58] => Some(Self::XZ),
             _ => None,
         }
     }
@@ -65,13 +71,19 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
             SupportedCompression::ZSTD => {
                 zstd::Decoder::with_buffer(bytes)?.read_to_end(out)?;
             },
+            SupportedCompression::BZIP2 => {
+                bzip2::bufread::MultiBzDecoder::new(bytes).read_to_end(out)?;
+            },
+            SupportedCompression::XZ => {
+                liblzma::bufread::XzDecoder::new_multi_decoder(bytes).read_to_end(out)?;
+            },
         }
 
         Ok(out)
     })
 }
 
-/// Reader that implements a streaming read trait for uncompressed, gzip, zlib and zstd
+/// Reader that implements a streaming read trait for uncompressed, gzip, zlib, zstd, bzip2 and xz
 /// compression.
 ///
 /// This allows handling decompression transparently in a streaming fashion.
@@ -86,6 +98,10 @@ pub enum CompressedReader {
     Zlib(flate2::bufread::ZlibDecoder<Cursor<Buffer<u8>>>),
     #[cfg(feature = "decompress")]
     Zstd(zstd::Decoder<'static, Cursor<Buffer<u8>>>),
+    #[cfg(feature = "decompress")]
+    Bzip2(bzip2::bufread::MultiBzDecoder<Cursor<Buffer<u8>>>),
+    #[cfg(feature = "decompress")]
+    Xz(liblzma::bufread::XzDecoder<Cursor<Buffer<u8>>>),
 }
 
 impl CompressedReader {
@@ -106,6 +122,14 @@ impl CompressedReader {
             Some(SupportedCompression::ZSTD) => {
                 CompressedReader::Zstd(zstd::Decoder::with_buffer(Cursor::new(slice))?)
             },
+            #[cfg(feature = "decompress")]
+            Some(SupportedCompression::BZIP2) => {
+                CompressedReader::Bzip2(bzip2::bufread::MultiBzDecoder::new(Cursor::new(slice)))
+            },
+            #[cfg(feature = "decompress")]
+            Some(SupportedCompression::XZ) => CompressedReader::Xz(
+                liblzma::bufread::XzDecoder::new_multi_decoder(Cursor::new(slice)),
+            ),
             #[cfg(not(feature = "decompress"))]
             _ => panic!("activate 'decompress' feature"),
         })
@@ -137,6 +161,7 @@ impl CompressedReader {
     pub fn total_len_estimate(&self) -> usize {
         const ESTIMATED_DEFLATE_RATIO: usize = 3;
         const ESTIMATED_ZSTD_RATIO: usize = 5;
+        const ESTIMATED_BZIP2_XZ_RATIO: usize = 6;
 
         match self {
             CompressedReader::Uncompressed { slice, .. } => slice.len(),
@@ -152,6 +177,14 @@ impl CompressedReader {
             CompressedReader::Zstd(reader) => {
                 reader.get_ref().get_ref().len() * ESTIMATED_ZSTD_RATIO
             },
+            #[cfg(feature = "decompress")]
+            CompressedReader::Bzip2(reader) => {
+                reader.get_ref().get_ref().len() * ESTIMATED_BZIP2_XZ_RATIO
+            },
+            #[cfg(feature = "decompress")]
+            CompressedReader::Xz(reader) => {
+                reader.get_ref().get_ref().len() * ESTIMATED_BZIP2_XZ_RATIO
+            },
         }
     }
 
@@ -216,6 +249,14 @@ impl CompressedReader {
             CompressedReader::Zstd(decoder) => {
                 new_slice_from_read(decoder.take(read_size as u64).read_to_end(&mut buf)?, buf)
             },
+            #[cfg(feature = "decompress")]
+            CompressedReader::Bzip2(decoder) => {
+                new_slice_from_read(decoder.take(read_size as u64).read_to_end(&mut buf)?, buf)
+            },
+            #[cfg(feature = "decompress")]
+            CompressedReader::Xz(decoder) => {
+                new_slice_from_read(decoder.take(read_size as u64).read_to_end(&mut buf)?, buf)
+            },
         }
     }
 }
@@ -237,6 +278,10 @@ impl Read for CompressedReader {
             CompressedReader::Zlib(decoder) => decoder.read(buf),
             #[cfg(feature = "decompress")]
             CompressedReader::Zstd(decoder) => decoder.read(buf),
+            #[cfg(feature = "decompress")]
+            CompressedReader::Bzip2(decoder) => decoder.read(buf),
+            #[cfg(feature = "decompress")]
+            CompressedReader::Xz(decoder) => decoder.read(buf),
         }
     }
 }
@@ -246,7 +291,9 @@ impl Read for CompressedReader {
 ///
 /// Implements `BufRead`, allowing uniform access regardless of whether
 /// the underlying data is an in-memory slice, a raw stream, or a
-/// compressed stream (gzip/zlib/zstd).
+/// compressed stream (gzip/zlib/zstd/bzip2/xz). Compressed streams are decoded incrementally,
+/// including concatenated gzip members, zstd frames and bzip2/xz streams, so only the decoder
+/// state and the requested slices are held in memory.
 ///
 /// This is the generic successor to [`CompressedReader`], which only
 /// supports in-memory (`Buffer<u8>`) sources.
@@ -262,6 +309,10 @@ pub enum ByteSourceReader<R: BufRead> {
     Zlib(flate2::bufread::ZlibDecoder<R>),
     #[cfg(feature = "decompress")]
     Zstd(zstd::Decoder<'static, R>),
+    #[cfg(feature = "decompress")]
+    Bzip2(bzip2::bufread::MultiBzDecoder<R>),
+    #[cfg(feature = "decompress")]
+    Xz(liblzma::bufread::XzDecoder<R>),
 }
 
 impl<R: BufRead> ByteSourceReader<R> {
@@ -278,6 +329,14 @@ impl<R: BufRead> ByteSourceReader<R> {
             },
             #[cfg(feature = "decompress")]
             Some(SupportedCompression::ZSTD) => Self::Zstd(zstd::Decoder::with_buffer(reader)?),
+            #[cfg(feature = "decompress")]
+            Some(SupportedCompression::BZIP2) => {
+                Self::Bzip2(bzip2::bufread::MultiBzDecoder::new(reader))
+            },
+            #[cfg(feature = "decompress")]
+            Some(SupportedCompression::XZ) => {
+                Self::Xz(liblzma::bufread::XzDecoder::new_multi_decoder(reader))
+            },
             #[cfg(not(feature = "decompress"))]
             _ => panic!("activate 'decompress' feature"),
         })
@@ -300,6 +359,10 @@ impl<R: BufRead> ByteSourceReader<R> {
             Self::Zlib(_) => Some(SupportedCompression::ZLIB),
             #[cfg(feature = "decompress")]
             Self::Zstd(_) => Some(SupportedCompression::ZSTD),
+            #[cfg(feature = "decompress")]
+            Self::Bzip2(_) => Some(SupportedCompression::BZIP2),
+            #[cfg(feature = "decompress")]
+            Self::Xz(_) => Some(SupportedCompression::XZ),
         }
     }
 
@@ -362,6 +425,10 @@ impl<R: BufRead> ByteSourceReader<R> {
             Self::Zlib(reader) => reader,
             #[cfg(feature = "decompress")]
             Self::Zstd(reader) => reader,
+            #[cfg(feature = "decompress")]
+            Self::Bzip2(reader) => reader,
+            #[cfg(feature = "decompress")]
+            Self::Xz(reader) => reader,
         };
 
         let mut buf = Vec::new();
@@ -394,6 +461,10 @@ pub enum CompressedWriter {
     Gzip(Option<flate2::write::GzEncoder<Writeable>>),
     #[cfg(feature = "decompress")]
     Zstd(Option<zstd::Encoder<'static, Writeable>>),
+    #[cfg(feature = "decompress")]
+    Bzip2(Option<bzip2::write::BzEncoder<Writeable>>),
+    #[cfg(feature = "decompress")]
+    Xz(Option<liblzma::write::XzEncoder<Writeable>>),
 }
 
 impl CompressedWriter {
@@ -413,6 +484,37 @@ impl CompressedWriter {
                 .map(Self::Zstd)
         })
     }
+
+    /// `level` must be in `1..=9`, defaults to 6.
+    pub fn bzip2(writer: Writeable, level: Option<u32>) -> PolarsResult<Self> {
+        feature_gated!("decompress", {
+            let level = level.unwrap_or(6);
+            polars_ensure!(
+                (1..=9).contains(&level),
+                InvalidOperation: "invalid bzip2 compression level: {level}, expected a value in 1..=9"
+            );
+
+            Ok(Self::Bzip2(Some(bzip2::write::BzEncoder::new(
+                writer,
+                bzip2::Compression::new(level),
+            ))))
+        })
+    }
+
+    /// `level` must be in `0..=9`, defaults to 6.
+    pub fn xz(writer: Writeable, level: Option<u32>) -> PolarsResult<Self> {
+        feature_gated!("decompress", {
+            let level = level.unwrap_or(6);
+            polars_ensure!(
+                level <= 9,
+                InvalidOperation: "invalid xz compression level: {level}, expected a value in 0..=9"
+            );
+
+            Ok(Self::Xz(Some(liblzma::write::XzEncoder::new(
+                writer, level,
+            ))))
+        })
+    }
 }
 
 impl Write for CompressedWriter {
@@ -421,6 +523,8 @@ impl Write for CompressedWriter {
             match self {
                 Self::Gzip(encoder) => encoder.as_mut().unwrap().write(buf),
                 Self::Zstd(encoder) => encoder.as_mut().unwrap().write(buf),
+                Self::Bzip2(encoder) => encoder.as_mut().unwrap().write(buf),
+                Self::Xz(encoder) => encoder.as_mut().unwrap().write(buf),
             }
         })
     }
@@ -430,6 +534,8 @@ impl Write for CompressedWriter {
             match self {
                 Self::Gzip(encoder) => encoder.as_mut().unwrap().flush(),
                 Self::Zstd(encoder) => encoder.as_mut().unwrap().flush(),
+                Self::Bzip2(encoder) => encoder.as_mut().unwrap().flush(),
+                Self::Xz(encoder) => encoder.as_mut().unwrap().flush(),
             }
         })
     }
@@ -441,6 +547,8 @@ impl WriteableTrait for CompressedWriter {
             let writer = match self {
                 Self::Gzip(encoder) => encoder.take().unwrap().finish()?,
                 Self::Zstd(encoder) => encoder.take().unwrap().finish()?,
+                Self::Bzip2(encoder) => encoder.take().unwrap().finish()?,
+                Self::Xz(encoder) => encoder.take().unwrap().finish()?,
             };
 
             writer.close(SyncOnCloseType::All)
@@ -452,6 +560,8 @@ impl WriteableTrait for CompressedWriter {
             match self {
                 Self::Gzip(encoder) => encoder.as_ref().unwrap().get_ref().sync_all(),
                 Self::Zstd(encoder) => encoder.as_ref().unwrap().get_ref().sync_all(),
+                Self::Bzip2(encoder) => encoder.as_ref().unwrap().get_ref().sync_all(),
+                Self::Xz(encoder) => encoder.as_ref().unwrap().get_ref().sync_all(),
             }
         })
     }
@@ -461,7 +571,99 @@ impl WriteableTrait for CompressedWriter {
             match self {
                 Self::Gzip(encoder) => encoder.as_ref().unwrap().get_ref().sync_data(),
                 Self::Zstd(encoder) => encoder.as_ref().unwrap().get_ref().sync_data(),
+                Self::Bzip2(encoder) => encoder.as_ref().unwrap().get_ref().sync_data(),
+                Self::Xz(encoder) => encoder.as_ref().unwrap().get_ref().sync_data(),
             }
         })
     }
 }
+
+#[cfg(all(test, feature = "decompress"))]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use polars_buffer::Buffer;
+
+    use super::{ByteSourceReader, SupportedCompression};
+    use crate::utils::stream_buf_reader::ReaderSource;
+
+    fn lines(range: std::ops::Range<usize>) -> Vec<u8> {
+        range
+            .flat_map(|i| format!("{{\"a\":{i}}}\n").into_bytes())
+            .collect()
+    }
+
+    /// Compresses each part separately and concatenates the results, i.e. multiple gzip members
+    /// / zstd frames / bzip2 and xz streams.
+    fn compress_parts(compression: SupportedCompression, parts: &[&[u8]]) -> Vec<u8> {
+        let mut out = vec![];
+
+        for part in parts {
+            match compression {
+                SupportedCompression::GZIP => {
+                    let mut encoder =
+                        flate2::write::GzEncoder::new(&mut out, flate2::Compression::fast());
+                    encoder.write_all(part).unwrap();
+                    encoder.finish().unwrap();
+                },
+                SupportedCompression::ZLIB => unreachable!(),
+                SupportedCompression::ZSTD => {
+                    out.extend(zstd::encode_all(*part, 1).unwrap());
+                },
+                SupportedCompression::BZIP2 => {
+                    let mut encoder =
+                        bzip2::write::BzEncoder::new(&mut out, bzip2::Compression::fast());
+                    encoder.write_all(part).unwrap();
+                    encoder.finish().unwrap();
+                },
+                SupportedCompression::XZ => {
+                    let mut encoder = liblzma::write::XzEncoder::new(&mut out, 1);
+                    encoder.write_all(part).unwrap();
+                    encoder.finish().unwrap();
+                },
+            }
+        }
+
+        out
+    }
+
+    #[test]
+    fn test_streaming_decompression_multi_member() {
+        let parts = [lines(0..1000), lines(1000..1500), lines(1500..3000)];
+        let expected = parts.concat();
+
+        for compression in [
+            SupportedCompression::GZIP,
+            SupportedCompression::ZSTD,
+            SupportedCompression::BZIP2,
+            SupportedCompression::XZ,
+        ] {
+            let compressed = compress_parts(
+                compression,
+                &parts.iter().map(|x| x.as_slice()).collect::<Vec<_>>(),
+            );
+            assert_eq!(SupportedCompression::check(&compressed), Some(compression));
+
+            let mut reader = ByteSourceReader::try_new(
+                ReaderSource::Memory(Cursor::new(Buffer::from_vec(compressed))),
+                Some(compression),
+            )
+            .unwrap();
+            assert_eq!(reader.compression(), Some(compression));
+
+            // Read in small slices to exercise incremental decoding across member boundaries.
+            let mut out = vec![];
+            loop {
+                let (slice, bytes_read) =
+                    reader.read_next_slice(&Buffer::new(), 1000, None).unwrap();
+                if bytes_read == 0 {
+                    break;
+                }
+                assert!(slice.len() <= 1000);
+                out.extend_from_slice(&slice);
+            }
+
+            assert_eq!(out, expected, "{compression:?}");
+        }
+    }
+}
@@ -4,9 +4,12 @@ mod other;
 pub use other::*;
 #[cfg(any(feature = "async", feature = "cloud"))]
 pub mod byte_source;
+pub mod commit_protocol;
 pub mod file;
 pub mod mkdir;
 pub mod slice;
+#[cfg(feature = "async")]
+pub mod staged_commit;
 pub mod stream_buf_reader;
 pub mod sync_on_close;
 
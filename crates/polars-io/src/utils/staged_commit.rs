@@ -0,0 +1,750 @@
+//! Commit protocol for sinks that write multiple files into a base path.
+//!
+//! With [`CommitProtocol::Staged`], files are written under a staging prefix inside of the base
+//! path (`{base_path}/_polars_staging/{write_id}/`). Only once all files have been written are they
+//! published to their final location. A `_SUCCESS` manifest listing the published files is written
+//! last, so readers can distinguish a complete dataset from one that is still being written.
+//!
+//! * Local paths are published using `rename`, which is atomic per file. If publishing fails
+//!   partway, the files that were already published are moved back and any files they replaced are
+//!   restored.
+//! * Object store paths are published using server-side copies followed by deletes of the staged
+//!   objects. Copies that were already made are not rolled back if publishing fails partway, only
+//!   the `_SUCCESS` manifest marks the dataset as complete.
+//!
+//! When overwriting partitions, the existing files are only removed after all new files have been
+//! published, so a failed write never leaves a partition without data. The `_SUCCESS` manifest
+//! lists the files of earlier writes that are still present together with the newly published
+//! files.
+//!
+//! Directory scans skip entries whose name starts with `_`, so neither the staging directory nor
+//! the manifest are read as data.
+use std::sync::{Arc, Mutex};
+
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
+use polars_utils::pl_path::PlRefPath;
+
+pub use super::commit_protocol::CommitProtocol;
+use crate::cloud::CloudOptions;
+
+/// Name of the directory under the base path that holds in-progress writes.
+pub const STAGING_DIR_NAME: &str = "_polars_staging";
+/// Name of the manifest file that is written once all files have been published.
+pub const SUCCESS_MARKER_NAME: &str = "_SUCCESS";
+/// Name of the directory under the staging path that holds files replaced while publishing.
+const REPLACED_DIR_NAME: &str = "_polars_replaced";
+
+/// State of a single staged write.
+pub struct StagedCommit {
+    base_path: PlRefPath,
+    write_id: String,
+    staging_path: PlRefPath,
+    overwrite_partitions: bool,
+    #[cfg_attr(not(feature = "cloud"), allow(unused))]
+    cloud_options: Option<Arc<CloudOptions>>,
+    /// Paths relative to the base path of all files that were staged.
+    staged_files: Mutex<Vec<String>>,
+}
+
+impl StagedCommit {
+    /// Returns an error if `commit_protocol` is not [`CommitProtocol::Staged`].
+    pub fn new(
+        base_path: PlRefPath,
+        write_id: &str,
+        commit_protocol: CommitProtocol,
+        cloud_options: Option<Arc<CloudOptions>>,
+    ) -> PolarsResult<Self> {
+        let CommitProtocol::Staged {
+            overwrite_partitions,
+        } = commit_protocol
+        else {
+            polars_bail!(
+                ComputeError:
+                "staged commit requires the staged commit protocol, got: {commit_protocol:?}"
+            )
+        };
+
+        let staging_path = base_path.join(STAGING_DIR_NAME).join(write_id);
+
+        Ok(Self {
+            base_path,
+            write_id: write_id.to_string(),
+            staging_path,
+            overwrite_partitions,
+            cloud_options,
+            staged_files: Mutex::new(vec![]),
+        })
+    }
+
+    pub fn base_path(&self) -> &PlRefPath {
+        &self.base_path
+    }
+
+    pub fn staging_path(&self) -> &PlRefPath {
+        &self.staging_path
+    }
+
+    /// Register a file to be published. Returns the path the file should be written to.
+    pub fn stage_file(&self, relative_path: &str) -> PolarsResult<PlRefPath> {
+        polars_ensure!(
+            !relative_path.is_empty() && !relative_path.starts_with(['/', '\\']),
+            ComputeError:
+            "staged commit requires paths relative to the base path, got: '{relative_path}'"
+        );
+
+        self.staged_files
+            .lock()
+            .unwrap()
+            .push(relative_path.to_string());
+
+        Ok(self.staging_path.join(relative_path))
+    }
+
+    pub fn staged_files(&self) -> Vec<String> {
+        self.staged_files.lock().unwrap().clone()
+    }
+
+    /// Directories (relative to the base path) of all staged files, sorted and deduplicated. The
+    /// base path itself is represented by an empty string.
+    fn touched_partitions(files: &[String]) -> Vec<&str> {
+        let mut out: Vec<&str> = files
+            .iter()
+            .map(|f| f.rfind('/').map_or("", |i| &f[..i]))
+            .collect();
+
+        out.sort_unstable();
+        out.dedup();
+        out
+    }
+
+    /// Files whose name starts with `_` or `.` are considered metadata and are never removed when
+    /// overwriting partitions.
+    fn is_data_file_name(name: &str) -> bool {
+        !name.starts_with(['_', '.'])
+    }
+
+    fn join_relative(partition: &str, name: &str) -> String {
+        if partition.is_empty() {
+            name.to_string()
+        } else {
+            format!("{partition}/{name}")
+        }
+    }
+
+    /// Files of `existing` that are not replaced by a published file of the same name.
+    fn stale_files(mut existing: Vec<String>, published: &[String]) -> Vec<String> {
+        existing.retain(|f| published.binary_search(f).is_err());
+        existing
+    }
+
+    /// Contents of the new manifest: the entries of the `existing` manifest that were not
+    /// `removed`, together with the `published` files.
+    fn manifest_bytes(
+        existing: Option<&[u8]>,
+        published: &[String],
+        removed: &[String],
+    ) -> Vec<u8> {
+        let existing = existing.map(String::from_utf8_lossy).unwrap_or_default();
+        let files: std::collections::BTreeSet<&str> = existing
+            .lines()
+            .filter(|f| !f.is_empty() && !removed.iter().any(|r| r == f))
+            .chain(published.iter().map(|f| f.as_str()))
+            .collect();
+
+        let mut out = Vec::with_capacity(files.iter().map(|f| f.len() + 1).sum());
+
+        for f in files {
+            out.extend_from_slice(f.as_bytes());
+            out.push(b'\n');
+        }
+
+        out
+    }
+
+    /// Publish all staged files to the base path and write the `_SUCCESS` manifest.
+    pub async fn commit(&self) -> PolarsResult<()> {
+        let mut files = self.staged_files();
+        files.sort_unstable();
+
+        if !self.base_path.has_scheme() {
+            self.commit_local(&files).await
+        } else {
+            #[cfg(feature = "cloud")]
+            {
+                self.commit_cloud(&files).await
+            }
+            #[cfg(not(feature = "cloud"))]
+            {
+                polars_error::polars_bail!(
+                    ComputeError:
+                    "staged commit to '{}' requires the 'cloud' feature", self.base_path
+                )
+            }
+        }
+    }
+
+    /// Remove the staging location of this write. Errors are ignored.
+    pub async fn abort(&self) {
+        if !self.staging_path.has_scheme() {
+            let _ = tokio::fs::remove_dir_all(self.staging_path.as_str()).await;
+        } else {
+            #[cfg(feature = "cloud")]
+            {
+                let _ =
+                    cloud::delete_prefix(&self.staging_path, self.cloud_options.as_deref()).await;
+            }
+        }
+    }
+
+    /// Remove staging locations of other writes that were started more than `max_age` ago. These
+    /// are left behind by processes that were killed before they could commit or abort.
+    ///
+    /// Staging locations are identified by UUIDv7 write IDs, which embed their creation time.
+    /// Locations with unrecognized names are left untouched.
+    pub async fn cleanup_orphaned(&self, max_age: std::time::Duration) -> PolarsResult<()> {
+        let staging_root = self.base_path.join(STAGING_DIR_NAME);
+        let now = std::time::SystemTime::now();
+
+        let is_orphaned = |write_id: &str| -> bool {
+            if write_id == self.write_id {
+                return false;
+            }
+
+            let Ok(uuid) = uuid::Uuid::parse_str(write_id) else {
+                return false;
+            };
+
+            let Some(ts) = uuid.get_timestamp() else {
+                return false;
+            };
+
+            let (secs, nanos) = ts.to_unix();
+            let started_at = std::time::UNIX_EPOCH + std::time::Duration::new(secs, nanos);
+
+            now.duration_since(started_at)
+                .is_ok_and(|elapsed| elapsed > max_age)
+        };
+
+        if !staging_root.has_scheme() {
+            let mut entries = match tokio::fs::read_dir(staging_root.as_str()).await {
+                Ok(v) => v,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+                Err(e) => return Err(e.into()),
+            };
+
+            while let Some(entry) = entries.next_entry().await? {
+                let name = entry.file_name();
+
+                if let Some(name) = name.to_str()
+                    && is_orphaned(name)
+                {
+                    let _ = tokio::fs::remove_dir_all(entry.path()).await;
+                }
+            }
+        } else {
+            #[cfg(feature = "cloud")]
+            {
+                for write_id in
+                    cloud::list_child_prefixes(&staging_root, self.cloud_options.as_deref()).await?
+                {
+                    if is_orphaned(&write_id) {
+                        cloud::delete_prefix(
+                            &staging_root.join(&write_id),
+                            self.cloud_options.as_deref(),
+                        )
+                        .await?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn commit_local(&self, files: &[String]) -> PolarsResult<()> {
+        let base_path = std::path::Path::new(self.base_path.as_str());
+        let staging_path = std::path::Path::new(self.staging_path.as_str());
+
+        // Collected before publishing, as the old files are only removed once all new files are in
+        // place.
+        let mut replaced_files = vec![];
+
+        if self.overwrite_partitions {
+            for partition in Self::touched_partitions(files) {
+                let mut entries = match tokio::fs::read_dir(base_path.join(partition)).await {
+                    Ok(v) => v,
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                    Err(e) => return Err(e.into()),
+                };
+
+                while let Some(entry) = entries.next_entry().await? {
+                    if entry.file_type().await?.is_file()
+                        && let Some(name) = entry.file_name().to_str()
+                        && Self::is_data_file_name(name)
+                    {
+                        replaced_files.push(Self::join_relative(partition, name));
+                    }
+                }
+            }
+        }
+
+        Self::publish_local(base_path, staging_path, files).await?;
+
+        let stale_files = Self::stale_files(replaced_files, files);
+        for file in &stale_files {
+            tokio::fs::remove_file(base_path.join(file)).await?;
+        }
+
+        let existing_manifest = match tokio::fs::read(base_path.join(SUCCESS_MARKER_NAME)).await {
+            Ok(v) => Some(v),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
+        let manifest = Self::manifest_bytes(existing_manifest.as_deref(), files, &stale_files);
+
+        // Write the manifest to a temporary file first so that it appears atomically.
+        let manifest_tmp = staging_path.join(SUCCESS_MARKER_NAME);
+        tokio::fs::write(&manifest_tmp, manifest).await?;
+        tokio::fs::rename(&manifest_tmp, base_path.join(SUCCESS_MARKER_NAME)).await?;
+
+        tokio::fs::remove_dir_all(staging_path).await?;
+
+        // Remove the staging root if no other writes are in progress.
+        let _ = tokio::fs::remove_dir(base_path.join(STAGING_DIR_NAME)).await;
+
+        Ok(())
+    }
+
+    /// Move the staged `files` to the base path. If this fails partway, the files that were
+    /// already moved are moved back and the files they replaced are restored.
+    async fn publish_local(
+        base_path: &std::path::Path,
+        staging_path: &std::path::Path,
+        files: &[String],
+    ) -> PolarsResult<()> {
+        let replaced_path = staging_path.join(REPLACED_DIR_NAME);
+        // Files that were (or were being) published, and whether they replaced an existing file.
+        let mut published: Vec<(&str, bool)> = vec![];
+
+        let result: std::io::Result<()> = async {
+            for file in files {
+                let to = base_path.join(file);
+
+                if let Some(parent) = to.parent() {
+                    tokio::fs::create_dir_all(parent).await?;
+                }
+
+                let replaces = match tokio::fs::metadata(&to).await {
+                    Ok(_) => true,
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => false,
+                    Err(e) => return Err(e),
+                };
+
+                if replaces {
+                    let backup = replaced_path.join(file);
+                    if let Some(parent) = backup.parent() {
+                        tokio::fs::create_dir_all(parent).await?;
+                    }
+                    tokio::fs::rename(&to, backup).await?;
+                }
+
+                published.push((file, replaces));
+                tokio::fs::rename(staging_path.join(file), to).await?;
+            }
+
+            Ok(())
+        }
+        .await;
+
+        if let Err(e) = result {
+            for (file, replaces) in published.into_iter().rev() {
+                let to = base_path.join(file);
+                let _ = tokio::fs::rename(&to, staging_path.join(file)).await;
+
+                if replaces {
+                    let _ = tokio::fs::rename(replaced_path.join(file), &to).await;
+                }
+            }
+
+            return Err(e.into());
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "cloud")]
+    async fn commit_cloud(&self, files: &[String]) -> PolarsResult<()> {
+        use object_store::ObjectStoreExt;
+
+        use crate::cloud::{build_object_store, object_path_from_str};
+
+        let cloud_options = self.cloud_options.as_deref();
+
+        let mut replaced_files = vec![];
+
+        if self.overwrite_partitions {
+            for partition in Self::touched_partitions(files) {
+                let partition_path = if partition.is_empty() {
+                    self.base_path.clone()
+                } else {
+                    self.base_path.join(partition)
+                };
+
+                for name in cloud::list_child_objects(&partition_path, cloud_options).await? {
+                    if Self::is_data_file_name(&name) {
+                        replaced_files.push(Self::join_relative(partition, &name));
+                    }
+                }
+            }
+        }
+
+        let (base_location, store) =
+            build_object_store(self.base_path.clone(), cloud_options, false).await?;
+        let (staging_location, _) =
+            build_object_store(self.staging_path.clone(), cloud_options, false).await?;
+
+        let base_prefix = base_location.prefix.trim_end_matches('/');
+        let staging_prefix = staging_location.prefix.trim_end_matches('/');
+
+        for file in files {
+            let from = object_path_from_str(&format!("{staging_prefix}/{file}"))?;
+            let to = object_path_from_str(&format!("{base_prefix}/{file}"))?;
+
+            store
+                .exec_with_rebuild_retry_on_err(|s| {
+                    let from = &from;
+                    let to = &to;
+                    async move { s.copy(from, to).await }
+                })
+                .await?;
+        }
+
+        let stale_files = Self::stale_files(replaced_files, files);
+        for file in &stale_files {
+            cloud::delete_object(&self.base_path.join(file), cloud_options).await?;
+        }
+
+        let manifest_path = object_path_from_str(&format!("{base_prefix}/{SUCCESS_MARKER_NAME}"))?;
+        let existing_manifest = store
+            .exec_with_rebuild_retry_on_err(|s| {
+                let manifest_path = &manifest_path;
+                async move {
+                    match s.get(manifest_path).await {
+                        Ok(v) => v.bytes().await.map(Some),
+                        Err(object_store::Error::NotFound { .. }) => Ok(None),
+                        Err(e) => Err(e),
+                    }
+                }
+            })
+            .await?;
+        let manifest = bytes::Bytes::from(Self::manifest_bytes(
+            existing_manifest.as_deref(),
+            files,
+            &stale_files,
+        ));
+
+        store
+            .exec_with_rebuild_retry_on_err(|s| {
+                let manifest_path = &manifest_path;
+                let manifest = manifest.clone();
+                async move { s.put(manifest_path, manifest.into()).await }
+            })
+            .await?;
+
+        cloud::delete_prefix(&self.staging_path, cloud_options).await
+    }
+}
+
+/// Remove a file that was written directly to its final location, i.e. without a staged commit.
+/// Errors are ignored.
+pub async fn remove_direct_file(
+    path: &PlRefPath,
+    #[cfg_attr(not(feature = "cloud"), expect(unused))] cloud_options: Option<&CloudOptions>,
+) {
+    if !path.has_scheme() {
+        let _ = tokio::fs::remove_file(crate::resolve_homedir(path.as_std_path())).await;
+    } else {
+        #[cfg(feature = "cloud")]
+        {
+            let _ = cloud::delete_object(path, cloud_options).await;
+        }
+    }
+}
+
+#[cfg(feature = "cloud")]
+mod cloud {
+    use futures::TryStreamExt;
+    use object_store::ObjectStoreExt;
+    use object_store::path::Path;
+    use polars_error::PolarsResult;
+    use polars_utils::pl_path::PlRefPath;
+
+    use crate::cloud::{CloudOptions, build_object_store, object_path_from_str};
+
+    pub(super) async fn delete_object(
+        path: &PlRefPath,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<()> {
+        let (location, store) = build_object_store(path.clone(), cloud_options, false).await?;
+        let path = object_path_from_str(&location.prefix)?;
+
+        store
+            .exec_with_rebuild_retry_on_err(|s| {
+                let path = &path;
+                async move { s.delete(path).await }
+            })
+            .await
+    }
+
+    /// Delete all objects under the prefix `path`.
+    pub(super) async fn delete_prefix(
+        path: &PlRefPath,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<()> {
+        let (location, store) = build_object_store(path.clone(), cloud_options, false).await?;
+        let prefix = Path::from(location.prefix.as_str());
+
+        let locations: Vec<Path> = store
+            .exec_with_rebuild_retry_on_err(|s| {
+                let prefix = &prefix;
+                async move {
+                    s.list(Some(prefix))
+                        .map_ok(|x| x.location)
+                        .try_collect()
+                        .await
+                }
+            })
+            .await?;
+
+        for location in locations {
+            store
+                .exec_with_rebuild_retry_on_err(|s| {
+                    let location = &location;
+                    async move { s.delete(location).await }
+                })
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Names of the objects directly under `path`.
+    pub(super) async fn list_child_objects(
+        path: &PlRefPath,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Vec<String>> {
+        let (location, store) = build_object_store(path.clone(), cloud_options, false).await?;
+        let prefix = Path::from(location.prefix.as_str());
+
+        let result = store
+            .exec_with_rebuild_retry_on_err(|s| {
+                let prefix = &prefix;
+                async move { s.list_with_delimiter(Some(prefix)).await }
+            })
+            .await?;
+
+        Ok(result
+            .objects
+            .into_iter()
+            .filter_map(|x| x.location.filename().map(|x| x.to_string()))
+            .collect())
+    }
+
+    /// Names of the "directories" directly under `path`.
+    pub(super) async fn list_child_prefixes(
+        path: &PlRefPath,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Vec<String>> {
+        let (location, store) = build_object_store(path.clone(), cloud_options, false).await?;
+        let prefix = Path::from(location.prefix.as_str());
+
+        let result = store
+            .exec_with_rebuild_retry_on_err(|s| {
+                let prefix = &prefix;
+                async move { s.list_with_delimiter(Some(prefix)).await }
+            })
+            .await?;
+
+        Ok(result
+            .common_prefixes
+            .into_iter()
+            .filter_map(|x| x.filename().map(|x| x.to_string()))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::time::Duration;
+
+    use super::{CommitProtocol, STAGING_DIR_NAME, SUCCESS_MARKER_NAME, StagedCommit};
+    use crate::pl_async::get_runtime;
+
+    fn new_staged_commit(base_path: &Path, overwrite_partitions: bool) -> StagedCommit {
+        StagedCommit::new(
+            base_path.to_str().unwrap().into(),
+            &uuid::Uuid::now_v7().to_string(),
+            CommitProtocol::Staged {
+                overwrite_partitions,
+            },
+            None,
+        )
+        .unwrap()
+    }
+
+    fn stage(commit: &StagedCommit, file: &str, contents: &[u8]) {
+        let staged = commit.stage_file(file).unwrap();
+        let staged = Path::new(staged.as_str());
+        std::fs::create_dir_all(staged.parent().unwrap()).unwrap();
+        std::fs::write(staged, contents).unwrap();
+    }
+
+    #[test]
+    fn test_staged_commit_requires_staged_protocol() {
+        assert!(StagedCommit::new("/tmp".into(), "id", CommitProtocol::Direct, None).is_err());
+    }
+
+    #[test]
+    fn test_staged_commit_append_merges_manifest() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+
+        let commit = new_staged_commit(dir, false);
+        stage(&commit, "a=1/0.parquet", b"");
+        get_runtime().block_on(commit.commit()).unwrap();
+
+        let commit = new_staged_commit(dir, false);
+        stage(&commit, "a=2/0.parquet", b"");
+        get_runtime().block_on(commit.commit()).unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(dir.join(SUCCESS_MARKER_NAME)).unwrap(),
+            "a=1/0.parquet\na=2/0.parquet\n"
+        );
+
+        // Overwriting a partition drops its old files from the manifest.
+        let commit = new_staged_commit(dir, true);
+        stage(&commit, "a=1/1.parquet", b"");
+        get_runtime().block_on(commit.commit()).unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(dir.join(SUCCESS_MARKER_NAME)).unwrap(),
+            "a=1/1.parquet\na=2/0.parquet\n"
+        );
+    }
+
+    #[test]
+    fn test_staged_commit_rolls_back_failed_publish() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+
+        std::fs::create_dir_all(dir.join("a=1")).unwrap();
+        std::fs::write(dir.join("a=1/0.parquet"), b"old").unwrap();
+
+        let commit = new_staged_commit(dir, false);
+        stage(&commit, "a=1/0.parquet", b"new");
+        stage(&commit, "a=2/0.parquet", b"new");
+        // Publishing the last file fails, as it was never written.
+        commit.stage_file("a=3/0.parquet").unwrap();
+
+        assert!(get_runtime().block_on(commit.commit()).is_err());
+
+        assert_eq!(std::fs::read(dir.join("a=1/0.parquet")).unwrap(), b"old");
+        assert!(!dir.join("a=2/0.parquet").exists());
+        assert!(!dir.join(SUCCESS_MARKER_NAME).exists());
+        let staging_path = Path::new(commit.staging_path().as_str());
+        assert_eq!(
+            std::fs::read(staging_path.join("a=2/0.parquet")).unwrap(),
+            b"new"
+        );
+    }
+
+    #[test]
+    fn test_touched_partitions() {
+        let files = [
+            "a=1/0.parquet",
+            "a=1/1.parquet",
+            "a=2/b=3/0.parquet",
+            "0.parquet",
+        ]
+        .map(String::from);
+
+        assert_eq!(
+            StagedCommit::touched_partitions(&files),
+            ["", "a=1", "a=2/b=3"]
+        );
+    }
+
+    #[test]
+    fn test_staged_commit_local() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+
+        std::fs::create_dir_all(dir.join("a=1")).unwrap();
+        std::fs::create_dir_all(dir.join("a=2")).unwrap();
+        std::fs::write(dir.join("a=1/old.parquet"), b"").unwrap();
+        std::fs::write(dir.join("a=1/new.parquet"), b"old").unwrap();
+        std::fs::write(dir.join("a=2/old.parquet"), b"").unwrap();
+
+        let commit = new_staged_commit(dir, true);
+
+        for file in ["a=1/new.parquet", "a=3/new.parquet"] {
+            let staged = commit.stage_file(file).unwrap();
+            let staged = Path::new(staged.as_str());
+
+            assert!(staged.starts_with(dir.join(STAGING_DIR_NAME)));
+
+            std::fs::create_dir_all(staged.parent().unwrap()).unwrap();
+            std::fs::write(staged, b"new").unwrap();
+        }
+
+        // Nothing is visible before the commit.
+        assert!(!dir.join("a=3/new.parquet").exists());
+        assert_eq!(std::fs::read(dir.join("a=1/new.parquet")).unwrap(), b"old");
+
+        get_runtime().block_on(commit.commit()).unwrap();
+
+        // Touched partitions are replaced, untouched partitions are kept.
+        assert!(!dir.join("a=1/old.parquet").exists());
+        assert_eq!(std::fs::read(dir.join("a=1/new.parquet")).unwrap(), b"new");
+        assert!(dir.join("a=2/old.parquet").exists());
+        assert!(dir.join("a=3/new.parquet").exists());
+        assert!(!dir.join(STAGING_DIR_NAME).exists());
+
+        assert_eq!(
+            std::fs::read_to_string(dir.join(SUCCESS_MARKER_NAME)).unwrap(),
+            "a=1/new.parquet\na=3/new.parquet\n"
+        );
+    }
+
+    #[test]
+    fn test_staged_commit_abort_and_cleanup_orphaned() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+
+        let commit = new_staged_commit(dir, false);
+        let staged = commit.stage_file("0.parquet").unwrap();
+        std::fs::create_dir_all(commit.staging_path().as_str()).unwrap();
+        std::fs::write(staged.as_str(), b"").unwrap();
+
+        // A staging directory left behind by another (killed) write.
+        let orphan = new_staged_commit(dir, false);
+        std::fs::create_dir_all(orphan.staging_path().as_str()).unwrap();
+
+        get_runtime()
+            .block_on(commit.cleanup_orphaned(Duration::from_secs(3600)))
+            .unwrap();
+        assert!(Path::new(orphan.staging_path().as_str()).exists());
+
+        get_runtime()
+            .block_on(commit.cleanup_orphaned(Duration::ZERO))
+            .unwrap();
+        assert!(!Path::new(orphan.staging_path().as_str()).exists());
+        // The staging location of the current write is never considered orphaned.
+        assert!(Path::new(commit.staging_path().as_str()).exists());
+
+        get_runtime().block_on(commit.abort());
+        assert!(!Path::new(commit.staging_path().as_str()).exists());
+    }
+}
@@ -0,0 +1,87 @@
+//! The byte-range cache is configured through environment variables that are read once per
+//! process, so this test lives in its own test binary.
+#![cfg(feature = "file_cache")]
+
+use polars_io::cloud::range_cache::{BYTE_RANGE_CACHE, ByteRangeCacheMode};
+use polars_io::pl_async::get_runtime;
+use polars_io::utils::byte_source::{ByteSource, DynByteSourceBuilder};
+use polars_utils::pl_path::PlRefPath;
+
+#[test]
+fn test_byte_range_cache_disk() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let data_dir = temp_dir.path().join("data");
+    let polars_temp_dir = temp_dir.path().join("polars");
+    std::fs::create_dir_all(&data_dir).unwrap();
+
+    // SAFETY: this is the only test in this binary, so nothing reads the environment concurrently.
+    unsafe {
+        std::env::set_var("POLARS_BYTE_RANGE_CACHE", "disk");
+        // Disable the in-memory tier, so that every hit is served from disk.
+        std::env::set_var("POLARS_BYTE_RANGE_CACHE_MEMORY_SIZE", "0");
+        std::env::set_var("POLARS_TEMP_DIR", &polars_temp_dir);
+    }
+
+    assert_eq!(BYTE_RANGE_CACHE.mode(), ByteRangeCacheMode::Disk);
+
+    let bytes: Vec<u8> = (0..=255).collect();
+    let path = data_dir.join("file.bin");
+    std::fs::write(&path, &bytes).unwrap();
+
+    // The object store byte source also handles local paths, through a local object store.
+    let uri = format!("file://{}", path.to_str().unwrap());
+    let uri = PlRefPath::new(uri.as_str());
+
+    get_runtime().block_on(async {
+        let source = DynByteSourceBuilder::ObjectStore
+            .try_build_from_path(uri.clone(), None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(&*source.get_range(16..32).await.unwrap(), &bytes[16..32]);
+        let stats = BYTE_RANGE_CACHE.stats();
+        assert_eq!((stats.hits, stats.misses, stats.memory_size), (0, 1, 0));
+
+        // Repeated reads, also through a new source, are served from the cache.
+        for _ in 0..2 {
+            let source = DynByteSourceBuilder::ObjectStore
+                .try_build_from_path(uri.clone(), None, None)
+                .await
+                .unwrap();
+            assert_eq!(&*source.get_range(16..32).await.unwrap(), &bytes[16..32]);
+        }
+        let stats = BYTE_RANGE_CACHE.stats();
+        assert_eq!((stats.hits, stats.misses, stats.memory_size), (2, 1, 0));
+
+        let mut ranges = [64..80, 16..32];
+        let out = source.get_ranges(&mut ranges).await.unwrap();
+        assert_eq!(&*out[&16], &bytes[16..32]);
+        assert_eq!(&*out[&64], &bytes[64..80]);
+        let stats = BYTE_RANGE_CACHE.stats();
+        assert_eq!((stats.hits, stats.misses), (3, 2));
+
+        let out = source.get_ranges(&mut ranges).await.unwrap();
+        assert_eq!(&*out[&64], &bytes[64..80]);
+        let stats = BYTE_RANGE_CACHE.stats();
+        assert_eq!((stats.hits, stats.misses), (5, 2));
+    });
+
+    // The ranges were persisted to the file cache directory.
+    let data_entries = std::fs::read_dir(polars_temp_dir.join("file-cache/d"))
+        .unwrap()
+        .count();
+    assert_eq!(data_entries, 2);
+
+    // A changed object never serves stale bytes.
+    let bytes: Vec<u8> = (0..512).map(|x| (x % 7) as u8).collect();
+    std::fs::write(&path, &bytes).unwrap();
+
+    get_runtime().block_on(async {
+        let source = DynByteSourceBuilder::ObjectStore
+            .try_build_from_path(uri, None, None)
+            .await
+            .unwrap();
+        assert_eq!(&*source.get_range(16..32).await.unwrap(), &bytes[16..32]);
+    });
+    assert_eq!(BYTE_RANGE_CACHE.stats().misses, 3);
+}
@@ -1,7 +1,7 @@
 //! APIs to write to JSON
 mod serialize;
 pub use serialize::{JsonSerializer, new_serializer};
-mod utf8;
+pub(crate) mod utf8;
 
 use std::io::Write;
 
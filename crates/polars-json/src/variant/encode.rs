@@ -0,0 +1,198 @@
+use polars_error::{PolarsResult, polars_err};
+use polars_utils::aliases::PlHashMap;
+use simd_json::{BorrowedValue, StaticNode};
+
+use super::*;
+
+/// Largest unscaled value of a 16-byte variant decimal, which has a precision of at most 38.
+const MAX_DECIMAL16: i128 = 10i128.pow(38) - 1;
+
+/// Number of bytes needed to store `n` as an unsigned integer.
+fn int_size(n: usize) -> usize {
+    match n {
+        0..=0xFF => 1,
+        0x100..=0xFFFF => 2,
+        0x10000..=0xFF_FFFF => 3,
+        _ => 4,
+    }
+}
+
+fn write_uint(buf: &mut Vec<u8>, value: usize, size: usize) {
+    buf.extend_from_slice(&(value as u32).to_le_bytes()[..size]);
+}
+
+fn primitive_header(type_id: u8) -> u8 {
+    (type_id << 2) | BASIC_TYPE_PRIMITIVE
+}
+
+/// Encode a JSON document in the variant binary encoding.
+///
+/// Returns the metadata and value buffers. The JSON buffer is used as scratch space by the parser.
+pub fn json_to_variant(json: &mut [u8]) -> PolarsResult<(Vec<u8>, Vec<u8>)> {
+    let value = simd_json::to_borrowed_value(json)
+        .map_err(|e| polars_err!(ComputeError: "error parsing JSON: '{e}'"))?;
+
+    let mut keys = vec![];
+    collect_keys(&value, &mut keys);
+    keys.sort_unstable();
+    keys.dedup();
+    let key_ids: PlHashMap<&str, usize> = keys.iter().enumerate().map(|(i, k)| (*k, i)).collect();
+
+    let mut out = vec![];
+    encode_value(&value, &key_ids, &mut out);
+    Ok((encode_metadata(&keys), out))
+}
+
+fn collect_keys<'a>(value: &'a BorrowedValue<'_>, keys: &mut Vec<&'a str>) {
+    match value {
+        BorrowedValue::Object(fields) => {
+            for (k, v) in fields.iter() {
+                keys.push(k.as_ref());
+                collect_keys(v, keys);
+            }
+        },
+        BorrowedValue::Array(values) => values.iter().for_each(|v| collect_keys(v, keys)),
+        _ => {},
+    }
+}
+
+/// Encode the metadata of a value using the given sorted and unique keys.
+fn encode_metadata(keys: &[&str]) -> Vec<u8> {
+    let total_len: usize = keys.iter().map(|k| k.len()).sum();
+    let offset_size = int_size(total_len.max(keys.len()));
+
+    let mut out = Vec::with_capacity(1 + offset_size * (keys.len() + 2) + total_len);
+    out.push(VERSION | (1 << 4) | (((offset_size - 1) as u8) << 6));
+    write_uint(&mut out, keys.len(), offset_size);
+    let mut offset = 0;
+    write_uint(&mut out, offset, offset_size);
+    for k in keys {
+        offset += k.len();
+        write_uint(&mut out, offset, offset_size);
+    }
+    for k in keys {
+        out.extend_from_slice(k.as_bytes());
+    }
+    out
+}
+
+fn encode_int(value: i64, out: &mut Vec<u8>) {
+    if let Ok(v) = i8::try_from(value) {
+        out.push(primitive_header(PRIMITIVE_INT8));
+        out.extend_from_slice(&v.to_le_bytes());
+    } else if let Ok(v) = i16::try_from(value) {
+        out.push(primitive_header(PRIMITIVE_INT16));
+        out.extend_from_slice(&v.to_le_bytes());
+    } else if let Ok(v) = i32::try_from(value) {
+        out.push(primitive_header(PRIMITIVE_INT32));
+        out.extend_from_slice(&v.to_le_bytes());
+    } else {
+        out.push(primitive_header(PRIMITIVE_INT64));
+        out.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+/// Encode an integer that does not fit in an `i64` as a decimal, or as a double if it does not
+/// fit in a decimal either.
+fn encode_large_int(value: Option<i128>, as_f64: f64, out: &mut Vec<u8>) {
+    match value {
+        Some(v) if (-MAX_DECIMAL16..=MAX_DECIMAL16).contains(&v) => {
+            out.push(primitive_header(PRIMITIVE_DECIMAL16));
+            out.push(0);
+            out.extend_from_slice(&v.to_le_bytes());
+        },
+        _ => encode_double(as_f64, out),
+    }
+}
+
+fn encode_double(value: f64, out: &mut Vec<u8>) {
+    out.push(primitive_header(PRIMITIVE_DOUBLE));
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+fn encode_str(value: &str, out: &mut Vec<u8>) {
+    if value.len() <= MAX_SHORT_STRING_LEN {
+        out.push(((value.len() as u8) << 2) | BASIC_TYPE_SHORT_STRING);
+    } else {
+        out.push(primitive_header(PRIMITIVE_STRING));
+        write_uint(out, value.len(), 4);
+    }
+    out.extend_from_slice(value.as_bytes());
+}
+
+fn encode_value(value: &BorrowedValue<'_>, key_ids: &PlHashMap<&str, usize>, out: &mut Vec<u8>) {
+    match value {
+        BorrowedValue::Static(node) => match *node {
+            StaticNode::Null => out.push(primitive_header(PRIMITIVE_NULL)),
+            StaticNode::Bool(true) => out.push(primitive_header(PRIMITIVE_TRUE)),
+            StaticNode::Bool(false) => out.push(primitive_header(PRIMITIVE_FALSE)),
+            StaticNode::I64(v) => encode_int(v, out),
+            StaticNode::U64(v) => match i64::try_from(v) {
+                Ok(v) => encode_int(v, out),
+                Err(_) => encode_large_int(Some(v as i128), v as f64, out),
+            },
+            StaticNode::I128(v) => match i64::try_from(v) {
+                Ok(v) => encode_int(v, out),
+                Err(_) => encode_large_int(Some(v), v as f64, out),
+            },
+            StaticNode::U128(v) => match i64::try_from(v) {
+                Ok(v) => encode_int(v, out),
+                Err(_) => encode_large_int(i128::try_from(v).ok(), v as f64, out),
+            },
+            StaticNode::F64(v) => encode_double(v, out),
+        },
+        BorrowedValue::String(s) => encode_str(s, out),
+        BorrowedValue::Array(values) => {
+            let mut data = vec![];
+            let mut offsets = Vec::with_capacity(values.len() + 1);
+            offsets.push(0);
+            for v in values.iter() {
+                encode_value(v, key_ids, &mut data);
+                offsets.push(data.len());
+            }
+
+            let offset_size = int_size(data.len());
+            let is_large = values.len() > u8::MAX as usize;
+            let value_header = (offset_size - 1) as u8 | ((is_large as u8) << 2);
+            out.push((value_header << 2) | BASIC_TYPE_ARRAY);
+            write_uint(out, values.len(), if is_large { 4 } else { 1 });
+            for offset in offsets {
+                write_uint(out, offset, offset_size);
+            }
+            out.extend_from_slice(&data);
+        },
+        BorrowedValue::Object(fields) => {
+            // The fields of an object must be sorted by key, which is the order of the ids as
+            // the keys in the metadata are sorted.
+            let mut fields: Vec<_> = fields
+                .iter()
+                .map(|(k, v)| (key_ids[k.as_ref()], v))
+                .collect();
+            fields.sort_unstable_by_key(|(id, _)| *id);
+
+            let mut data = vec![];
+            let mut offsets = Vec::with_capacity(fields.len() + 1);
+            offsets.push(0);
+            for (_, v) in fields.iter() {
+                encode_value(v, key_ids, &mut data);
+                offsets.push(data.len());
+            }
+
+            let offset_size = int_size(data.len());
+            let field_id_size = int_size(key_ids.len());
+            let is_large = fields.len() > u8::MAX as usize;
+            let value_header = (offset_size - 1) as u8
+                | (((field_id_size - 1) as u8) << 2)
+                | ((is_large as u8) << 4);
+            out.push((value_header << 2) | BASIC_TYPE_OBJECT);
+            write_uint(out, fields.len(), if is_large { 4 } else { 1 });
+            for (id, _) in fields.iter() {
+                write_uint(out, *id, field_id_size);
+            }
+            for offset in offsets {
+                write_uint(out, offset, offset_size);
+            }
+            out.extend_from_slice(&data);
+        },
+    }
+}
@@ -0,0 +1,146 @@
+use std::io::Write;
+
+use arrow::temporal_conversions::{
+    date32_to_date_opt, time64us_to_time_opt, timestamp_ns_to_datetime_opt,
+    timestamp_us_to_datetime_opt,
+};
+use polars_error::{PolarsResult, polars_err};
+
+use super::*;
+use crate::json::write::utf8::write_str;
+
+fn write_integer<I: itoa::Integer>(buf: &mut Vec<u8>, val: I) {
+    let mut buffer = itoa::Buffer::new();
+    buf.extend_from_slice(buffer.format(val).as_bytes())
+}
+
+fn write_float<I: zmij::Float>(buf: &mut Vec<u8>, val: I, is_finite: bool) {
+    // JSON has no representation for NaN and infinity.
+    if is_finite {
+        let mut buffer = zmij::Buffer::new();
+        buf.extend_from_slice(buffer.format(val).as_bytes())
+    } else {
+        buf.extend_from_slice(b"null")
+    }
+}
+
+fn write_decimal(buf: &mut Vec<u8>, value: i128, scale: u8) {
+    let scale = scale as usize;
+    let digits = value.unsigned_abs().to_string();
+    if value < 0 {
+        buf.push(b'-');
+    }
+    if scale == 0 {
+        buf.extend_from_slice(digits.as_bytes());
+        return;
+    }
+    let digits = format!("{digits:0>width$}", width = scale + 1);
+    let (int, frac) = digits.split_at(digits.len() - scale);
+    write!(buf, "{int}.{frac}").unwrap();
+}
+
+fn write_base64(buf: &mut Vec<u8>, bytes: &[u8]) {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    buf.push(b'"');
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = ((b[0] as u32) << 16) | ((b[1] as u32) << 8) | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                buf.push(ALPHABET[((n >> (18 - 6 * i)) & 0x3F) as usize]);
+            } else {
+                buf.push(b'=');
+            }
+        }
+    }
+    buf.push(b'"');
+}
+
+fn out_of_range() -> polars_error::PolarsError {
+    polars_err!(ComputeError: "variant temporal value out of range")
+}
+
+/// Write a variant value as JSON.
+///
+/// Values without a JSON counterpart are written as strings: temporal values in ISO 8601 format,
+/// UUIDs in their hyphenated form and binary values base64-encoded.
+pub fn write_variant_json(variant: &Variant<'_>, buf: &mut Vec<u8>) -> PolarsResult<()> {
+    use VariantValue as V;
+    match variant.get()? {
+        V::Null => buf.extend_from_slice(b"null"),
+        V::Boolean(v) => buf.extend_from_slice(if v { b"true" } else { b"false" }),
+        V::Int8(v) => write_integer(buf, v),
+        V::Int16(v) => write_integer(buf, v),
+        V::Int32(v) => write_integer(buf, v),
+        V::Int64(v) => write_integer(buf, v),
+        V::Float(v) => write_float(buf, v, v.is_finite()),
+        V::Double(v) => write_float(buf, v, v.is_finite()),
+        V::Decimal { value, scale } => write_decimal(buf, value, scale),
+        V::Date(v) => {
+            let date = date32_to_date_opt(v).ok_or_else(out_of_range)?;
+            write!(buf, "\"{date}\"").unwrap();
+        },
+        V::TimestampMicros(v) => {
+            let ts = timestamp_us_to_datetime_opt(v).ok_or_else(out_of_range)?;
+            write!(buf, "\"{}\"", ts.and_utc().to_rfc3339()).unwrap();
+        },
+        V::TimestampNanos(v) => {
+            let ts = timestamp_ns_to_datetime_opt(v).ok_or_else(out_of_range)?;
+            write!(buf, "\"{}\"", ts.and_utc().to_rfc3339()).unwrap();
+        },
+        V::TimestampNtzMicros(v) => {
+            let ts = timestamp_us_to_datetime_opt(v).ok_or_else(out_of_range)?;
+            write!(buf, "\"{ts}\"").unwrap();
+        },
+        V::TimestampNtzNanos(v) => {
+            let ts = timestamp_ns_to_datetime_opt(v).ok_or_else(out_of_range)?;
+            write!(buf, "\"{ts}\"").unwrap();
+        },
+        V::TimeNtzMicros(v) => {
+            let time = time64us_to_time_opt(v).ok_or_else(out_of_range)?;
+            write!(buf, "\"{time}\"").unwrap();
+        },
+        V::Binary(v) => write_base64(buf, v),
+        V::String(v) => write_str(buf, v).unwrap(),
+        V::Uuid(v) => {
+            let hex = |b: &[u8]| b.iter().map(|x| format!("{x:02x}")).collect::<String>();
+            write!(
+                buf,
+                "\"{}-{}-{}-{}-{}\"",
+                hex(&v[..4]),
+                hex(&v[4..6]),
+                hex(&v[6..8]),
+                hex(&v[8..10]),
+                hex(&v[10..])
+            )
+            .unwrap();
+        },
+        V::Object(obj) => {
+            buf.push(b'{');
+            for i in 0..obj.len() {
+                if i > 0 {
+                    buf.push(b',');
+                }
+                write_str(buf, obj.key(i)?).unwrap();
+                buf.push(b':');
+                write_variant_json(&obj.value(i)?, buf)?;
+            }
+            buf.push(b'}');
+        },
+        V::Array(arr) => {
+            buf.push(b'[');
+            for i in 0..arr.len() {
+                if i > 0 {
+                    buf.push(b',');
+                }
+                write_variant_json(&arr.get(i)?, buf)?;
+            }
+            buf.push(b']');
+        },
+    }
+    Ok(())
+}
@@ -0,0 +1,470 @@
+//! Reading and writing values in the [Parquet Variant binary encoding].
+//!
+//! A variant value is stored as two byte buffers: a `metadata` buffer holding a dictionary of the
+//! object keys used by the value, and a `value` buffer holding the self-describing encoded value
+//! itself, which refers to its object keys by their index in the dictionary.
+//!
+//! [Parquet Variant binary encoding]: https://github.com/apache/parquet-format/blob/master/VariantEncoding.md
+mod encode;
+mod json;
+mod path;
+
+pub use encode::json_to_variant;
+pub use json::write_variant_json;
+pub use path::{VariantPath, VariantPathElement};
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+
+const VERSION: u8 = 1;
+
+const BASIC_TYPE_PRIMITIVE: u8 = 0;
+const BASIC_TYPE_SHORT_STRING: u8 = 1;
+const BASIC_TYPE_OBJECT: u8 = 2;
+const BASIC_TYPE_ARRAY: u8 = 3;
+
+const PRIMITIVE_NULL: u8 = 0;
+const PRIMITIVE_TRUE: u8 = 1;
+const PRIMITIVE_FALSE: u8 = 2;
+const PRIMITIVE_INT8: u8 = 3;
+const PRIMITIVE_INT16: u8 = 4;
+const PRIMITIVE_INT32: u8 = 5;
+const PRIMITIVE_INT64: u8 = 6;
+const PRIMITIVE_DOUBLE: u8 = 7;
+const PRIMITIVE_DECIMAL4: u8 = 8;
+const PRIMITIVE_DECIMAL8: u8 = 9;
+const PRIMITIVE_DECIMAL16: u8 = 10;
+const PRIMITIVE_DATE: u8 = 11;
+const PRIMITIVE_TIMESTAMP_MICROS: u8 = 12;
+const PRIMITIVE_TIMESTAMP_NTZ_MICROS: u8 = 13;
+const PRIMITIVE_FLOAT: u8 = 14;
+const PRIMITIVE_BINARY: u8 = 15;
+const PRIMITIVE_STRING: u8 = 16;
+const PRIMITIVE_TIME_NTZ_MICROS: u8 = 17;
+const PRIMITIVE_TIMESTAMP_NANOS: u8 = 18;
+const PRIMITIVE_TIMESTAMP_NTZ_NANOS: u8 = 19;
+const PRIMITIVE_UUID: u8 = 20;
+
+/// Maximum length of a string stored with the short string encoding.
+const MAX_SHORT_STRING_LEN: usize = 63;
+
+/// Read an unsigned little-endian integer of `size` (1 to 4) bytes at `offset`.
+fn read_uint(bytes: &[u8], offset: usize, size: usize) -> PolarsResult<usize> {
+    let Some(b) = bytes.get(offset..offset + size) else {
+        polars_bail!(ComputeError: "malformed variant: unexpected end of buffer")
+    };
+    Ok(b.iter()
+        .rev()
+        .fold(0usize, |acc, &byte| (acc << 8) | byte as usize))
+}
+
+fn read_bytes(bytes: &[u8], offset: usize, len: usize) -> PolarsResult<&[u8]> {
+    match bytes.get(offset..offset + len) {
+        Some(b) => Ok(b),
+        None => polars_bail!(ComputeError: "malformed variant: unexpected end of buffer"),
+    }
+}
+
+fn read_array<const N: usize>(bytes: &[u8], offset: usize) -> PolarsResult<[u8; N]> {
+    Ok(read_bytes(bytes, offset, N)?.try_into().unwrap())
+}
+
+fn read_str(bytes: &[u8], offset: usize, len: usize) -> PolarsResult<&str> {
+    std::str::from_utf8(read_bytes(bytes, offset, len)?)
+        .map_err(|_| polars_err!(ComputeError: "malformed variant: invalid utf-8"))
+}
+
+/// The dictionary of object keys of a variant value.
+#[derive(Clone, Copy, Debug)]
+pub struct VariantMetadata<'a> {
+    bytes: &'a [u8],
+    offset_size: usize,
+    dictionary_size: usize,
+}
+
+impl<'a> VariantMetadata<'a> {
+    pub fn try_new(bytes: &'a [u8]) -> PolarsResult<Self> {
+        let Some(&header) = bytes.first() else {
+            polars_bail!(ComputeError: "malformed variant: empty metadata")
+        };
+        polars_ensure!(
+            header & 0x0F == VERSION,
+            ComputeError: "unsupported variant metadata version {}", header & 0x0F
+        );
+        let offset_size = ((header >> 6) & 0b11) as usize + 1;
+        let dictionary_size = read_uint(bytes, 1, offset_size)?;
+        let out = Self {
+            bytes,
+            offset_size,
+            dictionary_size,
+        };
+        // Check that all offsets are in bounds.
+        read_bytes(bytes, out.strings_start(), 0)?;
+        Ok(out)
+    }
+
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    pub fn len(&self) -> usize {
+        self.dictionary_size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dictionary_size == 0
+    }
+
+    fn strings_start(&self) -> usize {
+        1 + self.offset_size * (self.dictionary_size + 2)
+    }
+
+    /// Get the key with id `i`.
+    pub fn get(&self, i: usize) -> PolarsResult<&'a str> {
+        polars_ensure!(
+            i < self.dictionary_size,
+            ComputeError: "malformed variant: field id {i} out of bounds"
+        );
+        let start = read_uint(self.bytes, 1 + self.offset_size * (i + 1), self.offset_size)?;
+        let end = read_uint(self.bytes, 1 + self.offset_size * (i + 2), self.offset_size)?;
+        polars_ensure!(start <= end, ComputeError: "malformed variant: invalid key offsets");
+        read_str(self.bytes, self.strings_start() + start, end - start)
+    }
+}
+
+/// A single variant value, backed by its metadata and value buffers.
+#[derive(Clone, Copy, Debug)]
+pub struct Variant<'a> {
+    metadata: VariantMetadata<'a>,
+    value: &'a [u8],
+}
+
+/// The decoded top-level form of a [`Variant`].
+#[derive(Clone, Copy, Debug)]
+pub enum VariantValue<'a> {
+    Null,
+    Boolean(bool),
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    Float(f32),
+    Double(f64),
+    /// A decimal with the given scale, stored in 4, 8 or 16 bytes.
+    Decimal {
+        value: i128,
+        scale: u8,
+    },
+    /// Days since the UNIX epoch.
+    Date(i32),
+    /// Microseconds since the UNIX epoch, adjusted to UTC.
+    TimestampMicros(i64),
+    /// Microseconds since the UNIX epoch, without timezone.
+    TimestampNtzMicros(i64),
+    /// Nanoseconds since the UNIX epoch, adjusted to UTC.
+    TimestampNanos(i64),
+    /// Nanoseconds since the UNIX epoch, without timezone.
+    TimestampNtzNanos(i64),
+    /// Microseconds since midnight.
+    TimeNtzMicros(i64),
+    Binary(&'a [u8]),
+    String(&'a str),
+    Uuid([u8; 16]),
+    Object(VariantObject<'a>),
+    Array(VariantArray<'a>),
+}
+
+impl<'a> Variant<'a> {
+    pub fn try_new(metadata: &'a [u8], value: &'a [u8]) -> PolarsResult<Self> {
+        Self::try_new_with_metadata(VariantMetadata::try_new(metadata)?, value)
+    }
+
+    pub fn try_new_with_metadata(
+        metadata: VariantMetadata<'a>,
+        value: &'a [u8],
+    ) -> PolarsResult<Self> {
+        polars_ensure!(!value.is_empty(), ComputeError: "malformed variant: empty value");
+        Ok(Self { metadata, value })
+    }
+
+    pub fn metadata(&self) -> VariantMetadata<'a> {
+        self.metadata
+    }
+
+    fn header(&self) -> u8 {
+        self.value[0]
+    }
+
+    fn basic_type(&self) -> u8 {
+        self.header() & 0b11
+    }
+
+    fn value_header(&self) -> u8 {
+        self.header() >> 2
+    }
+
+    /// The encoded value bytes of exactly this value, without any trailing bytes.
+    ///
+    /// Since a nested value uses the metadata of its parent, the returned bytes together with
+    /// the metadata form a valid variant.
+    pub fn value_bytes(&self) -> PolarsResult<&'a [u8]> {
+        let size = match self.basic_type() {
+            BASIC_TYPE_PRIMITIVE => {
+                1 + match self.value_header() {
+                    PRIMITIVE_NULL | PRIMITIVE_TRUE | PRIMITIVE_FALSE => 0,
+                    PRIMITIVE_INT8 => 1,
+                    PRIMITIVE_INT16 => 2,
+                    PRIMITIVE_INT32 | PRIMITIVE_DATE | PRIMITIVE_FLOAT => 4,
+                    PRIMITIVE_INT64
+                    | PRIMITIVE_DOUBLE
+                    | PRIMITIVE_TIMESTAMP_MICROS
+                    | PRIMITIVE_TIMESTAMP_NTZ_MICROS
+                    | PRIMITIVE_TIME_NTZ_MICROS
+                    | PRIMITIVE_TIMESTAMP_NANOS
+                    | PRIMITIVE_TIMESTAMP_NTZ_NANOS => 8,
+                    PRIMITIVE_DECIMAL4 => 5,
+                    PRIMITIVE_DECIMAL8 => 9,
+                    PRIMITIVE_DECIMAL16 => 17,
+                    PRIMITIVE_UUID => 16,
+                    PRIMITIVE_BINARY | PRIMITIVE_STRING => 4 + read_uint(self.value, 1, 4)?,
+                    tp => polars_bail!(ComputeError: "unsupported variant primitive type {tp}"),
+                }
+            },
+            BASIC_TYPE_SHORT_STRING => 1 + self.value_header() as usize,
+            BASIC_TYPE_OBJECT => {
+                let obj = self.as_object()?;
+                obj.data_start + obj.offset(obj.len)?
+            },
+            _ => {
+                let arr = self.as_array()?;
+                arr.data_start + arr.offset(arr.len)?
+            },
+        };
+        read_bytes(self.value, 0, size)
+    }
+
+    /// Decode the top-level form of this value.
+    pub fn get(&self) -> PolarsResult<VariantValue<'a>> {
+        use VariantValue as V;
+        let v = self.value;
+        Ok(match self.basic_type() {
+            BASIC_TYPE_PRIMITIVE => match self.value_header() {
+                PRIMITIVE_NULL => V::Null,
+                PRIMITIVE_TRUE => V::Boolean(true),
+                PRIMITIVE_FALSE => V::Boolean(false),
+                PRIMITIVE_INT8 => V::Int8(i8::from_le_bytes(read_array(v, 1)?)),
+                PRIMITIVE_INT16 => V::Int16(i16::from_le_bytes(read_array(v, 1)?)),
+                PRIMITIVE_INT32 => V::Int32(i32::from_le_bytes(read_array(v, 1)?)),
+                PRIMITIVE_INT64 => V::Int64(i64::from_le_bytes(read_array(v, 1)?)),
+                PRIMITIVE_FLOAT => V::Float(f32::from_le_bytes(read_array(v, 1)?)),
+                PRIMITIVE_DOUBLE => V::Double(f64::from_le_bytes(read_array(v, 1)?)),
+                PRIMITIVE_DECIMAL4 => V::Decimal {
+                    value: i32::from_le_bytes(read_array(v, 2)?) as i128,
+                    scale: read_array::<1>(v, 1)?[0],
+                },
+                PRIMITIVE_DECIMAL8 => V::Decimal {
+                    value: i64::from_le_bytes(read_array(v, 2)?) as i128,
+                    scale: read_array::<1>(v, 1)?[0],
+                },
+                PRIMITIVE_DECIMAL16 => V::Decimal {
+                    value: i128::from_le_bytes(read_array(v, 2)?),
+                    scale: read_array::<1>(v, 1)?[0],
+                },
+                PRIMITIVE_DATE => V::Date(i32::from_le_bytes(read_array(v, 1)?)),
+                PRIMITIVE_TIMESTAMP_MICROS => {
+                    V::TimestampMicros(i64::from_le_bytes(read_array(v, 1)?))
+                },
+                PRIMITIVE_TIMESTAMP_NTZ_MICROS => {
+                    V::TimestampNtzMicros(i64::from_le_bytes(read_array(v, 1)?))
+                },
+                PRIMITIVE_TIMESTAMP_NANOS => {
+                    V::TimestampNanos(i64::from_le_bytes(read_array(v, 1)?))
+                },
+                PRIMITIVE_TIMESTAMP_NTZ_NANOS => {
+                    V::TimestampNtzNanos(i64::from_le_bytes(read_array(v, 1)?))
+                },
+                PRIMITIVE_TIME_NTZ_MICROS => {
+                    V::TimeNtzMicros(i64::from_le_bytes(read_array(v, 1)?))
+                },
+                PRIMITIVE_BINARY => V::Binary(read_bytes(v, 5, read_uint(v, 1, 4)?)?),
+                PRIMITIVE_STRING => V::String(read_str(v, 5, read_uint(v, 1, 4)?)?),
+                PRIMITIVE_UUID => V::Uuid(read_array(v, 1)?),
+                tp => polars_bail!(ComputeError: "unsupported variant primitive type {tp}"),
+            },
+            BASIC_TYPE_SHORT_STRING => V::String(read_str(v, 1, self.value_header() as usize)?),
+            BASIC_TYPE_OBJECT => V::Object(self.as_object()?),
+            _ => V::Array(self.as_array()?),
+        })
+    }
+
+    fn as_object(&self) -> PolarsResult<VariantObject<'a>> {
+        let vh = self.value_header();
+        let offset_size = (vh & 0b11) as usize + 1;
+        let field_id_size = ((vh >> 2) & 0b11) as usize + 1;
+        let num_elements_size = if (vh >> 4) & 1 == 1 { 4 } else { 1 };
+        let len = read_uint(self.value, 1, num_elements_size)?;
+        let field_ids_start = 1 + num_elements_size;
+        let offsets_start = field_ids_start + len * field_id_size;
+        let data_start = offsets_start + (len + 1) * offset_size;
+        read_bytes(self.value, 0, data_start)?;
+        Ok(VariantObject {
+            metadata: self.metadata,
+            value: self.value,
+            len,
+            field_id_size,
+            offset_size,
+            field_ids_start,
+            offsets_start,
+            data_start,
+        })
+    }
+
+    fn as_array(&self) -> PolarsResult<VariantArray<'a>> {
+        let vh = self.value_header();
+        let offset_size = (vh & 0b11) as usize + 1;
+        let num_elements_size = if (vh >> 2) & 1 == 1 { 4 } else { 1 };
+        let len = read_uint(self.value, 1, num_elements_size)?;
+        let offsets_start = 1 + num_elements_size;
+        let data_start = offsets_start + (len + 1) * offset_size;
+        read_bytes(self.value, 0, data_start)?;
+        Ok(VariantArray {
+            metadata: self.metadata,
+            value: self.value,
+            len,
+            offset_size,
+            offsets_start,
+            data_start,
+        })
+    }
+
+    /// Follow `path` into nested objects and arrays.
+    ///
+    /// Returns `None` if a field or index on the path does not exist, or if the path traverses
+    /// into a value that is not an object or an array.
+    pub fn get_path(&self, path: &VariantPath) -> PolarsResult<Option<Variant<'a>>> {
+        let mut current = *self;
+        for element in path.elements() {
+            let next = match (element, current.get()?) {
+                (VariantPathElement::Field(name), VariantValue::Object(obj)) => obj.get(name)?,
+                (VariantPathElement::Index(idx), VariantValue::Array(arr)) => {
+                    let idx = if *idx < 0 {
+                        arr.len().checked_sub(idx.unsigned_abs() as usize)
+                    } else {
+                        Some(*idx as usize)
+                    };
+                    match idx {
+                        Some(idx) if idx < arr.len() => Some(arr.get(idx)?),
+                        _ => None,
+                    }
+                },
+                _ => None,
+            };
+            match next {
+                Some(v) => current = v,
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(current))
+    }
+}
+
+/// An object in a variant value, with its fields sorted by key.
+#[derive(Clone, Copy, Debug)]
+pub struct VariantObject<'a> {
+    metadata: VariantMetadata<'a>,
+    value: &'a [u8],
+    len: usize,
+    field_id_size: usize,
+    offset_size: usize,
+    field_ids_start: usize,
+    offsets_start: usize,
+    data_start: usize,
+}
+
+impl<'a> VariantObject<'a> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn offset(&self, i: usize) -> PolarsResult<usize> {
+        read_uint(
+            self.value,
+            self.offsets_start + i * self.offset_size,
+            self.offset_size,
+        )
+    }
+
+    /// Get the key of the `i`-th field.
+    pub fn key(&self, i: usize) -> PolarsResult<&'a str> {
+        let id = read_uint(
+            self.value,
+            self.field_ids_start + i * self.field_id_size,
+            self.field_id_size,
+        )?;
+        self.metadata.get(id)
+    }
+
+    /// Get the value of the `i`-th field.
+    pub fn value(&self, i: usize) -> PolarsResult<Variant<'a>> {
+        let start = self.data_start + self.offset(i)?;
+        let Some(value) = self.value.get(start..) else {
+            polars_bail!(ComputeError: "malformed variant: unexpected end of buffer")
+        };
+        Variant::try_new_with_metadata(self.metadata, value)
+    }
+
+    /// Get the value of the field with key `name`.
+    pub fn get(&self, name: &str) -> PolarsResult<Option<Variant<'a>>> {
+        // Fields are sorted by key, so we can binary search.
+        let (mut lo, mut hi) = (0, self.len);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match self.key(mid)?.cmp(name) {
+                std::cmp::Ordering::Less => lo = mid + 1,
+                std::cmp::Ordering::Greater => hi = mid,
+                std::cmp::Ordering::Equal => return self.value(mid).map(Some),
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// An array in a variant value.
+#[derive(Clone, Copy, Debug)]
+pub struct VariantArray<'a> {
+    metadata: VariantMetadata<'a>,
+    value: &'a [u8],
+    len: usize,
+    offset_size: usize,
+    offsets_start: usize,
+    data_start: usize,
+}
+
+impl<'a> VariantArray<'a> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn offset(&self, i: usize) -> PolarsResult<usize> {
+        read_uint(
+            self.value,
+            self.offsets_start + i * self.offset_size,
+            self.offset_size,
+        )
+    }
+
+    /// Get the `i`-th element.
+    pub fn get(&self, i: usize) -> PolarsResult<Variant<'a>> {
+        let start = self.data_start + self.offset(i)?;
+        let Some(value) = self.value.get(start..) else {
+            polars_bail!(ComputeError: "malformed variant: unexpected end of buffer")
+        };
+        Variant::try_new_with_metadata(self.metadata, value)
+    }
+}
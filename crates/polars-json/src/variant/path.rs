@@ -0,0 +1,72 @@
+use polars_error::{PolarsResult, polars_err};
+
+/// A single step of a [`VariantPath`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VariantPathElement {
+    /// Select the field with the given key of an object.
+    Field(String),
+    /// Select the element at the given index of an array; negative indices count from the end.
+    Index(i64),
+}
+
+/// A path into a variant value, parsed from a JSONPath-like expression such as `$.a.b[0]`.
+///
+/// Supported are field access by name (`.name`, `['name']` or `["name"]`) and array element
+/// access by index (`[0]`, `[-1]`). The leading `$` is optional. An empty path selects the
+/// whole value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VariantPath(Vec<VariantPathElement>);
+
+impl VariantPath {
+    pub fn try_new(path: &str) -> PolarsResult<Self> {
+        let err = || polars_err!(ComputeError: "invalid variant path '{path}'");
+
+        let normalized;
+        let mut rest = match path.strip_prefix('$') {
+            Some(rest) => rest,
+            None if path.is_empty() || path.starts_with(['.', '[']) => path,
+            // Allow a bare field name without `$.`, e.g. `a.b`.
+            None => {
+                normalized = format!(".{path}");
+                normalized.as_str()
+            },
+        };
+        let mut elements = vec![];
+        while let Some(c) = rest.chars().next() {
+            match c {
+                '.' => {
+                    let name_end = rest[1..].find(['.', '[']).map_or(rest.len(), |i| i + 1);
+                    let name = &rest[1..name_end];
+                    if name.is_empty() {
+                        return Err(err());
+                    }
+                    elements.push(VariantPathElement::Field(name.to_string()));
+                    rest = &rest[name_end..];
+                },
+                '[' => {
+                    let inner = &rest[1..];
+                    if let Some(quote @ ('\'' | '"')) = inner.chars().next() {
+                        let end = inner[1..].find(quote).ok_or_else(err)? + 1;
+                        let name = &inner[1..end];
+                        if inner[end + 1..].chars().next() != Some(']') {
+                            return Err(err());
+                        }
+                        elements.push(VariantPathElement::Field(name.to_string()));
+                        rest = &inner[end + 2..];
+                    } else {
+                        let end = inner.find(']').ok_or_else(err)?;
+                        let idx = inner[..end].trim().parse::<i64>().map_err(|_| err())?;
+                        elements.push(VariantPathElement::Index(idx));
+                        rest = &inner[end + 1..];
+                    }
+                },
+                _ => return Err(err()),
+            }
+        }
+        Ok(Self(elements))
+    }
+
+    pub fn elements(&self) -> &[VariantPathElement] {
+        &self.0
+    }
+}
@@ -8,7 +8,17 @@ use super::*;
 
 impl LazyFrame {
     pub fn collect_concurrently(self) -> PolarsResult<InProcessQuery> {
+        self.collect_concurrently_with_limits(QueryLimits::default())
+    }
+
+    /// Like [`LazyFrame::collect_concurrently`], but aborts the query with an error if it
+    /// exceeds the given `limits`.
+    pub fn collect_concurrently_with_limits(
+        self,
+        limits: QueryLimits,
+    ) -> PolarsResult<InProcessQuery> {
         let (mut state, mut physical_plan, _) = self.prepare_collect(false, None)?;
+        state.set_limits(limits);
 
         let (tx, rx) = channel();
         let token = state.cancel_token();
@@ -34,6 +34,8 @@ use polars_ops::frame::{JoinBuildSide, JoinCoalesce, MaintainOrderJoin};
 #[cfg(feature = "is_between")]
 use polars_ops::prelude::ClosedInterval;
 pub use polars_plan::frame::{AllowedOptimizations, OptFlags};
+#[cfg(feature = "result_cache")]
+use polars_plan::plans::result_cache::apply_result_cache;
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::frame::cached_arenas::CachedArena;
@@ -147,6 +149,18 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle cost-based reordering of inner joins.
+    pub fn with_join_reorder(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::JOIN_REORDER, toggle);
+        self
+    }
+
+    /// Toggle filtering the probe side of streaming equi-joins on the keys of the build side.
+    pub fn with_join_runtime_filters(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::JOIN_RUNTIME_FILTERS, toggle);
+        self
+    }
+
     /// Toggle predicate pushdown optimization.
     pub fn with_predicate_pushdown(mut self, toggle: bool) -> Self {
         self.opt_state.set(OptFlags::PREDICATE_PUSHDOWN, toggle);
@@ -245,6 +259,25 @@ impl LazyFrame {
         }
     }
 
+    /// Execute the query with the streaming engine and return a String describing the physical
+    /// plan, annotated per node with the rows and morsels in/out, wall and CPU time and bytes read.
+    ///
+    /// The wall time of a node is the time from the first until the last poll of its tasks, summed
+    /// over the execution phases it ran in. Nodes that buffer data, such as sinks, group-bys and
+    /// joins, also report the peak number of bytes of data they held between phases.
+    ///
+    /// The result of the query is discarded.
+    #[cfg(feature = "new_streaming")]
+    pub fn explain_analyze(&self) -> PolarsResult<String> {
+        let mut ir_plan = self.clone().with_new_streaming(true).to_alp_optimized()?;
+        ir_plan.ensure_root_node_is_sink();
+        polars_stream::explain_analyze(
+            ir_plan.lp_top,
+            &mut ir_plan.lp_arena,
+            &mut ir_plan.expr_arena,
+        )
+    }
+
     /// Add a sort operation to the logical plan.
     ///
     /// Sorts the LazyFrame by the column name specified using the provided options.
@@ -616,7 +649,32 @@ impl LazyFrame {
     /// `engine`.
     ///
     /// The query is optimized prior to execution.
-    pub fn collect_with_engine(mut self, engine: Engine) -> PolarsResult<QueryResult> {
+    pub fn collect_with_engine(self, engine: Engine) -> PolarsResult<QueryResult> {
+        self.collect_with_engine_and_limits(engine, QueryLimits::default())
+    }
+
+    /// Execute all the lazy operations using a specified `engine`, aborting the query with an
+    /// error if it exceeds the given `limits`.
+    ///
+    /// The timeout starts counting before optimization. Memory limits are only supported by the
+    /// streaming engine.
+    pub fn collect_with_engine_and_limits(
+        self,
+        engine: Engine,
+        limits: QueryLimits,
+    ) -> PolarsResult<QueryResult> {
+        let mut state = ExecutionState::new();
+        state.set_limits(limits);
+        self.collect_with_engine_and_state(engine, state)
+    }
+
+    /// Execute all the lazy operations using a specified `engine` and [`ExecutionState`]. The
+    /// state carries the limits and progress callback of the query.
+    pub fn collect_with_engine_and_state(
+        mut self,
+        engine: Engine,
+        mut state: ExecutionState,
+    ) -> PolarsResult<QueryResult> {
         let engine = match engine {
             Engine::Streaming => Engine::Streaming,
             _ if std::env::var("POLARS_FORCE_NEW_STREAMING").as_deref() == Ok("1") => {
@@ -630,7 +688,10 @@ impl LazyFrame {
             && std::env::var("POLARS_AUTO_NEW_STREAMING").as_deref() == Ok("1")
         {
             feature_gated!("new_streaming", {
-                if let Some(r) = self.clone()._collect_with_streaming_suppress_todo_panic() {
+                if let Some(r) = self
+                    .clone()
+                    ._collect_with_streaming_suppress_todo_panic(state.clone())
+                {
                     return r;
                 }
             })
@@ -644,15 +705,32 @@ impl LazyFrame {
 
         ir_plan.ensure_root_node_is_sink();
 
+        #[cfg(feature = "result_cache")]
+        if engine != Engine::Gpu {
+            apply_result_cache(ir_plan.lp_top, &mut ir_plan.lp_arena, &ir_plan.expr_arena)?;
+        }
+
         match engine {
             Engine::Streaming => feature_gated!("new_streaming", {
-                polars_stream::run_query(
+                polars_stream::run_query_with_state(
                     ir_plan.lp_top,
                     &mut ir_plan.lp_arena,
                     &mut ir_plan.expr_arena,
+                    state,
                 )
             }),
             Engine::InMemory | Engine::Gpu => {
+                polars_ensure!(
+                    state.memory_limit().is_none(),
+                    InvalidOperation:
+                    "memory limits are only supported by the streaming engine"
+                );
+                polars_ensure!(
+                    state.progress_callback().is_none(),
+                    InvalidOperation:
+                    "progress reporting is only supported by the streaming engine"
+                );
+
                 if let IR::SinkMultiple { inputs } = ir_plan.root() {
                     polars_ensure!(
                         engine != Engine::Gpu,
@@ -666,7 +744,7 @@ impl LazyFrame {
                         &mut ir_plan.expr_arena,
                         BUILD_STREAMING_EXECUTOR,
                     )?
-                    .execute()
+                    .execute_with_state(state)
                     .map(QueryResult::Multiple);
                 }
 
@@ -676,7 +754,6 @@ impl LazyFrame {
                     &mut ir_plan.expr_arena,
                     BUILD_STREAMING_EXECUTOR,
                 )?;
-                let mut state = ExecutionState::new();
                 physical_plan.execute(&mut state).map(QueryResult::Single)
             },
             Engine::Auto => unreachable!(),
@@ -838,6 +915,7 @@ impl LazyFrame {
     #[cfg(feature = "new_streaming")]
     fn _collect_with_streaming_suppress_todo_panic(
         mut self,
+        state: ExecutionState,
     ) -> Option<PolarsResult<polars_core::query_result::QueryResult>> {
         self.opt_state |= OptFlags::NEW_STREAMING;
         let mut ir_plan = match self.to_alp_optimized() {
@@ -848,10 +926,11 @@ impl LazyFrame {
         ir_plan.ensure_root_node_is_sink();
 
         let f = || {
-            polars_stream::run_query(
+            polars_stream::run_query_with_state(
                 ir_plan.lp_top,
                 &mut ir_plan.lp_arena,
                 &mut ir_plan.expr_arena,
+                state,
             )
         };
 
@@ -901,6 +980,7 @@ impl LazyFrame {
                     partition_strategy,
                     max_rows_per_file,
                     approximate_bytes_per_file,
+                    commit_protocol,
                 } => SinkType::Partitioned(PartitionedSinkOptions {
                     base_path,
                     file_path_provider,
@@ -909,6 +989,7 @@ impl LazyFrame {
                     unified_sink_args,
                     max_rows_per_file,
                     approximate_bytes_per_file,
+                    commit_protocol,
                 }),
             },
         };
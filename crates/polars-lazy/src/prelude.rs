@@ -1,4 +1,7 @@
 pub(crate) use polars_expr::prelude::*;
+pub use polars_expr::state::{
+    ProgressCallback, QueryLimits, QueryProgress, SinkProgress, SourceProgress,
+};
 #[cfg(feature = "csv")]
 pub use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
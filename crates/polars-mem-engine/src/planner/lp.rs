@@ -105,8 +105,11 @@ pub struct MultiplePhysicalPlans {
 }
 
 impl MultiplePhysicalPlans {
-    pub fn execute(mut self) -> PolarsResult<Vec<DataFrame>> {
-        let mut state = ExecutionState::new();
+    pub fn execute(self) -> PolarsResult<Vec<DataFrame>> {
+        self.execute_with_state(ExecutionState::new())
+    }
+
+    pub fn execute_with_state(mut self, mut state: ExecutionState) -> PolarsResult<Vec<DataFrame>> {
         if let Some(mut cache_prefiller) = self.cache_prefiller {
             cache_prefiller.execute(&mut state)?;
         }
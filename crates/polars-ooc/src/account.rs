@@ -0,0 +1,87 @@
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+thread_local! {
+    static CURRENT_ACCOUNT: RefCell<Option<Arc<MemoryAccount>>> = const { RefCell::new(None) };
+}
+
+/// Tracks the bytes stored in the [`MemoryManager`](crate::MemoryManager) on
+/// behalf of a single query, and whether they exceeded the query's limit.
+///
+/// Frames are charged to the account that is current on the thread storing
+/// them, see [`MemoryAccount::enter`].
+#[derive(Debug)]
+pub struct MemoryAccount {
+    bytes: AtomicUsize,
+    limit: usize,
+    limit_exceeded: AtomicBool,
+}
+
+impl MemoryAccount {
+    pub fn new(limit: usize) -> Arc<Self> {
+        Arc::new(Self {
+            bytes: AtomicUsize::new(0),
+            limit,
+            limit_exceeded: AtomicBool::new(false),
+        })
+    }
+
+    /// Bytes currently charged to this account.
+    pub fn bytes(&self) -> usize {
+        self.bytes.load(Ordering::Relaxed)
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Whether a store, or an in-place update of a stored frame, pushed this
+    /// account over its limit at some point, and the bytes could not be
+    /// spilled.
+    pub fn limit_exceeded(&self) -> bool {
+        self.limit_exceeded.load(Ordering::Relaxed)
+    }
+
+    /// The account that is current on this thread.
+    pub fn current() -> Option<Arc<Self>> {
+        CURRENT_ACCOUNT.with_borrow(|a| a.clone())
+    }
+
+    /// Make this the current account of this thread until the guard is dropped.
+    pub fn enter(self: &Arc<Self>) -> MemoryAccountGuard {
+        let prev = CURRENT_ACCOUNT.replace(Some(Arc::clone(self)));
+        MemoryAccountGuard {
+            prev,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Charge `bytes` to this account, returns whether it is now over its limit.
+    pub(crate) fn add(&self, bytes: usize) -> bool {
+        self.bytes.fetch_add(bytes, Ordering::Relaxed) + bytes > self.limit
+    }
+
+    pub(crate) fn sub(&self, bytes: usize) {
+        self.bytes.fetch_sub(bytes, Ordering::Relaxed);
+    }
+
+    pub(crate) fn mark_limit_exceeded(&self) {
+        self.limit_exceeded.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Restores the previously current [`MemoryAccount`] when dropped.
+#[must_use]
+pub struct MemoryAccountGuard {
+    prev: Option<Arc<MemoryAccount>>,
+    // The guard restores the account of the thread it was created on.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for MemoryAccountGuard {
+    fn drop(&mut self) {
+        CURRENT_ACCOUNT.set(self.prev.take());
+    }
+}
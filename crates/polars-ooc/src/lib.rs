@@ -1,6 +1,8 @@
+mod account;
 mod memory_manager;
 mod spiller;
 mod token;
 
+pub use account::{MemoryAccount, MemoryAccountGuard};
 pub use memory_manager::{AccessPattern, MemoryManager, mm};
 pub use token::Token;
@@ -1,12 +1,13 @@
 use std::cell::Cell;
-use std::sync::LazyLock;
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock};
 
 use parking_lot::Mutex;
 use polars_config::{SpillFormat, SpillPolicy};
 use polars_core::prelude::DataFrame;
 use slotmap::{SlotMap, new_key_type};
 
+use crate::account::MemoryAccount;
 use crate::spiller::Spiller;
 use crate::token::Token;
 
@@ -48,6 +49,7 @@ struct Entry {
     height: usize,
     is_spilled: bool,
     access_pattern: AccessPattern,
+    account: Option<Arc<MemoryAccount>>,
 }
 
 #[derive(Default)]
@@ -72,21 +74,35 @@ impl ThreadLocalData {
         if !self.drift_threshold_reached() {
             return false;
         }
+        self.sync(global_bytes);
+        true
+    }
+
+    fn sync(&mut self, global_bytes: &AtomicUsize) {
         let drift = self.drift();
         self.last_sync_total_bytes = self.total_local_bytes;
         global_bytes.fetch_add(drift as usize, Ordering::Relaxed);
-        true
     }
 
     /// Update an entry's cached height and size after its DataFrame was
-    /// mutated in place, then sync the drift to the global counter.
-    fn update_entry_size(&mut self, key: DfKey, global_bytes: &AtomicUsize) {
+    /// mutated in place, then sync the drift to the global counter. Returns
+    /// whether this pushed the entry's account over its limit.
+    fn update_entry_size(&mut self, key: DfKey, global_bytes: &AtomicUsize) -> bool {
         let entry = self.get_mut(key);
         entry.height = entry.df.height();
         let new_size = entry.df.estimated_size();
         let old_size = std::mem::replace(&mut entry.size_bytes, new_size);
+        let mut over_account_limit = false;
+        if let Some(account) = &entry.account {
+            if new_size >= old_size {
+                over_account_limit = account.add(new_size - old_size);
+            } else {
+                account.sub(old_size - new_size);
+            }
+        }
         self.total_local_bytes = self.total_local_bytes + new_size - old_size;
         self.try_sync(global_bytes);
+        over_account_limit
     }
 
     fn get(&self, key: DfKey) -> &Entry {
@@ -112,7 +128,8 @@ const MEMORY_BUDGET_FRACTION: f64 = 0.7;
 /// atomic contention on every store/take. When the budget is exceeded the manager
 /// can spill frames to disk and reload them transparently.
 pub struct MemoryManager {
-    policy: SpillPolicy,
+    /// `None` follows the spill policy of the global config.
+    policy: Option<SpillPolicy>,
     spiller: Spiller,
     stores: boxcar::Vec<ThreadLocalMemoryManager>,
     total_bytes: AtomicUsize,
@@ -122,7 +139,10 @@ pub struct MemoryManager {
 impl Default for MemoryManager {
     fn default() -> Self {
         let cfg = polars_config::config();
-        Self::new(cfg.ooc_spill_policy(), cfg.ooc_spill_format())
+        Self {
+            policy: None,
+            ..Self::new(cfg.ooc_spill_policy(), cfg.ooc_spill_format())
+        }
     }
 }
 
@@ -131,7 +151,7 @@ impl MemoryManager {
     pub fn new(policy: SpillPolicy, format: SpillFormat) -> Self {
         let budget = (polars_utils::sys::total_memory() as f64 * MEMORY_BUDGET_FRACTION) as usize;
         Self {
-            policy,
+            policy: Some(policy),
             spiller: Spiller::new(format),
             stores: boxcar::Vec::new(),
             total_bytes: AtomicUsize::new(0),
@@ -139,6 +159,11 @@ impl MemoryManager {
         }
     }
 
+    fn policy(&self) -> SpillPolicy {
+        self.policy
+            .unwrap_or_else(|| polars_config::config().ooc_spill_policy())
+    }
+
     /// Return the index of the calling thread, registering it on first call.
     fn thread_idx(&self) -> u64 {
         THREAD_IDX.with(|cell| {
@@ -170,6 +195,12 @@ impl MemoryManager {
         tl.get(token.key).height
     }
 
+    /// Return the number of bytes the stored [`DataFrame`] accounts for in memory.
+    pub fn size_bytes(&self, token: &Token) -> usize {
+        let tl = self.lock(token);
+        tl.get(token.key).size_bytes
+    }
+
     /// Remove the entry for this [`Token`], update memory accounting, and
     /// delete the spill file if the frame was spilled. Called by [`Token::drop`].
     pub(crate) fn drop_token(&self, token: &Token) {
@@ -177,20 +208,30 @@ impl MemoryManager {
         let Some(entry) = tl.slots.remove(token.key) else {
             return;
         };
-        tl.total_local_bytes -= entry.size_bytes;
-        tl.try_sync(&self.total_bytes);
         if entry.is_spilled {
+            // Spilled frames were already released from the accounting.
             self.spiller.delete(token);
+            return;
+        }
+        tl.total_local_bytes -= entry.size_bytes;
+        tl.try_sync(&self.total_bytes);
+        if let Some(account) = &entry.account {
+            account.sub(entry.size_bytes);
         }
     }
 
-    /// Insert a [`DataFrame`] into the calling thread's store. Returns the
-    /// [`Token`] and whether the memory budget was exceeded after insertion.
+    /// Insert a [`DataFrame`] into the calling thread's store, charging it to
+    /// the thread's current [`MemoryAccount`]. Returns the [`Token`] and whether
+    /// we should spill because the memory budget or account limit was exceeded.
     fn insert(&self, df: DataFrame, access_pattern: AccessPattern) -> (Token, bool) {
         let size_bytes = df.estimated_size();
         let height = df.height();
         let idx = self.thread_idx();
 
+        let account = MemoryAccount::current();
+        let over_account_limit = account.as_ref().is_some_and(|a| a.add(size_bytes));
+        let over_account_limit = over_account_limit && self.spill_or_mark_exceeded(&account);
+
         let (key, should_spill) = {
             let mut tl = self.stores[idx as usize].0.lock();
             tl.total_local_bytes += size_bytes;
@@ -200,8 +241,9 @@ impl MemoryManager {
                 height,
                 is_spilled: false,
                 access_pattern,
+                account,
             });
-            (key, self.should_spill(&mut tl))
+            (key, self.should_spill(&mut tl) || over_account_limit)
         };
 
         (Token::new(idx, key), should_spill)
@@ -210,7 +252,7 @@ impl MemoryManager {
     /// Check whether the global memory budget is exceeded. Syncs the local
     /// drift first; returns `false` without checking if the drift is too small.
     fn should_spill(&self, tl: &mut ThreadLocalData) -> bool {
-        matches!(self.policy, SpillPolicy::Spill)
+        matches!(self.policy(), SpillPolicy::Spill)
             && tl.try_sync(&self.total_bytes)
             && self.total_bytes.load(Ordering::Relaxed) > self.budget
     }
@@ -280,34 +322,119 @@ impl MemoryManager {
     where
         F: FnOnce(&mut DataFrame) -> R,
     {
-        {
+        let is_spilled = self.lock(token).get(token.key).is_spilled;
+        // Reload from disk without holding the lock.
+        let reloaded = is_spilled.then(|| self.spiller.load(token));
+
+        let (r, over_account_limit) = {
             let mut tl = self.lock(token);
             let entry = tl.get_mut(token.key);
-            if !entry.is_spilled {
-                let r = f(&mut entry.df);
-                tl.update_entry_size(token.key, &self.total_bytes);
-                return r;
+            if let Some(df) = reloaded {
+                entry.df = df;
+                entry.is_spilled = false;
+                // Spilled frames are not charged, so charge the reloaded frame in full.
+                entry.size_bytes = 0;
             }
+            let r = f(&mut entry.df);
+            (r, tl.update_entry_size(token.key, &self.total_bytes))
+        };
+
+        if is_spilled {
+            self.spiller.delete(token);
         }
-        // Reload from disk without holding the lock.
-        let df = self.spiller.load(token);
-        let mut tl = self.lock(token);
-        let entry = tl.get_mut(token.key);
-        entry.df = df;
-        entry.is_spilled = false;
-        let r = f(&mut entry.df);
-        tl.update_entry_size(token.key, &self.total_bytes);
+        self.after_in_place_update(token, over_account_limit);
         r
     }
 
+    /// Spill if an in-place update pushed the entry's account over its limit.
+    fn after_in_place_update(&self, token: &Token, over_account_limit: bool) {
+        if !over_account_limit {
+            return;
+        }
+        let account = self.lock(token).get(token.key).account.clone();
+        if self.spill_or_mark_exceeded(&account) {
+            // The account guard is not `Send`, so spill without awaiting.
+            let _guard = account.as_ref().map(|a| a.enter());
+            self.spill_blocking(token.thread_idx());
+        }
+    }
+
+    /// Called when `account` went over its limit. Returns whether spilling
+    /// should try to bring it back under, otherwise the limit is marked as
+    /// exceeded and the query is aborted by its owner.
+    fn spill_or_mark_exceeded(&self, account: &Option<Arc<MemoryAccount>>) -> bool {
+        if matches!(self.policy(), SpillPolicy::Spill) {
+            return true;
+        }
+        if let Some(account) = account {
+            account.mark_limit_exceeded();
+        }
+        false
+    }
+
     /// Spill frames from the given thread's store to disk to free memory.
-    async fn spill(&self, _thread_idx: u64) {
-        unimplemented!("spilling to disk")
+    async fn spill(&self, thread_idx: u64) {
+        self.spill_blocking(thread_idx)
     }
 
     /// Blocking variant of [`spill`](Self::spill).
-    fn spill_blocking(&self, _thread_idx: u64) {
-        unimplemented!("spilling to disk")
+    ///
+    /// Spills the largest frames of the store until the global budget is met
+    /// and the current [`MemoryAccount`] is back under its limit. If that is
+    /// not possible the account's limit is marked as exceeded.
+    fn spill_blocking(&self, thread_idx: u64) {
+        let account = MemoryAccount::current();
+        let mut tl = self.stores[thread_idx as usize].0.lock();
+        tl.sync(&self.total_bytes);
+
+        loop {
+            let over_budget = self.total_bytes.load(Ordering::Relaxed) > self.budget;
+            let over_account_limit = account.as_ref().is_some_and(|a| a.bytes() > a.limit());
+            if !over_budget && !over_account_limit {
+                return;
+            }
+
+            // Over the budget any frame helps, otherwise only the account's own.
+            let candidate = tl
+                .slots
+                .iter()
+                .filter(|(_, e)| !e.is_spilled && e.size_bytes > 0)
+                .filter(|(_, e)| {
+                    over_budget
+                        || e.account
+                            .as_ref()
+                            .zip(account.as_ref())
+                            .is_some_and(|(a, b)| Arc::ptr_eq(a, b))
+                })
+                .max_by_key(|(_, e)| e.size_bytes)
+                .map(|(key, _)| key);
+
+            let spilled = candidate.is_some_and(|key| {
+                let entry = tl.get_mut(key);
+                if let Err(e) = self.spiller.spill(thread_idx, key, &mut entry.df) {
+                    if polars_config::config().verbose() {
+                        eprintln!("[MemoryManager] failed to spill frame: {e}");
+                    }
+                    return false;
+                }
+                entry.df = DataFrame::default();
+                entry.is_spilled = true;
+                let size_bytes = entry.size_bytes;
+                if let Some(account) = &entry.account {
+                    account.sub(size_bytes);
+                }
+                tl.total_local_bytes -= size_bytes;
+                tl.sync(&self.total_bytes);
+                true
+            });
+
+            if !spilled {
+                if over_account_limit && let Some(account) = &account {
+                    account.mark_limit_exceeded();
+                }
+                return;
+            }
+        }
     }
 
     /// Approximate total bytes tracked across all threads.
@@ -323,10 +450,49 @@ impl MemoryManager {
 impl std::fmt::Debug for MemoryManager {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MemoryManager")
-            .field("policy", &self.policy)
+            .field("policy", &self.policy())
             .field("total_bytes", &self.total_bytes.load(Ordering::Relaxed))
             .field("budget", &self.budget)
             .field("num_stores", &self.stores.count())
             .finish_non_exhaustive()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use polars_config::SpillPolicy;
+    use polars_core::df;
+
+    use super::mm;
+    use crate::{AccessPattern, MemoryAccount};
+
+    #[test]
+    fn test_spill_over_account_limit() {
+        let dir = tempfile::tempdir().unwrap();
+        unsafe {
+            std::env::set_var("POLARS_TEMP_DIR", dir.path());
+            std::env::set_var("POLARS_OOC_SPILL_POLICY", "spill");
+        }
+        polars_config::config().reload_env_var("POLARS_OOC_SPILL_POLICY");
+        assert_eq!(mm().policy(), SpillPolicy::Spill);
+
+        let account = MemoryAccount::new(1024);
+        let _guard = account.enter();
+
+        let dfs = (0..4)
+            .map(|i| df!("a" => (i * 1000..(i + 1) * 1000).collect::<Vec<i64>>()).unwrap())
+            .collect::<Vec<_>>();
+        let tokens = dfs
+            .iter()
+            .map(|df| mm().store_blocking(df.clone(), AccessPattern::NoPattern))
+            .collect::<Vec<_>>();
+
+        // Every frame is larger than the limit, so all of them were spilled.
+        assert!(!account.limit_exceeded());
+        assert_eq!(account.bytes(), 0);
+
+        for (token, df) in tokens.into_iter().zip(&dfs) {
+            assert_eq!(&mm().df_blocking(&token), df);
+        }
+    }
+}
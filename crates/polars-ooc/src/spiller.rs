@@ -1,36 +1,94 @@
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
 use polars_config::SpillFormat;
 use polars_core::prelude::DataFrame;
+use polars_error::{PolarsResult, polars_err};
+use slotmap::Key;
 
+use crate::memory_manager::DfKey;
 use crate::token::Token;
 
 pub struct Spiller {
-    #[allow(dead_code)]
     format: SpillFormat,
+    dir: OnceLock<PathBuf>,
 }
 
 impl Spiller {
     pub fn new(format: SpillFormat) -> Self {
-        Self { format }
+        Self {
+            format,
+            dir: OnceLock::new(),
+        }
+    }
+
+    /// Directory the spill files of this process are written to, created on first use.
+    fn dir(&self) -> PolarsResult<&PathBuf> {
+        if let Some(dir) = self.dir.get() {
+            return Ok(dir);
+        }
+
+        let base = std::env::var_os("POLARS_TEMP_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| std::env::temp_dir().join("polars"));
+        let dir = base.join(format!("ooc-spill-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).map_err(|e| {
+            polars_err!(ComputeError: "failed to create spill directory {}: {e}", dir.display())
+        })?;
+
+        Ok(self.dir.get_or_init(|| dir))
+    }
+
+    fn path(&self, thread_idx: u64, key: DfKey) -> PolarsResult<PathBuf> {
+        let ext = match self.format {
+            SpillFormat::Ipc => "ipc",
+        };
+        Ok(self
+            .dir()?
+            .join(format!("{thread_idx}-{}.{ext}", key.data().as_ffi())))
     }
 
     /// Spill a DataFrame to disk.
-    #[allow(dead_code)]
-    pub fn spill(&self, _token: &Token, _df: DataFrame) {
-        unimplemented!("spilling to disk")
+    pub fn spill(&self, thread_idx: u64, key: DfKey, df: &mut DataFrame) -> PolarsResult<()> {
+        let path = self.path(thread_idx, key)?;
+        let result = (|| {
+            let mut file = std::io::BufWriter::new(std::fs::File::create(&path)?);
+            match self.format {
+                SpillFormat::Ipc => df.serialize_into_writer(&mut file)?,
+            }
+            std::io::Write::flush(&mut file)?;
+            PolarsResult::Ok(())
+        })();
+
+        if result.is_err() {
+            let _ = std::fs::remove_file(&path);
+        }
+        result
     }
 
     /// Load a previously spilled DataFrame from disk.
-    pub fn load(&self, _token: &Token) -> DataFrame {
-        unimplemented!("loading spilled data from disk")
+    pub fn load(&self, token: &Token) -> DataFrame {
+        self.load_blocking(token)
     }
 
     /// Load a previously spilled DataFrame from disk (blocking).
-    pub fn load_blocking(&self, _token: &Token) -> DataFrame {
-        unimplemented!("loading spilled data from disk")
+    pub fn load_blocking(&self, token: &Token) -> DataFrame {
+        let result = (|| {
+            let path = self.path(token.thread_idx(), token.key)?;
+            let mut file = std::io::BufReader::new(std::fs::File::open(path)?);
+            match self.format {
+                SpillFormat::Ipc => DataFrame::deserialize_from_reader(&mut file),
+            }
+        })();
+
+        // The frame only exists on disk, there is nothing to fall back to.
+        result.unwrap_or_else(|e| panic!("failed to load spilled DataFrame: {e}"))
     }
 
     /// Best-effort delete of a spill file.
-    pub fn delete(&self, _token: &Token) {
-        unimplemented!("deleting spill file")
+    pub fn delete(&self, token: &Token) {
+        if let Ok(path) = self.path(token.thread_idx(), token.key) {
+            let _ = std::fs::remove_file(path);
+        }
     }
 }
@@ -36,6 +36,11 @@ impl Token {
         mm().height(self)
     }
 
+    /// Return the number of bytes the stored [`DataFrame`] accounts for in memory.
+    pub fn size_bytes(&self) -> usize {
+        mm().size_bytes(self)
+    }
+
     /// Clone the stored [`DataFrame`] without consuming the token.
     pub async fn df(&self) -> DataFrame {
         mm().df(self).await
@@ -186,6 +186,12 @@ impl TakeChunked for Series {
                 out.into_decimal_unchecked(ca.precision(), ca.scale())
                     .into_series()
             },
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(_, _) => {
+                let ca = self.decimal256().unwrap();
+                let out = take_chunked_unchecked_struct(ca.physical(), by, sorted, avoid_sharing);
+                Decimal256Chunked::new_logical(out, self.dtype().clone()).into_series()
+            },
             #[cfg(feature = "dtype-date")]
             Date => {
                 let ca = self.date().unwrap();
@@ -218,6 +224,14 @@ impl TakeChunked for Series {
                     .into_time()
                     .into_series()
             },
+            #[cfg(feature = "dtype-interval")]
+            Interval => {
+                let ca = self.interval().unwrap();
+                ca.physical()
+                    .take_chunked_unchecked(by, sorted, avoid_sharing)
+                    .into_interval()
+                    .into_series()
+            },
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | Enum(_, _) => {
                 with_match_categorical_physical_type!(self.dtype().cat_physical().unwrap(), |$C| {
@@ -286,6 +300,12 @@ impl TakeChunked for Series {
                 out.into_decimal_unchecked(ca.precision(), ca.scale())
                     .into_series()
             },
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(_, _) => {
+                let ca = self.decimal256().unwrap();
+                let out = take_opt_chunked_unchecked_struct(ca.physical(), by, avoid_sharing);
+                Decimal256Chunked::new_logical(out, self.dtype().clone()).into_series()
+            },
             #[cfg(feature = "dtype-date")]
             Date => {
                 let ca = self.date().unwrap();
@@ -318,6 +338,14 @@ impl TakeChunked for Series {
                     .into_time()
                     .into_series()
             },
+            #[cfg(feature = "dtype-interval")]
+            Interval => {
+                let ca = self.interval().unwrap();
+                ca.physical()
+                    .take_opt_chunked_unchecked(by, avoid_sharing)
+                    .into_interval()
+                    .into_series()
+            },
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | Enum(_, _) => {
                 with_match_categorical_physical_type!(self.dtype().cat_physical().unwrap(), |$C| {
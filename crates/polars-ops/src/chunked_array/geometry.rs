@@ -0,0 +1,477 @@
+//! Planar spatial measures and predicates on Well-Known Binary geometries.
+//!
+//! All computations are done on the raw `x` and `y` coordinates, without taking the coordinate
+//! reference system into account.
+use arrow::bitmap::Bitmap;
+use polars_core::datatypes::extension::geometry::{Coord, Geometry, read_wkb};
+use polars_core::prelude::*;
+
+fn geometry_storage(s: &Series) -> PolarsResult<&BinaryChunked> {
+    polars_ensure!(
+        s.dtype().is_geometry(),
+        InvalidOperation: "expected Geometry dtype, got: {}", s.dtype()
+    );
+    s.to_storage().binary()
+}
+
+fn decode(s: &Series) -> PolarsResult<Vec<Option<Geometry>>> {
+    geometry_storage(s)?
+        .iter()
+        .map(|opt_b| opt_b.map(read_wkb).transpose())
+        .collect()
+}
+
+fn unary<K>(s: &Series, f: impl Fn(&Geometry) -> Option<K>) -> PolarsResult<Vec<Option<K>>> {
+    geometry_storage(s)?
+        .iter()
+        .map(|opt_b| Ok(opt_b.map(read_wkb).transpose()?.and_then(|g| f(&g))))
+        .collect()
+}
+
+/// Apply `f` to every pair of non-null geometries, broadcasting unit-length inputs.
+fn binary<K>(
+    lhs: &Series,
+    rhs: &Series,
+    op: &str,
+    f: impl Fn(&Parts, &Parts) -> Option<K>,
+) -> PolarsResult<Vec<Option<K>>> {
+    fn at(v: &[Option<Geometry>], i: usize) -> Option<&Geometry> {
+        v[if v.len() == 1 { 0 } else { i }].as_ref()
+    }
+
+    let a = decode(lhs)?;
+    let b = decode(rhs)?;
+    let len = match (a.len(), b.len()) {
+        (l, r) if l == r => l,
+        (1, r) => r,
+        (l, 1) => l,
+        (l, r) => polars_bail!(length_mismatch = op, l, r),
+    };
+    Ok((0..len)
+        .map(|i| match (at(&a, i), at(&b, i)) {
+            (Some(x), Some(y)) => f(&Parts::new(x), &Parts::new(y)),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Get the bounding box of every geometry as a struct with fields `xmin`, `ymin`, `xmax` and
+/// `ymax`. Empty geometries have a null bounding box.
+pub fn st_bounds(s: &Series) -> PolarsResult<Series> {
+    let boxes = unary(s, bounding_box)?;
+    let field = |name: &'static str, i: usize| {
+        Float64Chunked::from_iter_options(
+            PlSmallStr::from_static(name),
+            boxes.iter().map(|b| b.map(|b| b[i])),
+        )
+        .into_series()
+    };
+    let fields = [
+        field("xmin", 0),
+        field("ymin", 1),
+        field("xmax", 2),
+        field("ymax", 3),
+    ];
+    let validity = boxes
+        .iter()
+        .any(Option::is_none)
+        .then(|| boxes.iter().map(Option::is_some).collect::<Bitmap>());
+    Ok(
+        StructChunked::from_series(s.name().clone(), boxes.len(), fields.iter())?
+            .with_outer_validity(validity)
+            .into_series(),
+    )
+}
+
+/// Get the area of every geometry. Points and lines have an area of zero.
+pub fn st_area(s: &Series) -> PolarsResult<Series> {
+    let out = unary(s, |g| Some(area(g)))?;
+    Ok(Float64Chunked::from_iter_options(s.name().clone(), out.into_iter()).into_series())
+}
+
+/// Get the length of every linear geometry. Points and polygons have a length of zero.
+pub fn st_length(s: &Series) -> PolarsResult<Series> {
+    let out = unary(s, |g| Some(length(g)))?;
+    Ok(Float64Chunked::from_iter_options(s.name().clone(), out.into_iter()).into_series())
+}
+
+/// Get the minimum distance between the geometries of `lhs` and `rhs`.
+///
+/// The distance is null if either geometry is empty.
+pub fn st_distance(lhs: &Series, rhs: &Series) -> PolarsResult<Series> {
+    let out = binary(lhs, rhs, "st_distance", distance)?;
+    Ok(Float64Chunked::from_iter_options(lhs.name().clone(), out.into_iter()).into_series())
+}
+
+/// Check whether the geometries of `lhs` and `rhs` have at least one point in common.
+pub fn st_intersects(lhs: &Series, rhs: &Series) -> PolarsResult<Series> {
+    let out = binary(lhs, rhs, "st_intersects", |a, b| Some(intersects(a, b)))?;
+    Ok(BooleanChunked::from_iter_options(lhs.name().clone(), out.into_iter()).into_series())
+}
+
+/// Check whether the geometries of `lhs` contain the geometries of `rhs`.
+///
+/// A geometry contains another if no point of the other lies in its exterior, and the interiors of
+/// both geometries have at least one point in common.
+pub fn st_contains(lhs: &Series, rhs: &Series) -> PolarsResult<Series> {
+    let out = binary(lhs, rhs, "st_contains", |a, b| Some(contains(a, b)))?;
+    Ok(BooleanChunked::from_iter_options(lhs.name().clone(), out.into_iter()).into_series())
+}
+
+fn bounding_box(g: &Geometry) -> Option<[f64; 4]> {
+    let mut bbox: Option<[f64; 4]> = None;
+    g.for_each_coord(&mut |c| {
+        let [xmin, ymin, xmax, ymax] = bbox.get_or_insert([c.x, c.y, c.x, c.y]);
+        *xmin = xmin.min(c.x);
+        *ymin = ymin.min(c.y);
+        *xmax = xmax.max(c.x);
+        *ymax = ymax.max(c.y);
+    });
+    bbox
+}
+
+fn area(g: &Geometry) -> f64 {
+    fn polygon_area(rings: &[Vec<Coord>]) -> f64 {
+        let Some((exterior, holes)) = rings.split_first() else {
+            return 0.0;
+        };
+        ring_area(exterior) - holes.iter().map(|r| ring_area(r)).sum::<f64>()
+    }
+
+    match g {
+        Geometry::Polygon(rings) => polygon_area(rings),
+        Geometry::MultiPolygon(polys) => polys.iter().map(|p| polygon_area(p)).sum(),
+        Geometry::GeometryCollection(geoms) => geoms.iter().map(area).sum(),
+        _ => 0.0,
+    }
+}
+
+/// The unsigned area of a ring, using the shoelace formula.
+fn ring_area(ring: &[Coord]) -> f64 {
+    let twice_area: f64 = ring_segments(ring)
+        .map(|(a, b)| a.x * b.y - b.x * a.y)
+        .sum();
+    twice_area.abs() / 2.0
+}
+
+fn length(g: &Geometry) -> f64 {
+    fn line_length(cs: &[Coord]) -> f64 {
+        cs.windows(2).map(|w| dist(w[0], w[1])).sum()
+    }
+
+    match g {
+        Geometry::LineString(cs) => line_length(cs),
+        Geometry::MultiLineString(lines) => lines.iter().map(|cs| line_length(cs)).sum(),
+        Geometry::GeometryCollection(geoms) => geoms.iter().map(length).sum(),
+        _ => 0.0,
+    }
+}
+
+fn distance(a: &Parts, b: &Parts) -> Option<f64> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    if intersects(a, b) {
+        return Some(0.0);
+    }
+
+    // The geometries are disjoint, so the minimum distance is attained at a vertex of one of them.
+    let mut d = f64::INFINITY;
+    for p in &a.points {
+        for q in &b.points {
+            d = d.min(dist(*p, *q));
+        }
+    }
+    for (p, other) in a
+        .points
+        .iter()
+        .map(|p| (p, b))
+        .chain(b.points.iter().map(|p| (p, a)))
+    {
+        for (s1, s2) in other.segments() {
+            d = d.min(point_segment_distance(*p, s1, s2));
+        }
+    }
+    for (a1, a2) in a.segments() {
+        for (b1, b2) in b.segments() {
+            d = d
+                .min(point_segment_distance(a1, b1, b2))
+                .min(point_segment_distance(a2, b1, b2))
+                .min(point_segment_distance(b1, a1, a2))
+                .min(point_segment_distance(b2, a1, a2));
+        }
+    }
+    Some(d)
+}
+
+fn intersects(a: &Parts, b: &Parts) -> bool {
+    // Shared points, and points on lines or boundaries.
+    for (points, other) in [(&a.points, b), (&b.points, a)] {
+        for p in points {
+            if other.points.contains(p) || other.segments().any(|(s1, s2)| on_segment(*p, s1, s2)) {
+                return true;
+            }
+        }
+    }
+
+    // Crossing or touching segments.
+    for (a1, a2) in a.segments() {
+        if b.segments()
+            .any(|(b1, b2)| segments_intersect(a1, a2, b1, b2))
+        {
+            return true;
+        }
+    }
+
+    // Without any crossings, a part is either fully inside or outside of a polygon.
+    a.vertices().any(|p| b.locate(p) != Location::Exterior)
+        || b.vertices().any(|p| a.locate(p) != Location::Exterior)
+}
+
+fn contains(a: &Parts, b: &Parts) -> bool {
+    if a.is_empty() || b.is_empty() {
+        return false;
+    }
+
+    // Every part of `b` must be covered by `a`.
+    let covered = b.points.iter().all(|p| a.covers_point(*p))
+        && b.segments().all(|(s1, s2)| a.covers_segment(s1, s2))
+        && b.polygons.iter().all(|rings| {
+            // A polygon of `b` can only be covered by polygons, and must not surround a hole.
+            !a.polygons.is_empty()
+                && a.polygons
+                    .iter()
+                    .flat_map(|rings| rings.iter().skip(1).flatten())
+                    .all(|c| polygon_location(*c, rings) != Location::Interior)
+        });
+    if !covered {
+        return false;
+    }
+
+    // The interiors must have a point in common.
+    !b.polygons.is_empty()
+        || b.points.iter().any(|p| a.has_interior_point(*p))
+        || b.segments()
+            .any(|(s1, s2)| a.has_interior_point(midpoint(s1, s2)))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Location {
+    Interior,
+    Boundary,
+    Exterior,
+}
+
+/// A geometry decomposed into its points, lines and polygons.
+#[derive(Default)]
+struct Parts<'a> {
+    points: Vec<Coord>,
+    lines: Vec<&'a [Coord]>,
+    polygons: Vec<&'a [Vec<Coord>]>,
+}
+
+impl<'a> Parts<'a> {
+    fn new(g: &'a Geometry) -> Self {
+        let mut parts = Self::default();
+        parts.add(g);
+        parts
+    }
+
+    fn add(&mut self, g: &'a Geometry) {
+        match g {
+            Geometry::Point(p) => self.points.extend(p),
+            Geometry::MultiPoint(cs) => self.points.extend(cs),
+            Geometry::LineString(cs) => self.add_line(cs),
+            Geometry::MultiLineString(lines) => lines.iter().for_each(|cs| self.add_line(cs)),
+            Geometry::Polygon(rings) => self.add_polygon(rings),
+            Geometry::MultiPolygon(polys) => polys.iter().for_each(|p| self.add_polygon(p)),
+            Geometry::GeometryCollection(geoms) => geoms.iter().for_each(|g| self.add(g)),
+        }
+    }
+
+    fn add_line(&mut self, cs: &'a [Coord]) {
+        match cs {
+            [] => {},
+            [c] => self.points.push(*c),
+            _ => self.lines.push(cs),
+        }
+    }
+
+    fn add_polygon(&mut self, rings: &'a [Vec<Coord>]) {
+        if rings.first().is_some_and(|r| !r.is_empty()) {
+            self.polygons.push(rings);
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.points.is_empty() && self.lines.is_empty() && self.polygons.is_empty()
+    }
+
+    fn vertices(&self) -> impl Iterator<Item = Coord> + '_ {
+        let lines = self.lines.iter().flat_map(|cs| cs.iter());
+        let rings = self
+            .polygons
+            .iter()
+            .flat_map(|rings| rings.iter().flatten());
+        self.points.iter().chain(lines).chain(rings).copied()
+    }
+
+    fn line_segments(&self) -> impl Iterator<Item = (Coord, Coord)> + '_ {
+        self.lines
+            .iter()
+            .flat_map(|cs| cs.windows(2).map(|w| (w[0], w[1])))
+    }
+
+    fn boundary_segments(&self) -> impl Iterator<Item = (Coord, Coord)> + '_ {
+        self.polygons
+            .iter()
+            .flat_map(|rings| rings.iter().flat_map(|r| ring_segments(r)))
+    }
+
+    /// All segments of lines and polygon boundaries.
+    fn segments(&self) -> impl Iterator<Item = (Coord, Coord)> + '_ {
+        self.line_segments().chain(self.boundary_segments())
+    }
+
+    /// The location of `p` with respect to the polygons.
+    fn locate(&self, p: Coord) -> Location {
+        let mut loc = Location::Exterior;
+        for rings in &self.polygons {
+            match polygon_location(p, rings) {
+                Location::Interior => return Location::Interior,
+                Location::Boundary => loc = Location::Boundary,
+                Location::Exterior => {},
+            }
+        }
+        loc
+    }
+
+    fn covers_point(&self, p: Coord) -> bool {
+        self.points.contains(&p)
+            || self.line_segments().any(|(s1, s2)| on_segment(p, s1, s2))
+            || self.locate(p) != Location::Exterior
+    }
+
+    fn covers_segment(&self, s1: Coord, s2: Coord) -> bool {
+        self.covers_point(s1)
+            && self.covers_point(s2)
+            && self.covers_point(midpoint(s1, s2))
+            && !self
+                .boundary_segments()
+                .any(|(b1, b2)| segments_cross(s1, s2, b1, b2))
+    }
+
+    fn has_interior_point(&self, p: Coord) -> bool {
+        // The endpoints of a line form its boundary.
+        let in_line_interior = |cs: &[Coord]| {
+            cs.windows(2).any(|w| on_segment(p, w[0], w[1]))
+                && (cs.first() == cs.last() || (Some(&p) != cs.first() && Some(&p) != cs.last()))
+        };
+        self.points.contains(&p)
+            || self.lines.iter().any(|cs| in_line_interior(cs))
+            || self.locate(p) == Location::Interior
+    }
+}
+
+fn polygon_location(p: Coord, rings: &[Vec<Coord>]) -> Location {
+    let Some((exterior, holes)) = rings.split_first() else {
+        return Location::Exterior;
+    };
+    match ring_location(p, exterior) {
+        Location::Interior => {
+            for hole in holes {
+                match ring_location(p, hole) {
+                    Location::Interior => return Location::Exterior,
+                    Location::Boundary => return Location::Boundary,
+                    Location::Exterior => {},
+                }
+            }
+            Location::Interior
+        },
+        loc => loc,
+    }
+}
+
+/// Locate `p` with respect to a ring, using the even-odd rule.
+fn ring_location(p: Coord, ring: &[Coord]) -> Location {
+    let mut inside = false;
+    for (a, b) in ring_segments(ring) {
+        if on_segment(p, a, b) {
+            return Location::Boundary;
+        }
+        if (a.y > p.y) != (b.y > p.y) {
+            let x = a.x + (p.y - a.y) * (b.x - a.x) / (b.y - a.y);
+            if p.x < x {
+                inside = !inside;
+            }
+        }
+    }
+    if inside {
+        Location::Interior
+    } else {
+        Location::Exterior
+    }
+}
+
+/// The segments of a ring, including the closing segment if the ring is not explicitly closed.
+fn ring_segments(ring: &[Coord]) -> impl Iterator<Item = (Coord, Coord)> + '_ {
+    let closing = match (ring.first(), ring.last()) {
+        (Some(first), Some(last)) if first != last => Some((*last, *first)),
+        _ => None,
+    };
+    ring.windows(2).map(|w| (w[0], w[1])).chain(closing)
+}
+
+fn dist(a: Coord, b: Coord) -> f64 {
+    (a.x - b.x).hypot(a.y - b.y)
+}
+
+fn midpoint(a: Coord, b: Coord) -> Coord {
+    Coord {
+        x: (a.x + b.x) / 2.0,
+        y: (a.y + b.y) / 2.0,
+    }
+}
+
+/// Twice the signed area of the triangle `o`, `a`, `b`.
+fn cross(o: Coord, a: Coord, b: Coord) -> f64 {
+    (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+}
+
+fn on_segment(p: Coord, a: Coord, b: Coord) -> bool {
+    cross(a, b, p) == 0.0
+        && p.x >= a.x.min(b.x)
+        && p.x <= a.x.max(b.x)
+        && p.y >= a.y.min(b.y)
+        && p.y <= a.y.max(b.y)
+}
+
+/// Whether the segments cross at a single point in the interior of both.
+fn segments_cross(a1: Coord, a2: Coord, b1: Coord, b2: Coord) -> bool {
+    let opposite = |d1: f64, d2: f64| (d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0);
+    opposite(cross(b1, b2, a1), cross(b1, b2, a2)) && opposite(cross(a1, a2, b1), cross(a1, a2, b2))
+}
+
+fn segments_intersect(a1: Coord, a2: Coord, b1: Coord, b2: Coord) -> bool {
+    segments_cross(a1, a2, b1, b2)
+        || on_segment(a1, b1, b2)
+        || on_segment(a2, b1, b2)
+        || on_segment(b1, a1, a2)
+        || on_segment(b2, a1, a2)
+}
+
+fn point_segment_distance(p: Coord, a: Coord, b: Coord) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let len2 = dx * dx + dy * dy;
+    if len2 == 0.0 {
+        return dist(p, a);
+    }
+    let t = (((p.x - a.x) * dx + (p.y - a.y) * dy) / len2).clamp(0.0, 1.0);
+    dist(
+        p,
+        Coord {
+            x: a.x + t * dx,
+            y: a.y + t * dy,
+        },
+    )
+}
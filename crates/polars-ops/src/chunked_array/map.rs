@@ -0,0 +1,158 @@
+use arrow::array::Array;
+use arrow::compute::utils::combine_validities_and;
+use polars_core::chunked_array::ops::ChunkNestingUtils;
+use polars_core::prelude::*;
+
+/// Get the `List(Struct{key, value})` storage of a map, as a single chunk with normalized
+/// offsets.
+fn map_storage(s: &Series) -> PolarsResult<ListChunked> {
+    polars_ensure!(
+        s.dtype().is_map(),
+        InvalidOperation: "expected Map dtype, got: {}", s.dtype()
+    );
+    let ca = s.to_storage().list()?.rechunk().into_owned();
+    Ok(ca.trim_lists_to_normalized_offsets().unwrap_or(ca))
+}
+
+fn map_entry_field(s: &Series, index: usize) -> PolarsResult<Series> {
+    let ca = map_storage(s)?;
+    let out =
+        ca.apply_to_inner(&|entries| Ok(entries.struct_()?.fields_as_series()[index].clone()))?;
+    Ok(out.into_series())
+}
+
+/// Get the keys of every map as a list.
+pub fn map_keys(s: &Series) -> PolarsResult<Series> {
+    map_entry_field(s, 0)
+}
+
+/// Get the values of every map as a list.
+pub fn map_values(s: &Series) -> PolarsResult<Series> {
+    map_entry_field(s, 1)
+}
+
+/// For every map, find the index of the first entry whose key equals `key`.
+///
+/// Returns the storage of the maps together with the indices into its flattened entries.
+fn find_keys(s: &Series, key: &Series) -> PolarsResult<(ListChunked, IdxCa)> {
+    let ca = map_storage(s)?;
+    polars_ensure!(
+        key.len() == 1 || key.len() == ca.len(),
+        ShapeMismatch: "map key of length {} does not match map of length {}",
+        key.len(), ca.len()
+    );
+
+    let arr = ca.downcast_as_array();
+    let entries = ca.get_inner();
+    let keys = entries.struct_()?.fields_as_series()[0].clone();
+    let key = key.strict_cast(keys.dtype())?;
+
+    let key = if key.len() == 1 {
+        key
+    } else {
+        let rows: IdxCa = arr
+            .offsets()
+            .lengths()
+            .enumerate()
+            .flat_map(|(row, len)| std::iter::repeat_n(row as IdxSize, len))
+            .collect_ca(PlSmallStr::EMPTY);
+        key.take(&rows)?
+    };
+    let mask = keys.equal(&key)?.rechunk().into_owned();
+    let mask = mask.downcast_as_array();
+
+    let idx: IdxCa = arr
+        .offsets()
+        .offset_and_length_iter()
+        .enumerate()
+        .map(|(row, (start, len))| {
+            if !arr.is_valid(row) {
+                return None;
+            }
+            (start..start + len)
+                .find(|&i| mask.is_valid(i) && mask.value(i))
+                .map(|i| i as IdxSize)
+        })
+        .collect_ca(s.name().clone());
+    Ok((ca, idx))
+}
+
+/// Get the value belonging to `key` in every map, or null if the key is not present.
+pub fn map_get(s: &Series, key: &Series) -> PolarsResult<Series> {
+    let (ca, idx) = find_keys(s, key)?;
+    let entries = ca.get_inner();
+    let values = entries.struct_()?.fields_as_series()[1].clone();
+    let mut out = values.take(&idx)?;
+    out.rename(s.name().clone());
+    Ok(out)
+}
+
+/// Check whether every map contains `key`.
+pub fn map_contains_key(s: &Series, key: &Series) -> PolarsResult<Series> {
+    let (ca, idx) = find_keys(s, key)?;
+    let arr = ca.downcast_as_array();
+    let out: BooleanChunked = idx
+        .into_iter()
+        .enumerate()
+        .map(|(row, idx)| arr.is_valid(row).then_some(idx.is_some()))
+        .collect_ca(s.name().clone());
+    Ok(out.into_series())
+}
+
+/// Create maps by zipping a list of keys with a list of values.
+///
+/// The lists of keys and values must have the same length in every row.
+pub fn map_from_lists(keys: &Series, values: &Series) -> PolarsResult<Series> {
+    let normalize = |s: &Series| -> PolarsResult<ListChunked> {
+        let ca = s.list()?.rechunk().into_owned();
+        Ok(ca.trim_lists_to_normalized_offsets().unwrap_or(ca))
+    };
+    let keys_ca = normalize(keys)?;
+    let values_ca = normalize(values)?;
+    polars_ensure!(
+        keys_ca.len() == values_ca.len(),
+        ShapeMismatch: "map keys of length {} do not match values of length {}",
+        keys_ca.len(), values_ca.len()
+    );
+
+    let keys_arr = keys_ca.downcast_as_array();
+    let values_arr = values_ca.downcast_as_array();
+    polars_ensure!(
+        keys_arr.offsets() == values_arr.offsets(),
+        ShapeMismatch: "map keys and values must have the same length in every row"
+    );
+
+    let entries = StructChunked::from_series(
+        PlSmallStr::from_static("entries"),
+        keys_arr.values().len(),
+        [
+            keys_ca
+                .get_inner()
+                .with_name(PlSmallStr::from_static("key")),
+            values_ca
+                .get_inner()
+                .with_name(PlSmallStr::from_static("value")),
+        ]
+        .iter(),
+    )?;
+    let entries = entries.rechunk().chunks()[0].clone();
+
+    let arr = LargeListArray::new(
+        LargeListArray::default_datatype(entries.dtype().clone()),
+        keys_arr.offsets().clone(),
+        entries,
+        combine_validities_and(keys_arr.validity(), values_arr.validity()),
+    );
+
+    let DataType::Extension(typ, storage) = DataType::new_map(
+        keys_ca.inner_dtype().clone(),
+        values_ca.inner_dtype().clone(),
+    ) else {
+        unreachable!()
+    };
+    // SAFETY: the arrow dtype of `arr` matches the map storage dtype.
+    let out = unsafe {
+        Series::from_chunks_and_dtype_unchecked(keys.name().clone(), vec![arr.boxed()], &storage)
+    };
+    Ok(out.into_extension(typ))
+}
@@ -3,7 +3,11 @@ pub mod array;
 mod binary;
 #[cfg(feature = "timezones")]
 pub mod datetime;
+#[cfg(feature = "dtype-geometry")]
+mod geometry;
 pub mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 #[cfg(feature = "propagate_nans")]
 pub mod nan_propagating_aggregate;
 #[cfg(feature = "peaks")]
@@ -13,6 +17,10 @@ pub mod strings;
 mod sum;
 #[cfg(feature = "top_k")]
 mod top_k;
+#[cfg(feature = "dtype-uuid")]
+mod uuid;
+#[cfg(feature = "dtype-variant")]
+mod variant;
 
 #[cfg(feature = "mode")]
 pub mod mode;
@@ -32,9 +40,13 @@ pub use binary::*;
 pub use datetime::*;
 #[cfg(feature = "chunked_ids")]
 pub use gather::*;
+#[cfg(feature = "dtype-geometry")]
+pub use geometry::*;
 #[cfg(feature = "hist")]
 pub use hist::*;
 pub use list::*;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
 #[allow(unused_imports)]
 use polars_core::prelude::*;
 #[cfg(feature = "repeat_by")]
@@ -43,6 +55,10 @@ pub use scatter::ChunkedSet;
 pub use strings::*;
 #[cfg(feature = "top_k")]
 pub use top_k::*;
+#[cfg(feature = "dtype-variant")]
+pub use variant::*;
 
+#[cfg(feature = "dtype-uuid")]
+pub use self::uuid::*;
 #[allow(unused_imports)]
 use crate::prelude::*;
@@ -98,6 +98,10 @@ pub trait Utf8JsonPathImpl: AsString {
         infer_schema_len: Option<usize>,
     ) -> PolarsResult<Series> {
         let ca = self.as_string();
+        #[cfg(feature = "dtype-variant")]
+        if dtype.as_ref().is_some_and(|dt| dt.is_variant()) {
+            return crate::chunked_array::variant_from_json(ca);
+        }
         // Ignore extra fields instead of erroring if the dtype was explicitly given.
         let allow_extra_fields_in_struct = dtype.is_some();
         let mut needs_cast = false;
@@ -0,0 +1,55 @@
+use polars_core::prelude::*;
+use uuid::Uuid;
+
+fn uuids_to_series<I>(name: PlSmallStr, uuids: I) -> Series
+where
+    I: Iterator<Item = Uuid>,
+{
+    let bytes: Vec<[u8; 16]> = uuids.map(Uuid::into_bytes).collect();
+    let ca: BinaryChunked = bytes.iter().map(|b| b.as_slice()).collect();
+    let DataType::Extension(typ, _) = DataType::new_uuid() else {
+        unreachable!()
+    };
+    ca.with_name(name).into_series().into_extension(typ)
+}
+
+/// Generate `n` random (version 4) UUIDs.
+pub fn uuid4(name: PlSmallStr, n: usize) -> Series {
+    uuids_to_series(name, (0..n).map(|_| Uuid::new_v4()))
+}
+
+/// Generate `n` time-ordered (version 7) UUIDs.
+///
+/// UUIDs generated by the same process are monotonically increasing, so they sort in the order in
+/// which they were generated.
+pub fn uuid7(name: PlSmallStr, n: usize) -> Series {
+    uuids_to_series(name, (0..n).map(|_| Uuid::now_v7()))
+}
+
+/// Get the creation time of version 7 UUIDs, as a UTC datetime with millisecond precision.
+///
+/// Returns null for UUIDs of other versions.
+pub fn uuid_timestamp(s: &Series) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.dtype().is_uuid(),
+        InvalidOperation: "expected UUID dtype, got: {}", s.dtype()
+    );
+    let out: Int64Chunked = s
+        .to_storage()
+        .binary()?
+        .iter()
+        .map(|opt_b| {
+            let b = opt_b?;
+            // The version is stored in the high nibble of the 7th byte.
+            if b.len() != 16 || b[6] >> 4 != 7 {
+                return None;
+            }
+            let mut ms = [0u8; 8];
+            ms[2..].copy_from_slice(&b[..6]);
+            Some(i64::from_be_bytes(ms))
+        })
+        .collect_ca(s.name().clone());
+    Ok(out
+        .into_datetime(TimeUnit::Milliseconds, Some(TimeZone::UTC))
+        .into_series())
+}
@@ -0,0 +1,274 @@
+use arrow::array::ValueSize;
+use arrow::bitmap::Bitmap;
+use polars_core::prelude::*;
+use polars_json::variant::{
+    Variant, VariantPath, VariantValue, json_to_variant, write_variant_json,
+};
+
+static UTC: TimeZone = TimeZone::UTC;
+
+/// The `Struct{metadata, value}` storage of a variant.
+struct VariantStorage {
+    metadata: BinaryChunked,
+    value: BinaryChunked,
+    validity: Option<Bitmap>,
+}
+
+impl VariantStorage {
+    fn new(s: &Series) -> PolarsResult<Self> {
+        polars_ensure!(
+            s.dtype().is_variant(),
+            InvalidOperation: "expected Variant dtype, got: {}", s.dtype()
+        );
+        let ca = s.to_storage().struct_()?;
+        let fields = ca.fields_as_series();
+        Ok(Self {
+            metadata: fields[0].binary()?.clone(),
+            value: fields[1].binary()?.clone(),
+            validity: ca.rechunk_validity(),
+        })
+    }
+
+    /// Iterate over the variants, with `None` for null rows.
+    fn iter(&self) -> impl Iterator<Item = PolarsResult<Option<Variant<'_>>>> {
+        self.metadata
+            .iter()
+            .zip(self.value.iter())
+            .enumerate()
+            .map(|(i, (m, v))| {
+                let is_valid = self.validity.as_ref().is_none_or(|bm| bm.get_bit(i));
+                match (m, v) {
+                    (Some(m), Some(v)) if is_valid => Variant::try_new(m, v).map(Some),
+                    _ => Ok(None),
+                }
+            })
+    }
+}
+
+/// Create a variant from its metadata and value buffers, with `None` for null rows.
+fn variant_from_buffers<'a, I>(name: PlSmallStr, rows: I) -> PolarsResult<Series>
+where
+    I: ExactSizeIterator<Item = Option<(&'a [u8], &'a [u8])>> + Clone,
+{
+    let len = rows.len();
+    let metadata: BinaryChunked = rows
+        .clone()
+        .map(|row| row.map(|(m, _)| m))
+        .collect_ca(PlSmallStr::from_static("metadata"));
+    let value: BinaryChunked = rows
+        .clone()
+        .map(|row| row.map(|(_, v)| v))
+        .collect_ca(PlSmallStr::from_static("value"));
+    let validity =
+        (metadata.null_count() > 0).then(|| rows.map(|row| row.is_some()).collect::<Bitmap>());
+
+    let ca = StructChunked::from_series(
+        name,
+        len,
+        [metadata.into_series(), value.into_series()].iter(),
+    )?
+    .with_outer_validity(validity);
+    let DataType::Extension(typ, _) = DataType::new_variant() else {
+        unreachable!()
+    };
+    Ok(ca.into_series().into_extension(typ))
+}
+
+/// Parse every JSON string into a variant.
+pub fn variant_from_json(ca: &StringChunked) -> PolarsResult<Series> {
+    let mut scratch = vec![];
+    let rows = ca
+        .iter()
+        .map(|opt_s| {
+            opt_s
+                .map(|s| {
+                    scratch.clear();
+                    scratch.extend_from_slice(s.as_bytes());
+                    json_to_variant(&mut scratch)
+                })
+                .transpose()
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    variant_from_buffers(
+        ca.name().clone(),
+        rows.iter()
+            .map(|row| row.as_ref().map(|(m, v)| (m.as_slice(), v.as_slice()))),
+    )
+}
+
+/// Serialize every variant to a JSON string.
+pub fn variant_to_json(s: &Series) -> PolarsResult<StringChunked> {
+    let storage = VariantStorage::new(s)?;
+    let mut buf = vec![];
+    let mut builder = StringChunkedBuilder::new(s.name().clone(), s.len());
+    for v in storage.iter() {
+        match v? {
+            Some(v) => {
+                buf.clear();
+                write_variant_json(&v, &mut buf)?;
+                // SAFETY: the JSON writer only writes valid UTF-8.
+                builder.append_value(unsafe { std::str::from_utf8_unchecked(&buf) });
+            },
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Get the value at `path` of every variant, or null if the path does not exist.
+///
+/// The values are returned as variants; see [`variant_cast`] to convert them.
+pub fn variant_get(s: &Series, path: &str) -> PolarsResult<Series> {
+    let path = VariantPath::try_new(path)?;
+    let storage = VariantStorage::new(s)?;
+    let rows = storage
+        .iter()
+        .map(|v| {
+            let Some(found) = v?.map(|v| v.get_path(&path)).transpose()?.flatten() else {
+                return Ok(None);
+            };
+            Ok(Some((found.metadata().as_bytes(), found.value_bytes()?)))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    variant_from_buffers(s.name().clone(), rows.into_iter())
+}
+
+fn type_name(v: &VariantValue<'_>) -> &'static str {
+    use VariantValue as V;
+    match v {
+        V::Null => "null",
+        V::Boolean(_) => "boolean",
+        V::Int8(_) | V::Int16(_) | V::Int32(_) | V::Int64(_) => "integer",
+        V::Float(_) | V::Double(_) => "float",
+        V::Decimal { .. } => "decimal",
+        V::Date(_) => "date",
+        V::TimestampMicros(_)
+        | V::TimestampNtzMicros(_)
+        | V::TimestampNanos(_)
+        | V::TimestampNtzNanos(_) => "datetime",
+        V::TimeNtzMicros(_) => "time",
+        V::Binary(_) => "binary",
+        V::String(_) => "string",
+        V::Uuid(_) => "uuid",
+        V::Object(_) => "object",
+        V::Array(_) => "array",
+    }
+}
+
+/// Get the name of the type of every variant value, e.g. `"integer"` or `"object"`.
+pub fn variant_type_name(s: &Series) -> PolarsResult<StringChunked> {
+    let storage = VariantStorage::new(s)?;
+    let mut builder = StringChunkedBuilder::new(s.name().clone(), s.len());
+    for v in storage.iter() {
+        match v? {
+            Some(v) => builder.append_value(type_name(&v.get()?)),
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Convert a scalar variant value to an [`AnyValue`].
+///
+/// Returns `None` for objects and arrays.
+fn to_any_value<'a>(v: &VariantValue<'a>) -> Option<AnyValue<'a>> {
+    use VariantValue as V;
+    Some(match *v {
+        V::Null => AnyValue::Null,
+        V::Boolean(v) => AnyValue::Boolean(v),
+        V::Int8(v) => AnyValue::Int8(v),
+        V::Int16(v) => AnyValue::Int16(v),
+        V::Int32(v) => AnyValue::Int32(v),
+        V::Int64(v) => AnyValue::Int64(v),
+        V::Float(v) => AnyValue::Float32(v),
+        V::Double(v) => AnyValue::Float64(v),
+        V::Decimal { value, scale } => AnyValue::Decimal(value, 38, scale as usize),
+        V::Date(v) => AnyValue::Date(v),
+        V::TimestampMicros(v) => AnyValue::Datetime(v, TimeUnit::Microseconds, Some(&UTC)),
+        V::TimestampNanos(v) => AnyValue::Datetime(v, TimeUnit::Nanoseconds, Some(&UTC)),
+        V::TimestampNtzMicros(v) => AnyValue::Datetime(v, TimeUnit::Microseconds, None),
+        V::TimestampNtzNanos(v) => AnyValue::Datetime(v, TimeUnit::Nanoseconds, None),
+        V::TimeNtzMicros(v) => AnyValue::Time(v * 1000),
+        V::Binary(v) => AnyValue::Binary(v),
+        V::String(v) => AnyValue::String(v),
+        V::Uuid(v) => AnyValue::BinaryOwned(v.to_vec()),
+        V::Object(_) | V::Array(_) => return None,
+    })
+}
+
+/// Convert every variant to a string.
+///
+/// Strings are returned as-is, all other values as their JSON representation.
+fn variant_to_string(s: &Series) -> PolarsResult<StringChunked> {
+    let storage = VariantStorage::new(s)?;
+    let mut buf = vec![];
+    let mut builder = StringChunkedBuilder::new(s.name().clone(), s.len());
+    for v in storage.iter() {
+        let Some(v) = v? else {
+            builder.append_null();
+            continue;
+        };
+        match v.get()? {
+            VariantValue::Null => builder.append_null(),
+            VariantValue::String(s) => builder.append_value(s),
+            value => {
+                buf.clear();
+                write_variant_json(&v, &mut buf)?;
+                // SAFETY: the JSON writer only writes valid UTF-8.
+                let json = unsafe { std::str::from_utf8_unchecked(&buf) };
+                // Non-JSON scalars such as dates are written as JSON strings; unquote them.
+                let json = match value {
+                    VariantValue::Object(_) | VariantValue::Array(_) => json,
+                    _ => json.trim_matches('"'),
+                };
+                builder.append_value(json)
+            },
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Cast every variant to `dtype`.
+///
+/// Nested types are decoded from the JSON representation of the variants. If `strict`, values
+/// that cannot be converted raise an error, otherwise they become null.
+pub fn variant_cast(s: &Series, dtype: &DataType, strict: bool) -> PolarsResult<Series> {
+    match dtype {
+        dt if dt.is_variant() => Ok(s.clone()),
+        DataType::String => Ok(variant_to_string(s)?.into_series()),
+        dt if dt.is_nested() => {
+            let json = variant_to_json(s)?;
+            let iter = json.iter().map(|x| x.unwrap_or("null"));
+            let array = polars_json::ndjson::deserialize::deserialize_iter(
+                iter,
+                dt.to_arrow(CompatLevel::newest()),
+                json.get_values_size() + json.null_count() * "null".len(),
+                json.len(),
+                true,
+            )
+            .map_err(|e| polars_err!(ComputeError: "error converting variant to {dt}: {e}"))?;
+            let out = Series::try_from((s.name().clone(), array))?;
+            out.cast(dt)
+        },
+        dt => {
+            let storage = VariantStorage::new(s)?;
+            let values = storage
+                .iter()
+                .map(|v| {
+                    let Some(v) = v? else {
+                        return Ok(AnyValue::Null);
+                    };
+                    let value = v.get()?;
+                    match to_any_value(&value) {
+                        Some(av) => Ok(av),
+                        None if strict => polars_bail!(
+                            InvalidOperation: "cannot cast variant {} to {dt}", type_name(&value)
+                        ),
+                        None => Ok(AnyValue::Null),
+                    }
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            Series::from_any_values_and_dtype(s.name().clone(), &values, dt, strict)
+        },
+    }
+}
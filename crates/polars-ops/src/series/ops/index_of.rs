@@ -155,6 +155,10 @@ pub fn index_of(series: &Series, needle: Scalar) -> PolarsResult<Option<usize>>
         // to_physical
         #[cfg(feature = "dtype-decimal")]
         DT::Decimal(..) => unreachable!(),
+        #[cfg(feature = "dtype-interval")]
+        DT::Interval => unreachable!(),
+        #[cfg(feature = "dtype-decimal256")]
+        DT::Decimal256(..) => unreachable!(),
         #[cfg(feature = "dtype-categorical")]
         DT::Categorical(..) | DT::Enum(..) => unreachable!(),
         DT::Date | DT::Datetime(..) | DT::Duration(..) | DT::Time => unreachable!(),
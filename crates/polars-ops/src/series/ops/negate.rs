@@ -29,6 +29,19 @@ pub fn negate(s: &Series) -> PolarsResult<Series> {
             let out = ca.wrapping_neg().into_series();
             out.cast(s.dtype())?
         },
+        #[cfg(feature = "dtype-interval")]
+        Interval => {
+            let ca = s.interval().unwrap();
+            let phys: Int128Chunked = ca.physical().apply_values(|v| {
+                let v = i128_to_interval(v);
+                interval_to_i128(months_days_ns::new(
+                    v.months().wrapping_neg(),
+                    v.days().wrapping_neg(),
+                    v.ns().wrapping_neg(),
+                ))
+            });
+            phys.into_interval().into_series()
+        },
         dt => polars_bail!(opq = neg, dt),
     };
     Ok(out)
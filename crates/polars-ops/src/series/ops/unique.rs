@@ -37,7 +37,7 @@ pub fn unique_counts(s: &Series) -> PolarsResult<Series> {
 
     let mut s = Cow::Borrowed(s);
 
-    if s.dtype().is_nested() {
+    if s.dtype().is_nested() || s.dtype().is_decimal256() {
         s = Cow::Owned(encode_rows_unordered(&[s.into_owned().into_column()])?.into_series());
     }
 
@@ -133,6 +133,8 @@ pub fn unique_counts(s: &Series) -> PolarsResult<Series> {
         | DataType::Datetime(..)
         | DataType::Duration(..)
         | DataType::Time => unreachable!("primitive numeric"),
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => unreachable!("primitive numeric"),
         #[cfg(feature = "dtype-decimal")]
         DataType::Decimal(..) => unreachable!("primitive numeric"),
         #[cfg(feature = "dtype-categorical")]
@@ -141,6 +143,8 @@ pub fn unique_counts(s: &Series) -> PolarsResult<Series> {
         DataType::Array(..) => unreachable!("row encoded"),
         #[cfg(feature = "dtype-struct")]
         DataType::Struct(..) => unreachable!("row encoded"),
+        #[cfg(feature = "dtype-decimal256")]
+        DataType::Decimal256(..) => unreachable!("row encoded"),
         DataType::List(..) => {
             unreachable!("row encoded")
         },
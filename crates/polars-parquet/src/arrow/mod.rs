@@ -6,3 +6,10 @@ pub mod write;
 pub use crate::parquet::bloom_filter;
 
 const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
+
+/// The name of the Arrow canonical extension type for UUIDs, which maps to the Parquet UUID
+/// logical type.
+const ARROW_UUID_EXTENSION_NAME: &str = "arrow.uuid";
+
+/// The name of the Arrow canonical extension type for unshredded Parquet variants.
+const ARROW_VARIANT_EXTENSION_NAME: &str = "arrow.parquet.variant";
@@ -4,7 +4,7 @@ use arrow::datatypes::{
     ArrowDataType, DTYPE_CATEGORICAL_LEGACY, DTYPE_CATEGORICAL_NEW, DTYPE_ENUM_VALUES_LEGACY,
     DTYPE_ENUM_VALUES_NEW, Field, IntegerType, IntervalUnit, TimeUnit,
 };
-use arrow::types::{days_ms, i256};
+use arrow::types::{days_ms, i256, months_days_ns};
 use ethnum::I256;
 use polars_compute::cast::CastOptionsImpl;
 use polars_utils::float16::pf16;
@@ -246,6 +246,39 @@ pub fn page_iter_to_array(
 
             (nested, out, ptm)
         },
+        (PhysicalType::FixedLenByteArray(12), Interval(IntervalUnit::MonthDayNano)) => {
+            // @TODO: Make a separate decoder for this
+
+            const N_BYTES: usize = 12;
+            let (nested, array, ptm) = PageDecoder::new(
+                &field.name,
+                pages,
+                ArrowDataType::FixedSizeBinary(N_BYTES),
+                fixed_size_binary::BinaryDecoder { size: N_BYTES },
+                init_nested,
+            )?
+            .collect(filter)?;
+
+            let array = array
+                .into_iter()
+                .map(|array| {
+                    let values = array
+                        .values()
+                        .chunks_exact(N_BYTES)
+                        .map(super::super::convert_month_day_nano)
+                        .collect::<Vec<_>>();
+                    let validity = array.validity().cloned();
+                    Ok(PrimitiveArray::<months_days_ns>::try_new(
+                        dtype.clone(),
+                        values.into(),
+                        validity,
+                    )?
+                    .to_boxed())
+                })
+                .collect::<ParquetResult<Vec<Box<dyn Array>>>>()?;
+
+            (nested, array, ptm)
+        },
         (PhysicalType::FixedLenByteArray(16), UInt128) => {
             let n = 16;
             let (nested, array, ptm) = PageDecoder::new(
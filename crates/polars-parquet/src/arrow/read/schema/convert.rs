@@ -1,11 +1,14 @@
 //! This module has entry points, [`parquet_to_arrow_schema`] and the more configurable [`parquet_to_arrow_schema_with_options`].
 use std::sync::Arc;
 
-use arrow::datatypes::{ArrowDataType, ArrowSchema, Field, IntervalUnit, Metadata, TimeUnit};
+use arrow::datatypes::{
+    ArrowDataType, ArrowSchema, ExtensionType, Field, IntervalUnit, Metadata, TimeUnit,
+};
 use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::arrow::read::schema::SchemaInferenceOptions;
+use crate::arrow::{ARROW_UUID_EXTENSION_NAME, ARROW_VARIANT_EXTENSION_NAME};
 use crate::parquet::schema::Repetition;
 use crate::parquet::schema::types::{
     FieldInfo, GroupConvertedType, GroupLogicalType, IntegerType, ParquetType, PhysicalType,
@@ -179,6 +182,13 @@ fn from_fixed_len_byte_array(
     converted_type: Option<PrimitiveConvertedType>,
 ) -> ArrowDataType {
     match (logical_type, converted_type) {
+        // Precisions beyond 38 digits only fit in a 256-bit decimal.
+        (Some(PrimitiveLogicalType::Decimal(precision, scale)), _) if precision > 38 => {
+            ArrowDataType::Decimal256(precision, scale)
+        },
+        (None, Some(PrimitiveConvertedType::Decimal(precision, scale))) if precision > 38 => {
+            ArrowDataType::Decimal256(precision, scale)
+        },
         (Some(PrimitiveLogicalType::Decimal(precision, scale)), _) => {
             ArrowDataType::Decimal(precision, scale)
         },
@@ -186,7 +196,14 @@ fn from_fixed_len_byte_array(
             ArrowDataType::Decimal(precision, scale)
         },
         (None, Some(PrimitiveConvertedType::Interval)) => {
-            ArrowDataType::Interval(IntervalUnit::MonthDayMillis)
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano)
+        },
+        (Some(PrimitiveLogicalType::Uuid), _) if length == 16 => {
+            ArrowDataType::Extension(Box::new(ExtensionType {
+                name: PlSmallStr::from_static(ARROW_UUID_EXTENSION_NAME),
+                inner: ArrowDataType::FixedSizeBinary(16),
+                metadata: None,
+            }))
         },
         _ => ArrowDataType::FixedSizeBinary(length),
     }
@@ -254,10 +271,37 @@ fn non_repeated_group(
         (None, Some(GroupConvertedType::Map) | Some(GroupConvertedType::MapKeyValue)) => {
             to_map(fields, options)
         },
+        (None, None) if options.infer_variant_groups && is_variant_group(fields) => {
+            Some(ArrowDataType::Extension(Box::new(ExtensionType {
+                name: PlSmallStr::from_static(ARROW_VARIANT_EXTENSION_NAME),
+                inner: to_struct(fields, options)?,
+                metadata: None,
+            })))
+        },
         _ => to_struct(fields, options),
     }
 }
 
+/// Whether the group is an unshredded variant: a `metadata` and a `value` binary column.
+///
+/// Spark and Delta write variants in this layout without a logical type annotation, so
+/// without an `ARROW:schema` the layout is the only thing that marks the group as a variant.
+/// Ordinary structs can have the same layout, so this is only used when
+/// [`SchemaInferenceOptions::infer_variant_groups`] is set.
+fn is_variant_group(fields: &[ParquetType]) -> bool {
+    let is_binary = |field: &ParquetType, name: &str| match field {
+        ParquetType::PrimitiveType(primitive) => {
+            primitive.field_info.name == name
+                && primitive.field_info.repetition != Repetition::Repeated
+                && primitive.physical_type == PhysicalType::ByteArray
+                && primitive.logical_type.is_none()
+                && primitive.converted_type.is_none()
+        },
+        ParquetType::GroupType { .. } => false,
+    };
+    matches!(fields, [metadata, value] if is_binary(metadata, "metadata") && is_binary(value, "value"))
+}
+
 /// Converts a parquet group type to an arrow [`ArrowDataType::Struct`].
 /// Returns [`None`] if all its fields are empty
 fn to_struct(fields: &[ParquetType], options: &SchemaInferenceOptions) -> Option<ArrowDataType> {
@@ -926,6 +970,73 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_variant_group() -> PolarsResult<()> {
+        let message_type = "
+        message test_schema {
+          OPTIONAL GROUP variant {
+            REQUIRED BYTE_ARRAY metadata;
+            REQUIRED BYTE_ARRAY value;
+          }
+          OPTIONAL GROUP not_variant {
+            REQUIRED BYTE_ARRAY metadata;
+            REQUIRED BYTE_ARRAY value (UTF8);
+          }
+        }
+        ";
+        let storage = ArrowDataType::Struct(vec![
+            Field::new("metadata".into(), ArrowDataType::BinaryView, false),
+            Field::new("value".into(), ArrowDataType::BinaryView, false),
+        ]);
+        let not_variant = Field::new(
+            "not_variant".into(),
+            ArrowDataType::Struct(vec![
+                Field::new("metadata".into(), ArrowDataType::BinaryView, false),
+                Field::new("value".into(), ArrowDataType::Utf8View, false),
+            ]),
+            true,
+        );
+        let parquet_schema = SchemaDescriptor::try_from_message(message_type)?;
+
+        // Without opting in, the layout alone does not make a variant.
+        let expected = vec![
+            Field::new("variant".into(), storage.clone(), true),
+            not_variant.clone(),
+        ];
+        let fields = parquet_to_arrow_schema_with_options(
+            parquet_schema.fields(),
+            &Some(SchemaInferenceOptions {
+                infer_variant_groups: false,
+                ..Default::default()
+            }),
+        );
+        let fields = fields.iter_values().cloned().collect::<Vec<_>>();
+        assert_eq!(fields, expected);
+
+        let expected = vec![
+            Field::new(
+                "variant".into(),
+                ArrowDataType::Extension(Box::new(ExtensionType {
+                    name: PlSmallStr::from_static(ARROW_VARIANT_EXTENSION_NAME),
+                    inner: storage,
+                    metadata: None,
+                })),
+                true,
+            ),
+            not_variant,
+        ];
+        let fields = parquet_to_arrow_schema_with_options(
+            parquet_schema.fields(),
+            &Some(SchemaInferenceOptions {
+                infer_variant_groups: true,
+                ..Default::default()
+            }),
+        );
+        let fields = fields.iter_values().cloned().collect::<Vec<_>>();
+        assert_eq!(fields, expected);
+        Ok(())
+    }
+
     #[ignore]
     #[test]
     fn test_repeated_nested_schema() -> PolarsResult<()> {
@@ -1233,6 +1344,7 @@ mod tests {
                 parquet_schema.fields(),
                 &Some(SchemaInferenceOptions {
                     int96_coerce_to_timeunit: tu,
+                    ..Default::default()
                 }),
             );
             let fields = fields.iter_values().cloned().collect::<Vec<_>>();
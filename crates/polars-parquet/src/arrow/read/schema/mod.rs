@@ -23,12 +23,20 @@ pub struct SchemaInferenceOptions {
     /// (e.g. TimeUnit::Milliseconds) will result in loss of precision, but support a larger range of dates
     /// without overflowing when parsing the data.
     pub int96_coerce_to_timeunit: TimeUnit,
+
+    /// Whether to read unannotated groups of exactly a `metadata` and a `value` binary column as
+    /// unshredded variants, the layout Spark and Delta write variants in.
+    ///
+    /// This defaults to `POLARS_PARQUET_INFER_VARIANT_GROUPS`, which is off, as ordinary structs
+    /// can have the same layout.
+    pub infer_variant_groups: bool,
 }
 
 impl Default for SchemaInferenceOptions {
     fn default() -> Self {
         SchemaInferenceOptions {
             int96_coerce_to_timeunit: TimeUnit::Nanosecond,
+            infer_variant_groups: polars_config::config().parquet_infer_variant_groups(),
         }
     }
 }
@@ -5,7 +5,7 @@ use arrow::array::{
     PrimitiveArray, Utf8ViewArray,
 };
 use arrow::datatypes::{ArrowDataType, Field, IntegerType, IntervalUnit, TimeUnit};
-use arrow::types::{days_ms, i256};
+use arrow::types::{days_ms, i256, months_days_ns};
 use ethnum::I256;
 use num_traits::{AsPrimitive, FromBytes};
 use polars_utils::IdxSize;
@@ -18,7 +18,7 @@ use crate::parquet::schema::types::PhysicalType as ParquetPhysicalType;
 use crate::parquet::statistics::Statistics as ParquetStatistics;
 use crate::read::{
     ColumnChunkMetadata, PrimitiveLogicalType, convert_days_ms, convert_i128, convert_i256,
-    convert_year_month, int96_to_i64_ns,
+    convert_month_day_nano, convert_year_month, int96_to_i64_ns,
 };
 
 /// Parquet statistics for a nesting level
@@ -199,6 +199,11 @@ impl ColumnStatistics {
                 @prim Vec<u8>,
                 |x| convert_days_ms(&x)
             ),
+            (D::Interval(IntervalUnit::MonthDayNano), _) => rmap!(
+                expect_binary,
+                @prim Vec<u8>,
+                |x| convert_month_day_nano(&x)
+            ),
 
             (D::UInt8, _) => rmap!(expect_int32, @prim i32 as u8),
             (D::UInt16, _) => rmap!(expect_int32, @prim i32 as u16),
@@ -441,6 +446,12 @@ pub fn deserialize_all(
                     @prim Vec<u8>,
                     |x| convert_days_ms(&x)
                 ),
+                (D::Interval(IntervalUnit::MonthDayNano), _) => rmap!(
+                    expect_binary,
+                    MutablePrimitiveArray::<months_days_ns>,
+                    @prim Vec<u8>,
+                    |x| convert_month_day_nano(&x)
+                ),
 
                 (D::UInt8, _) => rmap!(expect_int32, MutablePrimitiveArray::<u8>, @prim i32 as u8),
                 (D::UInt16, _) => {
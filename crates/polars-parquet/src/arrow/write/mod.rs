@@ -28,7 +28,7 @@ mod utils;
 use arrow::array::*;
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::*;
-use arrow::types::{NativeType, days_ms, i256};
+use arrow::types::{NativeType, days_ms, i256, months_days_ns};
 pub use nested::{num_values, write_rep_and_def};
 pub use pages::{to_leaves, to_nested, to_parquet_leaves};
 use polars_config::config;
@@ -618,6 +618,39 @@ pub fn array_to_page_simple(
             };
             fixed_size_binary::array_to_page(&array, options, type_, statistics)
         },
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<months_days_ns>>()
+                .unwrap();
+            // Parquet stores the months, days and milliseconds of an interval as unsigned
+            // 32-bit integers, anything else would not round-trip.
+            let fits = |x: &months_days_ns| {
+                x.months() >= 0
+                    && x.days() >= 0
+                    && x.ns() % 1_000_000 == 0
+                    && u32::try_from(x.ns() / 1_000_000).is_ok()
+            };
+            if let Some(x) = array.iter().flatten().find(|x| !fits(x)) {
+                polars_bail!(
+                    InvalidOperation: "cannot write interval of {} months, {} days and {} ns to parquet, it only stores non-negative intervals with millisecond precision",
+                    x.months(), x.days(), x.ns()
+                );
+            }
+            let mut values = Vec::<u8>::with_capacity(12 * array.len());
+            array.values().iter().for_each(|x| {
+                values.extend_from_slice(&(x.months() as u32).to_le_bytes());
+                values.extend_from_slice(&(x.days() as u32).to_le_bytes());
+                values.extend_from_slice(&((x.ns() / 1_000_000) as u32).to_le_bytes());
+            });
+            let array = FixedSizeBinaryArray::new(
+                ArrowDataType::FixedSizeBinary(12),
+                values.into(),
+                array.validity().cloned(),
+            );
+            // The sort order of intervals is undefined, so they have no min/max statistics.
+            fixed_size_binary::array_to_page(&array, options, type_, None)
+        },
         ArrowDataType::FixedSizeBinary(_) => {
             let array = array.as_any().downcast_ref().unwrap();
             let statistics = if options.has_statistics() {
@@ -1153,7 +1186,10 @@ fn array_to_page_nested(
     .map(Page::Data)
 }
 
-fn get_encodings_recursive(dtype: &ArrowDataType, encodings: &mut Vec<Encoding>) {
+fn get_encodings_recursive(
+    dtype: &ArrowDataType,
+    encodings: &mut Vec<Encoding>,
+) -> PolarsResult<()> {
     use arrow::datatypes::PhysicalType::*;
     match dtype.to_physical_type() {
         Null | Boolean | Primitive(_) | Binary | FixedSizeBinary | LargeBinary | Utf8
@@ -1163,11 +1199,11 @@ fn get_encodings_recursive(dtype: &ArrowDataType, encodings: &mut Vec<Encoding>)
         List | FixedSizeList | LargeList => {
             let a = dtype.to_storage();
             if let ArrowDataType::List(inner) = a {
-                get_encodings_recursive(&inner.dtype, encodings)
+                get_encodings_recursive(&inner.dtype, encodings)?
             } else if let ArrowDataType::LargeList(inner) = a {
-                get_encodings_recursive(&inner.dtype, encodings)
+                get_encodings_recursive(&inner.dtype, encodings)?
             } else if let ArrowDataType::FixedSizeList(inner, _) = a {
-                get_encodings_recursive(&inner.dtype, encodings)
+                get_encodings_recursive(&inner.dtype, encodings)?
             } else {
                 unreachable!()
             }
@@ -1180,7 +1216,7 @@ fn get_encodings_recursive(dtype: &ArrowDataType, encodings: &mut Vec<Encoding>)
                 }
 
                 for field in fields {
-                    get_encodings_recursive(&field.dtype, encodings)
+                    get_encodings_recursive(&field.dtype, encodings)?;
                 }
             } else {
                 unreachable!()
@@ -1190,7 +1226,7 @@ fn get_encodings_recursive(dtype: &ArrowDataType, encodings: &mut Vec<Encoding>)
             if let ArrowDataType::Map(field, _) = dtype.to_storage() {
                 if let ArrowDataType::Struct(fields) = field.dtype.to_storage() {
                     for field in fields {
-                        get_encodings_recursive(&field.dtype, encodings)
+                        get_encodings_recursive(&field.dtype, encodings)?;
                     }
                 } else {
                     unreachable!()
@@ -1199,21 +1235,29 @@ fn get_encodings_recursive(dtype: &ArrowDataType, encodings: &mut Vec<Encoding>)
                 unreachable!()
             }
         },
-        Union => todo!(),
+        Union | RunEndEncoded => {
+            polars_bail!(nyi = "Writing {dtype:?} to parquet")
+        },
     }
+    Ok(())
 }
 
 /// Transverses the `dtype` up to its (parquet) columns and returns a vector of
 /// items based on `map`.
 ///
 /// This is used to assign an [`Encoding`] to every parquet column based on the columns' type (see example)
-pub fn get_dtype_encoding(dtype: &ArrowDataType) -> Vec<Encoding> {
+pub fn get_dtype_encoding(dtype: &ArrowDataType) -> PolarsResult<Vec<Encoding>> {
     let mut encodings = vec![];
-    get_encodings_recursive(dtype, &mut encodings);
-    encodings
+    get_encodings_recursive(dtype, &mut encodings)?;
+    Ok(encodings)
 }
 
 fn get_primitive_dtype_encoding(dtype: &ArrowDataType) -> Encoding {
+    // Intervals are written as fixed-size binary.
+    if let ArrowDataType::Interval(IntervalUnit::MonthDayNano) = dtype {
+        return Encoding::Plain;
+    }
+
     match dtype.to_physical_type() {
         PhysicalType::Dictionary(_)
         | PhysicalType::LargeBinary
@@ -9,7 +9,7 @@ use base64::engine::general_purpose;
 use polars_error::{PolarsResult, polars_bail};
 use polars_utils::pl_str::PlSmallStr;
 
-use super::super::ARROW_SCHEMA_META_KEY;
+use super::super::{ARROW_SCHEMA_META_KEY, ARROW_UUID_EXTENSION_NAME};
 use crate::arrow::write::decimal_length_from_precision;
 use crate::parquet::metadata::KeyValue;
 use crate::parquet::schema::Repetition;
@@ -250,7 +250,15 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             return to_parquet_type(&dict_field);
         },
         ArrowDataType::FixedSizeBinary(size) => {
-            (PhysicalType::FixedLenByteArray(*size), None, None)
+            let logical_type = match field.dtype() {
+                ArrowDataType::Extension(ext)
+                    if ext.name == ARROW_UUID_EXTENSION_NAME && *size == 16 =>
+                {
+                    Some(PrimitiveLogicalType::Uuid)
+                },
+                _ => None,
+            };
+            (PhysicalType::FixedLenByteArray(*size), None, logical_type)
         },
         ArrowDataType::Decimal(precision, scale) => {
             let precision = *precision;
@@ -0,0 +1,34 @@
+use super::*;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, PartialEq, Debug, Hash)]
+pub enum GeometryFunction {
+    Bounds,
+    Area,
+    Length,
+    Distance,
+    Contains,
+    Intersects,
+}
+
+impl Display for GeometryFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use GeometryFunction::*;
+        let name = match self {
+            Bounds => "bounds",
+            Area => "area",
+            Length => "length",
+            Distance => "distance",
+            Contains => "contains",
+            Intersects => "intersects",
+        };
+        write!(f, "st.{name}")
+    }
+}
+
+impl From<GeometryFunction> for FunctionExpr {
+    fn from(func: GeometryFunction) -> Self {
+        FunctionExpr::GeometryExpr(func)
+    }
+}
@@ -14,7 +14,11 @@ mod correlation;
 mod datetime;
 #[cfg(feature = "dtype-extension")]
 mod extension;
+#[cfg(feature = "dtype-geometry")]
+mod geometry;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 mod pow;
 #[cfg(feature = "random")]
 mod random;
@@ -30,6 +34,10 @@ mod strings;
 mod struct_;
 #[cfg(feature = "trigonometry")]
 mod trigonometry;
+#[cfg(feature = "dtype-uuid")]
+mod uuid;
+#[cfg(feature = "dtype-variant")]
+mod variant;
 
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
@@ -58,6 +66,10 @@ pub use self::cat::CategoricalFunction;
 pub use self::datetime::TemporalFunction;
 #[cfg(feature = "dtype-extension")]
 pub use self::extension::ExtensionFunction;
+#[cfg(feature = "dtype-geometry")]
+pub use self::geometry::GeometryFunction;
+#[cfg(feature = "dtype-map")]
+pub use self::map::MapFunction;
 pub use self::pow::PowFunction;
 #[cfg(feature = "range")]
 pub use self::range::{DateRangeArgs, RangeFunction};
@@ -71,6 +83,10 @@ pub use self::strings::StringFunction;
 pub use self::struct_::StructFunction;
 #[cfg(feature = "trigonometry")]
 pub use self::trigonometry::TrigonometricFunction;
+#[cfg(feature = "dtype-uuid")]
+pub use self::uuid::UuidFunction;
+#[cfg(feature = "dtype-variant")]
+pub use self::variant::VariantFunction;
 use super::*;
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -86,6 +102,14 @@ pub enum FunctionExpr {
     #[cfg(feature = "dtype-extension")]
     Extension(ExtensionFunction),
     ListExpr(ListFunction),
+    #[cfg(feature = "dtype-map")]
+    MapExpr(MapFunction),
+    #[cfg(feature = "dtype-geometry")]
+    GeometryExpr(GeometryFunction),
+    #[cfg(feature = "dtype-uuid")]
+    UuidExpr(UuidFunction),
+    #[cfg(feature = "dtype-variant")]
+    VariantExpr(VariantFunction),
     #[cfg(feature = "strings")]
     StringExpr(StringFunction),
     #[cfg(feature = "dtype-struct")]
@@ -393,6 +417,14 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "dtype-extension")]
             Extension(f) => f.hash(state),
             ListExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-geometry")]
+            GeometryExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-uuid")]
+            UuidExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-variant")]
+            VariantExpr(f) => f.hash(state),
             #[cfg(feature = "strings")]
             StringExpr(f) => f.hash(state),
             #[cfg(feature = "dtype-struct")]
@@ -694,6 +726,14 @@ impl Display for FunctionExpr {
             #[cfg(feature = "dtype-extension")]
             Extension(func) => return write!(f, "{func}"),
             ListExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-geometry")]
+            GeometryExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-uuid")]
+            UuidExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-variant")]
+            VariantExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "strings")]
             StringExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-struct")]
@@ -0,0 +1,27 @@
+use super::*;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, PartialEq, Debug, Hash)]
+pub enum UuidFunction {
+    Uuid4,
+    Uuid7,
+    Timestamp,
+}
+
+impl Display for UuidFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use UuidFunction::*;
+        match self {
+            Uuid4 => write!(f, "uuid4"),
+            Uuid7 => write!(f, "uuid7"),
+            Timestamp => write!(f, "uuid.timestamp"),
+        }
+    }
+}
+
+impl From<UuidFunction> for FunctionExpr {
+    fn from(func: UuidFunction) -> Self {
+        FunctionExpr::UuidExpr(func)
+    }
+}
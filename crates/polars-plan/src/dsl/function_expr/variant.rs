@@ -0,0 +1,30 @@
+use super::*;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[derive(Clone, PartialEq, Debug, Hash)]
+pub enum VariantFunction {
+    Get(PlSmallStr),
+    Cast { dtype: DataTypeExpr, strict: bool },
+    ToJson,
+    TypeName,
+}
+
+impl Display for VariantFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use VariantFunction::*;
+        let name = match self {
+            Get(_) => "get",
+            Cast { .. } => "cast",
+            ToJson => "to_json",
+            TypeName => "type_name",
+        };
+        write!(f, "variant.{name}")
+    }
+}
+
+impl From<VariantFunction> for FunctionExpr {
+    fn from(func: VariantFunction) -> Self {
+        FunctionExpr::VariantExpr(func)
+    }
+}
@@ -0,0 +1,40 @@
+use super::*;
+
+/// Specialized expressions for geometry dtypes.
+///
+/// All measures and predicates are planar, they don't take the coordinate reference system into
+/// account.
+pub struct GeometryNameSpace(pub(crate) Expr);
+
+impl GeometryNameSpace {
+    /// Get the bounding box of every geometry as a struct with fields `xmin`, `ymin`, `xmax` and
+    /// `ymax`.
+    pub fn bounds(self) -> Expr {
+        self.0.map_unary(GeometryFunction::Bounds)
+    }
+
+    /// Get the area of every geometry.
+    pub fn area(self) -> Expr {
+        self.0.map_unary(GeometryFunction::Area)
+    }
+
+    /// Get the length of every linear geometry.
+    pub fn length(self) -> Expr {
+        self.0.map_unary(GeometryFunction::Length)
+    }
+
+    /// Get the minimum distance to the geometries of `other`.
+    pub fn distance(self, other: Expr) -> Expr {
+        self.0.map_binary(GeometryFunction::Distance, other)
+    }
+
+    /// Check whether every geometry contains the geometry of `other`.
+    pub fn contains(self, other: Expr) -> Expr {
+        self.0.map_binary(GeometryFunction::Contains, other)
+    }
+
+    /// Check whether every geometry intersects the geometry of `other`.
+    pub fn intersects(self, other: Expr) -> Expr {
+        self.0.map_binary(GeometryFunction::Intersects, other)
+    }
+}
@@ -0,0 +1,39 @@
+use super::*;
+
+/// Specialized expressions for Map dtypes.
+pub struct MapNameSpace(pub(crate) Expr);
+
+impl MapNameSpace {
+    /// Get the value belonging to `key`, or null if the map does not contain `key`.
+    pub fn get<E: Into<Expr>>(self, key: E) -> Expr {
+        self.0.map_binary(MapFunction::Get, key.into())
+    }
+
+    /// Check whether the map contains `key`.
+    pub fn contains_key<E: Into<Expr>>(self, key: E) -> Expr {
+        self.0.map_binary(MapFunction::ContainsKey, key.into())
+    }
+
+    /// Get the keys of the map as a list.
+    pub fn keys(self) -> Expr {
+        self.0.map_unary(MapFunction::Keys)
+    }
+
+    /// Get the values of the map as a list.
+    pub fn values(self) -> Expr {
+        self.0.map_unary(MapFunction::Values)
+    }
+
+    /// Create a map from a list of keys (this expression) and a list of `values`.
+    pub fn from_lists<E: Into<Expr>>(self, values: E) -> Expr {
+        self.0.map_binary(MapFunction::FromLists, values.into())
+    }
+
+    /// Explode the map into a struct with a `key` and a `value` field per entry.
+    pub fn explode(self) -> Expr {
+        self.0.ext().storage().explode(ExplodeOptions {
+            empty_as_null: true,
+            keep_nulls: true,
+        })
+    }
+}
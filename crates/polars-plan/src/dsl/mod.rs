@@ -26,7 +26,11 @@ mod format;
 mod from;
 pub mod function_expr;
 pub mod functions;
+#[cfg(feature = "dtype-geometry")]
+mod geometry;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 mod match_to_schema;
 #[cfg(feature = "meta")]
 mod meta;
@@ -46,6 +50,10 @@ pub mod string;
 #[cfg(feature = "dtype-struct")]
 mod struct_;
 pub mod udf;
+#[cfg(feature = "dtype-uuid")]
+mod uuid;
+#[cfg(feature = "dtype-variant")]
+mod variant;
 
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -60,7 +68,11 @@ pub use expr::*;
 #[cfg(feature = "dtype-extension")]
 pub use extension::*;
 pub use function_expr::*;
+#[cfg(feature = "dtype-geometry")]
+pub use geometry::*;
 pub use list::*;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
 pub use match_to_schema::*;
 #[cfg(feature = "meta")]
 pub use meta::*;
@@ -79,6 +91,10 @@ pub use selector::{DataTypeSelector, Selector, TimeUnitSet, TimeZoneSet};
 #[cfg(feature = "dtype-struct")]
 pub use struct_::*;
 pub use udf::UserDefinedFunction;
+#[cfg(feature = "dtype-uuid")]
+pub use uuid::*;
+#[cfg(feature = "dtype-variant")]
+pub use variant::*;
 mod file_scan;
 pub use file_scan::*;
 use functions::lit;
@@ -1663,6 +1679,30 @@ impl Expr {
         extension::ExtensionNameSpace(self)
     }
 
+    /// Get the [`geometry::GeometryNameSpace`].
+    #[cfg(feature = "dtype-geometry")]
+    pub fn st(self) -> geometry::GeometryNameSpace {
+        geometry::GeometryNameSpace(self)
+    }
+
+    /// Get the [`map::MapNameSpace`].
+    #[cfg(feature = "dtype-map")]
+    pub fn map_(self) -> map::MapNameSpace {
+        map::MapNameSpace(self)
+    }
+
+    /// Get the [`uuid::UuidNameSpace`].
+    #[cfg(feature = "dtype-uuid")]
+    pub fn uuid(self) -> uuid::UuidNameSpace {
+        uuid::UuidNameSpace(self)
+    }
+
+    /// Get the [`variant::VariantNameSpace`].
+    #[cfg(feature = "dtype-variant")]
+    pub fn variant(self) -> variant::VariantNameSpace {
+        variant::VariantNameSpace(self)
+    }
+
     /// Get the [`struct_::StructNameSpace`].
     #[cfg(feature = "dtype-struct")]
     pub fn struct_(self) -> struct_::StructNameSpace {
@@ -37,7 +37,7 @@ use strum_macros::IntoStaticStr;
 
 use super::Expr;
 use crate::dsl::Selector;
-use crate::plans::ExprIR;
+use crate::plans::{DynamicPred, ExprIR};
 
 #[derive(Copy, Clone, PartialEq, Debug, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -124,6 +124,9 @@ pub struct JoinOptionsIR {
     pub force_parallel: bool,
     pub args: JoinArgs,
     pub options: Option<JoinTypeOptionsIR>,
+    /// Filters on the join keys that are set at runtime once the build side of the join is known.
+    #[cfg_attr(feature = "ir_serde", serde(default))]
+    pub runtime_filters: Option<JoinRuntimeFilters>,
 }
 
 impl From<JoinOptions> for JoinOptionsIR {
@@ -133,10 +136,24 @@ impl From<JoinOptions> for JoinOptionsIR {
             force_parallel: opts.force_parallel,
             args: opts.args,
             options: Default::default(),
+            runtime_filters: None,
         }
     }
 }
 
+/// Dynamic predicates pushed into the inputs of a join.
+///
+/// The streaming engine sets the predicate of the probe side from the keys of the build side, so
+/// rows (and batches) that cannot find a match are dropped before they reach the join.
+#[derive(Clone, Debug, Default, PartialEq, Hash)]
+#[cfg_attr(feature = "ir_serde", derive(Serialize, Deserialize))]
+pub struct JoinRuntimeFilters {
+    /// Filter on the left input, set when the right input is the build side.
+    pub left: Option<DynamicPred>,
+    /// Filter on the right input, set when the left input is the build side.
+    pub right: Option<DynamicPred>,
+}
+
 #[derive(Clone, Debug, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -333,6 +350,8 @@ impl FileWriteFormat {
             #[cfg(feature = "parquet")]
             Self::Parquet(_) => "parquet",
             #[cfg(feature = "ipc")]
+            Self::Ipc(options) if options.stream => "arrows",
+            #[cfg(feature = "ipc")]
             Self::Ipc(_) => "ipc",
             #[cfg(feature = "csv")]
             Self::Csv(_) => "csv",
@@ -10,6 +10,7 @@ use polars_core::prelude::PlHashSet;
 use polars_core::schema::Schema;
 use polars_io::cloud::CloudOptions;
 use polars_io::metrics::IOMetrics;
+use polars_io::utils::commit_protocol::CommitProtocol;
 use polars_io::utils::file::Writeable;
 use polars_io::utils::sync_on_close::SyncOnCloseType;
 use polars_utils::IdxSize;
@@ -57,6 +58,7 @@ pub enum SinkDestination {
         partition_strategy: PartitionStrategy,
         max_rows_per_file: IdxSize,
         approximate_bytes_per_file: u64,
+        commit_protocol: CommitProtocol,
     },
 }
 
@@ -261,6 +263,7 @@ pub struct PartitionedSinkOptions {
     pub unified_sink_args: UnifiedSinkArgs,
     pub max_rows_per_file: IdxSize,
     pub approximate_bytes_per_file: u64,
+    pub commit_protocol: CommitProtocol,
 }
 
 impl PartitionedSinkOptions {
@@ -298,7 +301,7 @@ pub enum PartitionStrategyIR {
     FileSize,
 }
 
-#[cfg(feature = "cse")]
+#[cfg(any(feature = "cse", feature = "result_cache"))]
 impl PartitionStrategyIR {
     pub(crate) fn traverse_and_hash<H: Hasher>(&self, expr_arena: &Arena<AExpr>, state: &mut H) {
         std::mem::discriminant(self).hash(state);
@@ -321,7 +324,7 @@ impl PartitionStrategyIR {
 }
 
 impl SinkTypeIR {
-    #[cfg(feature = "cse")]
+    #[cfg(any(feature = "cse", feature = "result_cache"))]
     pub(crate) fn traverse_and_hash<H: Hasher>(&self, expr_arena: &Arena<AExpr>, state: &mut H) {
         std::mem::discriminant(self).hash(state);
         match self {
@@ -358,6 +361,7 @@ pub struct PartitionedSinkOptionsIR {
     pub unified_sink_args: UnifiedSinkArgs,
     pub max_rows_per_file: IdxSize,
     pub approximate_bytes_per_file: u64,
+    pub commit_protocol: CommitProtocol,
 }
 
 impl PartitionedSinkOptionsIR {
@@ -419,7 +423,7 @@ impl PartitionedSinkOptionsIR {
         })
     }
 
-    #[cfg(feature = "cse")]
+    #[cfg(any(feature = "cse", feature = "result_cache"))]
     pub(crate) fn traverse_and_hash<H: Hasher>(&self, expr_arena: &Arena<AExpr>, state: &mut H) {
         let PartitionedSinkOptionsIR {
             base_path,
@@ -429,6 +433,7 @@ impl PartitionedSinkOptionsIR {
             unified_sink_args,
             max_rows_per_file,
             approximate_bytes_per_file,
+            commit_protocol,
         } = self;
 
         base_path.hash(state);
@@ -438,6 +443,7 @@ impl PartitionedSinkOptionsIR {
         unified_sink_args.hash(state);
         max_rows_per_file.hash(state);
         approximate_bytes_per_file.hash(state);
+        commit_protocol.hash(state);
     }
 }
 
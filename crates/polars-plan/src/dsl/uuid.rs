@@ -0,0 +1,27 @@
+use super::*;
+
+/// Specialized expressions for UUID dtypes.
+pub struct UuidNameSpace(pub(crate) Expr);
+
+impl UuidNameSpace {
+    /// Get the creation time of version 7 UUIDs as a UTC datetime, or null for other versions.
+    pub fn timestamp(self) -> Expr {
+        self.0.map_unary(UuidFunction::Timestamp)
+    }
+}
+
+/// Generate a random (version 4) UUID for every row in the context.
+pub fn uuid4() -> Expr {
+    functions::len()
+        .map_unary(UuidFunction::Uuid4)
+        .alias(PlSmallStr::from_static("uuid"))
+}
+
+/// Generate a time-ordered (version 7) UUID for every row in the context.
+///
+/// UUIDs generated by the same process sort in the order in which they were generated.
+pub fn uuid7() -> Expr {
+    functions::len()
+        .map_unary(UuidFunction::Uuid7)
+        .alias(PlSmallStr::from_static("uuid"))
+}
@@ -0,0 +1,65 @@
+use super::*;
+
+/// Specialized expressions for Variant dtypes.
+pub struct VariantNameSpace(pub(crate) Expr);
+
+impl VariantNameSpace {
+    /// Get the value at a JSONPath-like `path` such as `$.a.b[0]`, or null if the path does not
+    /// exist.
+    ///
+    /// The values are returned as variants; use [`VariantNameSpace::cast`] to convert them.
+    pub fn get(self, path: impl Into<PlSmallStr>) -> Expr {
+        self.0.map_unary(VariantFunction::Get(path.into()))
+    }
+
+    /// Cast the values to `dtype`.
+    ///
+    /// If `strict`, values that cannot be converted raise an error, otherwise they become null.
+    pub fn cast(self, dtype: impl Into<DataTypeExpr>, strict: bool) -> Expr {
+        self.0.map_unary(VariantFunction::Cast {
+            dtype: dtype.into(),
+            strict,
+        })
+    }
+
+    /// Serialize the values to JSON strings.
+    pub fn to_json(self) -> Expr {
+        self.0.map_unary(VariantFunction::ToJson)
+    }
+
+    /// Get the name of the type of the values, for example `"integer"`, `"string"` or
+    /// `"object"`.
+    pub fn type_name(self) -> Expr {
+        self.0.map_unary(VariantFunction::TypeName)
+    }
+
+    /// Check whether the values are objects.
+    pub fn is_object(self) -> Expr {
+        self.type_name().eq(lit("object"))
+    }
+
+    /// Check whether the values are arrays.
+    pub fn is_array(self) -> Expr {
+        self.type_name().eq(lit("array"))
+    }
+
+    /// Check whether the values are strings.
+    pub fn is_string(self) -> Expr {
+        self.type_name().eq(lit("string"))
+    }
+
+    /// Check whether the values are booleans.
+    pub fn is_boolean(self) -> Expr {
+        self.type_name().eq(lit("boolean"))
+    }
+
+    /// Check whether the values are integers, floats or decimals.
+    pub fn is_numeric(self) -> Expr {
+        let type_name = self.type_name();
+        type_name
+            .clone()
+            .eq(lit("integer"))
+            .or(type_name.clone().eq(lit("float")))
+            .or(type_name.eq(lit("decimal")))
+    }
+}
@@ -39,6 +39,13 @@ bitflags! {
         const CHECK_ORDER_OBSERVE = 1 << 15;
         /// Collapse consecutive sort nodes and pull them up through selecting nodes.
         const SORT_COLLAPSE = 1 << 16;
+        /// Reorder inner joins based on the estimated size of their inputs.
+        ///
+        /// This is off by default.
+        const JOIN_REORDER = 1 << 17;
+        /// Let the streaming engine filter the probe side of equi-joins on the keys seen on the
+        /// build side.
+        const JOIN_RUNTIME_FILTERS = 1 << 18;
     }
 }
 
@@ -78,7 +85,10 @@ impl OptFlags {
 
 impl Default for OptFlags {
     fn default() -> Self {
-        Self::from_bits_truncate(u32::MAX) & !Self::NEW_STREAMING & !Self::EAGER
+        Self::from_bits_truncate(u32::MAX)
+            & !Self::NEW_STREAMING
+            & !Self::EAGER
+            & !Self::JOIN_REORDER
     }
 }
 
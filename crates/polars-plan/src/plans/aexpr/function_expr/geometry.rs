@@ -0,0 +1,58 @@
+use super::*;
+
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum IRGeometryFunction {
+    Bounds,
+    Area,
+    Length,
+    Distance,
+    Contains,
+    Intersects,
+}
+
+impl IRGeometryFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRGeometryFunction::*;
+        match self {
+            Bounds => mapper.with_dtype(DataType::Struct(
+                ["xmin", "ymin", "xmax", "ymax"]
+                    .into_iter()
+                    .map(|name| Field::new(PlSmallStr::from_static(name), DataType::Float64))
+                    .collect(),
+            )),
+            Area | Length | Distance => mapper.with_dtype(DataType::Float64),
+            Contains | Intersects => mapper.with_dtype(DataType::Boolean),
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        use IRGeometryFunction::*;
+        match self {
+            Bounds | Area | Length | Distance | Contains | Intersects => {
+                FunctionOptions::elementwise()
+            },
+        }
+    }
+}
+
+impl Display for IRGeometryFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRGeometryFunction::*;
+        let name = match self {
+            Bounds => "bounds",
+            Area => "area",
+            Length => "length",
+            Distance => "distance",
+            Contains => "contains",
+            Intersects => "intersects",
+        };
+        write!(f, "st.{name}")
+    }
+}
+
+impl From<IRGeometryFunction> for IRFunctionExpr {
+    fn from(func: IRGeometryFunction) -> Self {
+        IRFunctionExpr::GeometryExpr(func)
+    }
+}
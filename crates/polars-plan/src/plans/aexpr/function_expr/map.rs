@@ -0,0 +1,77 @@
+use super::*;
+
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum IRMapFunction {
+    Get,
+    ContainsKey,
+    Keys,
+    Values,
+    FromLists,
+}
+
+fn map_key_value_dtypes<'a>(
+    dtype: &'a DataType,
+    op: &str,
+) -> PolarsResult<(&'a DataType, &'a DataType)> {
+    dtype
+        .map_key_value_dtypes()
+        .ok_or_else(|| polars_err!(op = op, got = dtype, expected = "Map"))
+}
+
+impl IRMapFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRMapFunction::*;
+        match self {
+            Get => mapper.try_map_dtype(|dt| Ok(map_key_value_dtypes(dt, "map.get")?.1.clone())),
+            ContainsKey => mapper.try_map_dtype(|dt| {
+                map_key_value_dtypes(dt, "map.contains_key")?;
+                Ok(DataType::Boolean)
+            }),
+            Keys => mapper.try_map_dtype(|dt| {
+                let (key, _) = map_key_value_dtypes(dt, "map.keys")?;
+                Ok(DataType::List(Box::new(key.clone())))
+            }),
+            Values => mapper.try_map_dtype(|dt| {
+                let (_, value) = map_key_value_dtypes(dt, "map.values")?;
+                Ok(DataType::List(Box::new(value.clone())))
+            }),
+            FromLists => {
+                let args = mapper.args();
+                let inner = |field: &Field| match field.dtype() {
+                    DataType::List(inner) => Ok(inner.as_ref().clone()),
+                    dt => polars_bail!(op = "map.from_lists", got = dt, expected = "List"),
+                };
+                let dtype = DataType::new_map(inner(&args[0])?, inner(&args[1])?);
+                Ok(Field::new(args[0].name().clone(), dtype))
+            },
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        use IRMapFunction::*;
+        match self {
+            Get | ContainsKey | Keys | Values | FromLists => FunctionOptions::elementwise(),
+        }
+    }
+}
+
+impl Display for IRMapFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRMapFunction::*;
+        let name = match self {
+            Get => "get",
+            ContainsKey => "contains_key",
+            Keys => "keys",
+            Values => "values",
+            FromLists => "from_lists",
+        };
+        write!(f, "map.{name}")
+    }
+}
+
+impl From<IRMapFunction> for IRFunctionExpr {
+    fn from(func: IRMapFunction) -> Self {
+        IRFunctionExpr::MapExpr(func)
+    }
+}
@@ -18,7 +18,11 @@ mod datetime;
 mod extension;
 #[cfg(feature = "fused")]
 mod fused;
+#[cfg(feature = "dtype-geometry")]
+mod geometry;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 #[cfg(feature = "ffi_plugin")]
 pub mod plugin;
 mod pow;
@@ -38,6 +42,10 @@ mod strings;
 mod struct_;
 #[cfg(feature = "trigonometry")]
 mod trigonometry;
+#[cfg(feature = "dtype-uuid")]
+mod uuid;
+#[cfg(feature = "dtype-variant")]
+mod variant;
 
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
@@ -69,6 +77,10 @@ pub use self::cat::IRCategoricalFunction;
 pub use self::datetime::IRTemporalFunction;
 #[cfg(feature = "dtype-extension")]
 pub use self::extension::IRExtensionFunction;
+#[cfg(feature = "dtype-geometry")]
+pub use self::geometry::IRGeometryFunction;
+#[cfg(feature = "dtype-map")]
+pub use self::map::IRMapFunction;
 pub use self::pow::IRPowFunction;
 #[cfg(feature = "range")]
 pub use self::range::IRRangeFunction;
@@ -85,6 +97,10 @@ pub use self::strings::TZ_AWARE_RE;
 pub use self::struct_::IRStructFunction;
 #[cfg(feature = "trigonometry")]
 pub use self::trigonometry::IRTrigonometricFunction;
+#[cfg(feature = "dtype-uuid")]
+pub use self::uuid::IRUuidFunction;
+#[cfg(feature = "dtype-variant")]
+pub use self::variant::IRVariantFunction;
 use super::*;
 use crate::plans::optimizer::DynamicPred;
 
@@ -100,6 +116,14 @@ pub enum IRFunctionExpr {
     #[cfg(feature = "dtype-extension")]
     Extension(IRExtensionFunction),
     ListExpr(IRListFunction),
+    #[cfg(feature = "dtype-map")]
+    MapExpr(IRMapFunction),
+    #[cfg(feature = "dtype-geometry")]
+    GeometryExpr(IRGeometryFunction),
+    #[cfg(feature = "dtype-uuid")]
+    UuidExpr(IRUuidFunction),
+    #[cfg(feature = "dtype-variant")]
+    VariantExpr(IRVariantFunction),
     #[cfg(feature = "strings")]
     StringExpr(IRStringFunction),
     #[cfg(feature = "dtype-struct")]
@@ -393,6 +417,11 @@ pub enum IRFunctionExpr {
     DynamicPred {
         pred: DynamicPred,
     },
+    /// Skip-batch form of a [`IRFunctionExpr::DynamicPred`], evaluated on the `min`, `max` and
+    /// `null_count` statistics of a column.
+    DynamicPredSkipBatch {
+        pred: DynamicPred,
+    },
 }
 
 impl Hash for IRFunctionExpr {
@@ -409,6 +438,14 @@ impl Hash for IRFunctionExpr {
             #[cfg(feature = "dtype-extension")]
             Extension(f) => f.hash(state),
             ListExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-geometry")]
+            GeometryExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-uuid")]
+            UuidExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-variant")]
+            VariantExpr(f) => f.hash(state),
             #[cfg(feature = "strings")]
             StringExpr(f) => f.hash(state),
             #[cfg(feature = "dtype-struct")]
@@ -695,7 +732,7 @@ impl Hash for IRFunctionExpr {
                 fs.hash(state);
                 variants.hash(state);
             },
-            DynamicPred { pred } => {
+            DynamicPred { pred } | DynamicPredSkipBatch { pred } => {
                 pred.id().hash(state);
             },
         }
@@ -715,6 +752,14 @@ impl Display for IRFunctionExpr {
             #[cfg(feature = "dtype-extension")]
             Extension(func) => return write!(f, "{func}"),
             ListExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-geometry")]
+            GeometryExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-uuid")]
+            UuidExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-variant")]
+            VariantExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "strings")]
             StringExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-struct")]
@@ -914,6 +959,7 @@ impl Display for IRFunctionExpr {
             #[cfg(feature = "dtype-struct")]
             RowDecode(..) => "row_decode",
             DynamicPred { .. } => "dynamic_predicate",
+            DynamicPredSkipBatch { .. } => "dynamic_predicate_skip_batch",
         };
         write!(f, "{s}")
     }
@@ -1013,6 +1059,14 @@ impl IRFunctionExpr {
             #[cfg(feature = "dtype-extension")]
             F::Extension(e) => e.function_options(),
             F::ListExpr(e) => e.function_options(),
+            #[cfg(feature = "dtype-map")]
+            F::MapExpr(e) => e.function_options(),
+            #[cfg(feature = "dtype-geometry")]
+            F::GeometryExpr(e) => e.function_options(),
+            #[cfg(feature = "dtype-uuid")]
+            F::UuidExpr(e) => e.function_options(),
+            #[cfg(feature = "dtype-variant")]
+            F::VariantExpr(e) => e.function_options(),
             #[cfg(feature = "strings")]
             F::StringExpr(e) => e.function_options(),
             #[cfg(feature = "dtype-struct")]
@@ -1243,7 +1297,9 @@ impl IRFunctionExpr {
             F::RowEncode(..) => FunctionOptions::elementwise(),
             #[cfg(feature = "dtype-struct")]
             F::RowDecode(..) => FunctionOptions::elementwise(),
-            F::DynamicPred { .. } => FunctionOptions::elementwise(),
+            F::DynamicPred { .. } | F::DynamicPredSkipBatch { .. } => {
+                FunctionOptions::elementwise()
+            },
         }
     }
 }
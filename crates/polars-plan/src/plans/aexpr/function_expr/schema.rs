@@ -23,6 +23,14 @@ impl IRFunctionExpr {
             #[cfg(feature = "dtype-extension")]
             Extension(func) => func.get_field(mapper),
             ListExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-geometry")]
+            GeometryExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-uuid")]
+            UuidExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-variant")]
+            VariantExpr(func) => func.get_field(mapper),
             #[cfg(feature = "strings")]
             StringExpr(s) => s.get_field(mapper),
             #[cfg(feature = "dtype-struct")]
@@ -446,7 +454,9 @@ impl IRFunctionExpr {
             }),
             #[cfg(feature = "dtype-struct")]
             RowDecode(fields, _) => mapper.with_dtype(DataType::Struct(fields.to_vec())),
-            DynamicPred { .. } => mapper.with_dtype(DataType::Boolean),
+            DynamicPred { .. } | DynamicPredSkipBatch { .. } => {
+                mapper.with_dtype(DataType::Boolean)
+            },
         }
     }
 
@@ -540,6 +550,8 @@ impl<'a> FieldsMapper<'a> {
             dt @ DataType::Time => dt.clone(),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(..) => DataType::Float64,
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(..) => DataType::Float64,
 
             // All other types get mapped to a single `null` of the same type.
             dt => dt.clone(),
@@ -0,0 +1,54 @@
+use super::*;
+
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum IRUuidFunction {
+    Uuid4,
+    Uuid7,
+    Timestamp,
+}
+
+impl IRUuidFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRUuidFunction::*;
+        match self {
+            Uuid4 | Uuid7 => mapper.with_dtype(DataType::new_uuid()),
+            Timestamp => mapper.with_dtype(DataType::Datetime(
+                TimeUnit::Milliseconds,
+                Some(TimeZone::UTC),
+            )),
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        use IRUuidFunction::*;
+        match self {
+            Uuid4 | Uuid7 => {
+                FunctionOptions::groupwise().with_flags(|f| f | FunctionFlags::ALLOW_RENAME)
+            },
+            Timestamp => FunctionOptions::elementwise(),
+        }
+    }
+
+    /// Whether this function generates new UUIDs, so that it must be evaluated every time.
+    pub fn is_generator(&self) -> bool {
+        matches!(self, Self::Uuid4 | Self::Uuid7)
+    }
+}
+
+impl Display for IRUuidFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRUuidFunction::*;
+        match self {
+            Uuid4 => write!(f, "uuid4"),
+            Uuid7 => write!(f, "uuid7"),
+            Timestamp => write!(f, "uuid.timestamp"),
+        }
+    }
+}
+
+impl From<IRUuidFunction> for IRFunctionExpr {
+    fn from(func: IRUuidFunction) -> Self {
+        IRFunctionExpr::UuidExpr(func)
+    }
+}
@@ -0,0 +1,47 @@
+use super::*;
+
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum IRVariantFunction {
+    Get(PlSmallStr),
+    Cast { dtype: DataType, strict: bool },
+    ToJson,
+    TypeName,
+}
+
+impl IRVariantFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRVariantFunction::*;
+        match self {
+            Get(_) => mapper.with_dtype(DataType::new_variant()),
+            Cast { dtype, .. } => mapper.with_dtype(dtype.clone()),
+            ToJson | TypeName => mapper.with_dtype(DataType::String),
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        use IRVariantFunction::*;
+        match self {
+            Get(_) | Cast { .. } | ToJson | TypeName => FunctionOptions::elementwise(),
+        }
+    }
+}
+
+impl Display for IRVariantFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRVariantFunction::*;
+        let name = match self {
+            Get(_) => "get",
+            Cast { .. } => "cast",
+            ToJson => "to_json",
+            TypeName => "type_name",
+        };
+        write!(f, "variant.{name}")
+    }
+}
+
+impl From<IRVariantFunction> for IRFunctionExpr {
+    fn from(func: IRVariantFunction) -> Self {
+        IRFunctionExpr::VariantExpr(func)
+    }
+}
@@ -2,7 +2,7 @@ mod builder;
 mod equality;
 mod evaluate;
 mod function_expr;
-#[cfg(feature = "cse")]
+#[cfg(any(feature = "cse", feature = "result_cache"))]
 mod hash;
 mod minterm_iter;
 pub mod predicates;
@@ -13,7 +13,7 @@ mod traverse;
 use std::hash::{Hash, Hasher};
 
 pub use function_expr::*;
-#[cfg(feature = "cse")]
+#[cfg(any(feature = "cse", feature = "result_cache"))]
 pub(super) use hash::traverse_and_hash_aexpr;
 pub use minterm_iter::MintermIter;
 use polars_compute::rolling::QuantileMethod;
@@ -64,6 +64,11 @@ pub fn aexpr_to_column_predicates(
                 is_sumwise_complete = false;
                 continue;
             },
+            #[cfg(feature = "dtype-decimal256")]
+            D::Decimal256(_, _) => {
+                is_sumwise_complete = false;
+                continue;
+            },
             #[cfg(feature = "object")]
             D::Object(_) => {
                 is_sumwise_complete = false;
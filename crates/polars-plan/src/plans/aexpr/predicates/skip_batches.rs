@@ -12,7 +12,7 @@ use super::super::evaluate::{constant_evaluate, into_column};
 use super::super::{AExpr, IRBooleanFunction, IRFunctionExpr, LiteralValue, Operator};
 use crate::plans::aexpr::builder::IntoAExprBuilder;
 use crate::plans::predicates::get_binary_expr_col_and_lv;
-use crate::plans::{AExprBuilder, aexpr_to_leaf_names_iter, is_scalar_ae, rename_columns};
+use crate::plans::{AExprBuilder, ExprIR, aexpr_to_leaf_names_iter, is_scalar_ae, rename_columns};
 
 /// Return a new boolean expression determines whether a batch can be skipped based on min, max and
 /// null count statistics.
@@ -483,6 +483,28 @@ fn aexpr_to_skip_batch_predicate_rec(
                     },
                     _ => None,
                 },
+                IRFunctionExpr::DynamicPred { pred } => {
+                    let col = into_column(input[0].node(), arena)?;
+                    let dtype = schema.get(col)?;
+                    if !can_use_min_max_stats(dtype, None, None) {
+                        return None;
+                    }
+
+                    // dynamic_pred(col(A)) -> dynamic_pred_skip_batch(min(A), max(A), null_count(A))
+                    let col = col.clone();
+                    let function = IRFunctionExpr::DynamicPredSkipBatch { pred: pred.clone() };
+                    let options = function.function_options();
+                    let input = vec![
+                        ExprIR::from_node(col!(min: col).node(), arena),
+                        ExprIR::from_node(col!(max: col).node(), arena),
+                        ExprIR::from_node(col!(null_count: col).node(), arena),
+                    ];
+                    Some(arena.add(AExpr::Function {
+                        input,
+                        function,
+                        options,
+                    }))
+                },
                 _ => None,
             },
             #[cfg(feature = "dynamic_group_by")]
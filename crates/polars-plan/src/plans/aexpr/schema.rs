@@ -1,5 +1,7 @@
 #[cfg(feature = "dtype-decimal")]
 use polars_compute::decimal::DEC128_MAX_PREC;
+#[cfg(feature = "dtype-decimal256")]
+use polars_compute::decimal256::DEC256_MAX_PREC;
 use polars_core::series::arithmetic::NumericListOp;
 use polars_utils::format_pl_smallstr;
 use recursive::recursive;
@@ -196,6 +198,8 @@ impl AExpr {
                             },
                             Boolean => Some(IDX_DTYPE),
                             UInt8 | Int8 | Int16 | UInt16 => Some(Int64),
+                            #[cfg(feature = "dtype-decimal256")]
+                            Decimal256(_, scale) => Some(Decimal256(DEC256_MAX_PREC, *scale)),
                             _ => None,
                         };
                         if let Some(dt) = dt {
@@ -578,6 +582,10 @@ fn get_arithmetic_field(
                 (Decimal(_, scale_left), Decimal(_, scale_right)) => {
                     Decimal(DEC128_MAX_PREC, *scale_left.max(scale_right))
                 },
+                #[cfg(feature = "dtype-decimal256")]
+                (l, r) if l.is_decimal256() || r.is_decimal256() => {
+                    get_decimal256_arithmetic_dtype(l, r)?
+                },
                 (left, right) => try_get_supertype(left, right)?,
             }
         },
@@ -640,6 +648,10 @@ fn get_arithmetic_field(
                 (Decimal(_, scale_left), Decimal(_, scale_right)) => {
                     Decimal(DEC128_MAX_PREC, *scale_left.max(scale_right))
                 },
+                #[cfg(feature = "dtype-decimal256")]
+                (l, r) if l.is_decimal256() || r.is_decimal256() => {
+                    get_decimal256_arithmetic_dtype(l, r)?
+                },
                 (left, right) => try_get_supertype(left, right)?,
             }
         },
@@ -691,6 +703,12 @@ fn get_arithmetic_field(
                     left_field.coerce(dtype);
                     return Ok(left_field);
                 },
+                #[cfg(feature = "dtype-decimal256")]
+                (l, r) if l.is_decimal256() || r.is_decimal256() => {
+                    let dtype = get_decimal256_arithmetic_dtype(l, r)?;
+                    left_field.coerce(dtype);
+                    return Ok(left_field);
+                },
 
                 (l @ List(a), r @ List(b))
                     if ![a, b]
@@ -753,6 +771,15 @@ fn get_arithmetic_field(
     Ok(left_field)
 }
 
+/// Decimal256 arithmetic widens to the maximum precision at the larger of both scales.
+#[cfg(feature = "dtype-decimal256")]
+fn get_decimal256_arithmetic_dtype(left: &DataType, right: &DataType) -> PolarsResult<DataType> {
+    Ok(match try_get_supertype(left, right)? {
+        DataType::Decimal256(_, scale) => DataType::Decimal256(DEC256_MAX_PREC, scale),
+        dt => dt,
+    })
+}
+
 fn get_truediv_field(left: Node, right: Node, ctx: &ToFieldContext) -> PolarsResult<Field> {
     let mut left_field = ctx.arena.get(left).to_field_impl(ctx)?;
     let right_field = ctx.arena.get(right).to_field_impl(ctx)?;
@@ -860,6 +887,8 @@ fn get_truediv_dtype(left_dtype: &DataType, right_dtype: &DataType) -> PolarsRes
         (Decimal(_, scale_left), Decimal(_, scale_right)) => {
             Decimal(DEC128_MAX_PREC, *scale_left.max(scale_right))
         },
+        #[cfg(feature = "dtype-decimal256")]
+        (l, r) if l.is_decimal256() || r.is_decimal256() => get_decimal256_arithmetic_dtype(l, r)?,
         #[cfg(all(feature = "dtype-u8", feature = "dtype-f16"))]
         (UInt8 | Int8, Float16) => Float16,
         #[cfg(all(feature = "dtype-u16", feature = "dtype-f16"))]
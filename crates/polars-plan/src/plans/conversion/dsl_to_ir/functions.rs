@@ -129,6 +129,51 @@ pub(super) fn convert_functions(
                 E::Storage => IE::Storage,
             })
         },
+        #[cfg(feature = "dtype-map")]
+        F::MapExpr(map_function) => {
+            use {IRMapFunction as IM, MapFunction as M};
+            I::MapExpr(match map_function {
+                M::Get => IM::Get,
+                M::ContainsKey => IM::ContainsKey,
+                M::Keys => IM::Keys,
+                M::Values => IM::Values,
+                M::FromLists => IM::FromLists,
+            })
+        },
+        #[cfg(feature = "dtype-geometry")]
+        F::GeometryExpr(geometry_function) => {
+            use {GeometryFunction as G, IRGeometryFunction as IG};
+            I::GeometryExpr(match geometry_function {
+                G::Bounds => IG::Bounds,
+                G::Area => IG::Area,
+                G::Length => IG::Length,
+                G::Distance => IG::Distance,
+                G::Contains => IG::Contains,
+                G::Intersects => IG::Intersects,
+            })
+        },
+        #[cfg(feature = "dtype-uuid")]
+        F::UuidExpr(uuid_function) => {
+            use {IRUuidFunction as IU, UuidFunction as U};
+            I::UuidExpr(match uuid_function {
+                U::Uuid4 => IU::Uuid4,
+                U::Uuid7 => IU::Uuid7,
+                U::Timestamp => IU::Timestamp,
+            })
+        },
+        #[cfg(feature = "dtype-variant")]
+        F::VariantExpr(variant_function) => {
+            use {IRVariantFunction as IV, VariantFunction as V};
+            I::VariantExpr(match variant_function {
+                V::Get(path) => IV::Get(path),
+                V::Cast { dtype, strict } => IV::Cast {
+                    dtype: dtype.into_datatype(ctx.schema)?,
+                    strict,
+                },
+                V::ToJson => IV::ToJson,
+                V::TypeName => IV::TypeName,
+            })
+        },
         F::ListExpr(list_function) => {
             use {IRListFunction as IL, ListFunction as L};
             I::ListExpr(match list_function {
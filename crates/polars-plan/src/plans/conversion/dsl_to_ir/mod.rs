@@ -1363,7 +1363,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                                     extension.is_none_or(|extension| extension == suffix.strip_prefix(".").unwrap_or(suffix)),
                                     InvalidOperation: "the path ({}) does not conform to standard naming, expected suffix: ({}), set `check_extension` to `False` if you don't want this behavior", path, suffix
                                 );
-                            } else if ["gz", "zst", "zstd"].iter().any(|compression_extension| {
+                            } else if ["gz", "zst", "zstd", "bz2", "xz"].iter().any(|compression_extension| {
                                 extension == Some(compression_extension)
                             }) {
                                 polars_bail!(
@@ -1383,6 +1383,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     unified_sink_args,
                     max_rows_per_file,
                     approximate_bytes_per_file,
+                    commit_protocol,
                 }) => {
                     let expr_to_ir_cx = &mut ExprToIRContext::new_with_opt_eager(
                         ctxt.expr_arena,
@@ -1425,6 +1426,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                         unified_sink_args,
                         max_rows_per_file,
                         approximate_bytes_per_file,
+                        commit_protocol,
                     };
 
                     #[cfg(feature = "parquet")]
@@ -397,6 +397,51 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IE::Storage => E::Storage,
             })
         },
+        #[cfg(feature = "dtype-map")]
+        IF::MapExpr(f) => {
+            use {IRMapFunction as IM, MapFunction as M};
+            F::MapExpr(match f {
+                IM::Get => M::Get,
+                IM::ContainsKey => M::ContainsKey,
+                IM::Keys => M::Keys,
+                IM::Values => M::Values,
+                IM::FromLists => M::FromLists,
+            })
+        },
+        #[cfg(feature = "dtype-geometry")]
+        IF::GeometryExpr(f) => {
+            use {GeometryFunction as G, IRGeometryFunction as IG};
+            F::GeometryExpr(match f {
+                IG::Bounds => G::Bounds,
+                IG::Area => G::Area,
+                IG::Length => G::Length,
+                IG::Distance => G::Distance,
+                IG::Contains => G::Contains,
+                IG::Intersects => G::Intersects,
+            })
+        },
+        #[cfg(feature = "dtype-uuid")]
+        IF::UuidExpr(f) => {
+            use {IRUuidFunction as IU, UuidFunction as U};
+            F::UuidExpr(match f {
+                IU::Uuid4 => U::Uuid4,
+                IU::Uuid7 => U::Uuid7,
+                IU::Timestamp => U::Timestamp,
+            })
+        },
+        #[cfg(feature = "dtype-variant")]
+        IF::VariantExpr(f) => {
+            use {IRVariantFunction as IV, VariantFunction as V};
+            F::VariantExpr(match f {
+                IV::Get(path) => V::Get(path),
+                IV::Cast { dtype, strict } => V::Cast {
+                    dtype: dtype.into(),
+                    strict,
+                },
+                IV::ToJson => V::ToJson,
+                IV::TypeName => V::TypeName,
+            })
+        },
         IF::ListExpr(f) => {
             use {IRListFunction as IL, ListFunction as L};
             F::ListExpr(match f {
@@ -1165,7 +1210,7 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
             fs.into_iter().map(|f| (f.name, f.dtype.into())).collect(),
             v,
         ),
-        IF::DynamicPred { pred } => {
+        IF::DynamicPred { pred } | IF::DynamicPredSkipBatch { pred } => {
             return Expr::Display {
                 inputs: input,
                 fmt_str: Box::new(format_pl_smallstr!("{pred:?}")),
@@ -12,6 +12,39 @@ macro_rules! unpack {
     }};
 }
 
+/// Calendar arithmetic of a date(time) with an interval is delegated to `dt.offset_by`, as
+/// months and days have no fixed length.
+#[cfg(feature = "dtype-interval")]
+fn offset_by_interval(
+    node_temporal: Node,
+    node_interval: Node,
+    op: Operator,
+    expr_arena: &mut Arena<AExpr>,
+) -> AExpr {
+    let node_interval = if matches!(op, Operator::Minus) {
+        let function = IRFunctionExpr::Negate;
+        let options = function.function_options();
+        expr_arena.add(AExpr::Function {
+            input: vec![ExprIR::from_node(node_interval, expr_arena)],
+            function,
+            options,
+        })
+    } else {
+        node_interval
+    };
+
+    let function = IRFunctionExpr::TemporalExpr(IRTemporalFunction::OffsetBy);
+    let options = function.function_options();
+    AExpr::Function {
+        input: vec![
+            ExprIR::from_node(node_temporal, expr_arena),
+            ExprIR::from_node(node_interval, expr_arena),
+        ],
+        function,
+        options,
+    }
+}
+
 #[allow(unused_variables)]
 fn compares_cat_to_string(type_left: &DataType, type_right: &DataType, op: Operator) -> bool {
     #[cfg(feature = "dtype-categorical")]
@@ -291,6 +324,20 @@ pub(super) fn process_binary(
             (String, a) | (a, String) if a.is_primitive_numeric() => {
                 polars_bail!(InvalidOperation: "arithmetic on string and numeric not allowed, try an explicit cast first")
             },
+            #[cfg(feature = "dtype-interval")]
+            (Date | Datetime(_, _), Interval) if matches!(op, Operator::Plus | Operator::Minus) => {
+                return Ok(Some(offset_by_interval(
+                    node_left, node_right, op, expr_arena,
+                )));
+            },
+            #[cfg(feature = "dtype-interval")]
+            (Interval, Date | Datetime(_, _)) if matches!(op, Operator::Plus) => {
+                return Ok(Some(offset_by_interval(
+                    node_right, node_left, op, expr_arena,
+                )));
+            },
+            #[cfg(feature = "dtype-interval")]
+            (Interval, _) | (_, Interval) => return Ok(None),
             (Datetime(_, _), _)
             | (_, Datetime(_, _))
             | (Date, _)
@@ -62,6 +62,28 @@ fn check_namespace(function: &IRFunctionExpr, first_dtype: &DataType) -> PolarsR
         IRFunctionExpr::StructExpr(_) => {
             polars_ensure!(matches!(first_dtype, DataType::Struct(_)), InvalidOperation: "expected Struct type, got: {}", first_dtype)
         },
+        #[cfg(feature = "dtype-map")]
+        IRFunctionExpr::MapExpr(IRMapFunction::FromLists) => {
+            polars_ensure!(matches!(first_dtype, DataType::List(_)), InvalidOperation: "expected List type, got: {}", first_dtype)
+        },
+        #[cfg(feature = "dtype-map")]
+        IRFunctionExpr::MapExpr(_) => {
+            polars_ensure!(first_dtype.is_map(), InvalidOperation: "expected Map type, got: {}", first_dtype)
+        },
+        #[cfg(feature = "dtype-geometry")]
+        IRFunctionExpr::GeometryExpr(_) => {
+            polars_ensure!(first_dtype.is_geometry(), InvalidOperation: "expected Geometry type, got: {}", first_dtype)
+        },
+        #[cfg(feature = "dtype-uuid")]
+        IRFunctionExpr::UuidExpr(IRUuidFunction::Uuid4 | IRUuidFunction::Uuid7) => {},
+        #[cfg(feature = "dtype-uuid")]
+        IRFunctionExpr::UuidExpr(_) => {
+            polars_ensure!(first_dtype.is_uuid(), InvalidOperation: "expected UUID type, got: {}", first_dtype)
+        },
+        #[cfg(feature = "dtype-variant")]
+        IRFunctionExpr::VariantExpr(_) => {
+            polars_ensure!(first_dtype.is_variant(), InvalidOperation: "expected Variant type, got: {}", first_dtype)
+        },
         #[cfg(feature = "dtype-categorical")]
         IRFunctionExpr::Categorical(_) => {
             polars_ensure!(matches!(first_dtype, DataType::Categorical(_, _)), InvalidOperation: "expected Categorical type, got: {}", first_dtype)
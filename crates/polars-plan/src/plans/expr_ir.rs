@@ -1,6 +1,6 @@
 use std::borrow::{Borrow, BorrowMut};
 use std::hash::Hash;
-#[cfg(feature = "cse")]
+#[cfg(any(feature = "cse", feature = "result_cache"))]
 use std::hash::Hasher;
 use std::sync::OnceLock;
 
@@ -267,7 +267,7 @@ impl ExprIR {
         matches!(self.output_name, OutputName::Alias(_))
     }
 
-    #[cfg(feature = "cse")]
+    #[cfg(any(feature = "cse", feature = "result_cache"))]
     pub(crate) fn traverse_and_hash<H: Hasher>(&self, expr_arena: &Arena<AExpr>, state: &mut H) {
         traverse_and_hash_aexpr(self.node, expr_arena, state);
         if let Some(alias) = self.get_alias() {
@@ -25,6 +25,8 @@ pub mod python;
 #[cfg(feature = "python")]
 pub use python::*;
 pub mod prune;
+#[cfg(feature = "result_cache")]
+pub mod result_cache;
 mod schema;
 pub mod visitor;
 
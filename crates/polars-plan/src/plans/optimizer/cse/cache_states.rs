@@ -37,7 +37,7 @@ fn get_upper_predicates(
     parent: Node,
     lp_arena: &Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
-    predicate_scratch: &mut Vec<Expr>,
+    predicate_scratch: &mut Vec<(Expr, Node)>,
 ) -> bool {
     let parent = lp_arena.get(parent);
 
@@ -45,7 +45,7 @@ fn get_upper_predicates(
     match parent {
         Filter { predicate, .. } => {
             let expr = predicate.to_expr(expr_arena);
-            predicate_scratch.push(expr);
+            predicate_scratch.push((expr, predicate.node()));
             false
         },
         SimpleProjection { .. } => true,
@@ -118,6 +118,10 @@ type TwoParents = [Option<Node>; 2];
 // - Above the filters the caches are the same -> run predicate pd from the filter node -> finish
 // - There is a cache without predicates above the cache node -> run predicate form the cache nodes -> finish
 // - The predicates above the cache nodes are all different -> remove the cache nodes -> finish
+//
+// If `share_scans` is set, the cache nodes are kept when the predicates differ. If every cache
+// node has a predicate above it, the disjunction of the predicates is pushed down instead.
+#[allow(clippy::too_many_arguments)]
 pub(super) fn set_cache_states(
     root: Node,
     lp_arena: &mut Arena<IR>,
@@ -125,7 +129,8 @@ pub(super) fn set_cache_states(
     scratch: &mut Vec<Node>,
     verbose: bool,
     pushdown_maintain_errors: bool,
-    new_streaming: bool,
+    opt_flags: &OptFlags,
+    share_scans: bool,
 ) -> PolarsResult<()> {
     let mut stack = Vec::with_capacity(4);
     let mut names_scratch = vec![];
@@ -144,6 +149,8 @@ pub(super) fn set_cache_states(
         names_union: PlHashSet<PlSmallStr>,
         // Union over predicates.
         predicate_union: PlHashMap<Expr, u32>,
+        // The distinct predicates in `predicate_union`.
+        predicate_nodes: Vec<Node>,
     }
     let mut cache_schema_and_children = BTreeMap::new();
 
@@ -217,8 +224,11 @@ pub(super) fn set_cache_states(
                         &mut predicates_scratch,
                     );
                     if !predicates_scratch.is_empty() {
-                        for pred in predicates_scratch.drain(..) {
-                            let count = v.predicate_union.entry(pred).or_insert(0);
+                        for (pred, node) in predicates_scratch.drain(..) {
+                            let count = v.predicate_union.entry(pred).or_insert_with(|| {
+                                v.predicate_nodes.push(node);
+                                0
+                            });
                             *count += 1;
                         }
                     }
@@ -256,12 +266,12 @@ pub(super) fn set_cache_states(
     // back to the cache node again
     if !cache_schema_and_children.is_empty() {
         let mut proj_pd = ProjectionPushDown::new();
-        let mut pred_pd = PredicatePushDown::new(pushdown_maintain_errors, new_streaming);
+        let mut pred_pd = PredicatePushDown::new(pushdown_maintain_errors, opt_flags);
         for (_cache_id, v) in cache_schema_and_children {
             // # CHECK IF WE NEED TO REMOVE CACHES
             // If we encounter multiple predicates we remove the cache nodes completely as we don't
             // want to loose predicate pushdown in favor of scan sharing.
-            if v.predicate_union.len() > 1 {
+            if v.predicate_union.len() > 1 && !share_scans {
                 if verbose {
                     eprintln!("cache nodes will be removed because predicates don't match")
                 }
@@ -362,6 +372,8 @@ pub(super) fn set_cache_states(
                 let (_pred, count) = v.predicate_union.iter().next().unwrap();
                 *count == v.children.len() as u32
             };
+            let allow_disjunction_pushdown = v.predicate_union.len() > 1
+                && v.predicate_union.values().sum::<u32>() == v.children.len() as u32;
 
             if allow_parent_predicate_pushdown {
                 let parents = *v.parents.first().unwrap();
@@ -369,8 +381,8 @@ pub(super) fn set_cache_states(
                     .expect("expected filter; this is an optimizer bug");
                 let start_lp = lp_arena.take(node);
 
-                let mut pred_pd = PredicatePushDown::new(pushdown_maintain_errors, new_streaming)
-                    .block_at_cache(1);
+                let mut pred_pd =
+                    PredicatePushDown::new(pushdown_maintain_errors, opt_flags).block_at_cache(1);
                 let lp = pred_pd.optimize(start_lp, lp_arena, expr_arena)?;
                 lp_arena.replace(node, lp.clone());
 
@@ -413,6 +425,31 @@ pub(super) fn set_cache_states(
 
                     lp_arena.replace(filter_node, new_lp);
                 }
+            } else if allow_disjunction_pushdown {
+                if verbose {
+                    eprintln!(
+                        "cache nodes will be kept; sharing scan with disjunction of predicates"
+                    )
+                }
+
+                // Every parent filters, so only rows matching any of the predicates are needed.
+                // The filters above the cache nodes still select the rows of each parent.
+                let predicate = v.predicate_nodes[1..]
+                    .iter()
+                    .fold(
+                        AExprBuilder::new_from_node(v.predicate_nodes[0]),
+                        |acc, &node| acc.or(node, expr_arena),
+                    )
+                    .expr_ir_unnamed();
+
+                let child = *v.children.first().unwrap();
+                let child_lp = lp_arena.take(child);
+                let input = lp_arena.add(child_lp);
+                let lp = pred_pd.optimize(IR::Filter { input, predicate }, lp_arena, expr_arena)?;
+                lp_arena.replace(child, lp.clone());
+                for &child in &v.children[1..] {
+                    lp_arena.replace(child, lp.clone());
+                }
             } else {
                 let child = *v.children.first().unwrap();
                 let child_lp = lp_arena.take(child);
@@ -422,6 +422,11 @@ impl ExprIdentifierVisitor<'_> {
                 function: IRFunctionExpr::Random { .. },
                 ..
             } => REFUSE_NO_MEMBER,
+            #[cfg(feature = "dtype-uuid")]
+            AExpr::Function {
+                function: IRFunctionExpr::UuidExpr(f),
+                ..
+            } if f.is_generator() => REFUSE_NO_MEMBER,
             #[cfg(feature = "rolling_window")]
             AExpr::Function {
                 function: IRFunctionExpr::RollingExpr { .. },
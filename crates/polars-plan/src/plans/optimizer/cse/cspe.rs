@@ -1,38 +1,12 @@
-use std::hash::{Hash, Hasher};
+use std::hash::Hash;
 
 use hashbrown::hash_map::RawEntryMut;
 use polars_utils::unique_id::UniqueId;
 
 use super::*;
+use crate::plans::visitor::Blake3Hasher;
 use crate::prelude::visitor::IRNode;
 
-struct Blake3Hasher {
-    hasher: blake3::Hasher,
-}
-
-impl Blake3Hasher {
-    fn new() -> Self {
-        Self {
-            hasher: blake3::Hasher::new(),
-        }
-    }
-
-    fn finalize(self) -> [u8; 32] {
-        self.hasher.finalize().into()
-    }
-}
-
-impl Hasher for Blake3Hasher {
-    fn finish(&self) -> u64 {
-        // Not used - we'll call finalize() instead
-        0
-    }
-
-    fn write(&mut self, bytes: &[u8]) {
-        self.hasher.update(bytes);
-    }
-}
-
 mod identifier_impl {
     use super::*;
     #[derive(Clone)]
@@ -36,6 +36,10 @@ impl CommonSubPlanOptimizer {
         verbose: bool,
         scratch: &mut Vec<Node>,
     ) -> PolarsResult<Node> {
+        // Plans collected together can share their scans through the multiplexing of the
+        // streaming engine.
+        let share_scans =
+            opt_flags.new_streaming() && matches!(ir_arena.get(root), IR::SinkMultiple { .. });
         let (root, inserted_cache, _) = cse::elim_cmn_subplans(root, ir_arena, expr_arena);
 
         run_projection_predicate_pushdown(
@@ -55,7 +59,8 @@ impl CommonSubPlanOptimizer {
                 scratch,
                 verbose,
                 pushdown_maintain_errors,
-                opt_flags.new_streaming(),
+                opt_flags,
+                share_scans,
             )?;
         }
 
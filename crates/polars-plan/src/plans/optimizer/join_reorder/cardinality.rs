@@ -0,0 +1,199 @@
+//! Rough cardinality estimates of IR nodes, used to pick a join order.
+use polars_ops::frame::JoinType;
+
+use crate::prelude::*;
+
+/// Selectivity of an equality predicate.
+const EQ_SELECTIVITY: f64 = 0.1;
+/// Selectivity of a range predicate (`<`, `<=`, `>`, `>=`).
+const RANGE_SELECTIVITY: f64 = 1.0 / 3.0;
+/// Selectivity of a predicate we know nothing about.
+const DEFAULT_SELECTIVITY: f64 = 0.5;
+
+/// The estimated size of the output of an IR node.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct RowEstimate {
+    /// Estimated number of rows.
+    pub rows: f64,
+    /// Estimated number of rows before any filters were applied. This bounds the number of
+    /// distinct values in a column of the filtered rows.
+    pub unfiltered_rows: f64,
+}
+
+impl RowEstimate {
+    fn new(rows: f64) -> Self {
+        Self {
+            rows,
+            unfiltered_rows: rows,
+        }
+    }
+
+    fn filter(self, selectivity: f64) -> Self {
+        Self {
+            rows: self.rows * selectivity,
+            unfiltered_rows: self.unfiltered_rows,
+        }
+    }
+
+    fn limit(self, len: usize) -> Self {
+        let len = len as f64;
+        Self {
+            rows: self.rows.min(len),
+            unfiltered_rows: self.unfiltered_rows.min(len),
+        }
+    }
+}
+
+/// Estimate the number of rows produced by `node`.
+///
+/// Row counts come from the heights of in-memory frames, fast counts and the row estimates of
+/// file scans (exact for parquet, which stores them in the metadata). Returns `None` if the
+/// plan reads from a source without a known size.
+pub(super) fn estimate_rows(
+    node: Node,
+    ir_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<RowEstimate> {
+    use IR::*;
+
+    let estimate = |node| estimate_rows(node, ir_arena, expr_arena);
+
+    let out = match ir_arena.get(node) {
+        DataFrameScan { df, .. } => RowEstimate::new(df.height() as f64),
+        Scan {
+            file_info,
+            predicate,
+            unified_scan_args,
+            ..
+        } => {
+            let (_, n_rows) = file_info.row_estimation;
+            if n_rows == usize::MAX {
+                return None;
+            }
+
+            let mut out = RowEstimate::new(n_rows as f64);
+            if let Some(pre_slice) = &unified_scan_args.pre_slice {
+                out = out.limit(pre_slice.len());
+            }
+            if let Some(predicate) = predicate {
+                out = out.filter(predicate_selectivity(predicate.node(), expr_arena));
+            }
+            out
+        },
+        MapFunction {
+            function: FunctionIR::FastCount { .. },
+            ..
+        } => RowEstimate::new(1.0),
+        Filter { input, predicate } => {
+            estimate(*input)?.filter(predicate_selectivity(predicate.node(), expr_arena))
+        },
+        Slice { input, len, .. } => estimate(*input)?.limit(*len as usize),
+        Sort {
+            input,
+            slice,
+            sort_options,
+            ..
+        } => {
+            let mut out = estimate(*input)?;
+            if let Some((_, len, _)) = slice {
+                out = out.limit(*len);
+            }
+            if let Some(limit) = sort_options.limit {
+                out = out.limit(limit as usize);
+            }
+            out
+        },
+        GroupBy { input, keys, .. } => {
+            if keys.is_empty() {
+                RowEstimate::new(1.0)
+            } else {
+                // Every row could be its own group.
+                RowEstimate::new(estimate(*input)?.rows)
+            }
+        },
+        Distinct { input, .. } => RowEstimate::new(estimate(*input)?.rows),
+        Join {
+            input_left,
+            input_right,
+            options,
+            ..
+        } => {
+            let left = estimate(*input_left)?.rows;
+            let right = estimate(*input_right)?.rows;
+            let rows = match options.args.how {
+                // Assume a foreign-key join: every row of the larger side finds one match.
+                JoinType::Inner => left.max(right),
+                JoinType::Right => right,
+                JoinType::Full => left + right,
+                JoinType::Cross => left * right,
+                _ => left,
+            };
+            RowEstimate::new(rows)
+        },
+        Union { inputs, .. } => {
+            let mut rows = 0.0;
+            for input in inputs {
+                rows += estimate(*input)?.rows;
+            }
+            RowEstimate::new(rows)
+        },
+        #[cfg(feature = "merge_sorted")]
+        MergeSorted {
+            input_left,
+            input_right,
+            ..
+        } => RowEstimate::new(estimate(*input_left)?.rows + estimate(*input_right)?.rows),
+        HConcat { inputs, .. } => {
+            let mut rows: f64 = 0.0;
+            for input in inputs {
+                rows = rows.max(estimate(*input)?.rows);
+            }
+            RowEstimate::new(rows)
+        },
+        SimpleProjection { input, .. }
+        | Select { input, .. }
+        | HStack { input, .. }
+        | Cache { input, .. }
+        | MapFunction { input, .. }
+        | ExtContext { input, .. }
+        | Sink { input, .. } => estimate(*input)?,
+        #[cfg(feature = "python")]
+        PythonScan { .. } => return None,
+        SinkMultiple { .. } | Invalid => return None,
+    };
+
+    Some(out)
+}
+
+/// Estimate the fraction of rows for which `predicate` holds.
+fn predicate_selectivity(node: Node, expr_arena: &Arena<AExpr>) -> f64 {
+    use Operator::*;
+
+    match expr_arena.get(node) {
+        AExpr::BinaryExpr { left, op, right } => match op {
+            And | LogicalAnd => {
+                predicate_selectivity(*left, expr_arena) * predicate_selectivity(*right, expr_arena)
+            },
+            Or | LogicalOr => {
+                let l = predicate_selectivity(*left, expr_arena);
+                let r = predicate_selectivity(*right, expr_arena);
+                l + r - l * r
+            },
+            Eq | EqValidity => EQ_SELECTIVITY,
+            NotEq | NotEqValidity => 1.0 - EQ_SELECTIVITY,
+            Lt | LtEq | Gt | GtEq => RANGE_SELECTIVITY,
+            _ => DEFAULT_SELECTIVITY,
+        },
+        AExpr::Function {
+            input,
+            function: IRFunctionExpr::Boolean(function),
+            ..
+        } => match function {
+            IRBooleanFunction::IsNull => EQ_SELECTIVITY,
+            IRBooleanFunction::IsNotNull => 1.0 - EQ_SELECTIVITY,
+            IRBooleanFunction::Not => 1.0 - predicate_selectivity(input[0].node(), expr_arena),
+            _ => DEFAULT_SELECTIVITY,
+        },
+        _ => DEFAULT_SELECTIVITY,
+    }
+}
@@ -0,0 +1,399 @@
+//! Cost-based reordering of inner joins.
+//!
+//! A tree of inner equi-joins on same-named key columns, where the only columns shared by the two
+//! sides of every join are its keys, is a natural join of its inputs. Such a join is associative
+//! and commutative, so we are free to pick any order that does not introduce a cross join. We
+//! estimate the cardinality of every intermediate result and pick the order with the smallest sum
+//! of intermediate sizes, exhaustively for small join graphs and greedily for larger ones.
+mod cardinality;
+
+use polars_core::prelude::*;
+use polars_ops::frame::{JoinType, MaintainOrderJoin};
+use polars_utils::arena::{Arena, Node};
+
+use self::cardinality::{RowEstimate, estimate_rows};
+use crate::prelude::*;
+
+/// Join graphs up to this many relations are solved exactly, larger ones greedily.
+const MAX_DP_RELATIONS: usize = 10;
+/// Relations are tracked in a `u64` bitset.
+const MAX_RELATIONS: usize = 64;
+
+/// Order in which a set of relations is joined.
+enum JoinTree {
+    Relation(usize),
+    Join(Box<JoinTree>, Box<JoinTree>),
+}
+
+impl JoinTree {
+    fn set(&self) -> u64 {
+        match self {
+            Self::Relation(i) => 1 << i,
+            Self::Join(l, r) => l.set() | r.set(),
+        }
+    }
+}
+
+struct JoinGraph {
+    relations: Vec<Node>,
+    estimates: Vec<RowEstimate>,
+    /// For every column shared by multiple relations, the set of relations containing it.
+    keys: PlIndexMap<PlSmallStr, u64>,
+}
+
+impl JoinGraph {
+    fn connected(&self, a: u64, b: u64) -> bool {
+        self.keys.values().any(|m| m & a != 0 && m & b != 0)
+    }
+
+    /// Estimated number of rows of the natural join of the relations in `set`.
+    fn rows(&self, set: u64) -> f64 {
+        let members = || (0..self.relations.len()).filter(move |i| set & (1 << i) != 0);
+        let mut log_rows: f64 = members()
+            .map(|i| self.estimates[i].rows.max(1.0).ln())
+            .sum();
+
+        // Keys shared by the same relations form a single composite key.
+        let mut seen = PlHashSet::new();
+        for mask in self.keys.values() {
+            let joined = mask & set;
+            if joined.count_ones() < 2 || !seen.insert(joined) {
+                continue;
+            }
+
+            // The key can't have more distinct values than the smallest unfiltered relation
+            // holding it, which is usually the table it is the primary key of.
+            let domain = (0..self.relations.len())
+                .filter(|i| mask & (1 << i) != 0)
+                .map(|i| self.estimates[i].unfiltered_rows)
+                .fold(f64::INFINITY, f64::min);
+            let log_ndvs = members()
+                .filter(|i| joined & (1 << i) != 0)
+                .map(|i| self.estimates[i].rows.min(domain).max(1.0).ln());
+
+            // Every relation but the one with the fewest distinct keys divides the product of the
+            // row counts by its number of distinct keys.
+            let (sum, min) = log_ndvs.fold((0.0, f64::INFINITY), |(s, m), x| (s + x, m.min(x)));
+            log_rows -= sum - min;
+        }
+
+        log_rows.exp()
+    }
+
+    /// Sum of the estimated sizes of all intermediate join results.
+    fn cost(&self, tree: &JoinTree) -> f64 {
+        match tree {
+            JoinTree::Relation(_) => 0.0,
+            JoinTree::Join(l, r) => self.cost(l) + self.cost(r) + self.rows(tree.set()),
+        }
+    }
+
+    /// Find the cheapest bushy join tree without cross joins by dynamic programming over all
+    /// subsets of relations.
+    fn best_tree_dp(&self) -> Option<JoinTree> {
+        let n = self.relations.len();
+        let full = (1u64 << n) - 1;
+
+        // Per set: the cost and the split of its cheapest tree.
+        let mut best: Vec<Option<(f64, u64)>> = vec![None; 1 << n];
+        for i in 0..n {
+            best[1 << i] = Some((0.0, 0));
+        }
+
+        for set in 1..=full {
+            if set.count_ones() < 2 {
+                continue;
+            }
+            let lowest = set & set.wrapping_neg();
+            let rows = self.rows(set);
+
+            // Enumerate the splits once by keeping the lowest relation on the left.
+            let mut left = (set - 1) & set;
+            while left != 0 {
+                let right = set ^ left;
+                if left & lowest != 0
+                    && let (Some((l_cost, _)), Some((r_cost, _))) =
+                        (best[left as usize], best[right as usize])
+                    && self.connected(left, right)
+                {
+                    let cost = l_cost + r_cost + rows;
+                    if best[set as usize].is_none_or(|(c, _)| cost < c) {
+                        best[set as usize] = Some((cost, left));
+                    }
+                }
+                left = (left - 1) & set;
+            }
+        }
+
+        fn build(best: &[Option<(f64, u64)>], set: u64) -> JoinTree {
+            let (_, left) = best[set as usize].unwrap();
+            if left == 0 {
+                JoinTree::Relation(set.trailing_zeros() as usize)
+            } else {
+                JoinTree::Join(
+                    Box::new(build(best, left)),
+                    Box::new(build(best, set ^ left)),
+                )
+            }
+        }
+
+        best[full as usize].map(|_| build(&best, full))
+    }
+
+    /// Repeatedly join the two connected trees with the smallest result.
+    fn best_tree_greedy(&self) -> Option<JoinTree> {
+        let mut trees = (0..self.relations.len())
+            .map(JoinTree::Relation)
+            .collect::<Vec<_>>();
+
+        while trees.len() > 1 {
+            let mut best: Option<(f64, usize, usize)> = None;
+            for i in 0..trees.len() {
+                for j in i + 1..trees.len() {
+                    let (a, b) = (trees[i].set(), trees[j].set());
+                    if !self.connected(a, b) {
+                        continue;
+                    }
+                    let rows = self.rows(a | b);
+                    if best.is_none_or(|(r, _, _)| rows < r) {
+                        best = Some((rows, i, j));
+                    }
+                }
+            }
+
+            let (_, i, j) = best?;
+            let right = trees.swap_remove(j);
+            let left = trees.swap_remove(i);
+            trees.push(JoinTree::Join(Box::new(left), Box::new(right)));
+        }
+
+        trees.pop()
+    }
+}
+
+/// Returns the key columns if this is an inner join that can be freely reordered with the
+/// inner joins around it.
+fn natural_join_keys(
+    ir: &IR,
+    ir_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<Vec<PlSmallStr>> {
+    let IR::Join {
+        input_left,
+        input_right,
+        left_on,
+        right_on,
+        options,
+        ..
+    } = ir
+    else {
+        return None;
+    };
+
+    let args = &options.args;
+    if !matches!(args.how, JoinType::Inner)
+        || !args.should_coalesce()
+        || args.validation.needs_checks()
+        || args.slice.is_some()
+        // The output order of an order-maintaining join depends on the join order.
+        || args.maintain_order != MaintainOrderJoin::None
+        || options.options.is_some()
+        || options.runtime_filters.is_some()
+    {
+        return None;
+    }
+
+    let mut keys = Vec::with_capacity(left_on.len());
+    for (l, r) in left_on.iter().zip(right_on) {
+        match (expr_arena.get(l.node()), expr_arena.get(r.node())) {
+            (AExpr::Column(l), AExpr::Column(r)) if l == r => keys.push(l.clone()),
+            _ => return None,
+        }
+    }
+    if keys.is_empty() {
+        return None;
+    }
+
+    // Any other shared column would get a suffix, which depends on the join order.
+    let schema_left = ir_arena.get(*input_left).schema(ir_arena);
+    let schema_right = ir_arena.get(*input_right).schema(ir_arena);
+    if schema_right
+        .iter_names()
+        .any(|name| schema_left.contains(name) && !keys.contains(name))
+    {
+        return None;
+    }
+
+    Some(keys)
+}
+
+/// Collect the inputs of the region of reorderable joins rooted at `node`.
+fn collect_relations(
+    node: Node,
+    root_options: &JoinOptionsIR,
+    ir_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    relations: &mut Vec<Node>,
+) -> JoinTree {
+    let ir = ir_arena.get(node);
+    if let IR::Join {
+        input_left,
+        input_right,
+        options,
+        ..
+    } = ir
+        && options.args.nulls_equal == root_options.args.nulls_equal
+        && natural_join_keys(ir, ir_arena, expr_arena).is_some()
+    {
+        let left = collect_relations(*input_left, root_options, ir_arena, expr_arena, relations);
+        let right = collect_relations(*input_right, root_options, ir_arena, expr_arena, relations);
+        return JoinTree::Join(Box::new(left), Box::new(right));
+    }
+
+    relations.push(node);
+    JoinTree::Relation(relations.len() - 1)
+}
+
+fn build_tree(
+    tree: &JoinTree,
+    graph: &JoinGraph,
+    options: &Arc<JoinOptionsIR>,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> Node {
+    let JoinTree::Join(l, r) = tree else {
+        let JoinTree::Relation(i) = tree else {
+            unreachable!()
+        };
+        return graph.relations[*i];
+    };
+
+    // Put the larger side on the left, the engines prefer building on the smaller side.
+    let (l, r) = if graph.rows(l.set()) >= graph.rows(r.set()) {
+        (l, r)
+    } else {
+        (r, l)
+    };
+    let left = build_tree(l, graph, options, ir_arena, expr_arena);
+    let right = build_tree(r, graph, options, ir_arena, expr_arena);
+
+    let schema_left = ir_arena.get(left).schema(ir_arena).into_owned();
+    let schema_right = ir_arena.get(right).schema(ir_arena).into_owned();
+    let keys = schema_left
+        .iter_names()
+        .filter(|name| schema_right.contains(name))
+        .map(|name| ExprIR::from_column_name(name.clone(), expr_arena))
+        .collect::<Vec<_>>();
+
+    IRBuilder::new(left, expr_arena, ir_arena)
+        .join(right, keys.clone(), keys, options.clone())
+        .node()
+}
+
+/// Try to reorder the region of inner joins rooted at `node`. Returns the inputs of the region.
+fn reorder_region(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    verbose: bool,
+) -> Vec<Node> {
+    let IR::Join { options, .. } = ir_arena.get(node) else {
+        unreachable!()
+    };
+    let options = options.clone();
+
+    let mut relations = Vec::new();
+    let original = collect_relations(node, &options, ir_arena, expr_arena, &mut relations);
+    if relations.len() < 3 || relations.len() > MAX_RELATIONS {
+        return relations;
+    }
+
+    let Some(estimates) = relations
+        .iter()
+        .map(|n| estimate_rows(*n, ir_arena, expr_arena))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return relations;
+    };
+
+    let mut keys = PlIndexMap::<PlSmallStr, u64>::new();
+    for (i, n) in relations.iter().enumerate() {
+        for name in ir_arena.get(*n).schema(ir_arena).iter_names() {
+            *keys.entry(name.clone()).or_default() |= 1 << i;
+        }
+    }
+    keys.retain(|_, mask| mask.count_ones() > 1);
+
+    let graph = JoinGraph {
+        relations,
+        estimates,
+        keys,
+    };
+
+    let best = if graph.relations.len() <= MAX_DP_RELATIONS {
+        graph.best_tree_dp()
+    } else {
+        graph.best_tree_greedy()
+    };
+    let Some(best) = best else {
+        return graph.relations;
+    };
+
+    let original_cost = graph.cost(&original);
+    let best_cost = graph.cost(&best);
+    if best_cost >= original_cost {
+        return graph.relations;
+    }
+
+    if verbose {
+        eprintln!(
+            "join reordering: reordered {} inputs, estimated cost {original_cost:.0} -> {best_cost:.0}",
+            graph.relations.len()
+        );
+    }
+
+    let original_schema = ir_arena.get(node).schema(ir_arena).into_owned();
+    let new_root = build_tree(&best, &graph, &options, ir_arena, expr_arena);
+    let new_schema = ir_arena.get(new_root).schema(ir_arena).into_owned();
+
+    // Restore the column order of the original join.
+    let ir = if new_schema.iter_names().eq(original_schema.iter_names()) {
+        ir_arena.get(new_root).clone()
+    } else {
+        IR::SimpleProjection {
+            input: new_root,
+            columns: original_schema,
+        }
+    };
+    ir_arena.replace(node, ir);
+
+    graph.relations
+}
+
+/// Reorder the inner joins in the plan based on estimated cardinalities.
+pub(super) fn optimize(
+    root: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    verbose: bool,
+) {
+    let mut stack = vec![root];
+    let mut visited_caches = PlHashSet::new();
+
+    while let Some(node) = stack.pop() {
+        let ir = ir_arena.get(node);
+
+        if let IR::Cache { id, .. } = ir
+            && !visited_caches.insert(*id)
+        {
+            continue;
+        }
+
+        if natural_join_keys(ir, ir_arena, expr_arena).is_some() {
+            let inputs = reorder_region(node, ir_arena, expr_arena, verbose);
+            stack.extend(inputs);
+        } else {
+            ir.copy_inputs(&mut stack);
+        }
+    }
+}
@@ -14,6 +14,7 @@ mod cse;
 mod flatten_union;
 #[cfg(feature = "fused")]
 mod fused;
+mod join_reorder;
 mod join_utils;
 pub(crate) use join_utils::ExprOrigin;
 mod expand_datasets;
@@ -80,7 +81,7 @@ pub(super) fn run_projection_predicate_pushdown(
     // This allows columns only needed for filters to be dropped early.
     if opt_flags.predicate_pushdown() {
         let mut predicate_pushdown_opt =
-            PredicatePushDown::new(pushdown_maintain_errors, opt_flags.new_streaming());
+            PredicatePushDown::new(pushdown_maintain_errors, opt_flags);
         let ir = ir_arena.take(root);
         let ir = predicate_pushdown_opt.optimize(ir, ir_arena, expr_arena)?;
         ir_arena.replace(root, ir);
@@ -164,6 +165,14 @@ pub fn optimize(
         rules.push(Box::new(fused::FusedArithmetic {}));
     }
 
+    // Run before predicate pushdown, which ties runtime filters to the joins as written.
+    if opt_flags.contains(OptFlags::JOIN_REORDER)
+        && !opt_flags.eager()
+        && get_or_init_members!().has_joins_or_unions
+    {
+        join_reorder::optimize(root, ir_arena, expr_arena, verbose);
+    }
+
     let run_pushdowns = if comm_subplan_elim {
         #[allow(unused_assignments)]
         let mut run_pd = true;
@@ -16,6 +16,31 @@ pub trait PredicateExpr: Send + Sync + Any {
     // included, if false it is filtered out. If None is returned it is assumed
     // all values are needed.
     fn evaluate(&self, columns: &[Column]) -> PolarsResult<Option<Column>>;
+
+    // Invariant: output column must be of type `Boolean`. If true the whole batch
+    // described by the `min`, `max` and `null_count` statistics can be skipped.
+    // If None is returned it is assumed no batch can be skipped.
+    //
+    // By default only batches where the values are constant are checked.
+    fn evaluate_skip_batch(
+        &self,
+        min: &Column,
+        max: &Column,
+        null_count: &Column,
+    ) -> PolarsResult<Option<Column>> {
+        let Some(keep) = self.evaluate(std::slice::from_ref(min))? else {
+            return Ok(None);
+        };
+
+        let is_constant = min
+            .as_materialized_series()
+            .equal(max.as_materialized_series())?;
+        let has_no_nulls = null_count.as_materialized_series().equal(0)?;
+        let drop = !keep.as_materialized_series().bool()?;
+        let skip = (is_constant & has_no_nulls) & drop;
+        let skip = skip.fill_null_with_values(false)?;
+        Ok(Some(skip.with_name(min.name().clone()).into_column()))
+    }
 }
 
 pub struct TrivialPredicateExpr;
@@ -98,6 +123,27 @@ impl DynamicPred {
             columns[0].len(),
         )))
     }
+
+    /// Evaluate whether batches can be skipped based on their `min`, `max` and `null_count`
+    /// statistics. As long as the predicate is not set no batch is skipped.
+    pub fn evaluate_skip_batch(&self, columns: &[Column]) -> PolarsResult<Column> {
+        if self.inner.is_set.load(Ordering::Acquire) {
+            let guard = self.inner.pred.read().unwrap();
+            let dyn_func = guard.as_ref().unwrap();
+            if let Some(skip) =
+                dyn_func.evaluate_skip_batch(&columns[0], &columns[1], &columns[2])?
+            {
+                return Ok(skip);
+            }
+        }
+
+        let s = Scalar::new(DataType::Boolean, AnyValue::Boolean(false));
+        Ok(Column::Scalar(ScalarColumn::new(
+            columns[0].name().clone(),
+            s,
+            columns[0].len(),
+        )))
+    }
 }
 
 pub fn new_dynamic_pred(node: Node, arena: &mut Arena<AExpr>) -> (Node, DynamicPred) {
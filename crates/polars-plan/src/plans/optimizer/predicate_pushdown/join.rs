@@ -47,13 +47,21 @@ pub(super) fn process_join(
         streaming,
     )?;
 
-    if match &options.args.how {
-        // Full-join with no coalesce. We can only push filters if they do not remove NULLs, but
-        // we don't have a reliable way to guarantee this.
-        JoinType::Full => !options.args.should_coalesce(),
+    let runtime_filter_predicates = opt
+        .join_runtime_filters
+        .then(|| {
+            try_add_runtime_filters(&mut options, &left_on, &right_on, &schema_left, expr_arena)
+        })
+        .flatten();
+
+    if runtime_filter_predicates.is_none()
+        && (match &options.args.how {
+            // Full-join with no coalesce. We can only push filters if they do not remove NULLs,
+            // but we don't have a reliable way to guarantee this.
+            JoinType::Full => !options.args.should_coalesce(),
 
-        _ => false,
-    } || acc_predicates.is_empty()
+            _ => false,
+        } || acc_predicates.is_empty())
     {
         let lp = IR::Join {
             input_left,
@@ -352,6 +360,15 @@ pub(super) fn process_join(
         }
     }
 
+    if let Some((left, right)) = runtime_filter_predicates {
+        if let Some(predicate) = left {
+            insert_predicate_dedup(&mut pushdown_left, &predicate, expr_arena);
+        }
+        if let Some(predicate) = right {
+            insert_predicate_dedup(&mut pushdown_right, &predicate, expr_arena);
+        }
+    }
+
     opt.pushdown_and_assign(input_left, pushdown_left, lp_arena, expr_arena)?;
     opt.pushdown_and_assign(input_right, pushdown_right, lp_arena, expr_arena)?;
 
@@ -384,6 +401,66 @@ pub(super) fn process_join(
     Ok(lp)
 }
 
+/// Attaches runtime filters to an equi-join on a single pair of key columns.
+///
+/// The streaming engine sets these once it has seen all keys of the build side, allowing the probe
+/// side to drop rows that cannot match. Only the inputs of which unmatched rows do not appear in
+/// the output receive a filter. Returns the predicates to push into the left and right input.
+fn try_add_runtime_filters(
+    options: &mut Arc<JoinOptionsIR>,
+    left_on: &[ExprIR],
+    right_on: &[ExprIR],
+    schema_left: &Schema,
+    expr_arena: &mut Arena<AExpr>,
+) -> Option<(Option<ExprIR>, Option<ExprIR>)> {
+    if options.runtime_filters.is_some()
+        || options.options.is_some()
+        || options.args.validation.needs_checks()
+        || left_on.len() != 1
+        || right_on.len() != 1
+    {
+        return None;
+    }
+
+    let (filter_left, filter_right) = match &options.args.how {
+        JoinType::Inner => (true, true),
+        JoinType::Left => (false, true),
+        JoinType::Right => (true, false),
+        _ => return None,
+    };
+
+    let (left_key, right_key) = (left_on[0].node(), right_on[0].node());
+    let (AExpr::Column(left_name), AExpr::Column(_)) =
+        (expr_arena.get(left_key), expr_arena.get(right_key))
+    else {
+        return None;
+    };
+
+    // Min/max statistics and hashing are only comparable on a single type.
+    if schema_left
+        .get(left_name)
+        .is_none_or(|dtype| dtype.is_nested() || dtype.is_object())
+    {
+        return None;
+    }
+
+    let mut new_filter = |key: Node| {
+        // Don't share the key node with the join, the predicate may get rewritten when pushed down.
+        let key = expr_arena.add(expr_arena.get(key).clone());
+        let (node, pred) = new_dynamic_pred(key, expr_arena);
+        (ExprIR::from_node(node, expr_arena), pred)
+    };
+    let left = filter_left.then(|| new_filter(left_key));
+    let right = filter_right.then(|| new_filter(right_key));
+
+    Arc::make_mut(options).runtime_filters = Some(JoinRuntimeFilters {
+        left: left.as_ref().map(|(_, pred)| pred.clone()),
+        right: right.as_ref().map(|(_, pred)| pred.clone()),
+    });
+
+    Some((left.map(|(e, _)| e), right.map(|(e, _)| e)))
+}
+
 /// Attempts to rewrite the join-type based on NULL-removing filters.
 ///
 /// Changing between some join types may cause the output column order to change. If this is the
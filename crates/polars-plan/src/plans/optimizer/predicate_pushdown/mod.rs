@@ -23,22 +23,27 @@ mod inner {
     use polars_utils::idx_vec::UnitVec;
     use polars_utils::unitvec;
 
+    use crate::frame::OptFlags;
+
     pub struct PredicatePushDown {
         // How many cache nodes a predicate may be pushed down to.
         // Normally this is 0. Only needed for CSPE.
         pub(super) caches_pass_allowance: u32,
         nodes_scratch: UnitVec<Node>,
         pub(super) new_streaming: bool,
+        pub(super) join_runtime_filters: bool,
         // Controls pushing filters past fallible projections
         pub(super) maintain_errors: bool,
     }
 
     impl PredicatePushDown {
-        pub fn new(maintain_errors: bool, new_streaming: bool) -> Self {
+        pub fn new(maintain_errors: bool, opt_flags: &OptFlags) -> Self {
             Self {
                 caches_pass_allowance: 0,
                 nodes_scratch: unitvec![],
-                new_streaming,
+                new_streaming: opt_flags.new_streaming(),
+                join_runtime_filters: opt_flags.new_streaming()
+                    && opt_flags.contains(OptFlags::JOIN_RUNTIME_FILTERS),
                 maintain_errors,
             }
         }
@@ -0,0 +1,419 @@
+//! Opt-in, session-level cache of query results.
+//!
+//! Subtrees of an optimized plan that only read from file paths are keyed by a hash of the
+//! subtree and the fingerprints (modification time and size, or ETag) of the files they read.
+//! When a later query contains a subtree with the same key, it is replaced by a scan of the
+//! cached result. Entries are evicted least-recently-used first to stay within a byte budget.
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+use std::time::UNIX_EPOCH;
+
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use polars_io::cloud::CloudOptions;
+use polars_utils::aliases::PlSeedableRandomStateQuality;
+use polars_utils::arena::{Arena, Node};
+use polars_utils::pl_path::PlRefPath;
+
+use crate::plans::visitor::{Blake3Hasher, IRNode};
+use crate::prelude::*;
+
+type Key = [u8; 32];
+
+static RESULT_CACHE: Mutex<Option<ResultCache>> = Mutex::new(None);
+
+struct Entry {
+    df: DataFrame,
+    bytes: usize,
+    last_used: u64,
+}
+
+struct ResultCache {
+    max_bytes: usize,
+    bytes: usize,
+    clock: u64,
+    hits: u64,
+    misses: u64,
+    entries: PlHashMap<Key, Entry>,
+}
+
+impl ResultCache {
+    fn new(max_bytes: usize) -> Self {
+        Self {
+            max_bytes,
+            bytes: 0,
+            clock: 0,
+            hits: 0,
+            misses: 0,
+            entries: PlHashMap::new(),
+        }
+    }
+
+    fn get(&mut self, key: &Key) -> Option<DataFrame> {
+        self.clock += 1;
+        let entry = self.entries.get_mut(key)?;
+        entry.last_used = self.clock;
+        Some(entry.df.clone())
+    }
+
+    fn insert(&mut self, key: Key, df: DataFrame) {
+        let bytes = df.estimated_size();
+        if bytes > self.max_bytes {
+            return;
+        }
+        if let Some(old) = self.entries.remove(&key) {
+            self.bytes -= old.bytes;
+        }
+        self.evict(self.max_bytes - bytes);
+
+        self.clock += 1;
+        self.bytes += bytes;
+        let last_used = self.clock;
+        self.entries.insert(
+            key,
+            Entry {
+                df,
+                bytes,
+                last_used,
+            },
+        );
+    }
+
+    /// Evict the least recently used entries until at most `max_bytes` are in use.
+    fn evict(&mut self, max_bytes: usize) {
+        while self.bytes > max_bytes {
+            let (key, _) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .unwrap();
+            let key = *key;
+            let entry = self.entries.remove(&key).unwrap();
+            self.bytes -= entry.bytes;
+        }
+    }
+}
+
+/// Statistics of the result cache, see [`result_cache_info`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ResultCacheInfo {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+    pub bytes: usize,
+    pub max_bytes: usize,
+}
+
+/// Enable the result cache with a budget of `max_bytes`.
+///
+/// If the cache is already enabled only its budget is updated, evicting entries if needed.
+pub fn enable_result_cache(max_bytes: usize) {
+    let mut cache = RESULT_CACHE.lock().unwrap();
+    match cache.as_mut() {
+        Some(cache) => {
+            cache.max_bytes = max_bytes;
+            cache.evict(max_bytes);
+        },
+        None => *cache = Some(ResultCache::new(max_bytes)),
+    }
+}
+
+/// Disable and clear the result cache.
+pub fn disable_result_cache() {
+    *RESULT_CACHE.lock().unwrap() = None;
+}
+
+/// Drop all entries of the result cache and reset its statistics.
+pub fn clear_result_cache() {
+    if let Some(cache) = RESULT_CACHE.lock().unwrap().as_mut() {
+        *cache = ResultCache::new(cache.max_bytes);
+    }
+}
+
+pub fn using_result_cache() -> bool {
+    RESULT_CACHE.lock().unwrap().is_some()
+}
+
+/// Statistics of the result cache, or `None` if it is disabled.
+pub fn result_cache_info() -> Option<ResultCacheInfo> {
+    RESULT_CACHE
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|cache| ResultCacheInfo {
+            hits: cache.hits,
+            misses: cache.misses,
+            entries: cache.entries.len(),
+            bytes: cache.bytes,
+            max_bytes: cache.max_bytes,
+        })
+}
+
+/// Rewrite the plan under `root` to use the result cache, if it is enabled.
+///
+/// Cached subtrees are replaced by a scan of their result. The maximal cacheable subtrees that
+/// are not cached yet store their result in the cache once they are executed. Storing a result
+/// collects it in memory, so subtrees that feed into a file or callback sink are only looked up
+/// and never stored, which keeps those queries streaming.
+pub fn apply_result_cache(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<()> {
+    if !using_result_cache() {
+        return Ok(());
+    }
+
+    // Computing the keys fingerprints the scanned files, which can take network round trips, so
+    // do it before taking the lock.
+    let mut keys = PlHashMap::new();
+    subtree_key(root, lp_arena, expr_arena, &mut keys);
+
+    let mut cache = RESULT_CACHE.lock().unwrap();
+    let Some(cache) = cache.as_mut() else {
+        return Ok(());
+    };
+
+    let mut visited = PlHashSet::new();
+    let mut stack = vec![(root, false)];
+    while let Some((node, streaming_sink)) = stack.pop() {
+        // Common subplan elimination can turn the plan into a DAG.
+        if !visited.insert(node) {
+            continue;
+        }
+
+        let Some(key) = keys[&node] else {
+            let ir = lp_arena.get(node);
+            let streaming_sink = streaming_sink
+                || matches!(ir, IR::Sink { payload, .. } if !matches!(payload, SinkTypeIR::Memory));
+            stack.extend(ir.inputs().map(|input| (input, streaming_sink)));
+            continue;
+        };
+
+        if let Some(df) = cache.get(&key) {
+            cache.hits += 1;
+            let schema = lp_arena.get(node).schema(lp_arena).into_owned();
+            lp_arena.replace(
+                node,
+                IR::DataFrameScan {
+                    df: Arc::new(df),
+                    schema,
+                    output_schema: None,
+                },
+            );
+        } else if !streaming_sink {
+            cache.misses += 1;
+            let input = lp_arena.duplicate(node);
+            let store = move |df: DataFrame| {
+                if let Some(cache) = RESULT_CACHE.lock().unwrap().as_mut() {
+                    cache.insert(key, df.clone());
+                }
+                Ok(df)
+            };
+            lp_arena.replace(
+                node,
+                IR::MapFunction {
+                    input,
+                    function: FunctionIR::Opaque {
+                        function: Arc::new(store),
+                        schema: None,
+                        predicate_pd: false,
+                        projection_pd: false,
+                        streamable: false,
+                        fmt_str: PlSmallStr::from_static("RESULT_CACHE_STORE"),
+                    },
+                },
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Compute the cache key of every subtree under `node`, `None` if it cannot be cached.
+fn subtree_key(
+    node: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    keys: &mut PlHashMap<Node, Option<Key>>,
+) -> Option<Key> {
+    if let Some(key) = keys.get(&node) {
+        return *key;
+    }
+
+    let ir = lp_arena.get(node);
+    let mut cacheable = is_cacheable(ir, expr_arena);
+    let mut hasher = Blake3Hasher::new();
+    for input in ir.inputs() {
+        match subtree_key(input, lp_arena, expr_arena, keys) {
+            Some(key) => key.hash(&mut hasher),
+            None => cacheable = false,
+        }
+    }
+
+    let key = if cacheable {
+        node_key(node, lp_arena, expr_arena, hasher)
+    } else {
+        None
+    };
+    keys.insert(node, key);
+    key
+}
+
+/// Finish the key of `node`, given a hasher that has been fed the keys of its inputs.
+fn node_key(
+    node: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    mut hasher: Blake3Hasher,
+) -> Option<Key> {
+    let ir = lp_arena.get(node);
+    IRNode::new(node)
+        .hashable_and_cmp(lp_arena, expr_arena)
+        .hash_as_equality()
+        .hash(&mut hasher);
+    // The node hash does not cover all of the schema, e.g. of a projected scan.
+    ir.schema(lp_arena).hash(&mut hasher);
+    for e in ir.exprs() {
+        hash_series_literals(e.node(), expr_arena, &mut hasher)?;
+    }
+    if let IR::Scan {
+        sources,
+        unified_scan_args,
+        ..
+    } = ir
+    {
+        for path in sources.as_paths()? {
+            path.hash(&mut hasher);
+            file_fingerprint(path, unified_scan_args.cloud_options.as_ref(), &mut hasher).ok()?;
+        }
+    }
+    Some(hasher.finalize())
+}
+
+/// Whether the result of `ir` only depends on its inputs and is deterministic.
+fn is_cacheable(ir: &IR, expr_arena: &Arena<AExpr>) -> bool {
+    let node_ok = match ir {
+        // Only file paths can be fingerprinted, in-memory frames are hashed by address.
+        IR::Scan { sources, .. } => sources.is_paths(),
+        IR::DataFrameScan { .. }
+        | IR::Cache { .. }
+        | IR::Sink { .. }
+        | IR::SinkMultiple { .. }
+        | IR::ExtContext { .. }
+        | IR::Invalid => false,
+        #[cfg(feature = "python")]
+        IR::PythonScan { .. } => false,
+        IR::GroupBy { apply, .. } => apply.is_none(),
+        IR::MapFunction { function, .. } => {
+            !matches!(
+                function,
+                FunctionIR::Opaque { .. } | FunctionIR::FastCount { .. }
+            ) && !is_python_function(function)
+        },
+        _ => true,
+    };
+    node_ok
+        && ir.exprs().all(|e| {
+            !expr_arena
+                .iter(e.node())
+                .any(|(_, ae)| is_nondeterministic(ae))
+        })
+}
+
+fn is_nondeterministic(ae: &AExpr) -> bool {
+    match ae {
+        AExpr::AnonymousFunction { .. } => true,
+        #[cfg(feature = "random")]
+        AExpr::Function {
+            function: IRFunctionExpr::Random { .. },
+            ..
+        } => true,
+        _ => false,
+    }
+}
+
+#[cfg(feature = "python")]
+fn is_python_function(function: &FunctionIR) -> bool {
+    matches!(function, FunctionIR::OpaquePython(_))
+}
+
+#[cfg(not(feature = "python"))]
+fn is_python_function(_function: &FunctionIR) -> bool {
+    false
+}
+
+/// The IR only hashes a sample of `Series` literals, so hash them in full.
+fn hash_series_literals(
+    node: Node,
+    expr_arena: &Arena<AExpr>,
+    hasher: &mut Blake3Hasher,
+) -> Option<()> {
+    let mut buf = Vec::new();
+    for (_, ae) in expr_arena.iter(node) {
+        if let AExpr::Literal(LiteralValue::Series(s)) = ae {
+            buf.clear();
+            s.vec_hash(PlSeedableRandomStateQuality::fixed(), &mut buf)
+                .ok()?;
+            buf.hash(hasher);
+        }
+    }
+    Some(())
+}
+
+#[cfg_attr(not(feature = "cloud"), allow(unused_variables))]
+fn file_fingerprint(
+    path: &PlRefPath,
+    cloud_options: Option<&CloudOptions>,
+    hasher: &mut Blake3Hasher,
+) -> PolarsResult<()> {
+    if !path.has_scheme() {
+        let metadata = std::fs::metadata(path.as_std_path())?;
+        let modified = metadata
+            .modified()?
+            .duration_since(UNIX_EPOCH)
+            .map_err(to_compute_err)?;
+        modified.as_nanos().hash(hasher);
+        metadata.len().hash(hasher);
+        return Ok(());
+    }
+
+    #[cfg(feature = "cloud")]
+    {
+        use polars_io::cloud::{CloudLocation, build_object_store, object_path_from_str};
+
+        let path = path.clone();
+        let metadata = polars_io::pl_async::get_runtime().block_in_place_on(async move {
+            let (CloudLocation { prefix, .. }, object_store) =
+                build_object_store(path, cloud_options, false).await?;
+            object_store.head(&object_path_from_str(&prefix)?).await
+        })?;
+        metadata.e_tag.hash(hasher);
+        metadata.last_modified.timestamp_nanos_opt().hash(hasher);
+        metadata.size.hash(hasher);
+        Ok(())
+    }
+    #[cfg(not(feature = "cloud"))]
+    polars_bail!(ComputeError: "cannot fingerprint cloud path '{}'", path.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evict_least_recently_used() {
+        let df = |n: i32| DataFrame::new_infer_height(vec![Column::new("a".into(), vec![n; 8])]);
+        let bytes = df(0).unwrap().estimated_size();
+
+        let mut cache = ResultCache::new(2 * bytes);
+        cache.insert([0; 32], df(0).unwrap());
+        cache.insert([1; 32], df(1).unwrap());
+        assert!(cache.get(&[0; 32]).is_some());
+        cache.insert([2; 32], df(2).unwrap());
+
+        assert!(cache.get(&[0; 32]).is_some());
+        assert!(cache.get(&[1; 32]).is_none());
+        assert!(cache.get(&[2; 32]).is_some());
+        assert_eq!(cache.bytes, 2 * bytes);
+    }
+}
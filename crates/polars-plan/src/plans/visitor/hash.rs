@@ -10,6 +10,34 @@ use crate::plans::{AExpr, IR};
 use crate::prelude::aexpr::traverse_and_hash_aexpr;
 use crate::prelude::{ExprIR, PlanCallback};
 
+/// Hasher with a stable, collision resistant 256-bit output, used to key plans.
+pub(crate) struct Blake3Hasher {
+    hasher: blake3::Hasher,
+}
+
+impl Blake3Hasher {
+    pub(crate) fn new() -> Self {
+        Self {
+            hasher: blake3::Hasher::new(),
+        }
+    }
+
+    pub(crate) fn finalize(self) -> [u8; 32] {
+        self.hasher.finalize().into()
+    }
+}
+
+impl Hasher for Blake3Hasher {
+    fn finish(&self) -> u64 {
+        // Not used - we'll call finalize() instead
+        0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.hasher.update(bytes);
+    }
+}
+
 impl IRNode {
     pub(crate) fn hashable_and_cmp<'a>(
         &'a self,
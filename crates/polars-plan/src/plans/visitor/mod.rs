@@ -2,12 +2,14 @@
 
 use arrow::legacy::error::PolarsResult;
 mod expr;
-#[cfg(feature = "cse")]
+#[cfg(any(feature = "cse", feature = "result_cache"))]
 mod hash;
 mod lp;
 mod visitors;
 
 pub use expr::*;
+#[cfg(any(feature = "cse", feature = "result_cache"))]
+pub(crate) use hash::Blake3Hasher;
 pub use lp::*;
 pub use visitors::*;
 
@@ -210,6 +210,8 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(functions::concat_str))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::len)).unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::uuid4)).unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::uuid7)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::cov)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::cum_fold))
         .unwrap();
@@ -298,6 +300,21 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::using_string_cache))
         .unwrap();
+    #[cfg(feature = "result_cache")]
+    m.add_wrapped(wrap_pyfunction!(functions::enable_result_cache))
+        .unwrap();
+    #[cfg(feature = "result_cache")]
+    m.add_wrapped(wrap_pyfunction!(functions::disable_result_cache))
+        .unwrap();
+    #[cfg(feature = "result_cache")]
+    m.add_wrapped(wrap_pyfunction!(functions::clear_result_cache))
+        .unwrap();
+    #[cfg(feature = "result_cache")]
+    m.add_wrapped(wrap_pyfunction!(functions::using_result_cache))
+        .unwrap();
+    #[cfg(feature = "result_cache")]
+    m.add_wrapped(wrap_pyfunction!(functions::result_cache_info))
+        .unwrap();
 
     // Numeric formatting
     m.add_wrapped(wrap_pyfunction!(functions::get_thousands_separator))
@@ -324,6 +341,10 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     // Functions - misc
     m.add_wrapped(wrap_pyfunction!(functions::dtype_str_repr))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::serialize_enum_categories))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::deserialize_enum_categories))
+        .unwrap();
     #[cfg(feature = "object")]
     m.add_wrapped(wrap_pyfunction!(functions::__register_startup_deps))
         .unwrap();
@@ -12,8 +12,9 @@ use polars::chunked_array::object::PolarsObjectSafe;
 #[cfg(feature = "object")]
 use polars::datatypes::OwnedObject;
 use polars::datatypes::{DataType, Field, TimeUnit};
-use polars::prelude::{AnyValue, PlSmallStr, Series, TimeZone};
+use polars::prelude::{AnyValue, PlSmallStr, Series, TimeZone, i256};
 use polars_compute::decimal::{DEC128_MAX_PREC, DecimalFmtBuffer, dec128_fits};
+use polars_compute::decimal256::{DEC256_MAX_PREC, str_to_dec256};
 use polars_core::utils::any_values_to_supertype_and_n_dtypes;
 use polars_core::utils::arrow::temporal_conversions::date32_to_date;
 use polars_utils::aliases::PlFixedStateQuality;
@@ -106,6 +107,7 @@ pub(crate) fn any_value_into_py_object<'py>(
             time_delta.into_bound_py_any(py)
         },
         AnyValue::Time(v) => nanos_since_midnight_to_naivetime(v).into_bound_py_any(py),
+        AnyValue::Interval(v) => (v.months(), v.days(), v.ns()).into_bound_py_any(py),
         AnyValue::Array(v, _) | AnyValue::List(v) => PySeries::new(v).to_list(py),
         ref av @ AnyValue::Struct(_, _, flds) => {
             Ok(struct_dict(py, av._iter_struct_av(), flds)?.into_any())
@@ -131,6 +133,12 @@ pub(crate) fn any_value_into_py_object<'py>(
             let s = buf.format_dec128(v, scale, false, false);
             convert.call1((prec, s))
         },
+        AnyValue::Decimal256(v, prec, scale) => {
+            let convert = utils.getattr(intern!(py, "to_py_decimal"))?;
+            let mut buf = DecimalFmtBuffer::new();
+            let s = buf.format_dec256(v, scale, false, false);
+            convert.call1((prec, s))
+        },
     }
 }
 
@@ -331,21 +339,42 @@ pub(crate) fn py_object_to_any_value(
             dec128_fits(v, DEC128_MAX_PREC).then_some((v, scale))
         }
 
+        /// Values beyond 38 digits are parsed into a Decimal256 instead.
+        fn abs_decimal256_from_digits(digits: &[u8], exp: i32) -> Option<(i256, usize)> {
+            let mut buf: Vec<u8> = digits.iter().map(|d| b'0' + d).collect();
+            let scale = if exp > 0 {
+                buf.extend(std::iter::repeat_n(b'0', exp as usize));
+                0
+            } else {
+                (-exp) as usize
+            };
+            if buf.len() > DEC256_MAX_PREC || scale > DEC256_MAX_PREC {
+                return None;
+            }
+            Some((str_to_dec256(&buf, DEC256_MAX_PREC, 0, false)?, scale))
+        }
+
         // Note: Using Vec<u8> is not the most efficient thing here (input is a tuple)
         let (sign, digits, exp): (i8, Vec<u8>, i32) = ob
             .call_method0(intern!(ob.py(), "as_tuple"))
             .unwrap()
             .extract()
             .unwrap();
-        let (mut v, scale) = abs_decimal_from_digits(digits, exp).ok_or_else(|| {
+        if let Some((mut v, scale)) = abs_decimal_from_digits(digits.iter().copied(), exp) {
+            if sign > 0 {
+                v = -v; // Won't overflow since -i128::MAX > i128::MIN
+            }
+            return Ok(AnyValue::Decimal(v, DEC128_MAX_PREC, scale));
+        }
+        let (mut v, scale) = abs_decimal256_from_digits(&digits, exp).ok_or_else(|| {
             PyErr::from(PyPolarsErr::Other(
-                "Decimal is too large to fit in Decimal128".into(),
+                "Decimal is too large to fit in Decimal256".into(),
             ))
         })?;
         if sign > 0 {
-            v = -v; // Won't overflow since -i128::MAX > i128::MIN
+            v = -v;
         }
-        Ok(AnyValue::Decimal(v, DEC128_MAX_PREC, scale))
+        Ok(AnyValue::Decimal256(v, DEC256_MAX_PREC, scale))
     }
 
     fn get_list(ob: &Bound<'_, PyAny>, strict: bool) -> PyResult<AnyValue<'static>> {
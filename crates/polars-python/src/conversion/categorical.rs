@@ -1,7 +1,9 @@
 use std::sync::Arc;
 
 use polars_dtype::categorical::{CatSize, Categories};
-use pyo3::{pyclass, pymethods};
+use pyo3::{PyResult, pyclass, pymethods};
+
+use crate::error::PyPolarsErr;
 
 #[pyclass(frozen, from_py_object)]
 #[repr(transparent)]
@@ -70,6 +72,28 @@ impl PyCategories {
     pub fn is_global(&self) -> bool {
         self.categories.is_global()
     }
+
+    pub fn persist(&self) {
+        self.categories.persist();
+    }
+
+    pub fn is_persistent(&self) -> bool {
+        self.categories.is_persistent()
+    }
+
+    pub fn serialize_mapping(&self) -> PyResult<String> {
+        let serialized = self
+            .categories
+            .serialize_mapping()
+            .map_err(PyPolarsErr::from)?;
+        Ok(serialized)
+    }
+
+    #[staticmethod]
+    pub fn deserialize_mapping(encoded: &str) -> PyResult<Self> {
+        let categories = Categories::deserialize_mapping(encoded).map_err(PyPolarsErr::from)?;
+        Ok(Self { categories })
+    }
 }
 
 impl From<Arc<Categories>> for PyCategories {
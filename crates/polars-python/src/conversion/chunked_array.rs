@@ -130,6 +130,16 @@ impl<'py> IntoPyObject<'py> for &Wrap<&DecimalChunked> {
     }
 }
 
+impl<'py> IntoPyObject<'py> for &Wrap<&Decimal256Chunked> {
+    type Target = PyList;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let values = decimal256_to_pyobject_iter(py, self.0)?.collect::<Vec<_>>();
+        PyList::new(py, values)
+    }
+}
+
 pub(crate) fn decimal_to_pyobject_iter<'py, 'a>(
     py: Python<'py>,
     ca: &'a DecimalChunked,
@@ -145,3 +155,19 @@ pub(crate) fn decimal_to_pyobject_iter<'py, 'a>(
         })
     }))
 }
+
+pub(crate) fn decimal256_to_pyobject_iter<'py, 'a>(
+    py: Python<'py>,
+    ca: &'a Decimal256Chunked,
+) -> PyResult<impl Iterator<Item = Option<Bound<'py, PyAny>>> + use<'py, 'a>> {
+    let utils = pl_utils(py).bind(py);
+    let convert = utils.getattr(intern!(py, "to_py_decimal"))?;
+    let py_precision = ca.precision().into_pyobject(py)?;
+    let mut buf = DecimalFmtBuffer::new();
+    Ok(ca.iter().map(move |opt_v| {
+        opt_v.map(|v| {
+            let s = buf.format_dec256(v, ca.scale(), false, false);
+            convert.call1((&py_precision, s)).unwrap()
+        })
+    }))
+}
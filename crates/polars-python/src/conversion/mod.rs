@@ -23,7 +23,8 @@ use polars::prelude::default_values::{
 use polars::prelude::deletion::{DeletionFilesList, DeltaDeletionVectorProvider};
 use polars::series::ops::NullBehavior;
 use polars_buffer::Buffer;
-use polars_compute::decimal::dec128_verify_prec_scale;
+use polars_compute::decimal::{DEC128_MAX_PREC, dec128_verify_prec_scale};
+use polars_compute::decimal256::dec256_verify_prec_scale;
 use polars_core::datatypes::extension::get_extension_type_or_generic;
 use polars_core::schema::iceberg::IcebergSchema;
 use polars_core::utils::arrow::array::Array;
@@ -245,7 +246,7 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let class = pl.getattr(intern!(py, "Float64"))?;
                 class.call0()
             },
-            DataType::Decimal(precision, scale) => {
+            DataType::Decimal(precision, scale) | DataType::Decimal256(precision, scale) => {
                 let class = pl.getattr(intern!(py, "Decimal"))?;
                 let args = (*precision, *scale);
                 class.call1(args)
@@ -310,6 +311,7 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 class.call1((series,))
             },
             DataType::Time => pl.getattr(intern!(py, "Time")).and_then(|x| x.call0()),
+            DataType::Interval => pl.getattr(intern!(py, "Interval")).and_then(|x| x.call0()),
             DataType::Struct(fields) => {
                 let field_class = pl.getattr(intern!(py, "Field"))?;
                 let iter = fields.iter().map(|fld| {
@@ -405,12 +407,16 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<DataType> {
                     "Enum" => DataType::from_frozen_categories(FrozenCategories::new([]).unwrap()),
                     "Date" => DataType::Date,
                     "Time" => DataType::Time,
+                    "Interval" => DataType::Interval,
                     "Datetime" => DataType::Datetime(TimeUnit::Microseconds, None),
                     "Duration" => DataType::Duration(TimeUnit::Microseconds),
                     "List" => DataType::List(Box::new(DataType::Null)),
                     "Array" => DataType::Array(Box::new(DataType::Null), 0),
                     "Struct" => DataType::Struct(vec![]),
                     "Null" => DataType::Null,
+                    "Geometry" => DataType::new_geometry(None),
+                    "UUID" => DataType::new_uuid(),
+                    "Variant" => DataType::new_variant(),
                     #[cfg(feature = "object")]
                     "Object" => DataType::Object(OBJECT_NAME),
                     "Unknown" => DataType::Unknown(Default::default()),
@@ -460,6 +466,7 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<DataType> {
             },
             "Date" => DataType::Date,
             "Time" => DataType::Time,
+            "Interval" => DataType::Interval,
             "Datetime" => {
                 let time_unit = ob.getattr(intern!(py, "time_unit")).unwrap();
                 let time_unit = time_unit.extract::<Wrap<TimeUnit>>()?.0;
@@ -478,8 +485,13 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<DataType> {
             "Decimal" => {
                 let precision = ob.getattr(intern!(py, "precision"))?.extract()?;
                 let scale = ob.getattr(intern!(py, "scale"))?.extract()?;
-                dec128_verify_prec_scale(precision, scale).map_err(to_py_err)?;
-                DataType::Decimal(precision, scale)
+                if precision > DEC128_MAX_PREC {
+                    dec256_verify_prec_scale(precision, scale).map_err(to_py_err)?;
+                    DataType::Decimal256(precision, scale)
+                } else {
+                    dec128_verify_prec_scale(precision, scale).map_err(to_py_err)?;
+                    DataType::Decimal(precision, scale)
+                }
             },
             "List" => {
                 let inner = ob.getattr(intern!(py, "inner")).unwrap();
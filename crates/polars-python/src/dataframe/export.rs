@@ -89,10 +89,10 @@ impl PyDataFrame {
 
         let pyarrow = py.import("pyarrow")?;
 
-        let mut chunks = df.iter_chunks(compat_level.0, true);
+        let mut chunks = df.iter_chunks_encoded(compat_level.0, true);
         let mut rbs = Vec::with_capacity(chunks.size_hint().0);
-        // df.iter_chunks() iteration could internally try to acquire the GIL on another thread,
-        // so we make sure to run chunks.next() within enter_polars().
+        // df.iter_chunks_encoded() iteration could internally try to acquire the GIL on another
+        // thread, so we make sure to run chunks.next() within enter_polars().
         while let Some(rb) = py.enter_polars_ok(|| chunks.next())? {
             let rb = interop::arrow::to_py::to_py_rb(&rb, py, &pyarrow)?;
             rbs.push(rb);
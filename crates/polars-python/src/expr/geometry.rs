@@ -0,0 +1,30 @@
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn st_bounds(&self) -> Self {
+        self.inner.clone().st().bounds().into()
+    }
+
+    fn st_area(&self) -> Self {
+        self.inner.clone().st().area().into()
+    }
+
+    fn st_length(&self) -> Self {
+        self.inner.clone().st().length().into()
+    }
+
+    fn st_distance(&self, other: PyExpr) -> Self {
+        self.inner.clone().st().distance(other.inner).into()
+    }
+
+    fn st_contains(&self, other: PyExpr) -> Self {
+        self.inner.clone().st().contains(other.inner).into()
+    }
+
+    fn st_intersects(&self, other: PyExpr) -> Self {
+        self.inner.clone().st().intersects(other.inner).into()
+    }
+}
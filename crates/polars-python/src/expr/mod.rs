@@ -14,7 +14,11 @@ mod extension;
 #[cfg(feature = "pymethods")]
 mod general;
 #[cfg(feature = "pymethods")]
+mod geometry;
+#[cfg(feature = "pymethods")]
 mod list;
+#[cfg(feature = "pymethods")]
+mod map;
 #[cfg(all(feature = "meta", feature = "pymethods"))]
 mod meta;
 #[cfg(feature = "pymethods")]
@@ -28,6 +32,10 @@ mod serde;
 mod string;
 #[cfg(feature = "pymethods")]
 mod r#struct;
+#[cfg(feature = "pymethods")]
+mod uuid;
+#[cfg(feature = "pymethods")]
+mod variant;
 
 use std::mem::ManuallyDrop;
 
@@ -0,0 +1,10 @@
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn uuid_timestamp(&self) -> Self {
+        self.inner.clone().uuid().timestamp().into()
+    }
+}
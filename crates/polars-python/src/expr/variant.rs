@@ -0,0 +1,27 @@
+use pyo3::prelude::*;
+
+use super::datatype::PyDataTypeExpr;
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn variant_get(&self, path: &str) -> Self {
+        self.inner.clone().variant().get(path).into()
+    }
+
+    fn variant_cast(&self, dtype: PyDataTypeExpr, strict: bool) -> Self {
+        self.inner
+            .clone()
+            .variant()
+            .cast(dtype.inner, strict)
+            .into()
+    }
+
+    fn variant_to_json(&self) -> Self {
+        self.inner.clone().variant().to_json().into()
+    }
+
+    fn variant_type_name(&self) -> Self {
+        self.inner.clone().variant().type_name().into()
+    }
+}
@@ -3,12 +3,18 @@ use std::borrow::Cow;
 use std::hash::{BuildHasher, Hash, Hasher};
 use std::sync::Arc;
 
-use polars::prelude::PlFixedStateQuality;
 use polars::prelude::extension::{register_extension_type, unregister_extension_type};
-use polars_core::datatypes::DataType;
-use polars_core::datatypes::extension::{ExtensionTypeFactory, ExtensionTypeImpl};
+use polars::prelude::*;
+use polars_core::chunked_array::cast::CastOptions;
+use polars_core::datatypes::extension::{
+    ExtensionAggregation, ExtensionTypeFactory, ExtensionTypeImpl,
+};
+use pyo3::intern;
 use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
 
+use crate::PySeries;
+use crate::conversion::{get_series, to_series};
 use crate::prelude::Wrap;
 use crate::utils::to_py_err;
 
@@ -21,6 +27,16 @@ struct PyExtensionTypeImpl {
     name: String,
     display: String,
     metadata: Option<String>,
+    /// The Python extension type instance, which implements the behavioral hooks.
+    typ_obj: Arc<Py<PyAny>>,
+}
+
+/// Convert the result of a hook to a Series, or `None` if the hook returned `None`.
+fn hook_output_to_series(out: &Bound<'_, PyAny>) -> PolarsResult<Option<Series>> {
+    if out.is_none() {
+        return Ok(None);
+    }
+    Ok(Some(get_series(out)?))
 }
 
 impl ExtensionTypeFactory for PyExtensionTypeFactory {
@@ -52,6 +68,7 @@ impl ExtensionTypeFactory for PyExtensionTypeFactory {
                 name: name.to_string(),
                 display,
                 metadata,
+                typ_obj: Arc::new(typ_obj.unbind()),
             })
         })
     }
@@ -99,6 +116,100 @@ impl ExtensionTypeImpl for PyExtensionTypeImpl {
             Cow::Owned(format!("PyExtensionType(name='{}')", self.name))
         }
     }
+
+    fn fmt_value(&self, value: &AnyValue<'_>) -> PolarsResult<Option<String>> {
+        Python::attach(|py| {
+            let out = self
+                .typ_obj
+                .bind(py)
+                .call_method1(intern!(py, "ext_format_value"), (Wrap(value.clone()),))?;
+            Ok(out.extract::<Option<String>>()?)
+        })
+    }
+
+    fn validate_storage(&self, storage: &Series) -> PolarsResult<()> {
+        Python::attach(|py| {
+            let storage = to_series(py, PySeries::new(storage.clone()))?;
+            self.typ_obj
+                .bind(py)
+                .call_method1(intern!(py, "ext_validate"), (storage,))?;
+            Ok(())
+        })
+    }
+
+    fn cast_from(&self, s: &Series, options: CastOptions) -> Option<PolarsResult<Series>> {
+        Python::attach(|py| {
+            let s = to_series(py, PySeries::new(s.clone()))?;
+            let kwargs = [("strict", options.is_strict())].into_py_dict(py)?;
+            let out = self.typ_obj.bind(py).call_method(
+                intern!(py, "ext_cast_from"),
+                (s,),
+                Some(&kwargs),
+            )?;
+            hook_output_to_series(&out)
+        })
+        .transpose()
+    }
+
+    fn cast_to(
+        &self,
+        storage: &Series,
+        dtype: &DataType,
+        options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        Python::attach(|py| {
+            let storage = to_series(py, PySeries::new(storage.clone()))?;
+            let kwargs = [("strict", options.is_strict())].into_py_dict(py)?;
+            let out = self.typ_obj.bind(py).call_method(
+                intern!(py, "ext_cast_to"),
+                (storage, &Wrap(dtype.clone())),
+                Some(&kwargs),
+            )?;
+            hook_output_to_series(&out)
+        })
+        .transpose()
+    }
+
+    fn sort_key(&self, storage: &Series) -> Option<PolarsResult<Series>> {
+        Python::attach(|py| {
+            let storage = to_series(py, PySeries::new(storage.clone()))?;
+            let out = self
+                .typ_obj
+                .bind(py)
+                .call_method1(intern!(py, "ext_sort_key"), (storage,))?;
+            hook_output_to_series(&out)
+        })
+        .transpose()
+    }
+
+    fn aggregate(
+        &self,
+        storage: &Series,
+        agg: ExtensionAggregation,
+    ) -> Option<PolarsResult<Scalar>> {
+        let agg = match agg {
+            ExtensionAggregation::Min => "min",
+            ExtensionAggregation::Max => "max",
+            ExtensionAggregation::Sum => "sum",
+        };
+        Python::attach(|py| {
+            let storage = to_series(py, PySeries::new(storage.clone()))?;
+            let out = self
+                .typ_obj
+                .bind(py)
+                .call_method1(intern!(py, "ext_aggregate"), (storage, agg))?;
+            let Some(out) = hook_output_to_series(&out)? else {
+                return Ok(None);
+            };
+            polars_ensure!(
+                out.len() == 1,
+                ComputeError: "`ext_aggregate` must return a Series of length 1, got length {}", out.len()
+            );
+            let value = out.get(0)?.into_static();
+            Ok(Some(Scalar::new(out.dtype().clone(), value)))
+        })
+        .transpose()
+    }
 }
 
 #[pyfunction]
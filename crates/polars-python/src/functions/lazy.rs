@@ -258,6 +258,16 @@ pub fn len() -> PyExpr {
     dsl::len().into()
 }
 
+#[pyfunction]
+pub fn uuid4() -> PyExpr {
+    dsl::uuid4().into()
+}
+
+#[pyfunction]
+pub fn uuid7() -> PyExpr {
+    dsl::uuid7().into()
+}
+
 #[pyfunction]
 pub fn cov(a: PyExpr, b: PyExpr, ddof: u8) -> PyExpr {
     dsl::cov(a.inner, b.inner, ddof).into()
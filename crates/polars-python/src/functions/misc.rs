@@ -1,9 +1,13 @@
+use polars_dtype::categorical::FrozenCategories;
 use polars_plan::prelude::*;
+use pyo3::IntoPyObjectExt;
+use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
 
 use crate::PyExpr;
 use crate::conversion::Wrap;
+use crate::error::PyPolarsErr;
 use crate::expr::ToExprs;
 use crate::prelude::DataType;
 
@@ -13,6 +17,20 @@ pub fn dtype_str_repr(dtype: Wrap<DataType>) -> PyResult<String> {
     Ok(dtype.to_string())
 }
 
+#[pyfunction]
+pub fn serialize_enum_categories(dtype: Wrap<DataType>) -> PyResult<String> {
+    let DataType::Enum(fcats, _) = dtype.0 else {
+        return Err(PyTypeError::new_err("expected an Enum data type"));
+    };
+    Ok(fcats.serialize_categories())
+}
+
+#[pyfunction]
+pub fn deserialize_enum_categories(py: Python<'_>, encoded: &str) -> PyResult<Py<PyAny>> {
+    let fcats = FrozenCategories::deserialize_categories(encoded).map_err(PyPolarsErr::from)?;
+    (&Wrap(DataType::from_frozen_categories(fcats))).into_py_any(py)
+}
+
 #[cfg(feature = "ffi_plugin")]
 #[pyfunction]
 pub fn register_plugin_function(
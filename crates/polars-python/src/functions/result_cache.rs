@@ -0,0 +1,37 @@
+use polars_plan::plans::result_cache;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+#[pyfunction]
+pub fn enable_result_cache(max_bytes: usize) {
+    result_cache::enable_result_cache(max_bytes)
+}
+
+#[pyfunction]
+pub fn disable_result_cache() {
+    result_cache::disable_result_cache()
+}
+
+#[pyfunction]
+pub fn clear_result_cache() {
+    result_cache::clear_result_cache()
+}
+
+#[pyfunction]
+pub fn using_result_cache() -> bool {
+    result_cache::using_result_cache()
+}
+
+#[pyfunction]
+pub fn result_cache_info(py: Python<'_>) -> PyResult<Option<Bound<'_, PyDict>>> {
+    let Some(info) = result_cache::result_cache_info() else {
+        return Ok(None);
+    };
+    let dict = PyDict::new(py);
+    dict.set_item("hits", info.hits)?;
+    dict.set_item("misses", info.misses)?;
+    dict.set_item("entries", info.entries)?;
+    dict.set_item("bytes", info.bytes)?;
+    dict.set_item("max_bytes", info.max_bytes)?;
+    Ok(Some(dict))
+}
@@ -51,6 +51,20 @@ pub fn array_to_rust(obj: &Bound<PyAny>) -> PyResult<ArrayRef> {
     }
 }
 
+/// Convert a single record batch column, keeping run-end encoded arrays encoded.
+fn array_to_column(field: &ArrowField, arr: ArrayRef) -> PyResult<Column> {
+    let column = unsafe {
+        Column::_try_from_arrow_unchecked_with_md(
+            field.name.clone(),
+            vec![arr],
+            field.dtype(),
+            field.metadata.as_deref(),
+        )
+    }
+    .map_err(PyPolarsErr::from)?;
+    Ok(column)
+}
+
 pub fn to_rust_df(
     py: Python<'_>,
     rb: &[Bound<PyAny>],
@@ -137,17 +151,7 @@ pub fn to_rust_df(
                             .enumerate()
                             .map(|(i, arr)| {
                                 let (_, field) = schema.get_at_index(i).unwrap();
-                                let s = unsafe {
-                                    Series::_try_from_arrow_unchecked_with_md(
-                                        field.name.clone(),
-                                        vec![arr],
-                                        field.dtype(),
-                                        field.metadata.as_deref(),
-                                    )
-                                }
-                                .map_err(PyPolarsErr::from)?
-                                .into_column();
-                                Ok(s)
+                                array_to_column(field, arr)
                             })
                             .collect::<PyResult<Vec<_>>>()
                     })
@@ -158,17 +162,7 @@ pub fn to_rust_df(
                     .enumerate()
                     .map(|(i, arr)| {
                         let (_, field) = schema.get_at_index(i).unwrap();
-                        let s = unsafe {
-                            Series::_try_from_arrow_unchecked_with_md(
-                                field.name.clone(),
-                                vec![arr],
-                                field.dtype(),
-                                field.metadata.as_deref(),
-                            )
-                        }
-                        .map_err(PyPolarsErr::from)?
-                        .into_column();
-                        Ok(s)
+                        array_to_column(field, arr)
                     })
                     .collect::<PyResult<Vec<_>>>()
             }?;
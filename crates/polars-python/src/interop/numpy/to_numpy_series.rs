@@ -13,7 +13,9 @@ use super::utils::{
     reshape_numpy_array, series_contains_null,
 };
 use crate::conversion::ObjectValue;
-use crate::conversion::chunked_array::{decimal_to_pyobject_iter, time_to_pyobject_iter};
+use crate::conversion::chunked_array::{
+    decimal_to_pyobject_iter, decimal256_to_pyobject_iter, time_to_pyobject_iter,
+};
 use crate::series::PySeries;
 
 #[pymethods]
@@ -241,6 +243,15 @@ fn series_to_numpy_with_copy(py: Python<'_>, s: &Series, writable: bool) -> Py<P
             let values = time_to_pyobject_iter(ca).map(|v| v.into_py_any(py).unwrap());
             PyArray1::from_iter(py, values).into_py_any(py).unwrap()
         },
+        Interval => {
+            let ca = s.interval().unwrap();
+            let values = ca.iter().map(|v| {
+                v.map(|v| (v.months(), v.days(), v.ns()))
+                    .into_py_any(py)
+                    .unwrap()
+            });
+            PyArray1::from_iter(py, values).into_py_any(py).unwrap()
+        },
         String => {
             let ca = s.str().unwrap();
             let values = ca.iter().map(|s| s.into_py_any(py).unwrap());
@@ -265,6 +276,13 @@ fn series_to_numpy_with_copy(py: Python<'_>, s: &Series, writable: bool) -> Py<P
                 .map(|v| v.into_py_any(py).unwrap());
             PyArray1::from_iter(py, values).into_py_any(py).unwrap()
         },
+        Decimal256(_, _) => {
+            let ca = s.decimal256().unwrap();
+            let values = decimal256_to_pyobject_iter(py, ca)
+                .unwrap()
+                .map(|v| v.into_py_any(py).unwrap());
+            PyArray1::from_iter(py, values).into_py_any(py).unwrap()
+        },
         List(_) => list_series_to_numpy(py, s, writable),
         Array(_, _) => array_series_to_numpy(py, s, writable),
         Struct(_) => {
@@ -1,3 +1,4 @@
+use polars::prelude::commit_protocol::CommitProtocol;
 use polars::prelude::file_provider::{FileProviderFunction, FileProviderType, IcebergPathProvider};
 use polars::prelude::{PartitionStrategy, PlRefPath, PlSmallStr, SinkDestination, SpecialEq};
 use polars_utils::IdxSize;
@@ -46,6 +47,8 @@ impl PyFileSinkDestination<'_> {
             include_key: Option<bool>,
             max_rows_per_file: Option<IdxSize>,
             approximate_bytes_per_file: u64,
+            commit_protocol: PyBackedStr,
+            overwrite_partitions: bool,
         }
 
         let Extract {
@@ -55,8 +58,22 @@ impl PyFileSinkDestination<'_> {
             include_key,
             max_rows_per_file,
             approximate_bytes_per_file,
+            commit_protocol,
+            overwrite_partitions,
         } = partition_by_dataclass.extract()?;
 
+        let commit_protocol = match &*commit_protocol {
+            "direct" => CommitProtocol::Direct,
+            "staged" => CommitProtocol::Staged {
+                overwrite_partitions,
+            },
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`commit_protocol` must be one of {{'direct', 'staged'}}, got {v}",
+                )));
+            },
+        };
+
         let partition_strategy: PartitionStrategy = if let Some(partition_by) = key {
             PartitionStrategy::Keyed {
                 keys: partition_by.into_iter().map(|x| x.inner).collect(),
@@ -107,6 +124,7 @@ impl PyFileSinkDestination<'_> {
             partition_strategy,
             max_rows_per_file: max_rows_per_file.unwrap_or(IdxSize::MAX),
             approximate_bytes_per_file,
+            commit_protocol,
         })
     }
 }
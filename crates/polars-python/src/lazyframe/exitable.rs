@@ -1,17 +1,24 @@
 use polars::prelude::*;
 use pyo3::prelude::*;
 
-use super::PyLazyFrame;
+use super::{PyLazyFrame, query_limits};
 use crate::PyDataFrame;
 use crate::utils::EnterPolarsExt;
 
 #[pymethods]
 #[cfg(not(target_arch = "wasm32"))]
 impl PyLazyFrame {
-    fn collect_concurrently(&self, py: Python) -> PyResult<PyInProcessQuery> {
+    #[pyo3(signature = (timeout=None, memory_limit=None))]
+    fn collect_concurrently(
+        &self,
+        py: Python,
+        timeout: Option<f64>,
+        memory_limit: Option<usize>,
+    ) -> PyResult<PyInProcessQuery> {
+        let limits = query_limits(timeout, memory_limit)?;
         let ipq = py.enter_polars(|| {
             let ldf = self.ldf.read().clone();
-            ldf.collect_concurrently()
+            ldf.collect_concurrently_with_limits(limits)
         })?;
         Ok(PyInProcessQuery { ipq })
     }
@@ -11,6 +11,7 @@ use polars::io::RowIndex;
 use polars::time::*;
 use polars_core::prelude::*;
 use polars_core::query_result::QueryResult;
+use polars_expr::state::ExecutionState;
 #[cfg(feature = "parquet")]
 use polars_parquet::arrow::write::StatisticsOptions;
 use polars_plan::dsl::ScanSources;
@@ -22,7 +23,7 @@ use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
 use pyo3::types::{PyCapsule, PyDict, PyDictMethods, PyList};
 
-use super::{PyLazyFrame, PyOptFlags};
+use super::{PyLazyFrame, PyOptFlags, progress_callback, query_limits};
 use crate::error::PyPolarsErr;
 use crate::expr::ToExprs;
 use crate::expr::datatype::PyDataTypeExpr;
@@ -492,6 +493,11 @@ impl PyLazyFrame {
         py.enter_polars(|| self.ldf.read().to_dot_streaming_phys(optimized))
     }
 
+    #[cfg(feature = "new_streaming")]
+    fn explain_analyze(&self, py: Python) -> PyResult<String> {
+        py.enter_polars(|| self.ldf.read().explain_analyze())
+    }
+
     fn sort(
         &self,
         by_column: &str,
@@ -589,13 +595,26 @@ impl PyLazyFrame {
         Ok((df.into(), time_df.into()))
     }
 
-    #[pyo3(signature = (engine, lambda_post_opt))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        engine, lambda_post_opt, timeout=None, memory_limit=None, progress=None,
+        progress_interval=1.0
+    ))]
     fn collect(
         &self,
         py: Python<'_>,
         engine: Wrap<Engine>,
         lambda_post_opt: Option<Py<PyAny>>,
+        timeout: Option<f64>,
+        memory_limit: Option<usize>,
+        progress: Option<Py<PyAny>>,
+        progress_interval: f64,
     ) -> PyResult<PyDataFrame> {
+        let mut state = ExecutionState::new();
+        state.set_limits(query_limits(timeout, memory_limit)?);
+        if let Some(callback) = progress {
+            state.set_progress_callback(progress_callback(callback, progress_interval)?);
+        }
         py.enter_polars_df(|| {
             let ldf = self.ldf.read().clone();
             if let Some(lambda) = lambda_post_opt {
@@ -603,11 +622,12 @@ impl PyLazyFrame {
                     post_opt_callback(&lambda, root, lp_arena, expr_arena, None)
                 })
             } else {
-                ldf.collect_with_engine(engine.0).map(|r| match r {
-                    QueryResult::Single(df) => df,
-                    // TODO: Should return query results
-                    QueryResult::Multiple(_) => DataFrame::empty(),
-                })
+                ldf.collect_with_engine_and_state(engine.0, state)
+                    .map(|r| match r {
+                        QueryResult::Single(df) => df,
+                        // TODO: Should return query results
+                        QueryResult::Multiple(_) => DataFrame::empty(),
+                    })
             }
         })
     }
@@ -725,7 +745,8 @@ impl PyLazyFrame {
 
     #[cfg(feature = "ipc")]
     #[pyo3(signature = (
-        target, sink_options, compression, compat_level, record_batch_size, record_batch_statistics
+        target, sink_options, compression, compat_level, record_batch_size, record_batch_statistics,
+        stream
     ))]
     fn sink_ipc(
         &self,
@@ -736,12 +757,14 @@ impl PyLazyFrame {
         compat_level: PyCompatLevel,
         record_batch_size: Option<usize>,
         record_batch_statistics: bool,
+        stream: bool,
     ) -> PyResult<PyLazyFrame> {
         let options = IpcWriterOptions {
             compression: compression.0,
             compat_level: compat_level.0,
             record_batch_size,
             record_batch_statistics,
+            stream,
         };
 
         let target = target.extract_file_sink_destination()?;
@@ -11,10 +11,11 @@ pub mod visitor;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::PyInProcessQuery;
 use parking_lot::RwLock;
-use polars::prelude::{Engine, LazyFrame, OptFlags};
+use polars::prelude::{Engine, LazyFrame, OptFlags, ProgressCallback, QueryLimits, QueryProgress};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
+use pyo3::types::{PyDict, PyList};
 
 use crate::prelude::Wrap;
 
@@ -79,3 +80,68 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<Engine> {
         Ok(Wrap(parsed))
     }
 }
+
+/// Converts the `timeout` (in seconds) and `memory_limit` (in bytes) arguments of
+/// `collect` into [`QueryLimits`].
+pub(crate) fn query_limits(
+    timeout: Option<f64>,
+    memory_limit: Option<usize>,
+) -> PyResult<QueryLimits> {
+    let timeout = timeout
+        .map(|t| {
+            std::time::Duration::try_from_secs_f64(t)
+                .map_err(|_| PyValueError::new_err(format!("invalid timeout: {t}")))
+        })
+        .transpose()?;
+    Ok(QueryLimits {
+        timeout,
+        memory_limit,
+    })
+}
+
+/// Wraps a Python callable receiving the progress of a streaming query as a dict.
+pub(crate) fn progress_callback(callback: Py<PyAny>, interval: f64) -> PyResult<ProgressCallback> {
+    let interval = std::time::Duration::try_from_secs_f64(interval)
+        .map_err(|_| PyValueError::new_err(format!("invalid progress interval: {interval}")))?;
+    Ok(ProgressCallback::new(interval, move |progress| {
+        Python::attach(|py| {
+            let result = progress_to_dict(py, progress).and_then(|d| callback.call1(py, (d,)));
+            // Errors in the callback should not abort the query.
+            if let Err(e) = result {
+                e.write_unraisable(py, Some(callback.bind(py)));
+            }
+        })
+    }))
+}
+
+fn progress_to_dict<'py>(
+    py: Python<'py>,
+    progress: &QueryProgress,
+) -> PyResult<Bound<'py, PyDict>> {
+    let sources = PyList::empty(py);
+    for source in &progress.sources {
+        let d = PyDict::new(py);
+        d.set_item("name", source.name.as_str())?;
+        d.set_item("files_done", source.files_done)?;
+        d.set_item("files_total", source.files_total)?;
+        d.set_item("row_groups_done", source.row_groups_done)?;
+        d.set_item("row_groups_total", source.row_groups_total)?;
+        d.set_item("bytes_read", source.bytes_read)?;
+        d.set_item("bytes_total", source.bytes_total)?;
+        sources.append(d)?;
+    }
+    let sinks = PyList::empty(py);
+    for sink in &progress.sinks {
+        let d = PyDict::new(py);
+        d.set_item("name", sink.name.as_str())?;
+        d.set_item("rows_written", sink.rows_written)?;
+        sinks.append(d)?;
+    }
+
+    let out = PyDict::new(py);
+    out.set_item("elapsed", progress.elapsed.as_secs_f64())?;
+    out.set_item("finished", progress.finished)?;
+    out.set_item("sources", sources)?;
+    out.set_item("sinks", sinks)?;
+    Ok(out)
+}
@@ -59,6 +59,8 @@ flag_getter_setters! {
     (CHECK_ORDER_OBSERVE, get_check_order_observe, set_check_order_observe, clear=true)
     (FAST_PROJECTION, get_fast_projection, set_fast_projection, clear=true)
     (SORT_COLLAPSE, get_sort_collapse, set_sort_collapse, clear=true)
+    (JOIN_REORDER, get_join_reorder, set_join_reorder, clear=true)
+    (JOIN_RUNTIME_FILTERS, get_join_runtime_filters, set_join_runtime_filters, clear=true)
 
     (EAGER, get_eager, set_eager, clear=true)
     (NEW_STREAMING, get_streaming, set_streaming, clear=true)
@@ -823,6 +823,18 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::ListExpr(_) => {
                     return Err(PyNotImplementedError::new_err("list expr"));
                 },
+                IRFunctionExpr::GeometryExpr(_) => {
+                    return Err(PyNotImplementedError::new_err("geometry expr"));
+                },
+                IRFunctionExpr::MapExpr(_) => {
+                    return Err(PyNotImplementedError::new_err("map expr"));
+                },
+                IRFunctionExpr::UuidExpr(_) => {
+                    return Err(PyNotImplementedError::new_err("uuid expr"));
+                },
+                IRFunctionExpr::VariantExpr(_) => {
+                    return Err(PyNotImplementedError::new_err("variant expr"));
+                },
                 IRFunctionExpr::Bitwise(_) => {
                     return Err(PyNotImplementedError::new_err("bitwise expr"));
                 },
@@ -1457,7 +1469,8 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::RowDecode(..) => {
                     return Err(PyNotImplementedError::new_err("row_decode"));
                 },
-                IRFunctionExpr::DynamicPred { .. } => {
+                IRFunctionExpr::DynamicPred { .. }
+                | IRFunctionExpr::DynamicPredSkipBatch { .. } => {
                     return Err(PyNotImplementedError::new_err("dynamic_pred"));
                 },
             }?,
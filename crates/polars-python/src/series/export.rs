@@ -87,6 +87,13 @@ impl PySeries {
                     let ca = series.time().map_err(PyPolarsErr::from)?;
                     return Wrap(ca).into_bound_py_any(py);
                 },
+                DataType::Interval => {
+                    let ca = series.interval().map_err(PyPolarsErr::from)?;
+                    PyList::new(
+                        py,
+                        ca.iter().map(|v| v.map(|v| (v.months(), v.days(), v.ns()))),
+                    )?
+                },
                 DataType::Datetime(_, _) => {
                     let ca = series.datetime().map_err(PyPolarsErr::from)?;
                     return Wrap(ca).into_bound_py_any(py);
@@ -95,6 +102,10 @@ impl PySeries {
                     let ca = series.decimal().map_err(PyPolarsErr::from)?;
                     return Wrap(ca).into_bound_py_any(py);
                 },
+                DataType::Decimal256(_, _) => {
+                    let ca = series.decimal256().map_err(PyPolarsErr::from)?;
+                    return Wrap(ca).into_bound_py_any(py);
+                },
                 DataType::String => {
                     let ca = series.str().map_err(PyPolarsErr::from)?;
                     return Wrap(ca).into_bound_py_any(py);
@@ -140,6 +151,26 @@ impl PySeries {
                 DataType::BinaryOffset => {
                     unreachable!()
                 },
+                DataType::Extension(_, _) if series.dtype().is_uuid() => {
+                    let uuid_cls = py.import("uuid")?.getattr("UUID")?;
+                    let kwargs = pyo3::types::PyDict::new(py);
+                    let values = series
+                        .ext()
+                        .unwrap()
+                        .storage()
+                        .binary()
+                        .map_err(PyPolarsErr::from)?
+                        .iter()
+                        .map(|opt_b| match opt_b {
+                            Some(b) => {
+                                kwargs.set_item("bytes", b)?;
+                                uuid_cls.call((), Some(&kwargs))
+                            },
+                            None => Ok(py.None().into_bound(py)),
+                        })
+                        .collect::<PyResult<Vec<_>>>()?;
+                    PyList::new(py, values)?
+                },
                 DataType::Extension(_, _) => {
                     return to_list_recursive(py, series.ext().unwrap().storage());
                 },
@@ -35,6 +35,7 @@ macro_rules! apply_all_polars_dtypes {
             DataType::String => $self.str().unwrap().$method($($args),*),
             DataType::Binary => $self.binary().unwrap().$method($($args),*),
             DataType::Decimal(_, _) => $self.decimal().unwrap().$method($($args),*),
+            DataType::Decimal256(_, _) => $self.decimal256().unwrap().$method($($args),*),
 
             DataType::Date => $self.date().unwrap().$method($($args),*),
             DataType::Datetime(_, _) => $self.datetime().unwrap().$method($($args),*),
@@ -63,7 +64,7 @@ macro_rules! apply_all_polars_dtypes {
 
             DataType::Null => $self.null().unwrap().$method($($args),*),
 
-            dt @ (DataType::BinaryOffset | DataType::Unknown(_)) => panic!("dtype {:?} not supported", dt)
+            dt @ (DataType::BinaryOffset | DataType::Interval | DataType::Unknown(_)) => panic!("dtype {:?} not supported", dt)
         }
     }
 }
@@ -705,16 +705,28 @@ impl SQLContext {
         Ok(DataFrame::from_rows(frame_rows.as_ref())?.lazy())
     }
 
-    // EXPLAIN SELECT * FROM DF
+    // EXPLAIN [ANALYZE] SELECT * FROM DF
     fn execute_explain(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         match stmt {
-            Statement::Explain { statement, .. } => {
+            Statement::Explain {
+                statement, analyze, ..
+            } => {
                 let lf = self.execute_statement(statement)?;
-                let plan = lf.describe_optimized_plan()?;
+                let (plan, name) = if *analyze {
+                    #[cfg(feature = "new_streaming")]
+                    {
+                        let plan = lf.explain_analyze()?;
+                        (plan.trim_end().to_string(), "Physical Plan")
+                    }
+                    #[cfg(not(feature = "new_streaming"))]
+                    polars_bail!(SQLInterface: "EXPLAIN ANALYZE requires the 'new_streaming' feature")
+                } else {
+                    (lf.describe_optimized_plan()?, "Logical Plan")
+                };
                 let plan = plan
                     .split('\n')
                     .collect::<Series>()
-                    .with_name(PlSmallStr::from_static("Logical Plan"))
+                    .with_name(PlSmallStr::from_static(name))
                     .into_column();
                 let df = DataFrame::new_infer_height(vec![plan])?;
                 Ok(df.lazy())
@@ -233,6 +233,17 @@ impl SQLExprVisitor<'_> {
                 subquery,
                 negated,
             } => self.visit_in_subquery(expr, subquery, *negated),
+            #[cfg(feature = "dtype-interval")]
+            SQLExpr::Interval(interval) => {
+                // intervals with calendar components have no fixed duration
+                let duration = interval_to_duration(interval, false)?;
+                if duration.months() != 0 {
+                    Ok(lit(Scalar::new_interval(duration.to_interval())))
+                } else {
+                    Ok(lit(duration))
+                }
+            },
+            #[cfg(not(feature = "dtype-interval"))]
             SQLExpr::Interval(interval) => Ok(lit(interval_to_duration(interval, true)?)),
             SQLExpr::IsDistinctFrom(e1, e2) => {
                 Ok(self.visit_expr(e1)?.neq_missing(self.visit_expr(e2)?))
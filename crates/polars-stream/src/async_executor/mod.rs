@@ -18,30 +18,54 @@ use crossbeam_utils::CachePadded;
 use park_group::ParkGroup;
 use parking_lot::Mutex;
 use polars_core::ALLOW_RAYON_THREADS;
+use polars_ooc::MemoryAccount;
 use polars_utils::relaxed_cell::RelaxedCell;
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
 use slotmap::SlotMap;
 use task::{Cancellable, DynTask, Runnable};
 
+use crate::trace::TraceId;
+
 static NUM_EXECUTOR_THREADS: RelaxedCell<usize> = RelaxedCell::new_usize(0);
 pub fn set_num_threads(t: usize) {
     NUM_EXECUTOR_THREADS.store(t);
 }
 
-static TRACK_METRICS: RelaxedCell<bool> = RelaxedCell::new_bool(false);
-
-pub fn track_task_metrics(should_track: bool) {
-    TRACK_METRICS.store(should_track);
-}
-
 static GLOBAL_SCHEDULER: OnceLock<Executor> = OnceLock::new();
 
 thread_local!(
     /// Used to store which executor thread this is.
     static TLS_THREAD_ID: Cell<usize> = const { Cell::new(usize::MAX) };
+
+    /// Whether tasks spawned from this thread track their metrics. Like the
+    /// memory account, this is inherited by the tasks a tracked task spawns.
+    static TLS_TRACK_METRICS: Cell<bool> = const { Cell::new(false) };
 );
 
+/// Track the metrics of the tasks spawned from this thread, and transitively by
+/// those tasks, until the guard is dropped.
+pub fn track_task_metrics(should_track: bool) -> TrackMetricsGuard {
+    TrackMetricsGuard {
+        prev: TLS_TRACK_METRICS.replace(should_track),
+        _not_send: PhantomData,
+    }
+}
+
+/// Restores the previous metrics tracking setting of the thread when dropped.
+#[must_use]
+pub struct TrackMetricsGuard {
+    prev: bool,
+    // The guard restores the setting of the thread it was created on.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for TrackMetricsGuard {
+    fn drop(&mut self) {
+        TLS_TRACK_METRICS.set(self.prev);
+    }
+}
+
 slotmap::new_key_type! {
     struct TaskKey;
 }
@@ -66,6 +90,10 @@ pub struct TaskMetrics {
     pub total_stolen_polls: RelaxedCell<u64>,
     pub total_poll_time_ns: RelaxedCell<u64>,
     pub max_poll_time_ns: RelaxedCell<u64>,
+    /// Start of the first poll, in nanoseconds since the trace epoch.
+    pub first_poll_start_ns: RelaxedCell<u64>,
+    /// End of the last poll, in nanoseconds since the trace epoch.
+    pub last_poll_end_ns: RelaxedCell<u64>,
     pub done: RelaxedCell<bool>,
 }
 
@@ -75,6 +103,11 @@ struct TaskMetadata {
     freshly_spawned: AtomicBool,
     scoped: Option<ScopedTaskMetadata>,
     metrics: Option<Arc<TaskMetrics>>,
+    /// The memory account of the query that spawned this task, frames stored
+    /// in the memory manager while polling the task are charged to it.
+    memory_account: Option<Arc<MemoryAccount>>,
+    /// The trace of the query that spawned this task, if it is traced.
+    trace: Option<TraceId>,
 }
 
 impl Drop for TaskMetadata {
@@ -308,16 +341,32 @@ impl Executor {
 
             if let Some(task) = task {
                 worker.recruit_next();
+                let _account_guard = task.metadata().memory_account.as_ref().map(|a| a.enter());
+                let trace = task.metadata().trace;
+                let _trace_guard = trace.map(TraceId::enter);
+                let _metrics_guard = track_task_metrics(task.metadata().metrics.is_some());
                 if let Some(metrics) = task.metadata().metrics.clone() {
+                    let spawn_location = task.metadata().spawn_location;
                     let start = Instant::now();
                     task.run();
-                    let elapsed_ns = start.elapsed().as_nanos() as u64;
-                    metrics.total_polls.fetch_add(1);
+                    let end = Instant::now();
+                    let elapsed_ns = end.duration_since(start).as_nanos() as u64;
+                    if metrics.total_polls.fetch_add(1) == 0 {
+                        metrics
+                            .first_poll_start_ns
+                            .store(crate::trace::since_epoch_ns(start));
+                    }
+                    metrics
+                        .last_poll_end_ns
+                        .store(crate::trace::since_epoch_ns(end));
                     if !local {
                         metrics.total_stolen_polls.fetch_add(1);
                     }
                     metrics.total_poll_time_ns.fetch_add(elapsed_ns);
                     metrics.max_poll_time_ns.fetch_max(elapsed_ns);
+                    if let Some(trace) = trace {
+                        crate::trace::record_poll(trace, metrics, spawn_location, !local, start);
+                    }
                 } else {
                     task.run();
                 }
@@ -403,7 +452,7 @@ impl<'scope> TaskScope<'scope, '_> {
         let mut runnable = None;
         let mut join_handle = None;
         self.cancel_handles.lock().insert_with_key(|task_key| {
-            let metrics = TRACK_METRICS.load().then(Arc::default);
+            let metrics = TLS_TRACK_METRICS.get().then(Arc::default);
             let dyn_task = unsafe {
                 // SAFETY: we make sure to cancel this task before 'scope ends.
                 let executor = Executor::global();
@@ -420,6 +469,8 @@ impl<'scope> TaskScope<'scope, '_> {
                             completed_tasks: Arc::downgrade(&self.completed_tasks),
                         }),
                         metrics,
+                        memory_account: MemoryAccount::current(),
+                        trace: TraceId::current(),
                     },
                 )
             };
@@ -467,7 +518,7 @@ where
     let spawn_location = Location::caller();
     let executor = Executor::global();
     let on_wake = move |task| executor.schedule_task(task);
-    let metrics = TRACK_METRICS.load().then(Arc::default);
+    let metrics = TLS_TRACK_METRICS.get().then(Arc::default);
     let dyn_task = task::spawn(
         fut,
         on_wake,
@@ -477,6 +528,8 @@ where
             freshly_spawned: AtomicBool::new(true),
             scoped: None,
             metrics,
+            memory_account: MemoryAccount::current(),
+            trace: TraceId::current(),
         },
     );
     Arc::clone(&dyn_task).schedule();
@@ -1,11 +1,13 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use crossbeam_channel::Sender;
 use parking_lot::Mutex;
 use polars_core::POOL;
 use polars_core::frame::DataFrame;
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_ensure};
 use polars_expr::state::ExecutionState;
+use polars_ooc::MemoryAccount;
 use polars_utils::aliases::PlHashSet;
 use polars_utils::relaxed_cell::RelaxedCell;
 use polars_utils::reuse_vec::reuse_vec;
@@ -16,6 +18,7 @@ use crate::async_executor;
 use crate::graph::{Graph, GraphNode, GraphNodeKey, LogicalPipeKey, PortState};
 use crate::metrics::{GraphMetrics, MetricsBuilder};
 use crate::pipe::PhysicalPipe;
+use crate::progress::ProgressTracker;
 
 #[derive(Clone)]
 pub struct StreamingExecutionState {
@@ -25,6 +28,13 @@ pub struct StreamingExecutionState {
     /// The ExecutionState passed to any non-streaming operations.
     pub in_memory_exec_state: ExecutionState,
 
+    /// The account frames stored by this query are charged to, if it has a
+    /// memory limit.
+    pub memory_account: Option<Arc<MemoryAccount>>,
+
+    /// Sources and sinks register their progress here if it is reported.
+    pub progress: Option<Arc<ProgressTracker>>,
+
     query_tasks_send: Sender<JoinHandle<PolarsResult<()>>>,
     subphase_tasks_send: Sender<JoinHandle<PolarsResult<()>>>,
 }
@@ -52,6 +62,24 @@ impl StreamingExecutionState {
     }
 }
 
+/// How often a running phase checks whether the query was cancelled, timed out
+/// or exceeded its memory limit, and whether progress should be reported.
+const LIMIT_CHECK_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Returns an error if the query was cancelled, timed out or exceeded its
+/// memory limit.
+fn check_limits(state: &StreamingExecutionState) -> PolarsResult<()> {
+    state.in_memory_exec_state.should_stop()?;
+    if let Some(account) = &state.memory_account {
+        polars_ensure!(
+            !account.limit_exceeded(),
+            ComputeError: "query exceeded its memory limit of {} bytes",
+            account.limit()
+        );
+    }
+    Ok(())
+}
+
 /// Finds all runnable pipeline blockers in the graph, that is, nodes which:
 ///  - Only have blocked output ports.
 ///  - Have at least one ready input port connected to a ready output port.
@@ -289,21 +317,57 @@ fn run_subgraph(
             pipe.spawn(scope, &mut join_handles);
         }
 
-        // Wait until all tasks are done.
+        // Wait until all tasks are done, or until we have to stop early. In the
+        // latter case the remaining tasks are cancelled when the scope ends.
         polars_io::pl_async::get_runtime().block_on(async move {
-            for handle in join_handles {
-                handle.await?;
+            let tasks = std::pin::pin!(async move {
+                for handle in join_handles {
+                    handle.await?;
+                }
+                PolarsResult::Ok(())
+            });
+            let watcher = std::pin::pin!(async move {
+                loop {
+                    tokio::time::sleep(LIMIT_CHECK_INTERVAL).await;
+                    check_limits(state)?;
+                    if let Some(progress) = &state.progress {
+                        progress.maybe_report();
+                    }
+                }
+            });
+            match futures::future::select(tasks, watcher).await {
+                futures::future::Either::Left((result, _)) => result,
+                futures::future::Either::Right((result, _)) => result,
             }
-            PolarsResult::Ok(())
         })
     })?;
 
     Ok(())
 }
 
+/// Executes the graph to completion.
+///
+/// The query stops with an error when `exec_state` is cancelled, its timeout
+/// elapses or its memory limit is exceeded. In that case all nodes are
+/// aborted, allowing sinks to clean up partially written output.
 pub fn execute_graph(
     graph: &mut Graph,
     metrics: Option<Arc<Mutex<GraphMetrics>>>,
+    exec_state: ExecutionState,
+) -> PolarsResult<SparseSecondaryMap<GraphNodeKey, DataFrame>> {
+    let result = execute_graph_impl(graph, metrics, exec_state);
+    if result.is_err() {
+        for node in graph.nodes.values_mut() {
+            node.compute.abort();
+        }
+    }
+    result
+}
+
+fn execute_graph_impl(
+    graph: &mut Graph,
+    metrics: Option<Arc<Mutex<GraphMetrics>>>,
+    exec_state: ExecutionState,
 ) -> PolarsResult<SparseSecondaryMap<GraphNodeKey, DataFrame>> {
     // Get the number of threads from the rayon thread-pool as that respects our config.
     let num_pipelines = POOL.current_num_threads();
@@ -312,13 +376,26 @@ pub fn execute_graph(
     let (query_tasks_send, query_tasks_recv) = crossbeam_channel::unbounded();
     let (subphase_tasks_send, subphase_tasks_recv) = crossbeam_channel::unbounded();
 
+    let memory_account = exec_state.memory_limit().map(MemoryAccount::new);
+    let progress = exec_state
+        .progress_callback()
+        .map(|cb| Arc::new(ProgressTracker::new(cb.clone())));
     let state = StreamingExecutionState {
         num_pipelines,
-        in_memory_exec_state: ExecutionState::default(),
+        in_memory_exec_state: exec_state,
+        memory_account,
+        progress,
         query_tasks_send,
         subphase_tasks_send,
     };
 
+    // Charge everything stored on this thread, and by the tasks it spawns, to
+    // the query's account.
+    let _account_guard = state.memory_account.as_ref().map(|a| a.enter());
+    // Only the tasks of this query track their metrics, other queries running
+    // concurrently are unaffected.
+    let _metrics_guard = async_executor::track_task_metrics(metrics.is_some());
+
     // Ensure everything is properly connected.
     for (node_key, node) in &graph.nodes {
         for (i, input) in node.inputs.iter().enumerate() {
@@ -337,6 +414,10 @@ pub fn execute_graph(
         if polars_core::config::verbose() {
             eprintln!("polars-stream: updating graph state");
         }
+        check_limits(&state)?;
+        if let Some(progress) = &state.progress {
+            progress.maybe_report();
+        }
         graph.update_all_states(&state, metrics.as_deref())?;
 
         if let Some(m) = metrics.as_ref() {
@@ -409,5 +490,9 @@ pub fn execute_graph(
         }
     }
 
+    if let Some(progress) = &state.progress {
+        progress.report_finished();
+    }
+
     Ok(out)
 }
@@ -105,9 +105,15 @@ impl Graph {
                     node.compute.name()
                 );
             }
-            let start = (metrics.is_some() || verbose).then(Instant::now);
+            let trace = crate::trace::TraceId::current();
+            let before = trace.map(|_| format!("{recv_state:?} {send_state:?}"));
+            let start = (metrics.is_some() || verbose || trace.is_some()).then(Instant::now);
             if let Some(lock) = metrics {
-                lock.lock().start_state_update(node_key);
+                let mut m = lock.lock();
+                m.start_state_update(node_key);
+                if let Some(bytes) = node.compute.memory_usage() {
+                    m.add_memory_usage(node_key, bytes as u64);
+                }
             }
 
             node.compute
@@ -119,6 +125,12 @@ impl Graph {
                 lock.lock()
                     .stop_state_update(node_key, elapsed.unwrap(), is_done);
             }
+            if let Some(trace) = trace
+                && let Some(before) = before
+            {
+                let transition = format!("{before} -> {recv_state:?} {send_state:?}");
+                crate::trace::record_state_update(trace, node_key, transition, start.unwrap());
+            }
             if verbose {
                 eprintln!(
                     "updating {}, after: {recv_state:?} {send_state:?} (took {:?})",
@@ -5,7 +5,7 @@ mod skeleton;
 
 use std::sync::LazyLock;
 
-pub use skeleton::{run_query, visualize_physical_plan};
+pub use skeleton::{explain_analyze, run_query, run_query_with_state, visualize_physical_plan};
 
 mod execute;
 pub use dispatch::build_streaming_query_executor;
@@ -20,6 +20,8 @@ mod nodes;
 mod physical_plan;
 pub use physical_plan::{NodeStyle, PhysNode, PhysNodeKey, PhysNodeKind, ZipBehavior};
 mod pipe;
+mod progress;
+mod trace;
 mod utils;
 
 // TODO: experiment with these.
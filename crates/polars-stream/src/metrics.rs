@@ -8,6 +8,7 @@ use crate::LogicalPipe;
 use crate::async_executor::TaskMetrics;
 use crate::graph::{GraphNodeKey, LogicalPipeKey};
 use crate::pipe::PipeMetrics;
+use crate::trace::TraceId;
 
 #[derive(Default, Clone)]
 pub struct NodeMetrics {
@@ -32,6 +33,12 @@ pub struct NodeMetrics {
     pub io_total_bytes_received: u64,
     pub io_total_bytes_sent: u64,
 
+    /// Wall time during which this node was running: for every phase, the time from the first
+    /// poll of one of its tasks until the last poll of its tasks ended.
+    pub total_wall_time_ns: u64,
+    /// Largest amount of memory buffered by this node between phases, if the node reports it.
+    pub peak_buffered_bytes: Option<u64>,
+
     pub state_update_in_progress: bool,
     pub num_running_tasks: u32,
     pub done: bool,
@@ -68,6 +75,10 @@ impl NodeMetrics {
         self.done = is_done;
     }
 
+    fn add_memory_usage(&mut self, bytes: u64) {
+        self.peak_buffered_bytes = Some(self.peak_buffered_bytes.unwrap_or(0).max(bytes));
+    }
+
     fn add_send_metrics(&mut self, pipe_metrics: &PipeMetrics) {
         self.morsels_sent += pipe_metrics.morsels_sent.load();
         self.rows_sent += pipe_metrics.rows_sent.load();
@@ -91,10 +102,30 @@ pub struct GraphMetrics {
     in_progress_io_metrics: SecondaryMap<GraphNodeKey, Vec<Arc<IOMetrics>>>,
     in_progress_task_metrics: SecondaryMap<GraphNodeKey, Vec<Arc<TaskMetrics>>>,
     in_progress_pipe_metrics: SecondaryMap<LogicalPipeKey, Vec<Arc<PipeMetrics>>>,
+
+    trace: Option<TraceId>,
+    // Kept alive for the whole query while tracing to attribute trace events to nodes.
+    traced_tasks: Vec<(GraphNodeKey, Arc<TaskMetrics>)>,
+    traced_io: Vec<(GraphNodeKey, Arc<IOMetrics>)>,
 }
 
 impl GraphMetrics {
+    /// Metrics which also keep what is needed to attribute the events of `trace` to nodes.
+    pub fn traced(trace: TraceId) -> Self {
+        Self {
+            trace: Some(trace),
+            ..Default::default()
+        }
+    }
+
+    pub fn trace(&self) -> Option<TraceId> {
+        self.trace
+    }
+
     pub fn add_task(&mut self, key: GraphNodeKey, task_metrics: Arc<TaskMetrics>) {
+        if self.trace.is_some() {
+            self.traced_tasks.push((key, Arc::clone(&task_metrics)));
+        }
         self.in_progress_task_metrics
             .entry(key)
             .unwrap()
@@ -122,12 +153,31 @@ impl GraphMetrics {
         self.node_metrics[key].stop_state_update(time, is_done);
     }
 
+    pub fn add_memory_usage(&mut self, key: GraphNodeKey, bytes: u64) {
+        self.node_metrics
+            .entry(key)
+            .unwrap()
+            .or_default()
+            .add_memory_usage(bytes);
+    }
+
     pub fn flush(&mut self, pipes: &SlotMap<LogicalPipeKey, LogicalPipe>) {
         for (key, in_progress_task_metrics) in self.in_progress_task_metrics.iter_mut() {
             let this_node_metrics = self.node_metrics.entry(key).unwrap().or_default();
             this_node_metrics.num_running_tasks = 0;
+            // The tasks are flushed after every phase, so their polls span the time this node
+            // was running in the last phase.
+            let mut span: Option<(u64, u64)> = None;
             for task_metrics in in_progress_task_metrics.drain(..) {
                 this_node_metrics.add_task(&task_metrics);
+                if task_metrics.total_polls.load() > 0 {
+                    let start = task_metrics.first_poll_start_ns.load();
+                    let end = task_metrics.last_poll_end_ns.load();
+                    span = Some(span.map_or((start, end), |(s, e)| (s.min(start), e.max(end))));
+                }
+            }
+            if let Some((start, end)) = span {
+                this_node_metrics.total_wall_time_ns += end.saturating_sub(start);
             }
         }
 
@@ -163,6 +213,14 @@ impl GraphMetrics {
     pub fn iter(&self) -> slotmap::secondary::Iter<'_, GraphNodeKey, NodeMetrics> {
         self.node_metrics.iter()
     }
+
+    pub fn traced_tasks(&self) -> impl Iterator<Item = (GraphNodeKey, &Arc<TaskMetrics>)> {
+        self.traced_tasks.iter().map(|(k, m)| (*k, m))
+    }
+
+    pub fn traced_io(&self) -> impl Iterator<Item = (GraphNodeKey, &Arc<IOMetrics>)> {
+        self.traced_io.iter().map(|(k, m)| (*k, m))
+    }
 }
 
 pub struct MetricsBuilder {
@@ -172,10 +230,20 @@ pub struct MetricsBuilder {
 
 impl MetricsBuilder {
     pub fn new_io_metrics(&self) -> Arc<IOMetrics> {
-        let io_metrics: Arc<IOMetrics> = Default::default();
-
-        self.graph_metrics
-            .lock()
+        let mut graph_metrics = self.graph_metrics.lock();
+        let tracing = graph_metrics.trace.is_some();
+        let io_metrics: Arc<IOMetrics> = if tracing {
+            Arc::new(IOMetrics::with_sessions())
+        } else {
+            Default::default()
+        };
+
+        if tracing {
+            graph_metrics
+                .traced_io
+                .push((self.graph_key, Arc::clone(&io_metrics)));
+        }
+        graph_metrics
             .in_progress_io_metrics
             .entry(self.graph_key)
             .unwrap()
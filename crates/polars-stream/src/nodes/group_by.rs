@@ -707,4 +707,18 @@ impl ComputeNode for GroupByNode {
             GroupByState::Done => unreachable!(),
         }
     }
+
+    fn memory_usage(&self) -> Option<usize> {
+        match &self.state {
+            GroupByState::Sink(sink) => Some(
+                sink.locals
+                    .iter()
+                    .flat_map(|l| &l.cold_morsels)
+                    .map(|(_, _, _, token)| token.size_bytes())
+                    .sum(),
+            ),
+            GroupByState::Source(source) => source.memory_usage(),
+            GroupByState::Done => None,
+        }
+    }
 }
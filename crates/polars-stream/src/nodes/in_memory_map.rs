@@ -76,6 +76,13 @@ impl ComputeNode for InMemoryMapNode {
         matches!(self, Self::Sink { .. })
     }
 
+    fn memory_usage(&self) -> Option<usize> {
+        match self {
+            Self::Sink { sink_node, .. } => sink_node.memory_usage(),
+            Self::Source(_) | Self::Done => None,
+        }
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
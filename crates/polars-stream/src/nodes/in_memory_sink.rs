@@ -6,12 +6,14 @@ use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_ooc::AccessPattern::NoPattern;
 
 use super::compute_node_prelude::*;
+use crate::progress::SinkCounters;
 use crate::utils::in_memory_linearize::linearize;
 
 #[derive(Debug)]
 pub struct InMemorySinkNode {
     morsels_per_pipe: Mutex<Vec<Vec<(MorselSeq, Token)>>>,
     schema: Arc<Schema>,
+    progress: Option<Arc<SinkCounters>>,
 }
 
 impl InMemorySinkNode {
@@ -19,6 +21,7 @@ impl InMemorySinkNode {
         Self {
             morsels_per_pipe: Mutex::default(),
             schema,
+            progress: None,
         }
     }
 }
@@ -49,23 +52,43 @@ impl ComputeNode for InMemorySinkNode {
         true
     }
 
+    fn memory_usage(&self) -> Option<usize> {
+        let morsels_per_pipe = self.morsels_per_pipe.lock();
+        Some(
+            morsels_per_pipe
+                .iter()
+                .flatten()
+                .map(|(_, token)| token.size_bytes())
+                .sum(),
+        )
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
         recv_ports: &mut [Option<RecvPort<'_>>],
         send_ports: &mut [Option<SendPort<'_>>],
-        _state: &'s StreamingExecutionState,
+        state: &'s StreamingExecutionState,
         join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
     ) {
         assert!(recv_ports.len() == 1 && send_ports.is_empty());
         let receivers = recv_ports[0].take().unwrap().parallel();
 
+        if self.progress.is_none()
+            && let Some(tracker) = &state.progress
+        {
+            self.progress = Some(tracker.register_sink(self.name()));
+        }
+
         for mut recv in receivers {
             let slf = &*self;
             join_handles.push(scope.spawn_task(TaskPriority::High, async move {
                 let mut morsels = Vec::new();
                 while let Ok(mut morsel) = recv.recv().await {
                     morsel.take_consume_token();
+                    if let Some(progress) = &slf.progress {
+                        progress.rows_written.fetch_add(morsel.df().height() as u64);
+                    }
                     morsels.push(morsel.store_into_token_and_seq(NoPattern).await);
                 }
 
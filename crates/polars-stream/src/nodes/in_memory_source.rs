@@ -40,6 +40,10 @@ impl ComputeNode for InMemorySourceNode {
         "in-memory-source"
     }
 
+    fn memory_usage(&self) -> Option<usize> {
+        self.source.as_ref().map(|df| df.estimated_size())
+    }
+
     fn update_state(
         &mut self,
         recv: &mut [PortState],
@@ -1,14 +1,17 @@
 use std::sync::Arc;
 
-use polars_error::{PolarsResult, polars_ensure};
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
 use polars_io::cloud::CloudOptions;
 use polars_io::metrics::IOMetrics;
 use polars_io::pl_async;
 use polars_io::utils::file::Writeable;
+use polars_io::utils::staged_commit::StagedCommit;
 use polars_plan::dsl::file_provider::{FileProviderReturn, FileProviderType};
 use polars_plan::prelude::file_provider::FileProviderArgs;
 use polars_utils::pl_path::PlRefPath;
 
+use crate::nodes::io_sinks::components::output_files::OutputFiles;
+
 pub struct FileProvider {
     pub base_path: PlRefPath,
     pub cloud_options: Option<Arc<CloudOptions>>,
@@ -16,6 +19,9 @@ pub struct FileProvider {
     pub upload_chunk_size: usize,
     pub upload_max_concurrency: usize,
     pub io_metrics: Option<Arc<IOMetrics>>,
+    /// If set, files are written to the staging location of this commit instead of `base_path`.
+    pub staged_commit: Option<Arc<StagedCommit>>,
+    pub output_files: Arc<OutputFiles>,
 }
 
 impl FileProvider {
@@ -33,6 +39,12 @@ impl FileProvider {
 
                 match out {
                     FileProviderReturn::Path(p) => p,
+                    FileProviderReturn::Writeable(_) if self.staged_commit.is_some() => {
+                        polars_bail!(
+                            InvalidOperation:
+                            "staged commit protocol requires the file path provider to return paths"
+                        )
+                    },
                     FileProviderReturn::Writeable(v) => return Ok(v),
                 }
             },
@@ -58,6 +70,15 @@ impl FileProvider {
             "provided path '{provided_path}' contained parent dir component '..'"
         );
 
+        let path = if let Some(staged_commit) = &self.staged_commit {
+            let relative_path =
+                path.as_str()[self.base_path.as_str().len()..].trim_start_matches(['/', '\\']);
+
+            staged_commit.stage_file(relative_path)?
+        } else {
+            path
+        };
+
         if !path.has_scheme()
             && let Some(path) = path.parent()
         {
@@ -69,12 +90,18 @@ impl FileProvider {
                 .await;
         }
 
-        Writeable::try_new(
-            path,
+        let writeable = Writeable::try_new(
+            path.clone(),
             self.cloud_options.as_deref(),
             self.upload_chunk_size,
             self.upload_max_concurrency,
             self.io_metrics.clone(),
-        )
+        )?;
+
+        if self.staged_commit.is_none() {
+            self.output_files.register(path);
+        }
+
+        Ok(writeable)
     }
 }
@@ -0,0 +1,52 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use polars_io::cloud::CloudOptions;
+use polars_io::utils::staged_commit::remove_direct_file;
+use polars_utils::pl_path::PlRefPath;
+
+/// Files written by a sink directly to their final location, removed again if the query is aborted
+/// before the sink completed.
+///
+/// Files written through a staged commit are not tracked here, they are removed together with the
+/// staging location instead.
+pub struct OutputFiles {
+    cloud_options: Option<Arc<CloudOptions>>,
+    paths: Mutex<Vec<PlRefPath>>,
+    aborted: AtomicBool,
+}
+
+impl OutputFiles {
+    pub fn new(cloud_options: Option<Arc<CloudOptions>>) -> Self {
+        Self {
+            cloud_options,
+            paths: Mutex::new(vec![]),
+            aborted: AtomicBool::new(false),
+        }
+    }
+
+    /// Register a file that was opened for writing.
+    pub fn register(&self, path: PlRefPath) {
+        self.paths.lock().unwrap().push(path);
+    }
+
+    /// Mark the sink as aborted. Pipelines check this before publishing their output.
+    pub fn set_aborted(&self) {
+        self.aborted.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_aborted(&self) -> bool {
+        self.aborted.load(Ordering::Relaxed)
+    }
+
+    /// Remove all registered files. Errors are ignored.
+    pub async fn remove_all(&self) -> usize {
+        let paths = std::mem::take(&mut *self.paths.lock().unwrap());
+
+        for path in &paths {
+            remove_direct_file(path, self.cloud_options.as_deref()).await;
+        }
+
+        paths.len()
+    }
+}
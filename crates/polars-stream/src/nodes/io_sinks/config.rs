@@ -1,6 +1,7 @@
 use std::num::NonZeroUsize;
 
 use polars_core::schema::SchemaRef;
+use polars_io::utils::commit_protocol::CommitProtocol;
 use polars_plan::dsl::file_provider::FileProviderType;
 use polars_plan::dsl::{FileWriteFormat, SinkTarget, UnifiedSinkArgs};
 use polars_utils::pl_path::{CloudScheme, PlRefPath};
@@ -93,4 +94,5 @@ pub struct PartitionedTarget {
     pub include_keys_in_file: bool,
     pub file_schema: SchemaRef,
     pub file_size_limit: Option<NonZeroRowCountAndSize>,
+    pub commit_protocol: CommitProtocol,
 }
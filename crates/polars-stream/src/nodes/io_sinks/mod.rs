@@ -14,6 +14,7 @@ use crate::execute::StreamingExecutionState;
 use crate::metrics::MetricsBuilder;
 use crate::morsel::{Morsel, MorselSeq, SourceToken};
 use crate::nodes::TaskPriority;
+use crate::nodes::io_sinks::components::output_files::OutputFiles;
 use crate::nodes::io_sinks::components::partitioner::Partitioner;
 use crate::nodes::io_sinks::config::{IOSinkNodeConfig, IOSinkTarget};
 use crate::nodes::io_sinks::pipeline_initialization::partition_by::start_partition_sink_pipeline;
@@ -28,6 +29,8 @@ pub struct IOSinkNode {
     name: PlSmallStr,
     state: IOSinkNodeState,
     io_metrics: Option<Arc<IOMetrics>>,
+    output_files: Arc<OutputFiles>,
+    completed: bool,
     verbose: bool,
 }
 
@@ -48,10 +51,16 @@ impl IOSinkNode {
         let name = format_pl_smallstr!("io-sink[{target_type}[{extension}]]");
         let verbose = polars_core::config::verbose();
 
+        let output_files = Arc::new(OutputFiles::new(
+            config.unified_sink_args.cloud_options.clone(),
+        ));
+
         IOSinkNode {
             name,
             state: IOSinkNodeState::Uninitialized { config },
             io_metrics: None,
+            output_files,
+            completed: false,
             verbose,
         }
     }
@@ -66,6 +75,39 @@ impl ComputeNode for IOSinkNode {
         self.io_metrics = Some(metrics_builder.new_io_metrics());
     }
 
+    fn abort(&mut self) {
+        if self.completed {
+            return;
+        }
+
+        self.output_files.set_aborted();
+
+        if let IOSinkNodeState::Initialized {
+            phase_channel_tx,
+            task_handle,
+        } = std::mem::replace(&mut self.state, IOSinkNodeState::Finished)
+        {
+            if self.verbose {
+                eprintln!("{}: Join on task_handle (abort)", self.name());
+            }
+
+            // The tasks sending morsels were cancelled together with the query, so the writers
+            // stop once they have drained their inputs. Wait for them so that no file is written
+            // to after it was removed. Staged commits are rolled back instead of published.
+            drop(phase_channel_tx);
+            let _ = pl_async::get_runtime().block_on(task_handle);
+        }
+
+        let num_removed = pl_async::get_runtime().block_on(self.output_files.remove_all());
+
+        if self.verbose && num_removed > 0 {
+            eprintln!(
+                "{}: removed {num_removed} partially written files",
+                self.name()
+            );
+        }
+    }
+
     fn update_state(
         &mut self,
         recv: &mut [crate::graph::PortState],
@@ -77,8 +119,12 @@ impl ComputeNode for IOSinkNode {
 
         recv[0] = if recv[0] == PortState::Done {
             // Ensure initialize / writes empty file for empty output.
-            self.state
-                .initialize(&self.name, execution_state, self.io_metrics.clone())?;
+            self.state.initialize(
+                &self.name,
+                execution_state,
+                self.io_metrics.clone(),
+                &self.output_files,
+            )?;
 
             match std::mem::replace(&mut self.state, IOSinkNodeState::Finished) {
                 IOSinkNodeState::Initialized {
@@ -93,6 +139,7 @@ impl ComputeNode for IOSinkNode {
                     }
                     drop(phase_channel_tx);
                     pl_async::get_runtime().block_on(task_handle)?;
+                    self.completed = true;
                 },
                 IOSinkNodeState::Finished => {},
                 IOSinkNodeState::Uninitialized { .. } => unreachable!(),
@@ -127,8 +174,12 @@ impl ComputeNode for IOSinkNode {
         let phase_morsel_rx = recv_ports[0].take().unwrap().serial();
 
         join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
-            self.state
-                .initialize(&self.name, execution_state, self.io_metrics.clone())?;
+            self.state.initialize(
+                &self.name,
+                execution_state,
+                self.io_metrics.clone(),
+                &self.output_files,
+            )?;
 
             let IOSinkNodeState::Initialized {
                 phase_channel_tx, ..
@@ -184,6 +235,7 @@ impl IOSinkNodeState {
         node_name: &PlSmallStr,
         execution_state: &StreamingExecutionState,
         io_metrics: Option<Arc<IOMetrics>>,
+        output_files: &Arc<OutputFiles>,
     ) -> PolarsResult<()> {
         use IOSinkNodeState::*;
 
@@ -204,6 +256,11 @@ impl IOSinkNodeState {
             SourceToken::default(),
         ));
 
+        let progress = execution_state
+            .progress
+            .as_ref()
+            .map(|tracker| tracker.register_sink(node_name));
+
         async_executor::spawn(TaskPriority::High, async move {
             let mut morsel_seq: u64 = 1;
 
@@ -212,6 +269,10 @@ impl IOSinkNodeState {
                     morsel.set_seq(MorselSeq::new(morsel_seq));
                     morsel_seq = morsel_seq.saturating_add(1);
 
+                    if let Some(progress) = &progress {
+                        progress.rows_written.fetch_add(morsel.df().height() as u64);
+                    }
+
                     if multi_phase_tx.send(morsel).await.is_err() {
                         break;
                     }
@@ -226,6 +287,7 @@ impl IOSinkNodeState {
                 *config,
                 execution_state,
                 io_metrics,
+                Arc::clone(output_files),
             )?,
 
             IOSinkTarget::Partitioned { .. } => start_partition_sink_pipeline(
@@ -234,6 +296,7 @@ impl IOSinkNodeState {
                 *config,
                 execution_state,
                 io_metrics,
+                Arc::clone(output_files),
             )?,
         };
 
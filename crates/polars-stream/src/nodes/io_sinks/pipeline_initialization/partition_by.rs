@@ -1,8 +1,10 @@
 use std::num::NonZeroUsize;
 use std::sync::Arc;
 
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_err};
 use polars_io::metrics::IOMetrics;
+use polars_io::utils::commit_protocol::CommitProtocol;
+use polars_io::utils::staged_commit::StagedCommit;
 use polars_plan::dsl::UnifiedSinkArgs;
 use polars_utils::pl_str::PlSmallStr;
 
@@ -12,6 +14,7 @@ use crate::execute::StreamingExecutionState;
 use crate::morsel::Morsel;
 use crate::nodes::io_sinks::components::error_capture::ErrorCapture;
 use crate::nodes::io_sinks::components::file_provider::FileProvider;
+use crate::nodes::io_sinks::components::output_files::OutputFiles;
 use crate::nodes::io_sinks::components::partition_distributor::PartitionDistributor;
 use crate::nodes::io_sinks::components::partition_morsel_sender::PartitionMorselSender;
 use crate::nodes::io_sinks::components::partition_sink_starter::PartitionSinkStarter;
@@ -28,6 +31,7 @@ pub fn start_partition_sink_pipeline(
     config: IOSinkNodeConfig,
     execution_state: &StreamingExecutionState,
     io_metrics: Option<Arc<IOMetrics>>,
+    output_files: Arc<OutputFiles>,
 ) -> PolarsResult<async_executor::AbortOnDropHandle<PolarsResult<()>>> {
     let num_pipelines: NonZeroUsize = execution_state.num_pipelines.try_into().unwrap();
 
@@ -61,6 +65,7 @@ pub fn start_partition_sink_pipeline(
         include_keys_in_file,
         file_schema,
         file_size_limit,
+        commit_protocol,
     } = *target;
 
     let node_name = node_name.clone();
@@ -68,13 +73,24 @@ pub fn start_partition_sink_pipeline(
     let in_memory_exec_state = Arc::new(execution_state.in_memory_exec_state.clone());
     let io_metrics_is_some = io_metrics.is_some();
 
+    let write_id = uuid::Uuid::now_v7();
+
     if let Some(file_part_prefix) = file_path_provider.file_part_prefix_mut() {
         use std::fmt::Write as _;
-        let uuid = uuid::Uuid::now_v7();
-        let uuid = uuid.as_simple();
+        let uuid = write_id.as_simple();
         write!(file_part_prefix, "{uuid}").unwrap();
     }
 
+    let staged_commit: Option<Arc<StagedCommit>> = match commit_protocol {
+        CommitProtocol::Direct => None,
+        CommitProtocol::Staged { .. } => Some(Arc::new(StagedCommit::new(
+            base_path.clone(),
+            &write_id.to_string(),
+            commit_protocol,
+            cloud_options.clone(),
+        )?)),
+    };
+
     let file_provider = Arc::new(FileProvider {
         base_path,
         cloud_options,
@@ -82,6 +98,8 @@ pub fn start_partition_sink_pipeline(
         upload_chunk_size,
         upload_max_concurrency: upload_max_concurrency.get(),
         io_metrics,
+        staged_commit: staged_commit.clone(),
+        output_files: Arc::clone(&output_files),
     });
 
     let file_writer_starter: Arc<dyn FileWriterStarter> =
@@ -105,6 +123,7 @@ pub fn start_partition_sink_pipeline(
             file_size_limit: {:?}, \
             upload_chunk_size: {}, \
             upload_concurrency: {}, \
+            commit_protocol: {:?}, \
             io_metrics: {}",
             partitioner.verbose_display(),
             file_writer_starter.writer_name(),
@@ -115,6 +134,7 @@ pub fn start_partition_sink_pipeline(
             file_size_limit,
             upload_chunk_size,
             upload_max_concurrency,
+            commit_protocol,
             io_metrics_is_some,
         );
     }
@@ -164,7 +184,7 @@ pub fn start_partition_sink_pipeline(
         async_executor::AbortOnDropHandle::new(async_executor::spawn(
             TaskPriority::High,
             PartitionDistributor {
-                node_name,
+                node_name: node_name.clone(),
                 partitioned_dfs_rx,
                 partition_morsel_sender,
                 error_capture,
@@ -181,9 +201,48 @@ pub fn start_partition_sink_pipeline(
     let handle = async_executor::AbortOnDropHandle::new(async_executor::spawn(
         TaskPriority::Low,
         async move {
+            let Some(staged_commit) = staged_commit else {
+                partitioner_handle.await;
+                partition_distributor_handle.await?;
+                return Ok(());
+            };
+
+            let orphan_max_age = polars_io::configs::staged_commit_orphan_max_age();
+
+            if let Err(e) = staged_commit.cleanup_orphaned(orphan_max_age).await
+                && verbose
+            {
+                eprintln!("{node_name}: failed to clean up orphaned staging files: {e}");
+            }
+
             partitioner_handle.await;
-            partition_distributor_handle.await?;
-            Ok(())
+
+            let result = match partition_distributor_handle.await {
+                Ok(()) if output_files.is_aborted() => {
+                    Err(polars_err!(ComputeError: "sink was aborted"))
+                },
+                Ok(()) => staged_commit.commit().await,
+                Err(e) => Err(e),
+            };
+
+            if result.is_err() {
+                staged_commit.abort().await;
+            }
+
+            if verbose {
+                eprintln!(
+                    "{node_name}: staged commit of {} files to {}: {}",
+                    staged_commit.staged_files().len(),
+                    staged_commit.base_path(),
+                    if result.is_ok() {
+                        "committed"
+                    } else {
+                        "aborted"
+                    },
+                );
+            }
+
+            result
         },
     ));
 
@@ -5,7 +5,7 @@ use polars_core::frame::DataFrame;
 use polars_error::PolarsResult;
 use polars_io::metrics::IOMetrics;
 use polars_io::pl_async;
-use polars_plan::dsl::UnifiedSinkArgs;
+use polars_plan::dsl::{SinkTarget, UnifiedSinkArgs};
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::async_executor::{self, TaskPriority};
@@ -13,6 +13,7 @@ use crate::async_primitives::connector;
 use crate::execute::StreamingExecutionState;
 use crate::morsel::Morsel;
 use crate::nodes::io_sinks::components::morsel_resize_pipeline::MorselResizePipeline;
+use crate::nodes::io_sinks::components::output_files::OutputFiles;
 use crate::nodes::io_sinks::config::{IOSinkNodeConfig, IOSinkTarget};
 use crate::nodes::io_sinks::writers::create_file_writer_starter;
 use crate::nodes::io_sinks::writers::interface::{FileOpenTaskHandle, FileWriterStarter};
@@ -24,6 +25,7 @@ pub fn start_single_file_sink_pipeline(
     config: IOSinkNodeConfig,
     execution_state: &StreamingExecutionState,
     io_metrics: Option<Arc<IOMetrics>>,
+    output_files: Arc<OutputFiles>,
 ) -> PolarsResult<async_executor::AbortOnDropHandle<PolarsResult<()>>> {
     let num_pipelines: NonZeroUsize = execution_state.num_pipelines.try_into().unwrap();
 
@@ -54,7 +56,7 @@ pub fn start_single_file_sink_pipeline(
     let file_open_task = {
         let io_metrics = io_metrics.clone();
         tokio_handle_ext::AbortOnDropHandle(pl_async::get_runtime().spawn(async move {
-            target
+            let writeable = target
                 .open_into_writeable_async(
                     cloud_options.as_deref(),
                     mkdir,
@@ -62,7 +64,13 @@ pub fn start_single_file_sink_pipeline(
                     upload_max_concurrency.get(),
                     io_metrics,
                 )
-                .await
+                .await?;
+
+            if let SinkTarget::Path(path) = target {
+                output_files.register(path);
+            }
+
+            Ok(writeable)
         }))
     };
     let file_open_task = FileOpenTaskHandle::new(file_open_task, sync_on_close);
@@ -1,15 +1,19 @@
+use std::io::Write;
 use std::sync::Arc;
 
 use arrow::io::ipc::IpcField;
-use arrow::io::ipc::write::common_sync::{push_footer, push_magic, push_message};
+use arrow::io::ipc::write::common_sync::{
+    push_footer, push_magic, push_message, write_continuation, write_message,
+};
 use arrow::io::ipc::write::schema::schema_to_bytes;
-use arrow::io::ipc::write::{EncodedDataBytes, arrow_ipc_block};
+use arrow::io::ipc::write::{EncodedData, EncodedDataBytes, arrow_ipc_block};
 use bytes::Bytes;
 use polars_core::schema::SchemaRef;
 use polars_core::utils::arrow;
 use polars_error::PolarsResult;
 use polars_io::ipc::{IpcWriter, IpcWriterOptions};
 use polars_io::utils::file::Writeable;
+use polars_io::utils::sync_on_close::SyncOnCloseType;
 use polars_io::{SerWriter, schema_to_arrow_checked};
 
 use crate::nodes::io_sinks::writers::interface::FileOpenTaskHandle;
@@ -35,6 +39,18 @@ impl IOWriter {
 
         let (file, sync_on_close) = file.await?;
 
+        if options.stream {
+            return write_ipc_stream(
+                file,
+                sync_on_close,
+                ipc_batch_rx,
+                &options,
+                &schema,
+                &ipc_fields,
+            )
+            .await;
+        }
+
         match file {
             Writeable::Cloud(cloudwriter) => {
                 // The zero-copy implementation takes ownership of the encoded data and, after
@@ -154,3 +170,47 @@ impl IOWriter {
         Ok(())
     }
 }
+
+/// Writes the Arrow IPC streaming format: the schema message followed by dictionary and record
+/// batch messages in arrival order, terminated by an end-of-stream marker. Unlike the file format
+/// there is no footer, so readers can consume the output incrementally.
+async fn write_ipc_stream(
+    mut file: Writeable,
+    sync_on_close: SyncOnCloseType,
+    mut ipc_batch_rx: tokio::sync::mpsc::Receiver<IpcBatch>,
+    options: &IpcWriterOptions,
+    schema: &SchemaRef,
+    ipc_fields: &[IpcField],
+) -> PolarsResult<()> {
+    let mut buffered_file = file.as_buffered();
+    let mut writer = &mut *buffered_file;
+
+    let schema = schema_to_arrow_checked(schema, options.compat_level, "ipc")?;
+    let encoded_schema = EncodedData {
+        ipc_message: schema_to_bytes(&schema, ipc_fields, None),
+        arrow_data: vec![],
+    };
+    write_message(&mut writer, &encoded_schema)?;
+
+    while let Some(batch) = ipc_batch_rx.recv().await {
+        match batch {
+            IpcBatch::Record(handle, sink_morsel_permit) => {
+                let encoded_data = handle.await;
+                write_message(&mut writer, &encoded_data)?;
+                drop(encoded_data);
+                drop(sink_morsel_permit);
+            },
+            IpcBatch::Dictionary(dictionary_data) => {
+                write_message(&mut writer, &dictionary_data)?;
+            },
+        }
+    }
+
+    write_continuation(&mut writer, 0)?;
+    writer.flush()?;
+
+    drop(buffered_file);
+    file.close(sync_on_close)?;
+
+    Ok(())
+}
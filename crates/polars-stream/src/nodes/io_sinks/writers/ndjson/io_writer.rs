@@ -44,6 +44,12 @@ impl IOWriter {
             ExternalCompression::Zstd { level } => AsyncWriteable::Dyn(AsyncDynWriteable(
                 Box::new(CompressedWriter::zstd(writable, level)?),
             )),
+            ExternalCompression::Bzip2 { level } => AsyncWriteable::Dyn(AsyncDynWriteable(
+                Box::new(CompressedWriter::bzip2(writable, level)?),
+            )),
+            ExternalCompression::Xz { level } => AsyncWriteable::Dyn(AsyncDynWriteable(Box::new(
+                CompressedWriter::xz(writable, level)?,
+            ))),
         };
 
         while let Some((handle, permit)) = filled_serializer_rx.recv().await {
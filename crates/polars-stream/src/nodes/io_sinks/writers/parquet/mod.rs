@@ -89,7 +89,7 @@ impl FileWriterStarter for ParquetWriterStarter {
 
             if initialized_state.is_none() {
                 let schema_descriptor = Arc::new(to_parquet_schema(&self.arrow_schema)?);
-                let encodings = get_encodings(&self.arrow_schema);
+                let encodings = get_encodings(&self.arrow_schema)?;
 
                 *initialized_state = Some(InitializedState {
                     encodings,
@@ -74,6 +74,7 @@ impl DeletionFilesProvider {
                     prefetch_semaphore: std::sync::OnceLock::new(),
                     shared_prefetch_wait_group_slot: Default::default(),
                     io_metrics: io_metrics.map(OnceLock::from).unwrap_or_default(),
+                    progress: OnceLock::new(),
                 };
 
                 reader_builder.set_execution_state(execution_state);
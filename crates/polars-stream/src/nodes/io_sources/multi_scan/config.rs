@@ -19,6 +19,7 @@ use crate::metrics::IOMetrics;
 use crate::nodes::io_sources::multi_scan::components::forbid_extra_columns::ForbidExtraColumns;
 use crate::nodes::io_sources::multi_scan::components::projection::builder::ProjectionBuilder;
 use crate::nodes::io_sources::multi_scan::reader_interface;
+use crate::progress::SourceCounters;
 
 // Some parts are called MultiScan for now to avoid conflict with existing MultiScan.
 
@@ -52,6 +53,7 @@ pub struct MultiScanConfig {
     pub max_concurrent_scans: RelaxedCell<usize>,
     pub disable_morsel_split: bool,
     pub io_metrics: OnceLock<Arc<IOMetrics>>,
+    pub progress: OnceLock<Arc<SourceCounters>>,
 
     pub verbose: bool,
 }
@@ -73,6 +75,10 @@ impl MultiScanConfig {
         self.io_metrics.get().cloned()
     }
 
+    pub fn progress(&self) -> Option<Arc<SourceCounters>> {
+        self.progress.get().cloned()
+    }
+
     pub fn reader_capabilities(&self) -> ReaderCapabilities {
         if std::env::var("POLARS_FORCE_EMPTY_READER_CAPABILITIES").as_deref() == Ok("1") {
             self.file_reader_builder.reader_capabilities()
@@ -105,7 +105,7 @@ impl ComputeNode for MultiScan {
             use MultiScanState::*;
 
             self.state
-                .initialize(state.clone(), self.metrics_builder.as_ref());
+                .initialize(&self.name, state.clone(), self.metrics_builder.as_ref());
             self.state.refresh(verbose).await?;
 
             match &mut self.state {
@@ -173,6 +173,7 @@ impl MultiScanState {
     /// Initialize state if not yet initialized.
     fn initialize(
         &mut self,
+        node_name: &str,
         execution_state: StreamingExecutionState,
         metrics_builder: Option<&MetricsBuilder>,
     ) {
@@ -196,6 +197,13 @@ impl MultiScanState {
             config.file_reader_builder.set_io_metrics(io_metrics);
         }
 
+        if let Some(tracker) = &execution_state.progress {
+            let progress = tracker.register_source(node_name);
+            progress.files_total.store(config.sources.len() as u64);
+            config.file_reader_builder.set_progress(progress.clone());
+            config.progress.get_or_init(|| progress);
+        }
+
         let num_pipelines = execution_state.num_pipelines;
 
         config.num_pipelines.store(num_pipelines);
@@ -439,6 +439,7 @@ async fn finish_initialize_multi_scan_pipeline(
         AttachReaderToBridge {
             started_reader_rx,
             bridge_recv_port_tx,
+            progress: config.progress(),
             verbose,
         }
         .run(),
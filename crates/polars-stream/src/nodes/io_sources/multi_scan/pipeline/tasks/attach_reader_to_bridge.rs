@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use polars_error::PolarsResult;
 
 use crate::async_executor::AbortOnDropHandle;
@@ -5,6 +7,7 @@ use crate::async_primitives::connector;
 use crate::async_primitives::wait_group::WaitToken;
 use crate::nodes::io_sources::multi_scan::components::bridge::BridgeRecvPort;
 use crate::nodes::io_sources::multi_scan::pipeline::models::StartedReaderState;
+use crate::progress::SourceCounters;
 
 pub struct AttachReaderToBridge {
     /// The size of the channel controls how many readers are run in parallel.
@@ -13,6 +16,7 @@ pub struct AttachReaderToBridge {
         WaitToken,
     )>,
     pub bridge_recv_port_tx: connector::Sender<BridgeRecvPort>,
+    pub progress: Option<Arc<SourceCounters>>,
     pub verbose: bool,
 }
 
@@ -21,6 +25,7 @@ impl AttachReaderToBridge {
         let AttachReaderToBridge {
             mut started_reader_rx,
             mut bridge_recv_port_tx,
+            progress,
             verbose,
         } = self;
 
@@ -51,6 +56,10 @@ impl AttachReaderToBridge {
             if let Some(handle) = post_apply_pipeline_handle {
                 handle.await?;
             }
+
+            if let Some(progress) = &progress {
+                progress.files_done.fetch_add(1);
+            }
         }
 
         Ok(())
@@ -10,6 +10,7 @@ use super::FileReader;
 use super::capabilities::ReaderCapabilities;
 use crate::execute::StreamingExecutionState;
 use crate::metrics::IOMetrics;
+use crate::progress::SourceCounters;
 
 pub trait FileReaderBuilder: Debug + Send + Sync + 'static {
     fn reader_name(&self) -> &str;
@@ -21,6 +22,9 @@ pub trait FileReaderBuilder: Debug + Send + Sync + 'static {
 
     fn set_io_metrics(&self, _io_metrics: Arc<IOMetrics>) {}
 
+    /// Used by readers that report progress at a finer granularity than files, e.g. row groups.
+    fn set_progress(&self, _progress: Arc<SourceCounters>) {}
+
     fn build_file_reader(
         &self,
         source: ScanSource,
@@ -13,6 +13,7 @@ use crate::async_primitives::wait_group::WaitGroup;
 use crate::metrics::{IOMetrics, OptIOMetrics};
 use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
 use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+use crate::progress::SourceCounters;
 
 #[derive(Clone)]
 pub struct ParquetReaderBuilder {
@@ -22,6 +23,7 @@ pub struct ParquetReaderBuilder {
     pub prefetch_semaphore: std::sync::OnceLock<Arc<tokio::sync::Semaphore>>,
     pub shared_prefetch_wait_group_slot: Arc<std::sync::Mutex<Option<WaitGroup>>>,
     pub io_metrics: std::sync::OnceLock<Arc<IOMetrics>>,
+    pub progress: std::sync::OnceLock<Arc<SourceCounters>>,
 }
 
 impl std::fmt::Debug for ParquetReaderBuilder {
@@ -92,6 +94,10 @@ impl FileReaderBuilder for ParquetReaderBuilder {
         let _ = self.io_metrics.set(io_metrics);
     }
 
+    fn set_progress(&self, progress: Arc<SourceCounters>) {
+        let _ = self.progress.set(progress);
+    }
+
     fn build_file_reader(
         &self,
         source: ScanSource,
@@ -131,6 +137,7 @@ impl FileReaderBuilder for ParquetReaderBuilder {
                 current_all_spawned: None,
             },
             io_metrics: OptIOMetrics(self.io_metrics.get().cloned()),
+            progress: self.progress.get().cloned(),
             verbose,
 
             init_data: None,
@@ -61,6 +61,7 @@ impl ParquetReadImpl {
 
         let row_index = self.row_index.clone();
 
+        let progress = self.progress.clone();
         let rg_prefetch_semaphore = Arc::clone(&self.rg_prefetch_semaphore);
         let rg_prefetch_prev_all_spawned = Option::take(&mut self.rg_prefetch_prev_all_spawned);
         let rg_prefetch_current_all_spawned =
@@ -132,6 +133,25 @@ impl ParquetReadImpl {
             )
             .await?;
 
+            if let Some(progress) = &progress {
+                let (num_row_groups, num_bytes) = row_group_slice
+                    .clone()
+                    .filter(|i| {
+                        !row_group_mask
+                            .as_ref()
+                            .is_some_and(|m| m.get_bit(i - row_group_slice.start))
+                    })
+                    .fold((0, 0), |(n, bytes), i| {
+                        (
+                            n + 1,
+                            bytes + metadata.row_groups[i].compressed_size() as u64,
+                        )
+                    });
+                progress.row_groups_total.fetch_add(num_row_groups);
+                progress.bytes_total.fetch_add(num_bytes);
+                progress.files_sized.fetch_add(1);
+            }
+
             let mut row_group_data_fetcher = RowGroupDataFetcher {
                 projection: projected_arrow_fields.clone(),
                 is_full_projection,
@@ -168,12 +188,20 @@ impl ParquetReadImpl {
 
         // Decode loop (spawns decodes on the computational executor).
         let (decode_send, mut decode_recv) = tokio::sync::mpsc::channel(self.config.num_pipelines);
+        let progress = self.progress.clone();
         let decode_task = AbortOnDropHandle(io_runtime.spawn(async move {
             while let Some((prefetch_task, permit)) = prefetch_recv.recv().await {
                 let row_group_data = prefetch_task.await.unwrap()?;
                 let row_group_decoder = row_group_decoder.clone();
+                let progress = progress.clone();
                 let decode_fut = async_executor::spawn(TaskPriority::High, async move {
-                    row_group_decoder.row_group_data_to_df(row_group_data).await
+                    let num_bytes = row_group_data.row_group_metadata.compressed_size() as u64;
+                    let df = row_group_decoder.row_group_data_to_df(row_group_data).await;
+                    if let Some(progress) = &progress {
+                        progress.row_groups_done.fetch_add(1);
+                        progress.bytes_read.fetch_add(num_bytes);
+                    }
+                    df
                 });
                 if decode_send.send((decode_fut, permit)).await.is_err() {
                     break;
@@ -29,6 +29,7 @@ use crate::nodes::io_sources::parquet::projection::{
     ArrowFieldProjection, resolve_arrow_field_projections,
 };
 use crate::nodes::{TaskPriority, io_sources};
+use crate::progress::SourceCounters;
 use crate::utils::tokio_handle_ext;
 
 pub mod builder;
@@ -48,6 +49,7 @@ pub struct ParquetFileReader {
     byte_source_builder: DynByteSourceBuilder,
     row_group_prefetch_sync: RowGroupPrefetchSync,
     io_metrics: OptIOMetrics,
+    progress: Option<Arc<SourceCounters>>,
     verbose: bool,
 
     /// Set during initialize()
@@ -349,6 +351,7 @@ impl FileReader for ParquetFileReader {
                 &mut self.row_group_prefetch_sync.current_all_spawned,
             ),
             disable_morsel_split,
+            progress: self.progress.clone(),
         }
         .run();
 
@@ -438,6 +441,7 @@ struct ParquetReadImpl {
     rg_prefetch_prev_all_spawned: Option<WaitGroup>,
     rg_prefetch_current_all_spawned: Option<WaitToken>,
     disable_morsel_split: bool,
+    progress: Option<Arc<SourceCounters>>,
 }
 
 #[derive(Debug)]
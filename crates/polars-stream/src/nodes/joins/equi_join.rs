@@ -15,6 +15,8 @@ use polars_ooc::AccessPattern::NoPattern;
 use polars_ooc::mm;
 use polars_ops::frame::{JoinArgs, JoinBuildSide, JoinType, MaintainOrderJoin};
 use polars_ops::series::coalesce_columns;
+use polars_plan::dsl::JoinRuntimeFilters;
+use polars_plan::plans::DynamicPred;
 use polars_utils::cardinality_sketch::CardinalitySketch;
 use polars_utils::hashing::HashPartitioner;
 use polars_utils::itertools::Itertools;
@@ -25,6 +27,7 @@ use polars_utils::sparse_init_vec::SparseInitVec;
 use polars_utils::{IdxSize, format_pl_smallstr};
 use rayon::prelude::*;
 
+use super::runtime_filter::RuntimeFilterBuilder;
 use super::{BufferedStream, JOIN_SAMPLE_LIMIT, LOPSIDED_SAMPLE_FACTOR};
 use crate::async_executor;
 use crate::async_primitives::wait_group::WaitGroup;
@@ -48,6 +51,8 @@ struct EquiJoinParams {
     right_payload_schema: Arc<Schema>,
     args: JoinArgs,
     random_state: PlRandomState,
+    left_runtime_filter: Option<DynamicPred>,
+    right_runtime_filter: Option<DynamicPred>,
 }
 
 impl EquiJoinParams {
@@ -68,6 +73,20 @@ impl EquiJoinParams {
             self.args.how == JoinType::Left || self.args.how == JoinType::Full
         }
     }
+
+    /// The runtime filter to set on the probe side from the build keys, if the probe side may
+    /// drop its rows that don't match.
+    fn probe_runtime_filter(&self) -> Option<&DynamicPred> {
+        if self.emit_unmatched_probe() {
+            return None;
+        }
+
+        if self.left_is_build.unwrap() {
+            self.right_runtime_filter.as_ref()
+        } else {
+            self.left_runtime_filter.as_ref()
+        }
+    }
 }
 
 /// A payload selector contains for each column whether that column should be
@@ -175,17 +194,25 @@ fn select_schema(schema: &Schema, selector: &[Option<PlSmallStr>]) -> Schema {
         .collect()
 }
 
-async fn select_keys(
+async fn select_key_df(
     df: &DataFrame,
     key_selectors: &[StreamExpr],
-    params: &EquiJoinParams,
     state: &ExecutionState,
-) -> PolarsResult<HashKeys> {
+) -> PolarsResult<DataFrame> {
     let mut key_columns = Vec::new();
     for selector in key_selectors {
         key_columns.push(selector.evaluate(df, state).await?.into_column());
     }
-    let keys = unsafe { DataFrame::new_unchecked_with_broadcast(df.height(), key_columns)? };
+    unsafe { DataFrame::new_unchecked_with_broadcast(df.height(), key_columns) }
+}
+
+async fn select_keys(
+    df: &DataFrame,
+    key_selectors: &[StreamExpr],
+    params: &EquiJoinParams,
+    state: &ExecutionState,
+) -> PolarsResult<HashKeys> {
+    let keys = select_key_df(df, key_selectors, state).await?;
     Ok(HashKeys::from_df(
         &keys,
         params.random_state.clone(),
@@ -431,6 +458,9 @@ struct LocalBuilder {
     // let stop = morsel_idxs_offsets[(i + 1) * num_partitions + p];
     morsel_idxs_values_per_p: Vec<Vec<IdxSize>>,
     morsel_idxs_offsets_per_p: Vec<usize>,
+
+    // The keys seen by this builder, used to filter the probe side.
+    runtime_filter: Option<RuntimeFilterBuilder>,
 }
 
 struct BuildState {
@@ -450,6 +480,7 @@ impl BuildState {
                 sketch_per_p: vec![CardinalitySketch::default(); num_partitions],
                 morsel_idxs_values_per_p: vec![Vec::new(); num_partitions],
                 morsel_idxs_offsets_per_p: vec![0; num_partitions],
+                runtime_filter: None,
             })
             .collect();
         Self {
@@ -466,6 +497,7 @@ impl BuildState {
         state: &StreamingExecutionState,
     ) -> PolarsResult<()> {
         let track_unmatchable = params.emit_unmatched_build();
+        let build_runtime_filter = params.probe_runtime_filter().is_some();
         let (key_selectors, payload_selector);
         if params.left_is_build.unwrap() {
             payload_selector = &params.left_payload_select;
@@ -478,13 +510,20 @@ impl BuildState {
         while let Ok(morsel) = recv.recv().await {
             // Compute hashed keys and payload. We must rechunk the payload for
             // later gathers.
-            let hash_keys = select_keys(
-                morsel.df(),
-                key_selectors,
-                params,
-                &state.in_memory_exec_state,
-            )
-            .await?;
+            let keys =
+                select_key_df(morsel.df(), key_selectors, &state.in_memory_exec_state).await?;
+            let hash_keys = HashKeys::from_df(
+                &keys,
+                params.random_state.clone(),
+                params.args.nulls_equal,
+                false,
+            );
+            if build_runtime_filter {
+                local
+                    .runtime_filter
+                    .get_or_insert_with(|| RuntimeFilterBuilder::new(keys[0].dtype()))
+                    .insert(&keys[0], &hash_keys)?;
+            }
             let mut payload = select_payload(morsel.df().clone(), payload_selector);
             payload.rechunk_mut();
 
@@ -504,6 +543,31 @@ impl BuildState {
         Ok(())
     }
 
+    /// Combines the keys seen by all builders into a filter for the probe side.
+    fn publish_runtime_filter(&mut self, params: &EquiJoinParams) -> PolarsResult<()> {
+        let Some(pred) = params.probe_runtime_filter() else {
+            return Ok(());
+        };
+
+        let mut builders = self
+            .local_builders
+            .iter_mut()
+            .filter_map(|local| local.runtime_filter.take());
+        let Some(mut builder) = builders.next() else {
+            return Ok(());
+        };
+        for other in builders {
+            builder.combine(other)?;
+        }
+
+        let filter = builder.finish(params.random_state.clone(), params.args.nulls_equal)?;
+        if config::verbose() {
+            eprintln!("equi-join: setting runtime filter on probe side: {filter}");
+        }
+        pred.set(Arc::new(filter));
+        Ok(())
+    }
+
     fn finalize_ordered(&mut self, params: &EquiJoinParams, table: &dyn IdxTable) -> ProbeState {
         let track_unmatchable = params.emit_unmatched_build();
         let payload_schema = if params.left_is_build.unwrap() {
@@ -1193,6 +1257,7 @@ impl EquiJoinNode {
         left_key_selectors: Vec<StreamExpr>,
         right_key_selectors: Vec<StreamExpr>,
         args: JoinArgs,
+        runtime_filters: JoinRuntimeFilters,
         num_pipelines: usize,
     ) -> PolarsResult<Self> {
         let left_is_build = match args.maintain_order {
@@ -1273,6 +1338,8 @@ impl EquiJoinNode {
                 right_payload_schema,
                 args,
                 random_state: PlRandomState::default(),
+                left_runtime_filter: runtime_filters.left,
+                right_runtime_filter: runtime_filters.right,
             },
             table: new_idx_table(unique_key_schema),
         })
@@ -1316,6 +1383,7 @@ impl ComputeNode for EquiJoinNode {
         // If we are building and the build input is done, transition to probing.
         if let EquiJoinState::Build(build_state) = &mut self.state {
             if recv[build_idx] == PortState::Done {
+                build_state.publish_runtime_filter(&self.params)?;
                 let probe_state = if self.params.preserve_order_build {
                     build_state.finalize_ordered(&self.params, &*self.table)
                 } else {
@@ -1428,6 +1496,33 @@ impl ComputeNode for EquiJoinNode {
         )
     }
 
+    fn memory_usage(&self) -> Option<usize> {
+        let tables_size =
+            |tables: &[ProbeTable]| tables.iter().map(|t| t.payload.estimated_size()).sum();
+        match &self.state {
+            EquiJoinState::Sample(sample) => Some(
+                sample
+                    .left
+                    .iter()
+                    .chain(&sample.right)
+                    .map(|m| m.df().estimated_size())
+                    .sum(),
+            ),
+            EquiJoinState::Build(build) => Some(
+                build
+                    .local_builders
+                    .iter()
+                    .flat_map(|l| &l.morsels)
+                    .map(|(_, token, _)| token.size_bytes())
+                    .sum(),
+            ),
+            EquiJoinState::Probe(probe) => Some(tables_size(&probe.table_per_partition)),
+            EquiJoinState::EmitUnmatchedBuild(emit) => Some(tables_size(&emit.partitions)),
+            EquiJoinState::EmitUnmatchedBuildInOrder(source) => source.memory_usage(),
+            EquiJoinState::Done => None,
+        }
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
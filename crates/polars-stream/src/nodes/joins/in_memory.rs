@@ -91,6 +91,16 @@ impl ComputeNode for InMemoryJoinNode {
         matches!(self.state, InMemoryJoinState::Sink { .. })
     }
 
+    fn memory_usage(&self) -> Option<usize> {
+        match &self.state {
+            InMemoryJoinState::Sink { left, right, .. } => {
+                Some(left.memory_usage().unwrap_or(0) + right.memory_usage().unwrap_or(0))
+            },
+            InMemoryJoinState::Source(source_node) => source_node.memory_usage(),
+            InMemoryJoinState::Done => None,
+        }
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
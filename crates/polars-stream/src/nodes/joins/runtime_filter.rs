@@ -0,0 +1,319 @@
+//! Runtime filters on the join keys, published by the build side of an equi-join so the probe
+//! side can drop rows (and skip batches) that cannot find a match.
+use std::fmt::{Display, Formatter};
+
+use arrow::bitmap::MutableBitmap;
+use polars_core::prelude::*;
+use polars_expr::hash_keys::HashKeys;
+use polars_plan::plans::PredicateExpr;
+use polars_utils::pl_str::PlSmallStr;
+
+/// Maximum number of distinct build keys for which an exact `is_in` filter is used.
+const MAX_SET_SIZE: usize = 1024;
+
+/// Number of bits in the bloom filter, must be a power of two.
+const BLOOM_FILTER_BITS: usize = 1 << 20;
+
+/// A bloom filter over the hashes of the join keys, using two probes per hash.
+#[derive(Clone)]
+struct BloomFilter {
+    words: Vec<u64>,
+}
+
+impl BloomFilter {
+    fn new() -> Self {
+        Self {
+            words: vec![0; BLOOM_FILTER_BITS / 64],
+        }
+    }
+
+    #[inline(always)]
+    fn probes(h: u64) -> [usize; 2] {
+        // The hash partitioner uses the high bits, use the low and middle bits here.
+        let mask = (BLOOM_FILTER_BITS - 1) as u64;
+        [(h & mask) as usize, ((h >> 40) & mask) as usize]
+    }
+
+    #[inline(always)]
+    fn insert(&mut self, h: u64) {
+        for p in Self::probes(h) {
+            self.words[p / 64] |= 1 << (p % 64);
+        }
+    }
+
+    #[inline(always)]
+    fn contains(&self, h: u64) -> bool {
+        Self::probes(h)
+            .into_iter()
+            .all(|p| self.words[p / 64] & (1 << (p % 64)) != 0)
+    }
+
+    fn combine(&mut self, other: &Self) {
+        for (l, r) in self.words.iter_mut().zip(&other.words) {
+            *l |= *r;
+        }
+    }
+
+    fn fill_ratio(&self) -> f64 {
+        let ones: u64 = self.words.iter().map(|w| w.count_ones() as u64).sum();
+        ones as f64 / BLOOM_FILTER_BITS as f64
+    }
+}
+
+/// Whether min/max statistics of this type are ordered the same as the values.
+fn has_ordered_range(dtype: &DataType) -> bool {
+    dtype.is_integer() || dtype.is_temporal() || dtype.is_string()
+}
+
+fn extend_range(range: &mut Option<(Scalar, Scalar)>, min: Scalar, max: Scalar) {
+    if min.is_null() || max.is_null() {
+        return;
+    }
+
+    match range {
+        None => *range = Some((min, max)),
+        Some((lo, hi)) => {
+            if min.value() < lo.value() {
+                *lo = min;
+            }
+            if max.value() > hi.value() {
+                *hi = max;
+            }
+        },
+    }
+}
+
+/// Accumulates the join keys seen by a single build pipeline.
+pub(super) struct RuntimeFilterBuilder {
+    dtype: DataType,
+    // Distinct keys, `None` once there are too many of them.
+    keys: Option<Vec<Series>>,
+    num_keys: usize,
+    range: Option<(Scalar, Scalar)>,
+    has_nulls: bool,
+    bloom: BloomFilter,
+}
+
+impl RuntimeFilterBuilder {
+    pub fn new(dtype: &DataType) -> Self {
+        Self {
+            dtype: dtype.clone(),
+            keys: Some(Vec::new()),
+            num_keys: 0,
+            range: None,
+            has_nulls: false,
+            bloom: BloomFilter::new(),
+        }
+    }
+
+    pub fn insert(&mut self, keys: &Column, hash_keys: &HashKeys) -> PolarsResult<()> {
+        let keys = keys.as_materialized_series();
+        self.has_nulls |= keys.has_nulls();
+
+        if has_ordered_range(&self.dtype) {
+            extend_range(&mut self.range, keys.min_reduce()?, keys.max_reduce()?);
+        }
+
+        hash_keys.for_each_hash(|_, opt_h| {
+            if let Some(h) = opt_h {
+                self.bloom.insert(h);
+            }
+        });
+
+        if let Some(set) = &mut self.keys {
+            set.push(keys.clone());
+            self.num_keys += keys.len();
+            if self.num_keys > 4 * MAX_SET_SIZE {
+                self.compact_keys()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn combine(&mut self, other: Self) -> PolarsResult<()> {
+        self.has_nulls |= other.has_nulls;
+        if let Some((lo, hi)) = other.range {
+            extend_range(&mut self.range, lo, hi);
+        }
+        self.bloom.combine(&other.bloom);
+
+        match (&mut self.keys, other.keys) {
+            (Some(set), Some(other_set)) => {
+                set.extend(other_set);
+                self.num_keys += other.num_keys;
+                self.compact_keys()?;
+            },
+            _ => self.keys = None,
+        }
+
+        Ok(())
+    }
+
+    /// Deduplicates the collected keys, dropping them if there are too many distinct ones.
+    fn compact_keys(&mut self) -> PolarsResult<()> {
+        let Some(set) = self.keys.take() else {
+            return Ok(());
+        };
+
+        let mut acc = Series::new_empty(PlSmallStr::EMPTY, &self.dtype);
+        for s in &set {
+            acc.append(s)?;
+        }
+        let unique = acc.unique()?;
+
+        if unique.len() <= MAX_SET_SIZE {
+            self.num_keys = unique.len();
+            self.keys = Some(vec![unique]);
+        }
+
+        Ok(())
+    }
+
+    pub fn finish(
+        mut self,
+        random_state: PlRandomState,
+        nulls_equal: bool,
+    ) -> PolarsResult<RuntimeFilter> {
+        self.compact_keys()?;
+
+        let keys = self.keys.and_then(|mut set| set.pop());
+        // Exact membership needs `is_in`, fall back to the bloom filter otherwise.
+        let keys = keys.filter(|_| cfg!(feature = "is_in"));
+        // A mostly filled bloom filter rejects too few rows to be worth checking.
+        let bloom = (keys.is_none() && self.bloom.fill_ratio() <= 0.5).then_some(self.bloom);
+
+        Ok(RuntimeFilter {
+            dtype: self.dtype,
+            keys,
+            range: self.range,
+            bloom,
+            random_state,
+            nulls_equal,
+            nulls_match: nulls_equal && self.has_nulls,
+        })
+    }
+}
+
+/// A filter that keeps the probe-side rows that may match one of the build keys.
+pub(super) struct RuntimeFilter {
+    dtype: DataType,
+    keys: Option<Series>,
+    range: Option<(Scalar, Scalar)>,
+    bloom: Option<BloomFilter>,
+    random_state: PlRandomState,
+    nulls_equal: bool,
+    // Whether null probe keys can find a match.
+    nulls_match: bool,
+}
+
+impl RuntimeFilter {
+    fn mask(&self, s: &Series) -> PolarsResult<Option<BooleanChunked>> {
+        #[cfg(feature = "is_in")]
+        if let Some(keys) = &self.keys {
+            return polars_ops::series::is_in(s, keys, self.nulls_equal).map(Some);
+        }
+
+        let mut mask = None;
+
+        if let Some((lo, hi)) = &self.range {
+            let lo = lo.clone().into_series(PlSmallStr::EMPTY);
+            let hi = hi.clone().into_series(PlSmallStr::EMPTY);
+            let in_range = s.gt_eq(&lo)? & s.lt_eq(&hi)?;
+            mask = Some(in_range.fill_null_with_values(self.nulls_match)?);
+        }
+
+        if let Some(bloom) = &self.bloom {
+            let df = unsafe { DataFrame::new_unchecked(s.len(), vec![s.clone().into_column()]) };
+            let hash_keys =
+                HashKeys::from_df(&df, self.random_state.clone(), self.nulls_equal, false);
+            let mut maybe_contained = MutableBitmap::from_len_zeroed(s.len());
+            hash_keys.for_each_hash(|idx, opt_h| {
+                if opt_h.is_some_and(|h| bloom.contains(h)) {
+                    maybe_contained.set(idx as usize, true);
+                }
+            });
+            let in_bloom = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, maybe_contained.freeze());
+            mask = Some(match mask {
+                Some(mask) => mask & in_bloom,
+                None => in_bloom,
+            });
+        }
+
+        Ok(mask)
+    }
+}
+
+impl PredicateExpr for RuntimeFilter {
+    fn evaluate(&self, columns: &[Column]) -> PolarsResult<Option<Column>> {
+        let c = &columns[0];
+        if c.dtype() != &self.dtype {
+            return Ok(None);
+        }
+
+        let mask = if let Column::Scalar(sc) = c {
+            self.mask(&sc.as_single_value_series())?
+                .map(|m| m.new_from_index(0, c.len()))
+        } else {
+            self.mask(c.as_materialized_series())?
+        };
+
+        Ok(mask.map(|m| m.with_name(c.name().clone()).into_column()))
+    }
+
+    fn evaluate_skip_batch(
+        &self,
+        min: &Column,
+        max: &Column,
+        null_count: &Column,
+    ) -> PolarsResult<Option<Column>> {
+        if min.dtype() != &self.dtype || max.dtype() != &self.dtype {
+            return Ok(None);
+        }
+
+        // Batches with a single value can be checked against the filter directly.
+        let keep_min = self.mask(min.as_materialized_series())?;
+
+        let skip: BooleanChunked = (0..min.len())
+            .map(|i| {
+                let nc = null_count.get(i)?.extract::<u64>();
+                let (mn, mx) = (min.get(i)?, max.get(i)?);
+
+                if mn.is_null() || mx.is_null() || nc.is_none_or(|nc| nc > 0 && self.nulls_match) {
+                    return Ok(false);
+                }
+
+                let out_of_range = self
+                    .range
+                    .as_ref()
+                    .is_some_and(|(lo, hi)| &mx < lo.value() || &mn > hi.value());
+                let constant_miss = nc == Some(0)
+                    && mn == mx
+                    && keep_min.as_ref().is_some_and(|k| k.get(i) == Some(false));
+
+                Ok(out_of_range || constant_miss)
+            })
+            .collect::<PolarsResult<_>>()?;
+
+        Ok(Some(skip.with_name(min.name().clone()).into_column()))
+    }
+}
+
+impl Display for RuntimeFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(keys) = &self.keys {
+            parts.push(format!("is_in({} keys)", keys.len()));
+        }
+        if let Some((lo, hi)) = &self.range {
+            parts.push(format!("range({}, {})", lo.value(), hi.value()));
+        }
+        if self.bloom.is_some() {
+            parts.push("bloom".to_string());
+        }
+        if parts.is_empty() {
+            parts.push("none".to_string());
+        }
+        write!(f, "{}", parts.join(" & "))
+    }
+}
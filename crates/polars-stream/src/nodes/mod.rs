@@ -90,6 +90,12 @@ pub trait ComputeNode: Send {
         false
     }
 
+    /// The number of bytes of data this node currently holds on to, if it
+    /// keeps track of it. Sampled between phases to report peak memory usage.
+    fn memory_usage(&self) -> Option<usize> {
+        None
+    }
+
     /// Spawn the tasks that this compute node needs to receive input(s),
     /// process it and send to its output(s). Called once per execution phase.
     fn spawn<'env, 's>(
@@ -103,6 +109,10 @@ pub trait ComputeNode: Send {
 
     fn set_metrics_builder(&mut self, _metrics_builder: MetricsBuilder) {}
 
+    /// Called when the query stops with an error, e.g. because it was
+    /// cancelled or timed out. Nodes can use this to clean up partial output.
+    fn abort(&mut self) {}
+
     /// Called once after the last execution phase to extract output from
     /// in-memory nodes.
     fn get_output(&mut self) -> PolarsResult<Option<DataFrame>> {
@@ -3,14 +3,15 @@ use std::sync::Arc;
 use polars_core::chunked_array::cast::CastOptions;
 use polars_core::frame::DataFrame;
 use polars_core::prelude::{
-    DataType, Field, IDX_DTYPE, InitHashMaps, PlHashMap, PlHashSet, PlIndexMap, PlIndexSet,
+    DataType, ExplodeOptions, Field, IDX_DTYPE, InitHashMaps, PlHashMap, PlHashSet, PlIndexMap,
+    PlIndexSet,
 };
 use polars_core::scalar::Scalar;
 use polars_core::schema::{Schema, SchemaExt};
 use polars_error::PolarsResult;
 use polars_expr::state::ExecutionState;
 use polars_expr::{ExpressionConversionState, create_physical_expr};
-use polars_ops::frame::{JoinArgs, JoinType};
+use polars_ops::frame::{JoinArgs, JoinCoalesce, JoinType, MaintainOrderJoin};
 use polars_ops::series::{RLE_LENGTH_COLUMN_NAME, RLE_VALUE_COLUMN_NAME};
 use polars_plan::plans::AExpr;
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
@@ -1074,7 +1075,6 @@ fn lower_exprs_with_ctx(
             } if is_scalar_ae(inner_exprs[1].node(), ctx.expr_arena) => {
                 // Translate left and right side separately (they could have different lengths).
 
-                use polars_core::prelude::ExplodeOptions;
                 let left_on_name = unique_column_name();
                 let right_on_name = unique_column_name();
                 let (trans_input_left, trans_expr_left) =
@@ -2333,6 +2333,145 @@ fn lower_exprs_with_ctx(
                 transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
             },
 
+            AExpr::Over {
+                function,
+                ref partition_by,
+                order_by,
+                mapping,
+            } if !partition_by.is_empty()
+                && partition_by.iter().all(|k| {
+                    is_elementwise_rec_cached(*k, ctx.expr_arena, ctx.cache)
+                        && !is_input_independent_ctx(*k, ctx)
+                }) =>
+            {
+                let is_scalar = is_scalar_ae(function, ctx.expr_arena);
+                let is_elementwise = is_elementwise_rec_cached(function, ctx.expr_arena, ctx.cache)
+                    && !is_input_independent_ctx(function, ctx);
+
+                match mapping {
+                    // An elementwise function gives the same result per group as on the full
+                    // input, so the window can be dropped.
+                    WindowMapping::GroupsToRows if is_elementwise => {
+                        let (trans_input, trans_exprs) =
+                            lower_exprs_with_ctx(input, &[function], ctx)?;
+                        input_streams.insert(trans_input);
+                        transformed_exprs.push(trans_exprs[0]);
+                    },
+
+                    // Transform:
+                    //    function.over(k_1, .., k_n)
+                    //      ->
+                    //    .select(k_1, .., k_n)
+                    //    .join(
+                    //        .group_by(k_1, .., k_n).agg(out = function),
+                    //        on=[k_1, .., k_n], how='left', nulls_equal=True,
+                    //        maintain_order='left',
+                    //    )
+                    //    .select(out)
+                    WindowMapping::GroupsToRows | WindowMapping::Join
+                        if order_by.is_none() && (is_scalar || mapping == WindowMapping::Join) =>
+                    {
+                        let out_name = unique_column_name();
+                        let (keys, group_by_stream) = build_over_group_by_stream(
+                            input,
+                            function,
+                            partition_by,
+                            out_name.clone(),
+                            false,
+                            ctx,
+                        )?;
+
+                        let left_keys = keys
+                            .iter()
+                            .map(|k| {
+                                AExprBuilder::col(k.clone(), ctx.expr_arena).expr_ir(k.clone())
+                            })
+                            .collect_vec();
+                        let left_input = build_select_stream_with_ctx(
+                            input,
+                            &partition_by
+                                .iter()
+                                .zip(&keys)
+                                .map(|(k, name)| ExprIR::new(*k, OutputName::Alias(name.clone())))
+                                .collect_vec(),
+                            ctx,
+                        )?;
+
+                        let left_schema = ctx.phys_sm[left_input.node].output_schema.clone();
+                        let right_schema = ctx.phys_sm[group_by_stream.node].output_schema.clone();
+                        let output_schema = Schema::from_iter(
+                            left_schema
+                                .iter_fields()
+                                .chain([right_schema.get_field(&out_name).unwrap()]),
+                        );
+                        let node_kind = PhysNodeKind::EquiJoin {
+                            input_left: left_input,
+                            input_right: group_by_stream,
+                            left_on: left_keys.clone(),
+                            right_on: left_keys,
+                            args: JoinArgs {
+                                how: JoinType::Left,
+                                validation: Default::default(),
+                                suffix: None,
+                                slice: None,
+                                nulls_equal: true,
+                                coalesce: JoinCoalesce::CoalesceColumns,
+                                maintain_order: MaintainOrderJoin::Left,
+                                build_side: None,
+                            },
+                            runtime_filters: None,
+                        };
+                        let node_key = ctx
+                            .phys_sm
+                            .insert(PhysNode::new(Arc::new(output_schema), node_kind));
+                        input_streams.insert(PhysStream::first(node_key));
+                        transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
+                    },
+
+                    // Transform:
+                    //    function.over(k_1, .., k_n, mapping_strategy='explode')
+                    //      ->
+                    //    .group_by(k_1, .., k_n, maintain_order=True).agg(out = function)
+                    //    .select(out.explode())
+                    WindowMapping::Explode if order_by.is_none() => {
+                        let out_name = unique_column_name();
+                        let (_, group_by_stream) = build_over_group_by_stream(
+                            input,
+                            function,
+                            partition_by,
+                            out_name.clone(),
+                            true,
+                            ctx,
+                        )?;
+
+                        let mut out = AExprBuilder::col(out_name.clone(), ctx.expr_arena);
+                        if !is_scalar {
+                            out = out.explode(
+                                ctx.expr_arena,
+                                ExplodeOptions {
+                                    empty_as_null: true,
+                                    keep_nulls: true,
+                                },
+                            );
+                        }
+                        let stream = build_select_stream_with_ctx(
+                            group_by_stream,
+                            &[out.expr_ir(out_name.clone())],
+                            ctx,
+                        )?;
+                        input_streams.insert(stream);
+                        transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
+                    },
+
+                    _ => {
+                        let out_name = unique_column_name();
+                        fallback_subset
+                            .push(ExprIR::new(expr, OutputName::Alias(out_name.clone())));
+                        transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
+                    },
+                }
+            },
+
             AExpr::AnonymousFunction { .. }
             | AExpr::Function { .. }
             | AExpr::Over { .. }
@@ -2374,6 +2513,57 @@ fn lower_exprs_with_ctx(
     Ok((PhysStream::first(zip_node), transformed_exprs))
 }
 
+/// Builds the group-by evaluating the `function` of a window expression per partition.
+///
+/// Returns the names of the key columns and a stream with the key columns followed by the
+/// aggregated `out_name` column.
+fn build_over_group_by_stream(
+    input: PhysStream,
+    function: Node,
+    partition_by: &[Node],
+    out_name: PlSmallStr,
+    maintain_order: bool,
+    ctx: &mut LowerExprContext,
+) -> PolarsResult<(Vec<PlSmallStr>, PhysStream)> {
+    let input_schema = ctx.phys_sm[input.node].output_schema.clone();
+
+    let keys = partition_by
+        .iter()
+        .map(|k| ExprIR::new(*k, OutputName::Alias(unique_column_name())))
+        .collect_vec();
+    let agg = ExprIR::new(function, OutputName::Alias(out_name.clone()));
+
+    let mut output_schema = Schema::with_capacity(keys.len() + 1);
+    for key in &keys {
+        output_schema.insert(
+            key.output_name().clone(),
+            key.dtype(&input_schema, ctx.expr_arena)?.clone(),
+        );
+    }
+    let mut agg_dtype = agg.dtype(&input_schema, ctx.expr_arena)?.clone();
+    if !is_scalar_ae(function, ctx.expr_arena) {
+        agg_dtype = agg_dtype.implode();
+    }
+    output_schema.insert(out_name, agg_dtype);
+
+    let stream = build_group_by_stream(
+        input,
+        &keys,
+        &[agg],
+        Arc::new(output_schema),
+        maintain_order,
+        Default::default(),
+        None,
+        ctx.expr_arena,
+        ctx.phys_sm,
+        ctx.cache,
+        StreamingLowerIRContext::from(&*ctx),
+        false,
+    )?;
+    let key_names = keys.iter().map(|k| k.output_name().clone()).collect();
+    Ok((key_names, stream))
+}
+
 /// Computes the schema that selecting the given expressions on the input schema
 /// would result in.
 pub fn compute_output_schema(
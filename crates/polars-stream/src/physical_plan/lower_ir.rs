@@ -712,6 +712,7 @@ pub fn lower_ir(
                             prefetch_semaphore: std::sync::OnceLock::new(),
                             shared_prefetch_wait_group_slot: Default::default(),
                             io_metrics: std::sync::OnceLock::new(),
+                            progress: std::sync::OnceLock::new(),
                         },
                     ) as _,
 
@@ -1133,6 +1134,7 @@ pub fn lower_ir(
             let mut tmp_left_col_names: Vec<Option<PlSmallStr>> = Vec::new();
             let mut tmp_right_col_names: Vec<Option<PlSmallStr>> = Vec::new();
             let args = options.args.clone();
+            let runtime_filters = options.runtime_filters.clone();
             let options = options.options.clone();
 
             #[cfg(feature = "iejoin")]
@@ -1407,6 +1409,7 @@ pub fn lower_ir(
                             left_on: trans_left_on,
                             right_on: trans_right_on,
                             args: args.clone(),
+                            runtime_filters,
                         },
                     )),
                     _ if args.how.is_cross() => phys_sm.insert(PhysNode::new(
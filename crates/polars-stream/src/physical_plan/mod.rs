@@ -10,8 +10,9 @@ use polars_io::cloud::CloudOptions;
 use polars_ops::frame::JoinArgs;
 use polars_plan::dsl::deletion::DeletionFilesList;
 use polars_plan::dsl::{
-    CastColumnsPolicy, FileSinkOptions, JoinTypeOptionsIR, MissingColumnsPolicy,
-    PartitionedSinkOptionsIR, PredicateFileSkip, ScanSources, TableStatistics,
+    CastColumnsPolicy, FileSinkOptions, JoinRuntimeFilters, JoinTypeOptionsIR,
+    MissingColumnsPolicy, PartitionedSinkOptionsIR, PredicateFileSkip, ScanSources,
+    TableStatistics,
 };
 use polars_plan::plans::expr_ir::ExprIR;
 use polars_plan::plans::hive::HivePartitionsDf;
@@ -365,6 +366,7 @@ pub enum PhysNodeKind {
         left_on: Vec<ExprIR>,
         right_on: Vec<ExprIR>,
         args: JoinArgs,
+        runtime_filters: Option<JoinRuntimeFilters>,
     },
 
     MergeJoin {
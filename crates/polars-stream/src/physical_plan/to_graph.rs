@@ -362,6 +362,7 @@ fn to_graph_rec<'a>(
                     unified_sink_args,
                     max_rows_per_file,
                     approximate_bytes_per_file,
+                    commit_protocol,
                 },
         } => {
             use crate::nodes::io_sinks::IOSinkNode;
@@ -477,6 +478,7 @@ fn to_graph_rec<'a>(
                 include_keys_in_file,
                 file_schema,
                 file_size_limit,
+                commit_protocol: *commit_protocol,
             }));
 
             let config = IOSinkNodeConfig {
@@ -832,6 +834,7 @@ fn to_graph_rec<'a>(
                     max_concurrent_scans: RelaxedCell::new_usize(0),
                     disable_morsel_split,
                     io_metrics: OnceLock::default(),
+                    progress: OnceLock::default(),
                     verbose,
                 })),
                 [],
@@ -1009,6 +1012,7 @@ fn to_graph_rec<'a>(
                     force_parallel: false,
                     args: args.clone(),
                     options: options.clone(),
+                    runtime_filters: None,
                 }),
             });
 
@@ -1043,6 +1047,7 @@ fn to_graph_rec<'a>(
             left_on,
             right_on,
             args,
+            ..
         }
         | SemiAntiJoin {
             input_left,
@@ -1116,7 +1121,10 @@ fn to_graph_rec<'a>(
                         (right_input_key, input_right.port),
                     ],
                 ),
-                _ => ctx.graph.add_node(
+                EquiJoin {
+                    ref runtime_filters,
+                    ..
+                } => ctx.graph.add_node(
                     nodes::joins::equi_join::EquiJoinNode::new(
                         left_input_schema,
                         right_input_schema,
@@ -1126,6 +1134,7 @@ fn to_graph_rec<'a>(
                         left_key_selectors,
                         right_key_selectors,
                         args,
+                        runtime_filters.clone().unwrap_or_default(),
                         ctx.num_pipelines,
                     )?,
                     [
@@ -1133,6 +1142,7 @@ fn to_graph_rec<'a>(
                         (right_input_key, input_right.port),
                     ],
                 ),
+                _ => unreachable!(),
             }
         },
 
@@ -1494,6 +1504,7 @@ fn to_graph_rec<'a>(
                     max_concurrent_scans: RelaxedCell::new_usize(0),
                     disable_morsel_split,
                     io_metrics: OnceLock::default(),
+                    progress: OnceLock::default(),
                     verbose,
                 })),
                 [],
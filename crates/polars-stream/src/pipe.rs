@@ -1,5 +1,6 @@
 use std::cmp::Reverse;
 use std::sync::Arc;
+use std::time::Instant;
 
 use parking_lot::Mutex;
 use polars_error::PolarsResult;
@@ -7,13 +8,14 @@ use polars_utils::priority::Priority;
 use polars_utils::relaxed_cell::RelaxedCell;
 
 use crate::async_executor::{JoinHandle, TaskPriority, TaskScope};
-use crate::async_primitives::connector::{ReceiverExt, SenderExt, connector_with};
+use crate::async_primitives::connector::{ReceiverExt, SendError, SenderExt, connector_with};
 use crate::async_primitives::distributor_channel::distributor_channel;
 use crate::async_primitives::linearizer::Linearizer;
 use crate::async_primitives::wait_group::WaitGroup;
 use crate::graph::LogicalPipeKey;
 use crate::metrics::GraphMetrics;
 use crate::morsel::{Morsel, MorselSeq};
+use crate::trace::TraceId;
 use crate::{DEFAULT_DISTRIBUTOR_BUFFER_SIZE, DEFAULT_LINEARIZER_BUFFER_SIZE};
 
 pub fn port_channel(metrics: Option<Arc<PipeMetrics>>) -> (PortSender, PortReceiver) {
@@ -28,7 +30,21 @@ impl PortSender {
     #[inline]
     pub async fn send(&mut self, morsel: Morsel) -> Result<(), Morsel> {
         let rows = morsel.df().height() as u64;
-        self.0.send(morsel).await?;
+        let trace_pipe = self.0.shared().as_ref().and_then(|m| m.trace_pipe);
+        if let Some((trace, pipe)) = trace_pipe {
+            // Only time the send if we actually have to wait for the receiver.
+            match self.0.try_send(morsel) {
+                Ok(()) => {},
+                Err(SendError::Closed(morsel)) => return Err(morsel),
+                Err(SendError::Full(morsel)) => {
+                    let start = Instant::now();
+                    self.0.send(morsel).await?;
+                    crate::trace::record_backpressure(trace, pipe, start);
+                },
+            }
+        } else {
+            self.0.send(morsel).await?;
+        }
         if let Some(metrics) = self.0.shared() {
             metrics.morsels_sent.fetch_add(1);
             metrics.rows_sent.fetch_add(rows);
@@ -61,6 +77,8 @@ pub struct PipeMetrics {
     pub morsels_received: RelaxedCell<u64>,
     pub rows_received: RelaxedCell<u64>,
     pub largest_morsel_received: RelaxedCell<u64>,
+    /// Set while tracing, to attribute backpressure to this pipe.
+    pub trace_pipe: Option<(TraceId, LogicalPipeKey)>,
 }
 
 pub struct PhysicalPipe {
@@ -73,8 +91,12 @@ pub struct PhysicalPipe {
 impl PhysicalPipe {
     fn make_channel(&self) -> (PortSender, PortReceiver) {
         let metrics = self.metrics.as_ref().map(|m| {
-            let pipe_metrics = Arc::<PipeMetrics>::default();
-            m.lock().add_pipe(self.key, pipe_metrics.clone());
+            let mut m = m.lock();
+            let pipe_metrics = Arc::new(PipeMetrics {
+                trace_pipe: m.trace().map(|trace| (trace, self.key)),
+                ..Default::default()
+            });
+            m.add_pipe(self.key, pipe_metrics.clone());
             pipe_metrics
         });
         port_channel(metrics)
@@ -0,0 +1,124 @@
+//! Progress reporting for streaming queries.
+//!
+//! Sources and sinks register counters with the [`ProgressTracker`] of the
+//! query when they are initialized and update them as they go. The tracker
+//! periodically turns them into a [`QueryProgress`] for the user's callback.
+use std::sync::Arc;
+use std::time::Instant;
+
+use parking_lot::Mutex;
+use polars_expr::state::{ProgressCallback, QueryProgress, SinkProgress, SourceProgress};
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::relaxed_cell::RelaxedCell;
+
+#[derive(Debug, Default)]
+pub struct SourceCounters {
+    name: PlSmallStr,
+    pub files_done: RelaxedCell<u64>,
+    pub files_total: RelaxedCell<u64>,
+    /// Files whose row groups and bytes were added to the totals.
+    pub files_sized: RelaxedCell<u64>,
+    pub row_groups_done: RelaxedCell<u64>,
+    pub row_groups_total: RelaxedCell<u64>,
+    pub bytes_read: RelaxedCell<u64>,
+    pub bytes_total: RelaxedCell<u64>,
+}
+
+impl SourceCounters {
+    fn snapshot(&self) -> SourceProgress {
+        let files_total = self.files_total.load();
+        // Partial totals would make the progress ratio jump around, so they are only reported
+        // once the metadata of every file was read.
+        let totals_known = self.files_sized.load() >= files_total;
+
+        SourceProgress {
+            name: self.name.clone(),
+            files_done: self.files_done.load(),
+            files_total,
+            row_groups_done: self.row_groups_done.load(),
+            row_groups_total: totals_known.then(|| self.row_groups_total.load()),
+            bytes_read: self.bytes_read.load(),
+            bytes_total: totals_known.then(|| self.bytes_total.load()),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SinkCounters {
+    name: PlSmallStr,
+    pub rows_written: RelaxedCell<u64>,
+}
+
+impl SinkCounters {
+    fn snapshot(&self) -> SinkProgress {
+        SinkProgress {
+            name: self.name.clone(),
+            rows_written: self.rows_written.load(),
+        }
+    }
+}
+
+pub struct ProgressTracker {
+    callback: ProgressCallback,
+    query_start: Instant,
+    last_report: Mutex<Instant>,
+    sources: Mutex<Vec<Arc<SourceCounters>>>,
+    sinks: Mutex<Vec<Arc<SinkCounters>>>,
+}
+
+impl ProgressTracker {
+    pub fn new(callback: ProgressCallback) -> Self {
+        let now = Instant::now();
+        Self {
+            callback,
+            query_start: now,
+            last_report: Mutex::new(now),
+            sources: Mutex::default(),
+            sinks: Mutex::default(),
+        }
+    }
+
+    pub fn register_source(&self, name: &str) -> Arc<SourceCounters> {
+        let counters = Arc::new(SourceCounters {
+            name: name.into(),
+            ..Default::default()
+        });
+        self.sources.lock().push(Arc::clone(&counters));
+        counters
+    }
+
+    pub fn register_sink(&self, name: &str) -> Arc<SinkCounters> {
+        let counters = Arc::new(SinkCounters {
+            name: name.into(),
+            ..Default::default()
+        });
+        self.sinks.lock().push(Arc::clone(&counters));
+        counters
+    }
+
+    fn snapshot(&self, finished: bool) -> QueryProgress {
+        QueryProgress {
+            elapsed: self.query_start.elapsed(),
+            sources: self.sources.lock().iter().map(|s| s.snapshot()).collect(),
+            sinks: self.sinks.lock().iter().map(|s| s.snapshot()).collect(),
+            finished,
+        }
+    }
+
+    /// Calls the callback if the report interval elapsed since the last report.
+    pub fn maybe_report(&self) {
+        {
+            let mut last_report = self.last_report.lock();
+            if last_report.elapsed() < self.callback.interval {
+                return;
+            }
+            *last_report = Instant::now();
+        }
+        (self.callback.callback)(&self.snapshot(false));
+    }
+
+    /// Sends the final report once the query completed.
+    pub fn report_finished(&self) {
+        (self.callback.callback)(&self.snapshot(true));
+    }
+}
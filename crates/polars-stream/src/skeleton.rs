@@ -1,5 +1,6 @@
 #![allow(unused)] // TODO: remove me
 use std::cmp::Reverse;
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
 use parking_lot::Mutex;
@@ -7,6 +8,7 @@ use polars_core::POOL;
 use polars_core::prelude::*;
 use polars_core::query_result::QueryResult;
 use polars_expr::planner::{ExpressionConversionState, create_physical_expr, get_expr_depth_limit};
+use polars_expr::state::ExecutionState;
 use polars_plan::plans::{IR, IRPlan, IRPlanSorted};
 use polars_plan::prelude::AExpr;
 use polars_plan::prelude::expr_ir::ExprIR;
@@ -15,8 +17,9 @@ use polars_utils::relaxed_cell::RelaxedCell;
 use slotmap::{SecondaryMap, SlotMap};
 
 use crate::graph::{Graph, GraphNodeKey};
-use crate::metrics::GraphMetrics;
+use crate::metrics::{GraphMetrics, NodeMetrics};
 use crate::physical_plan::{PhysNode, PhysNodeKey, PhysNodeKind, StreamingLowerIRContext};
+use crate::trace::TraceId;
 
 /// Executes the IR with the streaming engine.
 ///
@@ -37,6 +40,18 @@ pub fn run_query(
     StreamingQuery::build(node, ir_arena, expr_arena)?.execute()
 }
 
+/// Executes the IR with the streaming engine, stopping with an error when
+/// `exec_state` is cancelled or exceeds its limits, see
+/// [`ExecutionState::set_limits`].
+pub fn run_query_with_state(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    exec_state: ExecutionState,
+) -> PolarsResult<QueryResult> {
+    StreamingQuery::build(node, ir_arena, expr_arena)?.execute_with_state(exec_state)
+}
+
 /// Visualizes the physical plan as a dot graph.
 pub fn visualize_physical_plan(
     node: Node,
@@ -58,6 +73,89 @@ pub fn visualize_physical_plan(
     Ok(out)
 }
 
+/// Executes the IR with the streaming engine and renders the physical plan
+/// annotated with the runtime metrics collected for each node.
+///
+/// The query result is discarded.
+pub fn explain_analyze(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<String> {
+    let mut query = StreamingQuery::build(node, ir_arena, expr_arena)?;
+
+    let metrics = Arc::clone(query.metrics.get_or_insert_with(Arc::default));
+
+    let query_start = Instant::now();
+    let result = crate::execute::execute_graph(
+        &mut query.graph,
+        Some(Arc::clone(&metrics)),
+        ExecutionState::default(),
+    );
+    let query_elapsed = query_start.elapsed();
+    result?;
+
+    let roots: Vec<GraphNodeKey> = match query.phys_sm[query.root_phys_node].kind() {
+        PhysNodeKind::SinkMultiple { sinks } => {
+            sinks.iter().map(|k| query.phys_to_graph[*k]).collect()
+        },
+        _ => vec![query.phys_to_graph[query.root_phys_node]],
+    };
+
+    let m = metrics.lock();
+    let mut out = format!("streaming query took {query_elapsed:.2?}\n");
+    let mut visited = SecondaryMap::new();
+    for root in roots {
+        fmt_analyzed_node(&query.graph, &m, root, 0, &mut visited, &mut out);
+    }
+    Ok(out)
+}
+
+fn fmt_analyzed_node(
+    graph: &Graph,
+    metrics: &GraphMetrics,
+    key: GraphNodeKey,
+    indent: usize,
+    visited: &mut SecondaryMap<GraphNodeKey, ()>,
+    out: &mut String,
+) {
+    use std::fmt::Write;
+
+    let node = &graph.nodes[key];
+    let name = node.compute.name();
+    let pad = indent * 2;
+    if visited.insert(key, ()).is_some() {
+        writeln!(out, "{:pad$}{name} (shown above)", "").unwrap();
+        return;
+    }
+
+    let default_metrics = NodeMetrics::default();
+    let m = metrics.get(key).unwrap_or(&default_metrics);
+    let wall_time = Duration::from_nanos(m.total_wall_time_ns);
+    let cpu_time = Duration::from_nanos(m.total_poll_time_ns + m.total_state_update_time_ns);
+    write!(
+        out,
+        "{:pad$}{name}: wall={wall_time:.2?}, cpu={cpu_time:.2?}, \
+         rows(in={}, out={}), morsels(in={}, out={}), bytes_read={}",
+        "",
+        m.rows_received,
+        m.rows_sent,
+        m.morsels_received,
+        m.morsels_sent,
+        m.io_total_bytes_received,
+    )
+    .unwrap();
+    if let Some(peak) = m.peak_buffered_bytes {
+        write!(out, ", buffered_peak={peak}").unwrap();
+    }
+    out.push('\n');
+
+    for input in &node.inputs {
+        let sender = graph.pipes[*input].sender;
+        fmt_analyzed_node(graph, metrics, sender, indent + 1, visited, out);
+    }
+}
+
 pub struct StreamingQuery {
     top_ir: IR,
     pub graph: Graph,
@@ -65,6 +163,7 @@ pub struct StreamingQuery {
     pub phys_sm: SlotMap<PhysNodeKey, PhysNode>,
     pub phys_to_graph: SecondaryMap<PhysNodeKey, GraphNodeKey>,
     pub metrics: Option<Arc<Mutex<GraphMetrics>>>,
+    trace_path: Option<PathBuf>,
 }
 
 /// Configures if IR lowering creates the `format_str` for `InMemoryMap`.
@@ -124,11 +223,17 @@ impl StreamingQuery {
 
         let top_ir = ir_arena.get(node).clone();
 
+        // Tracing attributes events to nodes through the metrics.
+        let trace_path = polars_config::config().stream_trace_path();
         let metrics = if std::env::var("POLARS_TRACK_METRICS").as_deref() == Ok("1")
             || std::env::var("POLARS_LOG_METRICS").as_deref() == Ok("1")
+            || trace_path.is_some()
         {
-            crate::async_executor::track_task_metrics(true);
-            Some(Arc::default())
+            let metrics = match trace_path {
+                Some(_) => GraphMetrics::traced(TraceId::new()),
+                None => GraphMetrics::default(),
+            };
+            Some(Arc::new(Mutex::new(metrics)))
         } else {
             None
         };
@@ -140,12 +245,17 @@ impl StreamingQuery {
             phys_sm,
             phys_to_graph,
             metrics,
+            trace_path,
         };
 
         Ok(out)
     }
 
     pub fn execute(self) -> PolarsResult<QueryResult> {
+        self.execute_with_state(ExecutionState::default())
+    }
+
+    pub fn execute_with_state(self, exec_state: ExecutionState) -> PolarsResult<QueryResult> {
         let StreamingQuery {
             top_ir,
             mut graph,
@@ -153,11 +263,22 @@ impl StreamingQuery {
             phys_sm,
             phys_to_graph,
             metrics,
+            trace_path,
         } = self;
 
+        let trace = metrics.as_ref().and_then(|m| m.lock().trace());
+        let trace_guard = trace.map(TraceId::enter);
         let query_start = Instant::now();
-        let mut results = crate::execute::execute_graph(&mut graph, metrics.clone())?;
+        let results = crate::execute::execute_graph(&mut graph, metrics.clone(), exec_state);
         let query_elapsed = query_start.elapsed();
+        drop(trace_guard);
+        if let Some(path) = &trace_path
+            && let Some(trace) = trace
+        {
+            let m = metrics.as_ref().unwrap().lock();
+            crate::trace::write_chrome_trace(path, trace, &graph, &m)?;
+        }
+        let mut results = results?;
 
         // Print metrics.
         if let Some(lock) = metrics
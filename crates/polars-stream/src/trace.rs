@@ -0,0 +1,343 @@
+//! Opt-in tracing of the streaming engine, written out as a Chrome trace-event
+//! JSON file which can be opened in Perfetto (<https://ui.perfetto.dev>).
+//!
+//! Tracing is enabled by setting `POLARS_STREAM_TRACE_PATH` to the file the
+//! trace should be written to. It records task polls, time spent blocked on
+//! sending into a full pipe, node state updates and IO sessions. Events are
+//! buffered per thread and written out when the query finishes.
+//!
+//! Every traced query gets its own [`TraceId`]. Like the memory account, it is
+//! current on the thread executing the query and inherited by the tasks that
+//! thread spawns, so concurrent queries never record into each other's trace.
+use std::cell::Cell;
+use std::io::Write;
+use std::marker::PhantomData;
+use std::path::Path;
+use std::sync::{Arc, LazyLock};
+use std::time::Instant;
+
+use parking_lot::Mutex;
+use polars_error::PolarsResult;
+use polars_io::metrics::IODirection;
+use polars_utils::aliases::PlHashMap;
+use polars_utils::relaxed_cell::RelaxedCell;
+use slotmap::Key;
+
+use crate::async_executor::TaskMetrics;
+use crate::graph::{Graph, GraphNodeKey, LogicalPipeKey};
+use crate::metrics::GraphMetrics;
+
+static NEXT_TRACE_ID: RelaxedCell<u64> = RelaxedCell::new_u64(0);
+static EPOCH: LazyLock<Instant> = LazyLock::new(Instant::now);
+static THREAD_BUFFERS: Mutex<Vec<Arc<ThreadBuffer>>> = Mutex::new(Vec::new());
+static NEXT_TID: RelaxedCell<u64> = RelaxedCell::new_u64(0);
+
+thread_local!(
+    static CURRENT_TRACE: Cell<Option<TraceId>> = const { Cell::new(None) };
+    static LOCAL_BUFFER: Arc<ThreadBuffer> = {
+        let buffer = Arc::new(ThreadBuffer {
+            tid: NEXT_TID.fetch_add(1),
+            name: std::thread::current()
+                .name()
+                .unwrap_or("unnamed")
+                .to_owned(),
+            events: Mutex::default(),
+        });
+        THREAD_BUFFERS.lock().push(Arc::clone(&buffer));
+        buffer
+    };
+);
+
+struct ThreadBuffer {
+    tid: u64,
+    name: String,
+    events: Mutex<Vec<TraceEvent>>,
+}
+
+enum TraceEventKind {
+    Poll {
+        task: Arc<TaskMetrics>,
+        spawn_location: &'static std::panic::Location<'static>,
+        stolen: bool,
+    },
+    Backpressure {
+        pipe: LogicalPipeKey,
+    },
+    StateUpdate {
+        node: GraphNodeKey,
+        transition: String,
+    },
+}
+
+struct TraceEvent {
+    trace: TraceId,
+    kind: TraceEventKind,
+    start_ns: u64,
+    dur_ns: u64,
+}
+
+/// Identifies the trace of a single query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TraceId(u64);
+
+impl TraceId {
+    pub fn new() -> Self {
+        LazyLock::force(&EPOCH);
+        Self(NEXT_TRACE_ID.fetch_add(1))
+    }
+
+    /// The trace that is current on this thread.
+    #[inline]
+    pub fn current() -> Option<Self> {
+        CURRENT_TRACE.get()
+    }
+
+    /// Make this the current trace of this thread until the guard is dropped.
+    pub fn enter(self) -> TraceGuard {
+        TraceGuard {
+            prev: CURRENT_TRACE.replace(Some(self)),
+            _not_send: PhantomData,
+        }
+    }
+}
+
+/// Restores the previously current [`TraceId`] when dropped.
+#[must_use]
+pub struct TraceGuard {
+    prev: Option<TraceId>,
+    // The guard restores the trace of the thread it was created on.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for TraceGuard {
+    fn drop(&mut self) {
+        CURRENT_TRACE.set(self.prev.take());
+    }
+}
+
+pub(crate) fn since_epoch_ns(t: Instant) -> u64 {
+    t.saturating_duration_since(*EPOCH).as_nanos() as u64
+}
+
+fn record(trace: TraceId, kind: TraceEventKind, start: Instant) {
+    let start_ns = since_epoch_ns(start);
+    let dur_ns = since_epoch_ns(Instant::now()) - start_ns;
+    LOCAL_BUFFER.with(|b| {
+        b.events.lock().push(TraceEvent {
+            trace,
+            kind,
+            start_ns,
+            dur_ns,
+        })
+    });
+}
+
+pub fn record_poll(
+    trace: TraceId,
+    task: Arc<TaskMetrics>,
+    spawn_location: &'static std::panic::Location<'static>,
+    stolen: bool,
+    start: Instant,
+) {
+    record(
+        trace,
+        TraceEventKind::Poll {
+            task,
+            spawn_location,
+            stolen,
+        },
+        start,
+    );
+}
+
+pub fn record_backpressure(trace: TraceId, pipe: LogicalPipeKey, start: Instant) {
+    record(trace, TraceEventKind::Backpressure { pipe }, start);
+}
+
+pub fn record_state_update(trace: TraceId, node: GraphNodeKey, transition: String, start: Instant) {
+    record(
+        trace,
+        TraceEventKind::StateUpdate { node, transition },
+        start,
+    );
+}
+
+fn push_json_str(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn push_event(
+    out: &mut String,
+    name: &str,
+    cat: &str,
+    tid: u64,
+    start_ns: u64,
+    dur_ns: u64,
+    args: &[(&str, &str)],
+) {
+    if !out.ends_with('[') {
+        out.push_str(",\n");
+    }
+    out.push_str("{\"name\":");
+    push_json_str(out, name);
+    out.push_str(",\"cat\":");
+    push_json_str(out, cat);
+    out.push_str(&format!(
+        ",\"ph\":\"X\",\"pid\":0,\"tid\":{tid},\"ts\":{:.3},\"dur\":{:.3},\"args\":{{",
+        start_ns as f64 / 1000.0,
+        dur_ns as f64 / 1000.0,
+    ));
+    for (i, (k, v)) in args.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        push_json_str(out, k);
+        out.push(':');
+        push_json_str(out, v);
+    }
+    out.push_str("}}");
+}
+
+fn push_thread_name(out: &mut String, tid: u64, name: &str) {
+    if !out.ends_with('[') {
+        out.push_str(",\n");
+    }
+    out.push_str(&format!(
+        "{{\"name\":\"thread_name\",\"ph\":\"M\",\"pid\":0,\"tid\":{tid},\"args\":{{\"name\":"
+    ));
+    push_json_str(out, name);
+    out.push_str("}}");
+}
+
+/// Takes the events recorded for `trace` and writes them to `path` as a Chrome
+/// trace, resolving tasks, pipes and IO sessions to the nodes of `graph`.
+pub fn write_chrome_trace(
+    path: &Path,
+    trace: TraceId,
+    graph: &Graph,
+    metrics: &GraphMetrics,
+) -> PolarsResult<()> {
+    let node_name = |key: GraphNodeKey| -> String {
+        match graph.nodes.get(key) {
+            Some(node) => format!("{} ({:?})", node.compute.name(), key.data()),
+            None => "unknown".to_owned(),
+        }
+    };
+
+    let mut task_to_node = PlHashMap::default();
+    for (key, task) in metrics.traced_tasks() {
+        task_to_node.insert(Arc::as_ptr(task) as usize, key);
+    }
+
+    let mut out = String::from("{\"displayTimeUnit\":\"ns\",\"traceEvents\":[");
+    let buffers = THREAD_BUFFERS.lock().clone();
+    for buffer in buffers {
+        let events: Vec<_> = {
+            let mut events = buffer.events.lock();
+            let (ours, others) = std::mem::take(&mut *events)
+                .into_iter()
+                .partition(|e| e.trace == trace);
+            *events = others;
+            ours
+        };
+        if events.is_empty() {
+            continue;
+        }
+        push_thread_name(&mut out, buffer.tid, &buffer.name);
+        for event in events {
+            let (name, cat, args) = match &event.kind {
+                TraceEventKind::Poll {
+                    task,
+                    spawn_location,
+                    stolen,
+                } => {
+                    let node = task_to_node.get(&(Arc::as_ptr(task) as usize));
+                    let name = match node {
+                        Some(key) => node_name(*key),
+                        None => "task".to_owned(),
+                    };
+                    let location = spawn_location.to_string();
+                    let stolen = if *stolen { "true" } else { "false" };
+                    (
+                        name,
+                        "poll",
+                        vec![("spawned_at", location), ("stolen", stolen.to_owned())],
+                    )
+                },
+                TraceEventKind::Backpressure { pipe } => {
+                    let (sender, receiver) = match graph.pipes.get(*pipe) {
+                        Some(p) => (node_name(p.sender), node_name(p.receiver)),
+                        None => ("unknown".to_owned(), "unknown".to_owned()),
+                    };
+                    (
+                        format!("{sender} blocked on {receiver}"),
+                        "backpressure",
+                        vec![("sender", sender), ("receiver", receiver)],
+                    )
+                },
+                TraceEventKind::StateUpdate { node, transition } => (
+                    node_name(*node),
+                    "state_update",
+                    vec![("transition", transition.clone())],
+                ),
+            };
+            let args: Vec<_> = args.iter().map(|(k, v)| (*k, v.as_str())).collect();
+            push_event(
+                &mut out,
+                &name,
+                cat,
+                buffer.tid,
+                event.start_ns,
+                event.dur_ns,
+                &args,
+            );
+        }
+    }
+
+    // IO sessions run on the async runtime, give them their own track per node.
+    let io_tid_base = NEXT_TID.load() + 1;
+    for (i, (key, io_metrics)) in metrics.traced_io().enumerate() {
+        let Some(sessions) = io_metrics.sessions.as_ref() else {
+            continue;
+        };
+        let sessions = std::mem::take(&mut *sessions.lock().unwrap());
+        if sessions.is_empty() {
+            continue;
+        }
+        let tid = io_tid_base + i as u64;
+        let name = node_name(key);
+        push_thread_name(&mut out, tid, &format!("io: {name}"));
+        for session in sessions {
+            let start_ns = since_epoch_ns(session.start);
+            let dur_ns = since_epoch_ns(session.end) - start_ns;
+            let cat = match session.direction {
+                IODirection::Read => "io_read",
+                IODirection::Write => "io_write",
+            };
+            let bytes = session.bytes.to_string();
+            push_event(
+                &mut out,
+                &name,
+                cat,
+                tid,
+                start_ns,
+                dur_ns,
+                &[("bytes", &bytes)],
+            );
+        }
+    }
+    out.push_str("]}\n");
+
+    let mut file = std::fs::File::create(path)?;
+    file.write_all(out.as_bytes())?;
+    Ok(())
+}
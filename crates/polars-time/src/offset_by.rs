@@ -58,7 +58,81 @@ fn apply_offsets_to_datetime(
     }
 }
 
+#[cfg(feature = "dtype-interval")]
+fn apply_intervals_to_datetime(
+    datetime: &Logical<DatetimeType, Int64Type>,
+    intervals: &IntervalChunked,
+    time_zone: Option<&Tz>,
+) -> PolarsResult<Int64Chunked> {
+    let offset_fn = match datetime.time_unit() {
+        TimeUnit::Milliseconds => Duration::add_ms,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Nanoseconds => Duration::add_ns,
+    };
+    broadcast_try_binary_elementwise(
+        datetime.physical(),
+        intervals.physical(),
+        |timestamp_opt, interval_opt| match (timestamp_opt, interval_opt) {
+            (Some(timestamp), Some(interval)) => {
+                Duration::from_interval(i128_to_interval(interval))
+                    .iter()
+                    .try_fold(timestamp, |t, offset| offset_fn(offset, t, time_zone))
+                    .map(Some)
+            },
+            _ => Ok(None),
+        },
+    )
+}
+
+/// Offset a Date or Datetime by calendar intervals, applying the months first, then the
+/// days and finally the nanoseconds.
+#[cfg(feature = "dtype-interval")]
+fn impl_offset_by_interval(ts: &Series, intervals: &IntervalChunked) -> PolarsResult<Series> {
+    let mut out = match ts.dtype() {
+        DataType::Date => {
+            let ts = ts
+                .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+                .unwrap();
+            let datetime = ts.datetime().unwrap();
+            let out = apply_intervals_to_datetime(datetime, intervals, None)?;
+            out.cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+                .unwrap()
+                .cast(&DataType::Date)?
+        },
+        DataType::Datetime(tu, tz) => {
+            let datetime = ts.datetime().unwrap();
+
+            let out = match tz {
+                #[cfg(feature = "timezones")]
+                Some(tz) => apply_intervals_to_datetime(
+                    datetime,
+                    intervals,
+                    tz.parse::<Tz>().ok().as_ref(),
+                )?,
+                _ => apply_intervals_to_datetime(datetime, intervals, None)?,
+            };
+            out.cast(&DataType::Datetime(*tu, tz.clone()))?
+        },
+        dt => polars_bail!(
+            ComputeError: "cannot use 'offset_by' on Series of datatype {}", dt,
+        ),
+    };
+    out.set_sorted_flag(IsSorted::Not);
+    Ok(out)
+}
+
 pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
+    #[cfg(feature = "dtype-interval")]
+    if let DataType::Interval = offsets.dtype() {
+        polars_ensure!(
+            ts.len() == offsets.len() || offsets.len() == 1 || ts.len() == 1,
+            length_mismatch = "dt.offset_by",
+            ts.len(),
+            offsets.len()
+        );
+        return impl_offset_by_interval(ts, offsets.interval()?);
+    }
+
     let offsets = offsets.str()?;
 
     polars_ensure!(
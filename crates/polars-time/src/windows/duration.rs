@@ -125,6 +125,38 @@ impl Duration {
         }
     }
 
+    /// Split a calendar interval into the durations of its months, days and nanoseconds.
+    ///
+    /// The components of an interval may have different signs, so they are applied one
+    /// after another, in the order they are returned.
+    #[cfg(feature = "dtype-interval")]
+    pub fn from_interval(interval: polars_core::prelude::months_days_ns) -> [Duration; 3] {
+        let component = |months: i32, days: i32, nsecs: i64, negative: bool| Duration {
+            months: months.unsigned_abs() as i64,
+            weeks: 0,
+            days: days.unsigned_abs() as i64,
+            nsecs: nsecs.unsigned_abs() as i64,
+            negative,
+            parsed_int: false,
+        };
+        [
+            component(interval.months(), 0, 0, interval.months() < 0),
+            component(0, interval.days(), 0, interval.days() < 0),
+            component(0, 0, interval.ns(), interval.ns() < 0),
+        ]
+    }
+
+    /// Convert into a calendar interval, folding the weeks into the days.
+    #[cfg(feature = "dtype-interval")]
+    pub fn to_interval(&self) -> polars_core::prelude::months_days_ns {
+        let sign = if self.negative { -1 } else { 1 };
+        polars_core::prelude::months_days_ns::new(
+            (sign * self.months) as i32,
+            (sign * (self.weeks * 7 + self.days)) as i32,
+            sign * self.nsecs,
+        )
+    }
+
     /// Parse a string into a `Duration`
     ///
     /// Strings are composed of a sequence of number-unit pairs, such as `5d` (5 days). A string may begin with a minus
@@ -84,6 +84,14 @@ impl<K, V, S> LruCache<K, V, S> {
 }
 
 impl<K: Hash + Eq, V, S: BuildHasher> LruCache<K, V, S> {
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
     fn lru_list_unlink(&mut self, lru_key: LruKey) {
         let list = self.elements[lru_key].list;
         if let Some(more_recent) = self.elements.get_mut(list.more_recent) {
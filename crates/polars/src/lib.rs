@@ -180,6 +180,7 @@
 //! * `sql` - Pass SQL queries to Polars.
 //! * `random` - Generate arrays with randomly sampled values
 //! * `ndarray`- Convert from [`DataFrame`] to [ndarray](https://docs.rs/ndarray/)
+//! * `arrow_rs` - Zero-copy conversions between [`DataFrame`]/[`Series`] and [arrow-rs](https://docs.rs/arrow/) `RecordBatch`/`ArrayRef`
 //! * `temporal` - Conversions between [Chrono](https://docs.rs/chrono/) and Polars for temporal data types
 //! * `timezones` - Activate timezone support.
 //! * `strings` - Extra string utilities for [`StringChunked`]
@@ -195,6 +196,7 @@
 //!       Polars will be a bit slower with this feature activated as many data structures
 //!       are less cache efficient.
 //!     - `cse` - Activate common subplan elimination optimization
+//!     - `result_cache` - Opt-in cache of query results across queries
 //! * IO related:
 //!     - `serde` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!       Can be used for JSON and more serde supported serialization formats.
@@ -675,7 +675,7 @@ fn integration_write(
         data_page_size: None,
     };
 
-    let encodings = get_encodings(schema);
+    let encodings = get_encodings(schema)?;
 
     let row_groups =
         RowGroupIterator::try_new(chunks.iter().cloned().map(Ok), schema, options, encodings)?;
@@ -75,6 +75,7 @@ from polars.convert import (
 from polars.dataframe import DataFrame
 from polars.datatype_expr import DataTypeExpr
 from polars.datatypes import (
+    UUID,
     Array,
     BaseExtension,
     Binary,
@@ -92,12 +93,15 @@ from polars.datatypes import (
     Float16,
     Float32,
     Float64,
+    Geometry,
     Int8,
     Int16,
     Int32,
     Int64,
     Int128,
+    Interval,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -110,6 +114,7 @@ from polars.datatypes import (
     UInt128,
     Unknown,
     Utf8,
+    Variant,
 )
 from polars.datatypes.extension import (
     get_extension_type,
@@ -205,6 +210,8 @@ from polars.functions import (
     time_range,
     time_ranges,
     union,
+    uuid4,
+    uuid7,
     var,
     when,
     zeros,
@@ -258,6 +265,13 @@ from polars.meta import (
     thread_pool_size,
     threadpool_size,
 )
+from polars.result_cache import (
+    clear_result_cache,
+    disable_result_cache,
+    enable_result_cache,
+    result_cache_info,
+    using_result_cache,
+)
 from polars.schema import Schema
 from polars.series import Series
 from polars.sql import SQLContext, sql
@@ -306,12 +320,15 @@ __all__ = [
     "Float16",
     "Float32",
     "Float64",
+    "Geometry",
     "Int8",
     "Int16",
     "Int32",
     "Int64",
     "Int128",
+    "Interval",
     "List",
+    "Map",
     "Null",
     "Object",
     "String",
@@ -322,8 +339,10 @@ __all__ = [
     "UInt32",
     "UInt64",
     "UInt128",
+    "UUID",
     "Unknown",
     "Utf8",
+    "Variant",
     # datatypes.extension
     "register_extension_type",
     "unregister_extension_type",
@@ -372,6 +391,12 @@ __all__ = [
     "disable_string_cache",
     "enable_string_cache",
     "using_string_cache",
+    # polars.result_cache
+    "clear_result_cache",
+    "disable_result_cache",
+    "enable_result_cache",
+    "result_cache_info",
+    "using_result_cache",
     # polars.config
     "Config",
     # polars.functions.whenthen
@@ -467,6 +492,9 @@ __all__ = [
     "var",
     # polars.functions.len
     "len",
+    # polars.functions.uuid
+    "uuid4",
+    "uuid7",
     # polars.functions.random
     "set_random_seed",
     # polars.convert
@@ -927,6 +927,7 @@ class PyLazyFrame:
     def describe_optimized_plan_tree(self) -> str: ...
     def to_dot(self, optimized: bool) -> str: ...
     def to_dot_streaming_phys(self, optimized: bool) -> str: ...
+    def explain_analyze(self) -> str: ...
     def sort(
         self,
         by_column: str,
@@ -954,7 +955,15 @@ class PyLazyFrame:
     def profile(
         self, lambda_post_opt: Any | None
     ) -> tuple[PyDataFrame, PyDataFrame]: ...
-    def collect(self, engine: Any, lambda_post_opt: Any | None) -> PyDataFrame: ...
+    def collect(
+        self,
+        engine: Any,
+        lambda_post_opt: Any | None,
+        timeout: float | None = None,
+        memory_limit: int | None = None,
+        progress: Callable[[dict[str, Any]], None] | None = None,
+        progress_interval: float = 1.0,
+    ) -> PyDataFrame: ...
     def collect_with_callback(self, engine: Any, lambda_func: Any) -> None: ...
     def collect_batches(
         self, engine: Any, maintain_order: bool, chunk_size: int | None, lazy: bool
@@ -979,13 +988,14 @@ class PyLazyFrame:
         compat_level: CompatLevel,
         record_batch_size: int | None,
         record_batch_statistics: bool | None,
+        stream: bool,
     ) -> PyLazyFrame: ...
     def sink_csv(
         self,
         target: SinkTarget,
         sink_options: Any,
         include_bom: bool,
-        compression: Literal["uncompressed", "gzip", "zstd"],
+        compression: Literal["uncompressed", "gzip", "zstd", "bzip2", "xz"],
         compression_level: int | None,
         check_extension: bool,
         include_header: bool,
@@ -1005,7 +1015,7 @@ class PyLazyFrame:
     def sink_ndjson(
         self,
         target: SinkTarget,
-        compression: Literal["uncompressed", "gzip", "zstd"],
+        compression: Literal["uncompressed", "gzip", "zstd", "bzip2", "xz"],
         compression_level: int | None,
         check_extension: bool,
         sink_options: Any,
@@ -1160,7 +1170,9 @@ class PyLazyFrame:
     ) -> PyLazyFrame: ...
 
     # exitable
-    def collect_concurrently(self) -> PyInProcessQuery: ...
+    def collect_concurrently(
+        self, timeout: float | None = None, memory_limit: int | None = None
+    ) -> PyInProcessQuery: ...
 
     # serde
     def serialize_binary(self, py_f: Any) -> None: ...
@@ -1949,6 +1961,17 @@ class PyExpr:
     def struct_json_encode(self) -> PyExpr: ...
     def struct_with_fields(self, fields: Sequence[PyExpr]) -> PyExpr: ...
 
+    # uuid
+    def uuid_timestamp(self) -> PyExpr: ...
+
+    # geometry
+    def st_bounds(self) -> PyExpr: ...
+    def st_area(self) -> PyExpr: ...
+    def st_length(self) -> PyExpr: ...
+    def st_distance(self, other: PyExpr) -> PyExpr: ...
+    def st_contains(self, other: PyExpr) -> PyExpr: ...
+    def st_intersects(self, other: PyExpr) -> PyExpr: ...
+
     # extension
     def ext_to(self, dtype: PyDataTypeExpr) -> PyExpr: ...
     def ext_storage(self) -> PyExpr: ...
@@ -2106,6 +2129,14 @@ class PyOptFlags:
     @sort_collapse.setter
     def sort_collapse(self, value: bool) -> None: ...
     @property
+    def join_reorder(self) -> bool: ...
+    @join_reorder.setter
+    def join_reorder(self, value: bool) -> None: ...
+    @property
+    def join_runtime_filters(self) -> bool: ...
+    @join_runtime_filters.setter
+    def join_runtime_filters(self, value: bool) -> None: ...
+    @property
     def eager(self) -> bool: ...
     @eager.setter
     def eager(self, value: bool) -> None: ...
@@ -2151,6 +2182,8 @@ def concat_list(s: Sequence[PyExpr]) -> PyExpr: ...
 def concat_arr(s: Sequence[PyExpr]) -> PyExpr: ...
 def concat_str(s: Sequence[PyExpr], separator: str, ignore_nulls: bool) -> PyExpr: ...
 def len() -> PyExpr: ...
+def uuid4() -> PyExpr: ...
+def uuid7() -> PyExpr: ...
 def cov(a: PyExpr, b: PyExpr, ddof: int) -> PyExpr: ...
 def arctan2(y: PyExpr, x: PyExpr) -> PyExpr: ...
 def cum_fold(
@@ -2271,6 +2304,8 @@ def get_trim_decimal_zeros() -> bool | None: ...
 
 # functions.misc
 def dtype_str_repr(dtype: Any) -> str: ...
+def serialize_enum_categories(dtype: Any) -> str: ...
+def deserialize_enum_categories(encoded: str) -> Any: ...
 def register_plugin_function(
     plugin_path: str,
     function_name: str,
@@ -2345,6 +2380,13 @@ def enable_string_cache() -> None: ...
 def disable_string_cache() -> None: ...
 def using_string_cache() -> bool: ...
 
+# functions.result_cache
+def enable_result_cache(max_bytes: int) -> None: ...
+def disable_result_cache() -> None: ...
+def clear_result_cache() -> None: ...
+def using_result_cache() -> bool: ...
+def result_cache_info() -> dict[str, int] | None: ...
+
 # functions.strings
 def escape_regex(s: str) -> str: ...
 
@@ -2402,6 +2444,11 @@ class PyCategories:
     def get_cat(self, s: str) -> int | None: ...
     def cat_to_str(self, cat: int) -> str | None: ...
     def is_global(self) -> bool: ...
+    def persist(self) -> None: ...
+    def is_persistent(self) -> bool: ...
+    def serialize_mapping(self) -> str: ...
+    @staticmethod
+    def deserialize_mapping(encoded: str) -> PyCategories: ...
 
 # catalog
 class PyCatalogClient:
@@ -103,7 +103,7 @@ Ambiguous: TypeAlias = Literal["earliest", "latest", "raise", "null"]
 AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
 CsvQuoteStyle: TypeAlias = Literal["necessary", "always", "non_numeric", "never"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
-CsvCompression: TypeAlias = Literal["uncompressed", "gzip", "zstd"]
+CsvCompression: TypeAlias = Literal["uncompressed", "gzip", "zstd", "bzip2", "xz"]
 CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
 ColumnMapping: TypeAlias = tuple[
     Literal["iceberg-column-mapping"],
@@ -129,7 +129,7 @@ Label: TypeAlias = Literal["left", "right", "datapoint"]
 MaintainOrderJoin: TypeAlias = Literal[
     "none", "left", "right", "left_right", "right_left"
 ]
-NdjsonCompression: TypeAlias = Literal["uncompressed", "gzip", "zstd"]
+NdjsonCompression: TypeAlias = Literal["uncompressed", "gzip", "zstd", "bzip2", "xz"]
 NonExistent: TypeAlias = Literal["raise", "null"]
 NullBehavior: TypeAlias = Literal["ignore", "drop"]
 ParallelStrategy: TypeAlias = Literal[
@@ -209,6 +209,7 @@ EpochTimeUnit = Literal["ns", "us", "ms", "s", "d"]
 JaxExportType: TypeAlias = Literal["array", "dict"]
 Orientation: TypeAlias = Literal["col", "row"]
 SearchSortedSide: TypeAlias = Literal["any", "left", "right"]
+SpatialPredicate: TypeAlias = Literal["intersects", "contains", "within"]
 TorchExportType: TypeAlias = Literal["tensor", "dataset", "dict"]
 TransferEncoding: TypeAlias = Literal["hex", "base64"]
 WindowMappingStrategy: TypeAlias = Literal["group_to_rows", "join", "explode"]
@@ -370,6 +371,7 @@ __all__ = [
     "ConditionalFormatDict",
     "ConnectionOrCursor",
     "CorrelationMethod",
+    "CsvCompression",
     "CsvEncoding",
     "CsvQuoteStyle",
     "Cursor",
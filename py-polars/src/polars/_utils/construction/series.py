@@ -9,6 +9,7 @@ from typing import (
     TYPE_CHECKING,
     Any,
 )
+from uuid import UUID as PyUUID
 
 import polars._reexport as pl
 import polars._utils.construction as plc
@@ -33,6 +34,7 @@ from polars._utils.various import (
 )
 from polars._utils.wrap import wrap_s
 from polars.datatypes import (
+    UUID,
     Array,
     BaseExtension,
     Boolean,
@@ -84,6 +86,14 @@ def sequence_to_pyseries(
     """Construct a PySeries from a sequence."""
     python_dtype: type | None = None
 
+    # UUIDs are loaded from `uuid.UUID` objects, bytes or strings
+    if dtype == UUID or (
+        dtype is None and isinstance(get_first_non_none(values), PyUUID)
+    ):
+        values = [v.bytes if isinstance(v, PyUUID) else v for v in values]
+        pys = sequence_to_pyseries(name, values, strict=strict)
+        return pys.cast(UUID(), strict=strict, wrap_numerical=False)
+
     if isinstance(dtype, BaseExtension):
         storage = dtype.ext_storage()
         pys = sequence_to_pyseries(
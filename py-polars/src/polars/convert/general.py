@@ -481,6 +481,11 @@ def from_arrow(
     Hint: You can also directly pass arrow tables to `pl.DataFrame()` / arrow
     arrays to `pl.Series()` if the output type is known to avoid typing issues.
 
+    .. versionchanged:: 1.40.0
+        Arrow `map` columns are read as :class:`Map` instead of
+        `List(Struct({"key": ..., "value": ...}))`. Set the environment variable
+        `POLARS_IMPORT_ARROW_MAP_AS_MAP=0` to restore the previous behavior.
+
     Parameters
     ----------
     data : :class:`pyarrow.Table`, :class:`pyarrow.Array`, one or more :class:`pyarrow.RecordBatch`
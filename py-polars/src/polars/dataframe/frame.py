@@ -153,6 +153,7 @@ if TYPE_CHECKING:
         ComparisonOperator,
         ConditionalFormatDict,
         ConnectionOrCursor,
+        CsvCompression,
         CsvQuoteStyle,
         DbWriteEngine,
         EngineType,
@@ -169,6 +170,7 @@ if TYPE_CHECKING:
         MaintainOrderJoin,
         MultiColSelector,
         MultiIndexSelector,
+        NdjsonCompression,
         OneOrMoreDataTypes,
         Orientation,
         ParquetCompression,
@@ -185,6 +187,7 @@ if TYPE_CHECKING:
         SingleColSelector,
         SingleIndexSelector,
         SizeUnit,
+        SpatialPredicate,
         StartBy,
         StorageOptionsDict,
         UniqueKeepStrategy,
@@ -1743,6 +1746,9 @@ class DataFrame:
         Data types that do copy:
             - CategoricalType
 
+        Columns that were imported from Arrow run-end encoded arrays are exported
+        as run-end encoded arrays again at `CompatLevel.newest()`.
+
         .. versionchanged:: 1.1
             The `future` parameter was renamed `compat_level`.
 
@@ -2896,7 +2902,7 @@ class DataFrame:
         self,
         file: None = None,
         *,
-        compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
+        compression: NdjsonCompression = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
     ) -> str: ...
@@ -2906,7 +2912,7 @@ class DataFrame:
         self,
         file: str | Path | IO[bytes] | IO[str],
         *,
-        compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
+        compression: NdjsonCompression = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
     ) -> None: ...
@@ -2915,7 +2921,7 @@ class DataFrame:
         self,
         file: str | Path | IO[bytes] | IO[str] | None = None,
         *,
-        compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
+        compression: NdjsonCompression = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
     ) -> str | None:
@@ -2996,7 +3002,7 @@ class DataFrame:
         file: None = None,
         *,
         include_bom: bool = ...,
-        compression: Literal["uncompressed", "gzip", "zstd"] = ...,
+        compression: CsvCompression = ...,
         compression_level: int | None = None,
         check_extension: bool = ...,
         include_header: bool = ...,
@@ -3023,7 +3029,7 @@ class DataFrame:
         file: str | Path | IO[str] | IO[bytes],
         *,
         include_bom: bool = ...,
-        compression: Literal["uncompressed", "gzip", "zstd"] = ...,
+        compression: CsvCompression = ...,
         compression_level: int | None = None,
         check_extension: bool = ...,
         include_header: bool = ...,
@@ -3049,7 +3055,7 @@ class DataFrame:
         file: str | Path | IO[str] | IO[bytes] | None = None,
         *,
         include_bom: bool = False,
-        compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
+        compression: CsvCompression = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
         include_header: bool = True,
@@ -8536,6 +8542,97 @@ class DataFrame:
             .collect(optimizations=QueryOptFlags._eager())
         )
 
+    @unstable()
+    def join_spatial(
+        self,
+        other: DataFrame,
+        left_on: str,
+        right_on: str | None = None,
+        *,
+        predicate: SpatialPredicate = "intersects",
+        suffix: str = "_right",
+    ) -> DataFrame:
+        """
+        Perform a spatial join on a geometry column of each table.
+
+        This performs an inner join, so only the pairs of rows whose geometries
+        satisfy the predicate are included in the result. Candidate pairs are first
+        found with a range join on the bounding boxes of the geometries, after which
+        the exact predicate is evaluated on these candidates only.
+
+        .. note::
+            The row order of the input DataFrames is not preserved.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            DataFrame to join with.
+        left_on
+            Name of the :class:`Geometry` column of the left table.
+        right_on
+            Name of the :class:`Geometry` column of the right table. Defaults to
+            `left_on`.
+        predicate : {'intersects', 'contains', 'within'}
+            The spatial relation between the left and the right geometries.
+
+            * *intersects*
+                The geometries have at least one point in common.
+            * *contains*
+                The left geometry contains the right geometry.
+            * *within*
+                The left geometry lies within the right geometry.
+        suffix
+            Suffix to append to columns with a duplicate name.
+
+        See Also
+        --------
+        join_where
+        Expr.st.intersects
+        Expr.st.contains
+
+        Examples
+        --------
+        >>> zones = pl.DataFrame(
+        ...     {
+        ...         "zone": ["a", "b"],
+        ...         "geom": [
+        ...             "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+        ...             "POLYGON ((2 0, 4 0, 4 2, 2 2, 2 0))",
+        ...         ],
+        ...     }
+        ... ).with_columns(pl.col("geom").cast(pl.Geometry))
+        >>> points = pl.DataFrame(
+        ...     {"id": [1, 2, 3], "geom": ["POINT (1 1)", "POINT (2 1)", "POINT (5 5)"]}
+        ... ).with_columns(pl.col("geom").cast(pl.Geometry))
+        >>> zones.join_spatial(points, "geom").select("zone", "id").sort("zone", "id")
+        shape: (3, 2)
+        ┌──────┬─────┐
+        │ zone ┆ id  │
+        │ ---  ┆ --- │
+        │ str  ┆ i64 │
+        ╞══════╪═════╡
+        │ a    ┆ 1   │
+        │ a    ┆ 2   │
+        │ b    ┆ 2   │
+        └──────┴─────┘
+        """
+        require_same_type(self, other)
+        return (
+            self.lazy()
+            .join_spatial(
+                other.lazy(),
+                left_on,
+                right_on,
+                predicate=predicate,
+                suffix=suffix,
+            )
+            .collect(optimizations=QueryOptFlags._eager())
+        )
+
     def map_rows(
         self,
         function: Callable[[tuple[Any, ...]], Any],
@@ -4,6 +4,7 @@ from polars.datatypes._parse import (
     try_parse_into_dtype,
 )
 from polars.datatypes.classes import (
+    UUID,
     Array,
     BaseExtension,
     Binary,
@@ -22,6 +23,7 @@ from polars.datatypes.classes import (
     Float16,
     Float32,
     Float64,
+    Geometry,
     FloatType,
     Int8,
     Int16,
@@ -29,7 +31,9 @@ from polars.datatypes.classes import (
     Int64,
     Int128,
     IntegerType,
+    Interval,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -43,6 +47,7 @@ from polars.datatypes.classes import (
     UInt128,
     Unknown,
     Utf8,
+    Variant,
 )
 from polars.datatypes.constants import (
     DTYPE_TEMPORAL_UNITS,
@@ -85,6 +90,7 @@ __all__ = [
     "Float16",
     "Float32",
     "Float64",
+    "Geometry",
     "FloatType",
     "Int16",
     "Int128",
@@ -92,7 +98,9 @@ __all__ = [
     "Int64",
     "Int8",
     "IntegerType",
+    "Interval",
     "List",
+    "Map",
     "Null",
     "Object",
     "String",
@@ -104,8 +112,10 @@ __all__ = [
     "UInt32",
     "UInt64",
     "UInt8",
+    "UUID",
     "Unknown",
     "Utf8",
+    "Variant",
     # constants
     "N_INFER_DEFAULT",
     "DTYPE_TEMPORAL_UNITS",
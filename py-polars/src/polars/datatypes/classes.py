@@ -2,10 +2,13 @@ from __future__ import annotations
 
 import contextlib
 import enum
+import json
 from collections import OrderedDict
 from collections.abc import Mapping
 from datetime import tzinfo
 from inspect import isclass
+from io import TextIOBase
+from pathlib import Path
 from typing import TYPE_CHECKING, Any, Generic, TypeVar, overload
 
 import polars._reexport as pl
@@ -23,6 +26,7 @@ import polars.datatypes.classes as pldt
 if TYPE_CHECKING:
     import sys
     from collections.abc import Callable, Iterable, Iterator, Sequence
+    from io import IOBase
 
     if sys.version_info >= (3, 11):
         from typing import Self
@@ -439,14 +443,17 @@ class Float64(FloatType):
 
 class Decimal(NumericType):
     """
-    Decimal 128-bit type with an optional precision and non-negative scale.
+    Decimal type with an optional precision and non-negative scale.
+
+    Decimals with a precision of up to 38 digits are stored as 128-bit
+    integers; a precision between 39 and 76 digits uses 256-bit storage.
 
     Parameters
     ----------
     precision
         Maximum number of digits in each number.
         If set to `None` (default), the precision is set to 38 (the maximum
-        supported by Polars).
+        supported by the 128-bit representation).
     scale
         Number of digits to the right of the decimal point in each number.
     """
@@ -667,6 +674,48 @@ class Duration(TemporalType):
         return f"{class_name}(time_unit={self.time_unit!r})"
 
 
+class Interval(TemporalType):
+    """
+    Data type representing a calendar interval.
+
+    An interval consists of a number of months, days and nanoseconds, each of which
+    may be negative. Adding an interval to a :class:`Date` or :class:`Datetime`
+    respects the varying lengths of months and days (in the presence of a time
+    zone), applying the months first, then the days and finally the nanoseconds.
+
+    Notes
+    -----
+    The underlying representation of this type is a 128-bit signed integer.
+    Individual values are represented in Python as a `(months, days, nanoseconds)`
+    tuple.
+    """
+
+
+def _write_serialized(serialized: str, file: IOBase | str | Path | None) -> str | None:
+    from polars._utils.various import normalize_filepath
+
+    if file is None:
+        return serialized
+    elif isinstance(file, (str, Path)):
+        file = normalize_filepath(file)
+        Path(file).write_text(serialized, encoding="utf-8")
+    elif isinstance(file, TextIOBase):
+        file.write(serialized)
+    else:
+        file.write(serialized.encode())  # type: ignore[attr-defined]
+    return None
+
+
+def _read_serialized(source: str | Path | IOBase) -> str:
+    from polars._utils.various import normalize_filepath
+
+    if isinstance(source, (str, Path)):
+        source = normalize_filepath(source)
+        return Path(source).read_text(encoding="utf-8")
+    data = source.read()  # type: ignore[attr-defined]
+    return data.decode() if isinstance(data, bytes) else data
+
+
 class Categories:
     """
     A named collection of categories for :py:class:`Categorical`.
@@ -724,8 +773,12 @@ class Categories:
         >>> fruit["apple"] is None
         True
 
-    If you wish to keep a persistent mapping, simply keep alive some object which
-    uses the mapping, e.g. `keepalive = pl.Series([], dtype=pl.Categorical(fruit))`.
+    If you wish to keep a persistent mapping, call :meth:`persist` or keep alive
+    some object which uses the mapping, e.g.
+    `keepalive = pl.Series([], dtype=pl.Categorical(fruit))`.
+
+    To get the same categorical codes in another process, the mapping can be
+    written with :meth:`serialize` and loaded again with :meth:`deserialize`.
     """
 
     _categories: PyCategories
@@ -817,6 +870,96 @@ class Categories:
         """Returns whether this refers to the global categories."""
         return self._categories.is_global()
 
+    def persist(self) -> Categories:
+        """
+        Keep the mapping alive for as long as this `Categories` is alive.
+
+        The mapping of a persistent `Categories` is also written to the metadata
+        of IPC and Parquet files, so that reading those files in another process
+        assigns the same categorical codes.
+
+        Examples
+        --------
+        >>> fruit = pl.Categories("persistent_fruit").persist()
+        >>> s = pl.Series(["apple", "banana"], dtype=pl.Categorical(fruit))
+        >>> del s
+        >>> fruit["banana"]
+        1
+        """
+        self._categories.persist()
+        return self
+
+    def is_persistent(self) -> bool:
+        """Returns whether the mapping is kept alive, see :meth:`persist`."""
+        return self._categories.is_persistent()
+
+    @overload
+    def serialize(self, file: None = ...) -> str: ...
+    @overload
+    def serialize(self, file: IOBase | str | Path) -> None: ...
+
+    def serialize(self, file: IOBase | str | Path | None = None) -> str | None:
+        """
+        Serialize the name, namespace, physical type and mapping of this `Categories`.
+
+        Parameters
+        ----------
+        file
+            File path or writable file-like object to which the result will be
+            written. If set to `None` (default), the output is returned as a string
+            instead.
+
+        See Also
+        --------
+        Categories.deserialize
+
+        Examples
+        --------
+        >>> fruit = pl.Categories("serialized_fruit")
+        >>> s = pl.Series(["apple", "banana"], dtype=pl.Categorical(fruit))
+        >>> fruit.serialize()
+        'categories;16;serialized_fruit0;u32;5;apple6;banana'
+        """
+        return _write_serialized(self._categories.serialize_mapping(), file)
+
+    @staticmethod
+    def deserialize(source: str | Path | IOBase) -> Categories:
+        """
+        Load a `Categories` written by :meth:`serialize`.
+
+        The categories get the same codes as when they were serialized and the
+        loaded `Categories` is persistent (see :meth:`persist`). An error is raised
+        if a `Categories` with the same name, namespace and physical type already
+        exists with conflicting codes.
+
+        Parameters
+        ----------
+        source
+            Path to a file or a file-like object (by file-like object, we refer to
+            objects that have a `read()` method, such as a file handler like the
+            builtin `open` function, or a `BytesIO` instance).
+
+        See Also
+        --------
+        Categories.serialize
+
+        Examples
+        --------
+        >>> import io
+        >>> serialized = "categories;12;loaded_fruit0;u32;6;banana5;apple"
+        >>> fruit = pl.Categories.deserialize(io.StringIO(serialized))
+        >>> pl.Series(["apple", "kiwi"], dtype=pl.Categorical(fruit)).to_physical()
+        shape: (2,)
+        Series: '' [u32]
+        [
+            1
+            2
+        ]
+        """
+        return Categories._from_py_categories(
+            PyCategories.deserialize_mapping(_read_serialized(source))
+        )
+
     def __getitem__(self, key: str | int | None) -> str | int | None:
         if key is None:
             return key
@@ -1031,6 +1174,57 @@ class Enum(DataType):
 
     __or__ = union
 
+    @overload
+    def serialize(self, file: None = ...) -> str: ...
+    @overload
+    def serialize(self, file: IOBase | str | Path) -> None: ...
+
+    def serialize(self, file: IOBase | str | Path | None = None) -> str | None:
+        """
+        Serialize the categories of this `Enum`.
+
+        Parameters
+        ----------
+        file
+            File path or writable file-like object to which the result will be
+            written. If set to `None` (default), the output is returned as a string
+            instead.
+
+        See Also
+        --------
+        Enum.deserialize
+
+        Examples
+        --------
+        >>> pl.Enum(["low", "high"]).serialize()
+        'frozen;3;low4;high'
+        """
+        return _write_serialized(plr.serialize_enum_categories(self), file)
+
+    @staticmethod
+    def deserialize(source: str | Path | IOBase) -> Enum:
+        """
+        Load an `Enum` written by :meth:`serialize`.
+
+        Parameters
+        ----------
+        source
+            Path to a file or a file-like object (by file-like object, we refer to
+            objects that have a `read()` method, such as a file handler like the
+            builtin `open` function, or a `BytesIO` instance).
+
+        See Also
+        --------
+        Enum.serialize
+
+        Examples
+        --------
+        >>> import io
+        >>> pl.Enum.deserialize(io.StringIO("frozen;3;low4;high"))
+        Enum(categories=['low', 'high'])
+        """
+        return plr.deserialize_enum_categories(_read_serialized(source))
+
 
 class Object(ObjectType):
     """Data type for wrapping arbitrary Python objects."""
@@ -1383,6 +1577,66 @@ class BaseExtension(DataType):
         else:
             return s[:10] + ".."
 
+    # The methods below are optional hooks that customize the behavior of the values
+    # of this extension type. By default the values behave like the storage values.
+    def ext_format_value(self, value: Any) -> str | None:
+        """
+        Format a single non-null storage value for display.
+
+        Return `None` to display the storage value as-is. Exceptions are propagated,
+        except when displaying a DataFrame or Series, where they are shown as a
+        warning and the storage value is displayed.
+        """
+
+    def ext_validate(self, storage: Series) -> None:
+        """
+        Validate storage values before they are converted to this extension type.
+
+        Raise an exception if the values are invalid.
+        """
+
+    def ext_cast_from(self, s: Series, *, strict: bool) -> Series | None:
+        """
+        Cast a Series of another dtype to the storage type of this extension type.
+
+        Return `None` to cast to the storage type directly. The result is validated
+        with :meth:`ext_validate`.
+        """
+
+    def ext_cast_to(
+        self,
+        storage: Series,
+        dtype: PolarsDataType,
+        *,
+        strict: bool,
+    ) -> Series | None:
+        """
+        Cast the storage values of this extension type to `dtype`.
+
+        Return `None` to use the default, which only allows casting to the
+        storage type.
+        """
+
+    def ext_sort_key(self, storage: Series) -> Series | None:
+        """
+        Get the keys by which the values are sorted and compared with `<` and `>`.
+
+        The keys must have the same length as the storage. Return `None` to order
+        by the storage values. Equality and grouping always use the storage values.
+        Exceptions are propagated, except by `arg_sort`, which shows them as a
+        warning and orders by the storage values.
+        """
+
+    def ext_aggregate(self, storage: Series, agg: str) -> Series | None:
+        """
+        Aggregate the storage values of a group, or of the whole column.
+
+        `agg` is one of `"min"`, `"max"` or `"sum"`. Return a Series of length 1
+        of the storage type, or `None` if the aggregation is not supported.
+        Exceptions are propagated, except in a group-by, where they are shown as a
+        warning and the result is null.
+        """
+
     def __repr__(self) -> str:
         md = self.ext_metadata()
         if md is not None:
@@ -1403,6 +1657,8 @@ class BaseExtension(DataType):
     def __eq__(self, other: pl.DataTypeExpr | PolarsDataType) -> pl.Expr | bool:
         if isinstance(other, pl.DataTypeExpr):
             return self.to_dtype_expr() == other
+        elif type(other) is DataTypeClass:
+            return issubclass(other, type(self))
         else:
             return (
                 isinstance(other, BaseExtension)
@@ -1434,3 +1690,170 @@ class Extension(BaseExtension):
     BaseExtension
     polars.register_extension_type
     """
+
+
+class Geometry(BaseExtension):
+    """
+    Geometry data type, storing planar geometries such as points and polygons.
+
+    Geometries are stored in the Well-Known Binary (WKB) encoding, and are exported
+    to (and imported from) the GeoArrow `geoarrow.wkb` extension type. Cast from
+    :class:`String` to parse Well-Known Text (WKT), and to :class:`String` to format
+    geometries as WKT. Parquet files with geometry columns get GeoParquet metadata.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed at any
+        point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    crs
+        The coordinate reference system, for example `"EPSG:4326"` or a PROJJSON
+        string. It is stored as metadata only, all spatial operations are planar.
+
+    Examples
+    --------
+    >>> s = pl.Series(["POINT (1 2)", "LINESTRING (0 0, 3 4)"]).cast(pl.Geometry)
+    >>> s.dtype
+    Geometry
+    """
+
+    def __init__(self, crs: str | None = None) -> None:
+        metadata = None if crs is None else json.dumps({"crs": crs})
+        super().__init__("geoarrow.wkb", Binary, metadata)
+
+    @property
+    def crs(self) -> str | None:
+        """The coordinate reference system of the geometries."""
+        md = self.ext_metadata()
+        if md is None:
+            return None
+        crs = json.loads(md).get("crs")
+        return crs if crs is None or isinstance(crs, str) else json.dumps(crs)
+
+    def _string_repr(self) -> str:
+        return "geometry"
+
+    def __repr__(self) -> str:
+        if self.crs is None:
+            return self.__class__.__name__
+        return f"{self.__class__.__name__}(crs={self.crs!r})"
+
+
+class Map(BaseExtension):
+    """
+    Map data type, associating keys with values.
+
+    A map is stored as a list of `{key, value}` structs, and is exported to (and
+    imported from) the Arrow, Parquet and IPC `Map` type.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed at any
+        point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    key
+        The `DataType` of the keys.
+    value
+        The `DataType` of the values.
+
+    Examples
+    --------
+    >>> s = pl.Series("m", [[{"key": "a", "value": 1}], []]).ext.to(
+    ...     pl.Map(pl.String, pl.Int64)
+    ... )
+    >>> s.dtype
+    Map(String, Int64)
+    """
+
+    def __init__(
+        self,
+        key: PolarsDataType | PythonDataType,
+        value: PolarsDataType | PythonDataType,
+    ) -> None:
+        key = polars.datatypes.parse_into_dtype(key)
+        value = polars.datatypes.parse_into_dtype(value)
+        super().__init__("polars.map", List(Struct({"key": key, "value": value})))
+
+    @property
+    def key(self) -> PolarsDataType:
+        """The `DataType` of the keys."""
+        return self._entries()[0].dtype
+
+    @property
+    def value(self) -> PolarsDataType:
+        """The `DataType` of the values."""
+        return self._entries()[1].dtype
+
+    def _entries(self) -> list[Field]:
+        storage = self.ext_storage()
+        assert isinstance(storage, List)
+        assert isinstance(storage.inner, Struct)
+        return storage.inner.fields
+
+    def _string_repr(self) -> str:
+        return f"map[{self.key._string_repr()},{self.value._string_repr()}]"
+
+    def __repr__(self) -> str:
+        return f"{self.__class__.__name__}({self.key!r}, {self.value!r})"
+
+
+class UUID(BaseExtension):
+    """
+    UUID data type, storing 16-byte universally unique identifiers.
+
+    UUIDs are exported to (and imported from) the Arrow `arrow.uuid` extension type
+    and the Parquet UUID logical type. Cast from :class:`String` to parse UUIDs, and to
+    :class:`String` to format them in the canonical hyphenated form.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed at any
+        point without it being considered a breaking change.
+
+    Examples
+    --------
+    >>> s = pl.Series(["f81d4fae-7dec-11d0-a765-00a0c91e6bf6"]).cast(pl.UUID)
+    >>> s.dtype
+    UUID
+    """
+
+    def __init__(self) -> None:
+        super().__init__("arrow.uuid", Binary)
+
+    def _string_repr(self) -> str:
+        return "uuid"
+
+    def __repr__(self) -> str:
+        return self.__class__.__name__
+
+
+class Variant(BaseExtension):
+    """
+    Variant data type, storing a self-describing semi-structured value per row.
+
+    Every value can be of a different type, such as a number, a string, an object or
+    an array. Values are stored in the Parquet Variant binary encoding, and are
+    exported to (and imported from) the Arrow `arrow.parquet.variant` extension type.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed at any
+        point without it being considered a breaking change.
+
+    Examples
+    --------
+    >>> s = pl.Series(['{"a": 1}', "[1, 2]", '"x"']).str.json_decode(pl.Variant)
+    >>> s.dtype
+    Variant
+    """
+
+    def __init__(self) -> None:
+        super().__init__(
+            "arrow.parquet.variant", Struct({"metadata": Binary, "value": Binary})
+        )
+
+    def _string_repr(self) -> str:
+        return "variant"
+
+    def __repr__(self) -> str:
+        return self.__class__.__name__
@@ -31,6 +31,7 @@ from polars.datatypes.classes import (
     Int32,
     Int64,
     Int128,
+    Interval,
     List,
     Null,
     Object,
@@ -177,6 +178,7 @@ class _DataTypeMappings:
             Int32: int,
             Int64: int,
             Int128: int,
+            Interval: tuple,
             List: list,
             Null: None.__class__,
             Object: object,
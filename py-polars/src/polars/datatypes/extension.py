@@ -8,7 +8,12 @@ from polars._utils.unstable import unstable
 with contextlib.suppress(ImportError):  # Module not available when building docs
     from polars._plr import _register_extension_type, _unregister_extension_type
 
-_REGISTRY: dict[str, str | type[dt.BaseExtension]] = {}
+_REGISTRY: dict[str, str | type[dt.BaseExtension]] = {
+    "polars.map": dt.Map,
+    "arrow.parquet.variant": dt.Variant,
+    "arrow.uuid": dt.UUID,
+    "geoarrow.wkb": dt.Geometry,
+}
 
 
 @unstable()
@@ -18,6 +18,7 @@ from polars.datatypes.classes import (
     Int32,
     Int64,
     Int128,
+    Interval,
     List,
     Struct,
     Time,
@@ -127,7 +128,7 @@ DURATION_DTYPES: Final[frozenset[PolarsDataType]] = DataTypeGroup(
     ]
 )
 TEMPORAL_DTYPES: Final[frozenset[PolarsTemporalType]] = DataTypeGroup(
-    frozenset([Date, Time]) | DATETIME_DTYPES | DURATION_DTYPES
+    frozenset([Date, Time, Interval]) | DATETIME_DTYPES | DURATION_DTYPES
 )
 
 NESTED_DTYPES: Final[frozenset[PolarsDataType]] = DataTypeGroup([List, Struct, Array])
@@ -62,11 +62,15 @@ from polars.expr.binary import ExprBinaryNameSpace
 from polars.expr.categorical import ExprCatNameSpace
 from polars.expr.datetime import ExprDateTimeNameSpace
 from polars.expr.ext import ExprExtensionNameSpace
+from polars.expr.geometry import ExprGeometryNameSpace
 from polars.expr.list import ExprListNameSpace
+from polars.expr.map import ExprMapNameSpace
 from polars.expr.meta import ExprMetaNameSpace
 from polars.expr.name import ExprNameNameSpace
 from polars.expr.string import ExprStringNameSpace
 from polars.expr.struct import ExprStructNameSpace
+from polars.expr.uuid import ExprUuidNameSpace
+from polars.expr.variant import ExprVariantNameSpace
 from polars.meta import thread_pool_size
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -144,10 +148,14 @@ class Expr:
         "dt",
         "ext",
         "list",
+        "map",
         "meta",
         "name",
+        "st",
         "str",
         "struct",
+        "uuid",
+        "variant",
     }
 
     @property
@@ -291,6 +299,42 @@ class Expr:
         """
         return ExprExtensionNameSpace(self)
 
+    @property
+    def map(self) -> ExprMapNameSpace:
+        """
+        Create an object namespace of all map related expressions.
+
+        See the individual method pages for full details.
+        """
+        return ExprMapNameSpace(self)
+
+    @property
+    def st(self) -> ExprGeometryNameSpace:
+        """
+        Create an object namespace of all geometry related expressions.
+
+        See the individual method pages for full details.
+        """
+        return ExprGeometryNameSpace(self)
+
+    @property
+    def uuid(self) -> ExprUuidNameSpace:
+        """
+        Create an object namespace of all UUID related expressions.
+
+        See the individual method pages for full details.
+        """
+        return ExprUuidNameSpace(self)
+
+    @property
+    def variant(self) -> ExprVariantNameSpace:
+        """
+        Create an object namespace of all variant related expressions.
+
+        See the individual method pages for full details.
+        """
+        return ExprVariantNameSpace(self)
+
     @classmethod
     def _from_pyexpr(cls, pyexpr: PyExpr) -> Expr:
         expr = cls.__new__(cls)
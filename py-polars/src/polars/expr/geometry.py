@@ -0,0 +1,240 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.parse import parse_into_expression
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+    from polars._typing import IntoExpr
+
+
+class ExprGeometryNameSpace:
+    """
+    Namespace for geometry related expressions.
+
+    All measures and predicates are planar: they are computed on the raw coordinates,
+    without taking the coordinate reference system into account.
+    """
+
+    _accessor = "st"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    @unstable()
+    def bounds(self) -> Expr:
+        """
+        Get the bounding box of every geometry.
+
+        The bounding box is returned as a struct with the fields `xmin`, `ymin`,
+        `xmax` and `ymax`. Empty geometries have a null bounding box.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"geom": ["POLYGON ((0 0, 4 0, 4 3, 0 0))", "LINESTRING (0 0, 3 4)"]}
+        ... ).with_columns(pl.col("geom").cast(pl.Geometry))
+        >>> df.select(pl.col("geom").st.bounds())
+        shape: (2, 1)
+        ┌───────────────────┐
+        │ geom              │
+        │ ---               │
+        │ struct[4]         │
+        ╞═══════════════════╡
+        │ {0.0,0.0,4.0,3.0} │
+        │ {0.0,0.0,3.0,4.0} │
+        └───────────────────┘
+        """
+        return wrap_expr(self._pyexpr.st_bounds())
+
+    @unstable()
+    def area(self) -> Expr:
+        """
+        Get the area of every geometry.
+
+        The area of points and lines is zero.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"geom": ["POLYGON ((0 0, 4 0, 4 3, 0 0))", "LINESTRING (0 0, 3 4)"]}
+        ... ).with_columns(pl.col("geom").cast(pl.Geometry))
+        >>> df.select(pl.col("geom").st.area())
+        shape: (2, 1)
+        ┌──────┐
+        │ geom │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ 6.0  │
+        │ 0.0  │
+        └──────┘
+        """
+        return wrap_expr(self._pyexpr.st_area())
+
+    @unstable()
+    def length(self) -> Expr:
+        """
+        Get the length of every linear geometry.
+
+        The length of points and polygons is zero. Use :meth:`bounds` or
+        :meth:`area` to measure polygons instead.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"geom": ["POLYGON ((0 0, 4 0, 4 3, 0 0))", "LINESTRING (0 0, 3 4)"]}
+        ... ).with_columns(pl.col("geom").cast(pl.Geometry))
+        >>> df.select(pl.col("geom").st.length())
+        shape: (2, 1)
+        ┌──────┐
+        │ geom │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ 0.0  │
+        │ 5.0  │
+        └──────┘
+        """
+        return wrap_expr(self._pyexpr.st_length())
+
+    @unstable()
+    def distance(self, other: IntoExpr) -> Expr:
+        """
+        Get the minimum distance between every geometry and the geometry of `other`.
+
+        The distance is zero if the geometries intersect, and null if either of them
+        is empty.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            An expression producing geometries. Strings are parsed as column names.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["POINT (0 0)", "POINT (1 1)"],
+        ...         "b": ["POINT (3 4)", "LINESTRING (0 0, 2 2)"],
+        ...     }
+        ... ).with_columns(pl.all().cast(pl.Geometry))
+        >>> df.select(pl.col("a").st.distance("b"))
+        shape: (2, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 5.0 │
+        │ 0.0 │
+        └─────┘
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.st_distance(other_pyexpr))
+
+    @unstable()
+    def contains(self, other: IntoExpr) -> Expr:
+        """
+        Check whether every geometry contains the geometry of `other`.
+
+        A geometry contains another if no point of the other geometry lies outside
+        of it, and their interiors have at least one point in common. A polygon
+        therefore does not contain points on its boundary.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            An expression producing geometries. Strings are parsed as column names.
+
+        See Also
+        --------
+        intersects
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0))"] * 2,
+        ...         "b": ["POINT (1 1)", "POINT (4 2)"],
+        ...     }
+        ... ).with_columns(pl.all().cast(pl.Geometry))
+        >>> df.select(pl.col("a").st.contains("b"))
+        shape: (2, 1)
+        ┌───────┐
+        │ a     │
+        │ ---   │
+        │ bool  │
+        ╞═══════╡
+        │ true  │
+        │ false │
+        └───────┘
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.st_contains(other_pyexpr))
+
+    @unstable()
+    def intersects(self, other: IntoExpr) -> Expr:
+        """
+        Check whether every geometry intersects the geometry of `other`.
+
+        Two geometries intersect if they have at least one point in common,
+        including points on their boundaries.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            An expression producing geometries. Strings are parsed as column names.
+
+        See Also
+        --------
+        contains
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0))"] * 2,
+        ...         "b": ["POINT (1 1)", "POINT (4 2)"],
+        ...     }
+        ... ).with_columns(pl.all().cast(pl.Geometry))
+        >>> df.select(pl.col("a").st.intersects("b"))
+        shape: (2, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ bool │
+        ╞══════╡
+        │ true │
+        │ true │
+        └──────┘
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.st_intersects(other_pyexpr))
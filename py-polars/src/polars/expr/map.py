@@ -0,0 +1,162 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.parse import parse_into_expression
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+    from polars._typing import IntoExpr
+
+
+class ExprMapNameSpace:
+    """Namespace for map related expressions."""
+
+    _accessor = "map"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    @unstable()
+    def get(self, key: IntoExpr) -> Expr:
+        """
+        Get the value belonging to `key`.
+
+        Returns null if the map does not contain `key`. If a key occurs more than
+        once, the value of its first occurrence is returned.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        key
+            The key to look up. Strings are parsed as literals.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"k": [["a", "b"], ["c"]], "v": [[1, 2], [3]]})
+        >>> df.select(m=pl.col("k").map.from_lists("v")).select(
+        ...     pl.col("m").map.get("b")
+        ... )
+        shape: (2, 1)
+        ┌──────┐
+        │ m    │
+        │ ---  │
+        │ i64  │
+        ╞══════╡
+        │ 2    │
+        │ null │
+        └──────┘
+        """
+        key_pyexpr = parse_into_expression(key, str_as_lit=True)
+        return wrap_expr(self._pyexpr.map_get(key_pyexpr))
+
+    @unstable()
+    def contains_key(self, key: IntoExpr) -> Expr:
+        """
+        Check whether the map contains `key`.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        key
+            The key to look up. Strings are parsed as literals.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"k": [["a", "b"], ["c"]], "v": [[1, 2], [3]]})
+        >>> df.select(m=pl.col("k").map.from_lists("v")).select(
+        ...     pl.col("m").map.contains_key("c")
+        ... )
+        shape: (2, 1)
+        ┌───────┐
+        │ m     │
+        │ ---   │
+        │ bool  │
+        ╞═══════╡
+        │ false │
+        │ true  │
+        └───────┘
+        """
+        key_pyexpr = parse_into_expression(key, str_as_lit=True)
+        return wrap_expr(self._pyexpr.map_contains_key(key_pyexpr))
+
+    @unstable()
+    def keys(self) -> Expr:
+        """
+        Get the keys of the map as a list.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+        """
+        return wrap_expr(self._pyexpr.map_keys())
+
+    @unstable()
+    def values(self) -> Expr:
+        """
+        Get the values of the map as a list.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+        """
+        return wrap_expr(self._pyexpr.map_values())
+
+    @unstable()
+    def from_lists(self, values: IntoExpr) -> Expr:
+        """
+        Create a map from a list of keys and a list of `values`.
+
+        The list of keys is taken from the expression this namespace is called on.
+        The lists of keys and values must have the same length in every row.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        values
+            The lists of values. Strings are parsed as column names.
+        """
+        values_pyexpr = parse_into_expression(values)
+        return wrap_expr(self._pyexpr.map_from_lists(values_pyexpr))
+
+    @unstable()
+    def explode(self) -> Expr:
+        """
+        Explode the map into one `{key, value}` struct per entry.
+
+        Use :meth:`Expr.struct.unnest` on the result to get separate `key` and
+        `value` columns.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"k": [["a", "b"], ["c"]], "v": [[1, 2], [3]]})
+        >>> df.select(m=pl.col("k").map.from_lists("v")).select(
+        ...     pl.col("m").map.explode().struct.unnest()
+        ... )
+        shape: (3, 2)
+        ┌─────┬───────┐
+        │ key ┆ value │
+        │ --- ┆ ---   │
+        │ str ┆ i64   │
+        ╞═════╪═══════╡
+        │ a   ┆ 1     │
+        │ b   ┆ 2     │
+        │ c   ┆ 3     │
+        └─────┴───────┘
+        """
+        return wrap_expr(self._pyexpr.map_explode())
@@ -0,0 +1,57 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+
+
+class ExprUuidNameSpace:
+    """Namespace for UUID related expressions."""
+
+    _accessor = "uuid"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    @unstable()
+    def timestamp(self) -> Expr:
+        """
+        Get the creation time of version 7 UUIDs.
+
+        The timestamp is returned as a UTC datetime with millisecond precision. UUIDs
+        of any other version return null.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        See Also
+        --------
+        polars.uuid7 : Generate time-ordered UUIDs.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "id": [
+        ...             "01890a5d-ac96-774b-bcce-b302099a8057",
+        ...             "f81d4fae-7dec-11d0-a765-00a0c91e6bf6",
+        ...         ]
+        ...     }
+        ... )
+        >>> df.select(pl.col("id").cast(pl.UUID).uuid.timestamp())
+        shape: (2, 1)
+        ┌─────────────────────────────┐
+        │ id                          │
+        │ ---                         │
+        │ datetime[ms, UTC]           │
+        ╞═════════════════════════════╡
+        │ 2023-06-30 03:34:18.518 UTC │
+        │ null                        │
+        └─────────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.uuid_timestamp())
@@ -0,0 +1,240 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+from polars.datatypes import parse_into_datatype_expr
+
+if TYPE_CHECKING:
+    import polars._reexport as pl
+    from polars import Expr
+    from polars._typing import PolarsDataType
+
+
+class ExprVariantNameSpace:
+    """Namespace for variant related expressions."""
+
+    _accessor = "variant"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    @unstable()
+    def get(
+        self,
+        path: str,
+        dtype: PolarsDataType | pl.DataTypeExpr | None = None,
+        *,
+        strict: bool = True,
+    ) -> Expr:
+        """
+        Get the value at `path`.
+
+        Returns null if the path does not exist.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        path
+            A JSONPath-like path such as `$.a.b[0]`. Fields are selected with
+            `.name` or `['name']` and array elements with `[i]`, where negative
+            indices count from the end. The leading `$` is optional.
+        dtype
+            The dtype to cast the value to. If not given, the value is returned
+            as a variant.
+        strict
+            Raise an error if a value cannot be cast to `dtype`, instead of
+            setting it to null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"json": ['{"a": {"b": [1, 2]}}', '{"a": 3}']})
+        >>> df.select(
+        ...     pl.col("json")
+        ...     .str.json_decode(pl.Variant)
+        ...     .variant.get("$.a.b[1]", pl.Int64)
+        ... )
+        shape: (2, 1)
+        ┌──────┐
+        │ json │
+        │ ---  │
+        │ i64  │
+        ╞══════╡
+        │ 2    │
+        │ null │
+        └──────┘
+        """
+        expr = wrap_expr(self._pyexpr.variant_get(path))
+        if dtype is None:
+            return expr
+        return expr.variant.cast(dtype, strict=strict)
+
+    @unstable()
+    def cast(
+        self,
+        dtype: PolarsDataType | pl.DataTypeExpr,
+        *,
+        strict: bool = True,
+    ) -> Expr:
+        """
+        Cast the variant values to `dtype`.
+
+        Casting to `String` returns strings as-is and all other values as JSON.
+        Nested dtypes such as `List` and `Struct` are decoded from the JSON
+        representation of the values.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        dtype
+            The dtype to cast to.
+        strict
+            Raise an error if a value cannot be cast to `dtype`, instead of
+            setting it to null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"json": ["1", "2.5", '"x"']})
+        >>> df.select(
+        ...     pl.col("json")
+        ...     .str.json_decode(pl.Variant)
+        ...     .variant.cast(pl.Float64, strict=False)
+        ... )
+        shape: (3, 1)
+        ┌──────┐
+        │ json │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ 1.0  │
+        │ 2.5  │
+        │ null │
+        └──────┘
+        """
+        dtype_expr = parse_into_datatype_expr(dtype)._pydatatype_expr
+        return wrap_expr(self._pyexpr.variant_cast(dtype_expr, strict))
+
+    @unstable()
+    def to_json(self) -> Expr:
+        """
+        Serialize the variant values to JSON strings.
+
+        Values without a JSON counterpart are written as strings: temporal values
+        in ISO 8601 format, UUIDs in their hyphenated form and binary values
+        base64-encoded.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"json": ['{"b": 1, "a": [true, null]}']})
+        >>> df.select(
+        ...     pl.col("json").str.json_decode(pl.Variant).variant.to_json()
+        ... )
+        shape: (1, 1)
+        ┌─────────────────────────┐
+        │ json                    │
+        │ ---                     │
+        │ str                     │
+        ╞═════════════════════════╡
+        │ {"a":[true,null],"b":1} │
+        └─────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.variant_to_json())
+
+    @unstable()
+    def type_name(self) -> Expr:
+        """
+        Get the name of the type of the variant values.
+
+        One of `"null"`, `"boolean"`, `"integer"`, `"float"`, `"decimal"`,
+        `"date"`, `"datetime"`, `"time"`, `"binary"`, `"string"`, `"uuid"`,
+        `"object"` or `"array"`.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"json": ["1", '"x"', "[1]", "null"]})
+        >>> df.select(
+        ...     pl.col("json").str.json_decode(pl.Variant).variant.type_name()
+        ... )
+        shape: (4, 1)
+        ┌─────────┐
+        │ json    │
+        │ ---     │
+        │ str     │
+        ╞═════════╡
+        │ integer │
+        │ string  │
+        │ array   │
+        │ null    │
+        └─────────┘
+        """
+        return wrap_expr(self._pyexpr.variant_type_name())
+
+    @unstable()
+    def is_object(self) -> Expr:
+        """
+        Check whether the variant values are objects.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+        """
+        return self.type_name() == "object"
+
+    @unstable()
+    def is_array(self) -> Expr:
+        """
+        Check whether the variant values are arrays.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+        """
+        return self.type_name() == "array"
+
+    @unstable()
+    def is_string(self) -> Expr:
+        """
+        Check whether the variant values are strings.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+        """
+        return self.type_name() == "string"
+
+    @unstable()
+    def is_boolean(self) -> Expr:
+        """
+        Check whether the variant values are booleans.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+        """
+        return self.type_name() == "boolean"
+
+    @unstable()
+    def is_numeric(self) -> Expr:
+        """
+        Check whether the variant values are integers, floats or decimals.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+        """
+        return self.type_name().is_in(["integer", "float", "decimal"])
@@ -90,6 +90,7 @@ from polars.functions.range import (
     time_ranges,
 )
 from polars.functions.repeat import ones, repeat, zeros
+from polars.functions.uuid import uuid4, uuid7
 from polars.functions.whenthen import when
 
 __all__ = [
@@ -186,6 +187,9 @@ __all__ = [
     "var",
     # polars.functions.len
     "len",
+    # polars.functions.uuid
+    "uuid4",
+    "uuid7",
     # polars.functions.whenthen
     "when",
     "sql_expr",
@@ -2078,7 +2078,10 @@ def collect_all(
     This can run all the computation graphs in parallel or combined.
 
     Common Subplan Elimination is applied on the combined plan, meaning
-    that diverging queries will run only once.
+    that diverging queries will run only once. With the streaming engine, this
+    includes scans that are filtered differently by each query: the source is
+    read once with the union of the filters, and every query applies its own
+    filter to the shared rows.
 
     Parameters
     ----------
@@ -0,0 +1,87 @@
+from __future__ import annotations
+
+import contextlib
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars._plr as plr
+
+if TYPE_CHECKING:
+    from polars import Expr
+
+
+@unstable()
+def uuid4() -> Expr:
+    """
+    Generate a random (version 4) UUID for every row in the context.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed at any
+        point without it being considered a breaking change.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`UUID`, named `uuid`.
+
+    See Also
+    --------
+    uuid7 : Generate time-ordered UUIDs.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"a": [1, 2]})
+    >>> df.with_columns(id=pl.uuid4())  # doctest: +IGNORE_RESULT
+    shape: (2, 2)
+    ┌─────┬──────────────────────────────────────┐
+    │ a   ┆ id                                   │
+    │ --- ┆ ---                                  │
+    │ i64 ┆ uuid                                 │
+    ╞═════╪══════════════════════════════════════╡
+    │ 1   ┆ 0f2b4c3e-5c0a-4e4b-9a51-7a8c3b1d2e6f │
+    │ 2   ┆ 9d7e1a42-3b6f-4c8d-8e2a-1f5b7c9d0a34 │
+    └─────┴──────────────────────────────────────┘
+    """
+    return wrap_expr(plr.uuid4())
+
+
+@unstable()
+def uuid7() -> Expr:
+    """
+    Generate a time-ordered (version 7) UUID for every row in the context.
+
+    The UUIDs start with the current Unix timestamp in milliseconds. UUIDs generated
+    within the same process are strictly increasing, so sorting by them sorts by
+    creation order. Use :meth:`Expr.uuid.timestamp` to extract the timestamp.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed at any
+        point without it being considered a breaking change.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`UUID`, named `uuid`.
+
+    See Also
+    --------
+    uuid4 : Generate random UUIDs.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"a": [1, 2]})
+    >>> df.with_columns(id=pl.uuid7())  # doctest: +IGNORE_RESULT
+    shape: (2, 2)
+    ┌─────┬──────────────────────────────────────┐
+    │ a   ┆ id                                   │
+    │ --- ┆ ---                                  │
+    │ i64 ┆ uuid                                 │
+    ╞═════╪══════════════════════════════════════╡
+    │ 1   ┆ 0192f8a1-6c3e-7b2a-9f41-3d5e7a9c1b20 │
+    │ 2   ┆ 0192f8a1-6c3e-7b2a-9f41-3d5e7a9c1b21 │
+    └─────┴──────────────────────────────────────┘
+    """
+    return wrap_expr(plr.uuid7())
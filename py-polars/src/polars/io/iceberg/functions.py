@@ -40,6 +40,11 @@ def scan_iceberg(
     """
     Lazily read from an Apache Iceberg table.
 
+    .. versionchanged:: 1.40.0
+        Iceberg `map` columns are read as :class:`Map` instead of
+        `List(Struct({"key": ..., "value": ...}))`. Set the environment variable
+        `POLARS_IMPORT_ARROW_MAP_AS_MAP=0` to restore the previous behavior.
+
     Parameters
     ----------
     source
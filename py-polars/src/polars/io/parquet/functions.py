@@ -85,6 +85,11 @@ def read_parquet(
         * The `row_count_name` parameter was renamed `row_index_name`.
         * The `row_count_offset` parameter was renamed `row_index_offset`.
 
+    .. versionchanged:: 1.40.0
+        Parquet and Arrow map columns are read as :class:`Map` instead of
+        `List(Struct({"key": ..., "value": ...}))`. Set the environment variable
+        `POLARS_IMPORT_ARROW_MAP_AS_MAP=0` to restore the previous behavior.
+
     Parameters
     ----------
     source
@@ -495,6 +500,11 @@ def scan_parquet(
     .. versionchanged:: 1.30.0
         * The `allow_missing_columns` is deprecated in favor of `missing_columns`.
 
+    .. versionchanged:: 1.40.0
+        Parquet and Arrow map columns are read as :class:`Map` instead of
+        `List(Struct({"key": ..., "value": ...}))`. Set the environment variable
+        `POLARS_IMPORT_ARROW_MAP_AS_MAP=0` to restore the previous behavior.
+
     Parameters
     ----------
     source
@@ -52,6 +52,17 @@ class PartitionBy:
     approximate_bytes_per_file
         Approximate number of bytes to write to each file. This is measured as
         the estimated size of the DataFrame in memory.
+    commit_protocol : {'direct', 'staged'}
+        How the written files are made visible at `base_path`.
+
+        * `direct`: Files are written to their final location directly.
+        * `staged`: Files are written to a staging location under `base_path` and
+          are only published once all files have been written. A `_SUCCESS`
+          manifest listing the published files is written last.
+    overwrite_partitions
+        Remove the existing data files of every partition that is written to. The
+        old files are only removed after the new files have been published.
+        Requires `commit_protocol="staged"`.
 
     Examples
     --------
@@ -94,6 +105,8 @@ class PartitionBy:
         include_key: bool | None = None,
         max_rows_per_file: int | None = None,
         approximate_bytes_per_file: int | Literal["auto"] | None = "auto",
+        commit_protocol: Literal["direct", "staged"] = "direct",
+        overwrite_partitions: bool = False,
     ) -> None:
         msg = "`PartitionBy` functionality is considered unstable"
         issue_unstable_warning(msg)
@@ -114,6 +127,10 @@ class PartitionBy:
             msg = "cannot use 'include_key' without specifying 'key'"
             raise ValueError(msg)
 
+        if overwrite_partitions and commit_protocol != "staged":
+            msg = "'overwrite_partitions' requires commit_protocol='staged'"
+            raise ValueError(msg)
+
         base_path = str(base_path)
 
         if approximate_bytes_per_file == "auto":
@@ -131,6 +148,8 @@ class PartitionBy:
             include_key=include_key,
             max_rows_per_file=max_rows_per_file,
             approximate_bytes_per_file=approximate_bytes_per_file,
+            commit_protocol=commit_protocol,
+            overwrite_partitions=overwrite_partitions,
         )
 
 
@@ -166,6 +185,8 @@ class _PartitionByInner:
     include_key: bool | None
     max_rows_per_file: int | None
     approximate_bytes_per_file: int
+    commit_protocol: Literal["direct", "staged"] = "direct"
+    overwrite_partitions: bool = False
 
 
 @dataclass(kw_only=True)
@@ -135,6 +135,7 @@ if TYPE_CHECKING:
         AsofJoinStrategy,
         ClosedInterval,
         ColumnNameOrSelector,
+        CsvCompression,
         CsvQuoteStyle,
         EngineType,
         ExplainFormat,
@@ -148,6 +149,7 @@ if TYPE_CHECKING:
         JoinValidation,
         Label,
         MaintainOrderJoin,
+        NdjsonCompression,
         Orientation,
         ParquetMetadata,
         PivotAgg,
@@ -158,6 +160,7 @@ if TYPE_CHECKING:
         SchemaDefinition,
         SchemaDict,
         SerializationFormat,
+        SpatialPredicate,
         StartBy,
         StorageOptionsDict,
         SyncOnCloseMethod,
@@ -1430,6 +1433,48 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         else:
             return self._ldf.describe_plan()
 
+    @unstable()
+    def explain_analyze(
+        self, *, optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS
+    ) -> str:
+        """
+        Execute the query and return its physical plan annotated with runtime metrics.
+
+        The query is run on the streaming engine. Every node of the physical plan
+        is listed together with the number of rows and morsels it received and
+        sent, its wall and CPU time and the number of bytes it read. The result
+        of the query itself is discarded.
+
+        The wall time of a node is the time from the first until the last poll of
+        its tasks, summed over the execution phases it ran in. Nodes that buffer
+        data, such as sinks, group-bys and joins, also report the peak number of
+        bytes of data they held between phases (`buffered_peak`).
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        optimizations
+            The optimization passes done during query optimization.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": ["a", "b", "a"], "b": [1, 2, 3]})
+        >>> print(
+        ...     lf.group_by("a").agg(pl.col("b").sum()).explain_analyze()
+        ... )  # doctest: +SKIP
+        streaming query took 1.21ms
+        in-memory-sink: wall=..., rows(in=2, out=0), ...
+          group-by: wall=..., rows(in=3, out=2), ...
+            in-memory-source: wall=..., rows(in=0, out=3), ...
+        """
+        optimizations = optimizations.__copy__()
+        optimizations._pyoptflags.streaming = True
+        ldf = self._ldf.with_optimizations(optimizations._pyoptflags)
+        return ldf.explain_analyze()
+
     @deprecate_streaming_parameter()
     @forward_old_opt_flags()
     def show_graph(
@@ -2209,6 +2254,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         engine: EngineType = "auto",
         background: Literal[True],
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+        timeout: float | None = None,
+        memory_limit: int | None = None,
+        progress: Callable[[dict[str, Any]], None] | None = None,
+        progress_interval: float = 1.0,
     ) -> InProcessQuery: ...
 
     @overload
@@ -2228,6 +2277,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         engine: EngineType = "auto",
         background: Literal[False] = False,
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+        timeout: float | None = None,
+        memory_limit: int | None = None,
+        progress: Callable[[dict[str, Any]], None] | None = None,
+        progress_interval: float = 1.0,
     ) -> DataFrame: ...
 
     @deprecate_streaming_parameter()
@@ -2248,6 +2301,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         engine: EngineType = "auto",
         background: bool = False,
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+        timeout: float | None = None,
+        memory_limit: int | None = None,
+        progress: Callable[[dict[str, Any]], None] | None = None,
+        progress_interval: float = 1.0,
         **_kwargs: Any,
     ) -> DataFrame | InProcessQuery:
         """
@@ -2344,6 +2401,40 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             .. warning::
                 This functionality is considered **unstable**. It may be changed
                 at any point without it being considered a breaking change.
+        timeout
+            Abort the query with a :class:`ComputeError` if it runs longer than
+            this many seconds. Streaming sinks that were aborted remove the files
+            they were writing. To limit a sink, use `lazy=True` and pass the
+            timeout to `collect`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        memory_limit
+            Abort the query with a :class:`ComputeError` if the data it buffers
+            exceeds this many bytes. If spilling is enabled through
+            `POLARS_OOC_SPILL_POLICY=spill`, buffered data is spilled to disk
+            first and the query is only aborted if that does not free enough
+            memory. Only supported by the streaming engine.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        progress
+            Function called with the progress of the query every
+            `progress_interval` seconds, and once more when the query completed.
+            It receives a dict with the `elapsed` seconds, whether the query is
+            `finished`, and per file source (`sources`) the files, row groups and
+            bytes consumed versus their totals, and per sink (`sinks`) the rows
+            written. The row group and byte totals are `None` until the metadata
+            of every file of the source was read. Only supported by the
+            streaming engine.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        progress_interval
+            Seconds between two calls of `progress`.
 
         Returns
         -------
@@ -2455,13 +2546,30 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             engine = "gpu"
 
         ldf = self._ldf.with_optimizations(optimizations._pyoptflags)
+        if timeout is not None or memory_limit is not None:
+            issue_unstable_warning("query limits are considered unstable.")
+        if progress is not None:
+            if background:
+                msg = "progress reporting is not supported in background mode"
+                raise InvalidOperationError(msg)
+            issue_unstable_warning("progress reporting is considered unstable.")
+
         if background:
             issue_unstable_warning("background mode is considered unstable.")
-            return InProcessQuery(ldf.collect_concurrently())
+            return InProcessQuery(ldf.collect_concurrently(timeout, memory_limit))
 
         # Only for testing purposes
         callback = _kwargs.get("post_opt_callback", callback)
-        return wrap_df(ldf.collect(engine, callback))
+        return wrap_df(
+            ldf.collect(
+                engine,
+                callback,
+                timeout,
+                memory_limit,
+                progress,
+                progress_interval,
+            )
+        )
 
     @overload
     def collect_async(
@@ -3298,6 +3406,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         compression: IpcCompression | None = "uncompressed",
         compat_level: CompatLevel | None = None,
         record_batch_size: int | None = None,
+        stream: bool = False,
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -3320,6 +3429,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         compression: IpcCompression | None = "uncompressed",
         compat_level: CompatLevel | None = None,
         record_batch_size: int | None = None,
+        stream: bool = False,
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -3341,6 +3451,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         compression: IpcCompression | None = "uncompressed",
         compat_level: CompatLevel | None = None,
         record_batch_size: int | None = None,
+        stream: bool = False,
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -3372,6 +3483,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         record_batch_size
             Size of the record batches in number of rows.
 
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        stream
+            Write the Arrow IPC streaming format instead of the file format. The
+            streaming format has no footer and can be read incrementally. The
+            `compression` setting applies to the record batch bodies.
+
             .. warning::
                 This functionality is considered **unstable**. It may be changed
                 at any point without it being considered a breaking change.
@@ -3525,6 +3644,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             compat_level=compat_level_py,
             record_batch_size=record_batch_size,
             record_batch_statistics=_record_batch_statistics,
+            stream=stream,
         )
 
         if not lazy:
@@ -3540,7 +3660,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         path: str | Path | IO[bytes] | IO[str] | PartitionBy,
         *,
         include_bom: bool = False,
-        compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
+        compression: CsvCompression = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
         include_header: bool = True,
@@ -3575,7 +3695,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         path: str | Path | IO[bytes] | IO[str] | PartitionBy,
         *,
         include_bom: bool = False,
-        compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
+        compression: CsvCompression = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
         include_header: bool = True,
@@ -3609,7 +3729,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         path: str | Path | IO[bytes] | IO[str] | PartitionBy,
         *,
         include_bom: bool = False,
-        compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
+        compression: CsvCompression = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
         include_header: bool = True,
@@ -3664,7 +3784,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         check_extension
             Whether to check if the filename matches the compression settings.
             Will raise an error if compression is set to 'uncompressed' and the
-            filename ends in one of (".gz", ".zst", ".zstd") or if
+            filename ends in one of (".gz", ".zst", ".zstd", ".bz2", ".xz") or if
             compression != 'uncompressed' and the file uses an mismatched
             extension. Only applies if file is a path.
 
@@ -3891,7 +4011,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitionBy,
         *,
-        compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
+        compression: NdjsonCompression = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
         maintain_order: bool = True,
@@ -3912,7 +4032,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitionBy,
         *,
-        compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
+        compression: NdjsonCompression = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
         maintain_order: bool = True,
@@ -3932,7 +4052,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitionBy,
         *,
-        compression: Literal["uncompressed", "gzip", "zstd"] = "uncompressed",
+        compression: NdjsonCompression = "uncompressed",
         compression_level: int | None = None,
         check_extension: bool = True,
         maintain_order: bool = True,
@@ -3972,7 +4092,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         check_extension
             Whether to check if the filename matches the compression settings.
             Will raise an error if compression is set to 'uncompressed' and the
-            filename ends in one of (".gz", ".zst", ".zstd") or if
+            filename ends in one of (".gz", ".zst", ".zstd", ".bz2", ".xz") or if
             compression != 'uncompressed' and the file uses an mismatched
             extension. Only applies if file is a path.
 
@@ -6431,6 +6551,161 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             )
         )
 
+    @unstable()
+    def join_spatial(
+        self,
+        other: LazyFrame,
+        left_on: str,
+        right_on: str | None = None,
+        *,
+        predicate: SpatialPredicate = "intersects",
+        suffix: str = "_right",
+    ) -> LazyFrame:
+        """
+        Perform a spatial join on a geometry column of each table.
+
+        This performs an inner join, so only the pairs of rows whose geometries
+        satisfy the predicate are included in the result. Candidate pairs are first
+        found with a range join on the bounding boxes of the geometries, after which
+        the exact predicate is evaluated on these candidates only.
+
+        .. note::
+            The row order of the input DataFrames is not preserved.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            LazyFrame to join with.
+        left_on
+            Name of the :class:`Geometry` column of the left table.
+        right_on
+            Name of the :class:`Geometry` column of the right table. Defaults to
+            `left_on`.
+        predicate : {'intersects', 'contains', 'within'}
+            The spatial relation between the left and the right geometries.
+
+            * *intersects*
+                The geometries have at least one point in common.
+            * *contains*
+                The left geometry contains the right geometry.
+            * *within*
+                The left geometry lies within the right geometry.
+        suffix
+            Suffix to append to columns with a duplicate name.
+
+        See Also
+        --------
+        join_where
+        Expr.st.intersects
+        Expr.st.contains
+
+        Examples
+        --------
+        >>> zones = pl.LazyFrame(
+        ...     {
+        ...         "zone": ["a", "b"],
+        ...         "geom": [
+        ...             "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+        ...             "POLYGON ((2 0, 4 0, 4 2, 2 2, 2 0))",
+        ...         ],
+        ...     }
+        ... ).with_columns(pl.col("geom").cast(pl.Geometry))
+        >>> points = pl.LazyFrame(
+        ...     {"id": [1, 2, 3], "geom": ["POINT (1 1)", "POINT (2 1)", "POINT (5 5)"]}
+        ... ).with_columns(pl.col("geom").cast(pl.Geometry))
+        >>> zones.join_spatial(points, "geom").select("zone", "id").sort(
+        ...     "zone", "id"
+        ... ).collect()
+        shape: (3, 2)
+        ┌──────┬─────┐
+        │ zone ┆ id  │
+        │ ---  ┆ --- │
+        │ str  ┆ i64 │
+        ╞══════╪═════╡
+        │ a    ┆ 1   │
+        │ a    ┆ 2   │
+        │ b    ┆ 2   │
+        └──────┴─────┘
+
+        Points on the boundary of a zone are not contained by it.
+
+        >>> zones.join_spatial(points, "geom", predicate="contains").select(
+        ...     "zone", "id"
+        ... ).collect()
+        shape: (1, 2)
+        ┌──────┬─────┐
+        │ zone ┆ id  │
+        │ ---  ┆ --- │
+        │ str  ┆ i64 │
+        ╞══════╪═════╡
+        │ a    ┆ 1   │
+        └──────┴─────┘
+        """
+        require_same_type(self, other)
+        if predicate not in ("intersects", "contains", "within"):
+            msg = (
+                "`predicate` must be one of {'intersects', 'contains', 'within'}, "
+                f"got {predicate!r}"
+            )
+            raise ValueError(msg)
+        if right_on is None:
+            right_on = left_on
+
+        # Add the bounding boxes as temporary columns to join on.
+        fields = ("xmin", "ymin", "xmax", "ymax")
+        lb = {f: f"__POLARS_SPATIAL_LEFT_{f.upper()}" for f in fields}
+        rb = {f: f"__POLARS_SPATIAL_RIGHT_{f.upper()}" for f in fields}
+        left = self.with_columns(
+            F.col(left_on).st.bounds().struct.field(f).alias(lb[f]) for f in fields
+        )
+        right = other.with_columns(
+            F.col(right_on).st.bounds().struct.field(f).alias(rb[f]) for f in fields
+        )
+
+        def bbox_within(inner: dict[str, str], outer: dict[str, str]) -> list[Expr]:
+            return [
+                F.col(inner["xmin"]) >= F.col(outer["xmin"]),
+                F.col(inner["ymin"]) >= F.col(outer["ymin"]),
+                F.col(inner["xmax"]) <= F.col(outer["xmax"]),
+                F.col(inner["ymax"]) <= F.col(outer["ymax"]),
+            ]
+
+        if predicate == "contains":
+            bbox_predicates = bbox_within(rb, lb)
+        elif predicate == "within":
+            bbox_predicates = bbox_within(lb, rb)
+        else:
+            bbox_predicates = [
+                F.col(lb["xmin"]) <= F.col(rb["xmax"]),
+                F.col(lb["xmax"]) >= F.col(rb["xmin"]),
+                F.col(lb["ymin"]) <= F.col(rb["ymax"]),
+                F.col(lb["ymax"]) >= F.col(rb["ymin"]),
+            ]
+
+        # Columns of the right table that also exist in the left table get a suffix.
+        right_geom = (
+            f"{right_on}{suffix}"
+            if right_on in self.collect_schema().names()
+            else right_on
+        )
+        left_geom, right_geom_expr = F.col(left_on), F.col(right_geom)
+        if predicate == "contains":
+            exact = left_geom.st.contains(right_geom_expr)
+        elif predicate == "within":
+            exact = right_geom_expr.st.contains(left_geom)
+        else:
+            exact = left_geom.st.intersects(right_geom_expr)
+
+        return (
+            left.join_where(right, *bbox_predicates, suffix=suffix)
+            .filter(exact)
+            .drop(*lb.values(), *rb.values())
+        )
+
     def with_columns(
         self,
         *exprs: IntoExpr | Iterable[IntoExpr],
@@ -8217,6 +8492,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         -----
         In some other frameworks, you might know this operation as `pivot_wider`.
 
+        The streaming engine runs a pivot as a streaming group-by on `index`,
+        with one aggregation over the rows of every value in `on_columns`.
+
         Examples
         --------
         You can use `pivot` to reshape a dataframe from "long" to "wide" format.
@@ -44,6 +44,8 @@ class QueryOptFlags:
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
         sort_collapse: None | bool = None,
+        join_reorder: None | bool = None,
+        join_runtime_filters: None | bool = None,
     ) -> None:
         self._pyoptflags = PyOptFlags.default()
         self.update(
@@ -58,6 +60,8 @@ class QueryOptFlags:
             check_order_observe=check_order_observe,
             fast_projection=fast_projection,
             sort_collapse=sort_collapse,
+            join_reorder=join_reorder,
+            join_runtime_filters=join_runtime_filters,
         )
 
     @classmethod
@@ -80,6 +84,8 @@ class QueryOptFlags:
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
         sort_collapse: None | bool = None,
+        join_reorder: None | bool = None,
+        join_runtime_filters: None | bool = None,
     ) -> QueryOptFlags:
         """Create new empty set off optimizations."""
         optflags = QueryOptFlags()
@@ -96,6 +102,8 @@ class QueryOptFlags:
             check_order_observe=check_order_observe,
             fast_projection=fast_projection,
             sort_collapse=sort_collapse,
+            join_reorder=join_reorder,
+            join_runtime_filters=join_runtime_filters,
         )
 
     def update(
@@ -112,6 +120,8 @@ class QueryOptFlags:
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
         sort_collapse: None | bool = None,
+        join_reorder: None | bool = None,
+        join_runtime_filters: None | bool = None,
     ) -> QueryOptFlags:
         """Update the current optimization flags."""
         if predicate_pushdown is not None:
@@ -142,6 +152,10 @@ class QueryOptFlags:
             self.fast_projection = fast_projection
         if sort_collapse is not None:
             self.sort_collapse = sort_collapse
+        if join_reorder is not None:
+            self.join_reorder = join_reorder
+        if join_runtime_filters is not None:
+            self.join_runtime_filters = join_runtime_filters
 
         return self
 
@@ -254,6 +268,28 @@ class QueryOptFlags:
     def sort_collapse(self, value: bool) -> None:
         self._pyoptflags.sort_collapse = value
 
+    @property
+    def join_reorder(self) -> bool:
+        """
+        Reorder inner joins based on the estimated size of their inputs.
+
+        This is turned off by default.
+        """
+        return self._pyoptflags.join_reorder
+
+    @join_reorder.setter
+    def join_reorder(self, value: bool) -> None:
+        self._pyoptflags.join_reorder = value
+
+    @property
+    def join_runtime_filters(self) -> bool:
+        """Filter the probe side of streaming joins on the keys of the build side."""
+        return self._pyoptflags.join_runtime_filters
+
+    @join_runtime_filters.setter
+    def join_runtime_filters(self, value: bool) -> None:
+        self._pyoptflags.join_runtime_filters = value
+
     def __str__(self) -> str:
         return f"""
 QueryOptFlags {{
@@ -270,6 +306,8 @@ QueryOptFlags {{
     check_order_observe: {self.check_order_observe}
     fast_projection: {self.fast_projection}
     sort_collapse: {self.sort_collapse}
+    join_reorder: {self.join_reorder}
+    join_runtime_filters: {self.join_runtime_filters}
 
     eager: {self._pyoptflags.eager}
     streaming: {self._pyoptflags.streaming}
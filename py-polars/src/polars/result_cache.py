@@ -0,0 +1,98 @@
+from __future__ import annotations
+
+import contextlib
+from typing import TypedDict
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars._plr as plr
+
+
+__all__ = [
+    "clear_result_cache",
+    "disable_result_cache",
+    "enable_result_cache",
+    "result_cache_info",
+    "using_result_cache",
+]
+
+
+class ResultCacheInfo(TypedDict):
+    """Statistics of the result cache."""
+
+    hits: int
+    misses: int
+    entries: int
+    bytes: int
+    max_bytes: int
+
+
+def enable_result_cache(max_bytes: int = 1 << 30) -> None:
+    """
+    Enable the global result cache.
+
+    While the result cache is enabled, the results of parts of a query that only
+    read from files are kept in memory and reused by later queries containing
+    the same part, for example when re-running a notebook cell. A cached result
+    is only reused if the files it was computed from have not been modified
+    since, as judged by their modification time and size, or ETag for cloud
+    storage.
+
+    Parts of a query that read from in-memory data, call Python functions or
+    sample randomly are never cached.
+
+    Parameters
+    ----------
+    max_bytes
+        Maximum estimated size of the cached results. When exceeded, the least
+        recently used results are evicted. If the cache is already enabled,
+        only its budget is updated.
+
+    See Also
+    --------
+    disable_result_cache : Function to disable the result cache.
+    clear_result_cache : Function to drop all cached results.
+
+    Examples
+    --------
+    >>> pl.enable_result_cache(max_bytes=512 * 1024 * 1024)
+    >>> pl.using_result_cache()
+    True
+    >>> pl.disable_result_cache()
+    """
+    plr.enable_result_cache(max_bytes)
+
+
+def disable_result_cache() -> None:
+    """
+    Disable and clear the global result cache.
+
+    See Also
+    --------
+    enable_result_cache : Function to enable the result cache.
+    """
+    plr.disable_result_cache()
+
+
+def clear_result_cache() -> None:
+    """
+    Drop all results from the global result cache and reset its statistics.
+
+    The result cache stays enabled.
+    """
+    plr.clear_result_cache()
+
+
+def using_result_cache() -> bool:
+    """Check whether the global result cache is enabled."""
+    return plr.using_result_cache()
+
+
+def result_cache_info() -> ResultCacheInfo | None:
+    """
+    Return statistics of the global result cache.
+
+    Returns `None` if the result cache is disabled. Otherwise returns a dictionary
+    with the number of cache `hits` and `misses`, the number of cached `entries`,
+    their estimated size in `bytes` and the `max_bytes` budget.
+    """
+    return plr.result_cache_info()  # type: ignore[return-value]
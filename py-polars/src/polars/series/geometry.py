@@ -0,0 +1,199 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from polars import Series
+    from polars._plr import PySeries
+    from polars._typing import IntoExpr
+
+
+@expr_dispatch
+class GeometryNameSpace:
+    """
+    Namespace for geometry related methods.
+
+    All measures and predicates are planar: they are computed on the raw coordinates,
+    without taking the coordinate reference system into account.
+    """
+
+    _accessor = "st"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    @unstable()
+    def bounds(self) -> Series:
+        """
+        Get the bounding box of every geometry.
+
+        The bounding box is returned as a struct with the fields `xmin`, `ymin`,
+        `xmax` and `ymax`. Empty geometries have a null bounding box.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "geom", ["POLYGON ((0 0, 4 0, 4 3, 0 0))", "LINESTRING (0 0, 3 4)"]
+        ... ).cast(pl.Geometry)
+        >>> s.st.bounds()
+        shape: (2,)
+        Series: 'geom' [struct[4]]
+        [
+        	{0.0,0.0,4.0,3.0}
+        	{0.0,0.0,3.0,4.0}
+        ]
+        """
+
+    @unstable()
+    def area(self) -> Series:
+        """
+        Get the area of every geometry.
+
+        The area of points and lines is zero.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "geom", ["POLYGON ((0 0, 4 0, 4 3, 0 0))", "LINESTRING (0 0, 3 4)"]
+        ... ).cast(pl.Geometry)
+        >>> s.st.area()
+        shape: (2,)
+        Series: 'geom' [f64]
+        [
+        	6.0
+        	0.0
+        ]
+        """
+
+    @unstable()
+    def length(self) -> Series:
+        """
+        Get the length of every linear geometry.
+
+        The length of points and polygons is zero.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "geom", ["POLYGON ((0 0, 4 0, 4 3, 0 0))", "LINESTRING (0 0, 3 4)"]
+        ... ).cast(pl.Geometry)
+        >>> s.st.length()
+        shape: (2,)
+        Series: 'geom' [f64]
+        [
+        	0.0
+        	5.0
+        ]
+        """
+
+    @unstable()
+    def distance(self, other: IntoExpr) -> Series:
+        """
+        Get the minimum distance between every geometry and the geometry of `other`.
+
+        The distance is zero if the geometries intersect, and null if either of them
+        is empty.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            A Series or expression producing geometries.
+
+        Examples
+        --------
+        >>> a = pl.Series("a", ["POINT (0 0)", "POINT (1 1)"]).cast(pl.Geometry)
+        >>> b = pl.Series("b", ["POINT (3 4)", "LINESTRING (0 0, 2 2)"]).cast(
+        ...     pl.Geometry
+        ... )
+        >>> a.st.distance(b)
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+        	5.0
+        	0.0
+        ]
+        """
+
+    @unstable()
+    def contains(self, other: IntoExpr) -> Series:
+        """
+        Check whether every geometry contains the geometry of `other`.
+
+        A geometry contains another if no point of the other geometry lies outside
+        of it, and their interiors have at least one point in common. A polygon
+        therefore does not contain points on its boundary.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            A Series or expression producing geometries.
+
+        Examples
+        --------
+        >>> a = pl.Series("a", ["POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0))"] * 2).cast(
+        ...     pl.Geometry
+        ... )
+        >>> b = pl.Series("b", ["POINT (1 1)", "POINT (4 2)"]).cast(pl.Geometry)
+        >>> a.st.contains(b)
+        shape: (2,)
+        Series: 'a' [bool]
+        [
+        	true
+        	false
+        ]
+        """
+
+    @unstable()
+    def intersects(self, other: IntoExpr) -> Series:
+        """
+        Check whether every geometry intersects the geometry of `other`.
+
+        Two geometries intersect if they have at least one point in common,
+        including points on their boundaries.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            A Series or expression producing geometries.
+
+        Examples
+        --------
+        >>> a = pl.Series("a", ["POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0))"] * 2).cast(
+        ...     pl.Geometry
+        ... )
+        >>> b = pl.Series("b", ["POINT (1 1)", "POINT (4 2)"]).cast(pl.Geometry)
+        >>> a.st.intersects(b)
+        shape: (2,)
+        Series: 'a' [bool]
+        [
+        	true
+        	true
+        ]
+        """
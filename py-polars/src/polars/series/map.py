@@ -0,0 +1,126 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from polars import Series
+    from polars._plr import PySeries
+    from polars._typing import IntoExpr
+
+
+@expr_dispatch
+class MapNameSpace:
+    """Namespace for map related methods."""
+
+    _accessor = "map"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    @unstable()
+    def get(self, key: IntoExpr) -> Series:
+        """
+        Get the value belonging to `key`.
+
+        Returns null if the map does not contain `key`. If a key occurs more than
+        once, the value of its first occurrence is returned.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        key
+            The key to look up. Strings are parsed as literals.
+
+        Examples
+        --------
+        >>> values = pl.Series([[1, 2], [3]])
+        >>> s = pl.Series("k", [["a", "b"], ["c"]]).map.from_lists(values)
+        >>> s.map.get("b")
+        shape: (2,)
+        Series: 'k' [i64]
+        [
+            2
+            null
+        ]
+        """
+
+    @unstable()
+    def contains_key(self, key: IntoExpr) -> Series:
+        """
+        Check whether the map contains `key`.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        key
+            The key to look up. Strings are parsed as literals.
+
+        Examples
+        --------
+        >>> values = pl.Series([[1, 2], [3]])
+        >>> s = pl.Series("k", [["a", "b"], ["c"]]).map.from_lists(values)
+        >>> s.map.contains_key("c")
+        shape: (2,)
+        Series: 'k' [bool]
+        [
+            false
+            true
+        ]
+        """
+
+    @unstable()
+    def keys(self) -> Series:
+        """
+        Get the keys of the map as a list.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+        """
+
+    @unstable()
+    def values(self) -> Series:
+        """
+        Get the values of the map as a list.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+        """
+
+    @unstable()
+    def from_lists(self, values: IntoExpr) -> Series:
+        """
+        Create a map from a list of keys and a list of `values`.
+
+        The list of keys is taken from the Series this namespace is called on.
+        The lists of keys and values must have the same length in every row.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        values
+            The lists of values.
+        """
+
+    @unstable()
+    def explode(self) -> Series:
+        """
+        Explode the map into one `{key, value}` struct per entry.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+        """
@@ -104,11 +104,15 @@ from polars.series.binary import BinaryNameSpace
 from polars.series.categorical import CatNameSpace
 from polars.series.datetime import DateTimeNameSpace
 from polars.series.ext import ExtensionNameSpace
+from polars.series.geometry import GeometryNameSpace
 from polars.series.list import ListNameSpace
+from polars.series.map import MapNameSpace
 from polars.series.plotting import SeriesPlot
 from polars.series.string import StringNameSpace
 from polars.series.struct import StructNameSpace
 from polars.series.utils import expr_dispatch, get_ffi_func
+from polars.series.uuid import UuidNameSpace
+from polars.series.variant import VariantNameSpace
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
     from polars._plr import PyDataFrame, PySeries
@@ -273,9 +277,13 @@ class Series:
         "dt",
         "ext",
         "list",
+        "map",
         "plot",
+        "st",
         "str",
         "struct",
+        "uuid",
+        "variant",
     }
 
     def __init__(
@@ -424,6 +432,26 @@ class Series:
         """Create an object namespace of all extension type related methods."""
         return ExtensionNameSpace(self)
 
+    @property
+    def map(self) -> MapNameSpace:
+        """Create an object namespace of all map related methods."""
+        return MapNameSpace(self)
+
+    @property
+    def st(self) -> GeometryNameSpace:
+        """Create an object namespace of all geometry related methods."""
+        return GeometryNameSpace(self)
+
+    @property
+    def uuid(self) -> UuidNameSpace:
+        """Create an object namespace of all UUID related methods."""
+        return UuidNameSpace(self)
+
+    @property
+    def variant(self) -> VariantNameSpace:
+        """Create an object namespace of all variant related methods."""
+        return VariantNameSpace(self)
+
     @property
     @unstable()
     def plot(self) -> SeriesPlot:
@@ -0,0 +1,54 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from polars import Series
+    from polars._plr import PySeries
+
+
+@expr_dispatch
+class UuidNameSpace:
+    """Namespace for UUID related methods."""
+
+    _accessor = "uuid"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    @unstable()
+    def timestamp(self) -> Series:
+        """
+        Get the creation time of version 7 UUIDs.
+
+        The timestamp is returned as a UTC datetime with millisecond precision. UUIDs
+        of any other version return null.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        See Also
+        --------
+        polars.uuid7 : Generate time-ordered UUIDs.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "id",
+        ...     [
+        ...         "01890a5d-ac96-774b-bcce-b302099a8057",
+        ...         "f81d4fae-7dec-11d0-a765-00a0c91e6bf6",
+        ...     ],
+        ... )
+        >>> s.cast(pl.UUID).uuid.timestamp()
+        shape: (2,)
+        Series: 'id' [datetime[ms, UTC]]
+        [
+        	2023-06-30 03:34:18.518 UTC
+        	null
+        ]
+        """
@@ -0,0 +1,204 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    import polars._reexport as pl
+    from polars import Series
+    from polars._plr import PySeries
+    from polars._typing import PolarsDataType
+
+
+@expr_dispatch
+class VariantNameSpace:
+    """Namespace for variant related methods."""
+
+    _accessor = "variant"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    @unstable()
+    def get(
+        self,
+        path: str,
+        dtype: PolarsDataType | pl.DataTypeExpr | None = None,
+        *,
+        strict: bool = True,
+    ) -> Series:
+        """
+        Get the value at `path`.
+
+        Returns null if the path does not exist.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        path
+            A JSONPath-like path such as `$.a.b[0]`. Fields are selected with
+            `.name` or `['name']` and array elements with `[i]`, where negative
+            indices count from the end. The leading `$` is optional.
+        dtype
+            The dtype to cast the value to. If not given, the value is returned
+            as a variant.
+        strict
+            Raise an error if a value cannot be cast to `dtype`, instead of
+            setting it to null.
+
+        Examples
+        --------
+        >>> s = pl.Series(['{"a": {"b": [1, 2]}}', '{"a": 3}'])
+        >>> s.str.json_decode(pl.Variant).variant.get("$.a.b[1]", pl.Int64)
+        shape: (2,)
+        Series: '' [i64]
+        [
+            2
+            null
+        ]
+        """
+
+    @unstable()
+    def cast(
+        self,
+        dtype: PolarsDataType | pl.DataTypeExpr,
+        *,
+        strict: bool = True,
+    ) -> Series:
+        """
+        Cast the variant values to `dtype`.
+
+        Casting to `String` returns strings as-is and all other values as JSON.
+        Nested dtypes such as `List` and `Struct` are decoded from the JSON
+        representation of the values.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        dtype
+            The dtype to cast to.
+        strict
+            Raise an error if a value cannot be cast to `dtype`, instead of
+            setting it to null.
+
+        Examples
+        --------
+        >>> s = pl.Series(["1", "2.5", '"x"']).str.json_decode(pl.Variant)
+        >>> s.variant.cast(pl.Float64, strict=False)
+        shape: (3,)
+        Series: '' [f64]
+        [
+            1.0
+            2.5
+            null
+        ]
+        """
+
+    @unstable()
+    def to_json(self) -> Series:
+        """
+        Serialize the variant values to JSON strings.
+
+        Values without a JSON counterpart are written as strings: temporal values
+        in ISO 8601 format, UUIDs in their hyphenated form and binary values
+        base64-encoded.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> s = pl.Series(['{"b": 1, "a": [true, null]}'])
+        >>> s.str.json_decode(pl.Variant).variant.to_json()
+        shape: (1,)
+        Series: '' [str]
+        [
+            "{"a":[true,null],"b":1}"
+        ]
+        """
+
+    @unstable()
+    def type_name(self) -> Series:
+        """
+        Get the name of the type of the variant values.
+
+        One of `"null"`, `"boolean"`, `"integer"`, `"float"`, `"decimal"`,
+        `"date"`, `"datetime"`, `"time"`, `"binary"`, `"string"`, `"uuid"`,
+        `"object"` or `"array"`.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        Examples
+        --------
+        >>> s = pl.Series(["1", '"x"', "[1]", "null"]).str.json_decode(pl.Variant)
+        >>> s.variant.type_name()
+        shape: (4,)
+        Series: '' [str]
+        [
+            "integer"
+            "string"
+            "array"
+            "null"
+        ]
+        """
+
+    @unstable()
+    def is_object(self) -> Series:
+        """
+        Check whether the variant values are objects.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+        """
+
+    @unstable()
+    def is_array(self) -> Series:
+        """
+        Check whether the variant values are arrays.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+        """
+
+    @unstable()
+    def is_string(self) -> Series:
+        """
+        Check whether the variant values are strings.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+        """
+
+    @unstable()
+    def is_boolean(self) -> Series:
+        """
+        Check whether the variant values are booleans.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+        """
+
+    @unstable()
+    def is_numeric(self) -> Series:
+        """
+        Check whether the variant values are integers, floats or decimals.
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+        """
@@ -4,6 +4,7 @@ import io
 import pickle
 import subprocess
 import sys
+from typing import TYPE_CHECKING
 
 import pytest
 
@@ -11,6 +12,9 @@ import polars as pl
 from polars.exceptions import ComputeError, SchemaError
 from polars.testing import assert_frame_equal, assert_series_equal
 
+if TYPE_CHECKING:
+    from pathlib import Path
+
 CATS = [
     pl.Categories(),
     pl.Categories("foo"),
@@ -157,3 +161,134 @@ def test_categories_pickle_24690() -> None:
     assert categories.name() == "name"
     assert categories.namespace() == "namespace"
     assert categories.physical() == pl.UInt8
+
+
+def _run_polars_script(script: str) -> bytes:
+    return subprocess.check_output([sys.executable, "-c", script])
+
+
+def test_categories_serialize_across_processes(tmp_path: Path) -> None:
+    cats = pl.Categories.random()
+    s = pl.Series(["b", "a", "b", "c"], dtype=pl.Categorical(cats))
+    path = tmp_path / "categories.txt"
+    cats.serialize(path)
+    (tmp_path / "data.csv").write_text("x\nc\nd\na\n")
+
+    # The codes of the loaded categories match the codes in this process, also
+    # for data which is scanned and cast to the loaded categories.
+    out = _run_polars_script(
+        f"""\
+import polars as pl
+
+cats = pl.Categories.deserialize({str(path)!r})
+assert cats.is_persistent()
+s = pl.Series(["c", "a", "d"], dtype=pl.Categorical(cats))
+assert s.to_physical().to_list() == [2, 1, 3]
+df = pl.scan_csv(
+    {str(tmp_path / "data.csv")!r},
+    schema_overrides={{"x": pl.Categorical(cats)}},
+).collect()
+assert df["x"].to_physical().to_list() == [2, 3, 1]
+
+print("OK", end="")
+"""
+    )
+    assert out == b"OK"
+    assert s.to_physical().to_list() == [0, 1, 0, 2]
+
+
+def test_categories_serialize_roundtrip() -> None:
+    cats = pl.Categories.random(namespace="ns", physical=pl.UInt16)
+    s = pl.Series(["x", "y", None, "z"], dtype=pl.Categorical(cats))
+    serialized = cats.serialize()
+    assert isinstance(serialized, str)
+
+    f = io.BytesIO()
+    cats.serialize(f)
+    f.seek(0)
+    loaded = pl.Categories.deserialize(f)
+    assert loaded == cats
+    assert loaded.physical() == pl.UInt16
+    assert loaded.is_persistent()
+
+    del s
+    assert loaded["z"] == 2
+
+
+def test_categories_deserialize_conflict() -> None:
+    cats = pl.Categories.random()
+    serialized = cats.serialize()
+    keepalive = pl.Series(["b", "a"], dtype=pl.Categorical(cats))
+
+    conflicting = serialized + "1;a1;b"
+    with pytest.raises(ComputeError, match="already has id"):
+        pl.Categories.deserialize(io.StringIO(conflicting))
+    assert keepalive.to_physical().to_list() == [0, 1]
+
+    # A conflict after a new category does not insert that category.
+    conflicting = serialized + "1;b1;c1;a"
+    with pytest.raises(ComputeError, match="already has id"):
+        pl.Categories.deserialize(io.StringIO(conflicting))
+    assert cats["c"] is None
+
+    with pytest.raises(ComputeError, match="invalid serialized categories"):
+        pl.Categories.deserialize(io.StringIO("not categories"))
+
+
+def test_enum_serialize_roundtrip(tmp_path: Path) -> None:
+    dtype = pl.Enum(["low", "", "high"])
+    serialized = dtype.serialize()
+    assert isinstance(serialized, str)
+    assert pl.Enum.deserialize(io.StringIO(serialized)) == dtype
+
+    path = tmp_path / "enum.txt"
+    dtype.serialize(path)
+    loaded = pl.Enum.deserialize(path)
+    assert loaded == dtype
+    s = pl.Series(["high", "low"], dtype=loaded)
+    assert s.to_physical().to_list() == [2, 0]
+
+    assert pl.Enum([]).serialize() == "frozen;"
+    assert pl.Enum.deserialize(io.StringIO("frozen;")) == pl.Enum([])
+
+    with pytest.raises(ComputeError, match="invalid serialized frozen categories"):
+        pl.Enum.deserialize(io.StringIO(serialized.removeprefix("frozen;")))
+
+
+def test_categories_persist() -> None:
+    cats = pl.Categories.random()
+    assert not cats.is_persistent()
+    s = pl.Series(["a", "b"], dtype=pl.Categorical(cats))
+    assert cats.persist() is cats
+    del s
+    assert cats.is_persistent()
+    assert cats["b"] == 1
+
+
+@pytest.mark.parametrize("file_format", ["ipc", "parquet"])
+def test_persistent_categories_file_metadata(file_format: str, tmp_path: Path) -> None:
+    cats = pl.Categories.random(physical=pl.UInt8)
+    path = tmp_path / f"data.{file_format}"
+
+    # Write from another process, so the mapping only exists in the file metadata.
+    out = _run_polars_script(
+        f"""\
+import polars as pl
+
+cats = pl.Categories({cats.name()!r}, physical=pl.UInt8).persist()
+pl.Series(["unused", "b"], dtype=pl.Categorical(cats))
+df = pl.DataFrame({{"x": ["b", "c", "a"]}}, schema={{"x": pl.Categorical(cats)}})
+df.write_{file_format}({str(path)!r})
+
+print("OK", end="")
+"""
+    )
+    assert out == b"OK"
+
+    df = getattr(pl, f"read_{file_format}")(path)
+    assert df.schema["x"] == pl.Categorical(cats)
+    assert df["x"].to_physical().to_list() == [1, 2, 3]
+    assert cats["unused"] == 0
+
+    # Reading only extends the mapping, persisting it is left to the user.
+    assert not cats.is_persistent()
@@ -0,0 +1,189 @@
+from __future__ import annotations
+
+import io
+from decimal import Decimal as D
+
+import pyarrow as pa
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+BIG = D("1234567890123456789012345678901234567890.12")
+
+
+def test_decimal256_from_to_arrow() -> None:
+    arr = pa.array([BIG, None, -BIG], type=pa.decimal256(50, 2))
+    s = pl.Series("a", arr)
+
+    assert s.dtype == pl.Decimal(50, 2)
+    assert s.to_list() == [BIG, None, -BIG]
+    assert s.to_arrow().equals(arr)
+
+
+def test_decimal256_small_precision_arrow_import() -> None:
+    arr = pa.array([D("1.5"), None], type=pa.decimal256(10, 1))
+    s = pl.Series("a", arr)
+    assert s.dtype == pl.Decimal(10, 1)
+    assert s.to_list() == [D("1.5"), None]
+
+
+def test_decimal256_from_python() -> None:
+    s = pl.Series("a", [BIG, None, D("0.5")], dtype=pl.Decimal(60, 2))
+    assert s.dtype == pl.Decimal(60, 2)
+    assert s.to_list() == [BIG, None, D("0.50")]
+
+    inferred = pl.Series("a", [D("1" * 45), None])
+    assert inferred.dtype == pl.Decimal(76, 0)
+    assert inferred.to_list() == [D("1" * 45), None]
+
+
+def test_decimal256_precision_bounds() -> None:
+    s = pl.Series([1, 2])
+    assert s.cast(pl.Decimal(76, 0)).to_list() == [D(1), D(2)]
+    with pytest.raises(InvalidOperationError):
+        s.cast(pl.Decimal(77, 0))
+
+
+def test_decimal256_arithmetic() -> None:
+    df = pl.DataFrame(
+        {
+            "a": pl.Series([BIG, D("1.00"), None], dtype=pl.Decimal(50, 2)),
+            "b": pl.Series([D("0.5"), D("2.5"), D("1.0")], dtype=pl.Decimal(45, 1)),
+        }
+    )
+    out = df.select(
+        add=pl.col("a") + pl.col("b"),
+        sub=pl.col("a") - pl.col("b"),
+        mul=pl.col("a") * 2,
+        div=pl.col("a") / pl.col("b"),
+    )
+    assert out.schema == pl.Schema(
+        {
+            "add": pl.Decimal(76, 2),
+            "sub": pl.Decimal(76, 2),
+            "mul": pl.Decimal(76, 2),
+            "div": pl.Decimal(76, 2),
+        }
+    )
+    assert out.row(0) == (BIG + D("0.5"), BIG - D("0.5"), BIG * 2, BIG * 2)
+    assert out.row(1) == (D("3.50"), D("-1.50"), D("2.00"), D("0.40"))
+    assert out.row(2) == (None, None, None, None)
+
+
+def test_decimal256_division_by_zero() -> None:
+    s = pl.Series([BIG], dtype=pl.Decimal(50, 2))
+    with pytest.raises(InvalidOperationError, match="division by zero"):
+        s / pl.Series([D(0)], dtype=pl.Decimal(50, 2))
+
+
+def test_decimal256_aggregations() -> None:
+    s = pl.Series([BIG, D("1.00"), None, -BIG], dtype=pl.Decimal(50, 2))
+    assert s.sum() == D("1.00")
+    assert s.min() == -BIG
+    assert s.max() == BIG
+    assert s.mean() == pytest.approx(1 / 3)
+
+    df = pl.DataFrame({"g": [1, 1, 2, 2], "x": s})
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("x").sum().alias("sum"), pl.col("x").max().alias("max")
+    )
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2],
+            "sum": pl.Series([BIG + 1, -BIG], dtype=pl.Decimal(76, 2)),
+            "max": pl.Series([BIG, -BIG], dtype=pl.Decimal(50, 2)),
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_decimal256_aggregations_streaming() -> None:
+    s = pl.Series([BIG, D("1.00"), None, -BIG, None], dtype=pl.Decimal(50, 2))
+    lf = pl.LazyFrame({"g": [1, 1, 2, 2, 3], "x": s})
+    aggs = [
+        pl.col("x").sum().alias("sum"),
+        pl.col("x").mean().alias("mean"),
+        pl.col("x").min().alias("min"),
+        pl.col("x").max().alias("max"),
+    ]
+
+    q = lf.group_by("g").agg(aggs).sort("g")
+    out = q.collect(engine="streaming")
+    assert_frame_equal(out, q.collect(engine="in-memory"))
+    assert out.schema == pl.Schema(
+        {
+            "g": pl.Int64,
+            "sum": pl.Decimal(76, 2),
+            "mean": pl.Float64,
+            "min": pl.Decimal(50, 2),
+            "max": pl.Decimal(50, 2),
+        }
+    )
+    assert out["sum"].to_list() == [BIG + 1, -BIG, D("0.00")]
+    assert out["min"].to_list() == [D("1.00"), -BIG, None]
+
+    q = lf.select(aggs)
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+def test_decimal256_sum_overflow_streaming() -> None:
+    max_value = D("9" * 74 + ".99")
+    lf = pl.LazyFrame({"x": [max_value, max_value]}, schema={"x": pl.Decimal(76, 2)})
+    q = lf.select(pl.col("x").sum())
+    assert q.collect(engine="streaming").item() is None
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+def test_decimal256_comparisons() -> None:
+    a = pl.Series([BIG, D("1.00"), None], dtype=pl.Decimal(50, 2))
+    b = pl.Series([BIG, D("2.0"), D("1.0")], dtype=pl.Decimal(45, 1))
+
+    assert (a == b).to_list() == [True, False, None]
+    assert (a < b).to_list() == [False, True, None]
+    assert (a >= b).to_list() == [True, False, None]
+
+    df = pl.DataFrame({"a": a})
+    assert df.filter(pl.col("a") > 1).to_series().to_list() == [BIG]
+
+
+def test_decimal256_sort_unique() -> None:
+    s = pl.Series([BIG, -BIG, D("0"), BIG, None], dtype=pl.Decimal(50, 2))
+    assert s.sort().to_list() == [None, -BIG, D("0"), BIG, BIG]
+    assert s.unique(maintain_order=True).to_list() == [BIG, -BIG, D("0"), None]
+    assert s.n_unique() == 4
+
+
+def test_decimal256_casts() -> None:
+    s = pl.Series([BIG, D("-1.25"), None], dtype=pl.Decimal(50, 2))
+
+    assert s.cast(pl.String).to_list() == [str(BIG), "-1.25", None]
+    assert s.cast(pl.Float64).to_list() == [float(BIG), -1.25, None]
+    assert s.cast(pl.Int64, strict=False).to_list() == [None, -1, None]
+    with pytest.raises(InvalidOperationError):
+        s.cast(pl.Decimal(38, 2))
+
+    small = s.slice(1)
+    assert_series_equal(small.cast(pl.Decimal(38, 2)).cast(pl.Decimal(50, 2)), small)
+    assert_series_equal(pl.Series([str(BIG), "-1.25", None]).cast(pl.Decimal(50, 2)), s)
+
+
+def test_decimal256_map_elements() -> None:
+    s = pl.Series([BIG, D("-1.25"), None], dtype=pl.Decimal(50, 2))
+
+    out = s.map_elements(str, return_dtype=pl.String)
+    assert out.to_list() == [str(BIG), "-1.25", None]
+
+    out = s.map_elements(lambda x: x.copy_negate(), return_dtype=pl.Decimal(50, 2))
+    assert out.to_list() == [-BIG, D("1.25"), None]
+
+
+@pytest.mark.parametrize("fmt", ["parquet", "ipc"])
+def test_decimal256_io_roundtrip(fmt: str) -> None:
+    df = pl.DataFrame({"a": pl.Series([BIG, None, -BIG], dtype=pl.Decimal(50, 2))})
+
+    f = io.BytesIO()
+    getattr(df, f"write_{fmt}")(f)
+    f.seek(0)
+    assert_frame_equal(getattr(pl, f"read_{fmt}")(f), df)
@@ -2,7 +2,7 @@ from __future__ import annotations
 
 import io
 import pickle
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Any
 
 import pytest
 
@@ -39,6 +39,81 @@ pl.register_extension_type("testing.python_test_extension", PythonTestExtension)
 pl.register_extension_type("testing.test_storage_extension", as_storage=True)
 
 
+class Money(pl.datatypes.BaseExtension):
+    """A test extension type storing non-negative amounts in cents."""
+
+    def __init__(self, currency: str = "USD") -> None:
+        super().__init__(name="testing.money", storage=pl.Int64, metadata=currency)
+
+    def ext_format_value(self, value: Any) -> str:
+        return f"{value / 100:.2f} {self.ext_metadata()}"
+
+    def ext_validate(self, storage: pl.Series) -> None:
+        if (storage < 0).any():
+            msg = "amounts must be non-negative"
+            raise ValueError(msg)
+
+    def ext_cast_from(self, s: pl.Series, *, strict: bool) -> pl.Series | None:
+        if s.dtype.is_float():
+            return (s * 100).round().cast(pl.Int64, strict=strict)
+        return None
+
+    def ext_cast_to(
+        self, storage: pl.Series, dtype: PolarsDataType, *, strict: bool
+    ) -> pl.Series | None:
+        if dtype == pl.Float64:
+            return storage / 100
+        return None
+
+    def ext_aggregate(self, storage: pl.Series, agg: str) -> pl.Series | None:
+        if agg == "sum":
+            return pl.Series([storage.sum()], dtype=pl.Int64)
+        return None
+
+
+pl.register_extension_type("testing.money", Money)
+
+
+class Version(pl.datatypes.BaseExtension):
+    """A test extension type for version strings, ordered by their numeric parts."""
+
+    def __init__(self) -> None:
+        super().__init__(name="testing.version", storage=pl.String)
+
+    def ext_sort_key(self, storage: pl.Series) -> pl.Series:
+        parts = storage.str.split(".").list.eval(pl.element().cast(pl.Int64))
+        return (
+            parts.list.get(0) * 1_000_000
+            + parts.list.get(1) * 1_000
+            + parts.list.get(2)
+        )
+
+
+pl.register_extension_type("testing.version", Version)
+
+
+class Broken(pl.datatypes.BaseExtension):
+    """A test extension type whose hooks raise."""
+
+    def __init__(self) -> None:
+        super().__init__(name="testing.broken", storage=pl.Int64)
+
+    def ext_format_value(self, value: Any) -> str:
+        msg = "broken format"
+        raise ValueError(msg)
+
+    def ext_sort_key(self, storage: pl.Series) -> pl.Series:
+        msg = "broken sort key"
+        raise ValueError(msg)
+
+    def ext_aggregate(self, storage: pl.Series, agg: str) -> pl.Series | None:
+        msg = "broken aggregate"
+        raise ValueError(msg)
+
+
+pl.register_extension_type("testing.broken", Broken)
+
+
 def test_extension_df_constructor() -> None:
     df = pl.DataFrame(
         {"a": [1, 2, 3], "b": ["a", "b", "c"]},
@@ -158,3 +233,86 @@ def test_extension_join_payload(how: JoinStrategy) -> None:
     )
     print(result, expected)
     assert_frame_equal(result, expected, check_row_order=False)
+
+
+def test_extension_format_value() -> None:
+    df = pl.DataFrame({"m": [150, None, 2025]}).with_columns(
+        pl.col("m").ext.to(Money("EUR"))
+    )
+    assert "1.50 EUR" in str(df)
+    assert "20.25 EUR" in str(df)
+    assert "1.50 EUR" in str(df["m"])
+    assert "150" not in str(df)
+
+
+def test_extension_validate() -> None:
+    with pytest.raises(ValueError, match="non-negative"):
+        pl.Series([1, -1]).cast(Money())
+    with pytest.raises(ValueError, match="non-negative"):
+        pl.select(pl.lit(pl.Series([-1])).ext.to(Money()))
+
+
+def test_extension_cast() -> None:
+    s = pl.Series("m", [1.5, 2.25]).cast(Money())
+    assert s.dtype == Money()
+    assert s.ext.storage().to_list() == [150, 225]
+
+    assert s.cast(pl.Float64).to_list() == [1.5, 2.25]
+    assert s.cast(pl.Int64).to_list() == [150, 225]
+    with pytest.raises(pl.exceptions.ComputeError, match="cannot cast extension"):
+        s.cast(pl.String)
+
+
+def test_extension_sort_key() -> None:
+    s = pl.Series("v", ["1.10.0", "1.2.0", None, "1.9.1"]).cast(Version())
+    assert s.sort().ext.storage().to_list() == [None, "1.2.0", "1.9.1", "1.10.0"]
+    assert s.arg_sort(descending=True).to_list() == [2, 0, 3, 1]
+
+    other = pl.Series("v", ["1.9.0", "1.3.0", "1.0.0", "2.0.0"]).cast(Version())
+    assert (s > other).to_list() == [True, False, None, False]
+
+    # Equality still uses the storage values.
+    assert (s == s).to_list() == [True, True, None, True]
+
+
+def test_extension_aggregate() -> None:
+    df = pl.DataFrame({"k": [1, 1, 2], "m": [1.5, 2.25, 0.5]}).with_columns(
+        pl.col("m").cast(Money())
+    )
+    out = df.select(pl.col("m").sum())
+    assert out.schema == {"m": Money()}
+    assert out.select(pl.col("m").ext.storage()).item() == 375
+
+    out = df.group_by("k", maintain_order=True).agg(pl.col("m").sum())
+    assert out.schema == {"k": pl.Int64, "m": Money()}
+    assert out["m"].ext.storage().to_list() == [375, 50]
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="does not support"):
+        df.select(pl.col("m").min())
+
+
+def test_extension_hooks_raise() -> None:
+    s = pl.Series("b", [3, 1, 2]).ext.to(Broken())
+
+    with pytest.warns(UserWarning, match="broken format"):
+        out = str(s)
+    assert "3" in out
+
+    with pytest.raises(ValueError, match="broken sort key"):
+        s.sort()
+    with pytest.raises(ValueError, match="broken sort key"):
+        s > s  # noqa: B015
+    with pytest.raises(ValueError, match="broken sort key"):
+        s.arg_sort()
+    with pytest.raises(ValueError, match="broken sort key"):
+        pl.DataFrame({"b": s, "i": [1, 2, 3]}).sort("b")
+
+    df = pl.DataFrame({"k": [1, 1, 2], "b": s})
+    with pytest.raises(ValueError, match="broken aggregate"):
+        df.select(pl.col("b").sum())
+    with pytest.raises(ValueError, match="broken aggregate"):
+        df.group_by("k").agg(pl.col("b").sum())
+    with pytest.raises(ValueError, match="broken aggregate"):
+        df.group_by("k").agg(pl.col("b").min())
+    with pytest.raises(ValueError, match="broken sort key"):
+        df.group_by("k").agg(pl.col("b").sort())
@@ -0,0 +1,356 @@
+from __future__ import annotations
+
+import io
+import json
+import struct
+from typing import TYPE_CHECKING
+
+import pyarrow as pa
+import pyarrow.parquet as pq
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+    from polars._typing import SpatialPredicate
+
+SQUARE = "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0))"
+
+
+def geoms(*values: str | None, crs: str | None = None) -> pl.Series:
+    return pl.Series("geom", values, pl.String).cast(pl.Geometry(crs))
+
+
+def test_geometry_dtype() -> None:
+    dtype = pl.Geometry()
+    assert dtype == pl.Geometry
+    assert repr(dtype) == "Geometry"
+    assert dtype.crs is None
+    assert dtype.ext_name() == "geoarrow.wkb"
+    assert dtype.ext_storage() == pl.Binary
+
+    dtype = pl.Geometry("EPSG:4326")
+    assert dtype == pl.Geometry
+    assert dtype != pl.Geometry()
+    assert repr(dtype) == "Geometry(crs='EPSG:4326')"
+    assert dtype.crs == "EPSG:4326"
+    metadata = dtype.ext_metadata()
+    assert metadata is not None
+    assert json.loads(metadata) == {"crs": "EPSG:4326"}
+
+
+def test_geometry_wkt_roundtrip() -> None:
+    wkt = [
+        "POINT (1 2)",
+        "POINT EMPTY",
+        "LINESTRING (0 0, 1.5 -1)",
+        "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 2 1, 2 2, 1 1))",
+        "MULTIPOINT ((1 2), (3 4))",
+        "MULTILINESTRING ((0 0, 1 1), (2 2, 3 3))",
+        "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((5 5, 6 5, 6 6, 5 5)))",
+        "GEOMETRYCOLLECTION (POINT (1 2), LINESTRING (0 0, 1 1))",
+        None,
+    ]
+    s = geoms(*wkt)
+    assert s.dtype == pl.Geometry
+    assert s.null_count() == 1
+    assert_series_equal(s.cast(pl.String), pl.Series("geom", wkt, pl.String))
+
+
+def test_geometry_parse_wkt_variants() -> None:
+    s = pl.Series(
+        [
+            "point(1 2)",
+            "POINT Z (1 2 3)",
+            "POINTZM (1 2 3 4)",
+            "SRID=4326;POINT (1 2)",
+            "  POINT  (  1   2 ) ",
+        ]
+    )
+    assert s.cast(pl.Geometry).cast(pl.String).to_list() == ["POINT (1 2)"] * len(s)
+
+    s = pl.Series(["MULTIPOINT (1 2, 3 4)", "MULTIPOINT ((1 2), EMPTY)"])
+    assert s.cast(pl.Geometry).cast(pl.String).to_list() == [
+        "MULTIPOINT ((1 2), (3 4))",
+        "MULTIPOINT ((1 2))",
+    ]
+
+
+def test_geometry_parse_invalid() -> None:
+    s = pl.Series(["POINT (1 2)", "POINT (1"])
+    with pytest.raises(pl.exceptions.InvalidOperationError, match=r"POINT \(1'"):
+        s.cast(pl.Geometry)
+    assert s.cast(pl.Geometry, strict=False).null_count() == 1
+
+
+def test_geometry_from_wkb() -> None:
+    little_endian = struct.pack("<BIdd", 1, 1, 1.0, 2.0)
+    big_endian = struct.pack(">BIdd", 0, 1, 1.0, 2.0)
+    ewkb_with_srid = struct.pack("<BIIdd", 1, 0x2000_0001, 4326, 1.0, 2.0)
+    iso_z = struct.pack("<BIddd", 1, 1001, 1.0, 2.0, 3.0)
+
+    s = pl.Series(
+        [little_endian, big_endian, ewkb_with_srid, iso_z], dtype=pl.Geometry()
+    )
+    assert s.cast(pl.String).to_list() == ["POINT (1 2)"] * 4
+
+    # Geometries are stored as two-dimensional little-endian WKB.
+    assert geoms("POINT Z (1 2 3)").ext.storage().to_list() == [little_endian]
+
+
+def test_geometry_invalid_wkb() -> None:
+    with pytest.raises(pl.exceptions.ComputeError, match="invalid WKB"):
+        pl.Series([b"\x01\x01\x00\x00\x00"], dtype=pl.Geometry())
+
+
+def test_geometry_display() -> None:
+    s = geoms("POINT (1 2)", None)
+    assert "POINT (1 2)" in str(s)
+    assert "[geometry]" in str(s)
+
+
+def test_geometry_bounds() -> None:
+    s = geoms("POLYGON ((0 0, 4 0, 4 3, 0 0))", "POINT (-1 5)", "POINT EMPTY", None)
+    expected = pl.Series(
+        "geom",
+        [
+            {"xmin": 0.0, "ymin": 0.0, "xmax": 4.0, "ymax": 3.0},
+            {"xmin": -1.0, "ymin": 5.0, "xmax": -1.0, "ymax": 5.0},
+            None,
+            None,
+        ],
+    )
+    assert_series_equal(s.st.bounds(), expected)
+
+
+def test_geometry_area_length() -> None:
+    s = geoms(
+        SQUARE,
+        "POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0), (2 2, 4 2, 4 4, 2 4, 2 2))",
+        "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((5 5, 6 5, 6 6, 5 5)))",
+        "LINESTRING (0 0, 3 4, 3 5)",
+        "MULTILINESTRING ((0 0, 1 0), (0 0, 0 2))",
+        "POINT (1 1)",
+        None,
+    )
+    assert s.st.area().to_list() == [16.0, 96.0, 1.0, 0.0, 0.0, 0.0, None]
+    assert s.st.length().to_list() == [0.0, 0.0, 0.0, 6.0, 3.0, 0.0, None]
+
+
+def test_geometry_distance() -> None:
+    a = geoms("POINT (0 0)", "POINT (0 3)", "POINT (2 2)", SQUARE, "POINT EMPTY", None)
+    b = geoms(
+        "POINT (3 4)",
+        "LINESTRING (-5 0, 5 0)",
+        SQUARE,
+        "POLYGON ((6 0, 8 0, 8 4, 6 4, 6 0))",
+        "POINT (0 0)",
+        "POINT (0 0)",
+    )
+    assert a.st.distance(b).to_list() == [5.0, 3.0, 0.0, 2.0, None, None]
+
+
+@pytest.mark.parametrize(
+    ("other", "contains", "intersects"),
+    [
+        ("POINT (1 1)", True, True),
+        ("POINT (4 2)", False, True),
+        ("POINT (5 5)", False, False),
+        ("LINESTRING (1 1, 3 3)", True, True),
+        ("LINESTRING (0 0, 4 0)", False, True),
+        ("LINESTRING (1 1, 5 5)", False, True),
+        ("LINESTRING (5 0, 5 4)", False, False),
+        ("POLYGON ((1 1, 3 1, 3 3, 1 3, 1 1))", True, True),
+        (SQUARE, True, True),
+        ("POLYGON ((3 3, 6 3, 6 6, 3 6, 3 3))", False, True),
+        ("POLYGON ((4 0, 6 0, 6 4, 4 4, 4 0))", False, True),
+        ("POLYGON ((-1 -1, 5 -1, 5 5, -1 5, -1 -1))", False, True),
+        ("MULTIPOINT ((1 1), (2 2))", True, True),
+        ("MULTIPOINT ((1 1), (5 5))", False, True),
+        ("POINT EMPTY", False, False),
+    ],
+)
+def test_geometry_predicates(other: str, contains: bool, intersects: bool) -> None:
+    a = geoms(SQUARE)
+    b = geoms(other)
+    assert a.st.contains(b).item() is contains
+    assert a.st.intersects(b).item() is intersects
+    assert b.st.intersects(a).item() is intersects
+
+
+def test_geometry_contains_hole() -> None:
+    donut = geoms("POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0), (4 4, 6 4, 6 6, 4 6, 4 4))")
+    others = geoms(
+        "POINT (1 1)",
+        "POINT (5 5)",
+        "POLYGON ((1 1, 3 1, 3 3, 1 3, 1 1))",
+        "POLYGON ((3 3, 7 3, 7 7, 3 7, 3 3))",
+        "LINESTRING (1 5, 9 5)",
+    )
+    assert donut.st.contains(others).to_list() == [True, False, True, False, False]
+    assert donut.st.intersects(others).to_list() == [True, False, True, True, True]
+
+
+def test_geometry_predicates_broadcast_and_nulls() -> None:
+    df = pl.DataFrame({"geom": geoms("POINT (1 1)", "POINT (9 9)", None)})
+    out = df.select(
+        contains=pl.lit(geoms(SQUARE)).st.contains(pl.col("geom")),
+        intersects=pl.col("geom").st.intersects(pl.lit(geoms(SQUARE))),
+    )
+    assert out.to_dict(as_series=False) == {
+        "contains": [True, False, None],
+        "intersects": [True, False, None],
+    }
+
+
+def test_geometry_non_geometry_input() -> None:
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="expected Geometry"):
+        pl.select(pl.lit("POINT (1 2)").st.area())
+
+
+def test_geometry_to_arrow() -> None:
+    df = pl.DataFrame({"geom": geoms("POINT (1 2)", None, crs="EPSG:4326")})
+    field = df.to_arrow().schema.field("geom")
+    # pyarrow loads `geoarrow.wkb` as a registered extension type if geoarrow is
+    # installed.
+    if isinstance(field.type, pa.ExtensionType):
+        assert field.type.extension_name == "geoarrow.wkb"
+    else:
+        assert field.metadata[b"ARROW:extension:name"] == b"geoarrow.wkb"
+        assert json.loads(field.metadata[b"ARROW:extension:metadata"]) == {
+            "crs": "EPSG:4326"
+        }
+        assert field.type in (pa.binary(), pa.large_binary(), pa.binary_view())
+
+    assert_frame_equal(pl.from_arrow(df.to_arrow()), df)  # type: ignore[arg-type]
+
+
+@pytest.mark.parametrize("fmt", ["ipc", "parquet"])
+def test_geometry_io_roundtrip(fmt: str) -> None:
+    df = pl.DataFrame(
+        {
+            "geom": geoms(SQUARE, "POINT (1 2)", None, crs="EPSG:4326"),
+            "other": geoms("LINESTRING (0 0, 1 1)", None, "POINT EMPTY"),
+        }
+    )
+
+    f = io.BytesIO()
+    getattr(df, f"write_{fmt}")(f)
+    f.seek(0)
+    out = getattr(pl, f"read_{fmt}")(f)
+
+    assert out.schema == {
+        "geom": pl.Geometry("EPSG:4326"),
+        "other": pl.Geometry(),
+    }
+    assert out.schema["geom"].crs == "EPSG:4326"  # type: ignore[attr-defined]
+    assert_frame_equal(out, df)
+
+
+def test_geometry_geoparquet_metadata() -> None:
+    df = pl.DataFrame(
+        {
+            "id": [1, 2],
+            "geom": geoms("POINT (1 2)", None, crs="EPSG:4326"),
+            "other": geoms("LINESTRING (0 0, 1 1)", None),
+        }
+    )
+
+    f = io.BytesIO()
+    df.write_parquet(f)
+    f.seek(0)
+    metadata = pq.ParquetFile(f).metadata.metadata
+    assert json.loads(metadata[b"geo"]) == {
+        "version": "1.1.0",
+        "primary_column": "geom",
+        "columns": {
+            "geom": {"encoding": "WKB", "geometry_types": [], "crs": "EPSG:4326"},
+            "other": {"encoding": "WKB", "geometry_types": []},
+        },
+    }
+
+    # Files without geometry columns get no GeoParquet metadata.
+    f = io.BytesIO()
+    df.drop("geom", "other").write_parquet(f)
+    f.seek(0)
+    assert b"geo" not in pq.ParquetFile(f).metadata.metadata
+
+
+def test_geometry_geoparquet_metadata_sink(tmp_path: Path) -> None:
+    path = tmp_path / "geom.parquet"
+    pl.LazyFrame({"geom": geoms("POINT (1 2)")}).sink_parquet(path)
+    geo = json.loads(pq.ParquetFile(path).metadata.metadata[b"geo"])
+    assert geo["primary_column"] == "geom"
+    assert geo["columns"]["geom"]["encoding"] == "WKB"
+
+
+def test_geometry_geoparquet_metadata_user_key() -> None:
+    df = pl.DataFrame({"geom": geoms("POINT (1 2)")})
+
+    f = io.BytesIO()
+    df.write_parquet(f, metadata={"geo": "custom", "other": "x"})
+    f.seek(0)
+    metadata = pq.ParquetFile(f).metadata.metadata
+    assert metadata[b"geo"] == b"custom"
+    assert metadata[b"other"] == b"x"
+
+
+@pytest.mark.parametrize("predicate", ["intersects", "contains", "within"])
+def test_join_spatial(predicate: SpatialPredicate) -> None:
+    zones = pl.DataFrame(
+        {
+            "zone": ["a", "b", "c"],
+            "geom": geoms(
+                "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+                "POLYGON ((2 0, 4 0, 4 2, 2 2, 2 0))",
+                "POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0))",
+            ),
+        }
+    )
+    items = pl.DataFrame(
+        {
+            "id": [1, 2, 3, 4, 5],
+            "geom": geoms(
+                "POINT (1 1)",
+                "POINT (2 1)",
+                "LINESTRING (1 1, 3 1)",
+                "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+                None,
+            ),
+        }
+    )
+
+    out = zones.join_spatial(items, "geom", predicate=predicate)
+    assert out.columns == ["zone", "geom", "id", "geom_right"]
+
+    # Compare with evaluating the predicate on the cross join.
+    cross = zones.join(items, how="cross")
+    if predicate == "within":
+        mask = pl.col("geom_right").st.contains(pl.col("geom"))
+    else:
+        mask = getattr(pl.col("geom").st, predicate)(pl.col("geom_right"))
+    expected = cross.filter(mask)
+    assert_frame_equal(out.sort("zone", "id"), expected.sort("zone", "id"))
+
+    pairs = sorted(out.select("zone", "id").iter_rows())
+    if predicate == "contains":
+        assert pairs == [("a", 1), ("a", 4), ("c", 1), ("c", 2), ("c", 3), ("c", 4)]
+    elif predicate == "within":
+        assert pairs == [("a", 4)]
+
+
+def test_join_spatial_right_on_and_lazy() -> None:
+    left = pl.LazyFrame({"l": geoms(SQUARE)})
+    right = pl.LazyFrame({"r": geoms("POINT (1 1)", "POINT (5 5)"), "id": [1, 2]})
+    out = left.join_spatial(right, "l", "r").collect()
+    assert out.columns == ["l", "r", "id"]
+    assert out["id"].to_list() == [1]
+
+
+def test_join_spatial_invalid_predicate() -> None:
+    df = pl.DataFrame({"geom": geoms(SQUARE)})
+    with pytest.raises(ValueError, match="predicate"):
+        df.join_spatial(df, "geom", predicate="touches")  # type: ignore[arg-type]
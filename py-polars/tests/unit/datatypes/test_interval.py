@@ -0,0 +1,150 @@
+from __future__ import annotations
+
+import io
+from datetime import date, datetime, timedelta
+from zoneinfo import ZoneInfo
+
+import pyarrow as pa
+import pyarrow.parquet as pq
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def intervals(*values: tuple[int, int, int] | None) -> pl.Series:
+    arr = pa.array(values, type=pa.month_day_nano_interval())
+    return pl.Series("interval", arr)
+
+
+def test_interval_from_to_arrow() -> None:
+    arr = pa.array(
+        [(1, 2, 3_000_000_000), None, (-1, 0, -5)],
+        type=pa.month_day_nano_interval(),
+    )
+    s = pl.Series("interval", arr)
+
+    assert s.dtype == pl.Interval
+    assert s.to_list() == [(1, 2, 3_000_000_000), None, (-1, 0, -5)]
+    assert s.to_arrow().equals(arr)
+
+
+def test_interval_to_string() -> None:
+    s = intervals((14, 2, 3_600_000_000_001), (-1, 0, 0), (0, 0, 0), None)
+    assert s.cast(pl.String).to_list() == ["1y2mo2d1h1ns", "-1mo", "0s", None]
+    assert "1y2mo2d1h1ns" in str(s)
+
+
+def test_interval_sort() -> None:
+    s = intervals((0, 1, 0), (1, 0, 0), (0, 0, 1), (-1, 5, 0))
+    assert s.sort().to_list() == [(-1, 5, 0), (0, 0, 1), (0, 1, 0), (1, 0, 0)]
+
+
+def test_interval_date_arithmetic() -> None:
+    df = pl.DataFrame(
+        {
+            "date": [date(2024, 1, 31), date(2023, 1, 31), date(2024, 3, 1)],
+            "interval": intervals((1, 0, 0), (1, 0, 0), (0, -1, 0)),
+        }
+    )
+    out = df.select(
+        plus=pl.col("date") + pl.col("interval"),
+        minus=pl.col("date") - pl.col("interval"),
+    )
+    expected = pl.DataFrame(
+        {
+            "plus": [date(2024, 2, 29), date(2023, 2, 28), date(2024, 2, 29)],
+            "minus": [date(2023, 12, 31), date(2022, 12, 31), date(2024, 3, 2)],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_interval_datetime_time_zone() -> None:
+    tz = "Europe/Amsterdam"
+    df = pl.DataFrame(
+        {
+            "dt": pl.Series([datetime(2024, 3, 30, 12)] * 2).dt.replace_time_zone(tz),
+            # one calendar day vs. 24 hours across the DST transition
+            "interval": intervals((0, 1, 0), (0, 0, 24 * 3_600 * 1_000_000_000)),
+        }
+    )
+    out = df.select(pl.col("interval") + pl.col("dt")).to_series()
+
+    assert out.dtype == pl.Datetime("us", tz)
+    assert out.to_list() == [
+        datetime(2024, 3, 31, 12, tzinfo=ZoneInfo(tz)),
+        datetime(2024, 3, 31, 13, tzinfo=ZoneInfo(tz)),
+    ]
+
+
+def test_interval_arithmetic() -> None:
+    a = intervals((1, 2, 3), (0, 0, 0))
+    b = intervals((1, -2, 3), None)
+    assert (a + b).to_list() == [(2, 0, 6), None]
+    assert (a - b).to_list() == [(0, 4, 0), None]
+    assert (-a).to_list() == [(-1, -2, -3), (0, 0, 0)]
+
+
+def test_interval_duration_cast() -> None:
+    s = pl.Series([timedelta(days=1), None], dtype=pl.Duration("us"))
+    out = s.cast(pl.Interval)
+    assert out.to_list() == [(0, 0, 86_400_000_000_000), None]
+    assert_series_equal(out.cast(pl.Duration("us")), s)
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="fixed length"):
+        intervals((1, 0, 0)).cast(pl.Duration("ns"))
+
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        pl.Series([1]).cast(pl.Interval)
+
+
+def test_interval_ipc_roundtrip() -> None:
+    df = pl.DataFrame({"interval": intervals((1, 2, 3), None, (-4, -5, -6))})
+
+    f = io.BytesIO()
+    df.write_ipc(f)
+    f.seek(0)
+    assert_frame_equal(pl.read_ipc(f), df)
+
+
+def test_interval_parquet() -> None:
+    df = pl.DataFrame(
+        {"interval": intervals((1, 2, 3_000_000), None, (14, 0, 1_000_000), (0, 0, 0))}
+    )
+
+    f = io.BytesIO()
+    df.write_parquet(f, statistics=True)
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+    # The sort order of intervals is undefined, so they have no statistics.
+    f.seek(0)
+    statistics = pq.ParquetFile(f).metadata.row_group(0).column(0).statistics
+    assert statistics is None or not statistics.has_min_max
+
+
+@pytest.mark.parametrize(
+    "value",
+    [
+        (-1, 0, 0),
+        (0, -1, 0),
+        (0, 0, -1_000_000),
+        (0, 0, 1_500_000),
+        (0, 0, 1),
+        (0, 0, (2**32) * 1_000_000),
+    ],
+)
+def test_interval_parquet_unrepresentable(value: tuple[int, int, int]) -> None:
+    df = pl.DataFrame({"interval": intervals((1, 2, 3_000_000), value)})
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="interval"):
+        df.write_parquet(io.BytesIO())
+
+
+def test_interval_sql_literal() -> None:
+    out = pl.select(pl.sql_expr("INTERVAL '1 year 2 months 3 days'")).to_series()
+    assert out.dtype == pl.Interval
+    assert out.to_list() == [(14, 3, 0)]
+
+    out = pl.select(pl.sql_expr("INTERVAL '3 days'")).to_series()
+    assert out.dtype == pl.Duration("ns")
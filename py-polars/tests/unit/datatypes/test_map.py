@@ -0,0 +1,115 @@
+from __future__ import annotations
+
+import io
+from typing import TYPE_CHECKING
+
+import pyarrow as pa
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+if TYPE_CHECKING:
+    from tests.conftest import PlMonkeyPatch
+
+
+def maps() -> pl.Series:
+    keys = pl.Series("m", [["a", "b"], ["c", "a", "c"], [], None])
+    values = pl.Series([[1, 2], [3, 4, 5], [], None])
+    return keys.map.from_lists(values)
+
+
+def test_map_dtype() -> None:
+    dtype = pl.Map(pl.String, pl.Int64)
+    assert dtype == pl.Map(pl.String, pl.Int64)
+    assert dtype != pl.Map(pl.String, pl.Int32)
+    assert dtype.key == pl.String
+    assert dtype.value == pl.Int64
+    assert repr(dtype) == "Map(String, Int64)"
+    assert dtype.ext_storage() == pl.List(
+        pl.Struct({"key": pl.String, "value": pl.Int64})
+    )
+
+
+def test_map_from_lists() -> None:
+    s = maps()
+    assert s.dtype == pl.Map(pl.String, pl.Int64)
+    assert s.map.keys().to_list() == [["a", "b"], ["c", "a", "c"], [], None]
+    assert s.map.values().to_list() == [[1, 2], [3, 4, 5], [], None]
+
+    with pytest.raises(pl.exceptions.ShapeError):
+        pl.Series([["a"]]).map.from_lists(pl.Series([[1, 2]]))
+
+
+def test_map_get() -> None:
+    s = maps()
+    assert s.map.get("a").to_list() == [1, 4, None, None]
+    # duplicate keys return the first value
+    assert s.map.get("c").to_list() == [None, 3, None, None]
+
+    df = pl.DataFrame({"m": s, "key": ["b", "a", "a", "a"]})
+    out = df.select(pl.col("m").map.get(pl.col("key")))
+    assert out.to_series().to_list() == [2, 4, None, None]
+
+
+def test_map_contains_key() -> None:
+    s = maps()
+    assert s.map.contains_key("a").to_list() == [True, True, False, None]
+    assert s.map.contains_key("z").to_list() == [False, False, False, None]
+
+
+def test_map_explode() -> None:
+    out = pl.select(maps().map.explode().struct.unnest())
+    expected = pl.DataFrame(
+        {
+            "key": ["a", "b", "c", "a", "c", None, None],
+            "value": [1, 2, 3, 4, 5, None, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_map_to_arrow() -> None:
+    arr = maps().to_arrow()
+    assert arr.type == pa.map_(pa.large_string(), pa.int64())
+    assert arr.to_pylist() == [
+        [("a", 1), ("b", 2)],
+        [("c", 3), ("a", 4), ("c", 5)],
+        [],
+        None,
+    ]
+
+
+@pytest.mark.parametrize("fmt", ["ipc", "parquet"])
+def test_map_io_roundtrip(fmt: str) -> None:
+    df = pl.DataFrame({"m": maps()})
+
+    f = io.BytesIO()
+    getattr(df, f"write_{fmt}")(f)
+    f.seek(0)
+    out = getattr(pl, f"read_{fmt}")(f)
+
+    assert out.schema == {"m": pl.Map(pl.String, pl.Int64)}
+    assert_frame_equal(out, df)
+
+
+def test_map_from_foreign_arrow(plmonkeypatch: PlMonkeyPatch) -> None:
+    arr = pa.array([[("a", 1)], None], type=pa.map_(pa.string(), pa.int64()))
+
+    s = pl.Series("m", arr)
+    assert s.dtype == pl.Map(pl.String, pl.Int64)
+    assert_series_equal(s.map.get("a"), pl.Series("m", [1, None]))
+
+    plmonkeypatch.setenv("POLARS_IMPORT_ARROW_MAP_AS_MAP", "0")
+    s = pl.Series("m", arr)
+    assert s.dtype == pl.List(pl.Struct({"key": pl.String, "value": pl.Int64}))
+
+
+def test_map_to_arrow_null_keys() -> None:
+    keys = pl.Series("m", [["a"], ["b", None]])
+    s = keys.map.from_lists(pl.Series([[1], [2, 3]]))
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="null keys"):
+        s.to_arrow()
+
+    # Null keys outside of the exported slice don't matter.
+    assert s.head(1).to_arrow().to_pylist() == [[("a", 1)]]
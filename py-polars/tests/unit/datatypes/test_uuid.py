@@ -0,0 +1,156 @@
+from __future__ import annotations
+
+import io
+import uuid
+from datetime import datetime, timedelta, timezone
+
+import pyarrow as pa
+import pyarrow.parquet as pq
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+UUIDS = [
+    "01890a5d-ac96-774b-bcce-b302099a8057",
+    "f81d4fae-7dec-11d0-a765-00a0c91e6bf6",
+    None,
+]
+
+
+def test_uuid_dtype() -> None:
+    dtype = pl.UUID()
+    assert dtype == pl.UUID
+    assert repr(dtype) == "UUID"
+    assert dtype.ext_name() == "arrow.uuid"
+    assert dtype.ext_storage() == pl.Binary
+
+
+def test_uuid_parse_format_roundtrip() -> None:
+    s = pl.Series("id", UUIDS).cast(pl.UUID)
+    assert s.dtype == pl.UUID
+    assert s.null_count() == 1
+    assert_series_equal(s.cast(pl.String), pl.Series("id", UUIDS))
+
+
+def test_uuid_parse_formats() -> None:
+    s = pl.Series(
+        [
+            "F81D4FAE-7DEC-11D0-A765-00A0C91E6BF6",
+            "f81d4fae7dec11d0a76500a0c91e6bf6",
+            "{f81d4fae-7dec-11d0-a765-00a0c91e6bf6}",
+            "urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6",
+        ]
+    )
+    assert s.cast(pl.UUID).cast(pl.String).to_list() == [
+        "f81d4fae-7dec-11d0-a765-00a0c91e6bf6"
+    ] * len(s)
+
+
+def test_uuid_parse_invalid() -> None:
+    s = pl.Series(["f81d4fae-7dec-11d0-a765-00a0c91e6bf6", "not-a-uuid"])
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="not-a-uuid"):
+        s.cast(pl.UUID)
+    assert s.cast(pl.UUID, strict=False).null_count() == 1
+
+
+def test_uuid_from_python_objects() -> None:
+    values = [uuid.UUID(UUIDS[0]), None, uuid.UUID(UUIDS[1])]
+    s = pl.Series("id", values)
+    assert s.dtype == pl.UUID
+    assert s.to_list() == values
+    from_bytes = pl.Series("id", [v.bytes if v else None for v in values], pl.UUID)
+    assert_series_equal(from_bytes, s)
+
+
+def test_uuid_invalid_storage() -> None:
+    with pytest.raises(pl.exceptions.ComputeError, match="expected 16 bytes, got 15"):
+        pl.Series([b"0" * 15], dtype=pl.UUID)
+
+
+def test_uuid4() -> None:
+    df = pl.DataFrame({"a": range(100)}).with_columns(pl.uuid4())
+    assert df.schema == {"a": pl.Int64, "uuid": pl.UUID}
+
+    ids = df["uuid"].to_list()
+    assert len(set(ids)) == 100
+    assert all(u.version == 4 for u in ids)
+
+
+def test_uuid4_not_deduplicated() -> None:
+    lf = pl.LazyFrame({"a": range(10)}).with_columns(x=pl.uuid4(), y=pl.uuid4())
+    df = lf.collect()
+    assert set(df["x"].to_list()).isdisjoint(df["y"].to_list())
+
+
+def test_uuid7() -> None:
+    before = datetime.now(timezone.utc) - timedelta(seconds=1)
+    df = pl.DataFrame({"a": range(1000)}).with_columns(id=pl.uuid7())
+    after = datetime.now(timezone.utc) + timedelta(seconds=1)
+
+    ids = df["id"].to_list()
+    assert all(u.version == 7 for u in ids)
+    assert len(set(ids)) == 1000
+
+    # Generated UUIDs sort in generation order.
+    strs = df["id"].cast(pl.String).to_list()
+    assert strs == sorted(strs)
+    assert_series_equal(df.sort("id")["a"], df["a"])
+
+    ts = df["id"].uuid.timestamp()
+    assert ts.dtype == pl.Datetime("ms", "UTC")
+    assert before <= ts.min() <= ts.max() <= after  # type: ignore[operator]
+
+
+def test_uuid_timestamp() -> None:
+    df = pl.DataFrame({"id": UUIDS}).select(
+        pl.col("id").cast(pl.UUID).uuid.timestamp()
+    )
+    assert df.to_series().to_list() == [
+        datetime(2023, 6, 30, 3, 34, 18, 518000, tzinfo=timezone.utc),
+        None,
+        None,
+    ]
+
+
+def test_uuid_timestamp_non_uuid() -> None:
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="expected UUID"):
+        pl.select(pl.lit("a").uuid.timestamp())
+
+
+def test_uuid_to_arrow() -> None:
+    df = pl.DataFrame({"id": pl.Series(UUIDS).cast(pl.UUID)})
+    field = df.to_arrow().schema.field("id")
+    # Newer versions of pyarrow load `arrow.uuid` as a registered extension type.
+    if isinstance(field.type, pa.ExtensionType):
+        assert field.type.extension_name == "arrow.uuid"
+        assert field.type.storage_type == pa.binary(16)
+    else:
+        assert field.metadata[b"ARROW:extension:name"] == b"arrow.uuid"
+        assert field.type == pa.binary(16)
+
+    assert_frame_equal(pl.from_arrow(df.to_arrow()), df)  # type: ignore[arg-type]
+
+
+@pytest.mark.parametrize("fmt", ["ipc", "parquet"])
+def test_uuid_io_roundtrip(fmt: str) -> None:
+    df = pl.DataFrame({"id": pl.Series(UUIDS).cast(pl.UUID)})
+
+    f = io.BytesIO()
+    getattr(df, f"write_{fmt}")(f)
+    f.seek(0)
+    out = getattr(pl, f"read_{fmt}")(f)
+
+    assert out.schema == {"id": pl.UUID}
+    assert_frame_equal(out, df)
+
+
+def test_uuid_parquet_logical_type() -> None:
+    df = pl.DataFrame({"id": pl.Series(UUIDS).cast(pl.UUID)})
+
+    f = io.BytesIO()
+    df.write_parquet(f)
+    f.seek(0)
+    column = pq.ParquetFile(f).schema.column(0)
+    assert column.physical_type == "FIXED_LEN_BYTE_ARRAY"
+    assert column.logical_type.type == "UUID"
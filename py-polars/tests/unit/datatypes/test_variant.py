@@ -0,0 +1,221 @@
+from __future__ import annotations
+
+import io
+from datetime import date
+from typing import TYPE_CHECKING
+
+import pyarrow as pa
+import pyarrow.parquet as pq
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+if TYPE_CHECKING:
+    from tests.conftest import PlMonkeyPatch
+
+
+def variants() -> pl.Series:
+    return pl.Series(
+        "v",
+        [
+            '{"a": 1, "b": {"c": [1, 2, 3]}}',
+            '{"a": "x", "b": {"c": []}}',
+            "[true, null, 2.5]",
+            "null",
+            None,
+        ],
+    ).str.json_decode(pl.Variant)
+
+
+def test_variant_dtype() -> None:
+    dtype = pl.Variant()
+    assert dtype == pl.Variant
+    assert repr(dtype) == "Variant"
+    assert dtype.ext_storage() == pl.Struct({"metadata": pl.Binary, "value": pl.Binary})
+
+
+def test_variant_json_roundtrip() -> None:
+    s = variants()
+    assert s.dtype == pl.Variant
+    assert s.null_count() == 1
+    assert s.variant.to_json().to_list() == [
+        '{"a":1,"b":{"c":[1,2,3]}}',
+        '{"a":"x","b":{"c":[]}}',
+        "[true,null,2.5]",
+        "null",
+        None,
+    ]
+
+
+def test_variant_json_decode_invalid() -> None:
+    with pytest.raises(pl.exceptions.ComputeError):
+        pl.Series(["{"]).str.json_decode(pl.Variant)
+
+
+def test_variant_get() -> None:
+    s = variants()
+    assert s.variant.get("a").dtype == pl.Variant
+    assert s.variant.get("$.a").variant.to_json().to_list() == [
+        "1",
+        '"x"',
+        None,
+        None,
+        None,
+    ]
+    assert s.variant.get("$.b.c[1]").variant.to_json().to_list() == [
+        "2",
+        None,
+        None,
+        None,
+        None,
+    ]
+    assert s.variant.get("$['b']['c'][-1]", pl.Int64).to_list() == [
+        3,
+        None,
+        None,
+        None,
+        None,
+    ]
+    assert s.variant.get("[2]", pl.Float64).to_list() == [
+        None,
+        None,
+        2.5,
+        None,
+        None,
+    ]
+
+
+def test_variant_get_invalid_path() -> None:
+    with pytest.raises(pl.exceptions.ComputeError, match="invalid variant path"):
+        variants().variant.get("$.a[")
+
+
+def test_variant_cast() -> None:
+    s = variants().variant.get("a")
+    assert s.variant.cast(pl.String).to_list() == ["1", "x", None, None, None]
+
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        s.variant.cast(pl.Int64)
+    assert s.variant.cast(pl.Int64, strict=False).to_list() == [
+        1,
+        None,
+        None,
+        None,
+        None,
+    ]
+
+    dates = pl.Series(['"2024-01-02"']).str.json_decode(pl.Variant)
+    assert dates.variant.cast(pl.Date).to_list() == [date(2024, 1, 2)]
+
+
+def test_variant_cast_nested() -> None:
+    s = variants()
+    out = s.variant.get("b").variant.cast(pl.Struct({"c": pl.List(pl.Int64)}))
+    assert out.to_list() == [{"c": [1, 2, 3]}, {"c": []}, None, None, None]
+
+    out = s.variant.get("$.b.c").variant.cast(pl.List(pl.Int8))
+    assert_series_equal(
+        out,
+        pl.Series("v", [[1, 2, 3], [], None, None, None], pl.List(pl.Int8)),
+    )
+
+
+def test_variant_type_name() -> None:
+    s = variants()
+    assert s.variant.type_name().to_list() == [
+        "object",
+        "object",
+        "array",
+        "null",
+        None,
+    ]
+    assert s.variant.get("a").variant.type_name().to_list() == [
+        "integer",
+        "string",
+        None,
+        None,
+        None,
+    ]
+    assert s.variant.is_object().to_list() == [True, True, False, False, None]
+    assert s.variant.is_array().to_list() == [False, False, True, False, None]
+
+    a = s.variant.get("a")
+    assert a.variant.is_numeric().to_list() == [True, False, None, None, None]
+    assert a.variant.is_string().to_list() == [False, True, None, None, None]
+    assert a.variant.is_boolean().to_list() == [False, False, None, None, None]
+
+
+def test_variant_lazy_schema() -> None:
+    lf = pl.LazyFrame({"json": ['{"a": 1}']}).select(
+        v=pl.col("json").str.json_decode(pl.Variant),
+        a=pl.col("json").str.json_decode(pl.Variant).variant.get("a"),
+        a_int=pl.col("json").str.json_decode(pl.Variant).variant.get("a", pl.Int32),
+        json=pl.col("json").str.json_decode(pl.Variant).variant.to_json(),
+        type_name=pl.col("json").str.json_decode(pl.Variant).variant.type_name(),
+    )
+    expected = {
+        "v": pl.Variant,
+        "a": pl.Variant,
+        "a_int": pl.Int32,
+        "json": pl.String,
+        "type_name": pl.String,
+    }
+    assert lf.collect_schema() == expected
+    assert lf.collect().schema == expected
+
+
+def test_variant_get_non_variant() -> None:
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="expected Variant"):
+        pl.select(pl.lit(1).variant.get("a"))
+
+
+def test_variant_to_arrow() -> None:
+    field = pl.DataFrame({"v": variants()}).to_arrow().schema.field("v")
+    assert pa.types.is_struct(field.type)
+    assert [f.name for f in field.type] == ["metadata", "value"]
+    assert field.metadata[b"ARROW:extension:name"] == b"arrow.parquet.variant"
+
+
+@pytest.mark.parametrize("fmt", ["ipc", "parquet"])
+def test_variant_io_roundtrip(fmt: str) -> None:
+    df = pl.DataFrame({"v": variants()})
+
+    f = io.BytesIO()
+    getattr(df, f"write_{fmt}")(f)
+    f.seek(0)
+    out = getattr(pl, f"read_{fmt}")(f)
+
+    assert out.schema == {"v": pl.Variant}
+    assert_frame_equal(out, df)
+    assert_series_equal(out["v"].variant.to_json(), df["v"].variant.to_json())
+
+
+def test_variant_parquet_without_arrow_schema(plmonkeypatch: PlMonkeyPatch) -> None:
+    # Spark and Delta write unshredded variants as a plain group of two required binary
+    # columns, without the Arrow schema. Reading them as variants is opt-in, as ordinary
+    # structs can have the same layout.
+    s = variants().head(4)
+    storage = pl.DataFrame({"v": s}).select(pl.col("v").ext.storage()).unnest("v")
+    fields = [
+        pa.field(name, pa.binary(), nullable=False) for name in ["metadata", "value"]
+    ]
+    arr = pa.StructArray.from_arrays(
+        [pa.array(storage[f.name].to_list(), pa.binary()) for f in fields],
+        fields=fields,
+    )
+
+    f = io.BytesIO()
+    pq.write_table(pa.table({"v": arr}), f, store_schema=False)
+    f.seek(0)
+    assert pq.read_schema(f).metadata is None
+
+    f.seek(0)
+    out = pl.read_parquet(f)
+    assert out.schema == {"v": pl.Struct({"metadata": pl.Binary, "value": pl.Binary})}
+
+    plmonkeypatch.setenv("POLARS_PARQUET_INFER_VARIANT_GROUPS", "1")
+    f.seek(0)
+    out = pl.read_parquet(f)
+    assert out.schema == {"v": pl.Variant}
+    assert_series_equal(out["v"].variant.to_json(), s.variant.to_json())
@@ -8,6 +8,7 @@ from typing import TYPE_CHECKING, Any, cast
 import numpy as np
 import pandas as pd
 import pyarrow as pa
+import pyarrow.compute as pc
 import pyarrow.parquet as pq
 import pytest
 
@@ -16,7 +17,6 @@ from polars.exceptions import (
     ComputeError,
     DuplicateError,
     InvalidOperationError,
-    PanicException,
     UnstableWarning,
 )
 from polars.interchange.protocol import CompatLevel
@@ -391,6 +391,8 @@ def test_from_pyarrow_map() -> None:
     pl.DataFrame(pa_table.slice(0, 0))
 
     result = pl.DataFrame(pa_table)
+    assert result.schema["mapping"] == pl.Map(pl.String, pl.String)
+    result = result.with_columns(pl.col("mapping").ext.storage())
     assert result.to_dict(as_series=False) == {
         "idx": [1, 2],
         "mapping": [
@@ -1063,7 +1065,9 @@ def test_from_arrow_recorbatch() -> None:
     )
 
 
-def test_from_arrow_map_containing_timestamp_23658() -> None:
+def test_from_arrow_map_containing_timestamp_23658(
+    plmonkeypatch: PlMonkeyPatch,
+) -> None:
     arrow_tbl = pa.Table.from_pydict(
         {
             "column_1": [
@@ -1125,6 +1129,14 @@ def test_from_arrow_map_containing_timestamp_23658() -> None:
         },
     )
 
+    out = pl.DataFrame(arrow_tbl)
+    assert out.schema == {
+        "column_1": pl.List(
+            pl.Struct({"field_1": pl.Map(pl.Int32, pl.Datetime("ms"))})
+        )
+    }
+
+    plmonkeypatch.setenv("POLARS_IMPORT_ARROW_MAP_AS_MAP", "0")
     out = pl.DataFrame(arrow_tbl)
     assert_frame_equal(out, expect)
 
@@ -1134,9 +1146,7 @@ def test_schema_constructor_from_schema_capsule() -> None:
         [pa.field("test", pa.map_(pa.int32(), pa.timestamp("ms")))]
     )
 
-    assert pl.Schema(arrow_schema) == {
-        "test": pl.List(pl.Struct({"key": pl.Int32, "value": pl.Datetime("ms")}))
-    }
+    assert pl.Schema(arrow_schema) == {"test": pl.Map(pl.Int32, pl.Datetime("ms"))}
 
     # Test __arrow_c_schema__ implementation on `pl.Schema`
     assert pa.schema(pl.Schema({"x": pl.Int32})) == pa.schema(
@@ -1314,162 +1324,53 @@ def pyarrow_table_to_ipc_bytes(tbl: pa.Table) -> bytes:
 
 
 @pytest.mark.write_disk
-def test_month_day_nano_from_ffi_15969(plmonkeypatch: PlMonkeyPatch) -> None:
+def test_month_day_nano_from_ffi_15969() -> None:
 
     def new_interval_scalar(months: int, days: int, nanoseconds: int) -> pa.Scalar:
         return pa.scalar((months, days, nanoseconds), type=pa.month_day_nano_interval())
 
+    values = [
+        (1, 0, 0),
+        (0, 1, 0),
+        (0, 0, 1_000),
+        (1, 1, 1_000_001_000),
+        (-1, 0, 0),
+        (0, -1, 0),
+        (0, 0, -1_000),
+        (-1, -1, -1_000_001_000),
+        (3558, 0, 0),
+        (-3558, 0, 0),
+        (1, -1, 1_999_999_000),
+    ]
     arrow_tbl = pa.Table.from_pydict(
-        {
-            "interval": [
-                new_interval_scalar(1, 0, 0),
-                new_interval_scalar(0, 1, 0),
-                new_interval_scalar(0, 0, 1_000),
-                new_interval_scalar(1, 1, 1_000_001_000),
-                new_interval_scalar(-1, 0, 0),
-                new_interval_scalar(0, -1, 0),
-                new_interval_scalar(0, 0, -1_000),
-                new_interval_scalar(-1, -1, -1_000_001_000),
-                new_interval_scalar(3558, 0, 0),
-                new_interval_scalar(-3558, 0, 0),
-                new_interval_scalar(1, -1, 1_999_999_000),
-            ]
-        },
+        {"interval": [new_interval_scalar(*v) for v in values]},
         schema=pa.schema([pa.field("interval", pa.month_day_nano_interval())]),
     )
 
     ipc_bytes = pyarrow_table_to_ipc_bytes(arrow_tbl)
 
-    import_err_msg = (
-        "could not import from `month_day_nano_interval` type. "
-        "Hint: This can be imported by setting "
-        "POLARS_IMPORT_INTERVAL_AS_STRUCT=1 in the environment. "
-        "Note however that this is unstable functionality "
-        "that may change at any time."
-    )
-
-    with pytest.raises(PanicException, match=import_err_msg):
-        pl.scan_ipc(ipc_bytes).collect_schema()
-
-    with pytest.raises(PanicException, match=import_err_msg):
-        pl.scan_ipc(ipc_bytes).collect()
-
-    with pytest.raises(PanicException, match=import_err_msg):
-        pl.DataFrame(
-            pa.Table.from_pydict(
-                {"interval": pa.array([], type=pa.month_day_nano_interval())}
-            )
-        )
-
-    with pytest.raises(ComputeError, match=import_err_msg):
-        pl.Series(pa.array([], type=pa.month_day_nano_interval()))
-
-    plmonkeypatch.setenv("POLARS_IMPORT_INTERVAL_AS_STRUCT", "1")
-
-    expect = pl.DataFrame(
-        [
-            pl.Series(
-                "interval",
-                [
-                    {"months": 1, "days": 0, "nanoseconds": timedelta(0)},
-                    {"months": 0, "days": 1, "nanoseconds": timedelta(0)},
-                    {
-                        "months": 0,
-                        "days": 0,
-                        "nanoseconds": timedelta(microseconds=1),
-                    },
-                    {
-                        "months": 1,
-                        "days": 1,
-                        "nanoseconds": timedelta(seconds=1, microseconds=1),
-                    },
-                    {"months": -1, "days": 0, "nanoseconds": timedelta(0)},
-                    {"months": 0, "days": -1, "nanoseconds": timedelta(0)},
-                    {
-                        "months": 0,
-                        "days": 0,
-                        "nanoseconds": timedelta(
-                            days=-1, seconds=86399, microseconds=999999
-                        ),
-                    },
-                    {
-                        "months": -1,
-                        "days": -1,
-                        "nanoseconds": timedelta(
-                            days=-1, seconds=86398, microseconds=999999
-                        ),
-                    },
-                    {"months": 3558, "days": 0, "nanoseconds": timedelta(0)},
-                    {"months": -3558, "days": 0, "nanoseconds": timedelta(0)},
-                    {
-                        "months": 1,
-                        "days": -1,
-                        "nanoseconds": timedelta(seconds=1, microseconds=999999),
-                    },
-                ],
-                dtype=pl.Struct(
-                    {
-                        "months": pl.Int32,
-                        "days": pl.Int32,
-                        "nanoseconds": pl.Duration(time_unit="ns"),
-                    }
-                ),
-            ),
-        ]
-    )
-
-    assert_frame_equal(pl.DataFrame(arrow_tbl), expect)
-    assert_series_equal(
-        pl.Series(arrow_tbl.column(0)).alias("interval"), expect.to_series()
-    )
+    df = pl.DataFrame(arrow_tbl)
+    assert df.schema == {"interval": pl.Interval}
+    assert df["interval"].to_list() == values
+    assert pl.Series(arrow_tbl.column(0)).to_list() == values
+    assert df.to_arrow()["interval"].equals(arrow_tbl["interval"])
 
     # Test IPC scan
-    assert pl.scan_ipc(ipc_bytes).collect_schema() == {
-        "interval": pl.Struct(
-            {
-                "months": pl.Int32,
-                "days": pl.Int32,
-                "nanoseconds": pl.Duration(time_unit="ns"),
-            }
-        )
-    }
-    assert_frame_equal(pl.scan_ipc(ipc_bytes).collect(), expect)
+    assert pl.scan_ipc(ipc_bytes).collect_schema() == {"interval": pl.Interval}
+    assert_frame_equal(pl.scan_ipc(ipc_bytes).collect(), df)
 
-    assert_frame_equal(
-        pl.DataFrame(
-            pa.Table.from_pydict(
-                {"interval": pa.array([], type=pa.month_day_nano_interval())}
-            )
-        ),
-        pl.DataFrame(
-            schema={
-                "interval": pl.Struct(
-                    {
-                        "months": pl.Int32,
-                        "days": pl.Int32,
-                        "nanoseconds": pl.Duration(time_unit="ns"),
-                    }
-                )
-            }
-        ),
-    )
-
-    assert_series_equal(
-        pl.Series(pa.array([], type=pa.month_day_nano_interval())),
-        pl.Series(
-            dtype=pl.Struct(
-                {
-                    "months": pl.Int32,
-                    "days": pl.Int32,
-                    "nanoseconds": pl.Duration(time_unit="ns"),
-                }
-            )
-        ),
+    assert pl.DataFrame(
+        pa.Table.from_pydict(
+            {"interval": pa.array([], type=pa.month_day_nano_interval())}
+        )
+    ).schema == {"interval": pl.Interval}
+    assert pl.Series(pa.array([], type=pa.month_day_nano_interval())).dtype == (
+        pl.Interval
     )
 
     f = io.BytesIO()
 
-    # TODO: Add Parquet round-trip test if this starts working.
+    # pyarrow cannot write this type to Parquet, see `test_interval_parquet`.
     with pytest.raises(pa.ArrowNotImplementedError):
         pq.write_table(arrow_tbl, f)
 
@@ -1524,3 +1425,68 @@ def test_0_width_df_roundtrip() -> None:
         match=r"cannot sink 0-width DataFrame with non-zero height \(1\) to CSV",
     ):
         pl.LazyFrame(height=1).sink_csv(io.BytesIO())
+
+
+def test_from_arrow_run_end_encoded() -> None:
+    values = ["a", "a", "a", None, None, "b", "a", "a"]
+    tbl = pa.table(
+        {
+            "x": pc.run_end_encode(pa.array(values)),
+            "y": pa.array(range(8), pa.int64()),
+        }
+    )
+    df = pl.from_arrow(tbl)
+    assert isinstance(df, pl.DataFrame)
+    assert df.schema == {"x": pl.String, "y": pl.Int64}
+    assert df["x"].to_list() == values
+
+    assert df.filter(pl.col("y") > 2)["x"].to_list() == values[3:]
+    assert df.slice(2, 4)["x"].to_list() == values[2:6]
+    assert df.select(pl.col("x") == "a")["x"].to_list() == [
+        True,
+        True,
+        True,
+        None,
+        None,
+        False,
+        True,
+        True,
+    ]
+    assert df.select(pl.col("x").null_count()).item() == 2
+    assert df.select(pl.col("x").n_unique()).item() == 3
+
+    out = df.group_by("x", maintain_order=True).agg(pl.col("y").sum())
+    expected = pl.DataFrame(
+        {"x": ["a", None, "b"], "y": [0 + 1 + 2 + 6 + 7, 3 + 4, 5]},
+    )
+    assert_frame_equal(out, expected)
+
+    # Sliced arrow input only keeps the referenced runs.
+    df = pl.from_arrow(tbl.slice(2, 4))
+    assert isinstance(df, pl.DataFrame)
+    assert df["x"].to_list() == values[2:6]
+
+
+def test_to_arrow_run_end_encoded_roundtrip() -> None:
+    values = ["a", "a", None, "b", "b", "b"]
+    tbl = pa.table({"x": pc.run_end_encode(pa.array(values)), "y": range(6)})
+    df = pl.from_arrow(tbl)
+    assert isinstance(df, pl.DataFrame)
+
+    out = df.to_arrow(compat_level=CompatLevel.newest())
+    assert pa.types.is_run_end_encoded(out.schema.field("x").type)
+    assert not pa.types.is_run_end_encoded(out.schema.field("y").type)
+    assert pc.run_end_decode(out["x"]).to_pylist() == values
+    result = pl.from_arrow(out)
+    assert isinstance(result, pl.DataFrame)
+    assert_frame_equal(result, df)
+
+    # Multiple record batches stay encoded as well.
+    df = pl.from_arrow(pa.concat_tables([tbl, tbl]))
+    assert isinstance(df, pl.DataFrame)
+    out = df.to_arrow(compat_level=CompatLevel.newest())
+    assert pa.types.is_run_end_encoded(out.schema.field("x").type)
+    assert df["x"].to_list() == values + values
+
+    out = df.to_arrow(compat_level=CompatLevel.oldest())
+    assert out.schema.field("x").type == pa.large_string()
@@ -412,7 +412,7 @@ class _TableDataAllTypes:
                     else []
                 ),
                 *(
-                    [pl.Series('UUIDType', [b"0000111100001111"], dtype=pl.Binary)]
+                    [pl.Series('UUIDType', [b"0000111100001111"], dtype=pl.UUID)]
                     if not exclude_uuid
                     else []
                 )
@@ -1292,8 +1292,37 @@ def test_scan_iceberg_nested_column_cast_deletion_rename(tmp_path: Path) -> None
                 )
             ),
         },
+    ).with_columns(
+        pl.col("column_1").list.eval(
+            pl.element().struct.with_fields(
+                pl.field("field_1").ext.to(pl.Map(pl.List(pl.Datetime("us")), pl.List(pl.Int32)))
+            )
+        ),
+        pl.col("column_3").ext.to(
+            pl.Map(
+                pl.Struct({"field_1": pl.Int32, "field_2": pl.Int32, "field_3": pl.Int32}),
+                pl.Struct({"field_1": pl.Int32, "field_2": pl.Int32, "field_3": pl.Int32}),
+            )
+        ),
     )  # fmt: skip
 
+    assert df.schema == {
+        "column_1": pl.List(
+            pl.Struct(
+                {
+                    "field_1": pl.Map(pl.List(pl.Datetime("us")), pl.List(pl.Int32)),
+                    "field_2": pl.Int32,
+                    "field_3": pl.String,
+                }
+            )
+        ),
+        "column_2": pl.String,
+        "column_3": pl.Map(
+            pl.Struct({"field_1": pl.Int32, "field_2": pl.Int32, "field_3": pl.Int32}),
+            pl.Struct({"field_1": pl.Int32, "field_2": pl.Int32, "field_3": pl.Int32}),
+        ),
+    }  # fmt: skip
+
     # The Iceberg table schema has a `Map` type, whereas the polars DataFrame
     # stores `list[struct{..}]` - directly using `write_iceberg()` causes the
     # following error:
@@ -1446,6 +1475,18 @@ def test_scan_iceberg_nested_column_cast_deletion_rename(tmp_path: Path) -> None
                 )
             ),
         },
+    ).with_columns(
+        pl.col("column_1").ext.to(
+            pl.Map(
+                pl.Struct({"field_1": pl.Int32, "field_2": pl.Int32, "field_3": pl.Int32}),
+                pl.Struct({"field_1": pl.Int64, "field_2": pl.Int64}),
+            )
+        ),
+        pl.col("column_2").list.eval(
+            pl.element().struct.with_fields(
+                pl.field("field_2").ext.to(pl.Map(pl.List(pl.Datetime("us")), pl.List(pl.Int32)))
+            )
+        ),
     )  # fmt: skip
 
     assert_frame_equal(
@@ -408,3 +408,22 @@ def test_sink_scan_ipc_round_trip_statistics_projection(
     out = pl.scan_ipc(buf, _record_batch_statistics=True).select(selection).collect()
     assert_frame_equal(df, out)
     assert_frame_equal(df._to_metadata(), out._to_metadata())
+
+
+@pytest.mark.parametrize("compression", COMPRESSIONS)
+def test_sink_ipc_stream(compression: IpcCompression) -> None:
+    lf = pl.LazyFrame(
+        {
+            "a": range(100),
+            "cat": pl.Series(["A", "B", "C", "D"] * 25, dtype=pl.Categorical),
+        }
+    )
+
+    buf = io.BytesIO()
+    lf.sink_ipc(buf, stream=True, compression=compression, record_batch_size=10)
+
+    buf.seek(0)
+    assert_frame_equal(pl.read_ipc_stream(buf), lf.collect())
+
+    buf.seek(0)
+    assert pyarrow.ipc.open_stream(buf).read_all().num_rows == 100
@@ -1364,7 +1364,11 @@ def test_parquet_pyarrow_map() -> None:
         schema={"x": pl.Struct({"key": pl.Int32, "value": pl.Int32})},
     )
     f.seek(0)
-    assert_frame_equal(pl.read_parquet(f).explode(["x"]), expected)
+    out = pl.read_parquet(f)
+    assert out.schema == {"x": pl.Map(pl.Int32, pl.Int32)}
+    assert_frame_equal(
+        out.select(pl.col("x").ext.storage()).explode(["x"]), expected
+    )
 
     # Test for https://github.com/pola-rs/polars/issues/21317
     # Specifying schema/allow_missing_columns
@@ -1373,9 +1377,11 @@ def test_parquet_pyarrow_map() -> None:
         assert_frame_equal(
             pl.read_parquet(
                 f,
-                schema={"x": pl.List(pl.Struct({"key": pl.Int32, "value": pl.Int32}))},
+                schema={"x": pl.Map(pl.Int32, pl.Int32)},
                 missing_columns=missing_columns,  # type: ignore[arg-type]
-            ).explode(["x"]),
+            )
+            .select(pl.col("x").ext.storage())
+            .explode(["x"]),
             expected,
         )
 
@@ -537,3 +537,83 @@ def test_partition_by_diff_expr_26370(tmp_path: Path) -> None:
     q.sink_parquet(pl.PartitionBy(tmp_path, key="z"))
 
     assert_frame_equal(pl.scan_parquet(tmp_path).collect(), q.collect())
+
+
+def test_partition_by_commit_protocol_api() -> None:
+    with pytest.raises(
+        ValueError, match="'overwrite_partitions' requires commit_protocol='staged'"
+    ):
+        pl.PartitionBy("", key="a", overwrite_partitions=True)
+
+    inner = pl.PartitionBy("", key="a")._pl_partition_by
+    assert inner.commit_protocol == "direct"
+    assert not inner.overwrite_partitions
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("io_type", io_types)
+def test_sink_partitioned_staged_commit(tmp_path: Path, io_type: IOType) -> None:
+    ext = io_type["ext"]
+
+    def sink(df: pl.DataFrame, *, overwrite_partitions: bool) -> None:
+        io_type["sink"](
+            df.lazy(),
+            pl.PartitionBy(
+                tmp_path,
+                key="a",
+                commit_protocol="staged",
+                overwrite_partitions=overwrite_partitions,
+            ),
+        )
+
+    # Scanning the base path directly skips the manifest and the staging directory.
+    def scan() -> pl.DataFrame:
+        return io_type["scan"](tmp_path).collect().sort("a", "b")
+
+    def manifest() -> list[str]:
+        return (tmp_path / "_SUCCESS").read_text().splitlines()
+
+    df = pl.DataFrame({"a": [1, 1, 2], "b": [1, 2, 3]})
+    sink(df, overwrite_partitions=False)
+
+    assert not (tmp_path / "_polars_staging").exists()
+    files = manifest()
+    assert len(files) == 2
+    assert all((tmp_path / f).is_file() for f in files)
+    assert_frame_equal(scan(), df, check_column_order=False, check_dtypes=False)
+
+    # Only the partitions that are written to are replaced.
+    sink(pl.DataFrame({"a": [1], "b": [10]}), overwrite_partitions=True)
+
+    assert not (tmp_path / "_polars_staging").exists()
+    # The manifest keeps the files of untouched partitions.
+    assert [f.split("/")[0] for f in manifest()] == ["a=1", "a=2"]
+    assert all((tmp_path / f).is_file() for f in manifest())
+    assert_frame_equal(
+        scan(),
+        pl.DataFrame({"a": [1, 2], "b": [10, 3]}),
+        check_column_order=False,
+        check_dtypes=False,
+    )
+
+    # Files of a write that is still in progress are not visible.
+    in_progress = tmp_path / "_polars_staging" / "in-progress" / "a=4"
+    in_progress.mkdir(parents=True)
+    io_type["sink"](pl.LazyFrame({"a": [4], "b": [5]}), in_progress / f"0.{ext}")
+    assert_frame_equal(
+        scan(),
+        pl.DataFrame({"a": [1, 2], "b": [10, 3]}),
+        check_column_order=False,
+        check_dtypes=False,
+    )
+
+    # Without overwriting, existing partitions are kept.
+    sink(pl.DataFrame({"a": [3], "b": [4]}), overwrite_partitions=False)
+
+    assert [f.split("/")[0] for f in manifest()] == ["a=1", "a=2", "a=3"]
+    assert_frame_equal(
+        scan(),
+        pl.DataFrame({"a": [1, 2, 3], "b": [10, 3, 4]}),
+        check_column_order=False,
+        check_dtypes=False,
+    )
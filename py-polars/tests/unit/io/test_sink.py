@@ -212,6 +212,10 @@ def check_compression(content: bytes, expected_format: str) -> None:
         assert content[:2] == bytes([0x1F, 0x8B])
     elif expected_format == "zstd":
         assert content[:4] == bytes([0x28, 0xB5, 0x2F, 0xFD])
+    elif expected_format == "bzip2":
+        assert content[:3] == b"BZh"
+    elif expected_format == "xz":
+        assert content[:6] == bytes([0xFD, 0x37, 0x7A, 0x58, 0x5A, 0x00])
     else:
         pytest.fail("Unreachable")
 
@@ -254,11 +258,43 @@ def test_write_compressed(write_fn_name: str, fmt: str, level: int | None) -> No
     assert_frame_equal(df, original)
 
 
+@pytest.mark.parametrize(
+    "write_fn_name", ["write_csv", "sink_csv", "write_ndjson", "sink_ndjson"]
+)
+@pytest.mark.parametrize("fmt", ["bzip2", "xz"])
+@pytest.mark.parametrize("level", [None, 1, 9])
+def test_write_compressed_bzip2_xz(
+    write_fn_name: str, fmt: str, level: int | None
+) -> None:
+    original = pl.DataFrame([pl.Series("A", [3.2, 6.2]), pl.Series("B", ["a", "z"])])
+    buf = io.BytesIO()
+    write_fn(original, write_fn_name)(buf, compression=fmt, compression_level=level)
+    buf.seek(0)
+    check_compression(buf.read(), fmt)
+    buf.seek(0)
+    df = scan_fn(write_fn_name)(buf).collect()
+    assert_frame_equal(df, original)
+
+
+@pytest.mark.parametrize("write_fn_name", ["sink_csv", "sink_ndjson"])
+@pytest.mark.parametrize(("fmt", "level"), [("bzip2", 0), ("bzip2", 10), ("xz", 10)])
+def test_write_compressed_invalid_level(
+    write_fn_name: str, fmt: str, level: int
+) -> None:
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="compression level"):
+        write_fn(pl.DataFrame(), write_fn_name)(
+            io.BytesIO(), compression=fmt, compression_level=level
+        )
+
+
 @pytest.mark.write_disk
 @pytest.mark.parametrize(
     "write_fn_name", ["write_csv", "sink_csv", "write_ndjson", "sink_ndjson"]
 )
-@pytest.mark.parametrize(("fmt", "suffix"), [("gzip", ".gz"), ("zstd", ".zst")])
+@pytest.mark.parametrize(
+    ("fmt", "suffix"),
+    [("gzip", ".gz"), ("zstd", ".zst"), ("bzip2", ".bz2"), ("xz", ".xz")],
+)
 @pytest.mark.parametrize("with_suffix", [True, False])
 def test_write_compressed_disk(
     tmp_path: Path, write_fn_name: str, fmt: str, suffix: str, with_suffix: bool
@@ -272,6 +308,33 @@ def test_write_compressed_disk(
     assert_frame_equal(df, original)
 
 
+@pytest.mark.write_disk
+@pytest.mark.parametrize(
+    ("write_fn_name", "extension"), [("sink_csv", "csv"), ("sink_ndjson", "jsonl")]
+)
+@pytest.mark.parametrize("fmt", ["gzip", "bzip2", "xz"])
+def test_sink_partitioned_compressed(
+    tmp_path: Path, write_fn_name: str, extension: str, fmt: str
+) -> None:
+    original = pl.DataFrame({"a": [1, 1, 2], "b": ["x", "y", "z"]})
+    write_fn(original, write_fn_name)(
+        pl.PartitionBy(tmp_path, key="a"), compression=fmt
+    )
+
+    # Partition files keep the plain extension of the file format.
+    files = sorted(tmp_path.rglob("*.*"))
+    assert [f.relative_to(tmp_path).as_posix() for f in files] == [
+        f"a=1/00000000.{extension}",
+        f"a=2/00000000.{extension}",
+    ]
+
+    for file in files:
+        check_compression(file.read_bytes(), fmt)
+
+    df = pl.concat([scan_fn(write_fn_name)(f).collect() for f in files])
+    assert_frame_equal(df, original)
+
+
 @pytest.mark.write_disk
 @pytest.mark.parametrize(
     "write_fn_name", ["write_csv", "sink_csv", "write_ndjson", "sink_ndjson"]
@@ -300,7 +363,7 @@ def test_write_uncommon_file_suffix_raise(write_fn_name: str, fmt: str) -> None:
 @pytest.mark.parametrize(
     "write_fn_name", ["write_csv", "sink_csv", "write_ndjson", "sink_ndjson"]
 )
-@pytest.mark.parametrize("extension", ["gz", "zst", "zstd"])
+@pytest.mark.parametrize("extension", ["gz", "zst", "zstd", "bz2", "xz"])
 def test_write_intended_compression(write_fn_name: str, extension: str) -> None:
     with pytest.raises(
         pl.exceptions.InvalidOperationError, match="use the compression parameter"
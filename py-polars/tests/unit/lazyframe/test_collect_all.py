@@ -1,9 +1,11 @@
 from pathlib import Path
 from typing import cast
+from unittest.mock import Mock
 
 import pytest
 
 import polars as pl
+from polars.io.plugins import register_io_source
 from polars.testing import assert_frame_equal
 
 
@@ -54,3 +56,26 @@ def test_collect_all_groupby_lazy_sink_issue_26296(tmp_path: Path) -> None:
 
     expected_sink = pl.DataFrame({"g": ["A"], "v": [1]})
     assert_frame_equal(pl.read_parquet(tmp_file), expected_sink)
+
+
+def test_collect_all_shared_scan_different_filters() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3, 4, 5], "b": [10, 20, 30, 40, 50]})
+    io_source = Mock(wraps=lambda *_: iter([df]))
+    lf = register_io_source(io_source, schema=df.schema, is_pure=True)
+
+    lfs = [
+        lf.filter(pl.col("a") < 3).select(pl.col("b").sum()),
+        lf.filter(pl.col("a") > 3).select("a"),
+        lf.filter(pl.col("b") == 30),
+    ]
+    out = pl.collect_all(lfs, engine="streaming")
+
+    # The scan is shared, each plan still applies its own filter.
+    assert io_source.call_count == 1
+    assert_frame_equal(out[0], pl.DataFrame({"b": [30]}))
+    assert_frame_equal(out[1], pl.DataFrame({"a": [4, 5]}))
+    assert_frame_equal(out[2], pl.DataFrame({"a": [3], "b": [30]}))
+
+    expected = pl.collect_all(lfs, engine="in-memory")
+    for result, exp in zip(out, expected):
+        assert_frame_equal(result, exp)
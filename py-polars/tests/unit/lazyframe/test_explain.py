@@ -37,3 +37,21 @@ def test_lf_explain_tree_format_deprecated() -> None:
 
     with pytest.deprecated_call():
         lf.explain(tree_format=True)
+
+
+def test_lf_explain_analyze() -> None:
+    lf = pl.LazyFrame({"a": ["x", "y", "x", "z", "y", "x"], "b": range(6)})
+    q = lf.filter(pl.col("b") > 0).group_by("a").agg(pl.col("b").sum())
+
+    lines = q.explain_analyze().splitlines()
+
+    assert lines[0].startswith("streaming query took")
+    # The result sink is the root and receives one row per group.
+    assert lines[1].startswith("in-memory-sink: wall=")
+    assert "rows(in=3, out=0)" in lines[1]
+    assert "buffered_peak=" in lines[1]
+    # Inputs are indented below the node they feed into.
+    assert all(line.startswith("  ") for line in lines[2:])
+    assert any("rows(in=0, out=6)" in line for line in lines[2:])
+    group_by = next(line for line in lines if line.lstrip().startswith("group-by:"))
+    assert "buffered_peak=" in group_by
@@ -0,0 +1,108 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from tests.conftest import PlMonkeyPatch
+
+
+@pytest.fixture
+def star_schema() -> tuple[pl.LazyFrame, pl.LazyFrame, pl.LazyFrame]:
+    fact = pl.LazyFrame(
+        {
+            "a": [i % 100 for i in range(1000)],
+            "b": [i % 97 for i in range(1000)],
+            "v": range(1000),
+        }
+    )
+    dim_a = pl.LazyFrame({"a": range(100), "x": [i % 7 for i in range(100)]})
+    dim_b = pl.LazyFrame({"b": range(100), "y": [i % 10 for i in range(100)]})
+    return fact, dim_a, dim_b
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_join_reorder_selective_join_first(
+    star_schema: tuple[pl.LazyFrame, pl.LazyFrame, pl.LazyFrame],
+    engine: pl.EngineType,
+    capfd: pytest.CaptureFixture[str],
+    plmonkeypatch: PlMonkeyPatch,
+) -> None:
+    fact, dim_a, dim_b = star_schema
+    q = fact.join(dim_a, on="a").join(dim_b.filter(pl.col("y") == 1), on="b")
+
+    plmonkeypatch.setenv("POLARS_VERBOSE", "1")
+    capfd.readouterr()
+    out = q.collect(engine=engine, optimizations=pl.QueryOptFlags(join_reorder=True))
+    assert "join reordering: reordered 3 inputs" in capfd.readouterr().err
+
+    expected = q.collect(optimizations=pl.QueryOptFlags(join_reorder=False))
+    assert out.columns == ["a", "b", "v", "x", "y"]
+    assert_frame_equal(out, expected, check_row_order=False)
+
+
+@pytest.mark.parametrize(
+    "optimizations",
+    [pl.QueryOptFlags(), pl.QueryOptFlags(join_reorder=False)],
+)
+def test_join_reorder_disabled(
+    star_schema: tuple[pl.LazyFrame, pl.LazyFrame, pl.LazyFrame],
+    optimizations: pl.QueryOptFlags,
+    capfd: pytest.CaptureFixture[str],
+    plmonkeypatch: PlMonkeyPatch,
+) -> None:
+    fact, dim_a, dim_b = star_schema
+    q = fact.join(dim_a, on="a").join(dim_b.filter(pl.col("y") == 1), on="b")
+
+    plmonkeypatch.setenv("POLARS_VERBOSE", "1")
+    capfd.readouterr()
+    q.collect(optimizations=optimizations)
+    assert "join reordering" not in capfd.readouterr().err
+
+
+@pytest.mark.parametrize(
+    "join_kwargs",
+    [
+        # The output order depends on the join order.
+        {"maintain_order": "left"},
+        # Columns shared by both sides that aren't keys get a suffix.
+        {"suffix": "_dup"},
+        {"validate": "m:1"},
+    ],
+)
+def test_join_reorder_not_applied(
+    star_schema: tuple[pl.LazyFrame, pl.LazyFrame, pl.LazyFrame],
+    join_kwargs: dict[str, str],
+    capfd: pytest.CaptureFixture[str],
+    plmonkeypatch: PlMonkeyPatch,
+) -> None:
+    fact, dim_a, dim_b = star_schema
+    if "suffix" in join_kwargs:
+        dim_b = dim_b.with_columns(v=pl.col("y"))
+    q = fact.join(dim_a, on="a").join(
+        dim_b.filter(pl.col("y") == 1),
+        on="b",
+        **join_kwargs,  # type: ignore[arg-type]
+    )
+
+    plmonkeypatch.setenv("POLARS_VERBOSE", "1")
+    capfd.readouterr()
+    out = q.collect(optimizations=pl.QueryOptFlags(join_reorder=True))
+    assert "join reordering" not in capfd.readouterr().err
+
+    expected = q.collect(optimizations=pl.QueryOptFlags(join_reorder=False))
+    assert_frame_equal(out, expected, check_row_order=False)
+
+
+def test_join_reorder_multiple_keys() -> None:
+    a = pl.LazyFrame({"k1": [1, 2, 3, 1], "k2": [1, 1, 2, 2], "p": [1, 2, 3, 4]})
+    b = pl.LazyFrame({"k1": [1, 2, 3], "k2": [1, 1, 2], "q": [5, 6, 7]})
+    c = pl.LazyFrame({"k2": [1, 2, 2], "r": [8, 9, 10]}).filter(pl.col("r") > 8)
+
+    q = a.join(b, on=["k1", "k2"]).join(c, on="k2")
+    out = q.collect(optimizations=pl.QueryOptFlags(join_reorder=True))
+    assert_frame_equal(out, q.collect(), check_row_order=False)
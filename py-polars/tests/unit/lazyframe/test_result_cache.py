@@ -0,0 +1,125 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+    from pathlib import Path
+
+    from polars._typing import EngineType
+
+
+@pytest.fixture
+def result_cache() -> Iterator[None]:
+    pl.enable_result_cache()
+    try:
+        yield
+    finally:
+        pl.disable_result_cache()
+
+
+@pytest.mark.usefixtures("result_cache")
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_result_cache_reuse_and_invalidate(tmp_path: Path, engine: EngineType) -> None:
+    path = tmp_path / "data.parquet"
+    pl.DataFrame({"a": [1, 2, 3, 4], "b": ["x", "y", "x", "y"]}).write_parquet(path)
+
+    q = pl.scan_parquet(path).filter(pl.col("b") == "x").select(pl.col("a").sum())
+    expected = pl.DataFrame({"a": [4]})
+
+    assert_frame_equal(q.collect(engine=engine), expected)
+    info = pl.result_cache_info()
+    assert info is not None
+    assert info["hits"] == 0
+    assert info["misses"] == 1
+    assert info["entries"] == 1
+    assert 0 < info["bytes"] <= info["max_bytes"]
+
+    assert_frame_equal(q.collect(engine=engine), expected)
+    info = pl.result_cache_info()
+    assert info is not None
+    assert info["hits"] == 1
+    assert info["misses"] == 1
+
+    # Modifying the file invalidates the cached result.
+    pl.DataFrame({"a": [10, 20, 30], "b": ["x", "x", "y"]}).write_parquet(path)
+    assert_frame_equal(q.collect(engine=engine), pl.DataFrame({"a": [30]}))
+    info = pl.result_cache_info()
+    assert info is not None
+    assert info["hits"] == 1
+    assert info["misses"] == 2
+
+
+@pytest.mark.usefixtures("result_cache")
+def test_result_cache_skips_in_memory_data(tmp_path: Path) -> None:
+    path = tmp_path / "data.parquet"
+    pl.DataFrame({"a": [1, 2, 3]}).write_parquet(path)
+    other = pl.LazyFrame({"a": [1, 3], "c": [True, False]})
+
+    # Only the scan of the file can be cached, not the join with in-memory data.
+    q = pl.scan_parquet(path).join(other, on="a")
+    for _ in range(2):
+        assert_frame_equal(
+            q.collect(),
+            pl.DataFrame({"a": [1, 3], "c": [True, False]}),
+            check_row_order=False,
+        )
+    info = pl.result_cache_info()
+    assert info is not None
+    assert info["entries"] == 1
+    assert info["hits"] == 1
+
+    assert pl.LazyFrame({"a": [1]}).collect().height == 1
+    assert pl.result_cache_info() == info
+
+
+@pytest.mark.usefixtures("result_cache")
+def test_result_cache_does_not_store_sink_input(tmp_path: Path) -> None:
+    path = tmp_path / "data.parquet"
+    pl.DataFrame({"a": [1, 2, 3, 4]}).write_parquet(path)
+    q = pl.scan_parquet(path).filter(pl.col("a") > 1)
+    expected = pl.DataFrame({"a": [2, 3, 4]})
+
+    # Storing the input of a sink would collect it in memory.
+    q.sink_parquet(tmp_path / "out_1.parquet")
+    info = pl.result_cache_info()
+    assert info is not None
+    assert info["entries"] == 0
+    assert info["misses"] == 0
+
+    # A result stored by a collect is still used by a sink.
+    assert_frame_equal(q.collect(), expected)
+    q.sink_parquet(tmp_path / "out_2.parquet")
+    info = pl.result_cache_info()
+    assert info is not None
+    assert info["entries"] == 1
+    assert info["hits"] == 1
+
+    pl.disable_result_cache()
+    for out in ["out_1.parquet", "out_2.parquet"]:
+        assert_frame_equal(pl.read_parquet(tmp_path / out), expected)
+
+
+def test_result_cache_enable_disable() -> None:
+    assert not pl.using_result_cache()
+    assert pl.result_cache_info() is None
+
+    pl.enable_result_cache(max_bytes=0)
+    try:
+        assert pl.using_result_cache()
+        pl.clear_result_cache()
+        assert pl.result_cache_info() == {
+            "hits": 0,
+            "misses": 0,
+            "entries": 0,
+            "bytes": 0,
+            "max_bytes": 0,
+        }
+    finally:
+        pl.disable_result_cache()
+    assert not pl.using_result_cache()
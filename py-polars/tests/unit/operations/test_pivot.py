@@ -732,3 +732,65 @@ def test_pivot_unsupported_agg_raises_25860() -> None:
     df = pl.DataFrame({"index": [0, 0], "data": ["foo", "bar"]})
     with pytest.raises(pl.exceptions.InvalidOperationError, match="sum"):
         df.pivot("index", index="index", aggregate_function=pl.element().sum())
+
+
+@pytest.mark.parametrize(
+    "aggregate_function",
+    ["first", "last", "sum", "mean", "len", pl.element().implode()],
+)
+@pytest.mark.parametrize("maintain_order", [False, True])
+def test_pivot_streaming(
+    aggregate_function: PivotAgg | pl.Expr, maintain_order: bool
+) -> None:
+    lf = pl.LazyFrame(
+        {
+            "idx": [1, 2, 1, 3, 2, 1, None, 4],
+            "on": ["a", "b", "a", "c", None, "b", "a", "z"],
+            "v1": [1, 2, 3, 4, 5, 6, 7, 8],
+            "v2": [1.0, None, 3.0, 4.0, 5.0, None, 7.0, 8.0],
+        }
+    )
+    q = lf.pivot(
+        "on",
+        on_columns=["a", "b", "c", "d"],
+        index="idx",
+        values=["v1", "v2"],
+        aggregate_function=aggregate_function,
+        maintain_order=maintain_order,
+    )
+
+    expected = q.collect(engine="in-memory")
+    out = q.collect(engine="streaming")
+    assert_frame_equal(out, expected, check_row_order=maintain_order)
+
+
+@pytest.mark.parametrize("aggregate_function", ["first", "sum", "mean", "len"])
+def test_pivot_streaming_group_by(aggregate_function: PivotAgg) -> None:
+    lf = pl.LazyFrame({"idx": [1, 1, 2], "on": ["a", "b", "a"], "v": [1, 2, 3]})
+    q = lf.pivot(
+        "on",
+        on_columns=["a", "b"],
+        index="idx",
+        aggregate_function=aggregate_function,
+    )
+
+    # Every output column is a reducer over the rows of its `on` value, so the
+    # pivot runs on the streaming group-by without an in-memory fallback.
+    dot = q.show_graph(engine="streaming", plan_stage="physical", raw_output=True)
+    assert isinstance(dot, str)
+    assert "group-by" in dot
+    assert "in-memory-map" not in dot
+
+
+def test_pivot_streaming_item() -> None:
+    lf = pl.LazyFrame({"idx": [1, 1, 2, 2], "on": ["a", "b", "a", "b"], "v": range(4)})
+    q = lf.pivot("on", on_columns=["a", "b"], index="idx", aggregate_function="item")
+    expected = pl.DataFrame({"idx": [1, 2], "a": [0, 2], "b": [1, 3]})
+    assert_frame_equal(q.collect(engine="streaming"), expected, check_row_order=False)
+
+    # Only a cell without any rows makes the aggregation fail.
+    q = lf.pivot(
+        "on", on_columns=["a", "b", "c"], index="idx", aggregate_function="item"
+    )
+    with pytest.raises(ComputeError):
+        q.collect(engine="streaming")
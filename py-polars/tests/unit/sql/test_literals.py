@@ -158,11 +158,10 @@ def test_fixed_intervals() -> None:
         ):
             ctx.execute("SELECT INTERVAL -'7d' AS one_week_ago FROM df")
 
-        with pytest.raises(
-            SQLSyntaxError,
-            match="fixed-duration interval cannot contain years, quarters, or months",
-        ):
-            ctx.execute("SELECT INTERVAL '1 quarter 1 month' AS q FROM df")
+        # calendar intervals have no fixed duration
+        out = ctx.execute("SELECT INTERVAL '1 quarter 1 month 2 days' AS q FROM df")
+        assert out.schema == {"q": pl.Interval}
+        assert out.item() == (4, 2, 0)
 
 
 def test_interval_offsets() -> None:
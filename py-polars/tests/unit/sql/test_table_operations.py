@@ -131,6 +131,15 @@ def test_explain_query(test_frame: pl.LazyFrame) -> None:
         )
 
 
+def test_explain_analyze_query(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame) as ctx:
+        plan = ctx.execute("EXPLAIN ANALYZE SELECT * FROM frame").collect()
+    assert plan.columns == ["Physical Plan"]
+    lines = plan["Physical Plan"].to_list()
+    assert lines[0].startswith("streaming query took")
+    assert any("rows(in=" in line for line in lines[1:])
+
+
 def test_show_tables(test_frame: pl.LazyFrame) -> None:
     # 'show tables' lists all tables registered with the sql context in sorted order
     with pl.SQLContext(
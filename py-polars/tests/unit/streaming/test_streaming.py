@@ -1,10 +1,11 @@
 from __future__ import annotations
 
+import json
 import os
 import time
 from datetime import date
 from pathlib import Path
-from typing import TYPE_CHECKING, Any
+from typing import TYPE_CHECKING, Any, Literal
 
 import numpy as np
 import pytest
@@ -412,3 +413,137 @@ def test_streaming_boolean_multiply_dtype_24609() -> None:
     assert (
         lf.collect(engine="streaming").schema == lf.collect(engine="in-memory").schema
     )
+
+
+@pytest.mark.parametrize(
+    "expr",
+    [
+        pl.col("x").sum().over("g"),
+        pl.col("x").mean().over("g", "h"),
+        pl.len().over("g"),
+        pl.col("x").first().over(pl.col("g") % 2),
+        (pl.col("x") * 2).over("g"),
+        pl.col("x").sum().over("g", mapping_strategy="join"),
+        pl.col("x").max().over("h", mapping_strategy="explode"),
+    ],
+)
+def test_streaming_over_native(expr: pl.Expr) -> None:
+    lf = pl.LazyFrame(
+        {
+            "g": [1, 2, None, 1, 3, 2, None, 1],
+            "h": ["a", "b", "a", "a", "b", "b", "a", "c"],
+            "x": [5, 3, 1, None, 2, 8, 4, 6],
+        }
+    ).select(expr.alias("out"))
+
+    dot = lf.show_graph(engine="streaming", plan_stage="physical", raw_output=True)
+    assert isinstance(dot, str)
+    assert "in-memory-map" not in dot
+
+    assert_frame_equal(lf.collect(engine="streaming"), lf.collect(engine="in-memory"))
+
+
+@pytest.mark.parametrize(
+    "expr",
+    [
+        pl.col("x").cum_sum().over("g"),
+        pl.col("x").sort().over("g", mapping_strategy="join"),
+        pl.col("x").sort().over("g", mapping_strategy="explode"),
+        pl.col("x").first().over("g", order_by="x"),
+        pl.col("x").sum().over(pl.col("g").rank()),
+    ],
+)
+def test_streaming_over_non_aggregating(expr: pl.Expr) -> None:
+    lf = pl.LazyFrame(
+        {
+            "g": [1, 2, None, 1, 3, 2, None, 1],
+            "x": [5, 3, 1, None, 2, 8, 4, 6],
+        }
+    ).with_columns(expr.alias("out"))
+
+    assert_frame_equal(lf.collect(engine="streaming"), lf.collect(engine="in-memory"))
+
+
+def test_streaming_chrome_trace(tmp_path: Path, plmonkeypatch: PlMonkeyPatch) -> None:
+    trace_path = tmp_path / "trace.json"
+    plmonkeypatch.setenv("POLARS_STREAM_TRACE_PATH", str(trace_path))
+
+    lf = pl.LazyFrame({"a": [i % 10 for i in range(1000)], "b": range(1000)})
+    out = lf.group_by("a").agg(pl.col("b").sum()).collect(engine="streaming")
+    assert out.height == 10
+
+    events = json.loads(trace_path.read_text())["traceEvents"]
+    categories = {e.get("cat") for e in events}
+    assert {"poll", "state_update"} <= categories
+    for e in events:
+        if e["ph"] == "X":
+            assert e["dur"] >= 0
+    assert any(
+        e["cat"] == "state_update" and e["name"].startswith("group-by")
+        for e in events
+        if e["ph"] == "X"
+    )
+
+
+def _slow_batches(s: pl.Series) -> pl.Series:
+    time.sleep(0.05)
+    return s
+
+
+def test_streaming_collect_timeout() -> None:
+    lf = pl.LazyFrame({"a": range(100_000)}).select(
+        pl.col("a").map_batches(_slow_batches, return_dtype=pl.Int64)
+    )
+    with pytest.raises(pl.exceptions.ComputeError, match="timed out"):
+        lf.collect(engine="streaming", timeout=0.01)
+
+
+@pytest.mark.write_disk
+def test_streaming_sink_timeout_removes_partial_file(tmp_path: Path) -> None:
+    path = tmp_path / "out.parquet"
+    lf = pl.LazyFrame({"a": range(100_000)}).select(
+        pl.col("a").map_batches(_slow_batches, return_dtype=pl.Int64)
+    )
+    with pytest.raises(pl.exceptions.ComputeError, match="timed out"):
+        lf.sink_parquet(path, lazy=True).collect(engine="streaming", timeout=0.01)
+    assert not path.exists()
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("commit_protocol", ["direct", "staged"])
+def test_streaming_partitioned_sink_timeout_removes_partial_files(
+    tmp_path: Path, commit_protocol: Literal["direct", "staged"]
+) -> None:
+    lf = pl.LazyFrame({"a": range(100_000)}).select(
+        pl.col("a").map_batches(_slow_batches, return_dtype=pl.Int64)
+    )
+    target = pl.PartitionBy(
+        tmp_path, max_rows_per_file=100, commit_protocol=commit_protocol
+    )
+    with pytest.raises(pl.exceptions.ComputeError, match="timed out"):
+        lf.sink_parquet(target, lazy=True).collect(engine="streaming", timeout=0.2)
+    assert [p for p in tmp_path.rglob("*") if p.is_file()] == []
+
+
+def test_streaming_collect_memory_limit() -> None:
+    lf = pl.LazyFrame({"a": range(100_000)})
+    with pytest.raises(pl.exceptions.ComputeError, match="memory limit"):
+        lf.collect(engine="streaming", memory_limit=1024)
+
+    out = lf.collect(engine="streaming", memory_limit=1 << 30)
+    assert out.height == 100_000
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="streaming"):
+        lf.collect(engine="in-memory", memory_limit=1 << 30)
+
+
+def test_streaming_collect_memory_limit_spill(
+    plmonkeypatch: PlMonkeyPatch, tmp_path: Path
+) -> None:
+    plmonkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    plmonkeypatch.setenv("POLARS_OOC_SPILL_POLICY", "spill")
+
+    # Going over the limit spills the buffered frames instead of failing.
+    lf = pl.LazyFrame({"a": range(100_000)})
+    out = lf.collect(engine="streaming", memory_limit=1024)
+    assert_frame_equal(out, lf.collect())
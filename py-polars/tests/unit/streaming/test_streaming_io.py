@@ -308,3 +308,37 @@ def test_scan_non_existent_file_21527() -> None:
         pl.scan_parquet("a-file-that-does-not-exist").sink_ipc(
             "x.ipc", engine="streaming"
         )
+
+
+@pytest.mark.write_disk
+def test_streaming_collect_progress(tmp_path: Path) -> None:
+    for i in range(3):
+        pl.DataFrame({"a": range(i * 100, (i + 1) * 100)}).write_parquet(
+            tmp_path / f"{i}.parquet", row_group_size=50
+        )
+
+    reports: list[dict[str, Any]] = []
+    out = pl.scan_parquet(tmp_path / "*.parquet").collect(
+        engine="streaming", progress=reports.append, progress_interval=0.0
+    )
+    assert out.height == 300
+
+    final = reports[-1]
+    assert final["finished"]
+    assert all(not r["finished"] for r in reports[:-1])
+    # Totals are only reported once known, so they never change.
+    assert all(
+        s["row_groups_total"] in (None, 6) for r in reports for s in r["sources"]
+    )
+
+    [source] = final["sources"]
+    assert source["name"].startswith("multi-scan[parquet]")
+    assert source["files_done"] == source["files_total"] == 3
+    assert source["row_groups_done"] == source["row_groups_total"] == 6
+    assert 0 < source["bytes_read"] == source["bytes_total"]
+
+    [sink] = final["sinks"]
+    assert sink["rows_written"] == 300
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="streaming"):
+        pl.LazyFrame({"a": [1]}).collect(engine="in-memory", progress=print)
@@ -23,6 +23,7 @@ if TYPE_CHECKING:
     from pathlib import Path
 
     from polars._typing import AsofJoinStrategy, JoinStrategy, MaintainOrderJoin
+    from tests.conftest import PlMonkeyPatch
 
 pytestmark = pytest.mark.xdist_group("streaming")
 
@@ -664,3 +665,66 @@ def test_streaming_asof_join(
     expected = q.collect(engine="in-memory")
     actual = q.collect(engine="streaming")
     assert_frame_equal(actual, expected)
+
+
+@pytest.mark.parametrize("how", ["inner", "left", "right"])
+@pytest.mark.parametrize("n_dim_keys", [10, 5_000])
+@pytest.mark.parametrize("nulls_equal", [False, True])
+def test_streaming_join_runtime_filter(
+    how: JoinStrategy,
+    n_dim_keys: int,
+    nulls_equal: bool,
+    tmp_path: Path,
+    plmonkeypatch: PlMonkeyPatch,
+    capfd: pytest.CaptureFixture[str],
+) -> None:
+    plmonkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    n = 50_000
+    fact = pl.DataFrame({"key": np.arange(n) % 20_000, "value": np.arange(n)})
+    fact = fact.with_columns(
+        pl.when(pl.col("value") % 97 != 0).then(pl.col("key")).alias("key")
+    )
+    fact_path = tmp_path / "fact.parquet"
+    fact.write_parquet(fact_path, row_group_size=1_000)
+
+    dim = pl.DataFrame(
+        {
+            "key": pl.Series(
+                [None, *range(15_000, 15_000 + n_dim_keys)], dtype=pl.Int64
+            ),
+            "label": range(n_dim_keys + 1),
+        }
+    )
+
+    # The dimension table is the build side. Unmatched rows of the fact table are not
+    # part of the output, so it can be filtered.
+    fact_q = pl.scan_parquet(fact_path)
+    dim_q = dim.lazy()
+    if how == "left":
+        q = dim_q.join(fact_q, on="key", how=how, nulls_equal=nulls_equal)
+    else:
+        q = fact_q.join(dim_q, on="key", how=how, nulls_equal=nulls_equal)
+
+    capfd.readouterr()
+    out = q.collect(engine="streaming")
+    assert "setting runtime filter on probe side" in capfd.readouterr().err
+
+    expected = q.collect(engine="in-memory")
+    assert_frame_equal(out, expected, check_row_order=False)
+
+
+def test_streaming_join_runtime_filter_disabled() -> None:
+    left = pl.LazyFrame({"a": [1, 2, 3, 4]})
+    right = pl.LazyFrame({"a": [2, 3], "b": ["x", "y"]})
+    q = left.join(right, on="a")
+
+    assert "dynamic_predicate" in q.explain(engine="streaming")
+
+    opts = pl.QueryOptFlags(join_runtime_filters=False)
+    assert "dynamic_predicate" not in q.explain(engine="streaming", optimizations=opts)
+    assert_frame_equal(
+        q.collect(engine="streaming", optimizations=opts),
+        pl.DataFrame({"a": [2, 3], "b": ["x", "y"]}),
+        check_row_order=False,
+    )